@@ -1,6 +1,8 @@
 use crate::line_editor::configure_ctrl_c;
 use nu_command::commands::default_context::create_default_context;
-use nu_engine::{evaluation_context, run_block, script::run_script_standalone, EvaluationContext};
+use nu_engine::plugin::build_plugin::{self, ShadowedPlugin};
+use nu_engine::script::{defines_main, run_script_main, run_script_standalone};
+use nu_engine::{evaluation_context, run_block, EvaluationContext};
 
 #[allow(unused_imports)]
 pub(crate) use nu_engine::script::{process_script, LineResult};
@@ -23,7 +25,17 @@ use std::sync::atomic::Ordering;
 #[cfg(feature = "rustyline-support")]
 use rustyline::{self, error::ReadlineError};
 
+#[cfg(feature = "rustyline-support")]
+use crate::session::{self, Session};
+
+#[cfg(feature = "rustyline-support")]
+use crate::shell::helper::is_incomplete;
+
+#[cfg(feature = "rustyline-support")]
+use nu_engine::JobStatus;
+
 use crate::EnvironmentSyncer;
+use crate::StartupReport;
 use nu_errors::ShellError;
 use nu_parser::ParserScope;
 use nu_protocol::{hir::ExternalRedirection, UntaggedValue, Value};
@@ -39,6 +51,8 @@ pub struct Options {
     pub save_history: bool,
     pub stdin: bool,
     pub scripts: Vec<NuScript>,
+    /// Timing the startup, for `--startup-report`
+    pub startup_report: Option<StartupReport>,
 }
 
 impl Default for Options {
@@ -55,6 +69,7 @@ impl Options {
             save_history: true,
             stdin: false,
             scripts: vec![],
+            startup_report: None,
         }
     }
 
@@ -72,6 +87,8 @@ impl Options {
 pub struct NuScript {
     pub filepath: Option<OsString>,
     pub contents: String,
+    /// The arguments the script is run with, which go to its `main` command
+    pub args: Vec<String>,
 }
 
 impl NuScript {
@@ -84,6 +101,7 @@ impl NuScript {
         Ok(Self {
             filepath: None,
             contents: text,
+            args: vec![],
         })
     }
 
@@ -104,15 +122,37 @@ impl NuScript {
         Ok(Self {
             filepath: Some(path),
             contents: buffer,
+            args: vec![],
         })
     }
 }
 
+/// The directories to look for plugins in, in order of precedence: the `plugin_dirs` from the
+/// config in the order they're listed, then the directory `nu` is in
 pub fn search_paths() -> Vec<std::path::PathBuf> {
     use std::env;
 
     let mut search_paths = Vec::new();
 
+    if let Ok(config) = nu_data::config::config(Tag::unknown()) {
+        if let Some(Value {
+            value: UntaggedValue::Table(pipelines),
+            ..
+        }) = config.get("plugin_dirs")
+        {
+            for pipeline in pipelines {
+                if let Ok(plugin_dir) = pipeline.as_string() {
+                    search_paths.push(PathBuf::from(plugin_dir));
+                }
+            }
+        }
+    }
+
+    // Then the plugins installed with `plugin install`
+    if let Ok(managed_path) = nu_data::config::managed_plugins_path() {
+        search_paths.push(managed_path.join("bin"));
+    }
+
     // Automatically add path `nu` is in as a search path
     if let Ok(exe_path) = env::current_exe() {
         if let Some(exe_dir) = exe_path.parent() {
@@ -120,21 +160,28 @@ pub fn search_paths() -> Vec<std::path::PathBuf> {
         }
     }
 
+    search_paths
+}
+
+/// The addresses of the plugins served over a connection, from the `remote_plugins` setting
+pub fn remote_plugin_addresses() -> Vec<String> {
+    let mut addresses = Vec::new();
+
     if let Ok(config) = nu_data::config::config(Tag::unknown()) {
         if let Some(Value {
-            value: UntaggedValue::Table(pipelines),
+            value: UntaggedValue::Table(entries),
             ..
-        }) = config.get("plugin_dirs")
+        }) = config.get("remote_plugins")
         {
-            for pipeline in pipelines {
-                if let Ok(plugin_dir) = pipeline.as_string() {
-                    search_paths.push(PathBuf::from(plugin_dir));
+            for entry in entries {
+                if let Ok(address) = entry.as_string() {
+                    addresses.push(address);
                 }
             }
         }
     }
 
-    search_paths
+    addresses
 }
 
 pub async fn run_script_file(mut options: Options) -> Result<(), Box<dyn Error>> {
@@ -142,7 +189,8 @@ pub async fn run_script_file(mut options: Options) -> Result<(), Box<dyn Error>>
     let mut syncer = create_environment_syncer(&context, &mut options);
     let config = syncer.get_config();
 
-    context.configure(&config, |_, ctx| {
+    context.configure(&config, |config, ctx| {
+        ctx.scope.set_config(config);
         syncer.load_environment();
         syncer.sync_env_vars(ctx);
         syncer.sync_path_vars(ctx);
@@ -164,6 +212,11 @@ pub async fn run_script_file(mut options: Options) -> Result<(), Box<dyn Error>>
 
     run_script_standalone(script.get_code().to_string(), options.stdin, &context, true).await?;
 
+    // Once the script has run, and defined its commands, a `main` it defines gets its arguments
+    if script.filepath.is_some() && defines_main(&context) {
+        run_script_main(&script.args, &context, true).await?;
+    }
+
     Ok(())
 }
 
@@ -218,6 +271,7 @@ pub async fn cli(
     let mut rl = default_rustyline_editor_configuration();
 
     context.configure(&configuration, |config, ctx| {
+        ctx.scope.set_config(config);
         syncer.load_environment();
         syncer.sync_env_vars(ctx);
         syncer.sync_path_vars(ctx);
@@ -233,6 +287,10 @@ pub async fn cli(
         rl.set_helper(helper);
     });
 
+    if let Some(report) = &mut options.startup_report {
+        report.phase("config load");
+    }
+
     // start time for command duration
     let startup_commands_start_time = std::time::Instant::now();
     // run the startup commands
@@ -246,6 +304,9 @@ pub async fn cli(
         "startup commands took {:?}",
         startup_commands_start_time.elapsed()
     );
+    if let Some(report) = &mut options.startup_report {
+        report.phase("startup commands");
+    }
 
     // Give ourselves a scope to work in
     context.scope.enter_scope();
@@ -253,6 +314,9 @@ pub async fn cli(
     options.history(|file| {
         let _ = rl.load_history(&file);
     });
+    if let Some(report) = &mut options.startup_report {
+        report.phase("history load");
+    }
 
     let mut session_text = String::new();
     let mut line_start: usize = 0;
@@ -273,7 +337,35 @@ pub async fn cli(
         let _ = nu_ansi_term::enable_ansi_support();
     }
 
+    // A session file left behind by a nu that isn't running means that session didn't exit
+    // cleanly
+    let pid = std::process::id();
+    let mut recovered = None;
+    options.history(|file| {
+        recovered = session::take_crashed(&session::dir(file));
+        session::clear_on_exit(session::dir(file), pid);
+    });
+    let mut recovered_line = None;
+    if let Some(recovered) = recovered {
+        if std::path::Path::new(&recovered.cwd).is_dir() {
+            context.shell_manager.set_path(recovered.cwd.clone());
+        }
+        println!(
+            "Recovered the previous session in {}{}",
+            recovered.cwd,
+            if recovered.pending.is_some() {
+                ", with the input that wasn't finished"
+            } else if recovered.running.is_some() {
+                ", with the line it was running"
+            } else {
+                ""
+            }
+        );
+        recovered_line = recovered.pending.or(recovered.running);
+    }
+
     let mut ctrlcbreak = false;
+    let mut last_failed = false;
 
     loop {
         if context.ctrl_c.load(Ordering::SeqCst) {
@@ -281,6 +373,14 @@ pub async fn cli(
             continue;
         }
 
+        for job in context.jobs.take_finished() {
+            let outcome = match job.status {
+                JobStatus::Exited(Some(code)) => format!("exit {}", code),
+                _ => "killed".to_string(),
+            };
+            println!("[{}] done ({}) {}", job.id, outcome, job.command);
+        }
+
         let cwd = context.shell_manager.path();
 
         let colored_prompt = {
@@ -296,7 +396,7 @@ pub async fn cli(
                 if err.is_some() {
                     context.scope.exit_scope();
 
-                    format!("\x1b[32m{}{}\x1b[m> ", cwd, current_branch())
+                    format!("\x1b[32m{}{}\x1b[m", cwd, current_branch())
                 } else {
                     let run_result = run_block(&prompt_block, &context, InputStream::empty()).await;
                     context.scope.exit_scope();
@@ -312,7 +412,7 @@ pub async fn cli(
                                 context.clear_errors();
 
                                 if !errors.is_empty() {
-                                    "> ".to_string()
+                                    String::new()
                                 } else {
                                     string_result.item
                                 }
@@ -321,46 +421,105 @@ pub async fn cli(
                                 context.host.lock().print_err(e, &Text::from(prompt_line));
                                 context.clear_errors();
 
-                                "> ".to_string()
+                                String::new()
                             }
                         },
                         Err(e) => {
                             context.host.lock().print_err(e, &Text::from(prompt_line));
                             context.clear_errors();
 
-                            "> ".to_string()
+                            String::new()
                         }
                     }
                 }
             } else {
-                format!("\x1b[32m{}{}\x1b[m> ", cwd, current_branch())
+                format!("\x1b[32m{}{}\x1b[m", cwd, current_branch())
             }
         };
 
-        let prompt = {
-            if let Ok(bytes) = strip_ansi_escapes::strip(&colored_prompt) {
-                String::from_utf8_lossy(&bytes).to_string()
-            } else {
-                "> ".to_string()
+        let (indicator, continuation) = prompt_indicators(&*configuration, last_failed);
+        let colored_prompt = format!("{}{}", colored_prompt, indicator);
+
+        // The editor only redraws the line the prompt ends on, so any lines before it are
+        // printed once up front
+        let colored_prompt = match colored_prompt.rfind('\n') {
+            Some(idx) => {
+                println!("{}", &colored_prompt[..idx]);
+                colored_prompt[idx + 1..].to_string()
             }
+            None => colored_prompt,
         };
 
-        rl.helper_mut().expect("No helper").colored_prompt = colored_prompt;
-        let mut initial_command = Some(String::new());
+        rl.helper_mut().expect("No helper").colored_prompt = colored_prompt.clone();
+
+        // The report ends with the first prompt, which is only rendered once
+        if let Some(mut report) = options.startup_report.take() {
+            report.phase("prompt first render");
+            print!("{}", report.render());
+        }
+
+        let mut initial_command = Some(recovered_line.take().unwrap_or_default());
         let mut readline = Err(ReadlineError::Eof);
         while let Some(ref cmd) = initial_command {
-            readline = rl.readline_with_initial(&prompt, (&cmd, ""));
+            readline = rl.readline_with_initial(&strip_prompt(&colored_prompt), (&cmd, ""));
             initial_command = None;
         }
 
+        // Input waiting for more, like an unclosed block or a trailing pipe, carries on over the
+        // next lines, each prompted with the continuation indicator
+        rl.helper_mut().expect("No helper").colored_prompt = continuation.clone();
+        while let Ok(line) = &readline {
+            if !is_incomplete(line) {
+                break;
+            }
+
+            // Keep what's typed so far, in case the terminal is killed before it's finished
+            options.history(|file| {
+                session::save(
+                    &session::dir(file),
+                    &Session {
+                        pid,
+                        cwd: cwd.clone(),
+                        running: None,
+                        pending: Some(line.clone()),
+                    },
+                );
+            });
+
+            let previous = line.clone();
+            match rl.readline(&strip_prompt(&continuation)) {
+                Ok(next) => readline = Ok(format!("{}\n{}", previous, next)),
+                // Running what there is reports what it was still waiting for
+                Err(ReadlineError::Eof) => break,
+                Err(err) => readline = Err(err),
+            }
+        }
+
         if let Ok(line) = &readline {
             line_start = session_text.len();
             session_text.push_str(line);
             session_text.push('\n');
+
+            // Save the line before running it, so neither it nor where we were is lost if the
+            // terminal is killed while it runs
+            options.history(|file| {
+                rl.add_history_entry(line.as_str());
+                let _ = rl.save_history(&file);
+                session::save(
+                    &session::dir(file),
+                    &Session {
+                        pid,
+                        cwd: cwd.clone(),
+                        running: Some(line.clone()),
+                        pending: None,
+                    },
+                );
+            });
         }
 
         // start time for command duration
         let cmd_start_time = std::time::Instant::now();
+        let cmd_start_date = chrono::Local::now();
 
         let line = match convert_rustyline_result_to_string(readline) {
             LineResult::Success(_) => {
@@ -377,9 +536,10 @@ pub async fn cli(
         };
 
         // Store cmd duration in an env var
+        let cmd_duration = cmd_start_time.elapsed();
         context
             .scope
-            .add_env_var("CMD_DURATION", format!("{:?}", cmd_start_time.elapsed()));
+            .add_env_var("CMD_DURATION", format!("{:?}", cmd_duration));
 
         // Check the config to see if we need to update the path
         // TODO: make sure config is cached so we don't path this load every call
@@ -388,6 +548,7 @@ pub async fn cli(
         context.configure(&configuration, |config, ctx| {
             if syncer.did_config_change() {
                 syncer.reload();
+                ctx.scope.set_config(&syncer.get_config());
                 syncer.sync_env_vars(ctx);
                 syncer.sync_path_vars(ctx);
             }
@@ -399,11 +560,31 @@ pub async fn cli(
             let _ = configure_rustyline_editor(&mut rl, config);
         });
 
+        // The line is done, so only where we are is left to recover
+        options.history(|file| {
+            session::save(
+                &session::dir(file),
+                &Session {
+                    pid,
+                    cwd: context.shell_manager.path(),
+                    running: None,
+                    pending: None,
+                },
+            );
+
+            // Changing directory counts as a visit for `z`
+            let path = context.shell_manager.path();
+            if context.shell_manager.is_filesystem() && path != cwd {
+                let _ = nu_data::frecency::record_visit(file, std::path::Path::new(&path));
+            }
+        });
+
         match line {
             LineResult::Success(line) => {
+                let success = context.get_errors().is_empty();
+                last_failed = !success;
                 options.history(|file| {
-                    rl.add_history_entry(&line);
-                    let _ = rl.save_history(&file);
+                    log_history(file, &line, cmd_start_date, cmd_duration, success, &cwd);
                 });
 
                 evaluation_context::maybe_print_errors(&context, Text::from(session_text.clone()));
@@ -413,13 +594,14 @@ pub async fn cli(
                 options.history(|file| {
                     rl.clear_history();
                     let _ = rl.save_history(&file);
+                    let _ = std::fs::remove_file(nu_engine::history_log::log_path(file));
                 });
             }
 
             LineResult::Error(line, reason) => {
+                last_failed = true;
                 options.history(|file| {
-                    rl.add_history_entry(&line);
-                    let _ = rl.save_history(&file);
+                    log_history(file, &line, cmd_start_date, cmd_duration, false, &cwd);
                 });
 
                 context.with_host(|host| host.print_err(reason, &Text::from(session_text.clone())));
@@ -440,7 +622,7 @@ pub async fn cli(
                         let _ = rl.save_history(&file);
                     });
 
-                    std::process::exit(0);
+                    context.exit(0);
                 } else {
                     context.with_host(|host| host.stdout("CTRL-C pressed (again to quit)"));
                     ctrlcbreak = true;
@@ -465,19 +647,75 @@ pub async fn cli(
     // we are ok if we can not save history
     options.history(|file| {
         let _ = rl.save_history(&file);
+        session::clear(&session::dir(file), pid);
     });
 
+    context.jobs.stop_all();
+
     Ok(())
 }
 
+/// Keeps the timing and outcome of each line for `history stats`. Like the history itself, this is
+/// best effort.
+#[cfg(feature = "rustyline-support")]
+fn log_history(
+    history: &std::path::Path,
+    command: &str,
+    start: chrono::DateTime<chrono::Local>,
+    duration: std::time::Duration,
+    success: bool,
+    cwd: &str,
+) {
+    if command.trim().is_empty() {
+        return;
+    }
+
+    let entry = nu_engine::history_log::HistoryLogEntry {
+        command: command.to_string(),
+        start: start.to_rfc3339(),
+        duration_ms: duration.as_millis() as u64,
+        success,
+        cwd: cwd.to_string(),
+    };
+    let _ = nu_engine::history_log::append(&nu_engine::history_log::log_path(history), &entry);
+}
+
 pub fn register_plugins(context: &mut EvaluationContext) -> Result<(), ShellError> {
-    if let Ok(plugins) = nu_engine::plugin::build_plugin::scan(search_paths()) {
-        context.add_commands(
-            plugins
-                .into_iter()
-                .filter(|p| !context.is_command_registered(p.name()))
-                .collect(),
-        );
+    if let Ok(scan) = build_plugin::scan_with_remotes(&remote_plugin_addresses(), search_paths()) {
+        let mut shadowed = scan.shadowed;
+        let mut plugins = vec![];
+
+        // Built-in commands always take precedence over plugins
+        for plugin in scan.commands {
+            if context.is_command_registered(plugin.name()) {
+                shadowed.push(ShadowedPlugin {
+                    name: plugin.name().to_string(),
+                    version: plugin.signature().version,
+                    path: plugin.plugin_path().unwrap_or_default().to_string(),
+                    used: "(built-in)".to_string(),
+                });
+            } else {
+                plugins.push(plugin);
+            }
+        }
+
+        for plugin in &plugins {
+            nu_engine::crash_context::add_plugin(
+                plugin.name(),
+                plugin.plugin_path().unwrap_or_default(),
+            );
+        }
+        context.add_commands(plugins);
+
+        if !shadowed.is_empty() {
+            let report = build_plugin::shadowed_plugins_report(&shadowed);
+            context.with_host(|host| host.stderr(&report));
+        }
+
+        // A plugin service that is down shouldn't keep the shell from starting
+        for err in scan.errors {
+            context.with_host(|host| host.print_err(err, &Text::from("")));
+        }
     }
 
     Ok(())
@@ -487,6 +725,23 @@ async fn run_startup_commands(
     context: &mut EvaluationContext,
     config: &dyn nu_data::config::Conf,
 ) -> Result<(), ShellError> {
+    if let Some(err) = config.error() {
+        // The startup commands may depend on settings that are missing now, so none of them run
+        // until the file is fixed
+        if let Some(diagnostic) = err.into_diagnostic() {
+            let reason = format!(
+                "{}\n\nNu is running with the default settings and no startup commands. \
+                 Run `config edit` to fix the file.",
+                diagnostic.message
+            );
+            context.with_host(|host| {
+                host.print_err(ShellError::untagged_runtime_error(reason), &Text::from(""))
+            });
+        }
+
+        return Ok(());
+    }
+
     if let Some(commands) = config.var("startup") {
         match commands {
             Value {
@@ -537,6 +792,40 @@ pub async fn parse_and_eval(line: &str, ctx: &EvaluationContext) -> Result<Strin
     result?.collect_string(Tag::unknown()).await.map(|x| x.item)
 }
 
+/// The indicators the prompt ends with and the continuation lines of incomplete input start
+/// with, colored by the `color_config` theme. After a failed line the prompt ends with the error
+/// indicator instead.
+#[cfg(feature = "rustyline-support")]
+fn prompt_indicators(config: &dyn Conf, last_failed: bool) -> (String, String) {
+    let var = |name: &str, default: &str| {
+        config
+            .var(name)
+            .and_then(|value| value.as_string().ok())
+            .unwrap_or_else(|| default.to_string())
+    };
+    let colors = nu_data::primitive::get_color_config();
+    let style = |name: &str| colors.get(name).copied().unwrap_or_default();
+
+    let prompt_indicator = var("prompt_indicator", "> ");
+    let indicator = if last_failed {
+        style("error_indicator_color").paint(var("error_indicator", &prompt_indicator))
+    } else {
+        style("prompt_indicator_color").paint(prompt_indicator)
+    };
+    let continuation = style("prompt_indicator_color").paint(var("continuation_indicator", "::: "));
+
+    (indicator.to_string(), continuation.to_string())
+}
+
+#[cfg(feature = "rustyline-support")]
+fn strip_prompt(colored_prompt: &str) -> String {
+    if let Ok(bytes) = strip_ansi_escapes::strip(colored_prompt) {
+        String::from_utf8_lossy(&bytes).to_string()
+    } else {
+        "> ".to_string()
+    }
+}
+
 #[allow(dead_code)]
 fn current_branch() -> String {
     #[cfg(feature = "shadow-rs")]
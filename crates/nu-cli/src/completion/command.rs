@@ -1,11 +1,10 @@
 use std::iter::FromIterator;
-use std::path::Path;
 
 use indexmap::set::IndexSet;
 
 use super::matchers::Matcher;
 use crate::completion::{Completer, CompletionContext, Suggestion};
-use nu_engine::EvaluationContext;
+use nu_engine::{is_executable, path_executables, EvaluationContext};
 
 pub struct CommandCompleter;
 
@@ -24,7 +23,9 @@ impl Completer for CommandCompleter {
         //   2. external command names relative to PATH env var, and
         //   3. any other executable (that matches what's been typed so far).
 
-        let path_executables = find_path_executables().unwrap_or_default();
+        let path_executables = std::env::var_os("PATH")
+            .map(|path_var| path_executables(&path_var))
+            .unwrap_or_default();
 
         // TODO quote these, if necessary
         commands.extend(path_executables.into_iter());
@@ -56,86 +57,3 @@ impl Completer for CommandCompleter {
         suggestions
     }
 }
-
-// TODO create a struct for "is executable" and store this information in it so we don't recompute
-//      on every dir entry
-
-#[cfg(windows)]
-fn pathext() -> Option<Vec<String>> {
-    std::env::var_os("PATHEXT").map(|v| {
-        v.to_string_lossy()
-            .split(';')
-            // Filter out empty tokens and ';' at the end
-            .filter(|f| f.len() > 1)
-            // Cut off the leading '.' character
-            .map(|ext| ext[1..].to_string())
-            .collect::<Vec<_>>()
-    })
-}
-
-#[cfg(windows)]
-fn is_executable(path: &Path) -> bool {
-    if let Ok(metadata) = path.metadata() {
-        let file_type = metadata.file_type();
-
-        // If the entry isn't a file, it cannot be executable
-        if !(file_type.is_file() || file_type.is_symlink()) {
-            return false;
-        }
-
-        if let Some(extension) = path.extension() {
-            if let Some(exts) = pathext() {
-                exts.iter()
-                    .any(|ext| extension.to_string_lossy().eq_ignore_ascii_case(ext))
-            } else {
-                false
-            }
-        } else {
-            false
-        }
-    } else {
-        false
-    }
-}
-
-#[cfg(target_arch = "wasm32")]
-fn is_executable(_path: &Path) -> bool {
-    false
-}
-
-#[cfg(unix)]
-fn is_executable(path: &Path) -> bool {
-    use std::os::unix::fs::PermissionsExt;
-
-    if let Ok(metadata) = path.metadata() {
-        let filetype = metadata.file_type();
-        let permissions = metadata.permissions();
-
-        // The file is executable if it is a directory or a symlink and the permissions are set for
-        // owner, group, or other
-        (filetype.is_file() || filetype.is_symlink()) && (permissions.mode() & 0o111 != 0)
-    } else {
-        false
-    }
-}
-
-// TODO cache these, but watch for changes to PATH
-fn find_path_executables() -> Option<IndexSet<String>> {
-    let path_var = std::env::var_os("PATH")?;
-    let paths: Vec<_> = std::env::split_paths(&path_var).collect();
-
-    let mut executables: IndexSet<String> = IndexSet::new();
-    for path in paths {
-        if let Ok(mut contents) = std::fs::read_dir(path) {
-            while let Some(Ok(item)) = contents.next() {
-                if is_executable(&item.path()) {
-                    if let Ok(name) = item.file_name().into_string() {
-                        executables.insert(name);
-                    }
-                }
-            }
-        }
-    }
-
-    Some(executables)
-}
@@ -3,6 +3,7 @@ pub(crate) mod engine;
 pub(crate) mod flag;
 pub(crate) mod matchers;
 pub(crate) mod path;
+pub(crate) mod variable;
 
 use matchers::Matcher;
 use nu_engine::EvaluationContext;
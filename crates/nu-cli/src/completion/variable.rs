@@ -0,0 +1,136 @@
+use super::matchers::Matcher;
+use crate::completion::{Completer, CompletionContext, Suggestion};
+use nu_engine::EvaluationContext;
+use nu_protocol::{merge_descriptors, quote_column_name, ColumnPath, UntaggedValue, Value};
+use nu_source::{Span, SpannedItem, Tag};
+use nu_value_ext::ValueExt;
+
+pub struct VariableCompleter;
+
+impl Completer for VariableCompleter {
+    fn complete(
+        &self,
+        ctx: &CompletionContext<'_>,
+        partial: &str,
+        matcher: &dyn Matcher,
+    ) -> Vec<Suggestion> {
+        let context: &EvaluationContext = ctx.as_ref();
+
+        let (head, last) = match split_last_member(partial) {
+            Some(split) => split,
+            None => {
+                // Still typing the name of the variable
+                let mut names: Vec<String> = context.scope.get_vars().keys().cloned().collect();
+                names.extend(vec![
+                    "$nu".to_string(),
+                    "$env".into(),
+                    "$true".into(),
+                    "$false".into(),
+                ]);
+                names.sort();
+                names.dedup();
+
+                return names
+                    .into_iter()
+                    .filter(|name| matcher.matches(partial, name))
+                    .map(|name| Suggestion {
+                        replacement: name.clone(),
+                        display: name,
+                    })
+                    .collect();
+            }
+        };
+
+        let (variable, path) = match split_first_member(head) {
+            Some((variable, path)) => (variable, Some(path)),
+            None => (head, None),
+        };
+
+        let value = match variable {
+            "$nu" => nu_engine::nu_variable(&context.scope, Tag::unknown()).ok(),
+            "$env" => nu_engine::env_variable(&context.scope, Tag::unknown()).ok(),
+            _ => context.scope.get_var(variable),
+        };
+        let value = match (value, path) {
+            (Some(value), None) => value,
+            (Some(value), Some(path)) => {
+                let path = ColumnPath::build(&path.to_string().spanned(Span::unknown()));
+                match value.get_data_by_column_path(&path, Box::new(|_, _, error| error)) {
+                    Ok(value) => value,
+                    Err(_) => return vec![],
+                }
+            }
+            (None, _) => return vec![],
+        };
+
+        // The name may be partly typed inside quotes already, eg `$it."first na`
+        let last = last.trim_start_matches(|c| c == '"' || c == '\'' || c == '`');
+
+        columns(&value)
+            .into_iter()
+            .filter(|column| matcher.matches(last, column))
+            .map(|column| Suggestion {
+                replacement: format!("{}.{}", head, quote_column_name(&column)),
+                display: column,
+            })
+            .collect()
+    }
+}
+
+fn columns(value: &Value) -> Vec<String> {
+    match &value.value {
+        UntaggedValue::Row(dict) => dict.keys().cloned().collect(),
+        UntaggedValue::Table(rows) => merge_descriptors(rows),
+        _ => vec![],
+    }
+}
+
+/// Splits a path like `$it."a.b".c` after its first member, ignoring dots inside quotes
+fn split_first_member(path: &str) -> Option<(&str, &str)> {
+    unquoted_dots(path)
+        .first()
+        .map(|&idx| (&path[..idx], &path[idx + 1..]))
+}
+
+/// Splits a path like `$it."a.b".c` before its last member, ignoring dots inside quotes
+fn split_last_member(path: &str) -> Option<(&str, &str)> {
+    unquoted_dots(path)
+        .last()
+        .map(|&idx| (&path[..idx], &path[idx + 1..]))
+}
+
+fn unquoted_dots(path: &str) -> Vec<usize> {
+    let mut dots = vec![];
+    let mut quote = None;
+
+    for (idx, c) in path.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' || c == '`' => quote = Some(c),
+            None if c == '.' => dots.push(idx),
+            None => {}
+        }
+    }
+
+    dots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_first_member, split_last_member};
+
+    #[test]
+    fn splits_paths_outside_of_quotes() {
+        assert_eq!(split_last_member("$it"), None);
+        assert_eq!(
+            split_last_member("$it.\"a.b\".c"),
+            Some(("$it.\"a.b\"", "c"))
+        );
+        assert_eq!(split_last_member("$it.\"a.b"), Some(("$it", "\"a.b")));
+        assert_eq!(
+            split_first_member("$nu.env.HOME"),
+            Some(("$nu", "env.HOME"))
+        );
+    }
+}
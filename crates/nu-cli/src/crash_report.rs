@@ -0,0 +1,97 @@
+use nu_engine::crash_context::{self, CrashContext};
+use std::fmt::Write as _;
+use std::panic::PanicInfo;
+use std::path::PathBuf;
+
+const ISSUES_URL: &str = "https://github.com/nushell/nushell/issues";
+
+/// Replaces the default panic output with a report written to a file in the user data directory,
+/// so a crash in the middle of a session leaves a short message on the terminal. The default
+/// output is still printed when the report can't be written.
+pub fn install_crash_reporter() {
+    let default_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        let report = crash_report(info, crash_context::current());
+
+        match write_report(&report) {
+            Ok(path) => eprintln!(
+                "\nNu crashed: {}\nA crash report was written to {}\nPlease attach it when reporting the crash at {}",
+                panic_message(info),
+                path.display(),
+                ISSUES_URL
+            ),
+            Err(_) => default_hook(info),
+        }
+    }));
+}
+
+fn panic_message(info: &PanicInfo) -> String {
+    let payload = info.payload();
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    };
+
+    match info.location() {
+        Some(location) => format!("{} at {}", message, location),
+        None => message,
+    }
+}
+
+fn crash_report(info: &PanicInfo, context: Option<CrashContext>) -> String {
+    let mut report = String::new();
+
+    let _ = writeln!(report, "# Nu crash report\n");
+    let _ = writeln!(report, "nu version: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        report,
+        "os: {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    let _ = writeln!(report, "time: {}", chrono::Local::now().to_rfc3339());
+    let _ = writeln!(report, "panic: {}", panic_message(info));
+
+    if let Some(context) = context {
+        if let Some(excerpt) = context.excerpt() {
+            let _ = writeln!(report, "\n## Last command\n\n{}", excerpt);
+        } else if let Some((source, _)) = &context.source {
+            let _ = writeln!(report, "\n## Last command\n\n{}", source);
+        }
+
+        let _ = writeln!(report, "\n## Plugins\n");
+        if context.plugins.is_empty() {
+            let _ = writeln!(report, "(none)");
+        }
+        for (name, path) in &context.plugins {
+            let _ = writeln!(report, "{} ({})", name, path);
+        }
+    }
+
+    let _ = writeln!(
+        report,
+        "\n## Backtrace\n\n{:?}",
+        backtrace::Backtrace::new()
+    );
+
+    report
+}
+
+fn write_report(report: &str) -> std::io::Result<PathBuf> {
+    let mut path = nu_data::config::user_data()
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::NotFound, "no user data directory"))?;
+    path.push("crash-reports");
+    std::fs::create_dir_all(&path)?;
+
+    path.push(format!(
+        "crash-{}.txt",
+        chrono::Local::now().format("%Y-%m-%d-%H%M%S")
+    ));
+    std::fs::write(&path, report)?;
+
+    Ok(path)
+}
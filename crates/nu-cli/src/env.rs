@@ -1,3 +1,4 @@
 pub(crate) mod directory_specific_environment;
 pub(crate) mod environment;
 pub(crate) mod environment_syncer;
+pub(crate) mod envrc;
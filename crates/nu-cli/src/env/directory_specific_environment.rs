@@ -1,3 +1,4 @@
+use crate::env::envrc::{direnv_export, read_envrc, EnvrcMode};
 use indexmap::{IndexMap, IndexSet};
 use nu_command::commands::autoenv;
 use nu_errors::ShellError;
@@ -25,6 +26,9 @@ pub struct DirectorySpecificEnvironment {
     //We track directories that we have read .nu-env from. This is different from the keys in added_vars since sometimes a file only wants to run scripts.
     visited_dirs: IndexSet<PathBuf>,
     exitscripts: IndexMap<PathBuf, Vec<String>>,
+
+    //How .envrc files are loaded, from the autoenv_envrc setting in the config.
+    pub envrc: EnvrcMode,
 }
 
 #[derive(Deserialize, Debug, Default)]
@@ -48,6 +52,7 @@ impl DirectorySpecificEnvironment {
             added_vars: IndexMap::new(),
             visited_dirs: IndexSet::new(),
             exitscripts: IndexMap::new(),
+            envrc: EnvrcMode::Off,
         }
     }
 
@@ -73,6 +78,28 @@ impl DirectorySpecificEnvironment {
                 format!("{:?} is untrusted. Run 'autoenv trust {:?}' to trust it.\nThis needs to be done after each change to the file.", nu_env_file, nu_env_file.parent().unwrap_or_else(|| &Path::new("")))))
     }
 
+    fn envrc_if_trusted(&self, envrc_file: &Path) -> Result<IndexMap<String, String>, ShellError> {
+        let content = std::fs::read(&envrc_file)?;
+
+        if autoenv::file_is_trusted(&envrc_file, &content)? {
+            let dir = envrc_file.parent().unwrap_or_else(|| Path::new(""));
+            return read_envrc(&String::from_utf8_lossy(&content), dir, |key| var(key).ok());
+        }
+        Err(ShellError::untagged_runtime_error(
+                format!("{:?} is untrusted. Run 'autoenv trust --envrc {:?}' to trust it.\nThis needs to be done after each change to the file.", envrc_file, envrc_file.parent().unwrap_or_else(|| &Path::new("")))))
+    }
+
+    //direnv keeps track of what it changed itself, so its changes are applied as they are instead of being added to added_vars.
+    fn maintain_direnv(&self, dir: &Path) -> Result<(), ShellError> {
+        for (key, val) in direnv_export(dir)? {
+            match val {
+                Some(val) => set_var(key, val),
+                None => remove_var(key),
+            }
+        }
+        Ok(())
+    }
+
     pub fn maintain_autoenv(&mut self) -> Result<(), ShellError> {
         let mut dir = current_dir()?;
 
@@ -80,6 +107,10 @@ impl DirectorySpecificEnvironment {
             return Ok(());
         }
 
+        if self.envrc == EnvrcMode::Direnv {
+            self.maintain_direnv(&dir)?;
+        }
+
         //We track which keys we set as we go up the directory hierarchy, so that we don't overwrite a value we set in a subdir.
         let mut added_keys = IndexSet::new();
 
@@ -119,6 +150,17 @@ impl DirectorySpecificEnvironment {
                     self.exitscripts.insert(dir.clone(), es);
                 }
             }
+
+            //Variables in .nu-env take precedence over the ones in an .envrc next to it.
+            let envrc_file = dir.join(".envrc");
+            if self.envrc == EnvrcMode::Read
+                && envrc_file.exists()
+                && !self.visited_dirs.contains(&dir)
+            {
+                for (env_key, env_val) in self.envrc_if_trusted(&envrc_file)? {
+                    self.maybe_add_key(&mut added_keys, &dir, &env_key, &env_val);
+                }
+            }
             new_visited_dirs.insert(dir.clone());
             popped = dir.pop();
         }
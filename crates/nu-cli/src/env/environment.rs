@@ -1,4 +1,5 @@
 use crate::env::directory_specific_environment::*;
+use crate::env::envrc::EnvrcMode;
 use indexmap::{indexmap, IndexSet};
 use nu_data::config::Conf;
 use nu_engine::Env;
@@ -24,10 +25,13 @@ impl Environment {
     pub fn from_config<T: Conf>(configuration: &T) -> Environment {
         let env = configuration.env();
         let path = configuration.path();
+        let mut autoenv = DirectorySpecificEnvironment::new();
+        autoenv.envrc = EnvrcMode::from_config(configuration);
+
         Environment {
             environment_vars: env,
             path_vars: path,
-            autoenv: DirectorySpecificEnvironment::new(),
+            autoenv,
         }
     }
 
@@ -42,6 +46,7 @@ impl Environment {
     pub fn morph<T: Conf>(&mut self, configuration: &T) {
         self.environment_vars = configuration.env();
         self.path_vars = configuration.path();
+        self.autoenv.envrc = EnvrcMode::from_config(configuration);
     }
 }
 
@@ -0,0 +1,317 @@
+use indexmap::IndexMap;
+use nu_data::config::Conf;
+use nu_errors::ShellError;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// How the `.envrc` files that direnv uses are loaded, from the `autoenv_envrc` setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvrcMode {
+    /// They are left alone, which is the default
+    Off,
+    /// The common subset (`export`, `PATH_add` and `path_add`) is read by Nu, from the files
+    /// trusted with `autoenv trust --envrc`
+    Read,
+    /// direnv itself is asked for the variables, with `direnv export json`, so it decides which
+    /// files are allowed and runs them in full
+    Direnv,
+}
+
+impl Default for EnvrcMode {
+    fn default() -> Self {
+        EnvrcMode::Off
+    }
+}
+
+impl EnvrcMode {
+    pub fn from_config<T: Conf>(configuration: &T) -> EnvrcMode {
+        match configuration
+            .var("autoenv_envrc")
+            .and_then(|value| value.as_string().ok())
+            .as_deref()
+        {
+            Some("read") => EnvrcMode::Read,
+            Some("direnv") => EnvrcMode::Direnv,
+            _ => EnvrcMode::Off,
+        }
+    }
+}
+
+/// Reads the variables an `.envrc` in `dir` exports. Values can refer to the variables set before
+/// them in the file, or else to the ones `lookup` finds, as `$NAME` or `${NAME}`. Anything else
+/// than exports and additions to paths needs a shell, so it's an error that points to direnv.
+pub fn read_envrc(
+    contents: &str,
+    dir: &Path,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<IndexMap<String, String>, ShellError> {
+    let mut vars: IndexMap<String, String> = IndexMap::new();
+
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let unsupported = || {
+            ShellError::untagged_runtime_error(format!(
+                "Line {} of {} can't be read without direnv: {}\nSet autoenv_envrc to \"direnv\" in the config to load it with direnv.",
+                number + 1,
+                dir.join(".envrc").display(),
+                line
+            ))
+        };
+        let get = |vars: &IndexMap<String, String>, name: &str| {
+            vars.get(name).cloned().or_else(|| lookup(name))
+        };
+
+        let (command, rest) = match line.find(char::is_whitespace) {
+            Some(at) => (&line[..at], line[at..].trim_start()),
+            None => (line, ""),
+        };
+
+        match command {
+            "export" => {
+                let (name, value) = match rest.find('=') {
+                    Some(at) => (&rest[..at], &rest[at + 1..]),
+                    // Exporting a variable that is already set changes nothing
+                    None if is_name(rest) => continue,
+                    None => return Err(unsupported()),
+                };
+                if !is_name(name) {
+                    return Err(unsupported());
+                }
+
+                let value = words(value, |var| get(&vars, var))
+                    .ok_or_else(unsupported)
+                    .and_then(|words| match words.as_slice() {
+                        [] => Ok(String::new()),
+                        [value] => Ok(value.clone()),
+                        _ => Err(unsupported()),
+                    })?;
+                vars.insert(name.to_string(), value);
+            }
+            "PATH_add" | "path_add" => {
+                let words = words(rest, |var| get(&vars, var)).ok_or_else(unsupported)?;
+                let (name, added) = match (command, words.as_slice()) {
+                    ("PATH_add", [added]) => ("PATH".to_string(), added),
+                    ("path_add", [name, added]) if is_name(name) => (name.clone(), added),
+                    _ => return Err(unsupported()),
+                };
+
+                let added = dir.join(added).to_string_lossy().to_string();
+                let value = match get(&vars, &name) {
+                    Some(current) if !current.is_empty() => {
+                        format!("{}{}{}", added, PATH_SEPARATOR, current)
+                    }
+                    _ => added,
+                };
+                vars.insert(name, value);
+            }
+            _ => return Err(unsupported()),
+        }
+    }
+
+    Ok(vars)
+}
+
+/// Asks direnv for the changes to the environment in `dir`, compared to the environment Nu has.
+/// The variables to unset have no value. direnv's messages, such as asking to `direnv allow` a
+/// file, go to stderr as they do in other shells.
+pub fn direnv_export(dir: &Path) -> Result<IndexMap<String, Option<String>>, ShellError> {
+    let output = Command::new("direnv")
+        .args(&["export", "json"])
+        .current_dir(dir)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| {
+            ShellError::untagged_runtime_error(format!(
+                "Couldn't run direnv for the .envrc files ({}). Is it installed?",
+                err
+            ))
+        })?;
+
+    if !output.status.success() {
+        return Err(ShellError::untagged_runtime_error(
+            "direnv couldn't load the .envrc file",
+        ));
+    }
+
+    // Nothing is printed when nothing changes
+    let output = String::from_utf8_lossy(&output.stdout);
+    if output.trim().is_empty() {
+        return Ok(IndexMap::new());
+    }
+
+    serde_json::from_str(&output).map_err(|err| {
+        ShellError::untagged_runtime_error(format!("Couldn't read direnv's output: {}", err))
+    })
+}
+
+#[cfg(windows)]
+const PATH_SEPARATOR: char = ';';
+#[cfg(not(windows))]
+const PATH_SEPARATOR: char = ':';
+
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Splits the text into words the way a shell does for quotes, variables and comments, or gives
+/// back nothing for what only a shell can do, like `$(...)`
+fn words(text: &str, get: impl Fn(&str) -> Option<String>) -> Option<Vec<String>> {
+    let mut words = vec![];
+    let mut word: Option<String> = None;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if let Some(word) = word.take() {
+                    words.push(word);
+                }
+            }
+            '#' if word.is_none() => break,
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next()? {
+                        '\'' => break,
+                        c => word.push(c),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => match chars.next()? {
+                            c @ '"' | c @ '\\' | c @ '$' | c @ '`' => word.push(c),
+                            c => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                        },
+                        '$' => word.push_str(&variable(&mut chars, &get)?),
+                        '`' => return None,
+                        c => word.push(c),
+                    }
+                }
+            }
+            '\\' => word.get_or_insert_with(String::new).push(chars.next()?),
+            '$' => {
+                let value = variable(&mut chars, &get)?;
+                word.get_or_insert_with(String::new).push_str(&value);
+            }
+            '`' | '(' | ')' | ';' | '&' | '|' | '<' | '>' | '*' | '?' => return None,
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    if let Some(word) = word {
+        words.push(word);
+    }
+
+    Some(words)
+}
+
+/// Reads the name after a `$` and gives back the value of the variable, empty if it's not set
+fn variable(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    get: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    let mut name = String::new();
+
+    if chars.peek() == Some(&'{') {
+        chars.next();
+        loop {
+            match chars.next()? {
+                '}' => break,
+                c if c.is_ascii_alphanumeric() || c == '_' => name.push(c),
+                // Defaults and other expansions are left to a shell
+                _ => return None,
+            }
+        }
+    } else {
+        while let Some(&c) = chars.peek() {
+            if !(c.is_ascii_alphanumeric() || c == '_') {
+                break;
+            }
+            name.push(c);
+            chars.next();
+        }
+    }
+
+    if !is_name(&name) {
+        return None;
+    }
+
+    Some(get(&name).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_envrc, PATH_SEPARATOR};
+    use indexmap::indexmap;
+    use std::path::Path;
+
+    fn home(name: &str) -> Option<String> {
+        match name {
+            "HOME" => Some("/home/nu".to_string()),
+            "PATH" => Some("/usr/bin".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn reads_exports() {
+        let envrc = r#"
+            # the project's settings
+            export DATABASE_URL=postgres://localhost/app
+            export GREETING="hello from $HOME"
+            export LITERAL='no $expansion here'
+            export CACHE=${HOME}/.cache # where the builds go
+            export CONFIG="$CACHE/config"
+        "#;
+
+        let vars = read_envrc(envrc, Path::new("/work/app"), home).expect("the file is read");
+
+        assert_eq!(
+            vars,
+            indexmap! {
+                "DATABASE_URL".to_string() => "postgres://localhost/app".to_string(),
+                "GREETING".to_string() => "hello from /home/nu".to_string(),
+                "LITERAL".to_string() => "no $expansion here".to_string(),
+                "CACHE".to_string() => "/home/nu/.cache".to_string(),
+                "CONFIG".to_string() => "/home/nu/.cache/config".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn adds_directories_to_paths() {
+        let dir = Path::new("/work/app");
+        let envrc = "PATH_add bin\npath_add PYTHONPATH lib";
+
+        let vars = read_envrc(envrc, dir, home).expect("the file is read");
+
+        assert_eq!(
+            vars["PATH"],
+            format!("{}{}/usr/bin", dir.join("bin").display(), PATH_SEPARATOR)
+        );
+        assert_eq!(vars["PYTHONPATH"], dir.join("lib").display().to_string());
+    }
+
+    #[test]
+    fn leaves_what_needs_a_shell_to_direnv() {
+        let dir = Path::new("/work/app");
+
+        assert!(read_envrc("use nix", dir, home).is_err());
+        assert!(read_envrc("export VERSION=$(git describe)", dir, home).is_err());
+        assert!(read_envrc("export NAME=${USER:-nu}", dir, home).is_err());
+    }
+}
@@ -16,12 +16,16 @@ extern crate quickcheck_macros;
 mod cli;
 #[cfg(feature = "rustyline-support")]
 mod completion;
+mod crash_report;
 mod env;
 mod format;
 #[cfg(feature = "rustyline-support")]
 mod keybinding;
 mod line_editor;
+#[cfg(feature = "rustyline-support")]
+mod session;
 mod shell;
+mod startup_report;
 pub mod types;
 
 #[cfg(feature = "rustyline-support")]
@@ -29,6 +33,8 @@ pub use crate::cli::cli;
 
 pub use crate::cli::{parse_and_eval, register_plugins, run_script_file};
 pub use crate::cli::{NuScript, Options};
+pub use crate::crash_report::install_crash_reporter;
+pub use crate::startup_report::StartupReport;
 
 pub use crate::env::environment_syncer::EnvironmentSyncer;
 pub use nu_command::commands::default_context::create_default_context;
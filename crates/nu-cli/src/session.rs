@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Where the REPL is, kept on disk while it runs so a killed terminal can pick up from there. Each
+/// running nu has its own file, removed on a clean exit, so a file whose nu isn't running anymore
+/// means that session crashed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Session {
+    pub pid: u32,
+    pub cwd: String,
+    /// The line that was running, if the session ended in the middle of one
+    pub running: Option<String>,
+    /// Input that was still being typed, like the first lines of an unclosed block
+    #[serde(default)]
+    pub pending: Option<String>,
+}
+
+/// The session files sit in a directory next to the history file
+pub(crate) fn dir(history: &Path) -> PathBuf {
+    history.with_file_name("sessions")
+}
+
+fn path(dir: &Path, pid: u32) -> PathBuf {
+    dir.join(format!("{}.json", pid))
+}
+
+/// Writes to a temporary file first, so a crash while saving can't leave half a session behind
+pub(crate) fn save(dir: &Path, session: &Session) {
+    let path = path(dir, session.pid);
+    let temp = path.with_extension("json.tmp");
+
+    if let Ok(contents) = serde_json::to_string(session) {
+        if std::fs::create_dir_all(dir).is_ok() && std::fs::write(&temp, contents).is_ok() {
+            let _ = std::fs::rename(&temp, path);
+        }
+    }
+}
+
+/// Reads the latest session left behind by a nu that isn't running anymore, if any, and removes
+/// it. The sessions of the nu's still running are left alone.
+pub(crate) fn take_crashed(dir: &Path) -> Option<Session> {
+    let mut crashed = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().map_or(false, |ext| ext == "json"))
+        .filter_map(|entry| {
+            let modified = entry.metadata().and_then(|meta| meta.modified()).ok()?;
+            let contents = std::fs::read_to_string(entry.path()).ok()?;
+            let session: Session = serde_json::from_str(&contents).ok()?;
+
+            Some((modified, session))
+        })
+        .filter(|(_, session)| session.pid != std::process::id() && !is_running(session.pid))
+        .collect::<Vec<_>>();
+
+    crashed.sort_by_key(|(modified, _)| *modified);
+    let (_, session) = crashed.pop()?;
+    clear(dir, session.pid);
+
+    Some(session)
+}
+
+pub(crate) fn clear(dir: &Path, pid: u32) {
+    let _ = std::fs::remove_file(path(dir, pid));
+}
+
+/// Removes the session file when a command like `exit` ends the shell, as that's a clean exit too
+pub(crate) fn clear_on_exit(dir: PathBuf, pid: u32) {
+    nu_engine::exit_hooks::on_exit(move || clear(&dir, pid));
+}
+
+/// Whether the process is still running. A process that can't be signalled, eg because another
+/// user owns it, still counts as running.
+#[cfg(unix)]
+fn is_running(pid: u32) -> bool {
+    use nix::errno::Errno;
+    use nix::sys::signal::kill;
+    use nix::unistd::Pid;
+
+    !matches!(
+        kill(Pid::from_raw(pid as i32), None),
+        Err(nix::Error::Sys(Errno::ESRCH))
+    )
+}
+
+/// Whether the process is still running. When tasklist can't tell, it counts as running, so a
+/// live session is never taken over.
+#[cfg(windows)]
+fn is_running(pid: u32) -> bool {
+    std::process::Command::new("tasklist")
+        .args(&["/FI", &format!("PID eq {}", pid), "/FO", "CSV", "/NH"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains(&format!("\"{}\"", pid)))
+        .unwrap_or(true)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn is_running(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clear_on_exit, dir, save, take_crashed, Session};
+    use std::path::Path;
+
+    /// A pid no process has, as it's above the largest pid Linux and macOS hand out
+    const GONE: u32 = 99_999_999;
+
+    fn session(pid: u32) -> Session {
+        Session {
+            pid,
+            cwd: "/home/nu/projects".to_string(),
+            running: Some("cargo build |\nlines".to_string()),
+            pending: None,
+        }
+    }
+
+    #[test]
+    fn session_files_sit_next_to_history() {
+        assert_eq!(
+            dir(Path::new("/home/nu/history.txt")),
+            Path::new("/home/nu/sessions")
+        );
+    }
+
+    #[test]
+    fn a_crashed_session_is_taken_once() {
+        let tmp = tempfile::tempdir().expect("could not create a temporary directory");
+        let dir = dir(&tmp.path().join("history.txt"));
+        let crashed = Session {
+            pending: Some("each {\n  echo $it".to_string()),
+            ..session(GONE)
+        };
+
+        save(&dir, &crashed);
+
+        assert_eq!(take_crashed(&dir), Some(crashed));
+        assert_eq!(take_crashed(&dir), None);
+    }
+
+    #[test]
+    fn exiting_the_shell_clears_its_session() {
+        let tmp = tempfile::tempdir().expect("could not create a temporary directory");
+        let dir = dir(&tmp.path().join("history.txt"));
+
+        save(
+            &dir,
+            &Session {
+                running: Some("exit".to_string()),
+                ..session(GONE)
+            },
+        );
+        clear_on_exit(dir.clone(), GONE);
+        nu_engine::exit_hooks::run();
+
+        assert_eq!(take_crashed(&dir), None);
+    }
+
+    #[test]
+    fn sessions_still_running_are_left_alone() {
+        let tmp = tempfile::tempdir().expect("could not create a temporary directory");
+        let dir = dir(&tmp.path().join("history.txt"));
+
+        // The session of this process, and of another that's running: the test runner's parent
+        save(&dir, &session(std::process::id()));
+        #[cfg(unix)]
+        save(&dir, &session(std::os::unix::process::parent_id()));
+
+        assert_eq!(take_crashed(&dir), None);
+        assert_eq!(
+            std::fs::read_dir(&dir)
+                .expect("could not read the sessions")
+                .count(),
+            if cfg!(unix) { 2 } else { 1 }
+        );
+    }
+}
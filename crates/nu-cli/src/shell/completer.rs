@@ -3,6 +3,7 @@ use crate::completion::flag::FlagCompleter;
 use crate::completion::matchers;
 use crate::completion::matchers::Matcher;
 use crate::completion::path::{PathCompleter, PathSuggestion};
+use crate::completion::variable::VariableCompleter;
 use crate::completion::{self, Completer, Suggestion};
 use nu_engine::EvaluationContext;
 use nu_parser::ParserScope;
@@ -105,7 +106,10 @@ impl NuCompleter {
                             .collect()
                         }
 
-                        LocationType::Variable => Vec::new(),
+                        LocationType::Variable => {
+                            let variable_completer = VariableCompleter;
+                            variable_completer.complete(context, partial, matcher.to_owned())
+                        }
                     }
                 })
                 .collect();
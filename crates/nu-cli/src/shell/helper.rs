@@ -9,7 +9,6 @@ pub struct Helper {
     hinter: Option<rustyline::hint::HistoryHinter>,
     context: EvaluationContext,
     pub colored_prompt: String,
-    validator: NuValidator,
 }
 
 impl Helper {
@@ -22,7 +21,6 @@ impl Helper {
             hinter,
             context,
             colored_prompt: String::new(),
-            validator: NuValidator {},
         }
     }
 }
@@ -91,45 +89,30 @@ impl rustyline::highlight::Highlighter for Helper {
     }
 }
 
-impl rustyline::validate::Validator for Helper {
-    fn validate(
-        &self,
-        ctx: &mut rustyline::validate::ValidationContext,
-    ) -> rustyline::Result<rustyline::validate::ValidationResult> {
-        self.validator.validate(ctx)
+// Incomplete input is finished after readline returns instead, so the lines after the first
+// can be read with the continuation indicator as their prompt
+impl rustyline::validate::Validator for Helper {}
+
+/// Whether `src` is waiting for more input: an unclosed block or string, or a line ending with
+/// `|` or an operator, so long pipelines can be typed over several lines
+pub(crate) fn is_incomplete(src: &str) -> bool {
+    let (tokens, err) = nu_parser::lex(src, 0);
+    if let Some(err) = err {
+        if let nu_errors::ParseErrorReason::Eof { .. } = err.reason() {
+            return true;
+        }
     }
 
-    fn validate_while_typing(&self) -> bool {
-        self.validator.validate_while_typing()
+    if nu_parser::line_continues(&tokens) {
+        return true;
     }
-}
-
-struct NuValidator {}
-
-impl rustyline::validate::Validator for NuValidator {
-    fn validate(
-        &self,
-        ctx: &mut rustyline::validate::ValidationContext,
-    ) -> rustyline::Result<rustyline::validate::ValidationResult> {
-        let src = ctx.input();
-
-        let (tokens, err) = nu_parser::lex(src, 0);
-        if let Some(err) = err {
-            if let nu_errors::ParseErrorReason::Eof { .. } = err.reason() {
-                return Ok(rustyline::validate::ValidationResult::Incomplete);
-            }
-        }
-
-        let (_, err) = nu_parser::parse_block(tokens);
 
-        if let Some(err) = err {
-            if let nu_errors::ParseErrorReason::Eof { .. } = err.reason() {
-                return Ok(rustyline::validate::ValidationResult::Incomplete);
-            }
-        }
+    let (_, err) = nu_parser::parse_block(tokens);
 
-        Ok(rustyline::validate::ValidationResult::Valid(None))
-    }
+    matches!(
+        err.as_ref().map(|err| err.reason()),
+        Some(nu_errors::ParseErrorReason::Eof { .. })
+    )
 }
 
 #[allow(unused)]
@@ -191,4 +174,19 @@ mod tests {
             "cd \"folder with spaces\\subdirectory\\subsubdirectory\\\""
         );
     }
+
+    #[test]
+    fn unfinished_input_is_incomplete() {
+        assert!(is_incomplete("ls |"));
+        assert!(is_incomplete("echo [1 2"));
+        assert!(is_incomplete("def greet [] {\n  echo hi"));
+        assert!(is_incomplete("echo 'not closed"));
+    }
+
+    #[test]
+    fn finished_input_is_not_incomplete() {
+        assert!(!is_incomplete("ls | get name"));
+        assert!(!is_incomplete("def greet [] {\n  echo hi\n}"));
+        assert!(!is_incomplete(">"));
+    }
 }
@@ -0,0 +1,122 @@
+use std::time::{Duration, Instant};
+
+/// The time each phase of starting the shell took, kept when nu is started with
+/// `--startup-report` and printed before the first line is read.
+#[derive(Debug, Clone)]
+pub struct StartupReport {
+    started: Instant,
+    last: Instant,
+    phases: Vec<(String, Duration)>,
+}
+
+impl Default for StartupReport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StartupReport {
+    pub fn new() -> Self {
+        let now = Instant::now();
+
+        Self {
+            started: now,
+            last: now,
+            phases: vec![],
+        }
+    }
+
+    /// Ends the phase that started when the previous one ended
+    pub fn phase(&mut self, name: impl Into<String>) {
+        let now = Instant::now();
+        self.phases.push((name.into(), now - self.last));
+        self.last = now;
+    }
+
+    /// The phases as a table, with the share of the whole startup each one took
+    pub fn render(&self) -> String {
+        let total = self.last - self.started;
+        let share = |took: Duration| {
+            if total.as_nanos() == 0 {
+                0.0
+            } else {
+                took.as_secs_f64() / total.as_secs_f64() * 100.0
+            }
+        };
+
+        let header = ["phase".to_string(), "time".to_string(), "share".to_string()];
+        let phases: Vec<[String; 3]> = self
+            .phases
+            .iter()
+            .map(|(name, took)| {
+                [
+                    name.clone(),
+                    format!("{:?}", took),
+                    format!("{:.1}%", share(*took)),
+                ]
+            })
+            .collect();
+        let total_row = [
+            "total".to_string(),
+            format!("{:?}", total),
+            "100.0%".to_string(),
+        ];
+
+        let mut widths = [0; 3];
+        for row in phases.iter().chain(vec![&header, &total_row]) {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |row: &[String; 3]| {
+            format!(
+                "{:<w0$} │ {:>w1$} │ {:>w2$}\n",
+                row[0],
+                row[1],
+                row[2],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2]
+            )
+        };
+        let rule = format!(
+            "{}─┼─{}─┼─{}\n",
+            "─".repeat(widths[0]),
+            "─".repeat(widths[1]),
+            "─".repeat(widths[2])
+        );
+
+        let mut table = line(&header);
+        table.push_str(&rule);
+        for row in &phases {
+            table.push_str(&line(row));
+        }
+        table.push_str(&rule);
+        table.push_str(&line(&total_row));
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StartupReport;
+
+    #[test]
+    fn renders_each_phase_and_the_total() {
+        let mut report = StartupReport::new();
+        report.phase("context creation");
+        report.phase("plugin scan");
+
+        let table = report.render();
+        let lines: Vec<_> = table.lines().collect();
+
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("phase "));
+        assert!(lines[2].starts_with("context creation │"));
+        assert!(lines[3].starts_with("plugin scan      │"));
+        assert!(lines[5].starts_with("total            │"));
+        assert!(lines[5].ends_with("100.0%"));
+    }
+}
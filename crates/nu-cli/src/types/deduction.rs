@@ -258,6 +258,12 @@ fn get_result_shape_of(
         }
         Operator::Modulo => SyntaxShape::Number,
         Operator::Pow => SyntaxShape::Number,
+        Operator::BitAnd
+        | Operator::BitOr
+        | Operator::BitXor
+        | Operator::ShiftLeft
+        | Operator::ShiftRight => SyntaxShape::Int,
+        Operator::Coalesce => r_shape,
     }
 }
 
@@ -799,15 +805,18 @@ impl VarSyntaxShapeDeductor {
                         | Expression::Invocation(_)
                         | Expression::Boolean(_)
                         | Expression::Garbage => {
-                            unreachable!("Parser should have rejected code. In only applicable with rhs of type List")
+                            //The rhs may be a record or a string as well, which says
+                            //nothing certain about the lhs
                         }
                     },
                     BinarySide::Right => {
+                        //Lists, records (by column name) and strings (by substring)
+                        //all support membership, and records have no shape of their own
                         self.checked_insert(
                             var,
                             VarShapeDeduction::from_usage_with_alternatives(
                                 &var.span,
-                                &[SyntaxShape::Table],
+                                &[SyntaxShape::Table, SyntaxShape::String, SyntaxShape::Any],
                             ),
                         )?;
                     }
@@ -821,6 +830,21 @@ impl VarSyntaxShapeDeductor {
                         ),
                     )?;
                 }
+                Operator::BitAnd
+                | Operator::BitOr
+                | Operator::BitXor
+                | Operator::ShiftLeft
+                | Operator::ShiftRight => {
+                    self.checked_insert(
+                        var,
+                        VarShapeDeduction::from_usage_with_alternatives(
+                            &var.span,
+                            &[SyntaxShape::Int],
+                        ),
+                    )?;
+                }
+                // The variable is allowed to be missing, so it can have any shape
+                Operator::Coalesce => {}
                 Operator::Equal
                 | Operator::NotEqual
                 | Operator::LessThan
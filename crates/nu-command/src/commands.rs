@@ -1,299 +1,759 @@
 #[macro_use]
 pub(crate) mod macros;
 
+#[cfg(feature = "formats")]
 mod from_delimited_data;
+#[cfg(feature = "core")]
+mod set_operations;
+#[cfg(feature = "formats")]
 mod to_delimited_data;
 
+#[cfg(feature = "core")]
 pub(crate) mod ansi;
+#[cfg(feature = "core")]
 pub(crate) mod append;
+#[cfg(feature = "core")]
 pub(crate) mod args;
+#[cfg(feature = "core")]
+pub(crate) mod assert;
 pub mod autoenv;
+#[cfg(feature = "filesystem")]
 pub(crate) mod autoenv_trust;
+#[cfg(feature = "filesystem")]
 pub(crate) mod autoenv_untrust;
+#[cfg(feature = "core")]
 pub(crate) mod autoview;
+#[cfg(feature = "extra")]
 pub(crate) mod benchmark;
+#[cfg(feature = "core")]
 pub(crate) mod build_string;
+#[cfg(feature = "extra")]
 pub(crate) mod cal;
+#[cfg(feature = "core")]
 pub(crate) mod cd;
+#[cfg(feature = "core")]
 pub(crate) mod char_;
+#[cfg(feature = "extra")]
 pub(crate) mod chart;
+#[cfg(feature = "core")]
+pub(crate) mod chunks;
 pub(crate) mod classified;
 #[cfg(feature = "clipboard-cli")]
 pub(crate) mod clip;
+#[cfg(feature = "core")]
+pub(crate) mod columns;
+#[cfg(feature = "core")]
 pub(crate) mod compact;
+#[cfg(feature = "core")]
+pub(crate) mod complete;
+#[cfg(feature = "core")]
 pub(crate) mod config;
+#[cfg(feature = "filesystem")]
 pub(crate) mod constants;
+#[cfg(feature = "filesystem")]
 pub(crate) mod cp;
+#[cfg(feature = "dataframe")]
+pub(crate) mod dataframe;
+#[cfg(feature = "core")]
 pub(crate) mod date;
+#[cfg(feature = "database")]
+pub(crate) mod db;
+#[cfg(feature = "core")]
 pub(crate) mod debug;
+#[cfg(feature = "extra")]
+pub(crate) mod debug_profile;
+#[cfg(feature = "extra")]
+pub(crate) mod debug_run;
+#[cfg(feature = "core")]
 pub(crate) mod def;
+#[cfg(feature = "core")]
 pub(crate) mod default;
 pub mod default_context;
+#[cfg(feature = "core")]
 pub(crate) mod describe;
+#[cfg(feature = "core")]
+pub(crate) mod difference;
+#[cfg(feature = "core")]
+pub(crate) mod dirs;
+#[cfg(feature = "core")]
 pub(crate) mod do_;
+#[cfg(feature = "core")]
 pub(crate) mod drop;
+#[cfg(feature = "filesystem")]
 pub(crate) mod du;
+#[cfg(feature = "core")]
 pub(crate) mod each;
+#[cfg(feature = "core")]
 pub(crate) mod echo;
+#[cfg(feature = "core")]
 pub(crate) mod empty;
+#[cfg(feature = "filesystem")]
 pub(crate) mod enter;
+#[cfg(feature = "core")]
+pub(crate) mod env;
+#[cfg(feature = "core")]
+pub(crate) mod env_restore;
+#[cfg(feature = "core")]
+pub(crate) mod env_save;
+#[cfg(feature = "core")]
 pub(crate) mod every;
+#[cfg(feature = "core")]
 pub(crate) mod exec;
+#[cfg(feature = "core")]
 pub(crate) mod exit;
+#[cfg(feature = "core")]
+pub(crate) mod export_env;
+#[cfg(feature = "formats")]
+pub(crate) mod extra_formats;
+#[cfg(feature = "core")]
 pub(crate) mod first;
+#[cfg(feature = "core")]
 pub(crate) mod flatten;
+#[cfg(feature = "core")]
 pub(crate) mod format;
+#[cfg(feature = "formats")]
 pub(crate) mod from;
+#[cfg(feature = "formats")]
 pub(crate) mod from_csv;
+#[cfg(feature = "formats")]
 pub(crate) mod from_eml;
+#[cfg(feature = "formats")]
+pub(crate) mod from_fixed_width;
+#[cfg(feature = "formats")]
 pub(crate) mod from_ics;
+#[cfg(feature = "formats")]
 pub(crate) mod from_ini;
+#[cfg(feature = "formats")]
 pub(crate) mod from_json;
+#[cfg(feature = "formats")]
+pub(crate) mod from_logfmt;
+#[cfg(feature = "formats")]
 pub(crate) mod from_ods;
+#[cfg(feature = "formats")]
+pub(crate) mod from_prometheus;
+#[cfg(feature = "formats")]
 pub(crate) mod from_ssv;
+#[cfg(feature = "formats")]
 pub(crate) mod from_toml;
+#[cfg(feature = "formats")]
 pub(crate) mod from_tsv;
+#[cfg(feature = "formats")]
 pub(crate) mod from_url;
+#[cfg(feature = "formats")]
 pub(crate) mod from_vcf;
+#[cfg(feature = "formats")]
 pub(crate) mod from_xlsx;
+#[cfg(feature = "formats")]
 pub(crate) mod from_xml;
+#[cfg(feature = "formats")]
 pub(crate) mod from_yaml;
+#[cfg(feature = "extra")]
+pub(crate) mod generate;
+#[cfg(feature = "core")]
 pub(crate) mod get;
+#[cfg(feature = "filesystem")]
+pub(crate) mod glob_;
+#[cfg(feature = "core")]
 pub(crate) mod group_by;
+#[cfg(feature = "extra")]
 pub(crate) mod group_by_date;
+#[cfg(feature = "extra")]
 pub(crate) mod hash_;
+#[cfg(feature = "core")]
 pub(crate) mod headers;
+#[cfg(feature = "core")]
 pub(crate) mod help;
+#[cfg(feature = "extra")]
 pub(crate) mod histogram;
+#[cfg(feature = "core")]
 pub(crate) mod history;
+#[cfg(feature = "core")]
+pub(crate) mod history_stats;
+#[cfg(feature = "core")]
 pub(crate) mod if_;
+#[cfg(feature = "core")]
 pub(crate) mod insert;
+#[cfg(feature = "core")]
+pub(crate) mod intersect;
+#[cfg(feature = "core")]
+pub(crate) mod into_glob;
+#[cfg(feature = "core")]
 pub(crate) mod into_int;
+#[cfg(feature = "sqlite")]
+pub(crate) mod into_sqlite;
+#[cfg(feature = "core")]
+pub(crate) mod is_empty;
+#[cfg(feature = "extra")]
+pub(crate) mod job;
+#[cfg(feature = "core")]
+pub(crate) mod join;
+#[cfg(feature = "core")]
 pub(crate) mod keep;
+#[cfg(feature = "core")]
 pub(crate) mod last;
+#[cfg(feature = "core")]
 pub(crate) mod length;
+#[cfg(feature = "core")]
 pub(crate) mod let_;
+#[cfg(feature = "core")]
 pub(crate) mod let_env;
+#[cfg(feature = "core")]
 pub(crate) mod lines;
+#[cfg(feature = "core")]
+pub(crate) mod load_env;
+#[cfg(feature = "filesystem")]
 pub(crate) mod ls;
+#[cfg(feature = "core")]
 pub(crate) mod math;
+#[cfg(feature = "core")]
 pub(crate) mod merge;
+#[cfg(feature = "core")]
+pub(crate) mod metadata;
+#[cfg(feature = "filesystem")]
 pub(crate) mod mkdir;
+#[cfg(feature = "core")]
 pub(crate) mod move_;
+#[cfg(feature = "mqtt")]
+pub(crate) mod mqtt;
+#[cfg(feature = "nats")]
+pub(crate) mod nats_;
+#[cfg(feature = "core")]
 pub(crate) mod next;
+#[cfg(feature = "core")]
 pub(crate) mod nth;
+#[cfg(feature = "core")]
 pub(crate) mod nu;
+#[cfg(feature = "filesystem")]
 pub(crate) mod open;
+#[cfg(feature = "extra")]
+pub(crate) mod par_each;
+#[cfg(feature = "core")]
 pub(crate) mod parse;
+#[cfg(feature = "filesystem")]
 pub(crate) mod path;
+#[cfg(feature = "core")]
 pub(crate) mod pivot;
+#[cfg(feature = "core")]
+pub(crate) mod plugin;
+#[cfg(feature = "core")]
+pub(crate) mod popd;
+#[cfg(feature = "core")]
 pub(crate) mod prepend;
+#[cfg(feature = "core")]
 pub(crate) mod prev;
+#[cfg(feature = "core")]
+pub(crate) mod pushd;
+#[cfg(feature = "core")]
 pub(crate) mod pwd;
+#[cfg(feature = "sqlite")]
+pub(crate) mod query_sql;
+#[cfg(feature = "extra")]
 pub(crate) mod random;
+#[cfg(feature = "core")]
 pub(crate) mod range;
+#[cfg(feature = "redis")]
+pub(crate) mod redis_;
+#[cfg(feature = "core")]
 pub(crate) mod reduce;
+#[cfg(feature = "core")]
 pub(crate) mod reject;
+#[cfg(feature = "core")]
 pub(crate) mod rename;
+#[cfg(feature = "core")]
 pub(crate) mod reverse;
+#[cfg(feature = "filesystem")]
 pub(crate) mod rm;
+#[cfg(feature = "extra")]
 pub(crate) mod roll;
+#[cfg(feature = "extra")]
 pub(crate) mod rotate;
 pub(crate) mod run_external;
+#[cfg(feature = "filesystem")]
 pub(crate) mod save;
+#[cfg(feature = "core")]
 pub(crate) mod select;
+#[cfg(feature = "extra")]
 pub(crate) mod seq;
+#[cfg(feature = "extra")]
 pub(crate) mod seq_dates;
+#[cfg(feature = "core")]
 pub(crate) mod shells;
+#[cfg(feature = "extra")]
 pub(crate) mod shuffle;
+#[cfg(feature = "core")]
 pub(crate) mod size;
+#[cfg(feature = "core")]
 pub(crate) mod skip;
+#[cfg(feature = "core")]
 pub(crate) mod sleep;
+#[cfg(feature = "core")]
 pub(crate) mod sort_by;
+#[cfg(feature = "core")]
 pub(crate) mod source;
+#[cfg(feature = "core")]
 pub(crate) mod split;
+#[cfg(feature = "core")]
 pub(crate) mod split_by;
+#[cfg(feature = "core")]
 pub(crate) mod str_;
+#[cfg(feature = "core")]
 pub(crate) mod table;
+#[cfg(feature = "core")]
 pub(crate) mod tags;
+#[cfg(feature = "extra")]
 pub(crate) mod termsize;
+#[cfg(feature = "extra")]
+pub(crate) mod test_run;
+#[cfg(feature = "core")]
+pub(crate) mod timeout;
+#[cfg(feature = "formats")]
 pub(crate) mod to;
+#[cfg(feature = "formats")]
 pub(crate) mod to_csv;
+#[cfg(feature = "formats")]
+pub(crate) mod to_fixed_width;
+#[cfg(feature = "formats")]
 pub(crate) mod to_html;
+#[cfg(feature = "formats")]
 pub(crate) mod to_json;
+#[cfg(feature = "formats")]
+pub(crate) mod to_logfmt;
+#[cfg(feature = "formats")]
 pub(crate) mod to_md;
+#[cfg(feature = "formats")]
+pub(crate) mod to_prometheus;
+#[cfg(feature = "formats")]
+pub(crate) mod to_sql;
+#[cfg(feature = "formats")]
 pub(crate) mod to_toml;
+#[cfg(feature = "formats")]
 pub(crate) mod to_tsv;
+#[cfg(feature = "formats")]
 pub(crate) mod to_url;
+#[cfg(feature = "formats")]
 pub(crate) mod to_xml;
+#[cfg(feature = "formats")]
 pub(crate) mod to_yaml;
+#[cfg(feature = "core")]
+pub(crate) mod transpose;
+#[cfg(feature = "core")]
+pub(crate) mod union;
+#[cfg(feature = "core")]
 pub(crate) mod uniq;
+#[cfg(feature = "core")]
+pub(crate) mod uniq_by;
+#[cfg(feature = "core")]
 pub(crate) mod update;
+#[cfg(feature = "core")]
+pub(crate) mod upsert;
+#[cfg(feature = "network")]
 pub(crate) mod url_;
+#[cfg(feature = "core")]
+pub(crate) mod values;
+#[cfg(feature = "core")]
 pub(crate) mod version;
+#[cfg(feature = "filesystem")]
+pub(crate) mod watch;
+#[cfg(feature = "core")]
 pub(crate) mod where_;
+#[cfg(feature = "core")]
 pub(crate) mod which_;
+#[cfg(feature = "core")]
+pub(crate) mod window;
+#[cfg(feature = "core")]
 pub(crate) mod with_env;
+#[cfg(feature = "core")]
 pub(crate) mod wrap;
+#[cfg(feature = "core")]
+pub(crate) mod z;
+#[cfg(feature = "extra")]
+pub(crate) mod zip_with;
 
+#[cfg(feature = "core")]
 pub(crate) use autoview::Autoview;
+#[cfg(feature = "core")]
 pub(crate) use cd::Cd;
 
+#[cfg(feature = "core")]
 pub(crate) use ansi::Ansi;
+#[cfg(feature = "core")]
 pub(crate) use ansi::AnsiStrip;
+#[cfg(feature = "core")]
 pub(crate) use append::Command as Append;
+#[cfg(feature = "core")]
+pub(crate) use assert::{Assert, AssertEqual, AssertError, AssertNotEqual};
+#[cfg(feature = "filesystem")]
 pub(crate) use autoenv::Autoenv;
+#[cfg(feature = "filesystem")]
 pub(crate) use autoenv_trust::AutoenvTrust;
+#[cfg(feature = "filesystem")]
 pub(crate) use autoenv_untrust::AutoenvUnTrust;
+#[cfg(feature = "extra")]
 pub(crate) use benchmark::Benchmark;
+#[cfg(feature = "core")]
 pub(crate) use build_string::BuildString;
+#[cfg(feature = "extra")]
 pub(crate) use cal::Cal;
+#[cfg(feature = "core")]
 pub(crate) use char_::Char;
+#[cfg(feature = "extra")]
 pub(crate) use chart::Chart;
+#[cfg(feature = "core")]
+pub(crate) use chunks::Chunks;
+#[cfg(feature = "core")]
+pub(crate) use columns::Columns;
+#[cfg(feature = "core")]
 pub(crate) use compact::Compact;
+#[cfg(feature = "core")]
+pub(crate) use complete::Complete;
+#[cfg(feature = "core")]
 pub(crate) use config::{
-    Config, ConfigClear, ConfigGet, ConfigPath, ConfigRemove, ConfigSet, ConfigSetInto,
+    Config, ConfigClear, ConfigEdit, ConfigGet, ConfigPath, ConfigRemove, ConfigSet, ConfigSetInto,
 };
+#[cfg(feature = "filesystem")]
 pub(crate) use cp::Cpy;
-pub(crate) use date::{Date, DateFormat, DateListTimeZone, DateNow, DateToTable, DateToTimeZone};
+#[cfg(feature = "core")]
+pub(crate) use date::{
+    Date, DateFormat, DateHumanize, DateListTimeZone, DateNow, DateToRecord, DateToTable,
+    DateToTimeZone,
+};
+#[cfg(feature = "core")]
 pub(crate) use debug::Debug;
+#[cfg(feature = "extra")]
+pub(crate) use debug_profile::DebugProfile;
+#[cfg(feature = "extra")]
+pub(crate) use debug_run::DebugRun;
+#[cfg(feature = "core")]
 pub(crate) use def::Def;
+#[cfg(feature = "core")]
 pub(crate) use default::Default;
+#[cfg(feature = "core")]
 pub(crate) use describe::Describe;
+#[cfg(feature = "core")]
+pub(crate) use difference::Difference;
+#[cfg(feature = "core")]
+pub(crate) use dirs::Dirs;
+#[cfg(feature = "core")]
 pub(crate) use do_::Do;
+#[cfg(feature = "core")]
 pub(crate) use drop::{Drop, DropColumn};
+#[cfg(feature = "filesystem")]
 pub(crate) use du::Du;
+#[cfg(feature = "core")]
 pub(crate) use each::Each;
+#[cfg(feature = "core")]
 pub(crate) use each::EachGroup;
+#[cfg(feature = "core")]
 pub(crate) use each::EachWindow;
+#[cfg(feature = "core")]
 pub(crate) use echo::Echo;
+#[cfg(feature = "core")]
 pub(crate) use empty::Command as Empty;
+#[cfg(feature = "core")]
 pub(crate) use if_::If;
+#[cfg(feature = "core")]
 pub(crate) use nu::NuPlugin;
+#[cfg(feature = "core")]
 pub(crate) use update::Command as Update;
+#[cfg(feature = "core")]
+pub(crate) use upsert::Command as Upsert;
+#[cfg(feature = "extra")]
 pub(crate) mod kill;
+#[cfg(feature = "extra")]
 pub(crate) use kill::Kill;
+#[cfg(feature = "core")]
 pub(crate) mod clear;
+#[cfg(feature = "core")]
 pub(crate) use clear::Clear;
+#[cfg(feature = "filesystem")]
 pub(crate) mod touch;
+#[cfg(feature = "filesystem")]
 pub(crate) use enter::Enter;
+#[cfg(feature = "core")]
+pub(crate) use env::Env;
+#[cfg(feature = "core")]
+pub(crate) use env_restore::EnvRestore;
+#[cfg(feature = "core")]
+pub(crate) use env_save::EnvSave;
+#[cfg(feature = "core")]
 pub(crate) use every::Every;
+#[cfg(feature = "core")]
 pub(crate) use exec::Exec;
+#[cfg(feature = "core")]
 pub(crate) use exit::Exit;
+#[cfg(feature = "core")]
+pub(crate) use export_env::ExportEnv;
+#[cfg(feature = "core")]
 pub(crate) use first::First;
+#[cfg(feature = "core")]
 pub(crate) use flatten::Command as Flatten;
+#[cfg(feature = "core")]
 pub(crate) use format::{FileSize, Format};
+#[cfg(feature = "formats")]
 pub(crate) use from::From;
+#[cfg(feature = "formats")]
 pub(crate) use from_csv::FromCsv;
+#[cfg(feature = "formats")]
 pub(crate) use from_eml::FromEml;
+#[cfg(feature = "formats")]
+pub(crate) use from_fixed_width::FromFixedWidth;
+#[cfg(feature = "formats")]
 pub(crate) use from_ics::FromIcs;
+#[cfg(feature = "formats")]
 pub(crate) use from_ini::FromIni;
+#[cfg(feature = "formats")]
 pub(crate) use from_json::FromJson;
+#[cfg(feature = "formats")]
+pub(crate) use from_logfmt::FromLogfmt;
+#[cfg(feature = "formats")]
 pub(crate) use from_ods::FromOds;
+#[cfg(feature = "formats")]
+pub(crate) use from_prometheus::FromPrometheus;
+#[cfg(feature = "formats")]
 pub(crate) use from_ssv::FromSsv;
+#[cfg(feature = "formats")]
 pub(crate) use from_toml::FromToml;
+#[cfg(feature = "formats")]
 pub(crate) use from_tsv::FromTsv;
+#[cfg(feature = "formats")]
 pub(crate) use from_url::FromUrl;
+#[cfg(feature = "formats")]
 pub(crate) use from_vcf::FromVcf;
+#[cfg(feature = "formats")]
 pub(crate) use from_xlsx::FromXlsx;
+#[cfg(feature = "formats")]
 pub(crate) use from_xml::FromXml;
+#[cfg(feature = "formats")]
 pub(crate) use from_yaml::FromYaml;
+#[cfg(feature = "formats")]
 pub(crate) use from_yaml::FromYml;
+#[cfg(feature = "extra")]
+pub(crate) use generate::Generate;
+#[cfg(feature = "core")]
 pub(crate) use get::Command as Get;
+#[cfg(feature = "filesystem")]
+pub(crate) use glob_::Glob;
+#[cfg(feature = "core")]
 pub(crate) use group_by::Command as GroupBy;
+#[cfg(feature = "extra")]
 pub(crate) use group_by_date::GroupByDate;
+#[cfg(feature = "extra")]
 pub(crate) use hash_::{Hash, HashBase64, HashMd5};
+#[cfg(feature = "core")]
 pub(crate) use headers::Headers;
+#[cfg(feature = "core")]
 pub(crate) use help::Help;
+#[cfg(feature = "extra")]
 pub(crate) use histogram::Histogram;
+#[cfg(feature = "core")]
 pub(crate) use history::History;
+#[cfg(feature = "core")]
+pub(crate) use history_stats::HistoryStats;
+#[cfg(feature = "core")]
 pub(crate) use insert::Command as Insert;
+#[cfg(feature = "core")]
+pub(crate) use intersect::Intersect;
+#[cfg(feature = "core")]
+pub(crate) use into_glob::IntoGlob;
+#[cfg(feature = "core")]
 pub(crate) use into_int::IntoInt;
+#[cfg(feature = "core")]
+pub(crate) use is_empty::IsEmpty;
+#[cfg(feature = "extra")]
+pub(crate) use job::{Job, JobDisown, JobList, JobSpawn};
+#[cfg(feature = "core")]
+pub(crate) use join::Join;
+#[cfg(feature = "core")]
 pub(crate) use keep::{Keep, KeepUntil, KeepWhile};
+#[cfg(feature = "core")]
 pub(crate) use last::Last;
+#[cfg(feature = "core")]
 pub(crate) use length::Length;
+#[cfg(feature = "core")]
 pub(crate) use let_::Let;
+#[cfg(feature = "core")]
 pub(crate) use let_env::LetEnv;
+#[cfg(feature = "core")]
 pub(crate) use lines::Lines;
+#[cfg(feature = "core")]
+pub(crate) use load_env::LoadEnv;
+#[cfg(feature = "filesystem")]
 pub(crate) use ls::Ls;
+#[cfg(feature = "core")]
 pub(crate) use math::{
     Math, MathAbs, MathAverage, MathCeil, MathEval, MathFloor, MathMaximum, MathMedian,
-    MathMinimum, MathMode, MathProduct, MathRound, MathStddev, MathSummation, MathVariance,
+    MathMinimum, MathMode, MathPercentile, MathProduct, MathRound, MathStddev, MathSummation,
+    MathVariance,
 };
+#[cfg(feature = "core")]
 pub(crate) use merge::Merge;
+#[cfg(feature = "core")]
+pub(crate) use metadata::Metadata;
+#[cfg(feature = "filesystem")]
 pub(crate) use mkdir::Mkdir;
+#[cfg(feature = "core")]
 pub(crate) use move_::{Move, Mv};
+#[cfg(feature = "core")]
 pub(crate) use next::Next;
+#[cfg(feature = "core")]
 pub(crate) use nth::Nth;
+#[cfg(feature = "filesystem")]
 pub(crate) use open::Open;
+#[cfg(feature = "extra")]
+pub(crate) use par_each::ParEach;
+#[cfg(feature = "core")]
 pub(crate) use parse::Parse;
+#[cfg(feature = "filesystem")]
 pub(crate) use path::{
     PathBasename, PathCommand, PathDirname, PathExists, PathExpand, PathExtension, PathFilestem,
     PathJoin, PathType,
 };
+#[cfg(feature = "core")]
 pub(crate) use pivot::Pivot;
+#[cfg(feature = "core")]
+pub(crate) use plugin::{Plugin, PluginInstall, PluginList, PluginUninstall, PluginUpdate};
+#[cfg(feature = "core")]
+pub(crate) use popd::Popd;
+#[cfg(feature = "core")]
 pub(crate) use prepend::Prepend;
+#[cfg(feature = "core")]
 pub(crate) use prev::Previous;
+#[cfg(feature = "core")]
+pub(crate) use pushd::Pushd;
+#[cfg(feature = "core")]
 pub(crate) use pwd::Pwd;
-#[cfg(feature = "uuid_crate")]
+#[cfg(all(feature = "extra", feature = "uuid_crate"))]
 pub(crate) use random::RandomUUID;
+#[cfg(feature = "extra")]
 pub(crate) use random::{
     Random, RandomBool, RandomChars, RandomDecimal, RandomDice, RandomInteger,
 };
+#[cfg(feature = "core")]
 pub(crate) use range::Range;
+#[cfg(feature = "core")]
 pub(crate) use reduce::Reduce;
+#[cfg(feature = "core")]
 pub(crate) use reject::Reject;
+#[cfg(feature = "core")]
 pub(crate) use rename::Rename;
+#[cfg(feature = "core")]
 pub(crate) use reverse::Reverse;
+#[cfg(feature = "filesystem")]
 pub(crate) use rm::Remove;
+#[cfg(feature = "extra")]
 pub(crate) use roll::{Roll, RollColumn, RollUp};
+#[cfg(feature = "extra")]
 pub(crate) use rotate::{Rotate, RotateCounterClockwise};
 pub(crate) use run_external::RunExternalCommand;
+#[cfg(feature = "filesystem")]
 pub(crate) use save::Save;
+#[cfg(feature = "core")]
 pub(crate) use select::Command as Select;
+#[cfg(feature = "extra")]
 pub(crate) use seq::Seq;
+#[cfg(feature = "extra")]
 pub(crate) use seq_dates::SeqDates;
+#[cfg(feature = "core")]
 pub(crate) use shells::Shells;
+#[cfg(feature = "extra")]
 pub(crate) use shuffle::Shuffle;
+#[cfg(feature = "core")]
 pub(crate) use size::Size;
+#[cfg(feature = "core")]
 pub(crate) use skip::{Skip, SkipUntil, SkipWhile};
+#[cfg(feature = "core")]
 pub(crate) use sleep::Sleep;
+#[cfg(feature = "core")]
 pub(crate) use sort_by::SortBy;
+#[cfg(feature = "core")]
 pub(crate) use source::Source;
+#[cfg(feature = "core")]
 pub(crate) use split::{Split, SplitChars, SplitColumn, SplitRow};
+#[cfg(feature = "core")]
 pub(crate) use split_by::SplitBy;
+#[cfg(feature = "core")]
 pub(crate) use str_::{
-    Str, StrCamelCase, StrCapitalize, StrCollect, StrContains, StrDowncase, StrEndsWith,
-    StrFindReplace, StrFrom, StrIndexOf, StrKebabCase, StrLPad, StrLength, StrPascalCase, StrRPad,
-    StrReverse, StrScreamingSnakeCase, StrSnakeCase, StrStartsWith, StrSubstring, StrToDatetime,
-    StrToDecimal, StrToInteger, StrTrim, StrTrimLeft, StrTrimRight, StrUpcase,
+    Str, StrCamelCase, StrCapitalize, StrCollect, StrContains, StrDistance, StrDowncase,
+    StrEndsWith, StrFindReplace, StrFrom, StrIndexOf, StrKebabCase, StrLPad, StrLength,
+    StrPascalCase, StrRPad, StrReverse, StrScreamingSnakeCase, StrSnakeCase, StrStartsWith,
+    StrSubstring, StrTitleCase, StrToDatetime, StrToDecimal, StrToInteger, StrTrim, StrTrimLeft,
+    StrTrimRight, StrUpcase,
 };
+#[cfg(feature = "core")]
 pub(crate) use table::Table;
+#[cfg(feature = "core")]
 pub(crate) use tags::Tags;
+#[cfg(feature = "extra")]
 pub(crate) use termsize::TermSize;
+#[cfg(feature = "extra")]
+pub(crate) use test_run::TestRun;
+#[cfg(feature = "core")]
+pub(crate) use timeout::Timeout;
+#[cfg(feature = "formats")]
 pub(crate) use to::To;
+#[cfg(feature = "formats")]
 pub(crate) use to_csv::ToCsv;
+#[cfg(feature = "formats")]
+pub(crate) use to_fixed_width::ToFixedWidth;
+#[cfg(feature = "formats")]
 pub(crate) use to_html::ToHtml;
+#[cfg(feature = "formats")]
 pub(crate) use to_json::ToJson;
+#[cfg(feature = "formats")]
+pub(crate) use to_logfmt::ToLogfmt;
+#[cfg(feature = "formats")]
 pub(crate) use to_md::Command as ToMarkdown;
+#[cfg(feature = "formats")]
+pub(crate) use to_prometheus::ToPrometheus;
+#[cfg(feature = "formats")]
+pub(crate) use to_sql::ToSql;
+#[cfg(feature = "formats")]
 pub(crate) use to_toml::ToToml;
+#[cfg(feature = "formats")]
 pub(crate) use to_tsv::ToTsv;
+#[cfg(feature = "formats")]
 pub(crate) use to_url::ToUrl;
+#[cfg(feature = "formats")]
 pub(crate) use to_xml::ToXml;
+#[cfg(feature = "formats")]
 pub(crate) use to_yaml::ToYaml;
+#[cfg(feature = "filesystem")]
 pub(crate) use touch::Touch;
+#[cfg(feature = "core")]
+pub(crate) use transpose::Transpose;
+#[cfg(feature = "core")]
+pub(crate) use union::Union;
+#[cfg(feature = "core")]
 pub(crate) use uniq::Uniq;
+#[cfg(feature = "core")]
+pub(crate) use uniq_by::UniqBy;
+#[cfg(feature = "network")]
 pub(crate) use url_::{UrlCommand, UrlHost, UrlPath, UrlQuery, UrlScheme};
+#[cfg(feature = "core")]
+pub(crate) use values::Values;
+#[cfg(feature = "core")]
 pub(crate) use version::Version;
+#[cfg(feature = "filesystem")]
+pub(crate) use watch::Watch;
+#[cfg(feature = "core")]
 pub(crate) use where_::Where;
+#[cfg(feature = "core")]
 pub(crate) use which_::Which;
+#[cfg(feature = "core")]
+pub(crate) use window::Window;
+#[cfg(feature = "core")]
 pub(crate) use with_env::WithEnv;
+#[cfg(feature = "core")]
 pub(crate) use wrap::Wrap;
+#[cfg(feature = "core")]
+pub(crate) use z::Z;
+#[cfg(feature = "extra")]
+pub(crate) use zip_with::ZipWith;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "core"))]
 mod tests {
     use super::*;
-    use crate::examples::{test_anchors, test_examples};
+    use crate::examples::{test_anchors, test_custom_examples, test_examples};
     use nu_engine::{whole_stream_command, Command};
     use nu_errors::ShellError;
 
@@ -304,6 +764,7 @@ mod tests {
             whole_stream_command(Insert),
             whole_stream_command(Move),
             whole_stream_command(Update),
+            whole_stream_command(Upsert),
             whole_stream_command(Empty),
             // whole_stream_command(Select),
             // whole_stream_command(Get),
@@ -335,6 +796,9 @@ mod tests {
             whole_stream_command(StrKebabCase),
             whole_stream_command(StrSnakeCase),
             whole_stream_command(StrScreamingSnakeCase),
+            whole_stream_command(StrTitleCase),
+            whole_stream_command(StrDistance),
+            #[cfg(feature = "formats")]
             whole_stream_command(ToMarkdown),
         ]
     }
@@ -354,6 +818,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn custom_command_examples_work_as_expected() -> Result<(), ShellError> {
+        test_custom_examples(
+            r#"
+# Doubles a number
+#
+# Example: Double two
+# > double 2
+# => 4
+def double [n] { = $n * 2 }
+"#,
+        )
+    }
+
     #[test]
     fn tracks_metadata() -> Result<(), ShellError> {
         for cmd in full_tests() {
@@ -0,0 +1,145 @@
+use super::failure;
+use crate::prelude::*;
+use nu_engine::evaluate_baseline_expr;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::hir::{CapturedBlock, ClassifiedCommand, Expression, SpannedExpression};
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::{PrettyDebug, Tagged};
+
+pub struct Command;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    condition: CapturedBlock,
+    message: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "assert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert")
+            .required(
+                "condition",
+                SyntaxShape::MathExpression,
+                "the condition that must hold",
+            )
+            .optional(
+                "message",
+                SyntaxShape::String,
+                "what the assertion checks, shown when it fails",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Fails with an error pointing at the condition if it doesn't hold."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"When the condition compares two values, eg $x > 5, the error shows both of them."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        assert(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check a condition",
+                example: "let x = 10; assert $x > 5",
+                result: None,
+            },
+            Example {
+                description: "Say what is checked, for when it fails",
+                example: "assert (ls | length) > 0 'the directory has files'",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn assert(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = raw_args.call_info.name_tag.clone();
+    let context = Arc::new(EvaluationContext::from_args(&raw_args));
+    let (Arguments { condition, message }, _) = raw_args.process().await?;
+
+    let expr = condition_expression(&condition, &tag)?;
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&condition.captured.entries);
+    let result = check(&expr, &message, &context).await;
+    context.scope.exit_scope();
+
+    result.map(|_| OutputStream::empty())
+}
+
+/// The condition is parsed as a block holding just the expression
+fn condition_expression(
+    condition: &CapturedBlock,
+    tag: &Tag,
+) -> Result<SpannedExpression, ShellError> {
+    let expected =
+        || ShellError::labeled_error("Expected a condition", "expected a condition", tag);
+
+    if condition.block.block.len() != 1 {
+        return Err(expected());
+    }
+
+    match condition.block.block[0]
+        .pipelines
+        .get(0)
+        .and_then(|pipeline| pipeline.list.get(0))
+    {
+        Some(ClassifiedCommand::Expr(expr)) => Ok((**expr).clone()),
+        _ => Err(expected()),
+    }
+}
+
+async fn check(
+    expr: &SpannedExpression,
+    message: &Option<Tagged<String>>,
+    context: &EvaluationContext,
+) -> Result<(), ShellError> {
+    let holds = evaluate_baseline_expr(expr, context).await?;
+    if holds.as_bool()? {
+        return Ok(());
+    }
+
+    // A failed comparison points at both sides, with what they came to
+    if let Expression::Binary(binary) = &expr.expr {
+        let left = evaluate_baseline_expr(&binary.left, context).await?;
+        let right = evaluate_baseline_expr(&binary.right, context).await?;
+
+        return Err(ShellError::labeled_error_with_secondary(
+            failure(message, "the condition is false"),
+            format!("this is {}", left.display()),
+            binary.left.span,
+            format!("this is {}", right.display()),
+            binary.right.span,
+        ));
+    }
+
+    Err(ShellError::labeled_error(
+        failure(message, "the condition is false"),
+        "this is false",
+        expr.span,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Command {})
+    }
+}
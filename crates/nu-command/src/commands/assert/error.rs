@@ -0,0 +1,94 @@
+use super::failure;
+use crate::prelude::*;
+use nu_engine::run_block_to_end;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    block: CapturedBlock,
+    message: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "assert error"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert error")
+            .required("block", SyntaxShape::Block, "the block that should fail")
+            .optional(
+                "message",
+                SyntaxShape::String,
+                "what the assertion checks, shown when it fails",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Fails with an error pointing at the block if it runs without an error."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        error(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check that a command fails",
+                example: "assert error { open does-not-exist.txt }",
+                result: None,
+            },
+            Example {
+                description: "Check that a condition doesn't hold",
+                example: "assert error { assert 1 > 2 }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn error(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = EvaluationContext::from_args(&raw_args);
+    let (Arguments { block, message }, input) = raw_args.process().await?;
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+    // The errors can come from the output of the block as well, so it's read in the scope
+    let result = run_block_to_end(&block.block, &context, input).await;
+    context.scope.exit_scope();
+
+    let failed = match result {
+        Ok(output) => output.iter().any(|value| value.value.is_error()),
+        Err(_) => true,
+    };
+
+    if failed {
+        return Ok(OutputStream::empty());
+    }
+
+    Err(ShellError::labeled_error(
+        failure(&message, "the block ran without an error"),
+        "this didn't fail",
+        block.block.span,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
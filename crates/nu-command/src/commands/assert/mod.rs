@@ -0,0 +1,29 @@
+pub mod command;
+pub mod equal;
+pub mod error;
+pub mod not_equal;
+
+pub use command::Command as Assert;
+pub use equal::SubCommand as AssertEqual;
+pub use error::SubCommand as AssertError;
+pub use not_equal::SubCommand as AssertNotEqual;
+
+use nu_data::value::compare_values;
+use nu_protocol::hir::Operator;
+use nu_protocol::Value;
+use nu_source::Tagged;
+
+/// What a failed assertion reports, the message it was given or what went wrong by default
+fn failure(message: &Option<Tagged<String>>, default: &str) -> String {
+    match message {
+        Some(message) => format!("Assertion failed: {}", message.item),
+        None => format!("Assertion failed: {}", default),
+    }
+}
+
+/// Whether the values are equal the way `==` has it, where 1 and 1.0 are, falling back to
+/// comparing tables and rows as they are
+fn values_equal(left: &Value, right: &Value) -> bool {
+    compare_values(Operator::Equal, &left.value, &right.value)
+        .unwrap_or_else(|_| left.value == right.value)
+}
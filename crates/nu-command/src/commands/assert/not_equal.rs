@@ -0,0 +1,92 @@
+use super::{failure, values_equal};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+use nu_source::{PrettyDebug, Tagged};
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    left: Value,
+    right: Value,
+    message: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "assert not equal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert not equal")
+            .required("left", SyntaxShape::Any, "the value to check")
+            .required("right", SyntaxShape::Any, "the value it should not be")
+            .optional(
+                "message",
+                SyntaxShape::String,
+                "what the assertion checks, shown when it fails",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Fails with an error pointing at both values if they are equal."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        not_equal(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check that a command changed something",
+                example: "assert not equal $(echo hello | str upcase) hello",
+                result: None,
+            },
+            Example {
+                description: "Compare tables",
+                example: "assert not equal $(echo [3 1 2]) [1 2 3]",
+                result: Some(vec![]),
+            },
+        ]
+    }
+}
+
+async fn not_equal(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (
+        Arguments {
+            left,
+            right,
+            message,
+        },
+        _,
+    ) = args.process().await?;
+
+    if !values_equal(&left, &right) {
+        return Ok(OutputStream::empty());
+    }
+
+    Err(ShellError::labeled_error_with_secondary(
+        failure(&message, "the values are equal"),
+        format!("this is {}", left.display()),
+        left.tag.span,
+        format!("and so is this, {}", right.display()),
+        right.tag.span,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -60,7 +60,10 @@ impl WholeStreamCommand for Autoenv {
 The file can contain several optional sections:
     env: environment variables to set when visiting the directory. The variables are unset after leaving the directory and any overwritten values are restored.
     scriptvars: environment variables that should be set to the return value of a script. After they have been set, they behave in the same way as variables set in the env section.
-    scripts: scripts to run when entering the directory or leaving it."#
+    scripts: scripts to run when entering the directory or leaving it.
+The .envrc files of direnv can be loaded as well, by setting autoenv_envrc in the config:
+    "read": nushell reads the exports and PATH_add lines of an .envrc trusted with 'autoenv trust --envrc'.
+    "direnv": direnv is run to load the .envrc files it allows, so they can use anything direnv supports."#
     }
 
     fn signature(&self) -> Signature {
@@ -15,29 +15,42 @@ impl WholeStreamCommand for AutoenvTrust {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("autoenv trust").optional("dir", SyntaxShape::String, "Directory to allow")
+        Signature::build("autoenv trust")
+            .optional("dir", SyntaxShape::String, "Directory to allow")
+            .switch(
+                "envrc",
+                "trust the .envrc file instead of the .nu-env file",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Trust a .nu-env file in the current or given directory"
+        "Trust a .nu-env (or .envrc) file in the current or given directory"
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         let tag = args.call_info.name_tag.clone();
         let ctx = EvaluationContext::from_args(&args);
 
-        let file_to_trust = match args.call_info.evaluate(&ctx).await?.args.nth(0) {
+        let evaluated = args.call_info.evaluate(&ctx).await?;
+        let file_name = if evaluated.args.has("envrc") {
+            ".envrc"
+        } else {
+            ".nu-env"
+        };
+
+        let file_to_trust = match evaluated.args.nth(0) {
             Some(Value {
                 value: UntaggedValue::Primitive(Primitive::String(ref path)),
                 tag: _,
             }) => {
                 let mut dir = fs::canonicalize(path)?;
-                dir.push(".nu-env");
+                dir.push(file_name);
                 dir
             }
             _ => {
                 let mut dir = fs::canonicalize(std::env::current_dir()?)?;
-                dir.push(".nu-env");
+                dir.push(file_name);
                 dir
             }
         };
@@ -57,7 +70,7 @@ impl WholeStreamCommand for AutoenvTrust {
         fs::write(config_path, tomlstr).expect("Couldn't write to toml file");
 
         Ok(OutputStream::one(ReturnSuccess::value(
-            UntaggedValue::string(".nu-env trusted!").into_value(tag),
+            UntaggedValue::string(format!("{} trusted!", file_name)).into_value(tag),
         )))
     }
     fn is_binary(&self) -> bool {
@@ -75,6 +88,11 @@ impl WholeStreamCommand for AutoenvTrust {
                 example: "autoenv trust foo",
                 result: None,
             },
+            Example {
+                description: "Allow .envrc file in current directory",
+                example: "autoenv trust --envrc",
+                result: None,
+            },
         ]
     }
 }
@@ -15,32 +15,41 @@ impl WholeStreamCommand for AutoenvUnTrust {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("autoenv untrust").optional(
-            "dir",
-            SyntaxShape::String,
-            "Directory to disallow",
-        )
+        Signature::build("autoenv untrust")
+            .optional("dir", SyntaxShape::String, "Directory to disallow")
+            .switch(
+                "envrc",
+                "untrust the .envrc file instead of the .nu-env file",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Untrust a .nu-env file in the current or given directory"
+        "Untrust a .nu-env (or .envrc) file in the current or given directory"
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         let tag = args.call_info.name_tag.clone();
         let ctx = EvaluationContext::from_args(&args);
-        let file_to_untrust = match args.call_info.evaluate(&ctx).await?.args.nth(0) {
+        let evaluated = args.call_info.evaluate(&ctx).await?;
+        let file_name = if evaluated.args.has("envrc") {
+            ".envrc"
+        } else {
+            ".nu-env"
+        };
+
+        let file_to_untrust = match evaluated.args.nth(0) {
             Some(Value {
                 value: UntaggedValue::Primitive(Primitive::String(ref path)),
                 tag: _,
             }) => {
                 let mut dir = fs::canonicalize(path)?;
-                dir.push(".nu-env");
+                dir.push(file_name);
                 dir
             }
             _ => {
                 let mut dir = std::env::current_dir()?;
-                dir.push(".nu-env");
+                dir.push(file_name);
                 dir
             }
         };
@@ -69,10 +78,10 @@ impl WholeStreamCommand for AutoenvUnTrust {
         let file_to_untrust = file_to_untrust.to_string_lossy().to_string();
 
         if allowed.files.remove(&file_to_untrust).is_none() {
-            return
-                Err(ShellError::untagged_runtime_error(
-                    "No .nu-env file to untrust in the given directory. Is it missing, or already untrusted?",
-                ));
+            return Err(ShellError::untagged_runtime_error(format!(
+                "No {} file to untrust in the given directory. Is it missing, or already untrusted?",
+                file_name
+            )));
         }
 
         let tomlstr = toml::to_string(&allowed).map_err(|_| {
@@ -81,7 +90,7 @@ impl WholeStreamCommand for AutoenvUnTrust {
         fs::write(config_path, tomlstr).expect("Couldn't write to toml file");
 
         Ok(OutputStream::one(ReturnSuccess::value(
-            UntaggedValue::string(".nu-env untrusted!").into_value(tag),
+            UntaggedValue::string(format!("{} untrusted!", file_name)).into_value(tag),
         )))
     }
     fn is_binary(&self) -> bool {
@@ -99,6 +108,11 @@ impl WholeStreamCommand for AutoenvUnTrust {
                 example: "autoenv untrust foo",
                 result: None,
             },
+            Example {
+                description: "Disallow .envrc file in current directory",
+                example: "autoenv untrust --envrc",
+                result: None,
+            },
         ]
     }
 }
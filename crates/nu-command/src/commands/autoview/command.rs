@@ -2,7 +2,7 @@ use crate::commands::autoview::options::{ConfigExtensions, NuConfig as AutoViewC
 use crate::prelude::*;
 use crate::primitive::get_color_config;
 use nu_data::value::format_leaf;
-use nu_engine::{UnevaluatedCallInfo, WholeStreamCommand};
+use nu_engine::{Jobs, UnevaluatedCallInfo, WholeStreamCommand};
 use nu_errors::ShellError;
 use nu_protocol::hir::{self, Expression, ExternalRedirection, Literal, SpannedExpression};
 use nu_protocol::{Primitive, Signature, UntaggedValue, Value};
@@ -34,6 +34,7 @@ impl WholeStreamCommand for Command {
             host: args.host,
             ctrl_c: args.ctrl_c,
             current_errors: args.current_errors,
+            jobs: args.jobs,
             name: args.call_info.name_tag,
         })
         .await
@@ -59,6 +60,7 @@ pub struct RunnableContextWithoutInput {
     pub shell_manager: ShellManager,
     pub host: Arc<parking_lot::Mutex<Box<dyn Host>>>,
     pub current_errors: Arc<Mutex<Vec<ShellError>>>,
+    pub jobs: Jobs,
     pub ctrl_c: Arc<AtomicBool>,
     pub scope: Scope,
     pub name: Tag,
@@ -71,6 +73,7 @@ impl RunnableContextWithoutInput {
             host: context.host,
             ctrl_c: context.ctrl_c,
             current_errors: context.current_errors,
+            jobs: context.jobs,
             scope: context.scope,
             name: context.name,
         };
@@ -112,13 +115,20 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
                 match x {
                     Value {
                         value: UntaggedValue::Primitive(Primitive::String(ref s)),
-                        tag: Tag { anchor, span },
+                        tag:
+                            Tag {
+                                anchor,
+                                span,
+                                content_type,
+                            },
                     } if anchor.is_some() => {
                         if let Some(text) = text {
                             let mut stream = VecDeque::new();
-                            stream.push_back(
-                                UntaggedValue::string(s).into_value(Tag { anchor, span }),
-                            );
+                            stream.push_back(UntaggedValue::string(s).into_value(Tag {
+                                anchor,
+                                span,
+                                content_type,
+                            }));
                             let command_args =
                                 create_default_command_args(&context).with_input(stream);
                             let result = text.run(command_args).await?;
@@ -290,6 +300,7 @@ fn create_default_command_args(context: &RunnableContextWithoutInput) -> RawComm
         ctrl_c: context.ctrl_c.clone(),
         current_errors: context.current_errors.clone(),
         shell_manager: context.shell_manager.clone(),
+        jobs: context.jobs.clone(),
         call_info: UnevaluatedCallInfo {
             args: hir::Call {
                 head: Box::new(SpannedExpression::new(
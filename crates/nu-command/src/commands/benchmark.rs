@@ -6,8 +6,9 @@ use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{
     hir::{Block, CapturedBlock, ClassifiedCommand, Group, InternalCommand, Pipeline},
-    Dictionary, Signature, SyntaxShape, UntaggedValue, Value,
+    Dictionary, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
 };
+use nu_source::Tagged;
 use rand::{
     distributions::Alphanumeric,
     prelude::{thread_rng, Rng},
@@ -21,6 +22,8 @@ pub struct Benchmark;
 struct BenchmarkArgs {
     block: CapturedBlock,
     passthrough: Option<CapturedBlock>,
+    runs: Option<Tagged<u64>>,
+    each: bool,
 }
 
 #[async_trait]
@@ -42,6 +45,17 @@ impl WholeStreamCommand for Benchmark {
                 "Display the benchmark results and pass through the block's output",
                 Some('p'),
             )
+            .named(
+                "runs",
+                SyntaxShape::Int,
+                "run the block this many times and return the min, mean and max time",
+                Some('n'),
+            )
+            .switch(
+                "each",
+                "return the time of each run instead of the min, mean and max",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -64,6 +78,16 @@ impl WholeStreamCommand for Benchmark {
                 example: "echo 45 | benchmark { sleep 500ms } --passthrough {}",
                 result: Some(vec![UntaggedValue::int(45).into()]),
             },
+            Example {
+                description: "Runs a block 10 times and returns the min, mean and max time",
+                example: "benchmark { ls | sort-by size } --runs 10",
+                result: None,
+            },
+            Example {
+                description: "Lists the time of each of 5 runs",
+                example: "benchmark { sleep 100ms } --runs 5 --each",
+                result: None,
+            },
         ]
     }
 }
@@ -72,7 +96,27 @@ async fn benchmark(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     let tag = raw_args.call_info.args.span;
     let mut context = EvaluationContext::from_args(&raw_args);
     let scope = raw_args.scope.clone();
-    let (BenchmarkArgs { block, passthrough }, input) = raw_args.process().await?;
+    let (
+        BenchmarkArgs {
+            block,
+            passthrough,
+            runs,
+            each,
+        },
+        input,
+    ) = raw_args.process().await?;
+
+    if let Some(runs) = runs {
+        if runs.item == 0 {
+            return Err(ShellError::labeled_error(
+                "Expected at least one run",
+                "must be at least 1",
+                &runs.tag,
+            ));
+        }
+
+        return benchmark_runs(block, passthrough, runs.item, each, input, tag, context).await;
+    }
 
     let env = scope.get_env_vars();
     let name = generate_free_name(&env);
@@ -102,7 +146,13 @@ async fn benchmark(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
 
         let real_time = into_big_int(end_time - start_time);
         indexmap.insert("real time".to_string(), real_time);
-        benchmark_output(indexmap, output, passthrough, &tag, &mut context).await
+        benchmark_output(
+            vec![timings(indexmap, tag)],
+            output,
+            passthrough,
+            &mut context,
+        )
+        .await
     }
     // return advanced stats
     // #[cfg(feature = "rich-benchmark")]
@@ -121,7 +171,7 @@ async fn benchmark(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     //     let idle_time = into_big_int(end.idle() - start.idle());
     //     indexmap.insert("idle time".to_string(), idle_time);
 
-    //     benchmark_output(indexmap, output, passthrough, &tag, &mut context).await
+    //     benchmark_output(vec![timings(indexmap, tag)], output, passthrough, &mut context).await
     // } else {
     //     Err(ShellError::untagged_runtime_error(
     //         "Could not retrieve CPU time",
@@ -129,27 +179,82 @@ async fn benchmark(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     // }
 }
 
-async fn benchmark_output<T, Output>(
-    indexmap: IndexMap<String, BigInt>,
-    block_output: Output,
+/// Runs the block the given number of times, each on the same input, and returns the min, mean
+/// and max time it took, or the time of each run. The output passed through is the last run's.
+async fn benchmark_runs(
+    block: CapturedBlock,
     passthrough: Option<CapturedBlock>,
-    tag: T,
-    context: &mut EvaluationContext,
-) -> Result<OutputStream, ShellError>
-where
-    T: Into<Tag> + Copy,
-    Output: Into<OutputStream>,
-{
-    let value = UntaggedValue::Row(Dictionary::from(
+    runs: u64,
+    each: bool,
+    input: InputStream,
+    tag: Span,
+    mut context: EvaluationContext,
+) -> Result<OutputStream, ShellError> {
+    let input = input.into_vec().await;
+    let mut times = vec![];
+    let mut output = vec![];
+
+    for _ in 0..runs {
+        let run_input = InputStream::from_stream(futures::stream::iter(input.clone()));
+        let start_time = Instant::now();
+
+        context.scope.enter_scope();
+        let result = run_block(&block.block, &context, run_input).await;
+        context.scope.exit_scope();
+        output = result?.into_vec().await;
+
+        times.push(Instant::now() - start_time);
+        context.clear_errors();
+    }
+
+    let values = if each {
+        times
+            .iter()
+            .enumerate()
+            .map(|(run, time)| {
+                let mut indexmap = IndexMap::with_capacity(2);
+                indexmap.insert("run".to_string(), BigInt::from(run + 1));
+                indexmap.insert("real time".to_string(), into_big_int(*time));
+                timings(indexmap, tag)
+            })
+            .collect()
+    } else {
+        let min = times.iter().min().copied().unwrap_or_default();
+        let max = times.iter().max().copied().unwrap_or_default();
+        let mean = times.iter().sum::<Duration>() / times.len() as u32;
+
+        let mut indexmap = IndexMap::with_capacity(3);
+        indexmap.insert("min".to_string(), into_big_int(min));
+        indexmap.insert("mean".to_string(), into_big_int(mean));
+        indexmap.insert("max".to_string(), into_big_int(max));
+        vec![timings(indexmap, tag)]
+    };
+
+    benchmark_output(values, output, passthrough, &mut context).await
+}
+
+/// A row of the times, as durations
+fn timings(indexmap: IndexMap<String, BigInt>, tag: impl Into<Tag> + Copy) -> Value {
+    UntaggedValue::Row(Dictionary::from(
         indexmap
             .into_iter()
             .map(|(k, v)| (k, UntaggedValue::duration(v).into_value(tag)))
             .collect::<IndexMap<String, Value>>(),
     ))
-    .into_value(tag);
+    .into_value(tag)
+}
 
+async fn benchmark_output<Output>(
+    timings: Vec<Value>,
+    block_output: Output,
+    passthrough: Option<CapturedBlock>,
+    context: &mut EvaluationContext,
+) -> Result<OutputStream, ShellError>
+where
+    Output: Into<OutputStream>,
+{
     if let Some(time_block) = passthrough {
-        let benchmark_output = InputStream::one(value);
+        let benchmark_output = InputStream::from_stream(futures::stream::iter(timings));
 
         // add autoview for an empty block
         let time_block = add_implicit_autoview(time_block.block);
@@ -162,8 +267,8 @@ where
 
         Ok(block_output.into())
     } else {
-        let benchmark_output = OutputStream::one(value);
-        Ok(benchmark_output)
+        let benchmark_output = futures::stream::iter(timings.into_iter().map(ReturnSuccess::value));
+        Ok(benchmark_output.to_output_stream())
     }
 }
 
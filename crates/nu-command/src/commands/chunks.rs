@@ -0,0 +1,99 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Chunks;
+
+#[derive(Deserialize)]
+pub struct ChunksArgs {
+    size: Tagged<u64>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Chunks {
+    fn name(&self) -> &str {
+        "chunks"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chunks").required(
+            "size",
+            SyntaxShape::Int,
+            "how many rows to put in each chunk",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Splits the rows into tables of the given size, the last of which may be smaller."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each chunk is passed on as soon as it is full, so the input doesn't have to be read in at once."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        chunks(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split a list into pairs",
+                example: "echo [1 2 3 4 5] | chunks 2",
+                result: Some(vec![
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(1).into(),
+                        UntaggedValue::int(2).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(3).into(),
+                        UntaggedValue::int(4).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[UntaggedValue::int(5).into()]).into(),
+                ]),
+            },
+            Example {
+                description: "Add up the sales a hundred at a time",
+                example: "open sales.csv | get amount | chunks 100 | each { echo $it | math sum }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn chunks(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (ChunksArgs { size }, input) = args.process().await?;
+
+    if size.item == 0 {
+        return Err(ShellError::labeled_error(
+            "Chunks can't be empty",
+            "expected a size of at least 1",
+            &size.tag,
+        ));
+    }
+
+    Ok(input
+        .chunks(size.item as usize)
+        .map(move |rows: Vec<Value>| {
+            ReturnSuccess::value(UntaggedValue::table(&rows).into_value(&name))
+        })
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Chunks;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Chunks {})
+    }
+}
@@ -1,25 +1,32 @@
 use crate::futures::ThreadedReceiver;
 use crate::prelude::*;
-use nu_engine::evaluate_baseline_expr;
+use nu_engine::{evaluate_baseline_expr, path_executables, run_block};
 use nu_engine::{MaybeTextCodec, StringOrBinary};
 
 use std::borrow::Cow;
-use std::io::Write;
+use std::ffi::OsStr;
+use std::io::{Read, Write};
 use std::ops::Deref;
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::Ordering;
 use std::sync::mpsc;
+use std::time::Duration;
 
 use futures::executor::block_on_stream;
 use futures_codec::FramedRead;
 use log::trace;
+use parking_lot::Mutex;
 
 use nu_errors::ShellError;
 use nu_protocol::hir::Expression;
 use nu_protocol::hir::{ExternalCommand, ExternalRedirection};
-use nu_protocol::{Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_protocol::{closest_matches, Primitive, ShellTypeName, UntaggedValue, Value};
 use nu_source::Tag;
 use nu_stream::trace_stream;
 
+const STDOUT_BUFFER_SIZE: usize = 8192;
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
 pub(crate) async fn run_external_command(
     command: ExternalCommand,
     context: &mut EvaluationContext,
@@ -29,16 +36,92 @@ pub(crate) async fn run_external_command(
     trace!(target: "nu::run::external", "-> {}", command.name);
 
     if !context.host.lock().is_external_cmd(&command.name) {
-        return Err(ShellError::labeled_error(
-            "Command not found",
-            "command not found",
-            &command.name_tag,
-        ));
+        return command_not_found(command, context).await;
     }
 
     run_with_stdin(command, context, input, external_redirection).await
 }
 
+/// Runs the `command_not_found` hook from the config, with the name of the command as `$it`, eg
+/// to suggest a package to install. Without a hook, the error suggests the closest command, alias
+/// or executable on the PATH instead.
+async fn command_not_found(
+    command: ExternalCommand,
+    context: &mut EvaluationContext,
+) -> Result<InputStream, ShellError> {
+    if let Some(hook) = command_not_found_hook(&context.scope) {
+        let (block, err) = nu_parser::parse(&hook, 0, &context.scope);
+        if err.is_some() {
+            return Err(ShellError::labeled_error(
+                "Could not parse the command_not_found hook in the config",
+                "command not found",
+                &command.name_tag,
+            ));
+        }
+
+        context.scope.enter_scope();
+        context.scope.add_var(
+            "$it",
+            UntaggedValue::string(&command.name).into_value(&command.name_tag),
+        );
+        let result = run_block(&block, context, InputStream::empty()).await;
+        context.scope.exit_scope();
+
+        // The command still failed, whatever the hook has to say about it
+        let error = Value {
+            value: UntaggedValue::Error(ShellError::labeled_error(
+                "Command not found",
+                "command not found",
+                &command.name_tag,
+            )),
+            tag: command.name_tag.clone(),
+        };
+
+        return Ok(InputStream::from_stream(
+            result?.chain(futures::stream::once(async { error })),
+        ));
+    }
+
+    let mut names = context
+        .scope
+        .get_command_names()
+        .into_iter()
+        .filter(|name| !name.contains(' '))
+        .collect::<Vec<_>>();
+    names.extend(context.scope.get_alias_names());
+    if let Some(path_var) = context.scope.get_env_vars().get("PATH") {
+        names.extend(path_executables(OsStr::new(path_var)));
+    }
+
+    let suggestion = closest_matches(names, &command.name).into_iter().next();
+
+    Err(ShellError::labeled_error(
+        "Command not found",
+        match suggestion {
+            Some(suggestion) => format!("command not found (did you mean {}?)", suggestion),
+            None => "command not found".to_string(),
+        },
+        &command.name_tag,
+    ))
+}
+
+/// The hook is read from the config the shell was started with, like `config get` does
+fn command_not_found_hook(scope: &Scope) -> Option<String> {
+    let path = match scope.get_var("config-path") {
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::FilePath(path)),
+            ..
+        }) => Some(path),
+        _ => nu_data::config::default_path().ok(),
+    };
+
+    nu_data::config::load(Tag::unknown(), &path)
+        .ok()?
+        .get("command_not_found")?
+        .as_string()
+        .ok()
+}
+
 async fn run_with_stdin(
     command: ExternalCommand,
     context: &mut EvaluationContext,
@@ -174,10 +257,22 @@ fn spawn(
     process.env_clear();
     process.envs(scope.get_env_vars());
 
+    // Programs that draw on the terminal, like editors and pagers, get one of their own when
+    // nothing is piped to or from them
+    #[cfg(unix)]
+    {
+        if external_redirection == ExternalRedirection::None
+            && input.is_empty()
+            && super::pty::needs_tty(&command.name, scope)
+        {
+            return super::pty::run(process, &command.name_tag, scope.get_cancellation());
+        }
+    }
+
     // We want stdout regardless of what
     // we are doing ($it case or pipe stdin)
     match external_redirection {
-        ExternalRedirection::Stdout => {
+        ExternalRedirection::Stdout | ExternalRedirection::StdoutBytes => {
             process.stdout(Stdio::piped());
             trace!(target: "nu::run::external", "set up stdout pipe");
         }
@@ -207,12 +302,21 @@ fn spawn(
         let (tx, rx) = mpsc::sync_channel(0);
 
         let mut stdin = child.stdin.take();
+        let mut stdout_pipe = child.stdout.take();
+        let mut stderr_pipe = child.stderr.take();
+        let child = Arc::new(Mutex::new(child));
 
         let stdin_write_tx = tx.clone();
         let stdout_read_tx = tx;
         let stdin_name_tag = command.name_tag.clone();
         let stdout_name_tag = command.name_tag;
 
+        // Inside a block that can be cancelled (eg by `timeout`), the external is killed when
+        // it is. Otherwise ctrl-c is left to reach it like in other shells.
+        let finished = scope
+            .get_cancellation()
+            .map(|cancel| kill_on_cancel(child.clone(), cancel));
+
         std::thread::spawn(move || {
             if !input.is_empty() {
                 let mut stdin_write = stdin
@@ -223,13 +327,13 @@ fn spawn(
                     match &value.value {
                         UntaggedValue::Primitive(Primitive::Nothing) => continue,
                         UntaggedValue::Primitive(Primitive::String(s)) => {
-                            if stdin_write.write(s.as_bytes()).is_err() {
+                            if stdin_write.write_all(s.as_bytes()).is_err() {
                                 // Other side has closed, so exit
                                 return Ok(());
                             }
                         }
                         UntaggedValue::Primitive(Primitive::Binary(b)) => {
-                            if stdin_write.write(b).is_err() {
+                            if stdin_write.write_all(b).is_err() {
                                 // Other side has closed, so exit
                                 return Ok(());
                             }
@@ -257,10 +361,69 @@ fn spawn(
         });
 
         std::thread::spawn(move || {
+            // Lets the cancellation watcher know the external is done once this thread is
+            let _finished = finished;
+
+            if external_redirection == ExternalRedirection::StdoutBytes {
+                let mut stdout = if let Some(stdout) = stdout_pipe.take() {
+                    stdout
+                } else {
+                    let _ = stdout_read_tx.send(Ok(Value {
+                        value: UntaggedValue::Error(ShellError::labeled_error(
+                            "Can't redirect the stdout for external command",
+                            "can't redirect stdout",
+                            &stdout_name_tag,
+                        )),
+                        tag: stdout_name_tag,
+                    }));
+                    return Err(());
+                };
+
+                // The next external gets the bytes as they come. The channel holds nothing
+                // back, so a slow reader keeps this one from reading ahead of it.
+                let mut buffer = vec![0; STDOUT_BUFFER_SIZE];
+                loop {
+                    match stdout.read(&mut buffer) {
+                        Ok(0) => break,
+                        Ok(read) => {
+                            let result = stdout_read_tx.send(Ok(Value {
+                                value: UntaggedValue::Primitive(Primitive::Binary(
+                                    buffer[..read].to_vec(),
+                                )),
+                                tag: stdout_name_tag.clone(),
+                            }));
+
+                            if result.is_err() {
+                                break;
+                            }
+                        }
+                        Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                        Err(e) => {
+                            let should_error = match wait(&child) {
+                                Ok(exit_status) => !exit_status.success(),
+                                Err(_) => true,
+                            };
+
+                            if should_error {
+                                let _ = stdout_read_tx.send(Ok(Value {
+                                    value: UntaggedValue::Error(ShellError::labeled_error(
+                                        format!("Unable to read from stdout ({})", e),
+                                        "unable to read from stdout",
+                                        &stdout_name_tag,
+                                    )),
+                                    tag: stdout_name_tag.clone(),
+                                }));
+                            }
+
+                            return Ok(());
+                        }
+                    }
+                }
+            }
             if external_redirection == ExternalRedirection::Stdout
                 || external_redirection == ExternalRedirection::StdoutAndStderr
             {
-                let stdout = if let Some(stdout) = child.stdout.take() {
+                let stdout = if let Some(stdout) = stdout_pipe.take() {
                     stdout
                 } else {
                     let _ = stdout_read_tx.send(Ok(Value {
@@ -307,7 +470,7 @@ fn spawn(
                             // If there's an exit status, it makes sense that we may error when
                             // trying to read from its stdout pipe (likely been closed). In that
                             // case, don't emit an error.
-                            let should_error = match child.wait() {
+                            let should_error = match wait(&child) {
                                 Ok(exit_status) => !exit_status.success(),
                                 Err(_) => true,
                             };
@@ -331,7 +494,7 @@ fn spawn(
             if external_redirection == ExternalRedirection::Stderr
                 || external_redirection == ExternalRedirection::StdoutAndStderr
             {
-                let stderr = if let Some(stderr) = child.stderr.take() {
+                let stderr = if let Some(stderr) = stderr_pipe.take() {
                     stderr
                 } else {
                     let _ = stdout_read_tx.send(Ok(Value {
@@ -380,7 +543,7 @@ fn spawn(
                             // If there's an exit status, it makes sense that we may error when
                             // trying to read from its stdout pipe (likely been closed). In that
                             // case, don't emit an error.
-                            let should_error = match child.wait() {
+                            let should_error = match wait(&child) {
                                 Ok(exit_status) => !exit_status.success(),
                                 Err(_) => true,
                             };
@@ -404,9 +567,9 @@ fn spawn(
 
             // We can give an error when we see a non-zero exit code, but this is different
             // than what other shells will do.
-            let external_failed = match child.wait() {
-                Err(_) => true,
-                Ok(exit_status) => !exit_status.success(),
+            let (external_failed, exit_code) = match wait(&child) {
+                Err(_) => (true, None),
+                Ok(exit_status) => (!exit_status.success(), exit_status.code()),
             };
 
             if external_failed {
@@ -424,7 +587,7 @@ fn spawn(
                     }
                 }
                 let _ = stdout_read_tx.send(Ok(Value {
-                    value: UntaggedValue::Error(ShellError::external_non_zero()),
+                    value: UntaggedValue::Error(ShellError::external_non_zero(exit_code)),
                     tag: stdout_name_tag,
                 }));
             }
@@ -443,6 +606,43 @@ fn spawn(
     }
 }
 
+/// Kills the process once `cancel` is set, unless the sender given back is dropped first
+pub(super) fn kill_on_cancel(
+    child: Arc<Mutex<Child>>,
+    cancel: Arc<AtomicBool>,
+) -> mpsc::Sender<()> {
+    let (finished_tx, finished_rx) = mpsc::channel();
+
+    std::thread::spawn(move || loop {
+        match finished_rx.recv_timeout(CANCEL_CHECK_INTERVAL) {
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if cancel.load(Ordering::SeqCst) {
+                    let _ = child.lock().kill();
+                    return;
+                }
+            }
+            _ => return,
+        }
+    });
+
+    finished_tx
+}
+
+/// Waits for the process to be done without holding the lock, so it can still be killed in the
+/// meantime. Waiting is what reaps it.
+pub(super) fn wait(child: &Mutex<Child>) -> std::io::Result<ExitStatus> {
+    let mut interval = Duration::from_millis(1);
+
+    loop {
+        if let Some(status) = child.lock().try_wait()? {
+            return Ok(status);
+        }
+
+        std::thread::sleep(interval);
+        interval = (interval * 2).min(CANCEL_CHECK_INTERVAL);
+    }
+}
+
 fn expand_tilde<SI: ?Sized, P, HD>(input: &SI, home_dir: HD) -> std::borrow::Cow<str>
 where
     SI: AsRef<str>,
@@ -503,6 +703,8 @@ mod tests {
     use super::{
         add_double_quotes, argument_is_quoted, escape_double_quotes, expand_tilde, remove_quotes,
     };
+    #[cfg(unix)]
+    use super::{kill_on_cancel, wait};
     #[cfg(feature = "which")]
     use super::{run_external_command, InputStream};
 
@@ -650,4 +852,25 @@ mod tests {
             "1~1"
         );
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn cancelled_externals_are_killed_and_reaped() {
+        use parking_lot::Mutex;
+        use std::sync::atomic::AtomicBool;
+        use std::sync::Arc;
+
+        let child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .expect("could not spawn sleep");
+        let child = Arc::new(Mutex::new(child));
+
+        let _finished = kill_on_cancel(child.clone(), Arc::new(AtomicBool::new(true)));
+        let status = wait(&child).expect("could not wait for sleep");
+
+        // Killed by a signal rather than exiting
+        assert!(!status.success());
+        assert_eq!(status.code(), None);
+    }
 }
@@ -1,5 +1,7 @@
 mod dynamic;
 pub(crate) mod external;
+#[cfg(unix)]
+mod pty;
 
 #[allow(unused_imports)]
 pub(crate) use dynamic::Command as DynamicCommand;
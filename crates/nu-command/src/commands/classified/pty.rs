@@ -0,0 +1,327 @@
+//! Running the externals that take over the terminal, like editors and pagers, on a
+//! pseudo-terminal of their own
+
+use super::external::{kill_on_cancel, wait};
+use crate::prelude::*;
+use nix::errno::Errno;
+use nix::fcntl::{fcntl, FcntlArg, FdFlag, OFlag};
+use nix::libc;
+use nix::poll::{poll, PollFd, PollFlags};
+use nix::pty::{openpty, Winsize};
+use nix::sys::signal::{sigaction, SaFlags, SigAction, SigHandler, SigSet, Signal};
+use nix::sys::termios::{cfmakeraw, tcgetattr, tcsetattr, SetArg, Termios};
+use nix::unistd::{close, dup, isatty, pipe, read, setsid, write};
+use nu_engine::Scope;
+use nu_errors::ShellError;
+use nu_protocol::{UntaggedValue, Value};
+use parking_lot::Mutex;
+use std::io::Write;
+use std::os::unix::io::{FromRawFd, RawFd};
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+const STDIN: RawFd = 0;
+const STDOUT: RawFd = 1;
+
+/// The programs that get a terminal unless `tty_commands` is set in the config
+const DEFAULT_TTY_COMMANDS: &[&str] = &[
+    "emacs", "fzf", "htop", "less", "man", "more", "nano", "nvim", "screen", "ssh", "tmux", "top",
+    "vi", "vim",
+];
+
+/// Where the SIGWINCH handler tells the running external that our terminal was resized
+static RESIZED: AtomicI32 = AtomicI32::new(-1);
+
+/// Whether the external should run on a terminal: nu itself has to be running on one, and the
+/// program has to be in the `tty_commands` of the config (or the default list)
+pub(crate) fn needs_tty(name: &str, scope: &Scope) -> bool {
+    matches!(isatty(STDIN), Ok(true))
+        && matches!(isatty(STDOUT), Ok(true))
+        && is_tty_command(name, scope.get_config_var("tty_commands").as_ref())
+}
+
+fn is_tty_command(name: &str, tty_commands: Option<&Value>) -> bool {
+    let program = Path::new(name)
+        .file_name()
+        .map(|program| program.to_string_lossy().to_string())
+        .unwrap_or_else(|| name.to_string());
+
+    match tty_commands {
+        Some(commands) => commands
+            .table_entries()
+            .any(|command| matches!(command.as_string(), Ok(command) if command == program)),
+        None => DEFAULT_TTY_COMMANDS.contains(&program.as_str()),
+    }
+}
+
+/// A descriptor that's closed when dropped
+struct Fd(RawFd);
+
+impl Drop for Fd {
+    fn drop(&mut self) {
+        let _ = close(self.0);
+    }
+}
+
+/// A pipe to wake up a thread waiting on the read end with
+fn wakeup_pipe() -> Result<(Fd, Fd), nix::Error> {
+    let (read, write) = pipe()?;
+    let (read, write) = (Fd(read), Fd(write));
+
+    for fd in &[read.0, write.0] {
+        fcntl(*fd, FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC))?;
+        fcntl(*fd, FcntlArg::F_SETFL(OFlag::O_NONBLOCK))?;
+    }
+
+    Ok((read, write))
+}
+
+fn wake(fd: RawFd) {
+    let _ = write(fd, &[0]);
+}
+
+fn drain(fd: RawFd) {
+    let mut buffer = [0; 64];
+    while matches!(read(fd, &mut buffer), Ok(read) if read > 0) {}
+}
+
+extern "C" fn on_resize(_: libc::c_int) {
+    // Only writing to a pipe, which is safe to do in a signal handler
+    let fd = RESIZED.load(Ordering::SeqCst);
+    if fd >= 0 {
+        wake(fd);
+    }
+}
+
+fn window_size() -> Option<Winsize> {
+    term_size::dimensions().map(|(cols, rows)| Winsize {
+        ws_row: rows as u16,
+        ws_col: cols as u16,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    })
+}
+
+/// Kills the process and waits for it, so it's neither left running without anyone reading its
+/// terminal, nor left a zombie
+fn reap(child: &mut Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Puts the terminal in raw mode for the process, giving back the settings it had. The process is
+/// reaped if that fails.
+fn raw_mode(terminal: RawFd, child: &mut Child) -> Result<Termios, nix::Error> {
+    let enter = || -> Result<Termios, nix::Error> {
+        let saved = tcgetattr(terminal)?;
+        let mut raw = saved.clone();
+        cfmakeraw(&mut raw);
+        tcsetattr(terminal, SetArg::TCSANOW, &raw)?;
+
+        Ok(saved)
+    };
+
+    enter().map_err(|error| {
+        reap(child);
+        error
+    })
+}
+
+fn pty_error(error: nix::Error, tag: &Tag) -> ShellError {
+    ShellError::labeled_error(
+        format!("Could not set up a terminal for the command: {}", error),
+        "could not set up a terminal",
+        tag,
+    )
+}
+
+/// Runs the process on a new pseudo-terminal the size of ours. While it runs, our terminal is in
+/// raw mode and every key, ctrl-c included, goes to the process, and everything it draws goes
+/// to our terminal. The terminal is put back the way it was once the process is done, or once
+/// it's killed because `cancel` was set.
+pub(crate) fn run(
+    mut process: Command,
+    tag: &Tag,
+    cancel: Option<Arc<AtomicBool>>,
+) -> Result<InputStream, ShellError> {
+    let pty = openpty(window_size().as_ref(), None).map_err(|e| pty_error(e, tag))?;
+    let master = Fd(pty.master);
+    let slave = Fd(pty.slave);
+
+    let (resized, resize) = wakeup_pipe().map_err(|e| pty_error(e, tag))?;
+    let (done, finish) = wakeup_pipe().map_err(|e| pty_error(e, tag))?;
+
+    // Safety: the descriptor was just made and nothing else owns it
+    let slave_stdio = || dup(slave.0).map(|fd| unsafe { Stdio::from_raw_fd(fd) });
+    process.stdin(slave_stdio().map_err(|e| pty_error(e, tag))?);
+    process.stdout(slave_stdio().map_err(|e| pty_error(e, tag))?);
+    process.stderr(slave_stdio().map_err(|e| pty_error(e, tag))?);
+
+    let master_fd = master.0;
+    // Safety: only async-signal-safe calls are made between the fork and the exec
+    unsafe {
+        // The pseudo-terminal becomes the controlling terminal of the process, in a session of
+        // its own, so ctrl-c and job control reach it
+        process.pre_exec(move || {
+            let _ = close(master_fd);
+            if setsid().is_err() || libc::ioctl(STDIN, libc::TIOCSCTTY as _, 0) == -1 {
+                return Err(std::io::Error::last_os_error());
+            }
+
+            Ok(())
+        });
+    }
+
+    let child = process.spawn();
+    // Our copies of the slave are closed, so reading the master ends when the process is done
+    drop(process);
+    drop(slave);
+    let mut child = child.map_err(|_| {
+        ShellError::labeled_error("Failed to spawn process", "failed to spawn", tag)
+    })?;
+
+    let saved = raw_mode(STDIN, &mut child).map_err(|e| pty_error(e, tag))?;
+
+    // Killing the process closes its end of the terminal, which ends the reading below
+    let child = Arc::new(Mutex::new(child));
+    let finished = cancel.map(|cancel| kill_on_cancel(child.clone(), cancel));
+
+    // Resizing our terminal resizes the process's too
+    RESIZED.store(resize.0, Ordering::SeqCst);
+    let on_resize = SigAction::new(
+        SigHandler::Handler(on_resize),
+        SaFlags::SA_RESTART,
+        SigSet::empty(),
+    );
+    // Safety: the handler only writes to a pipe
+    let previous = unsafe { sigaction(Signal::SIGWINCH, &on_resize) };
+
+    let (resized_fd, done_fd) = (resized.0, done.0);
+    let keys = std::thread::spawn(move || {
+        let mut buffer = [0; 1024];
+
+        // Keys are only read while the process runs, so the ones typed after it's done are left
+        // to the REPL
+        loop {
+            let mut fds = [
+                PollFd::new(STDIN, PollFlags::POLLIN),
+                PollFd::new(resized_fd, PollFlags::POLLIN),
+                PollFd::new(done_fd, PollFlags::POLLIN),
+            ];
+            match poll(&mut fds, -1) {
+                Ok(_) => {}
+                Err(nix::Error::Sys(Errno::EINTR)) => continue,
+                Err(_) => break,
+            }
+
+            let ready = |fd: &PollFd| fd.revents().map_or(false, |events| !events.is_empty());
+
+            if ready(&fds[2]) {
+                break;
+            }
+
+            if ready(&fds[1]) {
+                drain(resized_fd);
+                if let Some(size) = window_size() {
+                    // Safety: the size is a winsize, as TIOCSWINSZ expects
+                    unsafe { libc::ioctl(master_fd, libc::TIOCSWINSZ as _, &size) };
+                }
+            }
+
+            if ready(&fds[0]) {
+                match read(STDIN, &mut buffer) {
+                    Ok(0) => break,
+                    Ok(read) => {
+                        if write(master_fd, &buffer[..read]).is_err() {
+                            break;
+                        }
+                    }
+                    Err(nix::Error::Sys(Errno::EINTR)) => continue,
+                    Err(_) => break,
+                }
+            }
+        }
+    });
+
+    let mut buffer = [0; 8192];
+    loop {
+        match read(master_fd, &mut buffer) {
+            Ok(0) => break,
+            Ok(read) => {
+                let stdout = std::io::stdout();
+                let mut stdout = stdout.lock();
+                let _ = stdout.write_all(&buffer[..read]);
+                let _ = stdout.flush();
+            }
+            Err(nix::Error::Sys(Errno::EINTR)) => continue,
+            // Once the process is done, reading the master fails instead of ending on Linux
+            Err(_) => break,
+        }
+    }
+
+    let status = wait(&child);
+    drop(finished);
+
+    wake(finish.0);
+    let _ = keys.join();
+    if let Ok(previous) = previous {
+        // Safety: putting back the handler that was there before
+        let _ = unsafe { sigaction(Signal::SIGWINCH, &previous) };
+    }
+    RESIZED.store(-1, Ordering::SeqCst);
+    let _ = tcsetattr(STDIN, SetArg::TCSANOW, &saved);
+    drop(master);
+
+    match status {
+        Ok(status) if status.success() => Ok(InputStream::empty()),
+        Ok(status) => Ok(InputStream::one(Value {
+            value: UntaggedValue::Error(ShellError::external_non_zero(status.code())),
+            tag: tag.clone(),
+        })),
+        Err(_) => Ok(InputStream::one(Value {
+            value: UntaggedValue::Error(ShellError::external_non_zero(None)),
+            tag: tag.clone(),
+        })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_tty_command, raw_mode};
+    use nu_protocol::{UntaggedValue, Value};
+    use std::process::Command;
+
+    #[test]
+    fn programs_get_a_terminal_when_listed() {
+        assert!(is_tty_command("vim", None));
+        assert!(is_tty_command("/usr/bin/less", None));
+        assert!(!is_tty_command("ls", None));
+
+        let configured: Value = UntaggedValue::table(&[
+            UntaggedValue::string("ls").into(),
+            UntaggedValue::string("btm").into(),
+        ])
+        .into();
+
+        assert!(is_tty_command("btm", Some(&configured)));
+        assert!(is_tty_command("ls", Some(&configured)));
+        assert!(!is_tty_command("vim", Some(&configured)));
+    }
+
+    #[test]
+    fn the_process_is_reaped_when_the_terminal_cannot_be_set_up() {
+        let (not_a_terminal, write) = nix::unistd::pipe().expect("could not create a pipe");
+        let mut child = Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .expect("could not spawn sleep");
+
+        assert!(raw_mode(not_a_terminal, &mut child).is_err());
+        assert!(matches!(child.try_wait(), Ok(Some(status)) if !status.success()));
+
+        let _ = nix::unistd::close(not_a_terminal);
+        let _ = nix::unistd::close(write);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    merge_descriptors, ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value,
+};
+
+pub struct Columns;
+
+#[async_trait]
+impl WholeStreamCommand for Columns {
+    fn name(&self) -> &str {
+        "columns"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("columns")
+    }
+
+    fn usage(&self) -> &str {
+        "Show the column names of a record or table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "For a table, the columns of all of its rows are listed in the order they first show up."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        columns(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the column names of a record",
+                example: "echo [[name size]; [nu 5]] | columns",
+                result: Some(vec![
+                    UntaggedValue::string("name").into(),
+                    UntaggedValue::string("size").into(),
+                ]),
+            },
+            Example {
+                description: "Get the column names of the files table",
+                example: "ls | columns",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn columns(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let args = args.evaluate_once().await?;
+    let (input, _args) = args.parts();
+
+    let rows: Vec<Value> = input.collect().await;
+
+    if let Some(row) = rows.iter().find(|row| !row.is_row()) {
+        return Err(ShellError::labeled_error_with_secondary(
+            "Expected a record or table",
+            "requires a record or table input",
+            &tag,
+            format!("this is {}", row.type_name()),
+            &row.tag,
+        ));
+    }
+
+    let columns = merge_descriptors(&rows)
+        .into_iter()
+        .map(move |column| ReturnSuccess::value(UntaggedValue::string(column).into_value(&tag)));
+
+    Ok(futures::stream::iter(columns).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Columns;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Columns {})
+    }
+}
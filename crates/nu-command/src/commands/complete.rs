@@ -0,0 +1,141 @@
+use crate::prelude::*;
+use nu_engine::run_block_to_end;
+use nu_engine::WholeStreamCommand;
+use nu_errors::{ProximateShellError, ShellError};
+use nu_protocol::{
+    hir::CapturedBlock, hir::ExternalRedirection, Primitive, ReturnSuccess, ShellTypeName,
+    Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+};
+
+pub struct Complete;
+
+#[derive(Deserialize, Debug)]
+struct CompleteArgs {
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Complete {
+    fn name(&self) -> &str {
+        "complete"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("complete").required(
+            "block",
+            SyntaxShape::Block,
+            "the block with the external to run",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs a block and gives back its stdout, stderr and exit code as a record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Nothing is written to the terminal, and an external that fails isn't an error. The exit code is the one of the last external in the block to fail, or 0 if none did."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        complete(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let mut record = TaggedDictBuilder::new(Tag::unknown());
+        record.insert_untagged("stdout", UntaggedValue::string("hello"));
+        record.insert_untagged("stderr", UntaggedValue::string(""));
+        record.insert_untagged("exit_code", UntaggedValue::int(0));
+
+        vec![
+            Example {
+                description: "Run an external and capture what it writes",
+                example: "complete { ^cargo build }",
+                result: None,
+            },
+            Example {
+                description: "Check the exit code of an external",
+                example: "complete { ^git diff --quiet } | get exit_code",
+                result: None,
+            },
+            Example {
+                description: "Capture the text of the block",
+                example: "complete { echo hello }",
+                result: Some(vec![record.into_value()]),
+            },
+        ]
+    }
+}
+
+async fn complete(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = raw_args.call_info.name_tag.clone();
+    let context = EvaluationContext::from_args(&raw_args);
+    let (CompleteArgs { mut block }, input) = raw_args.process().await?;
+
+    block
+        .block
+        .set_redirect(ExternalRedirection::StdoutAndStderr);
+    context.scope.enter_scope();
+    let result = run_block_to_end(&block.block, &context, input).await;
+    context.scope.exit_scope();
+
+    let mut stdout = vec![];
+    let mut stderr = String::new();
+    let mut exit_code = Some(0);
+
+    for value in result? {
+        match value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => stdout.extend(s.into_bytes()),
+            UntaggedValue::Primitive(Primitive::Binary(b)) => stdout.extend(b),
+            UntaggedValue::Error(err) => {
+                if let Some(code) = err.external_exit_code() {
+                    exit_code = code;
+                } else if let ProximateShellError::UntaggedRuntimeError { reason } = err.error {
+                    // The stderr of an external comes in as runtime errors
+                    stderr.push_str(&reason);
+                } else {
+                    return Err(err);
+                }
+            }
+            _ => {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "Expected text from the block",
+                    "requires the block to give text",
+                    &tag,
+                    format!("this is {}", value.type_name()),
+                    &value.tag,
+                ))
+            }
+        }
+    }
+
+    let stdout = match String::from_utf8(stdout) {
+        Ok(text) => UntaggedValue::string(text),
+        Err(err) => UntaggedValue::binary(err.into_bytes()),
+    };
+
+    let mut record = TaggedDictBuilder::new(&tag);
+    record.insert_untagged("stdout", stdout);
+    record.insert_untagged("stderr", UntaggedValue::string(stderr));
+    record.insert_untagged(
+        "exit_code",
+        match exit_code {
+            Some(code) => UntaggedValue::int(code),
+            None => UntaggedValue::nothing(),
+        },
+    );
+
+    Ok(OutputStream::one(ReturnSuccess::value(record.into_value())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Complete;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Complete {})
+    }
+}
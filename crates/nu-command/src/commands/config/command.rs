@@ -31,7 +31,7 @@ impl WholeStreamCommand for Command {
             }) => Some(path),
             _ => nu_data::config::default_path().ok(),
         };
-        let result = nu_data::config::read(&name, &path)?;
+        let result = nu_data::config::load(&name, &path)?;
 
         Ok(futures::stream::iter(vec![ReturnSuccess::value(
             UntaggedValue::Row(result.into()).into_value(name),
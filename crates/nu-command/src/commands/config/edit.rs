@@ -0,0 +1,99 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, UntaggedValue, Value};
+use std::process::Command;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "config edit"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("config edit")
+    }
+
+    fn usage(&self) -> &str {
+        "open the config file in an editor"
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The editor is the editor setting of the config, or $VISUAL or $EDITOR if that isn't set
+(or the file can't be read), and vi (notepad on Windows) otherwise."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        edit(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Fix a config file that couldn't be read",
+            example: "config edit",
+            result: None,
+        }]
+    }
+}
+
+pub async fn edit(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+
+    let path = match args.scope.get_var("config-path") {
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::FilePath(path)),
+            ..
+        }) => path,
+        _ => nu_data::config::default_path()?,
+    };
+
+    let editor = nu_data::config::load(name_tag.clone(), &Some(path.clone()))
+        .ok()
+        .and_then(|config| {
+            config
+                .get("editor")
+                .and_then(|editor| editor.as_string().ok())
+        })
+        .or_else(|| std::env::var("VISUAL").ok())
+        .or_else(|| std::env::var("EDITOR").ok())
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                "notepad".to_string()
+            } else {
+                "vi".to_string()
+            }
+        });
+
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().ok_or_else(|| {
+        ShellError::labeled_error(
+            "No editor to open",
+            "the editor setting is empty",
+            &name_tag,
+        )
+    })?;
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(&path)
+        .status()
+        .map_err(|err| {
+            ShellError::labeled_error(
+                format!("Could not start {}", program),
+                err.to_string(),
+                &name_tag,
+            )
+        })?;
+
+    if !status.success() {
+        return Err(ShellError::labeled_error(
+            format!("{} exited with an error", program),
+            "the config file may not have been saved",
+            &name_tag,
+        ));
+    }
+
+    Ok(OutputStream::empty())
+}
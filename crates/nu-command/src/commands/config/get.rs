@@ -56,7 +56,7 @@ pub async fn get(args: CommandArgs) -> Result<OutputStream, ShellError> {
         _ => nu_data::config::default_path().ok(),
     };
 
-    let result = UntaggedValue::row(nu_data::config::read(&name, &path)?).into_value(&name);
+    let result = UntaggedValue::row(nu_data::config::load(&name, &path)?).into_value(&name);
 
     let value = crate::commands::get::get_column_path(&column_path, &result)?;
 
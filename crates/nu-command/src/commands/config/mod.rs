@@ -1,5 +1,6 @@
 pub mod clear;
 pub mod command;
+pub mod edit;
 pub mod get;
 pub mod path;
 pub mod remove;
@@ -8,6 +9,7 @@ pub mod set_into;
 
 pub use clear::SubCommand as ConfigClear;
 pub use command::Command as Config;
+pub use edit::SubCommand as ConfigEdit;
 pub use get::SubCommand as ConfigGet;
 pub use path::SubCommand as ConfigPath;
 pub use remove::SubCommand as ConfigRemove;
@@ -0,0 +1,62 @@
+use super::dataframe_from_input;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature};
+
+pub struct DfrCollect;
+
+#[async_trait]
+impl WholeStreamCommand for DfrCollect {
+    fn name(&self) -> &str {
+        "dfr collect"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dfr collect")
+    }
+
+    fn usage(&self) -> &str {
+        "Turns the dataframe into a table, so the other commands can work on its rows."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Every cell becomes a Nu value, so it's best done once the dataframe has been filtered or
+grouped down to the rows that are needed."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        dfr_collect(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "The five regions with the most sales",
+            example: "dfr open sales.csv | dfr group-by region -a sum | dfr collect | sort-by amount_sum | reverse | first 5",
+            result: None,
+        }]
+    }
+}
+
+async fn dfr_collect(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let input = args.input;
+
+    let dataframe = dataframe_from_input(input, &name).await?;
+    let rows = dataframe.to_rows(&name)?;
+
+    Ok(futures::stream::iter(rows.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DfrCollect;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(DfrCollect {})
+    }
+}
@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Dfr;
+
+#[async_trait]
+impl WholeStreamCommand for Dfr {
+    fn name(&self) -> &str {
+        "dfr"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dfr")
+    }
+
+    fn usage(&self) -> &str {
+        "Work on large tables as dataframes, which keep the data in columns."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(get_full_help(&Dfr, &args.scope)).into_value(Tag::unknown()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dfr;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Dfr {})
+    }
+}
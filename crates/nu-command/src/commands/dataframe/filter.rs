@@ -0,0 +1,176 @@
+use super::{dataframe_from_input, polars_error};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    NuDataFrame, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use polars::prelude::{BooleanChunked, ChunkCompare, Series};
+
+pub struct DfrFilter;
+
+#[derive(Deserialize)]
+pub struct DfrFilterArgs {
+    column: Tagged<String>,
+    comparison: Tagged<String>,
+    value: Value,
+}
+
+#[derive(Clone, Copy)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DfrFilter {
+    fn name(&self) -> &str {
+        "dfr filter"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dfr filter")
+            .required("column", SyntaxShape::String, "the column to compare")
+            .required(
+                "comparison",
+                SyntaxShape::String,
+                "how to compare: ==, !=, <, <=, > or >=",
+            )
+            .required(
+                "value",
+                SyntaxShape::Any,
+                "the number or string to compare with",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Keeps the rows of the dataframe whose value in the column compares true with the value given."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        dfr_filter(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Keep the sales over 100",
+                example: "dfr open sales.csv | dfr filter amount '>' 100",
+                result: None,
+            },
+            Example {
+                description: "Keep the sales of one region",
+                example: "dfr open sales.csv | dfr filter region == EMEA",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn dfr_filter(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (
+        DfrFilterArgs {
+            column,
+            comparison,
+            value,
+        },
+        input,
+    ) = args.process().await?;
+
+    let comparison = match comparison.item.as_str() {
+        "==" => Comparison::Equal,
+        "!=" => Comparison::NotEqual,
+        "<" => Comparison::LessThan,
+        "<=" => Comparison::LessThanOrEqual,
+        ">" => Comparison::GreaterThan,
+        ">=" => Comparison::GreaterThanOrEqual,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unknown comparison",
+                "expected one of ==, !=, <, <=, > or >=",
+                &comparison.tag,
+            ))
+        }
+    };
+
+    let dataframe = dataframe_from_input(input, &name).await?;
+    let series = dataframe
+        .dataframe
+        .column(&column.item)
+        .map_err(|error| polars_error(error, &column.tag))?;
+
+    let mask = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => {
+            let i = i.to_i64().ok_or_else(|| {
+                ShellError::labeled_error(
+                    "Integer too large for a dataframe",
+                    "doesn't fit in 64 bits",
+                    &value.tag,
+                )
+            })?;
+
+            compare(series, comparison, i)
+        }
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            let f = d.to_f64().ok_or_else(|| {
+                ShellError::labeled_error(
+                    "Decimal can't be compared in a dataframe",
+                    "can't be made a 64 bit float",
+                    &value.tag,
+                )
+            })?;
+
+            compare(series, comparison, f)
+        }
+        UntaggedValue::Primitive(Primitive::String(s)) => compare(series, comparison, s.as_str()),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a number or a string",
+                "can only compare with numbers and strings",
+                &value.tag,
+            ))
+        }
+    };
+
+    let filtered = dataframe
+        .dataframe
+        .filter(&mask)
+        .map_err(|error| polars_error(error, &name))?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::DataFrame(NuDataFrame::new(filtered)).into_value(&name),
+    )))
+}
+
+fn compare<Rhs>(series: &Series, comparison: Comparison, rhs: Rhs) -> BooleanChunked
+where
+    Series: ChunkCompare<Rhs>,
+{
+    match comparison {
+        Comparison::Equal => ChunkCompare::<Rhs>::eq(series, rhs),
+        Comparison::NotEqual => ChunkCompare::<Rhs>::neq(series, rhs),
+        Comparison::LessThan => ChunkCompare::<Rhs>::lt(series, rhs),
+        Comparison::LessThanOrEqual => ChunkCompare::<Rhs>::lt_eq(series, rhs),
+        Comparison::GreaterThan => ChunkCompare::<Rhs>::gt(series, rhs),
+        Comparison::GreaterThanOrEqual => ChunkCompare::<Rhs>::gt_eq(series, rhs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DfrFilter;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(DfrFilter {})
+    }
+}
@@ -0,0 +1,134 @@
+use super::{dataframe_from_input, polars_error};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{NuDataFrame, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct DfrGroupBy;
+
+#[derive(Deserialize)]
+pub struct DfrGroupByArgs {
+    by: Tagged<String>,
+    rest: Vec<Tagged<String>>,
+    aggregate: Tagged<String>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DfrGroupBy {
+    fn name(&self) -> &str {
+        "dfr group-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dfr group-by")
+            .required("by", SyntaxShape::String, "the column to group by")
+            .rest(SyntaxShape::String, "more columns to group by")
+            .required_named(
+                "aggregate",
+                SyntaxShape::String,
+                "how to combine the values of each group: sum, mean, min, max, first, last, count or n-unique",
+                Some('a'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Groups the rows of the dataframe by the columns given, combining the other columns of each group."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The result has a row for each group, with the columns grouped by and a column for each of the
+others, named after the column and the aggregate, eg amount_sum."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        dfr_group_by(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Add up the sales of each region",
+                example: "dfr open sales.csv | dfr group-by region --aggregate sum",
+                result: None,
+            },
+            Example {
+                description: "The average sale of each product in each region",
+                example: "dfr open sales.csv | dfr group-by region product -a mean",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn dfr_group_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (
+        DfrGroupByArgs {
+            by,
+            rest,
+            aggregate,
+        },
+        input,
+    ) = args.process().await?;
+
+    let dataframe = dataframe_from_input(input, &name).await?.dataframe;
+
+    let by = std::iter::once(by).chain(rest).collect::<Vec<_>>();
+    for column in &by {
+        dataframe
+            .column(&column.item)
+            .map_err(|error| polars_error(error, &column.tag))?;
+    }
+
+    let keys = by
+        .iter()
+        .map(|column| column.item.as_str())
+        .collect::<Vec<_>>();
+    let values = dataframe
+        .get_column_names()
+        .into_iter()
+        .filter(|column| !keys.contains(column))
+        .collect::<Vec<_>>();
+
+    let groups = dataframe
+        .groupby(&keys)
+        .map_err(|error| polars_error(error, &name))?
+        .select(&values);
+
+    let grouped = match aggregate.item.as_str() {
+        "sum" => groups.sum(),
+        "mean" => groups.mean(),
+        "min" => groups.min(),
+        "max" => groups.max(),
+        "first" => groups.first(),
+        "last" => groups.last(),
+        "count" => groups.count(),
+        "n-unique" => groups.n_unique(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unknown aggregate",
+                "expected one of sum, mean, min, max, first, last, count or n-unique",
+                &aggregate.tag,
+            ))
+        }
+    }
+    .map_err(|error| polars_error(error, &name))?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::DataFrame(NuDataFrame::new(grouped)).into_value(&name),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DfrGroupBy;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(DfrGroupBy {})
+    }
+}
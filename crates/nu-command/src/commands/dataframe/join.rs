@@ -0,0 +1,127 @@
+use super::{as_dataframe, dataframe_from_input, polars_error};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{NuDataFrame, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use polars::prelude::JoinType;
+
+pub struct DfrJoin;
+
+#[derive(Deserialize)]
+pub struct DfrJoinArgs {
+    dataframe: Value,
+    key: Tagged<String>,
+    rest: Vec<Tagged<String>>,
+    left: bool,
+    outer: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DfrJoin {
+    fn name(&self) -> &str {
+        "dfr join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dfr join")
+            .required("dataframe", SyntaxShape::Any, "the dataframe to join with")
+            .required("key", SyntaxShape::String, "the column to join on")
+            .rest(SyntaxShape::String, "more columns to join on")
+            .switch(
+                "left",
+                "keep the rows of the input that have no match",
+                Some('l'),
+            )
+            .switch(
+                "outer",
+                "keep the rows of both that have no match",
+                Some('o'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Joins the rows of the input dataframe with the rows of another that have the same values in the key columns."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Without a flag only the rows with a match are kept (an inner join). The other columns of the
+dataframe given that the input has too get a _right suffix."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        dfr_join(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Add the product details to each sale",
+            example: "let products = (dfr open products.csv); dfr open sales.csv | dfr join $products product_id",
+            result: None,
+        }]
+    }
+}
+
+async fn dfr_join(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (
+        DfrJoinArgs {
+            dataframe,
+            key,
+            rest,
+            left,
+            outer,
+        },
+        input,
+    ) = args.process().await?;
+
+    if left && outer {
+        return Err(ShellError::labeled_error(
+            "Only one kind of join can be given",
+            "use either --left or --outer",
+            &name,
+        ));
+    }
+
+    let right = as_dataframe(dataframe, &name)?.dataframe;
+    let left_dataframe = dataframe_from_input(input, &name).await?.dataframe;
+
+    let keys = std::iter::once(key).chain(rest).collect::<Vec<_>>();
+    for key in &keys {
+        for dataframe in &[&left_dataframe, &right] {
+            dataframe
+                .column(&key.item)
+                .map_err(|error| polars_error(error, &key.tag))?;
+        }
+    }
+
+    let keys = keys.iter().map(|key| key.item.as_str()).collect::<Vec<_>>();
+    let how = if left {
+        JoinType::Left
+    } else if outer {
+        JoinType::Outer
+    } else {
+        JoinType::Inner
+    };
+
+    let joined = left_dataframe
+        .join(&right, &keys, &keys, how)
+        .map_err(|error| polars_error(error, &name))?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::DataFrame(NuDataFrame::new(joined)).into_value(&name),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DfrJoin;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(DfrJoin {})
+    }
+}
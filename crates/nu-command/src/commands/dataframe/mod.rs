@@ -0,0 +1,51 @@
+mod collect;
+mod command;
+mod filter;
+mod group_by;
+mod join;
+mod open;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{NuDataFrame, ShellTypeName, UntaggedValue, Value};
+use polars::prelude::PolarsError;
+
+pub use collect::DfrCollect;
+pub use command::Dfr;
+pub use filter::DfrFilter;
+pub use group_by::DfrGroupBy;
+pub use join::DfrJoin;
+pub use open::DfrOpen;
+
+fn polars_error(error: PolarsError, tag: impl Into<Tag>) -> ShellError {
+    ShellError::labeled_error("Dataframe error", error.to_string(), tag)
+}
+
+/// The dataframe a value holds
+fn as_dataframe(value: Value, tag: &Tag) -> Result<NuDataFrame, ShellError> {
+    match value.value {
+        UntaggedValue::DataFrame(dataframe) => Ok(dataframe),
+        _ => Err(ShellError::labeled_error_with_secondary(
+            "Expected a dataframe",
+            "requires a dataframe",
+            tag,
+            format!("{} originated here", value.type_name()),
+            value.tag,
+        )),
+    }
+}
+
+/// The one dataframe the pipeline holds, eg from 'dfr open'
+async fn dataframe_from_input(input: InputStream, tag: &Tag) -> Result<NuDataFrame, ShellError> {
+    let mut values: Vec<Value> = input.collect().await;
+
+    if values.len() != 1 {
+        return Err(ShellError::labeled_error(
+            "Expected a dataframe from the pipeline",
+            "requires one dataframe as input, eg from 'dfr open'",
+            tag,
+        ));
+    }
+
+    as_dataframe(values.remove(0), tag)
+}
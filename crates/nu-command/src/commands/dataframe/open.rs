@@ -0,0 +1,163 @@
+use super::polars_error;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{NuDataFrame, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use polars::prelude::{CsvReader, DataFrame, ParquetReader, SerReader};
+use std::fs::File;
+use std::path::PathBuf;
+
+pub struct DfrOpen;
+
+#[derive(Deserialize)]
+pub struct DfrOpenArgs {
+    file: Tagged<PathBuf>,
+    delimiter: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "no-header"))]
+    no_header: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DfrOpen {
+    fn name(&self) -> &str {
+        "dfr open"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dfr open")
+            .required(
+                "file",
+                SyntaxShape::FilePath,
+                "the csv or parquet file to open",
+            )
+            .named(
+                "delimiter",
+                SyntaxShape::String,
+                "the character between the values of a csv file (default: ',')",
+                Some('d'),
+            )
+            .switch(
+                "no-header",
+                "the first row of the csv file isn't the column names",
+                Some('n'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Opens a csv or parquet file as a dataframe."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The file is read straight into columns without making a Nu value for each cell, which makes
+it the way in for files too large to work on as tables."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        dfr_open(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Open a csv file as a dataframe",
+                example: "dfr open sales.csv",
+                result: None,
+            },
+            Example {
+                description: "Open a tab separated file without column names",
+                example: "dfr open measurements.tsv --delimiter \"\\t\" --no-header",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn dfr_open(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = EvaluationContext::from_args(&args);
+    let (
+        DfrOpenArgs {
+            file,
+            delimiter,
+            no_header,
+        },
+        _,
+    ) = args.process().await?;
+
+    // Relative paths are relative to the directory the shell is in
+    let path = PathBuf::from(context.shell_manager.path()).join(&file.item);
+    let file = path.tagged(&file.tag);
+
+    let extension = file
+        .item
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_lowercase());
+
+    let dataframe = match extension.as_deref() {
+        Some("parquet") => open_parquet(&file)?,
+        Some("csv") | Some("tsv") | Some("txt") => open_csv(&file, delimiter, no_header)?,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unknown file type",
+                "expected a csv or parquet file",
+                &file.tag,
+            ))
+        }
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::DataFrame(NuDataFrame::new(dataframe)).into_value(&file.tag),
+    )))
+}
+
+fn open_parquet(file: &Tagged<PathBuf>) -> Result<DataFrame, ShellError> {
+    let reader = File::open(&file.item).map_err(|error| {
+        ShellError::labeled_error("Could not open file", error.to_string(), &file.tag)
+    })?;
+
+    ParquetReader::new(reader)
+        .finish()
+        .map_err(|error| polars_error(error, &file.tag))
+}
+
+fn open_csv(
+    file: &Tagged<PathBuf>,
+    delimiter: Option<Tagged<String>>,
+    no_header: bool,
+) -> Result<DataFrame, ShellError> {
+    let delimiter = match delimiter {
+        None => b',',
+        Some(delimiter) => match delimiter.item.as_bytes() {
+            [byte] => *byte,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected a single character delimiter",
+                    "expected one character",
+                    &delimiter.tag,
+                ))
+            }
+        },
+    };
+
+    CsvReader::from_path(&file.item)
+        .map_err(|error| polars_error(error, &file.tag))?
+        .with_delimiter(delimiter)
+        .has_header(!no_header)
+        .infer_schema(Some(100))
+        .finish()
+        .map_err(|error| polars_error(error, &file.tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DfrOpen;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(DfrOpen {})
+    }
+}
@@ -0,0 +1,201 @@
+use crate::prelude::*;
+use chrono::{DateTime, FixedOffset};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    date_distance, format_date, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+
+pub struct Date;
+
+#[derive(Deserialize)]
+struct DateHumanizeArgs {
+    locale: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Date {
+    fn name(&self) -> &str {
+        "date humanize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date humanize").named(
+            "locale",
+            SyntaxShape::String,
+            "the language to use, eg de or fr_FR (defaults to the one in $LANG)",
+            Some('l'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Print how long ago or from now a date is, eg 3 hours ago."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The supported languages are English (en), German (de), Spanish (es) and French (fr). Any other language in $LANG falls back to English."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        humanize(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Print how long ago a file was modified",
+                example: "ls | get modified | first | date humanize",
+                result: None,
+            },
+            Example {
+                description: "Print how long ago a file was modified, in German",
+                example: "ls | get modified | first | date humanize --locale de",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn humanize(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let (DateHumanizeArgs { locale }, input) = args.process().await?;
+
+    let language = match locale {
+        Some(locale) => Language::from_locale(&locale.item).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Unsupported locale",
+                "expected en, de, es or fr",
+                &locale.tag,
+            )
+        })?,
+        None => ["LC_ALL", "LC_TIME", "LANG"]
+            .iter()
+            .find_map(|name| std::env::var(name).ok())
+            .and_then(|locale| Language::from_locale(&locale))
+            .unwrap_or(Language::English),
+    };
+
+    Ok(input
+        .map(move |value| match value {
+            Value {
+                value: UntaggedValue::Primitive(Primitive::Date(dt)),
+                ..
+            } => {
+                ReturnSuccess::value(UntaggedValue::string(language.humanize(&dt)).into_value(&tag))
+            }
+            _ => Err(ShellError::labeled_error(
+                "Expected a date from pipeline",
+                "requires date input",
+                &tag,
+            )),
+        })
+        .to_output_stream())
+}
+
+#[derive(Clone, Copy)]
+enum Language {
+    English,
+    German,
+    Spanish,
+    French,
+}
+
+impl Language {
+    /// The language of a locale like `de`, `de_DE.UTF-8` or `de-AT`
+    fn from_locale(locale: &str) -> Option<Language> {
+        let language = locale
+            .split(|c| c == '_' || c == '-' || c == '.')
+            .next()
+            .unwrap_or_default();
+
+        match language.to_lowercase().as_str() {
+            "en" | "c" | "posix" => Some(Language::English),
+            "de" => Some(Language::German),
+            "es" => Some(Language::Spanish),
+            "fr" => Some(Language::French),
+            _ => None,
+        }
+    }
+
+    fn humanize(self, date: &DateTime<FixedOffset>) -> String {
+        let (amount, unit) = date_distance(date);
+        let (singular, plural) = match self {
+            Language::English => return format_date(date),
+            Language::German => match unit {
+                "year" => ("Jahr", "Jahren"),
+                "month" => ("Monat", "Monaten"),
+                "week" => ("Woche", "Wochen"),
+                "day" => ("Tag", "Tagen"),
+                "hour" => ("Stunde", "Stunden"),
+                "minute" => ("Minute", "Minuten"),
+                _ => ("Sekunde", "Sekunden"),
+            },
+            Language::Spanish => match unit {
+                "year" => ("año", "años"),
+                "month" => ("mes", "meses"),
+                "week" => ("semana", "semanas"),
+                "day" => ("día", "días"),
+                "hour" => ("hora", "horas"),
+                "minute" => ("minuto", "minutos"),
+                _ => ("segundo", "segundos"),
+            },
+            Language::French => match unit {
+                "year" => ("an", "ans"),
+                "month" => ("mois", "mois"),
+                "week" => ("semaine", "semaines"),
+                "day" => ("jour", "jours"),
+                "hour" => ("heure", "heures"),
+                "minute" => ("minute", "minutes"),
+                _ => ("seconde", "secondes"),
+            },
+        };
+
+        let count = amount.abs();
+        let unit = if count == 1 { singular } else { plural };
+
+        match (self, amount < 0) {
+            (Language::German, false) => format!("vor {} {}", count, unit),
+            (Language::German, true) => format!("in {} {}", count, unit),
+            (Language::Spanish, false) => format!("hace {} {}", count, unit),
+            (Language::Spanish, true) => format!("dentro de {} {}", count, unit),
+            (Language::French, false) => format!("il y a {} {}", count, unit),
+            (_, _) => format!("dans {} {}", count, unit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Date;
+    use super::Language;
+    use super::ShellError;
+    use chrono::{DateTime, Duration, FixedOffset, Local};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Date {})
+    }
+
+    #[test]
+    fn humanizes_in_the_language_of_the_locale() {
+        let now = Local::now();
+        let three_hours_ago = (now - Duration::minutes(185)).with_timezone(now.offset());
+        let in_two_days = (now + Duration::hours(50)).with_timezone(now.offset());
+
+        fn humanize(locale: &str, date: &DateTime<FixedOffset>) -> String {
+            Language::from_locale(locale)
+                .expect("supported locale")
+                .humanize(date)
+        }
+
+        assert_eq!(humanize("en_US.UTF-8", &three_hours_ago), "3 hours ago");
+        assert_eq!(humanize("de", &three_hours_ago), "vor 3 Stunden");
+        assert_eq!(humanize("es-MX", &in_two_days), "dentro de 2 días");
+        assert_eq!(humanize("fr_FR", &in_two_days), "dans 2 jours");
+        assert!(Language::from_locale("xx").is_none());
+    }
+}
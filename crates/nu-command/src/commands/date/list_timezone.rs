@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use chrono::{Offset, Utc};
 use chrono_tz::TZ_VARIANTS;
 use indexmap::IndexMap;
 use nu_engine::WholeStreamCommand;
@@ -18,7 +19,7 @@ impl WholeStreamCommand for Date {
     }
 
     fn usage(&self) -> &str {
-        "List supported time zones."
+        "List supported time zones with their current offset from UTC."
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -37,6 +38,11 @@ impl WholeStreamCommand for Date {
                 example: "date list-timezone | where timezone =~ Europe",
                 result: None,
             },
+            Example {
+                description: "List the time zones currently at UTC+01:00",
+                example: "date list-timezone | where offset == '+01:00'",
+                result: None,
+            },
         ]
     }
 }
@@ -45,6 +51,7 @@ async fn list_timezone(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once().await?;
     let tag = args.call_info.name_tag.clone();
 
+    let now = Utc::now();
     let list = TZ_VARIANTS.iter().map(move |tz| {
         let mut entries = IndexMap::new();
 
@@ -52,6 +59,11 @@ async fn list_timezone(args: CommandArgs) -> Result<OutputStream, ShellError> {
             "timezone".to_string(),
             UntaggedValue::string(tz.name()).into_value(&tag),
         );
+        entries.insert(
+            "offset".to_string(),
+            UntaggedValue::string(now.with_timezone(tz).offset().fix().to_string())
+                .into_value(&tag),
+        );
 
         Ok(ReturnSuccess::Value(
             UntaggedValue::Row(Dictionary { entries }).into_value(&tag),
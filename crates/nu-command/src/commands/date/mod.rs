@@ -1,7 +1,9 @@
 pub mod command;
 pub mod format;
+pub mod humanize;
 pub mod list_timezone;
 pub mod now;
+pub mod to_record;
 pub mod to_table;
 pub mod to_timezone;
 
@@ -9,7 +11,9 @@ mod parser;
 
 pub use command::Command as Date;
 pub use format::Date as DateFormat;
+pub use humanize::Date as DateHumanize;
 pub use list_timezone::Date as DateListTimeZone;
 pub use now::Date as DateNow;
+pub use to_record::Date as DateToRecord;
 pub use to_table::Date as DateToTable;
 pub use to_timezone::Date as DateToTimeZone;
@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
+use indexmap::IndexMap;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+
+pub struct Date;
+
+#[async_trait]
+impl WholeStreamCommand for Date {
+    fn name(&self) -> &str {
+        "date to-record"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date to-record")
+    }
+
+    fn usage(&self) -> &str {
+        "Split the date into a record of its year, month, day, hour, minute, second and timezone."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        to_record(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split the current date into a record",
+                example: "date now | date to-record",
+                result: None,
+            },
+            Example {
+                description: "Group the dates the files were modified on by their month",
+                example: "ls | each { get modified | date to-record | select year month } | group-by month",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn to_record(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once().await?;
+    let tag = args.call_info.name_tag.clone();
+    let input = args.input;
+
+    Ok(input
+        .map(move |value| match value {
+            Value {
+                value: UntaggedValue::Primitive(Primitive::Date(dt)),
+                ..
+            } => ReturnSuccess::value(date_to_record(&dt, &tag)),
+            _ => Err(ShellError::labeled_error(
+                "Expected a date from pipeline",
+                "requires date input",
+                &tag,
+            )),
+        })
+        .to_output_stream())
+}
+
+pub(crate) fn date_to_record(dt: &DateTime<FixedOffset>, tag: &Tag) -> Value {
+    let mut indexmap = IndexMap::new();
+
+    indexmap.insert(
+        "year".to_string(),
+        UntaggedValue::int(dt.year()).into_value(tag),
+    );
+    indexmap.insert(
+        "month".to_string(),
+        UntaggedValue::int(dt.month()).into_value(tag),
+    );
+    indexmap.insert(
+        "day".to_string(),
+        UntaggedValue::int(dt.day()).into_value(tag),
+    );
+    indexmap.insert(
+        "hour".to_string(),
+        UntaggedValue::int(dt.hour()).into_value(tag),
+    );
+    indexmap.insert(
+        "minute".to_string(),
+        UntaggedValue::int(dt.minute()).into_value(tag),
+    );
+    indexmap.insert(
+        "second".to_string(),
+        UntaggedValue::int(dt.second()).into_value(tag),
+    );
+
+    let tz = dt.offset();
+    indexmap.insert(
+        "timezone".to_string(),
+        UntaggedValue::string(format!("{}", tz)).into_value(tag),
+    );
+
+    UntaggedValue::Row(Dictionary::from(indexmap)).into_value(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Date;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Date {})
+    }
+}
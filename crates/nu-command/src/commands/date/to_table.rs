@@ -1,9 +1,8 @@
+use crate::commands::date::to_record::date_to_record;
 use crate::prelude::*;
-use chrono::{Datelike, Timelike};
-use indexmap::IndexMap;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Dictionary, Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
 
 pub struct Date;
 
@@ -44,44 +43,7 @@ async fn to_table(args: CommandArgs) -> Result<OutputStream, ShellError> {
             Value {
                 value: UntaggedValue::Primitive(Primitive::Date(dt)),
                 ..
-            } => {
-                let mut indexmap = IndexMap::new();
-
-                indexmap.insert(
-                    "year".to_string(),
-                    UntaggedValue::int(dt.year()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "month".to_string(),
-                    UntaggedValue::int(dt.month()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "day".to_string(),
-                    UntaggedValue::int(dt.day()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "hour".to_string(),
-                    UntaggedValue::int(dt.hour()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "minute".to_string(),
-                    UntaggedValue::int(dt.minute()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "second".to_string(),
-                    UntaggedValue::int(dt.second()).into_value(&tag),
-                );
-
-                let tz = dt.offset();
-                indexmap.insert(
-                    "timezone".to_string(),
-                    UntaggedValue::string(format!("{}", tz)).into_value(&tag),
-                );
-
-                let value = UntaggedValue::Row(Dictionary::from(indexmap)).into_value(&tag);
-
-                ReturnSuccess::value(value)
-            }
+            } => ReturnSuccess::value(date_to_record(&dt, &tag)),
             _ => Err(ShellError::labeled_error(
                 "Expected a date from pipeline",
                 "requires date input",
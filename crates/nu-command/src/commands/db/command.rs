@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Db;
+
+#[async_trait]
+impl WholeStreamCommand for Db {
+    fn name(&self) -> &str {
+        "db"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("db")
+    }
+
+    fn usage(&self) -> &str {
+        "Query Postgres and MySQL databases."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(get_full_help(&Db, &args.scope)).into_value(Tag::unknown()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Db;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Db {})
+    }
+}
@@ -0,0 +1,225 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tag;
+use url::Url;
+
+/// Passwords left out of a connection url are looked up in the system keyring under this
+/// service, with `user@host` as the account name.
+const KEYRING_SERVICE: &str = "nushell-db";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Backend {
+    Postgres,
+    MySql,
+}
+
+impl Backend {
+    fn from_scheme(scheme: &str) -> Option<Backend> {
+        match scheme {
+            "postgres" | "postgresql" => Some(Backend::Postgres),
+            "mysql" => Some(Backend::MySql),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Backend::Postgres => "postgres",
+            Backend::MySql => "mysql",
+        }
+    }
+
+    fn default_port(self) -> u16 {
+        match self {
+            Backend::Postgres => 5432,
+            Backend::MySql => 3306,
+        }
+    }
+}
+
+/// Where to find a database, parsed from a url like `postgres://user@localhost/app`
+#[derive(Debug, Clone)]
+pub(crate) struct Connection {
+    pub(crate) backend: Backend,
+    url: Url,
+    pub(crate) tag: Tag,
+}
+
+impl Connection {
+    pub(crate) fn parse(url: &str, tag: impl Into<Tag>) -> Result<Connection, ShellError> {
+        let tag = tag.into();
+
+        let url = Url::parse(url).map_err(|e| {
+            ShellError::labeled_error("Invalid connection url", e.to_string(), &tag)
+        })?;
+
+        let backend = Backend::from_scheme(url.scheme()).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Unsupported database",
+                format!(
+                    "expected a postgres:// or mysql:// url, got {}://",
+                    url.scheme()
+                ),
+                &tag,
+            )
+        })?;
+
+        if url.host_str().is_none() {
+            return Err(ShellError::labeled_error(
+                "Invalid connection url",
+                "the url has no host",
+                &tag,
+            ));
+        }
+
+        Ok(Connection { backend, url, tag })
+    }
+
+    /// A connection is either a url, or the record made by `db connect`
+    pub(crate) fn from_value(value: &Value) -> Result<Connection, ShellError> {
+        match &value.value {
+            UntaggedValue::Primitive(Primitive::String(url)) => Connection::parse(url, &value.tag),
+            UntaggedValue::Row(dict) => {
+                let url = dict.get_data("url");
+                let url = url.borrow().as_string().map_err(|_| {
+                    ShellError::labeled_error(
+                        "Expected a database connection",
+                        "the record has no url column",
+                        &value.tag,
+                    )
+                })?;
+
+                Connection::parse(&url, &value.tag)
+            }
+            _ => Err(ShellError::labeled_error(
+                "Expected a database connection",
+                "expected a url or the output of 'db connect'",
+                &value.tag,
+            )),
+        }
+    }
+
+    pub(crate) fn host(&self) -> &str {
+        self.url.host_str().unwrap_or_default()
+    }
+
+    pub(crate) fn port(&self) -> u16 {
+        self.url
+            .port()
+            .unwrap_or_else(|| self.backend.default_port())
+    }
+
+    pub(crate) fn user(&self) -> &str {
+        self.url.username()
+    }
+
+    pub(crate) fn database(&self) -> &str {
+        self.url.path().trim_start_matches('/')
+    }
+
+    fn keyring_account(&self) -> String {
+        format!("{}@{}", self.user(), self.host())
+    }
+
+    /// The url to connect with, filling in the password from the keyring if the url doesn't
+    /// carry one
+    pub(crate) fn resolved_url(&self) -> Url {
+        let mut url = self.url.clone();
+
+        if url.password().is_none() && !url.username().is_empty() {
+            let account = self.keyring_account();
+            let keyring = keyring::Keyring::new(KEYRING_SERVICE, &account);
+
+            if let Ok(password) = keyring.get_password() {
+                let _ = url.set_password(Some(&password));
+            }
+        }
+
+        url
+    }
+
+    /// Moves the password out of the url into the keyring, so the connection can be shown and
+    /// kept in variables without it, and is looked up again when the connection is used. When
+    /// there's no keyring to keep it in, the error says why so it can be shown as a warning.
+    pub(crate) fn store_password(&mut self) -> Result<(), String> {
+        let password = match self.url.password() {
+            Some(password) => password.to_string(),
+            None => return Ok(()),
+        };
+
+        let account = self.keyring_account();
+        let stored = keyring::Keyring::new(KEYRING_SERVICE, &account).set_password(&password);
+
+        let _ = self.url.set_password(None);
+
+        stored.map_err(|e| {
+            format!(
+                "could not keep the password for {} in the keyring ({}), so it is left out of \
+                 the connection; put it in the keyring under the service '{}' to use it later",
+                account, e, KEYRING_SERVICE
+            )
+        })
+    }
+
+    pub(crate) fn into_value(self) -> Value {
+        let mut dict = TaggedDictBuilder::new(&self.tag);
+
+        dict.insert_untagged("backend", UntaggedValue::string(self.backend.name()));
+        dict.insert_untagged("host", UntaggedValue::string(self.host()));
+        dict.insert_untagged("port", UntaggedValue::int(self.port()));
+        dict.insert_untagged("user", UntaggedValue::string(self.user()));
+        dict.insert_untagged("database", UntaggedValue::string(self.database()));
+
+        // The password is never shown or kept, it's looked up when the connection is used
+        let mut url = self.url;
+        let _ = url.set_password(None);
+        dict.insert_untagged("url", UntaggedValue::string(url.as_str()));
+
+        dict.into_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Backend, Connection};
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+
+    #[test]
+    fn parses_connection_urls() {
+        let connection =
+            Connection::parse("postgres://nu@db.local/app", Tag::unknown()).expect("valid url");
+
+        assert_eq!(connection.backend, Backend::Postgres);
+        assert_eq!(connection.host(), "db.local");
+        assert_eq!(connection.port(), 5432);
+        assert_eq!(connection.user(), "nu");
+        assert_eq!(connection.database(), "app");
+
+        let connection = Connection::parse("mysql://root:pw@127.0.0.1:3307/shop", Tag::unknown())
+            .expect("valid url");
+
+        assert_eq!(connection.backend, Backend::MySql);
+        assert_eq!(connection.port(), 3307);
+    }
+
+    #[test]
+    fn the_connection_record_leaves_out_the_password() {
+        let connection = Connection::parse("mysql://root:pw@127.0.0.1:3307/shop", Tag::unknown())
+            .expect("valid url");
+
+        let record = connection.into_value();
+        let url = match &record.value {
+            UntaggedValue::Row(dict) => dict.get_data("url").borrow().as_string(),
+            _ => panic!("expected a record"),
+        };
+
+        assert_eq!(url.expect("url column"), "mysql://root@127.0.0.1:3307/shop");
+    }
+
+    #[test]
+    fn rejects_other_schemes() {
+        assert!(Connection::parse("redis://localhost", Tag::unknown()).is_err());
+        assert!(Connection::parse("not a url", Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+mod command;
+mod connect;
+mod connection;
+mod mysql_;
+mod postgres_;
+mod query;
+
+pub use command::Db;
+pub use connect::DbConnect;
+pub use query::DbQuery;
@@ -0,0 +1,170 @@
+use super::connection::Connection;
+use crate::utils::json::value_to_json_value;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Timelike};
+use mysql::consts::ColumnType;
+use mysql::prelude::Queryable;
+use mysql::{Column, Conn, Opts, Params};
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tag;
+use num_traits::ToPrimitive;
+use std::str::FromStr;
+use std::sync::mpsc::SyncSender;
+
+/// The character set MySQL reports for binary (rather than text) columns
+const BINARY_CHARSET: u16 = 63;
+
+fn mysql_error(error: impl std::fmt::Display, tag: &Tag) -> ShellError {
+    ShellError::labeled_error("MySQL error", error.to_string(), tag)
+}
+
+pub(crate) fn connect(connection: &Connection) -> Result<Conn, ShellError> {
+    let opts = Opts::from_url(connection.resolved_url().as_str())
+        .map_err(|e| mysql_error(e, &connection.tag))?;
+
+    Conn::new(opts).map_err(|e| mysql_error(e, &connection.tag))
+}
+
+/// Runs the query, sending each row down `rows` as soon as it arrives so large results
+/// never have to fit in memory
+pub(crate) fn query(
+    connection: &Connection,
+    sql: &str,
+    params: &[Value],
+    tag: &Tag,
+    rows: &SyncSender<Result<Value, ShellError>>,
+) -> Result<(), ShellError> {
+    let mut conn = connect(connection)?;
+
+    let params = if params.is_empty() {
+        Params::Empty
+    } else {
+        Params::Positional(params.iter().map(to_param).collect::<Result<_, _>>()?)
+    };
+
+    let results = conn
+        .exec_iter(sql, params)
+        .map_err(|e| mysql_error(e, tag))?;
+    let columns: Vec<Column> = results.columns().as_ref().to_vec();
+
+    for row in results {
+        let row = row.map_err(|e| mysql_error(e, tag))?;
+        let mut dict = TaggedDictBuilder::new(tag);
+
+        for (column, value) in columns.iter().zip(row.unwrap()) {
+            dict.insert_value(
+                column.name_str().to_string(),
+                column_value(value, column, tag),
+            );
+        }
+
+        if rows.send(Ok(dict.into_value())).is_err() {
+            // Nobody is reading the rest of the rows anymore
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn column_value(value: mysql::Value, column: &Column, tag: &Tag) -> Value {
+    let value = match value {
+        mysql::Value::NULL => UntaggedValue::nothing(),
+        mysql::Value::Int(i) => UntaggedValue::int(i),
+        mysql::Value::UInt(u) => UntaggedValue::int(u),
+        mysql::Value::Float(f) => UntaggedValue::decimal_from_float(f as f64, tag.span),
+        mysql::Value::Double(f) => UntaggedValue::decimal_from_float(f, tag.span),
+        mysql::Value::Date(year, month, day, hour, minute, second, micros) => {
+            // Zero dates like 0000-00-00 have no calendar equivalent
+            match NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32).and_then(|date| {
+                date.and_hms_micro_opt(hour as u32, minute as u32, second as u32, micros)
+            }) {
+                Some(date) => UntaggedValue::date(DateTime::<FixedOffset>::from_utc(
+                    date,
+                    FixedOffset::east(0),
+                )),
+                None => UntaggedValue::nothing(),
+            }
+        }
+        mysql::Value::Time(negative, days, hours, minutes, seconds, micros) => {
+            let secs =
+                ((days as i64 * 24 + hours as i64) * 60 + minutes as i64) * 60 + seconds as i64;
+            let nanos = secs * 1_000_000_000 + micros as i64 * 1_000;
+
+            UntaggedValue::duration(if negative { -nanos } else { nanos })
+        }
+        mysql::Value::Bytes(bytes) => match column.column_type() {
+            ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL => {
+                match std::str::from_utf8(&bytes)
+                    .ok()
+                    .and_then(|text| BigDecimal::from_str(text).ok())
+                {
+                    Some(decimal) => UntaggedValue::decimal(decimal),
+                    None => UntaggedValue::binary(bytes),
+                }
+            }
+            _ if column.character_set() == BINARY_CHARSET => UntaggedValue::binary(bytes),
+            _ => match String::from_utf8(bytes) {
+                Ok(text) => UntaggedValue::string(text),
+                Err(e) => UntaggedValue::binary(e.into_bytes()),
+            },
+        },
+    };
+
+    value.into_value(tag)
+}
+
+fn to_param(value: &Value) -> Result<mysql::Value, ShellError> {
+    let out_of_range = || {
+        ShellError::labeled_error(
+            "Value too large for MySQL",
+            "doesn't fit in a 64-bit integer",
+            &value.tag,
+        )
+    };
+
+    Ok(match &value.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => mysql::Value::NULL,
+        UntaggedValue::Primitive(Primitive::Int(i))
+        | UntaggedValue::Primitive(Primitive::Filesize(i))
+        | UntaggedValue::Primitive(Primitive::Duration(i)) => {
+            mysql::Value::Int(i.to_i64().ok_or_else(out_of_range)?)
+        }
+        UntaggedValue::Primitive(Primitive::Boolean(b)) => mysql::Value::Int(*b as i64),
+        // Sent as text so no precision is lost on the way to a DECIMAL column
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            mysql::Value::Bytes(d.to_string().into_bytes())
+        }
+        UntaggedValue::Primitive(Primitive::Binary(bytes)) => mysql::Value::Bytes(bytes.clone()),
+        UntaggedValue::Primitive(Primitive::Date(date)) => {
+            let date = date.naive_utc();
+
+            mysql::Value::Date(
+                date.year() as u16,
+                date.month() as u8,
+                date.day() as u8,
+                date.hour() as u8,
+                date.minute() as u8,
+                date.second() as u8,
+                date.nanosecond() / 1_000,
+            )
+        }
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => {
+            // Nested data is sent as JSON text
+            let json = value_to_json_value(value)?;
+            mysql::Value::Bytes(
+                serde_json::to_string(&json)
+                    .map_err(|e| {
+                        ShellError::labeled_error(
+                            "Could not convert to JSON",
+                            e.to_string(),
+                            &value.tag,
+                        )
+                    })?
+                    .into_bytes(),
+            )
+        }
+        _ => mysql::Value::Bytes(value.convert_to_string().into_bytes()),
+    })
+}
@@ -0,0 +1,340 @@
+use super::connection::Connection;
+use crate::utils::json::value_to_json_value;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tag;
+use num_bigint::BigInt;
+use num_traits::{ToPrimitive, Zero};
+use postgres::fallible_iterator::FallibleIterator;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use postgres::{Client, NoTls, Row};
+use std::error::Error;
+use std::sync::mpsc::SyncSender;
+
+type BoxedError = Box<dyn Error + Sync + Send>;
+
+fn postgres_error(error: postgres::Error, tag: &Tag) -> ShellError {
+    ShellError::labeled_error("Postgres error", error.to_string(), tag)
+}
+
+pub(crate) fn connect(connection: &Connection) -> Result<Client, ShellError> {
+    Client::connect(connection.resolved_url().as_str(), NoTls)
+        .map_err(|e| postgres_error(e, &connection.tag))
+}
+
+/// Runs the query, sending each row down `rows` as soon as it arrives so large results
+/// never have to fit in memory
+pub(crate) fn query(
+    connection: &Connection,
+    sql: &str,
+    params: &[Value],
+    tag: &Tag,
+    rows: &SyncSender<Result<Value, ShellError>>,
+) -> Result<(), ShellError> {
+    let mut client = connect(connection)?;
+    let statement = client.prepare(sql).map_err(|e| postgres_error(e, tag))?;
+
+    if statement.params().len() != params.len() {
+        return Err(ShellError::labeled_error(
+            "Wrong number of query parameters",
+            format!(
+                "the query takes {} parameters, got {}",
+                statement.params().len(),
+                params.len()
+            ),
+            tag,
+        ));
+    }
+
+    let params = statement
+        .params()
+        .iter()
+        .zip(params)
+        .map(|(kind, value)| to_param(value, kind))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let columns: Vec<(String, Type)> = statement
+        .columns()
+        .iter()
+        .map(|column| (column.name().to_string(), column.type_().clone()))
+        .collect();
+
+    let mut results = client
+        .query_raw(
+            &statement,
+            params.iter().map(|param| param.as_ref() as &dyn ToSql),
+        )
+        .map_err(|e| postgres_error(e, tag))?;
+
+    while let Some(row) = results.next().map_err(|e| postgres_error(e, tag))? {
+        let mut dict = TaggedDictBuilder::new(tag);
+
+        for (idx, (name, kind)) in columns.iter().enumerate() {
+            dict.insert_value(name.clone(), column_value(&row, idx, kind, tag)?);
+        }
+
+        if rows.send(Ok(dict.into_value())).is_err() {
+            // Nobody is reading the rest of the rows anymore
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn get<'a, T: FromSql<'a>>(row: &'a Row, idx: usize, tag: &Tag) -> Result<Option<T>, ShellError> {
+    row.try_get(idx).map_err(|e| postgres_error(e, tag))
+}
+
+fn column_value(row: &Row, idx: usize, kind: &Type, tag: &Tag) -> Result<Value, ShellError> {
+    let span = tag.span;
+
+    let value = match *kind {
+        Type::BOOL => get::<bool>(row, idx, tag)?.map(UntaggedValue::boolean),
+        Type::INT2 => get::<i16>(row, idx, tag)?.map(UntaggedValue::int),
+        Type::INT4 => get::<i32>(row, idx, tag)?.map(UntaggedValue::int),
+        Type::INT8 => get::<i64>(row, idx, tag)?.map(UntaggedValue::int),
+        Type::OID => get::<u32>(row, idx, tag)?.map(UntaggedValue::int),
+        Type::FLOAT4 => {
+            get::<f32>(row, idx, tag)?.map(|f| UntaggedValue::decimal_from_float(f as f64, span))
+        }
+        Type::FLOAT8 => {
+            get::<f64>(row, idx, tag)?.map(|f| UntaggedValue::decimal_from_float(f, span))
+        }
+        Type::NUMERIC => get::<Numeric>(row, idx, tag)?.map(|n| UntaggedValue::decimal(n.0)),
+        Type::BYTEA => get::<Vec<u8>>(row, idx, tag)?.map(UntaggedValue::binary),
+        Type::TIMESTAMPTZ => get::<DateTime<FixedOffset>>(row, idx, tag)?.map(UntaggedValue::date),
+        Type::TIMESTAMP => get::<NaiveDateTime>(row, idx, tag)?.map(|date| {
+            UntaggedValue::date(DateTime::<FixedOffset>::from_utc(
+                date,
+                FixedOffset::east(0),
+            ))
+        }),
+        Type::DATE => get::<NaiveDate>(row, idx, tag)?.map(|date| {
+            UntaggedValue::date(DateTime::<FixedOffset>::from_utc(
+                date.and_time(NaiveTime::from_hms(0, 0, 0)),
+                FixedOffset::east(0),
+            ))
+        }),
+        Type::JSON | Type::JSONB => {
+            get::<serde_json::Value>(row, idx, tag)?.map(|json| json_to_value(json, tag).value)
+        }
+        _ => match row.try_get::<_, Option<String>>(idx) {
+            Ok(text) => text.map(UntaggedValue::string),
+            Err(_) => {
+                return Err(ShellError::labeled_error(
+                    "Unsupported column type",
+                    format!(
+                        "can't read '{}' values, cast the column to text in the query",
+                        kind
+                    ),
+                    tag,
+                ))
+            }
+        },
+    };
+
+    Ok(value.unwrap_or_else(UntaggedValue::nothing).into_value(tag))
+}
+
+fn json_to_value(json: serde_json::Value, tag: &Tag) -> Value {
+    match json {
+        serde_json::Value::Null => UntaggedValue::nothing().into_value(tag),
+        serde_json::Value::Bool(b) => UntaggedValue::boolean(b).into_value(tag),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => UntaggedValue::int(i).into_value(tag),
+            None => UntaggedValue::decimal_from_float(n.as_f64().unwrap_or_default(), tag.span)
+                .into_value(tag),
+        },
+        serde_json::Value::String(s) => UntaggedValue::string(s).into_value(tag),
+        serde_json::Value::Array(items) => UntaggedValue::Table(
+            items
+                .into_iter()
+                .map(|item| json_to_value(item, tag))
+                .collect(),
+        )
+        .into_value(tag),
+        serde_json::Value::Object(entries) => {
+            let mut dict = TaggedDictBuilder::new(tag);
+            for (key, value) in entries {
+                dict.insert_value(key, json_to_value(value, tag));
+            }
+            dict.into_value()
+        }
+    }
+}
+
+/// A NUMERIC read from its binary form: digit count, weight, sign and display scale, followed
+/// by the digits in base 10000
+struct Numeric(BigDecimal);
+
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xC000;
+
+impl<'a> FromSql<'a> for Numeric {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Numeric, BoxedError> {
+        let read = |idx: usize| -> Result<u16, BoxedError> {
+            match raw.get(idx..idx + 2) {
+                Some(bytes) => Ok(u16::from_be_bytes([bytes[0], bytes[1]])),
+                None => Err("invalid numeric value".into()),
+            }
+        };
+
+        let ndigits = read(0)? as usize;
+        let weight = read(2)? as i16 as i64;
+        let sign = read(4)?;
+        let dscale = read(6)? as i64;
+
+        if sign == NUMERIC_NAN {
+            return Err("NaN can't be represented as a decimal".into());
+        }
+
+        let mut digits = BigInt::zero();
+        for idx in 0..ndigits {
+            digits = digits * 10_000 + read(8 + idx * 2)?;
+        }
+
+        // The first digit group is worth 10000^weight
+        let scale = (ndigits as i64 - weight - 1) * 4;
+        let mut value = BigDecimal::new(digits, scale).with_scale(dscale);
+
+        if sign == NUMERIC_NEG {
+            value = -value;
+        }
+
+        Ok(Numeric(value))
+    }
+
+    fn accepts(kind: &Type) -> bool {
+        *kind == Type::NUMERIC
+    }
+}
+
+/// A NULL parameter, which fits whatever type the query expects
+#[derive(Debug)]
+struct Null;
+
+impl ToSql for Null {
+    fn to_sql(&self, _: &Type, _: &mut bytes::BytesMut) -> Result<IsNull, BoxedError> {
+        Ok(IsNull::Yes)
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+/// Converts a parameter to the type the server expects at its position in the query
+fn to_param(value: &Value, kind: &Type) -> Result<Box<dyn ToSql + Sync>, ShellError> {
+    let mismatch = || {
+        ShellError::labeled_error(
+            "Wrong query parameter type",
+            format!("the query expects a '{}' value here", kind),
+            &value.tag,
+        )
+    };
+
+    let int = || match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i))
+        | UntaggedValue::Primitive(Primitive::Filesize(i))
+        | UntaggedValue::Primitive(Primitive::Duration(i)) => Ok(i),
+        _ => Err(mismatch()),
+    };
+
+    let float = || match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_f64().ok_or_else(mismatch),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64().ok_or_else(mismatch),
+        _ => Err(mismatch()),
+    };
+
+    let date = || match &value.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => Ok(*date),
+        _ => Err(mismatch()),
+    };
+
+    if let UntaggedValue::Primitive(Primitive::Nothing) = &value.value {
+        return Ok(Box::new(Null));
+    }
+
+    let param: Box<dyn ToSql + Sync> = match *kind {
+        Type::BOOL => match &value.value {
+            UntaggedValue::Primitive(Primitive::Boolean(b)) => Box::new(*b),
+            _ => return Err(mismatch()),
+        },
+        Type::INT2 => Box::new(int()?.to_i16().ok_or_else(mismatch)?),
+        Type::INT4 => Box::new(int()?.to_i32().ok_or_else(mismatch)?),
+        Type::INT8 => Box::new(int()?.to_i64().ok_or_else(mismatch)?),
+        Type::OID => Box::new(int()?.to_u32().ok_or_else(mismatch)?),
+        Type::FLOAT4 => Box::new(float()? as f32),
+        Type::FLOAT8 => Box::new(float()?),
+        Type::BYTEA => match &value.value {
+            UntaggedValue::Primitive(Primitive::Binary(bytes)) => Box::new(bytes.clone()),
+            UntaggedValue::Primitive(Primitive::String(s)) => Box::new(s.clone().into_bytes()),
+            _ => return Err(mismatch()),
+        },
+        Type::TIMESTAMPTZ => Box::new(date()?),
+        Type::TIMESTAMP => Box::new(date()?.naive_utc()),
+        Type::DATE => Box::new(date()?.naive_utc().date()),
+        Type::JSON | Type::JSONB => {
+            let json = value_to_json_value(value)?;
+            Box::new(serde_json::to_value(&json).map_err(|e| {
+                ShellError::labeled_error("Could not convert to JSON", e.to_string(), &value.tag)
+            })?)
+        }
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::UNKNOWN => {
+            Box::new(value.convert_to_string())
+        }
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unsupported query parameter type",
+                format!(
+                    "can't send '{}' values, pass text and cast it in the query (eg $1::text::{})",
+                    kind, kind
+                ),
+                &value.tag,
+            ))
+        }
+    };
+
+    Ok(param)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Numeric;
+    use bigdecimal::BigDecimal;
+    use postgres::types::{FromSql, Type};
+    use std::str::FromStr;
+
+    fn numeric(words: &[u16]) -> BigDecimal {
+        let raw: Vec<u8> = words
+            .iter()
+            .flat_map(|word| word.to_be_bytes().to_vec())
+            .collect();
+
+        Numeric::from_sql(&Type::NUMERIC, &raw)
+            .expect("valid numeric")
+            .0
+    }
+
+    #[test]
+    fn reads_binary_numerics() {
+        // 12.5: two digit groups [12, 5000], weight 0, one decimal shown
+        assert_eq!(
+            numeric(&[2, 0, 0, 1, 12, 5000]),
+            BigDecimal::from_str("12.5").expect("decimal")
+        );
+
+        // -1000000.25: groups [100, 0, 2500], weight 1
+        assert_eq!(
+            numeric(&[3, 1, 0x4000, 2, 100, 0, 2500]),
+            BigDecimal::from_str("-1000000.25").expect("decimal")
+        );
+
+        assert_eq!(numeric(&[0, 0, 0, 0]), BigDecimal::from(0));
+    }
+}
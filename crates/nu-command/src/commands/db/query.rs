@@ -0,0 +1,122 @@
+use super::connection::{Backend, Connection};
+use super::{mysql_, postgres_};
+use crate::futures::ThreadedReceiver;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use std::sync::mpsc;
+
+pub struct DbQuery;
+
+#[derive(Deserialize)]
+pub struct DbQueryArgs {
+    sql: Tagged<String>,
+    params: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DbQuery {
+    fn name(&self) -> &str {
+        "db query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("db query")
+            .required("sql", SyntaxShape::String, "the query to run")
+            .named(
+                "params",
+                SyntaxShape::Any,
+                "values for the query's placeholders ($1, $2.. in Postgres, ? in MySQL)",
+                Some('p'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a query against the database connection from the pipeline."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The connection is either a url or the record made by 'db connect'. Rows are streamed as the
+database sends them, with column types mapped to Nu's: dates stay dates, decimals keep their
+precision and binary columns become binary values."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        db_query(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List the tables of a Postgres database",
+                example: "db connect postgres://nu@localhost/app | db query 'select tablename from pg_tables'",
+                result: None,
+            },
+            Example {
+                description: "Pass values to a query instead of pasting them into it",
+                example: "echo mysql://root@localhost/shop | db query 'select * from orders where total > ? and status = ?' --params [100 shipped]",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn db_query(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (DbQueryArgs { sql, params }, input) = args.process().await?;
+    let input: Vec<Value> = input.collect().await;
+
+    let connection = match input.as_slice() {
+        [value] => Connection::from_value(value)?,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a database connection",
+                "pipe in a url or the output of 'db connect'",
+                &name_tag,
+            ))
+        }
+    };
+
+    let params = match params {
+        None => vec![],
+        Some(Value {
+            value: UntaggedValue::Table(values),
+            ..
+        }) => values,
+        Some(value) => vec![value],
+    };
+
+    // The database clients block, so the rows are read on their own thread and handed over
+    // one at a time as the pipeline asks for them
+    let (tx, rx) = mpsc::sync_channel(0);
+
+    std::thread::spawn(move || {
+        let result = match connection.backend {
+            Backend::Postgres => postgres_::query(&connection, &sql.item, &params, &name_tag, &tx),
+            Backend::MySql => mysql_::query(&connection, &sql.item, &params, &name_tag, &tx),
+        };
+
+        if let Err(error) = result {
+            let _ = tx.send(Err(error));
+        }
+    });
+
+    Ok(ThreadedReceiver::new(rx)
+        .map(|row: Result<Value, ShellError>| row.map(ReturnSuccess::Value))
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DbQuery;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(DbQuery {})
+    }
+}
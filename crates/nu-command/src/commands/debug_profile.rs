@@ -0,0 +1,129 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_engine::{run_block, Profiler};
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::CapturedBlock, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+};
+
+pub struct DebugProfile;
+
+#[derive(Deserialize)]
+struct DebugProfileArgs {
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DebugProfile {
+    fn name(&self) -> &str {
+        "debug profile"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("debug profile").required(
+            "block",
+            SyntaxShape::Block,
+            "the block to run and profile",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs a block and returns how long each of its commands took and what they output."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each command gets a row, in the order they started, with:
+    depth: how deeply it is nested in blocks, 0 for the commands of the profiled block
+    calls: how many times it ran, eg once for each row a block of 'each' is run on
+    rows: how many values it output
+    self: the time spent in the command itself
+    total: the time spent in the command and the commands before it in its pipeline
+    memory: a rough estimate of the memory the values it output take up
+The commands in a pipeline run at the same time, so the time is the time spent producing values
+when the next command asked for them. The time of a command includes the blocks it runs, whose
+commands have rows of their own. The output of the block is dropped."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        debug_profile(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the slowest command of a pipeline",
+                example: "debug profile { ls **/* | where size > 1mb | sort-by size } | sort-by self | last",
+                result: None,
+            },
+            Example {
+                description: "Profile a block that runs another block for each row",
+                example: "debug profile { echo [1 2 3] | each { = $it * 2 } }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn debug_profile(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = raw_args.call_info.name_tag.clone();
+    let context = EvaluationContext::from_args(&raw_args);
+    let (DebugProfileArgs { block }, input) = raw_args.process().await?;
+
+    let profiler = Profiler::new();
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+    context.scope.set_profiler(profiler.clone());
+    let result = run_block(&block.block, &context, input).await;
+
+    // The commands only run as their output is read, which has to happen before leaving the scope
+    let result = match result {
+        Ok(stream) => {
+            stream.into_vec().await;
+            Ok(())
+        }
+        Err(err) => Err(err),
+    };
+    context.scope.exit_scope();
+    result?;
+
+    if let Some(err) = context.get_errors().get(0) {
+        context.clear_errors();
+        return Err(err.clone());
+    }
+
+    let entries = profiler.entries();
+    let rows: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            let mut row = TaggedDictBuilder::new(&tag);
+            row.insert_untagged("command", UntaggedValue::string(&entry.command));
+            row.insert_untagged("depth", UntaggedValue::int(entry.depth));
+            row.insert_untagged("calls", UntaggedValue::int(entry.calls));
+            row.insert_untagged("rows", UntaggedValue::int(entry.rows));
+            row.insert_untagged(
+                "self",
+                UntaggedValue::duration(entry.self_time(&entries).as_nanos()),
+            );
+            row.insert_untagged("total", UntaggedValue::duration(entry.elapsed.as_nanos()));
+            row.insert_untagged("memory", UntaggedValue::filesize(entry.bytes as u64));
+
+            ReturnSuccess::value(row.into_value())
+        })
+        .collect();
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugProfile;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(DebugProfile {})
+    }
+}
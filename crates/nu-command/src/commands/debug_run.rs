@@ -0,0 +1,187 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_engine::{run_block, Breakpoint, Debugger, DebuggerInput};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::{Tagged, Text};
+use std::path::PathBuf;
+
+pub struct DebugRun;
+
+#[derive(Deserialize)]
+struct DebugRunArgs {
+    script: Tagged<PathBuf>,
+    rest: Vec<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DebugRun {
+    fn name(&self) -> &str {
+        "debug run"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("debug run")
+            .required("script", SyntaxShape::FilePath, "the script to debug")
+            .rest(
+                SyntaxShape::String,
+                "the lines, or names of commands, to stop at (without any, stops at the start)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs a script, stopping at breakpoints to step through it and look at its variables."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The script stops before running a pipeline on a line, or with a command, given as a
+breakpoint. While it's stopped, the debugger takes commands at its prompt:
+    c, continue          run until the next breakpoint
+    s, step              run the next pipeline, stopping inside the blocks it runs
+    n, next              run the next pipeline, stepping over the blocks it runs
+    b, break [line|cmd]  stop at a line or before a command, or list the breakpoints
+    d, delete <line|cmd> remove a breakpoint
+    l, list              show the lines around the paused pipeline
+    v, vars              show the variables in the paused scope
+    e, env               show the environment of the paused scope
+    p, print <pipeline>  run a pipeline in the paused scope and show its output
+    q, quit              stop the script
+Once the prompt gets no more input, the rest of the script runs without stopping."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        debug_run(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Step through a script from its first pipeline",
+                example: "debug run build.nu",
+                result: None,
+            },
+            Example {
+                description: "Stop at line 12 and before every 'where'",
+                example: "debug run build.nu 12 where",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn debug_run(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = EvaluationContext::from_args(&raw_args);
+    let (DebugRunArgs { script, rest }, input) = raw_args.process().await?;
+
+    let path = PathBuf::from(context.shell_manager.path()).join(&script.item);
+    let source = std::fs::read_to_string(&path).map_err(|e| {
+        ShellError::labeled_error(
+            format!("Cannot read {}: {}", script.item.display(), e),
+            "cannot read the script",
+            &script.tag,
+        )
+    })?;
+
+    // The errors of the script point into it, so they're shown with its source rather than
+    // with the line that ran `debug run`
+    let (block, err) = nu_parser::parse(&source, 0, &context.scope);
+    if let Some(err) = err {
+        context.with_host(|host| host.print_err(err.into(), &Text::from(source.clone())));
+        return Err(ShellError::labeled_error(
+            "Could not parse the script",
+            "could not parse",
+            &script.tag,
+        ));
+    }
+
+    let breakpoints = rest
+        .iter()
+        .map(|breakpoint| Breakpoint::parse(&breakpoint.item))
+        .collect();
+    let debugger = Debugger::new(
+        script.item.display().to_string(),
+        source.clone(),
+        breakpoints,
+        debugger_input(),
+    );
+
+    context.scope.enter_scope();
+    context.scope.set_debugger(debugger);
+    let result = run_block(&block, &context, input).await;
+
+    // The pipelines only run as their output is read, which has to happen before leaving the scope
+    let result = match result {
+        Ok(stream) => Ok(stream.into_vec().await),
+        Err(err) => Err(err),
+    };
+    context.scope.exit_scope();
+
+    let error = match result {
+        Ok(values) if context.get_errors().is_empty() => {
+            return Ok(
+                futures::stream::iter(values.into_iter().map(ReturnSuccess::value))
+                    .to_output_stream(),
+            )
+        }
+        Ok(_) => {
+            let error = context.get_errors().remove(0);
+            context.clear_errors();
+            error
+        }
+        Err(err) => err,
+    };
+
+    context.with_host(|host| host.print_err(error, &Text::from(source)));
+    Err(ShellError::labeled_error(
+        "The script failed",
+        "failed",
+        &script.tag,
+    ))
+}
+
+/// Reads the debugger commands with line editing and history, like the REPL
+#[cfg(feature = "rustyline-support")]
+#[derive(Default)]
+struct LineEditorInput {
+    history: Vec<String>,
+}
+
+#[cfg(feature = "rustyline-support")]
+impl DebuggerInput for LineEditorInput {
+    fn read_line(&mut self, prompt: &str) -> Option<String> {
+        let mut editor = rustyline::Editor::<()>::new();
+        for line in &self.history {
+            editor.add_history_entry(line.as_str());
+        }
+
+        let line = editor.readline(prompt).ok()?;
+        if !line.trim().is_empty() {
+            self.history.push(line.clone());
+        }
+
+        Some(line)
+    }
+}
+
+#[cfg(feature = "rustyline-support")]
+fn debugger_input() -> Box<dyn DebuggerInput> {
+    Box::new(LineEditorInput::default())
+}
+
+#[cfg(not(feature = "rustyline-support"))]
+fn debugger_input() -> Box<dyn DebuggerInput> {
+    Box::new(nu_engine::StdinInput)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugRun;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(DebugRun {})
+    }
+}
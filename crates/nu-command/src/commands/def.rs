@@ -29,12 +29,21 @@ impl WholeStreamCommand for Def {
                 "the parameters of the command",
             )
             .required("block", SyntaxShape::Block, "the body of the command")
+            .switch(
+                "env",
+                "keep the changes the command makes to the environment once it's done",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
         "Create a command and set it to a definition."
     }
 
+    fn extra_usage(&self) -> &str {
+        "The environment variables a command sets, eg with let-env or load-env, are only set while it runs. With --env they stay set for its caller, so the command can set up the environment, like `def --env use-java [] { let-env JAVA_HOME = \"/opt/java\" }`."
+    }
+
     async fn run(&self, _args: CommandArgs) -> Result<OutputStream, ShellError> {
         // Currently, we don't do anything here because we should have already
         // installed the definition as we entered the scope
@@ -1,5 +1,5 @@
 use nu_engine::whole_stream_command;
-use nu_engine::EvaluationContext;
+use nu_engine::{Command, EvaluationContext};
 use std::error::Error;
 
 pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Box<dyn Error>> {
@@ -8,250 +8,455 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
     {
         use crate::commands::*;
 
-        context.add_commands(vec![
-            // Fundamentals
-            whole_stream_command(NuPlugin),
-            whole_stream_command(Let),
-            whole_stream_command(LetEnv),
-            whole_stream_command(Def),
-            whole_stream_command(Source),
-            // System/file operations
-            whole_stream_command(Exec),
-            whole_stream_command(Pwd),
-            whole_stream_command(Ls),
-            whole_stream_command(Du),
-            whole_stream_command(Cd),
-            whole_stream_command(Remove),
-            whole_stream_command(Open),
-            whole_stream_command(Config),
-            whole_stream_command(ConfigGet),
-            whole_stream_command(ConfigSet),
-            whole_stream_command(ConfigSetInto),
-            whole_stream_command(ConfigClear),
-            whole_stream_command(ConfigRemove),
-            whole_stream_command(ConfigPath),
-            whole_stream_command(Help),
-            whole_stream_command(History),
-            whole_stream_command(Save),
-            whole_stream_command(Touch),
-            whole_stream_command(Cpy),
-            whole_stream_command(Date),
-            whole_stream_command(DateListTimeZone),
-            whole_stream_command(DateNow),
-            whole_stream_command(DateToTable),
-            whole_stream_command(DateToTimeZone),
-            whole_stream_command(DateFormat),
-            whole_stream_command(Cal),
-            whole_stream_command(Mkdir),
-            whole_stream_command(Mv),
-            whole_stream_command(Kill),
-            whole_stream_command(Version),
-            whole_stream_command(Clear),
-            whole_stream_command(Describe),
-            whole_stream_command(Which),
-            whole_stream_command(Debug),
-            whole_stream_command(WithEnv),
-            whole_stream_command(Do),
-            whole_stream_command(Sleep),
-            // Statistics
-            whole_stream_command(Size),
-            whole_stream_command(Length),
-            whole_stream_command(Benchmark),
-            // Metadata
-            whole_stream_command(Tags),
-            // Shells
-            whole_stream_command(Next),
-            whole_stream_command(Previous),
-            whole_stream_command(Shells),
-            whole_stream_command(Enter),
-            whole_stream_command(Exit),
-            // Viz
-            whole_stream_command(Chart),
-            // Viewers
-            whole_stream_command(Autoview),
-            whole_stream_command(Table),
-            // Text manipulation
-            whole_stream_command(Hash),
-            whole_stream_command(HashBase64),
-            whole_stream_command(HashMd5),
-            whole_stream_command(Split),
-            whole_stream_command(SplitColumn),
-            whole_stream_command(SplitRow),
-            whole_stream_command(SplitChars),
-            whole_stream_command(Lines),
-            whole_stream_command(Echo),
-            whole_stream_command(Parse),
-            whole_stream_command(Str),
-            whole_stream_command(StrToDecimal),
-            whole_stream_command(StrToInteger),
-            whole_stream_command(StrDowncase),
-            whole_stream_command(StrUpcase),
-            whole_stream_command(StrCapitalize),
-            whole_stream_command(StrFindReplace),
-            whole_stream_command(StrFrom),
-            whole_stream_command(StrSubstring),
-            whole_stream_command(StrToDatetime),
-            whole_stream_command(StrContains),
-            whole_stream_command(StrIndexOf),
-            whole_stream_command(StrTrim),
-            whole_stream_command(StrTrimLeft),
-            whole_stream_command(StrTrimRight),
-            whole_stream_command(StrStartsWith),
-            whole_stream_command(StrEndsWith),
-            whole_stream_command(StrCollect),
-            whole_stream_command(StrLength),
-            whole_stream_command(StrLPad),
-            whole_stream_command(StrReverse),
-            whole_stream_command(StrRPad),
-            whole_stream_command(StrCamelCase),
-            whole_stream_command(StrPascalCase),
-            whole_stream_command(StrKebabCase),
-            whole_stream_command(StrSnakeCase),
-            whole_stream_command(StrScreamingSnakeCase),
-            whole_stream_command(BuildString),
-            whole_stream_command(Ansi),
-            whole_stream_command(AnsiStrip),
-            whole_stream_command(Char),
-            // Column manipulation
-            whole_stream_command(DropColumn),
-            whole_stream_command(Move),
-            whole_stream_command(Reject),
-            whole_stream_command(Select),
-            whole_stream_command(Get),
-            whole_stream_command(Update),
-            whole_stream_command(Insert),
-            whole_stream_command(IntoInt),
-            whole_stream_command(SplitBy),
-            // Row manipulation
-            whole_stream_command(Reverse),
-            whole_stream_command(Append),
-            whole_stream_command(Prepend),
-            whole_stream_command(SortBy),
-            whole_stream_command(GroupBy),
-            whole_stream_command(GroupByDate),
-            whole_stream_command(First),
-            whole_stream_command(Last),
-            whole_stream_command(Every),
-            whole_stream_command(Nth),
-            whole_stream_command(Drop),
-            whole_stream_command(Format),
-            whole_stream_command(FileSize),
-            whole_stream_command(Where),
-            whole_stream_command(If),
-            whole_stream_command(Compact),
-            whole_stream_command(Default),
-            whole_stream_command(Skip),
-            whole_stream_command(SkipUntil),
-            whole_stream_command(SkipWhile),
-            whole_stream_command(Keep),
-            whole_stream_command(KeepUntil),
-            whole_stream_command(KeepWhile),
-            whole_stream_command(Range),
-            whole_stream_command(Rename),
-            whole_stream_command(Uniq),
-            whole_stream_command(Each),
-            whole_stream_command(EachGroup),
-            whole_stream_command(EachWindow),
-            whole_stream_command(Empty),
-            // Table manipulation
-            whole_stream_command(Flatten),
-            whole_stream_command(Move),
-            whole_stream_command(Merge),
-            whole_stream_command(Shuffle),
-            whole_stream_command(Wrap),
-            whole_stream_command(Pivot),
-            whole_stream_command(Headers),
-            whole_stream_command(Reduce),
-            whole_stream_command(Roll),
-            whole_stream_command(RollColumn),
-            whole_stream_command(RollUp),
-            whole_stream_command(Rotate),
-            whole_stream_command(RotateCounterClockwise),
-            // Data processing
-            whole_stream_command(Histogram),
-            whole_stream_command(Autoenv),
-            whole_stream_command(AutoenvTrust),
-            whole_stream_command(AutoenvUnTrust),
-            whole_stream_command(Math),
-            whole_stream_command(MathAbs),
-            whole_stream_command(MathAverage),
-            whole_stream_command(MathEval),
-            whole_stream_command(MathMedian),
-            whole_stream_command(MathMinimum),
-            whole_stream_command(MathMode),
-            whole_stream_command(MathMaximum),
-            whole_stream_command(MathStddev),
-            whole_stream_command(MathSummation),
-            whole_stream_command(MathVariance),
-            whole_stream_command(MathProduct),
-            whole_stream_command(MathRound),
-            whole_stream_command(MathFloor),
-            whole_stream_command(MathCeil),
-            // File format output
-            whole_stream_command(To),
-            whole_stream_command(ToCsv),
-            whole_stream_command(ToHtml),
-            whole_stream_command(ToJson),
-            whole_stream_command(ToMarkdown),
-            whole_stream_command(ToToml),
-            whole_stream_command(ToTsv),
-            whole_stream_command(ToUrl),
-            whole_stream_command(ToYaml),
-            whole_stream_command(ToXml),
-            // File format input
-            whole_stream_command(From),
-            whole_stream_command(FromCsv),
-            whole_stream_command(FromEml),
-            whole_stream_command(FromTsv),
-            whole_stream_command(FromSsv),
-            whole_stream_command(FromIni),
-            whole_stream_command(FromJson),
-            whole_stream_command(FromOds),
-            whole_stream_command(FromToml),
-            whole_stream_command(FromUrl),
-            whole_stream_command(FromXlsx),
-            whole_stream_command(FromXml),
-            whole_stream_command(FromYaml),
-            whole_stream_command(FromYml),
-            whole_stream_command(FromIcs),
-            whole_stream_command(FromVcf),
-            // "Private" commands (not intended to be accessed directly)
-            whole_stream_command(RunExternalCommand { interactive }),
-            // Random value generation
-            whole_stream_command(Random),
-            whole_stream_command(RandomBool),
-            whole_stream_command(RandomDice),
-            #[cfg(feature = "uuid_crate")]
-            whole_stream_command(RandomUUID),
-            whole_stream_command(RandomInteger),
-            whole_stream_command(RandomDecimal),
-            whole_stream_command(RandomChars),
-            // Path
-            whole_stream_command(PathBasename),
-            whole_stream_command(PathCommand),
-            whole_stream_command(PathDirname),
-            whole_stream_command(PathExists),
-            whole_stream_command(PathExpand),
-            whole_stream_command(PathExtension),
-            whole_stream_command(PathFilestem),
-            whole_stream_command(PathJoin),
-            whole_stream_command(PathType),
-            // Url
-            whole_stream_command(UrlCommand),
-            whole_stream_command(UrlScheme),
-            whole_stream_command(UrlPath),
-            whole_stream_command(UrlHost),
-            whole_stream_command(UrlQuery),
-            whole_stream_command(Seq),
-            whole_stream_command(SeqDates),
-            whole_stream_command(TermSize),
-        ]);
+        // "Private" commands (not intended to be accessed directly)
+        context.add_commands(vec![whole_stream_command(RunExternalCommand {
+            interactive,
+        })]);
+
+        // Each group of commands can be left out of a build, eg for a small binary that only has
+        // the core commands
+        #[cfg(feature = "core")]
+        {
+            context.add_commands(core_commands());
+        }
+
+        #[cfg(feature = "filesystem")]
+        {
+            context.add_commands(filesystem_commands());
+        }
+
+        #[cfg(feature = "network")]
+        {
+            context.add_commands(network_commands());
+        }
+
+        #[cfg(feature = "formats")]
+        {
+            context.add_commands(formats_commands());
+        }
+
+        #[cfg(feature = "extra")]
+        {
+            context.add_commands(extra_commands());
+        }
 
         #[cfg(feature = "clipboard-cli")]
         {
             context.add_commands(vec![whole_stream_command(crate::commands::clip::Clip)]);
         }
+
+        // `from logs` comes with the other formats, the rest with the extra-formats feature
+        #[cfg(feature = "formats")]
+        {
+            context.add_commands(crate::commands::extra_formats::commands());
+        }
+
+        #[cfg(feature = "sqlite")]
+        {
+            context.add_commands(vec![
+                whole_stream_command(crate::commands::into_sqlite::IntoSqlite),
+                whole_stream_command(crate::commands::query_sql::QuerySql),
+            ]);
+        }
+
+        #[cfg(feature = "database")]
+        {
+            context.add_commands(vec![
+                whole_stream_command(crate::commands::db::Db),
+                whole_stream_command(crate::commands::db::DbConnect),
+                whole_stream_command(crate::commands::db::DbQuery),
+            ]);
+        }
+
+        #[cfg(feature = "mqtt")]
+        {
+            context.add_commands(vec![
+                whole_stream_command(crate::commands::mqtt::Mqtt),
+                whole_stream_command(crate::commands::mqtt::MqttPublish),
+                whole_stream_command(crate::commands::mqtt::MqttSubscribe),
+            ]);
+        }
+
+        #[cfg(feature = "nats")]
+        {
+            context.add_commands(vec![
+                whole_stream_command(crate::commands::nats_::Nats),
+                whole_stream_command(crate::commands::nats_::NatsPublish),
+                whole_stream_command(crate::commands::nats_::NatsSubscribe),
+            ]);
+        }
+
+        #[cfg(feature = "redis")]
+        {
+            context.add_commands(vec![
+                whole_stream_command(crate::commands::redis_::Redis),
+                whole_stream_command(crate::commands::redis_::RedisGet),
+                whole_stream_command(crate::commands::redis_::RedisSet),
+                whole_stream_command(crate::commands::redis_::RedisScan),
+            ]);
+        }
+
+        #[cfg(feature = "dataframe")]
+        {
+            context.add_commands(vec![
+                whole_stream_command(crate::commands::dataframe::Dfr),
+                whole_stream_command(crate::commands::dataframe::DfrOpen),
+                whole_stream_command(crate::commands::dataframe::DfrFilter),
+                whole_stream_command(crate::commands::dataframe::DfrGroupBy),
+                whole_stream_command(crate::commands::dataframe::DfrJoin),
+                whole_stream_command(crate::commands::dataframe::DfrCollect),
+            ]);
+        }
     }
 
     Ok(context)
 }
+
+/// The commands for working with values and the shell itself
+#[cfg(feature = "core")]
+fn core_commands() -> Vec<Command> {
+    use crate::commands::*;
+
+    vec![
+        // Fundamentals
+        whole_stream_command(NuPlugin),
+        whole_stream_command(Plugin),
+        whole_stream_command(PluginList),
+        whole_stream_command(PluginInstall),
+        whole_stream_command(PluginUninstall),
+        whole_stream_command(PluginUpdate),
+        whole_stream_command(Let),
+        whole_stream_command(LetEnv),
+        whole_stream_command(Env),
+        whole_stream_command(EnvSave),
+        whole_stream_command(EnvRestore),
+        whole_stream_command(Def),
+        whole_stream_command(Source),
+        // System/file operations
+        whole_stream_command(Exec),
+        whole_stream_command(Pwd),
+        whole_stream_command(Cd),
+        whole_stream_command(Pushd),
+        whole_stream_command(Popd),
+        whole_stream_command(Dirs),
+        whole_stream_command(Z),
+        whole_stream_command(Config),
+        whole_stream_command(ConfigGet),
+        whole_stream_command(ConfigSet),
+        whole_stream_command(ConfigSetInto),
+        whole_stream_command(ConfigClear),
+        whole_stream_command(ConfigRemove),
+        whole_stream_command(ConfigPath),
+        whole_stream_command(ConfigEdit),
+        whole_stream_command(Help),
+        whole_stream_command(History),
+        whole_stream_command(HistoryStats),
+        whole_stream_command(Date),
+        whole_stream_command(DateHumanize),
+        whole_stream_command(DateListTimeZone),
+        whole_stream_command(DateNow),
+        whole_stream_command(DateToRecord),
+        whole_stream_command(DateToTable),
+        whole_stream_command(DateToTimeZone),
+        whole_stream_command(DateFormat),
+        whole_stream_command(Version),
+        whole_stream_command(Clear),
+        whole_stream_command(Describe),
+        whole_stream_command(Which),
+        whole_stream_command(Debug),
+        whole_stream_command(WithEnv),
+        whole_stream_command(LoadEnv),
+        whole_stream_command(ExportEnv),
+        whole_stream_command(Do),
+        whole_stream_command(Complete),
+        whole_stream_command(Sleep),
+        whole_stream_command(Timeout),
+        whole_stream_command(Assert),
+        whole_stream_command(AssertEqual),
+        whole_stream_command(AssertNotEqual),
+        whole_stream_command(AssertError),
+        // Statistics
+        whole_stream_command(Size),
+        whole_stream_command(Length),
+        whole_stream_command(IsEmpty),
+        whole_stream_command(Columns),
+        whole_stream_command(Values),
+        // Metadata
+        whole_stream_command(Tags),
+        whole_stream_command(Metadata),
+        // Shells
+        whole_stream_command(Next),
+        whole_stream_command(Previous),
+        whole_stream_command(Shells),
+        whole_stream_command(Exit),
+        // Viewers
+        whole_stream_command(Autoview),
+        whole_stream_command(Table),
+        // Text manipulation
+        whole_stream_command(Split),
+        whole_stream_command(SplitColumn),
+        whole_stream_command(SplitRow),
+        whole_stream_command(SplitChars),
+        whole_stream_command(Lines),
+        whole_stream_command(Echo),
+        whole_stream_command(Parse),
+        whole_stream_command(Str),
+        whole_stream_command(StrToDecimal),
+        whole_stream_command(StrToInteger),
+        whole_stream_command(StrDowncase),
+        whole_stream_command(StrUpcase),
+        whole_stream_command(StrCapitalize),
+        whole_stream_command(StrFindReplace),
+        whole_stream_command(StrFrom),
+        whole_stream_command(StrSubstring),
+        whole_stream_command(StrToDatetime),
+        whole_stream_command(StrContains),
+        whole_stream_command(StrIndexOf),
+        whole_stream_command(StrTrim),
+        whole_stream_command(StrTrimLeft),
+        whole_stream_command(StrTrimRight),
+        whole_stream_command(StrStartsWith),
+        whole_stream_command(StrEndsWith),
+        whole_stream_command(StrCollect),
+        whole_stream_command(StrLength),
+        whole_stream_command(StrLPad),
+        whole_stream_command(StrReverse),
+        whole_stream_command(StrRPad),
+        whole_stream_command(StrCamelCase),
+        whole_stream_command(StrPascalCase),
+        whole_stream_command(StrKebabCase),
+        whole_stream_command(StrSnakeCase),
+        whole_stream_command(StrScreamingSnakeCase),
+        whole_stream_command(StrTitleCase),
+        whole_stream_command(StrDistance),
+        whole_stream_command(BuildString),
+        whole_stream_command(Ansi),
+        whole_stream_command(AnsiStrip),
+        whole_stream_command(Char),
+        // Column manipulation
+        whole_stream_command(DropColumn),
+        whole_stream_command(Move),
+        whole_stream_command(Reject),
+        whole_stream_command(Select),
+        whole_stream_command(Get),
+        whole_stream_command(Update),
+        whole_stream_command(Insert),
+        whole_stream_command(Upsert),
+        whole_stream_command(IntoGlob),
+        whole_stream_command(IntoInt),
+        whole_stream_command(SplitBy),
+        // Row manipulation
+        whole_stream_command(Reverse),
+        whole_stream_command(Append),
+        whole_stream_command(Prepend),
+        whole_stream_command(SortBy),
+        whole_stream_command(GroupBy),
+        whole_stream_command(First),
+        whole_stream_command(Last),
+        whole_stream_command(Every),
+        whole_stream_command(Chunks),
+        whole_stream_command(Window),
+        whole_stream_command(Nth),
+        whole_stream_command(Drop),
+        whole_stream_command(Format),
+        whole_stream_command(FileSize),
+        whole_stream_command(Where),
+        whole_stream_command(If),
+        whole_stream_command(Compact),
+        whole_stream_command(Default),
+        whole_stream_command(Skip),
+        whole_stream_command(SkipUntil),
+        whole_stream_command(SkipWhile),
+        whole_stream_command(Keep),
+        whole_stream_command(KeepUntil),
+        whole_stream_command(KeepWhile),
+        whole_stream_command(Range),
+        whole_stream_command(Rename),
+        whole_stream_command(Uniq),
+        whole_stream_command(UniqBy),
+        whole_stream_command(Intersect),
+        whole_stream_command(Union),
+        whole_stream_command(Difference),
+        whole_stream_command(Each),
+        whole_stream_command(Empty),
+        // Table manipulation
+        whole_stream_command(Flatten),
+        whole_stream_command(Move),
+        whole_stream_command(Merge),
+        whole_stream_command(Join),
+        whole_stream_command(Wrap),
+        whole_stream_command(Transpose),
+        whole_stream_command(Pivot),
+        whole_stream_command(Headers),
+        whole_stream_command(Reduce),
+        // Data processing
+        whole_stream_command(Math),
+        whole_stream_command(MathAbs),
+        whole_stream_command(MathAverage),
+        whole_stream_command(MathEval),
+        whole_stream_command(MathMedian),
+        whole_stream_command(MathMinimum),
+        whole_stream_command(MathMode),
+        whole_stream_command(MathPercentile),
+        whole_stream_command(MathMaximum),
+        whole_stream_command(MathStddev),
+        whole_stream_command(MathSummation),
+        whole_stream_command(MathVariance),
+        whole_stream_command(MathProduct),
+        whole_stream_command(MathRound),
+        whole_stream_command(MathFloor),
+        whole_stream_command(MathCeil),
+    ]
+}
+
+/// The commands that read and change files and directories
+#[cfg(feature = "filesystem")]
+fn filesystem_commands() -> Vec<Command> {
+    use crate::commands::*;
+
+    vec![
+        // System/file operations
+        whole_stream_command(Ls),
+        whole_stream_command(Glob),
+        whole_stream_command(Du),
+        whole_stream_command(Remove),
+        whole_stream_command(Open),
+        whole_stream_command(Save),
+        whole_stream_command(Touch),
+        whole_stream_command(Cpy),
+        whole_stream_command(Mkdir),
+        whole_stream_command(Mv),
+        whole_stream_command(Watch),
+        // Shells
+        whole_stream_command(Enter),
+        // Autoenv
+        whole_stream_command(Autoenv),
+        whole_stream_command(AutoenvTrust),
+        whole_stream_command(AutoenvUnTrust),
+        // Path
+        whole_stream_command(PathBasename),
+        whole_stream_command(PathCommand),
+        whole_stream_command(PathDirname),
+        whole_stream_command(PathExists),
+        whole_stream_command(PathExpand),
+        whole_stream_command(PathExtension),
+        whole_stream_command(PathFilestem),
+        whole_stream_command(PathJoin),
+        whole_stream_command(PathType),
+    ]
+}
+
+/// The commands for working with URLs
+#[cfg(feature = "network")]
+fn network_commands() -> Vec<Command> {
+    use crate::commands::*;
+
+    vec![
+        // Url
+        whole_stream_command(UrlCommand),
+        whole_stream_command(UrlScheme),
+        whole_stream_command(UrlPath),
+        whole_stream_command(UrlHost),
+        whole_stream_command(UrlQuery),
+    ]
+}
+
+/// The commands that convert values from and to file formats
+#[cfg(feature = "formats")]
+fn formats_commands() -> Vec<Command> {
+    use crate::commands::*;
+
+    vec![
+        // File format output
+        whole_stream_command(To),
+        whole_stream_command(ToCsv),
+        whole_stream_command(ToFixedWidth),
+        whole_stream_command(ToHtml),
+        whole_stream_command(ToJson),
+        whole_stream_command(ToLogfmt),
+        whole_stream_command(ToMarkdown),
+        whole_stream_command(ToPrometheus),
+        whole_stream_command(ToSql),
+        whole_stream_command(ToToml),
+        whole_stream_command(ToTsv),
+        whole_stream_command(ToUrl),
+        whole_stream_command(ToYaml),
+        whole_stream_command(ToXml),
+        // File format input
+        whole_stream_command(From),
+        whole_stream_command(FromCsv),
+        whole_stream_command(FromEml),
+        whole_stream_command(FromFixedWidth),
+        whole_stream_command(FromTsv),
+        whole_stream_command(FromSsv),
+        whole_stream_command(FromIni),
+        whole_stream_command(FromJson),
+        whole_stream_command(FromLogfmt),
+        whole_stream_command(FromOds),
+        whole_stream_command(FromPrometheus),
+        whole_stream_command(FromToml),
+        whole_stream_command(FromUrl),
+        whole_stream_command(FromXlsx),
+        whole_stream_command(FromXml),
+        whole_stream_command(FromYaml),
+        whole_stream_command(FromYml),
+        whole_stream_command(FromIcs),
+        whole_stream_command(FromVcf),
+    ]
+}
+
+/// The commands that are less often needed, left out of slim builds
+#[cfg(feature = "extra")]
+fn extra_commands() -> Vec<Command> {
+    use crate::commands::*;
+
+    vec![
+        // System
+        whole_stream_command(Cal),
+        whole_stream_command(Kill),
+        whole_stream_command(Job),
+        whole_stream_command(JobSpawn),
+        whole_stream_command(JobList),
+        whole_stream_command(JobDisown),
+        whole_stream_command(DebugProfile),
+        whole_stream_command(DebugRun),
+        whole_stream_command(TestRun),
+        // Statistics
+        whole_stream_command(Benchmark),
+        // Viz
+        whole_stream_command(Chart),
+        // Text manipulation
+        whole_stream_command(Hash),
+        whole_stream_command(HashBase64),
+        whole_stream_command(HashMd5),
+        // Row manipulation
+        whole_stream_command(GroupByDate),
+        whole_stream_command(EachGroup),
+        whole_stream_command(EachWindow),
+        whole_stream_command(ParEach),
+        // Table manipulation
+        whole_stream_command(Shuffle),
+        whole_stream_command(ZipWith),
+        whole_stream_command(Roll),
+        whole_stream_command(RollColumn),
+        whole_stream_command(RollUp),
+        whole_stream_command(Rotate),
+        whole_stream_command(RotateCounterClockwise),
+        // Data processing
+        whole_stream_command(Histogram),
+        // Random value generation
+        whole_stream_command(Random),
+        whole_stream_command(RandomBool),
+        whole_stream_command(RandomDice),
+        #[cfg(feature = "uuid_crate")]
+        whole_stream_command(RandomUUID),
+        whole_stream_command(RandomInteger),
+        whole_stream_command(RandomDecimal),
+        whole_stream_command(RandomChars),
+        // Sequences
+        whole_stream_command(Seq),
+        whole_stream_command(SeqDates),
+        whole_stream_command(Generate),
+        whole_stream_command(TermSize),
+    ]
+}
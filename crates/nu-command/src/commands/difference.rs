@@ -0,0 +1,71 @@
+use crate::commands::set_operations::{set_operation, set_operation_signature, SetOperation};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, UntaggedValue};
+
+pub struct Difference;
+
+#[async_trait]
+impl WholeStreamCommand for Difference {
+    fn name(&self) -> &str {
+        "difference"
+    }
+
+    fn signature(&self) -> Signature {
+        set_operation_signature("difference")
+    }
+
+    fn usage(&self) -> &str {
+        "Keep the rows that are not in another list or table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Rows are matched by the given columns, or by the whole row when no columns are given. Each
+row is kept once unless --all is given, in which case every row on the other side cancels out a
+single matching row of the input."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        set_operation(args, SetOperation::Difference).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the numbers missing from the second list",
+                example: "echo [1 2 3 4] | difference [2 4]",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Remove one occurrence per match",
+                example: "echo [1 1 1 2] | difference [1 2] --all",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(1).into(),
+                ]),
+            },
+            Example {
+                description: "List the files that are new since a snapshot",
+                example: "ls | difference { open snapshot.json } name",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Difference;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Difference {})
+    }
+}
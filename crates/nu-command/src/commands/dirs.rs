@@ -0,0 +1,113 @@
+use crate::prelude::*;
+use nu_engine::shell::CdArgs;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct Dirs;
+
+#[derive(Deserialize)]
+pub struct DirsArgs {
+    index: Option<Tagged<u64>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Dirs {
+    fn name(&self) -> &str {
+        "dirs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dirs").optional(
+            "index",
+            SyntaxShape::Int,
+            "the entry of the stack to change to, which rotates it to the top",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "List the directory stack, starting with the current path."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The paths saved with pushd are listed after the current one, the most recent first.
+Given an index, the stack is rotated so that entry becomes the current path, keeping the other paths in order."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        dirs(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List the directory stack",
+                example: "dirs",
+                result: None,
+            },
+            Example {
+                description: "Change to the second most recently saved path",
+                example: "dirs 2",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn dirs(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let shell_manager = args.shell_manager.clone();
+    let (DirsArgs { index }, _) = args.process().await?;
+
+    let dirs = shell_manager.dirs();
+
+    let index = match index {
+        Some(index) => index,
+        None => {
+            return Ok(dirs
+                .into_iter()
+                .enumerate()
+                .map(|(index, path)| {
+                    let mut dict = TaggedDictBuilder::new(&name);
+                    dict.insert_untagged("index", UntaggedValue::int(index));
+                    dict.insert_untagged("path", UntaggedValue::filepath(path));
+                    ReturnSuccess::value(dict.into_value())
+                })
+                .collect::<VecDeque<_>>()
+                .into())
+        }
+    };
+
+    let target = dirs.get(index.item as usize).cloned().ok_or_else(|| {
+        ShellError::labeled_error(
+            format!("The directory stack has {} entries", dirs.len()),
+            "no entry at this index",
+            &index.tag,
+        )
+    })?;
+
+    let stream = shell_manager.cd(
+        CdArgs {
+            path: Some(PathBuf::from(target).tagged(&index.tag)),
+        },
+        name,
+    )?;
+    shell_manager.pivot_dirs(index.item as usize);
+
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dirs;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Dirs {})
+    }
+}
@@ -1,8 +1,10 @@
 use crate::prelude::*;
-use nu_engine::run_block;
 use nu_engine::WholeStreamCommand;
-use nu_errors::ShellError;
-use nu_protocol::{hir::CapturedBlock, hir::ExternalRedirection, Signature, SyntaxShape, Value};
+use nu_engine::{run_block, run_block_to_end};
+use nu_errors::{ProximateShellError, ShellError};
+use nu_protocol::{
+    hir::CapturedBlock, hir::ExternalRedirection, Signature, SyntaxShape, UntaggedValue, Value,
+};
 
 pub struct Do;
 
@@ -10,6 +12,7 @@ pub struct Do;
 struct DoArgs {
     block: CapturedBlock,
     ignore_errors: bool,
+    capture_errors: bool,
 }
 
 #[async_trait]
@@ -26,12 +29,21 @@ impl WholeStreamCommand for Do {
                 "ignore errors as the block runs",
                 Some('i'),
             )
+            .switch(
+                "capture_errors",
+                "capture the stderr of externals, failing with it if one of them fails",
+                Some('c'),
+            )
     }
 
     fn usage(&self) -> &str {
         "Runs a block, optionally ignoring errors."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --ignore_errors the block gives nothing when it fails, and the errors and stderr of externals in it are dropped. With --capture_errors an external that fails makes do fail with the stderr of the external, so an optional step can be tried and reported on, and otherwise the stderr is written out when the block is done."
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         do_(args).await
     }
@@ -48,32 +60,48 @@ impl WholeStreamCommand for Do {
                 example: r#"do -i { thisisnotarealcommand }"#,
                 result: Some(vec![]),
             },
+            Example {
+                description: "Run an external and fail with its stderr if it fails",
+                example: r#"do -c { ^git pull }"#,
+                result: None,
+            },
         ]
     }
 }
 
 async fn do_(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     let external_redirection = raw_args.call_info.args.external_redirection;
+    let tag = raw_args.call_info.name_tag.clone();
 
     let context = EvaluationContext::from_args(&raw_args);
     let (
         DoArgs {
             ignore_errors,
+            capture_errors,
             mut block,
         },
         input,
     ) = raw_args.process().await?;
 
+    if ignore_errors && capture_errors {
+        return Err(ShellError::labeled_error(
+            "Errors can't be both ignored and captured",
+            "use either --ignore_errors or --capture_errors",
+            &tag,
+        ));
+    }
+
+    let redirect_stderr = ignore_errors || capture_errors;
     let block_redirection = match external_redirection {
         ExternalRedirection::None => {
-            if ignore_errors {
+            if redirect_stderr {
                 ExternalRedirection::Stderr
             } else {
                 ExternalRedirection::None
             }
         }
         ExternalRedirection::Stdout => {
-            if ignore_errors {
+            if redirect_stderr {
                 ExternalRedirection::StdoutAndStderr
             } else {
                 ExternalRedirection::Stdout
@@ -84,24 +112,68 @@ async fn do_(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     block.block.set_redirect(block_redirection);
     context.scope.enter_scope();
-    let result = run_block(&block.block, &context, input).await;
+
+    if !redirect_stderr {
+        let result = run_block(&block.block, &context, input).await;
+        context.scope.exit_scope();
+
+        return result.map(|x| x.to_output_stream());
+    }
+
+    let result = run_block_to_end(&block.block, &context, input).await;
     context.scope.exit_scope();
 
     if ignore_errors {
         // To properly ignore errors we need to redirect stderr, consume it, and remove
         // any errors we see in the process.
+        context.clear_errors();
+
+        // The stderr of externals comes in as errors, so it's dropped with them
+        let output = result
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|value| !value.value.is_error());
+
+        return Ok(futures::stream::iter(output).to_output_stream());
+    }
 
-        match result {
-            Ok(mut stream) => {
-                let output = stream.drain_vec().await;
-                context.clear_errors();
-                Ok(futures::stream::iter(output).to_output_stream())
+    let mut values = vec![];
+    let mut stderr = String::new();
+    let mut external_failed = false;
+
+    for value in result? {
+        match value.value {
+            UntaggedValue::Error(err) => {
+                if err.external_exit_code().is_some() {
+                    external_failed = true;
+                } else if let ProximateShellError::UntaggedRuntimeError { reason } = err.error {
+                    stderr.push_str(&reason);
+                } else {
+                    return Err(err);
+                }
             }
-            Err(_) => Ok(OutputStream::empty()),
+            _ => values.push(value),
         }
-    } else {
-        result.map(|x| x.to_output_stream())
     }
+
+    let stderr = stderr.trim_end();
+    if external_failed {
+        return Err(ShellError::labeled_error(
+            "External command failed",
+            if stderr.is_empty() {
+                "command failed"
+            } else {
+                stderr
+            },
+            &tag,
+        ));
+    }
+
+    if !stderr.is_empty() {
+        context.with_host(|host| host.stderr(stderr));
+    }
+
+    Ok(futures::stream::iter(values).to_output_stream())
 }
 
 #[cfg(test)]
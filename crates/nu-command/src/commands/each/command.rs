@@ -48,6 +48,11 @@ impl WholeStreamCommand for Each {
                 example: "echo [[1 2] [3 4]] | each { echo $it | math sum }",
                 result: None,
             },
+            Example {
+                description: "Name the row with a block parameter instead of using $it",
+                example: "echo [[name]; [bob] [fred]] | each {|person| echo $person.name }",
+                result: Some(vec![Value::from("bob"), Value::from("fred")]),
+            },
             Example {
                 description: "Echo the square of each integer",
                 example: "echo [1 2 3] | each { echo $(= $it * $it) }",
@@ -1,13 +1,12 @@
 use crate::prelude::*;
-use nu_engine::UnevaluatedCallInfo;
-use nu_engine::WholeStreamCommand;
+use nu_engine::{ArchiveShell, UnevaluatedCallInfo, WholeStreamCommand};
 use nu_errors::ShellError;
 use nu_protocol::hir::ExternalRedirection;
 use nu_protocol::{
     CommandAction, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
 };
 use nu_source::Tagged;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct Enter;
 
@@ -43,7 +42,15 @@ impl WholeStreamCommand for Enter {
     }
 
     fn extra_usage(&self) -> &str {
-        r#"Multiple encodings are supported for reading text files by using
+        r#"Zip and tar archives (.zip, .tar, .tar.gz and .tgz) are entered as read-only shells,
+where `ls`, `cd` and `open` look inside the archive and `cp` copies files out of it.
+SQLite databases are entered the same way, with `ls` listing the tables and, after `cd`ing
+into a table, its rows.
+With the s3 feature, `enter s3://bucket/prefix` browses an S3 compatible object store, with
+`open` and `save` reading and writing objects. The credentials come from the s3 table of the
+config (endpoint, access_key, secret_key and region) or the AWS_* environment variables.
+
+Multiple encodings are supported for reading text files by using
 the '--encoding <encoding>' parameter. Here is an example of a few:
 big5, euc-jp, euc-kr, gbk, iso-8859-1, utf-16, cp1252, latin5
 
@@ -67,6 +74,21 @@ documentation link at https://docs.rs/encoding_rs/0.8.28/encoding_rs/#statics"#
                 example: "enter package.json",
                 result: None,
             },
+            Example {
+                description: "Enter a zip or tar archive as a read-only shell",
+                example: "enter release.tar.gz",
+                result: None,
+            },
+            Example {
+                description: "Enter a SQLite database, to list its tables and cd into one",
+                example: "enter app.db",
+                result: None,
+            },
+            Example {
+                description: "Enter a bucket of an S3 compatible object store",
+                example: "enter s3://my-bucket/logs",
+                result: None,
+            },
             Example {
                 description: "Enters file with iso-8859-1 encoding",
                 example: "enter file.csv --encoding iso-8859-1",
@@ -82,13 +104,18 @@ async fn enter(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     let head = raw_args.call_info.args.head.clone();
     let ctrl_c = raw_args.ctrl_c.clone();
     let current_errors = raw_args.current_errors.clone();
+    let jobs = raw_args.jobs.clone();
     let host = raw_args.host.clone();
     let tag = raw_args.call_info.name_tag.clone();
     let (EnterArgs { location, encoding }, _) = raw_args.process().await?;
     let location_string = location.display().to_string();
     let location_clone = location_string.clone();
 
-    if location_string.starts_with("help") {
+    if location_string.starts_with("s3://") {
+        Ok(OutputStream::one(ReturnSuccess::action(
+            CommandAction::EnterS3Shell(location_string),
+        )))
+    } else if location_string.starts_with("help") {
         let spec = location_string.split(':').collect::<Vec<&str>>();
 
         if spec.len() == 2 {
@@ -105,6 +132,27 @@ async fn enter(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
         Ok(OutputStream::one(ReturnSuccess::action(
             CommandAction::EnterHelpShell(UntaggedValue::nothing().into_value(Tag::unknown())),
         )))
+    } else if ArchiveShell::is_archive(&location) {
+        let archive = PathBuf::from(shell_manager.path()).join(&location.item);
+        if !archive.is_file() {
+            return Err(ShellError::labeled_error(
+                "Cannot find archive",
+                "cannot find archive",
+                location.tag(),
+            ));
+        }
+
+        Ok(OutputStream::one(ReturnSuccess::action(
+            CommandAction::EnterArchiveShell(archive.display().to_string()),
+        )))
+    } else if cfg!(feature = "sqlite")
+        && is_sqlite(&PathBuf::from(shell_manager.path()).join(&location.item))
+    {
+        let database = PathBuf::from(shell_manager.path()).join(&location.item);
+
+        Ok(OutputStream::one(ReturnSuccess::action(
+            CommandAction::EnterSqliteShell(database.display().to_string()),
+        )))
     } else if location.is_dir() {
         Ok(OutputStream::one(ReturnSuccess::action(
             CommandAction::EnterShell(location_clone),
@@ -134,6 +182,7 @@ async fn enter(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
                             ctrl_c,
                             current_errors,
                             shell_manager,
+                            jobs,
                             call_info: UnevaluatedCallInfo {
                                 args: nu_protocol::hir::Call {
                                     head,
@@ -182,6 +231,17 @@ async fn enter(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     }
 }
 
+/// Whether the file is a SQLite database, going by the header it starts with
+fn is_sqlite(path: &Path) -> bool {
+    use std::io::Read;
+
+    let mut header = [0u8; 16];
+    match std::fs::File::open(path) {
+        Ok(mut file) => file.read_exact(&mut header).is_ok() && &header == b"SQLite format 3\0",
+        Err(_) => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Enter;
@@ -0,0 +1,102 @@
+use crate::prelude::*;
+use nu_data::config::{Conf, NuConfig};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+
+pub struct Env;
+
+#[async_trait]
+impl WholeStreamCommand for Env {
+    fn name(&self) -> &str {
+        "env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env")
+    }
+
+    fn usage(&self) -> &str {
+        "List the environment variables and where each one comes from."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The origin is config for variables from the env section of the config, session for ones set
+or changed since Nu started (eg with let-env), and inherited for the ones Nu was started with. To
+use a variable, read it from $env, where PATH is a list of directories."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        env(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the proxy settings",
+                example: "env | where name =~ PROXY",
+                result: None,
+            },
+            Example {
+                description: "See which variables were set in this session",
+                example: "env | where origin == session",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn env(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+
+    let config = match args.scope.get_var("config-path") {
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::FilePath(path)),
+            ..
+        }) => NuConfig::with(Some(path.into_os_string())),
+        _ => NuConfig::new(),
+    };
+    let config_env = config
+        .env()
+        .map(|env| env.data_descriptors())
+        .unwrap_or_default();
+
+    // The host has the variables Nu started with, with the config's added
+    let host_env: IndexMap<String, String> = args.host.lock().vars().into_iter().collect();
+
+    let rows: Vec<_> = args
+        .scope
+        .get_env_vars()
+        .into_iter()
+        .map(|(name, value)| {
+            let origin = if host_env.get(&name) != Some(&value) {
+                "session"
+            } else if config_env.contains(&name) {
+                "config"
+            } else {
+                "inherited"
+            };
+
+            let mut row = TaggedDictBuilder::new(&tag);
+            row.insert_untagged("name", UntaggedValue::string(name));
+            row.insert_untagged("value", UntaggedValue::string(value));
+            row.insert_untagged("origin", UntaggedValue::string(origin));
+            ReturnSuccess::value(row.into_value())
+        })
+        .collect();
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Env;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Env {})
+    }
+}
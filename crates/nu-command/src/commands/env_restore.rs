@@ -0,0 +1,117 @@
+use crate::prelude::*;
+use nu_data::env_snapshot;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct EnvRestore;
+
+#[derive(Deserialize)]
+struct Arguments {
+    name: Tagged<String>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for EnvRestore {
+    fn name(&self) -> &str {
+        "env restore"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env restore").required(
+            "name",
+            SyntaxShape::String,
+            "the name the environment was saved under",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Bring back the environment variables saved with env save."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The variables are set back to their saved values, and the ones set since are unset. If the
+directory was saved too, it changes back to it."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        env_restore(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Undo the changes made to PATH since the environment was saved",
+                example: "env restore clean",
+                result: None,
+            },
+            Example {
+                description: "Switch back to a project saved with env save --cwd",
+                example: "env restore work",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn env_restore(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let scope = args.scope.clone();
+    let (Arguments { name }, _) = args.process().await?;
+
+    if !env_snapshot::is_valid_name(&name.item)
+        || !env_snapshot::snapshot_path(&name.item)?.exists()
+    {
+        let saved = env_snapshot::names()?;
+        let label = if saved.is_empty() {
+            "no environment has been saved".to_string()
+        } else {
+            format!("the saved ones are {}", saved.join(", "))
+        };
+
+        return Err(ShellError::labeled_error(
+            "No environment saved under this name",
+            label,
+            &name.tag,
+        ));
+    }
+
+    let snapshot = env_snapshot::load(&name.item)?;
+
+    if let Some(cwd) = &snapshot.cwd {
+        if !cwd.is_dir() {
+            return Err(ShellError::labeled_error(
+                "Cannot restore the environment",
+                format!("the directory {} is gone", cwd.display()),
+                &name.tag,
+            ));
+        }
+    }
+
+    for current in scope.get_env_vars().keys() {
+        if !snapshot.env.contains_key(current) {
+            scope.remove_env_var(current);
+        }
+    }
+    scope.add_env(snapshot.env);
+
+    Ok(match snapshot.cwd {
+        Some(cwd) => OutputStream::one(ReturnSuccess::action(CommandAction::ChangePath(
+            cwd.to_string_lossy().to_string(),
+        ))),
+        None => OutputStream::empty(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnvRestore;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(EnvRestore {})
+    }
+}
@@ -0,0 +1,96 @@
+use crate::prelude::*;
+use nu_data::env_snapshot::{self, EnvSnapshot};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct EnvSave;
+
+#[derive(Deserialize)]
+struct Arguments {
+    name: Tagged<String>,
+    cwd: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for EnvSave {
+    fn name(&self) -> &str {
+        "env save"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env save")
+            .required("name", SyntaxShape::String, "the name to save it under")
+            .switch("cwd", "save the current directory too", Some('c'))
+    }
+
+    fn usage(&self) -> &str {
+        "Save the environment variables under a name, to bring them back later with env restore."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The snapshot is kept in Nu's data directory, so it can be restored in later sessions too.
+Saving under a name that is already used replaces that snapshot."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        env_save(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Save the environment before changing PATH",
+                example: "env save clean",
+                result: None,
+            },
+            Example {
+                description: "Save the environment and directory of a project",
+                example: "env save work --cwd",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn env_save(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let scope = args.scope.clone();
+    let shell_manager = args.shell_manager.clone();
+    let (Arguments { name, cwd }, _) = args.process().await?;
+
+    if !env_snapshot::is_valid_name(&name.item) {
+        return Err(ShellError::labeled_error(
+            "Invalid snapshot name",
+            "the name can't be empty or have a path separator in it",
+            &name.tag,
+        ));
+    }
+
+    let snapshot = EnvSnapshot {
+        cwd: if cwd {
+            Some(PathBuf::from(shell_manager.path()))
+        } else {
+            None
+        },
+        env: scope.get_env_vars(),
+    };
+
+    env_snapshot::save(&name.item, &snapshot)?;
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnvSave;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(EnvSave {})
+    }
+}
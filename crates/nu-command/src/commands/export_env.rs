@@ -0,0 +1,78 @@
+use crate::prelude::*;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, Signature, SyntaxShape};
+
+pub struct ExportEnv;
+
+#[derive(Deserialize, Debug)]
+struct ExportEnvArgs {
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for ExportEnv {
+    fn name(&self) -> &str {
+        "export-env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("export-env").required(
+            "block",
+            SyntaxShape::Block,
+            "the block that sets up the environment",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs a block and keeps the environment variables it sets."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The variables and commands the block creates are only there while it runs. This lets a file that is sourced set up the environment without leaving its helper variables behind."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        export_env(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Set an environment variable from a helper variable",
+            example: r#"export-env { let root = "/opt/java"; let-env JAVA_HOME = $root }"#,
+            result: None,
+        }]
+    }
+}
+
+async fn export_env(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = EvaluationContext::from_args(&raw_args);
+    let (ExportEnvArgs { block }, input) = raw_args.process().await?;
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+    let result = run_block(&block.block, &context, input).await;
+
+    // The block is run to the end before its scope is left, so everything it sets is kept
+    let output = match result {
+        Ok(mut stream) => Ok(stream.drain_vec().await),
+        Err(err) => Err(err),
+    };
+    context.scope.exit_scope_with_env();
+
+    Ok(futures::stream::iter(output?).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExportEnv;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(ExportEnv {})
+    }
+}
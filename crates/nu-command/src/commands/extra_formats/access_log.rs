@@ -0,0 +1,41 @@
+use super::logs::{parse_access_log_line, ACCESS_LOG_PATTERN};
+use super::{parse_error, ExtraFormat};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::Value;
+use regex::Regex;
+
+pub struct AccessLog;
+
+impl ExtraFormat for AccessLog {
+    fn name(&self) -> &'static str {
+        "access-log"
+    }
+
+    fn usage(&self) -> &'static str {
+        "Parse Apache/Nginx access logs (common or combined format) and create a table."
+    }
+
+    fn parse(&self, input: &str, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+        let regex = Regex::new(ACCESS_LOG_PATTERN)
+            .map_err(|e| parse_error("access log", e.to_string(), tag))?;
+
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                parse_access_log_line(&regex, line, tag).ok_or_else(|| {
+                    parse_error("access log", format!("unrecognized line '{}'", line), tag)
+                })
+            })
+            .collect()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Count requests per status code",
+            example: "open access.log | from access-log | group-by status",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,107 @@
+use super::{parse_error, ExtraFormat};
+use crate::prelude::*;
+use chrono::{FixedOffset, TimeZone};
+use nu_errors::ShellError;
+use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+
+/// Fields holding microseconds since the epoch, which are turned into dates.
+const TIMESTAMP_FIELDS: &[&str] = &["__REALTIME_TIMESTAMP", "_SOURCE_REALTIME_TIMESTAMP"];
+
+pub struct Journald;
+
+impl ExtraFormat for Journald {
+    fn name(&self) -> &'static str {
+        "journald"
+    }
+
+    fn usage(&self) -> &'static str {
+        "Parse the journald export format (journalctl -o export) and create a table."
+    }
+
+    fn parse(&self, input: &str, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+        let bytes = input.as_bytes();
+        let mut rows = vec![];
+        let mut row = TaggedDictBuilder::new(tag);
+        let mut pos = 0;
+
+        while pos < bytes.len() {
+            let line_end = bytes[pos..]
+                .iter()
+                .position(|b| *b == b'\n')
+                .map(|offset| pos + offset)
+                .unwrap_or_else(|| bytes.len());
+            let line = &bytes[pos..line_end];
+
+            if line.is_empty() {
+                // A blank line ends the current entry
+                if !row.is_empty() {
+                    rows.push(row.into_value());
+                    row = TaggedDictBuilder::new(tag);
+                }
+                pos = line_end + 1;
+            } else if let Some(eq) = line.iter().position(|b| *b == b'=') {
+                let name = String::from_utf8_lossy(&line[..eq]).to_string();
+                let value = String::from_utf8_lossy(&line[eq + 1..]).to_string();
+                row.insert_untagged(name.clone(), field_value(&name, value));
+                pos = line_end + 1;
+            } else {
+                // Binary-safe field: the name is followed by a little-endian u64 length and
+                // the raw data, terminated by a newline.
+                let name = String::from_utf8_lossy(line).to_string();
+                let size_start = line_end + 1;
+                let data_start = size_start + 8;
+
+                if data_start > bytes.len() {
+                    return Err(parse_error(
+                        "journald export",
+                        format!("field '{}' is missing its size", name),
+                        tag,
+                    ));
+                }
+
+                let mut size = [0u8; 8];
+                size.copy_from_slice(&bytes[size_start..data_start]);
+                let data_end = data_start + u64::from_le_bytes(size) as usize;
+
+                if data_end > bytes.len() {
+                    return Err(parse_error(
+                        "journald export",
+                        format!("field '{}' is truncated", name),
+                        tag,
+                    ));
+                }
+
+                let value = String::from_utf8_lossy(&bytes[data_start..data_end]).to_string();
+                row.insert_untagged(name.clone(), field_value(&name, value));
+                pos = data_end + 1;
+            }
+        }
+
+        if !row.is_empty() {
+            rows.push(row.into_value());
+        }
+
+        Ok(rows)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the messages of a unit's journal",
+            example: "^journalctl -u sshd -o export | from journald | select __REALTIME_TIMESTAMP MESSAGE",
+            result: None,
+        }]
+    }
+}
+
+fn field_value(name: &str, value: String) -> UntaggedValue {
+    if TIMESTAMP_FIELDS.contains(&name) {
+        if let Ok(micros) = value.parse::<i64>() {
+            let utc = FixedOffset::east(0);
+            return UntaggedValue::date(
+                utc.timestamp(micros / 1_000_000, (micros % 1_000_000) as u32 * 1000),
+            );
+        }
+    }
+
+    UntaggedValue::string(value)
+}
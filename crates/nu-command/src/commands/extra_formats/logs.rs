@@ -0,0 +1,358 @@
+use super::{parse_error, ExtraFormat};
+use crate::commands::from_json::from_json_string_to_value;
+use crate::commands::from_logfmt::parse_logfmt_line;
+use crate::prelude::*;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+use regex::Regex;
+
+const RFC5424_PATTERN: &str =
+    r"^<(\d{1,3})>\d (\S+) (\S+) (\S+) (\S+) (\S+) (?:-|\[.*?\])(?: (.*))?$";
+const RFC3164_PATTERN: &str = r"^(?:<(\d{1,3})>)?([A-Z][a-z]{2} [ \d]\d \d{2}:\d{2}:\d{2}) (\S+) ([^:\[\s]+)(?:\[(\d+)\])?: ?(.*)$";
+
+/// Matches the Common Log Format, optionally followed by the referer and user agent of the
+/// Combined Log Format (the default for both Apache and Nginx).
+pub(crate) const ACCESS_LOG_PATTERN: &str =
+    r#"^(\S+) (\S+) (\S+) \[([^\]]+)\] "([^"]*)" (\d{3}) (\S+)(?: "([^"]*)" "([^"]*)")?"#;
+const ACCESS_LOG_TIME_FORMAT: &str = "%d/%b/%Y:%H:%M:%S %z";
+
+const TIMESTAMP_KEYS: &[&str] = &["timestamp", "@timestamp", "time", "ts", "date"];
+const LEVEL_KEYS: &[&str] = &["level", "lvl", "severity", "loglevel"];
+const SOURCE_KEYS: &[&str] = &["source", "logger", "component", "service", "host"];
+const MESSAGE_KEYS: &[&str] = &["message", "msg", "@message", "text"];
+
+lazy_static::lazy_static! {
+    static ref PARSERS: Result<LogParsers, regex::Error> = LogParsers::new();
+}
+
+pub struct Logs;
+
+impl ExtraFormat for Logs {
+    fn name(&self) -> &'static str {
+        "logs"
+    }
+
+    fn usage(&self) -> &'static str {
+        "Detect the format of each log line (syslog, access log, JSON lines, logfmt) and create a normalized table."
+    }
+
+    fn parse(&self, input: &str, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+        let parsers = PARSERS
+            .as_ref()
+            .map_err(|e| parse_error("logs", e.to_string(), tag))?;
+
+        Ok(input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| parsers.parse_line(line, tag))
+            .collect())
+    }
+
+    fn by_line(&self) -> bool {
+        true
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show only the errors of a log file, whatever its format",
+            example: "open app.log | from logs | where level == error",
+            result: None,
+        }]
+    }
+}
+
+/// The columns every log line is normalized into.
+struct LogEntry {
+    format: &'static str,
+    timestamp: UntaggedValue,
+    level: UntaggedValue,
+    source: UntaggedValue,
+    message: UntaggedValue,
+    fields: TaggedDictBuilder,
+}
+
+impl LogEntry {
+    fn new(format: &'static str, tag: &Tag) -> LogEntry {
+        LogEntry {
+            format,
+            timestamp: UntaggedValue::nothing(),
+            level: UntaggedValue::nothing(),
+            source: UntaggedValue::nothing(),
+            message: UntaggedValue::nothing(),
+            fields: TaggedDictBuilder::new(tag),
+        }
+    }
+
+    fn into_value(self, tag: &Tag) -> Value {
+        let mut row = TaggedDictBuilder::new(tag);
+        row.insert_untagged("timestamp", self.timestamp);
+        row.insert_untagged("level", self.level);
+        row.insert_untagged("source", self.source);
+        row.insert_untagged("message", self.message);
+        row.insert_value("fields", self.fields.into_value());
+        row.insert_untagged("format", UntaggedValue::string(self.format));
+        row.into_value()
+    }
+
+    /// Sorts a key/value pair into one of the normalized columns, or into `fields`.
+    fn insert(&mut self, key: &str, value: Value) {
+        let lowercase = key.to_lowercase();
+        let lowercase = lowercase.as_str();
+
+        if TIMESTAMP_KEYS.contains(&lowercase) && self.timestamp.is_none() {
+            self.timestamp = match value.as_string() {
+                Ok(s) => parse_timestamp(&s).unwrap_or(value.value),
+                Err(_) => value.value,
+            };
+        } else if LEVEL_KEYS.contains(&lowercase) && self.level.is_none() {
+            self.level = match value.as_string() {
+                Ok(s) => UntaggedValue::string(normalize_level(&s)),
+                Err(_) => value.value,
+            };
+        } else if SOURCE_KEYS.contains(&lowercase) && self.source.is_none() {
+            self.source = value.value;
+        } else if MESSAGE_KEYS.contains(&lowercase) && self.message.is_none() {
+            self.message = value.value;
+        } else {
+            self.fields.insert_value(key, value);
+        }
+    }
+}
+
+struct LogParsers {
+    access_log: Regex,
+    rfc5424: Regex,
+    rfc3164: Regex,
+}
+
+impl LogParsers {
+    fn new() -> Result<LogParsers, regex::Error> {
+        Ok(LogParsers {
+            access_log: Regex::new(ACCESS_LOG_PATTERN)?,
+            rfc5424: Regex::new(RFC5424_PATTERN)?,
+            rfc3164: Regex::new(RFC3164_PATTERN)?,
+        })
+    }
+
+    fn parse_line(&self, line: &str, tag: &Tag) -> Value {
+        self.parse_json(line, tag)
+            .or_else(|| self.parse_syslog(line, tag))
+            .or_else(|| self.parse_access_log(line, tag))
+            .or_else(|| parse_logfmt(line, tag))
+            .unwrap_or_else(|| {
+                let mut entry = LogEntry::new("unknown", tag);
+                entry.message = UntaggedValue::string(line);
+                entry
+            })
+            .into_value(tag)
+    }
+
+    fn parse_json(&self, line: &str, tag: &Tag) -> Option<LogEntry> {
+        if !line.trim_start().starts_with('{') {
+            return None;
+        }
+
+        let value = from_json_string_to_value(line.to_string(), tag).ok()?;
+        if !value.is_row() {
+            return None;
+        }
+
+        let mut entry = LogEntry::new("json", tag);
+        for (key, value) in value.row_entries() {
+            entry.insert(key, value.clone());
+        }
+
+        Some(entry)
+    }
+
+    fn parse_syslog(&self, line: &str, tag: &Tag) -> Option<LogEntry> {
+        if let Some(captures) = self.rfc5424.captures(line) {
+            let field = |idx: usize| captures.get(idx).map(|m| m.as_str()).unwrap_or("-");
+            let mut entry = LogEntry::new("syslog", tag);
+
+            insert_priority(&mut entry, field(1));
+            entry.timestamp =
+                parse_timestamp(field(2)).unwrap_or_else(|| UntaggedValue::string(field(2)));
+            entry.source = UntaggedValue::string(field(4));
+            entry.message = UntaggedValue::string(captures.get(7).map_or("", |m| m.as_str()));
+            entry
+                .fields
+                .insert_untagged("host", UntaggedValue::string(field(3)));
+            entry
+                .fields
+                .insert_untagged("pid", UntaggedValue::string(field(5)));
+            entry
+                .fields
+                .insert_untagged("msgid", UntaggedValue::string(field(6)));
+
+            return Some(entry);
+        }
+
+        let captures = self.rfc3164.captures(line)?;
+        let mut entry = LogEntry::new("syslog", tag);
+
+        if let Some(priority) = captures.get(1) {
+            insert_priority(&mut entry, priority.as_str());
+        }
+
+        // BSD syslog timestamps have no year, so assume the current one
+        let timestamp = &captures[2];
+        entry.timestamp = NaiveDateTime::parse_from_str(
+            &format!("{} {}", Utc::now().year(), timestamp),
+            "%Y %b %e %H:%M:%S",
+        )
+        .map(|naive| UntaggedValue::date(DateTime::<FixedOffset>::from_utc(naive, utc())))
+        .unwrap_or_else(|_| UntaggedValue::string(timestamp));
+        entry.source = UntaggedValue::string(&captures[4]);
+        entry.message = UntaggedValue::string(&captures[6]);
+        entry
+            .fields
+            .insert_untagged("host", UntaggedValue::string(&captures[3]));
+        if let Some(pid) = captures.get(5) {
+            entry
+                .fields
+                .insert_untagged("pid", UntaggedValue::string(pid.as_str()));
+        }
+
+        Some(entry)
+    }
+
+    fn parse_access_log(&self, line: &str, tag: &Tag) -> Option<LogEntry> {
+        let row = parse_access_log_line(&self.access_log, line, tag)?;
+        let mut entry = LogEntry::new("access-log", tag);
+
+        for (key, value) in row.row_entries() {
+            match key.as_str() {
+                "time" => entry.timestamp = value.value.clone(),
+                "host" => entry.source = value.value.clone(),
+                "status" => {
+                    let status = value.as_i64().unwrap_or(0);
+                    entry.level = UntaggedValue::string(match status {
+                        500..=599 => "error",
+                        400..=499 => "warn",
+                        _ => "info",
+                    });
+                    entry.fields.insert_value(key, value.clone());
+                }
+                _ => entry.fields.insert_value(key, value.clone()),
+            }
+        }
+
+        let request = line
+            .splitn(2, '"')
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap_or("");
+        entry.message = UntaggedValue::string(request);
+
+        Some(entry)
+    }
+}
+
+fn parse_logfmt(line: &str, tag: &Tag) -> Option<LogEntry> {
+    // Only take the line as logfmt if every token is a `key=value` pair
+    let pairs = parse_logfmt_line(line).ok()?;
+    if pairs.is_empty() || pairs.iter().any(|(_, value)| value.is_none()) {
+        return None;
+    }
+
+    let mut entry = LogEntry::new("logfmt", tag);
+    for (key, value) in pairs {
+        let value = value.unwrap_or_default();
+        entry.insert(&key, UntaggedValue::string(value).into_value(tag));
+    }
+
+    Some(entry)
+}
+
+fn insert_priority(entry: &mut LogEntry, priority: &str) {
+    if let Ok(priority) = priority.parse::<u8>() {
+        entry.level = UntaggedValue::string(syslog_severity(priority % 8));
+        entry
+            .fields
+            .insert_untagged("facility", UntaggedValue::int(priority / 8));
+    }
+}
+
+fn syslog_severity(severity: u8) -> &'static str {
+    match severity {
+        0..=2 => "critical",
+        3 => "error",
+        4 => "warn",
+        5 | 6 => "info",
+        _ => "debug",
+    }
+}
+
+fn normalize_level(level: &str) -> String {
+    match level.to_lowercase().as_str() {
+        "warning" => "warn".into(),
+        "err" => "error".into(),
+        "fatal" | "crit" | "emerg" | "alert" | "panic" => "critical".into(),
+        "trace" | "verbose" => "debug".into(),
+        other => other.into(),
+    }
+}
+
+fn parse_timestamp(s: &str) -> Option<UntaggedValue> {
+    let date = match DateTime::parse_from_rfc3339(s) {
+        Ok(date) => date,
+        Err(_) => {
+            let (naive, offset) = dtparse::parse(s).ok()?;
+            offset
+                .unwrap_or_else(utc)
+                .from_local_datetime(&naive)
+                .earliest()?
+        }
+    };
+
+    Some(UntaggedValue::date(date))
+}
+
+fn utc() -> FixedOffset {
+    FixedOffset::east(0)
+}
+
+pub(crate) fn parse_access_log_line(regex: &Regex, line: &str, tag: &Tag) -> Option<Value> {
+    let captures = regex.captures(line)?;
+    let field = |idx: usize| captures.get(idx).map(|m| m.as_str()).unwrap_or("");
+
+    let optional_string = |value: &str| {
+        if value == "-" || value.is_empty() {
+            UntaggedValue::nothing()
+        } else {
+            UntaggedValue::string(value)
+        }
+    };
+
+    let mut request = field(5).splitn(3, ' ');
+    let method = request.next().unwrap_or("");
+    let path = request.next().unwrap_or("");
+    let protocol = request.next().unwrap_or("");
+
+    let time = match DateTime::parse_from_str(field(4), ACCESS_LOG_TIME_FORMAT) {
+        Ok(time) => UntaggedValue::date(time),
+        Err(_) => UntaggedValue::string(field(4)),
+    };
+
+    let size = match field(7).parse::<u64>() {
+        Ok(size) => UntaggedValue::filesize(size),
+        Err(_) => UntaggedValue::nothing(),
+    };
+
+    let mut row = TaggedDictBuilder::new(tag);
+    row.insert_untagged("host", UntaggedValue::string(field(1)));
+    row.insert_untagged("ident", optional_string(field(2)));
+    row.insert_untagged("user", optional_string(field(3)));
+    row.insert_untagged("time", time);
+    row.insert_untagged("method", optional_string(method));
+    row.insert_untagged("path", optional_string(path));
+    row.insert_untagged("protocol", optional_string(protocol));
+    row.insert_untagged("status", UntaggedValue::int(field(6).parse::<i64>().ok()?));
+    row.insert_untagged("size", size);
+    row.insert_untagged("referer", optional_string(field(8)));
+    row.insert_untagged("agent", optional_string(field(9)));
+
+    Some(row.into_value())
+}
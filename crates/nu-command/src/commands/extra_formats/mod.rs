@@ -0,0 +1,184 @@
+//! Parsers for long-tail text formats (subtitles, web server access logs, journald exports,
+//! mixed application logs).
+//!
+//! Every format implements [`ExtraFormat`] and is listed in [`formats`]. The `from <name>`
+//! commands are generated from that list, so adding a format only requires a new parser and
+//! a new entry in the registry. `from logs` comes with the other formats, the rest with the
+//! `extra-formats` feature.
+
+#[cfg(feature = "extra-formats")]
+mod access_log;
+#[cfg(feature = "extra-formats")]
+mod journald;
+mod logs;
+#[cfg(feature = "extra-formats")]
+mod subtitles;
+
+use crate::prelude::*;
+use nu_engine::{whole_stream_command, Command, WholeStreamCommand};
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+
+pub trait ExtraFormat: Send + Sync {
+    /// The format name, as used in `from <name>` and matched against file extensions by `open`.
+    fn name(&self) -> &'static str;
+
+    fn usage(&self) -> &'static str;
+
+    /// Parse the whole input into rows.
+    fn parse(&self, input: &str, tag: &Tag) -> Result<Vec<Value>, ShellError>;
+
+    /// Whether every line is a record of its own. The lines are then parsed as they arrive,
+    /// rather than once the whole input is in.
+    fn by_line(&self) -> bool {
+        false
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+}
+
+/// All registered extra formats.
+pub fn formats() -> Vec<Box<dyn ExtraFormat>> {
+    let mut formats: Vec<Box<dyn ExtraFormat>> = vec![Box::new(logs::Logs)];
+
+    #[cfg(feature = "extra-formats")]
+    formats.extend(long_tail_formats());
+
+    formats
+}
+
+/// The formats that come with the extra-formats feature
+#[cfg(feature = "extra-formats")]
+fn long_tail_formats() -> Vec<Box<dyn ExtraFormat>> {
+    vec![
+        Box::new(subtitles::Srt),
+        Box::new(subtitles::Vtt),
+        Box::new(access_log::AccessLog),
+        Box::new(journald::Journald),
+    ]
+}
+
+/// A `from <name>` command for every registered extra format.
+pub fn commands() -> Vec<Command> {
+    formats()
+        .into_iter()
+        .map(|format| whole_stream_command(FromExtraFormat::new(format)))
+        .collect()
+}
+
+pub struct FromExtraFormat {
+    command_name: String,
+    format: Arc<dyn ExtraFormat>,
+}
+
+impl FromExtraFormat {
+    pub fn new(format: Box<dyn ExtraFormat>) -> FromExtraFormat {
+        FromExtraFormat {
+            command_name: format!("from {}", format.name()),
+            format: format.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for FromExtraFormat {
+    fn name(&self) -> &str {
+        &self.command_name
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(&self.command_name)
+    }
+
+    fn usage(&self) -> &str {
+        self.format.usage()
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let args = args.evaluate_once().await?;
+        let tag = args.name_tag();
+        let input = args.input;
+
+        if self.format.by_line() {
+            return Ok(parse_by_line(self.format.clone(), input, tag));
+        }
+
+        let concat_string = input.collect_string(tag.clone()).await?;
+        let rows = self.format.parse(&concat_string.item, &tag)?;
+
+        Ok(futures::stream::iter(rows.into_iter().map(ReturnSuccess::value)).to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        self.format.examples()
+    }
+}
+
+/// Parses the lines as soon as they're complete, so the rows come out as the input arrives
+fn parse_by_line(format: Arc<dyn ExtraFormat>, input: InputStream, tag: Tag) -> OutputStream {
+    let mut rest: Vec<u8> = vec![];
+
+    input
+        .map(Some)
+        .chain(futures::stream::once(async { None }))
+        .map(move |value| {
+            match value {
+                Some(value) => match value.value {
+                    UntaggedValue::Primitive(Primitive::String(s)) => rest.extend(s.into_bytes()),
+                    UntaggedValue::Primitive(Primitive::Binary(b)) => rest.extend(b),
+                    UntaggedValue::Primitive(Primitive::Nothing) => {}
+                    _ => {
+                        return vec![Err(ShellError::labeled_error_with_secondary(
+                            "Expected a string from pipeline",
+                            "requires string input",
+                            &tag,
+                            "value originates from here",
+                            &value.tag,
+                        ))]
+                    }
+                },
+                // The end of the input ends the last line too
+                None => rest.push(b'\n'),
+            }
+
+            // A newline can't be part of a longer character, so the lines are whole text
+            let lines: Vec<u8> = match rest.iter().rposition(|byte| *byte == b'\n') {
+                Some(end) => rest.drain(..=end).collect(),
+                None => return vec![],
+            };
+
+            match format.parse(&String::from_utf8_lossy(&lines), &tag) {
+                Ok(rows) => rows.into_iter().map(ReturnSuccess::value).collect(),
+                Err(err) => vec![Err(err)],
+            }
+        })
+        .flat_map(futures::stream::iter)
+        .to_output_stream()
+}
+
+fn parse_error(format: &str, message: impl Into<String>, tag: &Tag) -> ShellError {
+    ShellError::labeled_error(
+        format!("Could not parse as {}", format),
+        message,
+        tag.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{formats, FromExtraFormat};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        for format in formats() {
+            test_examples(FromExtraFormat::new(format))?;
+        }
+
+        Ok(())
+    }
+}
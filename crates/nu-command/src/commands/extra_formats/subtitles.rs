@@ -0,0 +1,207 @@
+use super::{parse_error, ExtraFormat};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+
+const NANOS_PER_MILLI: i64 = 1_000_000;
+
+pub struct Srt;
+
+impl ExtraFormat for Srt {
+    fn name(&self) -> &'static str {
+        "srt"
+    }
+
+    fn usage(&self) -> &'static str {
+        "Parse text as .srt subtitles and create a table of cues."
+    }
+
+    fn parse(&self, input: &str, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+        let mut rows = vec![];
+
+        for block in cue_blocks(input) {
+            let mut lines = block.into_iter();
+
+            let index = match lines.next() {
+                Some(line) => line.trim().parse::<i64>().map_err(|_| {
+                    parse_error(
+                        "srt",
+                        format!("expected a cue number, found '{}'", line),
+                        tag,
+                    )
+                })?,
+                None => continue,
+            };
+
+            let (start, end, _) = match lines.next() {
+                Some(line) => parse_timing(line, ',')
+                    .ok_or_else(|| parse_error("srt", format!("invalid timing '{}'", line), tag))?,
+                None => return Err(parse_error("srt", "cue is missing its timing", tag)),
+            };
+
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("index", UntaggedValue::int(index));
+            row.insert_untagged("start", UntaggedValue::duration(start));
+            row.insert_untagged("end", UntaggedValue::duration(end));
+            row.insert_untagged(
+                "text",
+                UntaggedValue::string(lines.collect::<Vec<_>>().join("\n")),
+            );
+            rows.push(row.into_value());
+        }
+
+        Ok(rows)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the text of every cue of a subtitle file",
+            example: "open movie.srt | get text",
+            result: None,
+        }]
+    }
+}
+
+pub struct Vtt;
+
+impl ExtraFormat for Vtt {
+    fn name(&self) -> &'static str {
+        "vtt"
+    }
+
+    fn usage(&self) -> &'static str {
+        "Parse text as WebVTT subtitles and create a table of cues."
+    }
+
+    fn parse(&self, input: &str, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+        let mut blocks = cue_blocks(input).into_iter();
+
+        match blocks.next() {
+            Some(header)
+                if header[0]
+                    .trim_start_matches('\u{feff}')
+                    .starts_with("WEBVTT") => {}
+            _ => return Err(parse_error("vtt", "missing WEBVTT header", tag)),
+        }
+
+        let mut rows = vec![];
+
+        for block in blocks {
+            let first = block[0].trim();
+            if first.starts_with("NOTE") || first == "STYLE" || first == "REGION" {
+                continue;
+            }
+
+            let mut lines = block.into_iter().peekable();
+            let id = match lines.peek() {
+                Some(line) if !line.contains("-->") => lines.next().map(|id| id.trim().to_string()),
+                _ => None,
+            };
+
+            let (start, end, settings) = match lines.next() {
+                Some(line) => parse_timing(line, '.')
+                    .ok_or_else(|| parse_error("vtt", format!("invalid timing '{}'", line), tag))?,
+                None => return Err(parse_error("vtt", "cue is missing its timing", tag)),
+            };
+
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged(
+                "id",
+                match id {
+                    Some(id) => UntaggedValue::string(id),
+                    None => UntaggedValue::nothing(),
+                },
+            );
+            row.insert_untagged("start", UntaggedValue::duration(start));
+            row.insert_untagged("end", UntaggedValue::duration(end));
+            row.insert_untagged("settings", UntaggedValue::string(settings));
+            row.insert_untagged(
+                "text",
+                UntaggedValue::string(lines.collect::<Vec<_>>().join("\n")),
+            );
+            rows.push(row.into_value());
+        }
+
+        Ok(rows)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find the cues that start after the first minute",
+            example: "open captions.vtt | where start > 1min",
+            result: None,
+        }]
+    }
+}
+
+/// Splits the input into blocks of non-empty lines separated by blank lines.
+fn cue_blocks(input: &str) -> Vec<Vec<&str>> {
+    let mut blocks = vec![];
+    let mut current = vec![];
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    blocks
+}
+
+/// Parses a `start --> end [settings]` line into nanosecond offsets and the trailing settings.
+fn parse_timing(line: &str, fraction_separator: char) -> Option<(i64, i64, String)> {
+    let mut parts = line.splitn(2, "-->");
+    let start = parse_timestamp(parts.next()?.trim(), fraction_separator)?;
+
+    let mut rest = parts.next()?.trim().splitn(2, char::is_whitespace);
+    let end = parse_timestamp(rest.next()?, fraction_separator)?;
+    let settings = rest.next().unwrap_or("").trim().to_string();
+
+    Some((start, end, settings))
+}
+
+/// Parses `hh:mm:ss,mmm` (SRT) or `[hh:]mm:ss.mmm` (WebVTT) into nanoseconds.
+fn parse_timestamp(timestamp: &str, fraction_separator: char) -> Option<i64> {
+    let mut parts = timestamp.splitn(2, fraction_separator);
+    let clock = parts.next()?;
+    let millis = parts.next()?.parse::<i64>().ok()?;
+
+    let units = clock
+        .split(':')
+        .map(|part| part.parse::<i64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let seconds = match units.as_slice() {
+        [h, m, s] => h * 3600 + m * 60 + s,
+        [m, s] => m * 60 + s,
+        _ => return None,
+    };
+
+    Some((seconds * 1000 + millis) * NANOS_PER_MILLI)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_timestamp, NANOS_PER_MILLI};
+
+    #[test]
+    fn parses_srt_and_vtt_timestamps() {
+        assert_eq!(
+            parse_timestamp("01:02:03,004", ','),
+            Some(3_723_004 * NANOS_PER_MILLI)
+        );
+        assert_eq!(
+            parse_timestamp("02:03.004", '.'),
+            Some(123_004 * NANOS_PER_MILLI)
+        );
+        assert_eq!(parse_timestamp("02:03", '.'), None);
+    }
+}
@@ -11,6 +11,8 @@ pub struct Command;
 #[derive(Deserialize)]
 pub struct Arguments {
     rest: Vec<Tagged<String>>,
+    all: bool,
+    depth: Option<Tagged<u64>>,
 }
 
 #[async_trait]
@@ -20,13 +22,34 @@ impl WholeStreamCommand for Command {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("flatten").rest(SyntaxShape::String, "optionally flatten data by column")
+        Signature::build("flatten")
+            .rest(SyntaxShape::String, "optionally flatten data by column")
+            .switch(
+                "all",
+                "keep flattening until nothing is nested anymore",
+                Some('a'),
+            )
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "how many levels of nesting to flatten (default: 1)",
+                Some('d'),
+            )
     }
 
     fn usage(&self) -> &str {
         "Flatten the table."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"The columns of a nested row become columns of the row it's in, and the rows of a nested table
+each become a row, with the other columns repeated. A column whose name is taken already gets the
+name of the column it came from in front, eg people_name, and a number after if that's taken too.
+
+With column names, only those columns are flattened, and at deeper levels only what came out of
+them."#
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         flatten(args).await
     }
@@ -47,6 +70,16 @@ impl WholeStreamCommand for Command {
                 description: "restrict the flattening by passing column names",
                 example: "echo [[origin, crate, versions]; [World, $(echo [[name]; ['nu-cli']]), ['0.21', '0.22']]] | flatten versions | last | get versions",
                 result: Some(vec![Value::from("0.22")]),
+            },
+            Example {
+                description: "flatten a response until nothing is nested",
+                example: "fetch https://api.github.com/repos/nushell/nushell | from json | flatten --all",
+                result: None,
+            },
+            Example {
+                description: "flatten two levels of a column",
+                example: "open package.json | flatten repository --depth 2",
+                result: None,
             }
         ]
     }
@@ -54,14 +87,108 @@ impl WholeStreamCommand for Command {
 
 async fn flatten(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let tag = args.call_info.name_tag.clone();
-    let (Arguments { rest: columns }, input) = args.process().await?;
+    let (
+        Arguments {
+            rest: columns,
+            all,
+            depth,
+        },
+        input,
+    ) = args.process().await?;
+
+    let depth = match depth {
+        Some(depth) if depth.item == 0 => {
+            return Err(ShellError::labeled_error(
+                "Expected a depth of at least 1",
+                "cannot flatten 0 levels",
+                &depth.tag,
+            ))
+        }
+        _ if all => None,
+        Some(depth) => Some(depth.item),
+        None => Some(1),
+    };
 
     Ok(input
-        .map(move |item| futures::stream::iter(flat_value(&columns, &item, &tag).into_iter()))
+        .map(move |item| futures::stream::iter(flat_levels(&columns, item, depth, &tag)))
         .flatten()
         .to_output_stream())
 }
 
+/// Flattens the item again and again, each time the columns that came out of the columns flattened
+/// before, until the depth or until nothing is nested anymore
+fn flat_levels(
+    columns: &[Tagged<String>],
+    item: Value,
+    depth: Option<u64>,
+    name_tag: &Tag,
+) -> Vec<Result<ReturnSuccess, ShellError>> {
+    let mut out = vec![];
+    let mut pending = vec![(item, columns.to_vec(), 0)];
+
+    while let Some((item, columns, level)) = pending.pop() {
+        let done = level > 0
+            && (depth.map(|depth| level >= depth).unwrap_or(false) || !is_nested(&item, &columns));
+        if done {
+            out.push(ReturnSuccess::value(item));
+            continue;
+        }
+
+        let before = item
+            .row_entries()
+            .map(|(column, _)| column.clone())
+            .collect::<Vec<_>>();
+        let flattened = flat_value(&columns, &item, name_tag);
+
+        // Flattened in reverse, so they're taken off the stack in order
+        for flat in flattened.into_iter().rev() {
+            match flat {
+                Ok(ReturnSuccess::Value(flat)) => {
+                    let next_columns = if columns.is_empty() {
+                        vec![]
+                    } else {
+                        flat.row_entries()
+                            .map(|(column, _)| column)
+                            .filter(|column| {
+                                !before.contains(*column)
+                                    || columns.iter().any(|c| c.item == **column)
+                            })
+                            .map(|column| column.clone().tagged(name_tag))
+                            .collect()
+                    };
+
+                    pending.push((flat, next_columns, level + 1));
+                }
+                other => out.push(other),
+            }
+        }
+    }
+
+    out
+}
+
+/// Whether the item is a row with a (requested) column that's a row or a table
+fn is_nested(item: &Value, columns: &[Tagged<String>]) -> bool {
+    item.row_entries().any(|(column, value)| {
+        (columns.is_empty() || columns.iter().any(|c| c.item == *column))
+            && (value.is_row() || value.is_table())
+    })
+}
+
+/// Inserts the value, with a number after its name if the name is taken
+fn insert_unique(out: &mut TaggedDictBuilder, name: impl Into<String>, value: Value) {
+    let name = name.into();
+    let mut unique = name.clone();
+    let mut number = 1;
+
+    while out.contains_key(&unique) {
+        number += 1;
+        unique = format!("{}_{}", name, number);
+    }
+
+    out.insert_value(unique, value);
+}
+
 enum TableInside<'a> {
     Entries(&'a str, &'a Tag, Vec<&'a Value>),
 }
@@ -90,7 +217,11 @@ fn flat_value(
                 {
                     if column_requested.is_none() && !columns.is_empty() {
                         if out.contains_key(&column) {
-                            out.insert_value(format!("{}_{}", column, column), value.clone());
+                            insert_unique(
+                                &mut out,
+                                format!("{}_{}", column, column),
+                                value.clone(),
+                            );
                         } else {
                             out.insert_value(column, value.clone());
                         }
@@ -99,7 +230,7 @@ fn flat_value(
 
                     for (k, v) in mapa.into_iter() {
                         if out.contains_key(k) {
-                            out.insert_value(format!("{}_{}", column, k), v.clone());
+                            insert_unique(&mut out, format!("{}_{}", column, k), v.clone());
                         } else {
                             out.insert_value(k, v.clone());
                         }
@@ -141,7 +272,7 @@ fn flat_value(
 
                             tables_explicitly_flattened += 1;
                         } else {
-                            out.insert_value(column, value.clone());
+                            insert_unique(&mut out, column, value.clone());
                         }
                     } else if a_table.is_none() {
                         a_table = Some(TableInside::Entries(
@@ -150,10 +281,10 @@ fn flat_value(
                             value.table_entries().collect(),
                         ))
                     } else {
-                        out.insert_value(column, value.clone());
+                        insert_unique(&mut out, column, value.clone());
                     }
                 } else {
-                    out.insert_value(column, value.clone());
+                    insert_unique(&mut out, column, value.clone());
                 }
             }
 
@@ -162,7 +293,7 @@ fn flat_value(
             if let Some(TableInside::Entries(column, _, entries)) = a_table {
                 for entry in entries.into_iter() {
                     let mut base = out.clone();
-                    base.insert_value(column, entry.clone());
+                    insert_unique(&mut base, column, entry.clone());
                     expanded.push(base.into_value());
                 }
             } else {
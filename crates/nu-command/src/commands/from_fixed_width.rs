@@ -0,0 +1,319 @@
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use num_bigint::BigInt;
+use std::str::FromStr;
+
+pub struct FromFixedWidth;
+
+#[derive(Deserialize)]
+pub struct FromFixedWidthArgs {
+    columns: Option<Value>,
+    noheaders: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FieldType {
+    String,
+    Int,
+    Decimal,
+    Bool,
+}
+
+/// Where a column sits in each line, in characters. A column without a length runs to the end of
+/// the line.
+#[derive(Debug, Clone, PartialEq)]
+struct Field {
+    name: String,
+    start: usize,
+    length: Option<usize>,
+    field_type: FieldType,
+}
+
+#[async_trait]
+impl WholeStreamCommand for FromFixedWidth {
+    fn name(&self) -> &str {
+        "from fixed-width"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from fixed-width")
+            .named(
+                "columns",
+                SyntaxShape::Any,
+                "a table of name, start, length and (optionally) type for each column",
+                Some('c'),
+            )
+            .switch(
+                "noheaders",
+                "don't treat the first line as column names when working out the columns",
+                Some('n'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text with fixed-width columns into a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"With --columns, each line is cut at the given character positions: start counts from 0,
+the length of the last column can be left out to take the rest of the line, and the type can be
+string (the default), int, decimal or bool. Every line is data in this case.
+
+Without --columns, a column starts wherever a character follows a position that is blank on every
+line, and the first line names the columns unless --noheaders is given. Values are trimmed and
+empty values of typed columns are nothing."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        from_fixed_width(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Read an export with a column spec",
+                example: "open accounts.txt | from fixed-width --columns [[name start length type]; [id 0 4 int] [owner 4 20] [balance 24 12 decimal]]",
+                result: None,
+            },
+            Example {
+                description: "Work out the columns from the layout of a report",
+                example: "open report.txt | from fixed-width",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn from_fixed_width(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (FromFixedWidthArgs { columns, noheaders }, input) = args.process().await?;
+
+    let text = input.collect_string(name_tag.clone()).await?;
+    let mut lines: Vec<Vec<char>> = text
+        .item
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().collect())
+        .collect();
+
+    let fields = match columns {
+        Some(spec) => parse_spec(&spec)?,
+        None => {
+            let fields = infer_fields(&lines, !noheaders);
+            if !noheaders && !lines.is_empty() {
+                lines.remove(0);
+            }
+            fields
+        }
+    };
+
+    let rows = lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let mut dict = TaggedDictBuilder::new(&text.tag);
+
+            for field in &fields {
+                let text = cut(line, field.start, field.length);
+                let value = convert(&text, field.field_type).ok_or_else(|| {
+                    ShellError::labeled_error(
+                        format!("Could not read column '{}'", field.name),
+                        format!("line {}: '{}' is not a valid value", index + 1, text),
+                        &name_tag,
+                    )
+                })?;
+
+                dict.insert_untagged(&field.name, value);
+            }
+
+            Ok(ReturnSuccess::Value(dict.into_value()))
+        })
+        .collect::<Vec<_>>();
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+fn parse_spec(spec: &Value) -> Result<Vec<Field>, ShellError> {
+    let rows = match &spec.value {
+        UntaggedValue::Table(rows) if !rows.is_empty() => rows,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a column spec",
+                "expected a table with name, start and length columns",
+                &spec.tag,
+            ))
+        }
+    };
+
+    rows.iter()
+        .map(|row| {
+            let missing = |column: &str| {
+                ShellError::labeled_error(
+                    "Incomplete column spec",
+                    format!("every column needs a {}", column),
+                    &row.tag,
+                )
+            };
+
+            let name = row.get_data("name");
+            if name.value.is_none() {
+                return Err(missing("name"));
+            }
+            let start = row.get_data("start");
+            if start.value.is_none() {
+                return Err(missing("start"));
+            }
+            let length = row.get_data("length");
+            let field_type = row.get_data("type");
+
+            Ok(Field {
+                name: name.as_string()?,
+                start: start.as_u64()? as usize,
+                length: if length.value.is_none() {
+                    None
+                } else {
+                    Some(length.as_u64()? as usize)
+                },
+                field_type: if field_type.value.is_none() {
+                    FieldType::String
+                } else {
+                    match field_type.as_string()?.as_str() {
+                        "string" => FieldType::String,
+                        "int" => FieldType::Int,
+                        "decimal" => FieldType::Decimal,
+                        "bool" => FieldType::Bool,
+                        _ => {
+                            return Err(ShellError::labeled_error(
+                                "Unknown column type",
+                                "expected string, int, decimal or bool",
+                                &field_type.tag,
+                            ))
+                        }
+                    }
+                },
+            })
+        })
+        .collect()
+}
+
+/// Finds the columns from the blank positions that every line shares
+fn infer_fields(lines: &[Vec<char>], headers: bool) -> Vec<Field> {
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+    let blank = |position: usize| {
+        lines
+            .iter()
+            .all(|line| line.get(position).map_or(true, |c| c.is_whitespace()))
+    };
+
+    let starts: Vec<usize> = (0..width)
+        .filter(|&position| !blank(position) && (position == 0 || blank(position - 1)))
+        .collect();
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let length = starts.get(i + 1).map(|next| next - start);
+            let name = match lines.first() {
+                Some(header) if headers => cut(header, start, length),
+                _ => String::new(),
+            };
+
+            Field {
+                name: if name.is_empty() {
+                    format!("Column{}", i + 1)
+                } else {
+                    name
+                },
+                start,
+                length,
+                field_type: FieldType::String,
+            }
+        })
+        .collect()
+}
+
+fn cut(line: &[char], start: usize, length: Option<usize>) -> String {
+    let end = length
+        .map_or(line.len(), |length| start + length)
+        .min(line.len());
+
+    if start >= end {
+        return String::new();
+    }
+
+    line[start..end]
+        .iter()
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+fn convert(text: &str, field_type: FieldType) -> Option<UntaggedValue> {
+    if text.is_empty() && field_type != FieldType::String {
+        return Some(UntaggedValue::nothing());
+    }
+
+    match field_type {
+        FieldType::String => Some(UntaggedValue::string(text)),
+        // Leading plus signs and zeros are common in exports
+        FieldType::Int => BigInt::from_str(text.trim_start_matches('+'))
+            .ok()
+            .map(UntaggedValue::int),
+        FieldType::Decimal => BigDecimal::from_str(text.trim_start_matches('+'))
+            .ok()
+            .map(UntaggedValue::decimal),
+        FieldType::Bool => match text.to_ascii_lowercase().as_str() {
+            "true" | "t" | "yes" | "y" | "1" => Some(UntaggedValue::boolean(true)),
+            "false" | "f" | "no" | "n" | "0" => Some(UntaggedValue::boolean(false)),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{infer_fields, Field, FieldType, FromFixedWidth};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(FromFixedWidth {})
+    }
+
+    fn lines(text: &str) -> Vec<Vec<char>> {
+        text.lines().map(|line| line.chars().collect()).collect()
+    }
+
+    fn field(name: &str, start: usize, length: Option<usize>) -> Field {
+        Field {
+            name: name.to_string(),
+            start,
+            length,
+            field_type: FieldType::String,
+        }
+    }
+
+    #[test]
+    fn infers_columns_from_shared_blanks() {
+        let fields = infer_fields(&lines("id  name\n7   ann\n42  bob"), true);
+
+        assert_eq!(
+            fields,
+            vec![field("id", 0, Some(4)), field("name", 4, None)]
+        );
+    }
+
+    #[test]
+    fn values_spanning_header_gaps_join_columns() {
+        // "a b" has no blank between the headers, so it is a single column
+        let fields = infer_fields(&lines("a b\nxyz"), false);
+
+        assert_eq!(fields, vec![field("Column1", 0, None)]);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::prelude::*;
+use indexmap::map::{Entry, IndexMap};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, TaggedDictBuilder, UntaggedValue, Value};
+
+pub struct FromLogfmt;
+
+#[async_trait]
+impl WholeStreamCommand for FromLogfmt {
+    fn name(&self) -> &str {
+        "from logfmt"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from logfmt")
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as logfmt (key=value pairs, one record per line) and create table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Values may be quoted to contain spaces, eg msg="hello world". A key repeated on the same line
+collects its values into a list, and a key without a value is read as true."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        from_logfmt(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert logfmt into a table",
+                example: r#"echo 'level=info msg="server started" port=8080' | from logfmt"#,
+                result: Some(vec![row! {
+                    "level".into() => Value::from("info"),
+                    "msg".into() => Value::from("server started"),
+                    "port".into() => Value::from("8080")
+                }]),
+            },
+            Example {
+                description: "Repeated keys become lists",
+                example: "echo 'tag=a tag=b' | from logfmt",
+                result: Some(vec![row! {
+                    "tag".into() => UntaggedValue::table(&[
+                        Value::from("a"),
+                        Value::from("b")
+                    ]).into_untagged_value()
+                }]),
+            },
+        ]
+    }
+}
+
+/// Splits a single logfmt line into its keys and values. Keys given without a value
+/// (eg `debug` in `level=info debug`) have no value.
+pub(crate) fn parse_logfmt_line(line: &str) -> Result<Vec<(String, Option<String>)>, String> {
+    let mut pairs = vec![];
+    let mut chars = line.trim().chars().peekable();
+
+    while chars.peek().is_some() {
+        let mut key = String::new();
+        let mut has_value = false;
+
+        while let Some(c) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+
+            let c = *c;
+            chars.next();
+
+            if c == '=' {
+                has_value = true;
+                break;
+            } else if c == '"' {
+                return Err(format!("unexpected quote in key '{}'", key));
+            }
+            key.push(c);
+        }
+
+        if key.is_empty() {
+            return Err("expected a key before '='".to_string());
+        }
+
+        let value = if !has_value {
+            None
+        } else if chars.peek() == Some(&'"') {
+            chars.next();
+
+            let mut value = String::new();
+            let mut closed = false;
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => match chars.next() {
+                        Some('n') => value.push('\n'),
+                        Some('t') => value.push('\t'),
+                        Some(escaped) => value.push(escaped),
+                        None => {}
+                    },
+                    '"' => {
+                        closed = true;
+                        break;
+                    }
+                    c => value.push(c),
+                }
+            }
+
+            if !closed {
+                return Err(format!("unterminated quoted value for key '{}'", key));
+            }
+
+            Some(value)
+        } else {
+            let mut value = String::new();
+            while let Some(c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                value.push(*c);
+                chars.next();
+            }
+
+            Some(value)
+        };
+
+        pairs.push((key, value));
+
+        while let Some(c) = chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            chars.next();
+        }
+    }
+
+    Ok(pairs)
+}
+
+pub fn from_logfmt_string_to_value(s: &str, tag: impl Into<Tag>) -> Result<Vec<Value>, String> {
+    let tag = tag.into();
+    let mut rows = vec![];
+
+    for line in s.lines().filter(|line| !line.trim().is_empty()) {
+        let mut values: IndexMap<String, Vec<Value>> = IndexMap::new();
+
+        for (key, value) in parse_logfmt_line(line)? {
+            let value = match value {
+                Some(value) => UntaggedValue::string(value).into_value(&tag),
+                None => UntaggedValue::boolean(true).into_value(&tag),
+            };
+
+            match values.entry(key) {
+                Entry::Occupied(mut entry) => entry.get_mut().push(value),
+                Entry::Vacant(entry) => {
+                    entry.insert(vec![value]);
+                }
+            }
+        }
+
+        let mut row = TaggedDictBuilder::new(&tag);
+        for (key, mut values) in values {
+            if values.len() == 1 {
+                row.insert_value(key, values.remove(0));
+            } else {
+                row.insert_value(key, UntaggedValue::table(&values).into_value(&tag));
+            }
+        }
+        rows.push(row.into_value());
+    }
+
+    Ok(rows)
+}
+
+async fn from_logfmt(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once().await?;
+    let tag = args.name_tag();
+    let input = args.input;
+    let concat_string = input.collect_string(tag.clone()).await?;
+
+    match from_logfmt_string_to_value(&concat_string.item, tag.clone()) {
+        Ok(rows) => Ok(futures::stream::iter(rows).to_output_stream()),
+        Err(message) => Err(ShellError::labeled_error_with_secondary(
+            "Could not parse as logfmt",
+            message,
+            &tag,
+            "input originates from here",
+            concat_string.tag,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_logfmt_line;
+    use super::FromLogfmt;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(FromLogfmt {})
+    }
+
+    #[test]
+    fn parses_quoted_values_and_bare_keys() {
+        assert_eq!(
+            parse_logfmt_line(r#"msg="say \"hi\"" debug at=now"#),
+            Ok(vec![
+                ("msg".to_string(), Some(r#"say "hi""#.to_string())),
+                ("debug".to_string(), None),
+                ("at".to_string(), Some("now".to_string())),
+            ])
+        );
+        assert!(parse_logfmt_line(r#"msg="unterminated"#).is_err());
+    }
+}
@@ -0,0 +1,271 @@
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use chrono::{FixedOffset, TimeZone};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, TaggedDictBuilder, UntaggedValue, Value};
+use num_bigint::BigInt;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct FromPrometheus;
+
+#[async_trait]
+impl WholeStreamCommand for FromPrometheus {
+    fn name(&self) -> &str {
+        "from prometheus"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from prometheus")
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text in the Prometheus exposition format and create table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each sample becomes a row with the metric name, its labels as a record, the value and the
+timestamp (when given). The metric type from the `# TYPE` comments is kept in the type column."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        from_prometheus(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the request counters scraped from a service",
+            example: "fetch http://localhost:9090/metrics --raw | from prometheus | where metric == http_requests_total",
+            result: None,
+        }]
+    }
+}
+
+struct Sample {
+    metric: String,
+    labels: Vec<(String, String)>,
+    value: String,
+    timestamp: Option<i64>,
+}
+
+/// Parses one sample line, eg `http_requests_total{method="post",code="200"} 1027 1395066363000`
+fn parse_sample(line: &str) -> Result<Sample, String> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .ok_or_else(|| format!("missing value in '{}'", line))?;
+    let metric = line[..name_end].to_string();
+
+    let mut labels = vec![];
+    let mut rest = line[name_end..].chars().peekable();
+
+    if rest.peek() == Some(&'{') {
+        rest.next();
+
+        loop {
+            while let Some(c) = rest.peek() {
+                if !c.is_whitespace() && *c != ',' {
+                    break;
+                }
+                rest.next();
+            }
+
+            if rest.peek() == Some(&'}') {
+                rest.next();
+                break;
+            }
+
+            let mut name = String::new();
+            while let Some(c) = rest.next() {
+                if c == '=' {
+                    break;
+                }
+                name.push(c);
+            }
+
+            if rest.next() != Some('"') {
+                return Err(format!(
+                    "expected a quoted value for label '{}' of {}",
+                    name.trim(),
+                    metric
+                ));
+            }
+
+            let mut value = String::new();
+            let mut closed = false;
+            while let Some(c) = rest.next() {
+                match c {
+                    '\\' => match rest.next() {
+                        Some('n') => value.push('\n'),
+                        Some(escaped) => value.push(escaped),
+                        None => {}
+                    },
+                    '"' => {
+                        closed = true;
+                        break;
+                    }
+                    c => value.push(c),
+                }
+            }
+
+            if !closed {
+                return Err(format!("unterminated labels for {}", metric));
+            }
+
+            labels.push((name.trim().to_string(), value));
+        }
+    }
+
+    let rest: String = rest.collect();
+    let mut fields = rest.split_whitespace();
+    let value = fields
+        .next()
+        .ok_or_else(|| format!("missing value for {}", metric))?
+        .to_string();
+    let timestamp = match fields.next() {
+        Some(timestamp) => Some(
+            timestamp
+                .parse::<i64>()
+                .map_err(|_| format!("invalid timestamp '{}' for {}", timestamp, metric))?,
+        ),
+        None => None,
+    };
+
+    Ok(Sample {
+        metric,
+        labels,
+        value,
+        timestamp,
+    })
+}
+
+fn sample_value(value: &str) -> UntaggedValue {
+    if let Ok(int) = BigInt::from_str(value) {
+        UntaggedValue::int(int)
+    } else if let Ok(decimal) = BigDecimal::from_str(value) {
+        UntaggedValue::decimal(decimal)
+    } else {
+        // NaN, +Inf and -Inf have no decimal representation
+        UntaggedValue::string(value)
+    }
+}
+
+/// The family a sample belongs to, so `foo_bucket` and `foo_sum` pick up the type of `foo`
+fn metric_family<'a>(metric: &'a str, types: &HashMap<String, String>) -> &'a str {
+    for suffix in &["_bucket", "_sum", "_count", "_total"] {
+        if let Some(family) = metric.strip_suffix(suffix) {
+            if types.contains_key(family) {
+                return family;
+            }
+        }
+    }
+
+    metric
+}
+
+pub fn from_prometheus_string_to_value(s: &str, tag: impl Into<Tag>) -> Result<Vec<Value>, String> {
+    let tag = tag.into();
+    let mut types = HashMap::new();
+    let mut rows = vec![];
+
+    for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some(comment) = line.strip_prefix('#') {
+            let mut words = comment.split_whitespace();
+            if let (Some("TYPE"), Some(metric), Some(kind)) =
+                (words.next(), words.next(), words.next())
+            {
+                types.insert(metric.to_string(), kind.to_string());
+            }
+            continue;
+        }
+
+        let sample = parse_sample(line)?;
+
+        let mut labels = TaggedDictBuilder::new(&tag);
+        for (name, value) in sample.labels {
+            labels.insert_untagged(name, UntaggedValue::string(value));
+        }
+
+        let mut row = TaggedDictBuilder::new(&tag);
+        row.insert_untagged("metric", UntaggedValue::string(&sample.metric));
+        row.insert_value("labels", labels.into_value());
+        row.insert_untagged("value", sample_value(&sample.value));
+        let timestamp = match sample.timestamp {
+            Some(millis) => match FixedOffset::east(0).timestamp_millis_opt(millis).single() {
+                Some(time) => UntaggedValue::date(time),
+                None => return Err(format!("the timestamp {} is out of range", millis)),
+            },
+            None => UntaggedValue::nothing(),
+        };
+        row.insert_untagged("timestamp", timestamp);
+        row.insert_untagged(
+            "type",
+            match types.get(metric_family(&sample.metric, &types)) {
+                Some(kind) => UntaggedValue::string(kind),
+                None => UntaggedValue::nothing(),
+            },
+        );
+        rows.push(row.into_value());
+    }
+
+    Ok(rows)
+}
+
+async fn from_prometheus(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once().await?;
+    let tag = args.name_tag();
+    let input = args.input;
+    let concat_string = input.collect_string(tag.clone()).await?;
+
+    match from_prometheus_string_to_value(&concat_string.item, tag.clone()) {
+        Ok(rows) => Ok(futures::stream::iter(rows).to_output_stream()),
+        Err(message) => Err(ShellError::labeled_error_with_secondary(
+            "Could not parse as Prometheus metrics",
+            message,
+            &tag,
+            "input originates from here",
+            concat_string.tag,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromPrometheus;
+    use super::ShellError;
+    use super::{from_prometheus_string_to_value, parse_sample};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(FromPrometheus {})
+    }
+
+    #[test]
+    fn parses_labels_value_and_timestamp() {
+        let sample =
+            parse_sample(r#"http_requests_total{method="post",path="/a\"b"} 1027 1395066363000"#)
+                .expect("valid sample");
+
+        assert_eq!(sample.metric, "http_requests_total");
+        assert_eq!(
+            sample.labels,
+            vec![
+                ("method".to_string(), "post".to_string()),
+                ("path".to_string(), "/a\"b".to_string()),
+            ]
+        );
+        assert_eq!(sample.value, "1027");
+        assert_eq!(sample.timestamp, Some(1395066363000));
+    }
+
+    #[test]
+    fn rejects_timestamps_out_of_range() {
+        let error = from_prometheus_string_to_value("up 1 9223372036854775807", Tag::unknown())
+            .expect_err("timestamp out of range");
+
+        assert!(error.contains("out of range"));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::prelude::*;
+use futures::stream::once;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::CapturedBlock, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+
+pub struct Generate;
+
+#[derive(Deserialize)]
+pub struct GenerateArgs {
+    initial: Value,
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Generate {
+    fn name(&self) -> &str {
+        "generate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("generate")
+            .required("initial", SyntaxShape::Any, "the state to start from")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block that gets the state and returns a row of 'out' and 'next'",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a stream of values from a state, one step at a time."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The block gets the current state as $it, or as its parameter, and returns a row whose 'out'
+column is the next value of the stream and whose 'next' column is the state for the following
+step. Without an 'out' column the step outputs nothing, and without a 'next' column (or when the
+block returns nothing) the stream ends. Steps only run when a value is needed, so endless streams
+can be cut off with commands like 'first' or 'keep while'."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        generate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Powers of two",
+                example: "generate 1 { echo [[out next]; [$it $(= $it * 2)]] } | first 5",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(4).into(),
+                    UntaggedValue::int(8).into(),
+                    UntaggedValue::int(16).into(),
+                ]),
+            },
+            Example {
+                description: "Count down and stop",
+                example: "generate 3 {|n| if $n > 1 { echo [[out next]; [$n $(= $n - 1)]] } { echo [[out]; [$n]] } }",
+                result: None,
+            },
+            Example {
+                description: "The Fibonacci sequence, keeping the last two numbers as the state",
+                example: "generate $(echo [[a b]; [0 1]]) { echo [[out next]; [$it.a $(echo [[a b]; [$it.b $(= $it.a + $it.b)]])]] } | first 10",
+                result: None,
+            },
+            Example {
+                description: "Fetch the pages of an API until one comes back empty",
+                example: "generate 1 {|page| echo [[out next]; [$(fetch `https://example.com/items?page={{$page}}`) $(= $page + 1)]] } | keep while { = $(echo $it | length) > 0 }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn generate(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = Arc::new(EvaluationContext::from_args(&raw_args));
+    let ctrl_c = raw_args.ctrl_c.clone();
+    let name_tag = raw_args.call_info.name_tag.clone();
+    let (GenerateArgs { initial, block }, _) = raw_args.process().await?;
+    let block = Arc::new(block);
+
+    Ok(futures::stream::unfold(Some(initial), move |state| {
+        let block = block.clone();
+        let context = context.clone();
+        let name_tag = name_tag.clone();
+
+        async move {
+            let state = state?;
+
+            match step(block, context, state, &name_tag).await {
+                Ok((out, next)) => Some((Ok(out), next)),
+                // After an error there's no state to go on with
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+    .filter_map(|out| async move {
+        match out {
+            Ok(Some(out)) => Some(ReturnSuccess::value(out)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    })
+    .interruptible(ctrl_c)
+    .to_output_stream())
+}
+
+/// Runs the block on the state, giving back the value to output, if any, and the next state, if
+/// the stream goes on
+async fn step(
+    block: Arc<CapturedBlock>,
+    context: Arc<EvaluationContext>,
+    state: Value,
+    name_tag: &Tag,
+) -> Result<(Option<Value>, Option<Value>), ShellError> {
+    let input_stream = if block.block.params.positional.is_empty() {
+        let state = state.clone();
+        once(async { Ok(state) }).to_input_stream()
+    } else {
+        InputStream::empty()
+    };
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+    if let Some((param, _)) = block.block.params.positional.first() {
+        context.scope.add_var(param.name(), state.clone());
+    }
+    context.scope.add_var("$it", state);
+
+    let result = run_block(&block.block, &*context, input_stream).await;
+    context.scope.exit_scope();
+
+    let mut output = result?.into_vec().await;
+
+    let row = match output.len() {
+        0 => return Ok((None, None)),
+        1 => output.remove(0),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a single row from the block",
+                format!("the block returned {} values", output.len()),
+                name_tag,
+            ))
+        }
+    };
+
+    match &row.value {
+        UntaggedValue::Row(dict) => {
+            let out = dict.entries.get("out").cloned();
+            let next = dict
+                .entries
+                .get("next")
+                .filter(|next| next.value.is_some())
+                .cloned();
+
+            Ok((out, next))
+        }
+        UntaggedValue::Error(e) => Err(e.clone()),
+        _ => Err(ShellError::labeled_error(
+            "Expected a row with 'out' and 'next' columns",
+            format!("the block returned {}", row.type_name()),
+            row.tag.span,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Generate;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Generate {})
+    }
+}
@@ -0,0 +1,129 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_engine::{glob_paths, GlobOptions};
+use nu_errors::ShellError;
+use nu_protocol::{NuGlob, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::path::Path;
+
+pub struct Glob;
+
+#[derive(Deserialize)]
+pub struct GlobArgs {
+    pattern: Tagged<NuGlob>,
+    all: bool,
+    #[serde(rename = "no-dir")]
+    no_dir: bool,
+    #[serde(rename = "no-file")]
+    no_file: bool,
+    #[serde(rename = "max-depth")]
+    max_depth: Option<Tagged<u64>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Glob {
+    fn name(&self) -> &str {
+        "glob"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("glob")
+            .required(
+                "pattern",
+                SyntaxShape::GlobPattern,
+                "the pattern to match, where ** matches any number of directories",
+            )
+            .switch("all", "Match hidden files too", Some('a'))
+            .switch("no-dir", "Leave out the directories", Some('D'))
+            .switch("no-file", "Leave out the files", Some('F'))
+            .named(
+                "max-depth",
+                SyntaxShape::Int,
+                "How many directories deep a `**` pattern may match",
+                Some('m'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "List the paths that match a glob pattern."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        glob(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description:
+                    "List the rust files in the current directory and the directories below it",
+                example: "glob **/*.rs",
+                result: None,
+            },
+            Example {
+                description: "List the directories at most two levels down",
+                example: "glob **/* --no-file --max-depth 2",
+                result: None,
+            },
+            Example {
+                description: "List the files, hidden ones included",
+                example: "glob * --all --no-dir",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn glob(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let ctrl_c = args.ctrl_c.clone();
+    let (
+        GlobArgs {
+            pattern,
+            all,
+            no_dir,
+            no_file,
+            max_depth,
+        },
+        _,
+    ) = args.process().await?;
+
+    let options = GlobOptions {
+        max_depth: max_depth.map(|depth| depth.item as usize),
+        hidden: all,
+    };
+
+    let paths = glob_paths(&pattern.item.pattern_in(Path::new("")), options)
+        .map_err(|e| ShellError::labeled_error(e.to_string(), "invalid pattern", &pattern.tag))?;
+
+    Ok(futures::stream::iter(paths.filter_map(move |path| {
+        let path = match path {
+            Ok(path) => path,
+            Err(e) => return Some(Err(ShellError::from(e.into_error()))),
+        };
+
+        let is_dir = path.is_dir();
+        if (no_dir && is_dir) || (no_file && !is_dir) {
+            return None;
+        }
+
+        Some(ReturnSuccess::value(
+            UntaggedValue::filepath(path).into_value(&tag),
+        ))
+    }))
+    .interruptible(ctrl_c)
+    .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Glob;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Glob {})
+    }
+}
@@ -1,8 +1,12 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
 use crate::utils::suggestions::suggestions;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    hir::CapturedBlock, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
+};
 use nu_source::Tagged;
 use nu_value_ext::as_string;
 
@@ -11,6 +15,7 @@ pub struct Command;
 #[derive(Deserialize)]
 pub struct Arguments {
     grouper: Option<Value>,
+    rest: Vec<Value>,
 }
 
 #[async_trait]
@@ -20,11 +25,9 @@ impl WholeStreamCommand for Command {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("group-by").optional(
-            "grouper",
-            SyntaxShape::Any,
-            "the grouper value to use",
-        )
+        Signature::build("group-by")
+            .optional("grouper", SyntaxShape::Any, "the grouper value to use")
+            .rest(SyntaxShape::Any, "more groupers, to group each group by")
     }
 
     fn usage(&self) -> &str {
@@ -119,6 +122,16 @@ impl WholeStreamCommand for Command {
                 })
                 .into()]),
             },
+            Example {
+                description: "group by more than one column, which nests the groups",
+                example: "open employees.csv | group-by department title",
+                result: None,
+            },
+            Example {
+                description: "group the files by the month they were modified in, then by type",
+                example: "ls | group-by { get modified | date format '%Y-%m' } type",
+                result: None,
+            },
         ]
     }
 }
@@ -131,9 +144,15 @@ enum Grouper {
 pub async fn group_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
     let context = Arc::new(EvaluationContext::from_args(&args));
-    let (Arguments { grouper }, input) = args.process().await?;
+    let (Arguments { grouper, rest }, input) = args.process().await?;
+
+    let values: Vec<Value> = collect_within_limit(input, "group-by", &context.scope, &name).await?;
+
+    if !rest.is_empty() {
+        let groupers = grouper.into_iter().chain(rest).collect::<Vec<_>>();
+        return group_by_each(groupers, values, &context, name).await;
+    }
 
-    let values: Vec<Value> = input.collect().await;
     let mut keys: Vec<Result<String, ShellError>> = vec![];
     let mut group_strategy = Grouper::ByColumn(None);
 
@@ -142,45 +161,7 @@ pub async fn group_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
             value: UntaggedValue::Block(block_given),
             ..
         }) => {
-            let block = Arc::new(block_given);
-            let error_key = "error";
-
-            for value in values.iter() {
-                let run = block.clone();
-                let context = context.clone();
-
-                match crate::commands::each::process_row(run, context, value.clone()).await {
-                    Ok(mut s) => {
-                        let collection: Vec<Result<ReturnSuccess, ShellError>> =
-                            s.drain_vec().await;
-
-                        if collection.len() > 1 {
-                            return Err(ShellError::labeled_error(
-                                "expected one value from the block",
-                                "requires a table with one value for grouping",
-                                &name,
-                            ));
-                        }
-
-                        let value = match collection.get(0) {
-                            Some(Ok(return_value)) => {
-                                return_value.raw_value().unwrap_or_else(|| {
-                                    UntaggedValue::string(error_key).into_value(&name)
-                                })
-                            }
-                            Some(Err(_)) | None => {
-                                UntaggedValue::string(error_key).into_value(&name)
-                            }
-                        };
-
-                        keys.push(as_string(&value));
-                    }
-                    Err(_) => {
-                        keys.push(Ok(error_key.into()));
-                    }
-                }
-            }
-
+            keys = block_keys(block_given, &values, &context, &name).await?;
             group_strategy = Grouper::ByBlock;
         }
         Some(other) => {
@@ -225,6 +206,134 @@ pub async fn group_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
     Ok(OutputStream::one(ReturnSuccess::value(group_value?)))
 }
 
+/// Runs the block on each row for its key. Rows the block fails on, or gives nothing for, are
+/// grouped under "error".
+async fn block_keys(
+    block: Box<CapturedBlock>,
+    values: &[Value],
+    context: &Arc<EvaluationContext>,
+    name: &Tag,
+) -> Result<Vec<Result<String, ShellError>>, ShellError> {
+    let block = Arc::new(block);
+    let error_key = "error";
+    let mut keys = vec![];
+
+    for value in values.iter() {
+        let run = block.clone();
+        let context = context.clone();
+
+        match crate::commands::each::process_row(run, context, value.clone()).await {
+            Ok(mut s) => {
+                let collection: Vec<Result<ReturnSuccess, ShellError>> = s.drain_vec().await;
+
+                if collection.len() > 1 {
+                    return Err(ShellError::labeled_error(
+                        "expected one value from the block",
+                        "requires a table with one value for grouping",
+                        &name,
+                    ));
+                }
+
+                let value = match collection.get(0) {
+                    Some(Ok(return_value)) => return_value
+                        .raw_value()
+                        .unwrap_or_else(|| UntaggedValue::string(error_key).into_value(&name)),
+                    Some(Err(_)) | None => UntaggedValue::string(error_key).into_value(&name),
+                };
+
+                keys.push(as_string(&value));
+            }
+            Err(_) => {
+                keys.push(Ok(error_key.into()));
+            }
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Groups by each of the groupers in turn, the groups of one grouped again by the next, so the
+/// groups nest a level for each grouper
+async fn group_by_each(
+    groupers: Vec<Value>,
+    values: Vec<Value>,
+    context: &Arc<EvaluationContext>,
+    name: Tag,
+) -> Result<OutputStream, ShellError> {
+    if values.is_empty() {
+        return Err(ShellError::labeled_error(
+            "expected table from pipeline",
+            "requires a table input",
+            name,
+        ));
+    }
+
+    let mut keys_by_grouper = vec![];
+    for grouper in groupers {
+        let keys = match grouper {
+            Value {
+                value: UntaggedValue::Block(block),
+                ..
+            } => block_keys(block, &values, context, &name).await?,
+            other => {
+                let column_name = as_string(&other)?.tagged(&other.tag);
+                values
+                    .iter()
+                    .map(
+                        |row| match row.get_data_by_key(column_name.borrow_spanned()) {
+                            Some(group_key) => as_string(&group_key),
+                            None => Err(suggestions(column_name.borrow_tagged(), &row)),
+                        },
+                    )
+                    .collect()
+            }
+        };
+
+        keys_by_grouper.push(keys);
+    }
+
+    let mut rows = vec![];
+    for (idx, value) in values.into_iter().enumerate() {
+        let keys = keys_by_grouper
+            .iter()
+            .map(|keys| keys[idx].clone())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.push((keys, value));
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(nest(rows, &name))))
+}
+
+/// The rows grouped by the first of their keys, and each group by the rest of them
+fn nest(rows: Vec<(Vec<String>, Value)>, tag: &Tag) -> Value {
+    let mut groups: IndexMap<String, Vec<(Vec<String>, Value)>> = IndexMap::new();
+    let mut ungrouped = vec![];
+
+    for (mut keys, value) in rows {
+        if keys.is_empty() {
+            ungrouped.push(value);
+        } else {
+            let key = keys.remove(0);
+            groups
+                .entry(key)
+                .or_insert_with(Vec::new)
+                .push((keys, value));
+        }
+    }
+
+    if groups.is_empty() {
+        return UntaggedValue::table(&ungrouped).into_value(tag);
+    }
+
+    let mut out = TaggedDictBuilder::new(tag);
+    for (key, rows) in groups {
+        out.insert_value(key, nest(rows, tag));
+    }
+
+    out.into_value()
+}
+
 pub fn group(
     column_name: &Option<Tagged<String>>,
     values: &Value,
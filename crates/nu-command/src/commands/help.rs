@@ -1,8 +1,10 @@
 use crate::prelude::*;
-use nu_engine::command_dict;
+use crate::utils::json::value_to_json_value;
 use nu_engine::documentation::generate_docs;
 use nu_engine::WholeStreamCommand;
+use nu_engine::{command_dict, get_documentation_value, page_documentation};
 use nu_errors::ShellError;
+use nu_protocol::hir::ExternalRedirection;
 use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::{SpannedItem, Tagged};
 use nu_value_ext::ValueExt;
@@ -12,6 +14,7 @@ pub struct Help;
 #[derive(Deserialize)]
 pub struct HelpArgs {
     rest: Vec<Tagged<String>>,
+    json: bool,
 }
 
 #[async_trait]
@@ -21,7 +24,13 @@ impl WholeStreamCommand for Help {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("help").rest(SyntaxShape::String, "the name of command to get help on")
+        Signature::build("help")
+            .rest(SyntaxShape::String, "the name of command to get help on")
+            .switch(
+                "json",
+                "output the help of a command, or of every command with 'help commands', as JSON",
+                Some('j'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -31,19 +40,40 @@ impl WholeStreamCommand for Help {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         help(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the help of a command",
+                example: "help where",
+                result: None,
+            },
+            Example {
+                description: "Get the signature and examples of a command as JSON",
+                example: "help str trim --json",
+                result: None,
+            },
+            Example {
+                description: "Export the signatures and examples of every command",
+                example: "help commands --json | save commands.json",
+                result: None,
+            },
+        ]
+    }
 }
 
 async fn help(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
     let scope = args.scope.clone();
-    let (HelpArgs { rest }, ..) = args.process().await?;
+    let end_of_pipeline = args.call_info.args.external_redirection == ExternalRedirection::None;
+    let (HelpArgs { rest, json }, ..) = args.process().await?;
 
     if !rest.is_empty() {
         if rest[0].item == "commands" {
             let mut sorted_names = scope.get_command_names();
             sorted_names.sort();
 
-            let (mut subcommand_names, command_names) = sorted_names
+            let sorted_names = sorted_names
                 .into_iter()
                 // Internal only commands shouldn't be displayed
                 .filter(|cmd_name| {
@@ -52,6 +82,23 @@ async fn help(args: CommandArgs) -> Result<OutputStream, ShellError> {
                         .filter(|command| !command.is_internal())
                         .is_some()
                 })
+                .collect::<Vec<_>>();
+
+            if json {
+                // Every command, subcommands included, for doc sites and completion generators
+                let commands = sorted_names
+                    .iter()
+                    .filter_map(|cmd_name| scope.get_command(cmd_name))
+                    .map(|command| {
+                        get_documentation_value(command.stream_command(), &scope, name.clone())
+                    })
+                    .collect::<Vec<_>>();
+
+                return json_output(&UntaggedValue::table(&commands).into_value(&name));
+            }
+
+            let (mut subcommand_names, command_names) = sorted_names
+                .into_iter()
                 .partition::<Vec<_>, _>(|cmd_name| cmd_name.contains(' '));
 
             fn process_name(
@@ -160,18 +207,24 @@ async fn help(args: CommandArgs) -> Result<OutputStream, ShellError> {
             // Check for a subcommand
             let command_name = format!("{} {}", rest[0].item, rest[1].item);
             if let Some(command) = scope.get_command(&command_name) {
-                Ok(OutputStream::one(ReturnSuccess::value(
-                    UntaggedValue::string(get_full_help(command.stream_command(), &scope))
-                        .into_value(Tag::unknown()),
-                )))
+                command_help(
+                    command.stream_command(),
+                    &scope,
+                    json,
+                    end_of_pipeline,
+                    name,
+                )
             } else {
                 Ok(OutputStream::empty())
             }
         } else if let Some(command) = scope.get_command(&rest[0].item) {
-            Ok(OutputStream::one(ReturnSuccess::value(
-                UntaggedValue::string(get_full_help(command.stream_command(), &scope))
-                    .into_value(Tag::unknown()),
-            )))
+            command_help(
+                command.stream_command(),
+                &scope,
+                json,
+                end_of_pipeline,
+                name,
+            )
         } else {
             Err(ShellError::labeled_error(
                 "Can't find command (use 'help commands' for full list)",
@@ -208,6 +261,37 @@ You can also learn more at https://www.nushell.sh/book/"#;
     }
 }
 
+fn command_help(
+    command: &dyn WholeStreamCommand,
+    scope: &Scope,
+    json: bool,
+    end_of_pipeline: bool,
+    tag: Tag,
+) -> Result<OutputStream, ShellError> {
+    if json {
+        return json_output(&get_documentation_value(command, scope, tag));
+    }
+
+    let help = get_full_help(command, scope);
+    if end_of_pipeline && page_documentation(&help) {
+        return Ok(OutputStream::empty());
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(help).into_value(Tag::unknown()),
+    )))
+}
+
+fn json_output(value: &Value) -> Result<OutputStream, ShellError> {
+    let json = serde_json::to_string_pretty(&value_to_json_value(value)?).map_err(|e| {
+        ShellError::labeled_error("Could not convert help to JSON", e.to_string(), &value.tag)
+    })?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(json).into_value(&value.tag),
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::Help;
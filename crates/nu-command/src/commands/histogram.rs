@@ -2,9 +2,11 @@ use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{
-    ColumnPath, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, TaggedDictBuilder,
+    UntaggedValue, Value,
 };
 use nu_source::Tagged;
+use std::collections::HashMap;
 
 pub struct Histogram;
 
@@ -19,9 +21,27 @@ impl WholeStreamCommand for Histogram {
             .named(
                 "use",
                 SyntaxShape::ColumnPath,
-                "Use data at the column path given as valuator",
+                "the column whose values are summed for each group, instead of counting the rows",
                 None,
             )
+            .named(
+                "bins",
+                SyntaxShape::Int,
+                "split the numbers into this many equally wide ranges",
+                Some('b'),
+            )
+            .named(
+                "char",
+                SyntaxShape::String,
+                "the character the bars are drawn with (default: *)",
+                Some('c'),
+            )
+            .named(
+                "width",
+                SyntaxShape::Int,
+                "how long the longest bar is (default: 100)",
+                Some('w'),
+            )
             .rest(
                 SyntaxShape::ColumnPath,
                 "column name to give the histogram's frequency column",
@@ -32,6 +52,14 @@ impl WholeStreamCommand for Histogram {
         "Creates a new table with a histogram based on the column name passed in."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Each group has its count, its percentage of the largest count, and its quantile: the share of
+the total in it and the groups before it. The bars are as long as the percentage of the width.
+
+With --bins, the values have to be numbers and the groups are ranges from the smallest to the
+largest value, including the ranges no value falls in."#
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         histogram(args).await
     }
@@ -54,6 +82,21 @@ impl WholeStreamCommand for Histogram {
                 example: "echo [1 2 3 1 1 1 2 2 1 1] | histogram",
                 result: None,
             },
+            Example {
+                description: "Get a histogram of the file sizes in four ranges",
+                example: "ls | histogram size --bins 4",
+                result: None,
+            },
+            Example {
+                description: "Get a histogram of how much space each type of file takes up",
+                example: "ls | histogram type --use size",
+                result: None,
+            },
+            Example {
+                description: "Draw shorter bars with another character",
+                example: "echo [1 2 3 1 1 1 2 2 1 1] | histogram --char '#' --width 20",
+                result: None,
+            },
         ]
     }
 }
@@ -76,6 +119,31 @@ pub async fn histogram(args: CommandArgs) -> Result<OutputStream, ShellError> {
         None
     };
 
+    let bins = match args.get("bins") {
+        Some(bins) => {
+            let count = bins.as_u64()?;
+            if count == 0 {
+                return Err(ShellError::labeled_error(
+                    "Expected a number of bins bigger than 0",
+                    "cannot be 0",
+                    &bins.tag,
+                ));
+            }
+            Some(count as usize)
+        }
+        None => None,
+    };
+
+    let bar = match args.get("char") {
+        Some(bar) => bar.as_string()?,
+        None => "*".to_string(),
+    };
+
+    let width = match args.get("width") {
+        Some(width) => width.as_u64()? as f64,
+        None => 100.0,
+    };
+
     let column_grouper = if !columns.is_empty() {
         match columns.remove(0).split_last() {
             Some((key, _)) => Some(key.as_string().tagged(&name)),
@@ -99,11 +167,16 @@ pub async fn histogram(args: CommandArgs) -> Result<OutputStream, ShellError> {
         "value".to_string().tagged(&name)
     };
 
+    let bins = match bins {
+        Some(count) => Some(Bins::new(&values, &column_grouper, count)?),
+        None => None,
+    };
+
     let results = nu_data::utils::report(
         &UntaggedValue::table(&values).into_value(&name),
         nu_data::utils::Operation {
             grouper: Some(Box::new(move |_, _| Ok(String::from("frequencies")))),
-            splitter: Some(splitter(column_grouper)),
+            splitter: Some(splitter(column_grouper, bins.clone())),
             format: &None,
             eval: &evaluate_with,
             reduction: &nu_data::utils::Reduction::Count,
@@ -111,76 +184,149 @@ pub async fn histogram(args: CommandArgs) -> Result<OutputStream, ShellError> {
         &name,
     )?;
 
-    let labels = results.labels.y.clone();
-    let mut idx = 0;
-
-    Ok(futures::stream::iter(
-        results
-            .data
-            .table_entries()
-            .cloned()
-            .collect::<Vec<_>>()
-            .into_iter()
-            .zip(
-                results
-                    .percentages
-                    .table_entries()
-                    .cloned()
-                    .collect::<Vec<_>>()
-                    .into_iter(),
-            )
-            .map(move |(counts, percentages)| {
-                let percentage = percentages
-                    .table_entries()
-                    .cloned()
-                    .last()
-                    .unwrap_or_else(|| {
-                        UntaggedValue::decimal_from_float(0.0, name.span).into_value(&name)
-                    });
-                let value = counts
-                    .table_entries()
-                    .cloned()
-                    .last()
-                    .unwrap_or_else(|| UntaggedValue::int(0).into_value(&name));
-
-                let mut fact = TaggedDictBuilder::new(&name);
-                let column_value = labels
-                    .get(idx)
-                    .ok_or_else(|| {
-                        ShellError::labeled_error(
-                            "Unable to load group labels",
-                            "unable to load group labels",
-                            &name,
-                        )
-                    })?
-                    .clone();
-
-                fact.insert_value(&column.item, column_value);
-                fact.insert_untagged("count", value);
-
-                let fmt_percentage = format!(
-                    "{}%",
-                    // Some(2) < the number of digits
-                    // true < group the digits
-                    crate::commands::str_::from::action(&percentage, &name, Some(2), true)?
-                        .as_string()?
-                );
-                fact.insert_untagged("percentage", UntaggedValue::string(fmt_percentage));
-
-                let string = std::iter::repeat("*")
-                    .take(percentage.as_u64().map_err(|_| {
-                        ShellError::labeled_error("expected a number", "expected a number", &name)
-                    })? as usize)
-                    .collect::<String>();
-
-                fact.insert_untagged(&frequency_column_name, UntaggedValue::string(string));
-
-                idx += 1;
-
-                ReturnSuccess::value(fact.into_value())
-            }),
+    let mut counts = HashMap::new();
+    for (label, count) in results.labels.y.iter().zip(results.data.table_entries()) {
+        if let Some(count) = count.table_entries().last() {
+            counts.insert(label.clone(), count.clone());
+        }
+    }
+
+    // Bins are given in the order of their values, with the ones no value fell in counted as 0
+    let labels = match &bins {
+        Some(bins) => bins.labels(),
+        None => results.labels.y.clone(),
+    };
+
+    let counts = labels
+        .into_iter()
+        .map(|label| {
+            let count = counts
+                .remove(&label)
+                .unwrap_or_else(|| UntaggedValue::int(0).into_value(&name));
+            let amount = as_f64(&count)?;
+
+            Ok((label, count, amount))
+        })
+        .collect::<Result<Vec<_>, ShellError>>()?;
+
+    let max = counts
+        .iter()
+        .fold(0.0, |max, (_, _, amount)| amount.max(max));
+    let total: f64 = counts.iter().map(|(_, _, amount)| amount).sum();
+    let mut so_far = 0.0;
+
+    Ok(
+        futures::stream::iter(counts.into_iter().map(move |(label, count, amount)| {
+            let share_of_max = if max > 0.0 { amount / max } else { 0.0 };
+            so_far += amount;
+
+            let mut fact = TaggedDictBuilder::new(&name);
+
+            fact.insert_untagged(&column.item, UntaggedValue::string(label));
+            fact.insert_value("count", count);
+            fact.insert_untagged(
+                "percentage",
+                UntaggedValue::string(format!("{:.2}%", share_of_max * 100.0)),
+            );
+            fact.insert_untagged(
+                "quantile",
+                UntaggedValue::decimal_from_float(
+                    if total > 0.0 { so_far / total } else { 0.0 },
+                    name.span,
+                ),
+            );
+            fact.insert_untagged(
+                &frequency_column_name,
+                UntaggedValue::string(bar.repeat((share_of_max * width) as usize)),
+            );
+
+            ReturnSuccess::value(fact.into_value())
+        }))
+        .to_output_stream(),
     )
-    .to_output_stream())
+}
+
+/// Equally wide ranges between the smallest and the largest of the values
+#[derive(Clone)]
+struct Bins {
+    min: f64,
+    max: f64,
+    count: usize,
+}
+
+impl Bins {
+    fn new(
+        values: &[Value],
+        by: &Option<Tagged<String>>,
+        count: usize,
+    ) -> Result<Bins, ShellError> {
+        let mut numbers = values
+            .iter()
+            .map(|row| as_f64(&grouping_value(by, row)?))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+
+        let first = numbers.next().unwrap_or(0.0);
+        let (min, max) = numbers.fold((first, first), |(min, max), number| {
+            (number.min(min), number.max(max))
+        });
+
+        Ok(Bins { min, max, count })
+    }
+
+    fn width(&self) -> f64 {
+        (self.max - self.min) / self.count as f64
+    }
+
+    fn index_of(&self, number: f64) -> usize {
+        if self.width() > 0.0 {
+            // The largest value is the end of the last bin, not the start of one after it
+            (((number - self.min) / self.width()) as usize).min(self.count - 1)
+        } else {
+            0
+        }
+    }
+
+    fn label(&self, index: usize) -> String {
+        let start = self.min + self.width() * index as f64;
+        let end = if index + 1 == self.count {
+            self.max
+        } else {
+            start + self.width()
+        };
+
+        format!("{} to {}", bound(start), bound(end))
+    }
+
+    fn labels(&self) -> Vec<String> {
+        (0..self.count).map(|index| self.label(index)).collect()
+    }
+}
+
+/// The number with at most two decimals, without trailing zeros
+fn bound(number: f64) -> String {
+    let formatted = format!("{:.2}", number);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn as_f64(value: &Value) -> Result<f64, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i))
+        | UntaggedValue::Primitive(Primitive::Filesize(i))
+        | UntaggedValue::Primitive(Primitive::Duration(i)) => i.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected a number",
+            format!("{} is not a number", value.type_name()),
+            &value.tag,
+        )
+    })
 }
 
 fn evaluator(by: ColumnPath) -> Box<dyn Fn(usize, &Value) -> Result<Value, ShellError> + Send> {
@@ -196,24 +342,32 @@ fn evaluator(by: ColumnPath) -> Box<dyn Fn(usize, &Value) -> Result<Value, Shell
     })
 }
 
+fn grouping_value(by: &Option<Tagged<String>>, row: &Value) -> Result<Value, ShellError> {
+    match by {
+        Some(key) => match row.get_data_by_key(key.borrow_spanned()) {
+            Some(value) => Ok(value),
+            None => Err(ShellError::labeled_error(
+                "unknown column",
+                "unknown column",
+                key.tag(),
+            )),
+        },
+        None => Ok(row.clone()),
+    }
+}
+
 fn splitter(
     by: Option<Tagged<String>>,
+    bins: Option<Bins>,
 ) -> Box<dyn Fn(usize, &Value) -> Result<String, ShellError> + Send> {
-    match by {
-        Some(column) => Box::new(move |_, row: &Value| {
-            let key = &column;
-
-            match row.get_data_by_key(key.borrow_spanned()) {
-                Some(key) => nu_value_ext::as_string(&key),
-                None => Err(ShellError::labeled_error(
-                    "unknown column",
-                    "unknown column",
-                    key.tag(),
-                )),
-            }
-        }),
-        None => Box::new(move |_, row: &Value| nu_value_ext::as_string(&row)),
-    }
+    Box::new(move |_, row: &Value| {
+        let value = grouping_value(&by, row)?;
+
+        match &bins {
+            Some(bins) => Ok(bins.label(bins.index_of(as_f64(&value)?))),
+            None => nu_value_ext::as_string(&value),
+        }
+    })
 }
 
 #[cfg(test)]
@@ -0,0 +1,226 @@
+use crate::prelude::*;
+use chrono::{DateTime, Local, Timelike};
+use indexmap::IndexMap;
+use nu_data::config::{path::history as history_path, NuConfig};
+use nu_engine::history_log::{self, HistoryLogEntry};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct HistoryStats;
+
+#[derive(Deserialize)]
+struct Arguments {
+    #[serde(rename = "by-hour")]
+    by_hour: bool,
+    log: Option<Tagged<PathBuf>>,
+}
+
+#[derive(Default)]
+struct Usage {
+    count: usize,
+    errors: usize,
+    total_ms: u64,
+}
+
+#[async_trait]
+impl WholeStreamCommand for HistoryStats {
+    fn name(&self) -> &str {
+        "history stats"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("history stats")
+            .switch(
+                "by-hour",
+                "count the lines entered in each hour of the day instead",
+                Some('b'),
+            )
+            .named(
+                "log",
+                SyntaxShape::FilePath,
+                "read this history log instead of the one next to the history file",
+                Some('l'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Show which commands you use most, how long they take and how often they fail."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The numbers come from a log that is kept next to the history file (history.jsonl next to
+history.txt) with the start time, duration and outcome of every line entered. The log is only
+read and written locally. A line that uses several commands counts once for each of them."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        history_stats(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the ten commands you use most",
+                example: "history stats | first 10",
+                result: None,
+            },
+            Example {
+                description: "Find the commands that fail most often",
+                example: "history stats | where errors > 0 | sort-by errors | reverse",
+                result: None,
+            },
+            Example {
+                description: "See when in the day you use the shell",
+                example: "history stats --by-hour",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn history_stats(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
+    let (Arguments { by_hour, log }, _) = args.process().await?;
+
+    let (path, path_tag) = match log {
+        Some(log) => (log.item, log.tag),
+        None => (
+            history_log::log_path(&history_path(&NuConfig::new())),
+            tag.clone(),
+        ),
+    };
+
+    let entries = history_log::read(&path).map_err(|e| {
+        ShellError::labeled_error(
+            "Could not read the history log",
+            format!("{}: {}", path.display(), e),
+            &path_tag,
+        )
+    })?;
+
+    let rows = if by_hour {
+        by_hour_rows(&entries, &tag)
+    } else {
+        usage_rows(&entries, &scope, &tag)
+    };
+
+    Ok(futures::stream::iter(rows.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+fn usage_rows(entries: &[HistoryLogEntry], scope: &Scope, tag: &Tag) -> Vec<Value> {
+    let mut usage: IndexMap<String, Usage> = IndexMap::new();
+
+    for entry in entries {
+        for name in command_names(&entry.command, scope) {
+            let usage = usage.entry(name).or_default();
+            usage.count += 1;
+            usage.total_ms += entry.duration_ms;
+            if !entry.success {
+                usage.errors += 1;
+            }
+        }
+    }
+
+    let mut usage: Vec<(String, Usage)> = usage.into_iter().collect();
+    usage.sort_by(|(a_name, a), (b_name, b)| b.count.cmp(&a.count).then(a_name.cmp(b_name)));
+
+    usage
+        .into_iter()
+        .map(|(name, usage)| {
+            let average_ms = usage.total_ms / usage.count as u64;
+
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("command", UntaggedValue::string(name));
+            row.insert_untagged("count", UntaggedValue::int(usage.count as i64));
+            row.insert_untagged(
+                "average",
+                UntaggedValue::duration(average_ms as i64 * 1_000_000),
+            );
+            row.insert_untagged("errors", UntaggedValue::int(usage.errors as i64));
+            row.insert_untagged(
+                "error_rate",
+                UntaggedValue::string(format!(
+                    "{:.1}%",
+                    usage.errors as f64 * 100.0 / usage.count as f64
+                )),
+            );
+            row.into_value()
+        })
+        .collect()
+}
+
+fn by_hour_rows(entries: &[HistoryLogEntry], tag: &Tag) -> Vec<Value> {
+    let mut counts = [0usize; 24];
+
+    for entry in entries {
+        if let Ok(start) = DateTime::parse_from_rfc3339(&entry.start) {
+            counts[start.with_timezone(&Local).hour() as usize] += 1;
+        }
+    }
+
+    let most = counts.iter().copied().max().unwrap_or(0).max(1);
+
+    counts
+        .iter()
+        .enumerate()
+        .map(|(hour, count)| {
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("hour", UntaggedValue::int(hour as i64));
+            row.insert_untagged("count", UntaggedValue::int(*count as i64));
+            // The busiest hour gets a full bar of 50
+            row.insert_untagged(
+                "frequency",
+                UntaggedValue::string("*".repeat(count * 50 / most)),
+            );
+            row.into_value()
+        })
+        .collect()
+}
+
+/// The commands used in a line, each once. Subcommands like `str trim` count under their full
+/// name.
+fn command_names(line: &str, scope: &Scope) -> Vec<String> {
+    let (tokens, _) = nu_parser::lex(line, 0);
+    let (block, _) = nu_parser::parse_block(tokens);
+
+    let mut names = vec![];
+
+    for group in &block.block {
+        for pipeline in &group.pipelines {
+            for command in &pipeline.commands {
+                let name = match command.parts.as_slice() {
+                    [first, second, ..]
+                        if scope.has_command(&format!("{} {}", first.item, second.item)) =>
+                    {
+                        format!("{} {}", first.item, second.item)
+                    }
+                    [first, ..] => first.item.clone(),
+                    [] => continue,
+                };
+
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HistoryStats;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(HistoryStats {})
+    }
+}
@@ -83,6 +83,9 @@ async fn process_row(
             context.scope.enter_scope();
             context.scope.add_vars(&block.captured.entries);
             context.scope.add_var("$it", input.clone());
+            if let Some((param, _)) = block.block.params.positional.first() {
+                context.scope.add_var(param.name(), input.clone());
+            }
 
             let result = run_block(&block.block, &*context, input_stream).await;
 
@@ -0,0 +1,72 @@
+use crate::commands::set_operations::{set_operation, set_operation_signature, SetOperation};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, UntaggedValue};
+
+pub struct Intersect;
+
+#[async_trait]
+impl WholeStreamCommand for Intersect {
+    fn name(&self) -> &str {
+        "intersect"
+    }
+
+    fn signature(&self) -> Signature {
+        set_operation_signature("intersect")
+    }
+
+    fn usage(&self) -> &str {
+        "Keep the rows that are also in another list or table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Rows are matched by the given columns, or by the whole row when no columns are given, and
+the rows kept are the ones from the input. Each row is kept once unless --all is given, in which
+case a row appearing twice in the input needs two matches to be kept twice."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        set_operation(args, SetOperation::Intersect).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the numbers in both lists",
+                example: "echo [1 2 3 4] | intersect [3 4 5]",
+                result: Some(vec![
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+            Example {
+                description: "Keep duplicates as many times as they match",
+                example: "echo [1 1 1 2] | intersect [1 1 2] --all",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                ]),
+            },
+            Example {
+                description: "Find the packages installed on both machines, by name and version",
+                example: "open laptop.json | intersect { open server.json } name version",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Intersect;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Intersect {})
+    }
+}
@@ -0,0 +1,75 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value};
+
+pub struct IntoGlob;
+
+#[async_trait]
+impl WholeStreamCommand for IntoGlob {
+    fn name(&self) -> &str {
+        "into glob"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into glob")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert strings into glob patterns."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Commands like ls, cp, mv and rm only expand globs written as bare words. Quoted paths and
+strings from variables are taken literally, unless they're turned into a glob first."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(args.input.map(into_glob).to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List the files matching a pattern kept in a variable",
+                example: "let pattern = '*.txt'; ls (echo $pattern | into glob)",
+                result: None,
+            },
+            Example {
+                description: "Convert a string into a glob",
+                example: "echo '*.rs' | into glob",
+                result: Some(vec![UntaggedValue::glob_pattern("*.rs").into()]),
+            },
+        ]
+    }
+}
+
+fn into_glob(value: Value) -> Result<ReturnSuccess, ShellError> {
+    let pattern = match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::GlobPattern(s)) => s.clone(),
+        UntaggedValue::Primitive(Primitive::FilePath(path)) => path.to_string_lossy().to_string(),
+        other => {
+            return Err(ShellError::labeled_error(
+                "Could not convert to a glob",
+                format!("expected a string, got {}", other.type_name()),
+                &value.tag,
+            ))
+        }
+    };
+
+    ReturnSuccess::value(UntaggedValue::glob_pattern(pattern).into_value(&value.tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntoGlob;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(IntoGlob {})
+    }
+}
@@ -0,0 +1,318 @@
+use crate::prelude::*;
+use crate::utils::json::value_to_json_value;
+use indexmap::IndexMap;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use num_traits::ToPrimitive;
+use rusqlite::{types, Connection};
+use std::path::PathBuf;
+
+pub(crate) const DEFAULT_TABLE_NAME: &str = "data";
+
+pub struct IntoSqlite;
+
+#[derive(Deserialize)]
+pub struct IntoSqliteArgs {
+    path: Tagged<PathBuf>,
+    #[serde(rename = "table-name")]
+    table_name: Option<Tagged<String>>,
+    upsert: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for IntoSqlite {
+    fn name(&self) -> &str {
+        "into sqlite"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into sqlite")
+            .required(
+                "path",
+                SyntaxShape::FilePath,
+                "the database file to write to (created if missing)",
+            )
+            .named(
+                "table-name",
+                SyntaxShape::String,
+                "the table to write to (defaults to 'data')",
+                Some('t'),
+            )
+            .named(
+                "upsert",
+                SyntaxShape::Any,
+                "column(s) identifying a row; existing rows with the same key are updated",
+                Some('u'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Write the table into a SQLite database file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The table is created if it doesn't exist yet, with column types taken from the first
+non-empty value of each column. Rows are appended, unless upsert keys are given."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        into_sqlite(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Save the list of files into a database",
+                example: "ls | into sqlite files.db --table-name files",
+                result: None,
+            },
+            Example {
+                description: "Keep a table of the latest package versions, keyed by name",
+                example: "open packages.json | into sqlite cache.db -t packages --upsert name",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub(crate) fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn column_type(value: &Value) -> Option<&'static str> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => None,
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Filesize(_))
+        | UntaggedValue::Primitive(Primitive::Duration(_))
+        | UntaggedValue::Primitive(Primitive::Boolean(_)) => Some("INTEGER"),
+        UntaggedValue::Primitive(Primitive::Decimal(_)) => Some("REAL"),
+        UntaggedValue::Primitive(Primitive::Binary(_)) => Some("BLOB"),
+        _ => Some("TEXT"),
+    }
+}
+
+pub(crate) fn sqlite_value(value: Option<&Value>) -> Result<types::Value, ShellError> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(types::Value::Null),
+    };
+
+    let out_of_range = || {
+        ShellError::labeled_error(
+            "Value too large for SQLite",
+            "doesn't fit in a 64-bit integer",
+            &value.tag,
+        )
+    };
+
+    Ok(match &value.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => types::Value::Null,
+        UntaggedValue::Primitive(Primitive::Int(i))
+        | UntaggedValue::Primitive(Primitive::Filesize(i))
+        | UntaggedValue::Primitive(Primitive::Duration(i)) => {
+            types::Value::Integer(i.to_i64().ok_or_else(out_of_range)?)
+        }
+        UntaggedValue::Primitive(Primitive::Boolean(b)) => types::Value::Integer(*b as i64),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            types::Value::Real(d.to_f64().ok_or_else(out_of_range)?)
+        }
+        UntaggedValue::Primitive(Primitive::Binary(bytes)) => types::Value::Blob(bytes.clone()),
+        UntaggedValue::Primitive(Primitive::Date(date)) => types::Value::Text(date.to_rfc3339()),
+        UntaggedValue::Primitive(Primitive::String(s)) => types::Value::Text(s.clone()),
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => {
+            // Nested data is stored as JSON text
+            let json = value_to_json_value(value)?;
+            types::Value::Text(serde_json::to_string(&json).map_err(|e| {
+                ShellError::labeled_error("Could not convert to JSON", e.to_string(), &value.tag)
+            })?)
+        }
+        _ => types::Value::Text(value.convert_to_string()),
+    })
+}
+
+/// The upsert keys can be given as a single column name or a list of them
+fn upsert_keys(upsert: Option<Value>) -> Result<Vec<String>, ShellError> {
+    match upsert {
+        None => Ok(vec![]),
+        Some(Value {
+            value: UntaggedValue::Table(keys),
+            ..
+        }) => keys.iter().map(|key| key.as_string()).collect(),
+        Some(key) => Ok(vec![key.as_string()?]),
+    }
+}
+
+/// Every column seen in the rows, with the SQLite type of its first non-empty value
+pub(crate) fn table_columns(
+    rows: &[Value],
+    name_tag: &Tag,
+) -> Result<IndexMap<String, Option<&'static str>>, ShellError> {
+    let mut columns: IndexMap<String, Option<&'static str>> = IndexMap::new();
+    for row in rows.iter() {
+        match &row.value {
+            UntaggedValue::Row(dict) => {
+                for (name, value) in dict.entries.iter() {
+                    let entry = columns.entry(name.clone()).or_insert(None);
+                    if entry.is_none() {
+                        *entry = column_type(value);
+                    }
+                }
+            }
+            _ => {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "Expected a table from pipeline",
+                    "requires table input",
+                    name_tag,
+                    "value originates from here",
+                    row.tag.span,
+                ))
+            }
+        }
+    }
+
+    Ok(columns)
+}
+
+/// The statement creating the table with the columns, unless it already exists
+pub(crate) fn create_table(
+    table_name: &str,
+    columns: &IndexMap<String, Option<&'static str>>,
+) -> String {
+    let column_definitions: Vec<String> = columns
+        .iter()
+        .map(|(name, kind)| format!("{} {}", quote_identifier(name), kind.unwrap_or("TEXT")))
+        .collect();
+
+    format!(
+        "CREATE TABLE IF NOT EXISTS {} ({});",
+        quote_identifier(table_name),
+        column_definitions.join(", ")
+    )
+}
+
+async fn into_sqlite(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (
+        IntoSqliteArgs {
+            path,
+            table_name,
+            upsert,
+        },
+        input,
+    ) = args.process().await?;
+    let rows: Vec<Value> = input.collect().await;
+
+    let table_name = table_name
+        .map(|name| name.item)
+        .unwrap_or_else(|| DEFAULT_TABLE_NAME.to_string());
+    let keys = upsert_keys(upsert)?;
+
+    let columns = table_columns(&rows, &name_tag)?;
+
+    if columns.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Expected a table from pipeline",
+            "requires table input",
+            &name_tag,
+        ));
+    }
+
+    for key in keys.iter() {
+        if !columns.contains_key(key) {
+            return Err(ShellError::labeled_error(
+                "Unknown upsert key",
+                format!("there is no '{}' column", key),
+                &name_tag,
+            ));
+        }
+    }
+
+    let sqlite_error = |e: rusqlite::Error| {
+        ShellError::labeled_error("Could not write to database", e.to_string(), &path.tag)
+    };
+
+    let mut conn = Connection::open(&path.item).map_err(sqlite_error)?;
+    let transaction = conn.transaction().map_err(sqlite_error)?;
+
+    let table = quote_identifier(&table_name);
+    let column_names: Vec<String> = columns.keys().map(|name| quote_identifier(name)).collect();
+
+    transaction
+        .execute_batch(&create_table(&table_name, &columns))
+        .map_err(sqlite_error)?;
+
+    let mut insert = format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        table,
+        column_names.join(", "),
+        vec!["?"; columns.len()].join(", ")
+    );
+
+    if !keys.is_empty() {
+        let key_names: Vec<String> = keys.iter().map(|key| quote_identifier(key)).collect();
+
+        // ON CONFLICT needs a unique index over the keys to detect the existing row
+        transaction
+            .execute_batch(&format!(
+                "CREATE UNIQUE INDEX IF NOT EXISTS {} ON {} ({});",
+                quote_identifier(&format!("{}_upsert_{}", table_name, keys.join("_"))),
+                table,
+                key_names.join(", ")
+            ))
+            .map_err(sqlite_error)?;
+
+        let updates: Vec<String> = column_names
+            .iter()
+            .filter(|name| !key_names.contains(name))
+            .map(|name| format!("{} = excluded.{}", name, name))
+            .collect();
+
+        if updates.is_empty() {
+            insert.push_str(&format!(
+                " ON CONFLICT ({}) DO NOTHING",
+                key_names.join(", ")
+            ));
+        } else {
+            insert.push_str(&format!(
+                " ON CONFLICT ({}) DO UPDATE SET {}",
+                key_names.join(", "),
+                updates.join(", ")
+            ));
+        }
+    }
+
+    {
+        let mut statement = transaction.prepare(&insert).map_err(sqlite_error)?;
+
+        for row in rows.iter() {
+            if let UntaggedValue::Row(dict) = &row.value {
+                let values = columns
+                    .keys()
+                    .map(|name| sqlite_value(dict.entries.get(name)))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                statement.execute(&values).map_err(sqlite_error)?;
+            }
+        }
+    }
+
+    transaction.commit().map_err(sqlite_error)?;
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntoSqlite;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(IntoSqlite {})
+    }
+}
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+
+pub struct IsEmpty;
+
+#[async_trait]
+impl WholeStreamCommand for IsEmpty {
+    fn name(&self) -> &str {
+        "is-empty"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("is-empty")
+    }
+
+    fn usage(&self) -> &str {
+        "Check whether the input is empty."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The input as a whole is checked, rather than each of its rows like empty? does. Nothing, an empty string or binary, a list or table without rows and a record without columns are all empty."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        is_empty(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check whether a string is empty",
+                example: "echo '' | is-empty",
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+            Example {
+                description: "Check whether a list is empty",
+                example: "echo [1 2 3] | is-empty",
+                result: Some(vec![UntaggedValue::boolean(false).into()]),
+            },
+            Example {
+                description: "Check whether the directory has no files in it",
+                example: "ls | where type == File | is-empty",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn is_empty(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let args = args.evaluate_once().await?;
+    let (input, _args) = args.parts();
+
+    let values: Vec<Value> = input.collect().await;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::boolean(values_are_empty(&values)).into_value(tag),
+    )))
+}
+
+fn values_are_empty(values: &[Value]) -> bool {
+    match values {
+        [value] => match &value.value {
+            UntaggedValue::Primitive(Primitive::Nothing) => true,
+            UntaggedValue::Primitive(Primitive::String(s)) => s.is_empty(),
+            UntaggedValue::Primitive(Primitive::Binary(b)) => b.is_empty(),
+            UntaggedValue::Table(rows) => rows.is_empty(),
+            UntaggedValue::Row(row) => row.entries.is_empty(),
+            _ => false,
+        },
+        // Binary data can stream in as several chunks, which together are still one value
+        values => values.iter().all(|value| {
+            matches!(&value.value, UntaggedValue::Primitive(Primitive::Binary(b)) if b.is_empty())
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IsEmpty;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(IsEmpty {})
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Command;
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "job"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job")
+    }
+
+    fn usage(&self) -> &str {
+        "Run external commands in the background."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Jobs are kept from one line of the shell to the next, and when one finishes it is reported
+before the next prompt. The jobs still running when the shell exits are stopped, unless they
+were disowned."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(Ok(ReturnSuccess::Value(
+            UntaggedValue::string(get_full_help(&Command, &args.scope)).into_value(Tag::unknown()),
+        ))))
+    }
+}
@@ -0,0 +1,64 @@
+use super::job_row;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct DisownArgs {
+    id: Tagged<usize>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "job disown"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job disown").required("id", SyntaxShape::Int, "the id of the job")
+    }
+
+    fn usage(&self) -> &str {
+        "Remove a job from the job table, so it keeps running after the shell exits."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let tag = args.call_info.name_tag.clone();
+        let jobs = args.jobs.clone();
+        let (DisownArgs { id }, _) = args.process().await?;
+
+        match jobs.disown(id.item) {
+            Some(job) => Ok(OutputStream::one(ReturnSuccess::value(job_row(&job, &tag)))),
+            None => Err(ShellError::labeled_error(
+                "No such job",
+                format!("there is no job {}", id.item),
+                id.tag,
+            )),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Keep the first job running after the shell exits",
+            example: "job disown 1",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
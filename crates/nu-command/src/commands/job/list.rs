@@ -0,0 +1,55 @@
+use super::job_row;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "job list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job list")
+    }
+
+    fn usage(&self) -> &str {
+        "List the background jobs."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let tag = args.call_info.name_tag.clone();
+        let rows: Vec<_> = args
+            .jobs
+            .list()
+            .iter()
+            .map(|job| ReturnSuccess::value(job_row(job, &tag)))
+            .collect();
+
+        Ok(futures::stream::iter(rows).to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the jobs that are still running",
+            example: "job list | where status == running",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
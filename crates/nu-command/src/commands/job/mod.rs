@@ -0,0 +1,40 @@
+pub mod command;
+
+pub mod disown;
+pub mod list;
+pub mod spawn;
+
+pub use command::Command as Job;
+
+pub use disown::SubCommand as JobDisown;
+pub use list::SubCommand as JobList;
+pub use spawn::SubCommand as JobSpawn;
+
+use nu_engine::{JobInfo, JobStatus};
+use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tag;
+
+fn job_row(job: &JobInfo, tag: impl Into<Tag>) -> Value {
+    let mut row = TaggedDictBuilder::new(tag);
+    row.insert_untagged("id", UntaggedValue::int(job.id));
+    row.insert_untagged("pid", UntaggedValue::int(job.pid));
+    row.insert_untagged("command", UntaggedValue::string(&job.command));
+    match job.status {
+        JobStatus::Running => {
+            row.insert_untagged("status", UntaggedValue::string("running"));
+            row.insert_untagged("exit_code", UntaggedValue::nothing());
+        }
+        JobStatus::Exited(code) => {
+            row.insert_untagged("status", UntaggedValue::string("done"));
+            row.insert_untagged(
+                "exit_code",
+                match code {
+                    Some(code) => UntaggedValue::int(code),
+                    None => UntaggedValue::nothing(),
+                },
+            );
+        }
+    }
+
+    row.into_value()
+}
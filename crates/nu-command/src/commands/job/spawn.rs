@@ -0,0 +1,128 @@
+use super::job_row;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct SpawnArgs {
+    command: Tagged<String>,
+    output: Option<Tagged<PathBuf>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "job spawn"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job spawn")
+            .required(
+                "command",
+                SyntaxShape::String,
+                "the external command to run, as it would be typed in sh (cmd on Windows)",
+            )
+            .named(
+                "output",
+                SyntaxShape::FilePath,
+                "a file to write the output of the job to, instead of dropping it",
+                Some('o'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run an external command in the background."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        spawn(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Build in the background",
+                example: "job spawn 'cargo build --release'",
+                result: None,
+            },
+            Example {
+                description: "Keep the output of a background job",
+                example: "job spawn 'make test' --output test.log",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn spawn(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let jobs = args.jobs.clone();
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let env = args.scope.get_env_vars();
+    let (SpawnArgs { command, output }, _) = args.process().await?;
+
+    let mut process = if cfg!(windows) {
+        let mut process = Command::new("cmd");
+        process.arg("/c").arg(&command.item);
+        process
+    } else {
+        let mut process = Command::new("sh");
+        process.arg("-c").arg(&command.item);
+        process
+    };
+
+    process.current_dir(&cwd);
+    process.env_clear();
+    process.envs(env);
+
+    // A job can't read from the terminal the shell is reading lines from
+    process.stdin(Stdio::null());
+    match output {
+        Some(path) => {
+            let file = std::fs::File::create(cwd.join(&path.item)).map_err(|err| {
+                ShellError::labeled_error(
+                    "Could not create the output file",
+                    err.to_string(),
+                    &path.tag,
+                )
+            })?;
+            let stderr = file.try_clone().map_err(|err| {
+                ShellError::labeled_error(
+                    "Could not create the output file",
+                    err.to_string(),
+                    &path.tag,
+                )
+            })?;
+            process.stdout(file).stderr(stderr);
+        }
+        None => {
+            process.stdout(Stdio::null()).stderr(Stdio::null());
+        }
+    }
+
+    let child = process.spawn().map_err(|err| {
+        ShellError::labeled_error("Could not start the job", err.to_string(), &command.tag)
+    })?;
+    let job = jobs.add(command.item, child);
+
+    Ok(OutputStream::one(ReturnSuccess::value(job_row(&job, &tag))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
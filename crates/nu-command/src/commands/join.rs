@@ -0,0 +1,347 @@
+use crate::prelude::*;
+use crate::utils::suggestions::suggestions;
+use indexmap::IndexSet;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Dictionary, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+
+pub struct Join;
+
+#[derive(Deserialize)]
+pub struct JoinArgs {
+    table: Value,
+    key: Tagged<String>,
+    rest: Vec<Tagged<String>>,
+    left: bool,
+    right: bool,
+    outer: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Inner,
+    Left,
+    Right,
+    Outer,
+}
+
+impl Kind {
+    fn keeps_unmatched_left(self) -> bool {
+        matches!(self, Kind::Left | Kind::Outer)
+    }
+
+    fn keeps_unmatched_right(self) -> bool {
+        matches!(self, Kind::Right | Kind::Outer)
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for Join {
+    fn name(&self) -> &str {
+        "join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("join")
+            .required("table", SyntaxShape::Any, "the table to join with")
+            .required("key", SyntaxShape::String, "the column to join on")
+            .rest(SyntaxShape::String, "more columns to join on")
+            .switch(
+                "left",
+                "keep the rows of the input that have no match",
+                Some('l'),
+            )
+            .switch(
+                "right",
+                "keep the rows of the table that have no match",
+                Some('r'),
+            )
+            .switch(
+                "outer",
+                "keep the rows of both that have no match",
+                Some('o'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Joins the rows of the input with the rows of a table that have the same values in the key columns."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Without a flag only the rows with a match are kept (an inner join). The key columns are in the
+joined rows once, and the other columns of the table that the input has too get a _right suffix.
+The columns a row has no match for are empty.
+
+The table is read into memory first, while the input streams through."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        join(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Join two tables on their id",
+                example: "echo [[id name]; [1 Andrés] [2 Jonathan]] | join $(echo [[id age]; [1 30] [3 25]]) id",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "id".to_string() => UntaggedValue::int(1).into(),
+                    "name".to_string() => UntaggedValue::string("Andrés").into(),
+                    "age".to_string() => UntaggedValue::int(30).into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Keep the people without an order",
+                example: "open people.csv | join $(open orders.csv) person_id --left",
+                result: None,
+            },
+            Example {
+                description: "Join on two columns",
+                example: "open sales.csv | join $(open targets.csv) region year --outer",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The rows of the table joined with, by the values of their keys
+struct Right {
+    rows: Vec<Dictionary>,
+    by_key: HashMap<Vec<UntaggedValue>, Vec<usize>>,
+    columns: Vec<String>,
+}
+
+async fn join(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (
+        JoinArgs {
+            table,
+            key,
+            rest,
+            left,
+            right,
+            outer,
+        },
+        input,
+    ) = args.process().await?;
+
+    let kind = match (left, right, outer) {
+        (false, false, false) => Kind::Inner,
+        (true, false, false) => Kind::Left,
+        (false, true, false) => Kind::Right,
+        (false, false, true) => Kind::Outer,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Only one kind of join can be given",
+                "use one of --left, --right or --outer",
+                &name,
+            ))
+        }
+    };
+
+    let keys = std::iter::once(key).chain(rest).collect::<Vec<_>>();
+    let right = Arc::new(collect_right(table, &keys)?);
+    let matched = Arc::new(Mutex::new(vec![false; right.rows.len()]));
+    let left_columns = Arc::new(Mutex::new(IndexSet::new()));
+
+    let joined = {
+        let keys = keys.clone();
+        let right = right.clone();
+        let matched = matched.clone();
+        let left_columns = left_columns.clone();
+
+        input.map(move |value| {
+            let row = match row_of(&value, &keys) {
+                Ok(row) => row,
+                Err(err) => return vec![Err(err)],
+            };
+            left_columns.lock().extend(row.keys().cloned());
+
+            let key = key_of(row, &keys);
+            let matches = right.by_key.get(&key).cloned().unwrap_or_default();
+
+            if matches.is_empty() {
+                return if kind.keeps_unmatched_left() {
+                    vec![ReturnSuccess::value(joined_row(
+                        row,
+                        None,
+                        &keys,
+                        &right.columns,
+                        &value.tag,
+                    ))]
+                } else {
+                    vec![]
+                };
+            }
+
+            let mut matched = matched.lock();
+            matches
+                .into_iter()
+                .map(|index| {
+                    matched[index] = true;
+                    ReturnSuccess::value(joined_row(
+                        row,
+                        Some(&right.rows[index]),
+                        &keys,
+                        &right.columns,
+                        &value.tag,
+                    ))
+                })
+                .collect()
+        })
+    };
+
+    // The rows of the table without a match can only be known once the input is done
+    let unmatched = futures::stream::once(async move {
+        if !kind.keeps_unmatched_right() {
+            return vec![];
+        }
+
+        let matched = matched.lock();
+        let left_columns = left_columns.lock().iter().cloned().collect::<Vec<_>>();
+        right
+            .rows
+            .iter()
+            .zip(matched.iter())
+            .filter(|(_, matched)| !**matched)
+            .map(|(row, _)| {
+                let mut out = TaggedDictBuilder::new(&name);
+                for column in &left_columns {
+                    let value = if keys.iter().any(|key| key.item == *column) {
+                        row.get_data(column).borrow().clone()
+                    } else {
+                        UntaggedValue::nothing().into_value(&name)
+                    };
+                    out.insert_value(column.clone(), value);
+                }
+                for (column, value) in row.entries.iter() {
+                    if !left_columns.contains(column) {
+                        out.insert_value(column.clone(), value.clone());
+                    } else if !keys.iter().any(|key| key.item == *column) {
+                        out.insert_value(format!("{}_right", column), value.clone());
+                    }
+                }
+
+                ReturnSuccess::value(out.into_value())
+            })
+            .collect()
+    });
+
+    Ok(joined
+        .chain(unmatched)
+        .map(futures::stream::iter)
+        .flatten()
+        .to_output_stream())
+}
+
+fn collect_right(right: Value, keys: &[Tagged<String>]) -> Result<Right, ShellError> {
+    let values = match right.value {
+        UntaggedValue::Table(values) => values,
+        UntaggedValue::Row(_) => vec![right],
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a table to join with",
+                format!("this is {}", right.type_name()),
+                &right.tag,
+            ))
+        }
+    };
+
+    let mut rows = vec![];
+    let mut by_key: HashMap<Vec<UntaggedValue>, Vec<usize>> = HashMap::new();
+    let mut columns = IndexSet::new();
+
+    for (index, value) in values.iter().enumerate() {
+        let row = row_of(value, keys)?;
+        columns.extend(row.keys().cloned());
+        by_key.entry(key_of(row, keys)).or_default().push(index);
+        rows.push(row.clone());
+    }
+
+    Ok(Right {
+        rows,
+        by_key,
+        columns: columns
+            .into_iter()
+            .filter(|column| !keys.iter().any(|key| key.item == *column))
+            .collect(),
+    })
+}
+
+/// The row the value is, which has to have all the key columns
+fn row_of<'a>(value: &'a Value, keys: &[Tagged<String>]) -> Result<&'a Dictionary, ShellError> {
+    let row = match &value.value {
+        UntaggedValue::Row(row) => row,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a table of rows to join",
+                format!("this is {}", value.type_name()),
+                &value.tag,
+            ))
+        }
+    };
+
+    for key in keys {
+        if !row.contains_key(&key.item) {
+            return Err(suggestions(key.borrow_tagged(), value));
+        }
+    }
+
+    Ok(row)
+}
+
+fn key_of(row: &Dictionary, keys: &[Tagged<String>]) -> Vec<UntaggedValue> {
+    keys.iter()
+        .map(|key| row.get_data(&key.item).borrow().value.clone())
+        .collect()
+}
+
+/// The columns of the input row, then the other columns of the table, empty without a match.
+/// The key columns were already among those of the input row.
+fn joined_row(
+    left: &Dictionary,
+    right: Option<&Dictionary>,
+    keys: &[Tagged<String>],
+    right_columns: &[String],
+    tag: &Tag,
+) -> Value {
+    let mut out = TaggedDictBuilder::new(tag);
+
+    for (column, value) in left.entries.iter() {
+        out.insert_value(column.clone(), value.clone());
+    }
+
+    for column in right_columns {
+        let value = right
+            .and_then(|right| right.entries.get(column))
+            .cloned()
+            .unwrap_or_else(|| UntaggedValue::nothing().into_value(tag));
+
+        if left.contains_key(column) && !keys.iter().any(|key| key.item == *column) {
+            out.insert_value(format!("{}_right", column), value);
+        } else {
+            out.insert_value(column.clone(), value);
+        }
+    }
+
+    out.into_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Join;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Join {})
+    }
+}
@@ -2,7 +2,7 @@ use crate::prelude::*;
 use futures::stream::StreamExt;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, UntaggedValue, Value};
+use nu_protocol::{merge_descriptors, Primitive, Signature, UntaggedValue, Value};
 
 pub struct Length;
 
@@ -29,28 +29,27 @@ impl WholeStreamCommand for Length {
         "Show the total number of rows or items."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Every row of a list or table is one item, whatever its type. A string counts as a single item (see str length for its characters), and so does binary data, even when it streams in as several chunks. With --column, the columns of a record or of all the rows of a table are counted instead."
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         let tag = args.call_info.name_tag.clone();
         let (LengthArgs { column }, input) = args.process().await?;
         let rows: Vec<Value> = input.collect().await;
 
         let length = if column {
-            if rows.is_empty() {
-                0
+            if rows.iter().all(|row| row.is_row()) {
+                merge_descriptors(&rows).len()
             } else {
-                match &rows[0].value {
-                    UntaggedValue::Row(dictionary) => dictionary.length(),
-                    _ => {
-                        return Err(ShellError::labeled_error(
-                            "Cannot obtain column length",
-                            "cannot obtain column length",
-                            tag,
-                        ));
-                    }
-                }
+                return Err(ShellError::labeled_error(
+                    "Cannot obtain column length",
+                    "cannot obtain column length",
+                    tag,
+                ));
             }
         } else {
-            rows.len()
+            count_items(&rows)
         };
 
         Ok(OutputStream::one(
@@ -70,10 +69,27 @@ impl WholeStreamCommand for Length {
                 example: "cal | length -c",
                 result: None,
             },
+            Example {
+                description: "Count the columns of a record",
+                example: "echo [[name size]; [nu 5]] | length -c",
+                result: Some(vec![UntaggedValue::int(2).into()]),
+            },
         ]
     }
 }
 
+/// The number of items, where the chunks of binary data streaming in one after another make up
+/// a single item
+fn count_items(rows: &[Value]) -> usize {
+    let is_binary =
+        |row: &Value| matches!(row.value, UntaggedValue::Primitive(Primitive::Binary(_)));
+
+    rows.iter()
+        .enumerate()
+        .filter(|(index, row)| *index == 0 || !is_binary(row) || !is_binary(&rows[index - 1]))
+        .count()
+}
+
 #[cfg(test)]
 mod tests {
     use super::Length;
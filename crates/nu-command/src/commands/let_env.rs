@@ -1,8 +1,10 @@
 use crate::prelude::*;
-use nu_engine::{evaluate_baseline_expr, WholeStreamCommand};
+use nu_engine::{evaluate_baseline_expr, is_path_var, WholeStreamCommand};
 
 use nu_errors::ShellError;
-use nu_protocol::{hir::CapturedBlock, hir::ClassifiedCommand, Signature, SyntaxShape};
+use nu_protocol::{
+    hir::CapturedBlock, hir::ClassifiedCommand, Signature, SyntaxShape, UntaggedValue, Value,
+};
 use nu_source::Tagged;
 
 pub struct LetEnv;
@@ -91,9 +93,8 @@ pub async fn set_env(args: CommandArgs) -> Result<OutputStream, ShellError> {
     ctx.scope.exit_scope();
 
     let value = value?;
-    let value = value.as_string()?;
-
     let name = name.item.clone();
+    let value = env_var_value(&name, &value)?;
 
     // Note: this is a special case for setting the context from a command
     // In this case, if we don't set it now, we'll lose the scope that this
@@ -102,3 +103,24 @@ pub async fn set_env(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     Ok(OutputStream::empty())
 }
+
+/// The text of an environment variable set to a value. A list of directories for PATH, eg from
+/// `$env.PATH | append ~/bin`, is joined back up.
+pub(crate) fn env_var_value(name: &str, value: &Value) -> Result<String, ShellError> {
+    match &value.value {
+        UntaggedValue::Table(paths) if is_path_var(name) => {
+            let paths = paths
+                .iter()
+                .map(|path| path.as_string())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(std::env::join_paths(paths)
+                .map_err(|e| {
+                    ShellError::labeled_error("Could not set the path", e.to_string(), &value.tag)
+                })?
+                .to_string_lossy()
+                .to_string())
+        }
+        _ => value.as_string(),
+    }
+}
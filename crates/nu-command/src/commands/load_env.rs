@@ -0,0 +1,113 @@
+use crate::commands::let_env::env_var_value;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct LoadEnv;
+
+#[derive(Deserialize)]
+pub struct LoadEnvArgs {
+    environ: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for LoadEnv {
+    fn name(&self) -> &str {
+        "load-env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("load-env").optional(
+            "environ",
+            SyntaxShape::Any,
+            "the record of environment variables to set (default the input)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Set environment variables from a record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each column of the record sets a variable, as let-env does, so they stay set until the end of the block, or for the rest of the session at the top level. Several records from the input are loaded in turn."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        load_env(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Set the variables of a JSON file",
+                example: "open env.json | load-env",
+                result: None,
+            },
+            Example {
+                description: "Set variables from a record",
+                example: r#"load-env $(echo [[NAME VERSION]; [nu 0.28]]); echo $nu.env.NAME"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn load_env(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let ctx = EvaluationContext::from_args(&args);
+
+    let (LoadEnvArgs { environ }, input) = args.process().await?;
+
+    let records: Vec<Value> = match environ {
+        Some(environ) => vec![environ],
+        None => input.collect().await,
+    };
+
+    for record in records {
+        // A table with a single row, eg `[[NAME VERSION]; [nu 0.28]]`, is taken as the record
+        let record = match &record.value {
+            UntaggedValue::Table(rows) if rows.len() == 1 => &rows[0],
+            _ => &record,
+        };
+
+        let row = match &record.value {
+            UntaggedValue::Row(row) => row,
+            _ => {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "Expected a record",
+                    "requires a record of environment variables",
+                    &tag,
+                    format!("this is {}", record.type_name()),
+                    &record.tag,
+                ))
+            }
+        };
+
+        for (name, value) in &row.entries {
+            let value = if value.is_primitive() {
+                value.convert_to_string()
+            } else {
+                env_var_value(name, value)?
+            };
+
+            // Like let-env, this is set in the scope the command is run in
+            ctx.scope.add_env_var(name, value);
+        }
+    }
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LoadEnv;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(LoadEnv {})
+    }
+}
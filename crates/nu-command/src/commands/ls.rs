@@ -34,6 +34,12 @@ impl WholeStreamCommand for Ls {
                 "Display the apparent directory size in place of the directory metadata size",
                 Some('d'),
             )
+            .named(
+                "max-depth",
+                SyntaxShape::Int,
+                "How many directories deep a `**` pattern may match",
+                Some('m'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -65,6 +71,17 @@ impl WholeStreamCommand for Ls {
                 example: "ls *.rs",
                 result: None,
             },
+            Example {
+                description:
+                    "List all rust files in the current directory and the directories below it",
+                example: "ls **/*.rs",
+                result: None,
+            },
+            Example {
+                description: "List the entries of the current directory and its subdirectories",
+                example: "ls **/* --max-depth 2",
+                result: None,
+            },
         ]
     }
 }
@@ -30,6 +30,7 @@ impl WholeStreamCommand for SubCommand {
                 host: args.host,
                 ctrl_c: args.ctrl_c,
                 current_errors: args.current_errors,
+                jobs: args.jobs,
                 name: args.call_info.name_tag,
             },
             floor_big_int,
@@ -8,6 +8,7 @@ pub mod max;
 pub mod median;
 pub mod min;
 pub mod mode;
+pub mod percentile;
 pub mod product;
 pub mod round;
 pub mod stddev;
@@ -27,6 +28,7 @@ pub use max::SubCommand as MathMaximum;
 pub use median::SubCommand as MathMedian;
 pub use min::SubCommand as MathMinimum;
 pub use mode::SubCommand as MathMode;
+pub use percentile::SubCommand as MathPercentile;
 pub use product::SubCommand as MathProduct;
 pub use round::SubCommand as MathRound;
 pub use stddev::SubCommand as MathStddev;
@@ -0,0 +1,167 @@
+use crate::commands::math::utils::{calculate, into_output_stream};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct Arguments {
+    percentile: Tagged<f64>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math percentile"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math percentile").required(
+            "percentile",
+            SyntaxShape::Number,
+            "the percentage of the values to be at or below the result, from 0 to 100",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the percentile of a list of numbers"
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"When the percentile falls between two of the values, the result is interpolated between them,
+so the 50th percentile is the median."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let name = args.call_info.name_tag.clone();
+        let (Arguments { percentile: p }, mut input) = args.process().await?;
+
+        if !(0.0..=100.0).contains(&p.item) {
+            return Err(ShellError::labeled_error(
+                "Expected a percentile from 0 to 100",
+                "out of range",
+                &p.tag,
+            ));
+        }
+
+        let values: Vec<Value> = input.drain_vec().await;
+
+        calculate(&values, &name, |values, tag| {
+            percentile(values, p.item, tag)
+        })
+        .map(into_output_stream)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the value three quarters of a list of numbers are at or below",
+                example: "echo [1 2 3 4 5] | math percentile 75",
+                result: Some(vec![UntaggedValue::int(4).into()]),
+            },
+            Example {
+                description: "Get the 50th percentile, between the two middle numbers",
+                example: "echo [3 8 9 12 12 15] | math percentile 50",
+                result: Some(vec![UntaggedValue::decimal_from_float(
+                    10.5,
+                    Span::unknown(),
+                )
+                .into()]),
+            },
+            Example {
+                description: "Get the 50th percentile of each column",
+                example: "echo [[a b]; [1 10] [2 20] [3 30]] | math percentile 50",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "a".to_string() => UntaggedValue::int(2).into(),
+                    "b".to_string() => UntaggedValue::int(20).into(),
+                })
+                .into()]),
+            },
+        ]
+    }
+}
+
+pub fn percentile(values: &[Value], percentile: f64, name: &Tag) -> Result<Value, ShellError> {
+    let filesizes = values.iter().filter(|value| is_filesize(value)).count();
+
+    if filesizes > 0 && filesizes < values.len() {
+        return Err(ShellError::labeled_error(
+            "could not calculate percentile of non-numeric or unrelated types",
+            "source",
+            name,
+        ));
+    }
+
+    for value in values {
+        value_to_f64(value)?;
+    }
+
+    let mut sorted = values.to_vec();
+    crate::commands::sort_by::sort(&mut sorted, &[], name, false)?;
+
+    let rank = percentile / 100.0 * sorted.len().saturating_sub(1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+
+    let (low, high) = match (sorted.get(lower), sorted.get(upper)) {
+        (Some(low), Some(high)) => (low, high),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "could not extract value",
+                "could not extract value",
+                &name.span,
+            ))
+        }
+    };
+
+    if lower == upper {
+        return Ok(low.clone());
+    }
+
+    let (low_number, high_number) = (value_to_f64(low)?, value_to_f64(high)?);
+    let interpolated = low_number + (high_number - low_number) * rank.fract();
+
+    if is_filesize(low) {
+        Ok(UntaggedValue::filesize(interpolated.round() as u64).into_value(name))
+    } else {
+        Ok(UntaggedValue::decimal_from_float(interpolated, name.span).into_value(name))
+    }
+}
+
+fn is_filesize(value: &Value) -> bool {
+    matches!(
+        value.value,
+        UntaggedValue::Primitive(Primitive::Filesize(_))
+    )
+}
+
+fn value_to_f64(value: &Value) -> Result<f64, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i))
+        | UntaggedValue::Primitive(Primitive::Filesize(i)) => i.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ShellError::labeled_error(
+            "could not calculate percentile of non-numeric or unrelated types",
+            "source",
+            &value.tag,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -14,20 +14,19 @@ pub async fn run_with_function(
 ) -> Result<OutputStream, ShellError> {
     let values: Vec<Value> = input.drain_vec().await;
 
-    let res = calculate(&values, &name, mf);
-    match res {
-        Ok(v) => {
-            if v.value.is_table() {
-                Ok(OutputStream::from(
-                    v.table_entries()
-                        .map(|v| ReturnSuccess::value(v.clone()))
-                        .collect::<Vec<_>>(),
-                ))
-            } else {
-                Ok(OutputStream::one(ReturnSuccess::value(v)))
-            }
-        }
-        Err(e) => Err(e),
+    calculate(&values, &name, mf).map(into_output_stream)
+}
+
+/// The calculated value, or the values of it when it's a table
+pub fn into_output_stream(v: Value) -> OutputStream {
+    if v.value.is_table() {
+        OutputStream::from(
+            v.table_entries()
+                .map(|v| ReturnSuccess::value(v.clone()))
+                .collect::<Vec<_>>(),
+        )
+    } else {
+        OutputStream::one(ReturnSuccess::value(v))
     }
 }
 
@@ -51,7 +50,11 @@ pub async fn run_with_numerical_functions_on_stream(
     Ok(OutputStream::from_input(mapped))
 }
 
-pub fn calculate(values: &[Value], name: &Tag, mf: MathFunction) -> Result<Value, ShellError> {
+pub fn calculate(
+    values: &[Value],
+    name: &Tag,
+    mf: impl Fn(&[Value], &Tag) -> Result<Value, ShellError>,
+) -> Result<Value, ShellError> {
     if values.iter().all(|v| v.is_primitive()) {
         mf(&values, &name)
     } else {
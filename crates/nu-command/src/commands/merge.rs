@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use nu_data::value::merge_values;
+use nu_data::value::{merge_values, merge_values_deep, ListMerge};
 use nu_engine::run_block;
 use nu_engine::WholeStreamCommand;
 
@@ -8,11 +8,14 @@ use nu_errors::ShellError;
 use nu_protocol::{
     hir::CapturedBlock, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
 };
+use nu_source::Tagged;
 pub struct Merge;
 
 #[derive(Deserialize)]
 pub struct MergeArgs {
     block: CapturedBlock,
+    deep: bool,
+    lists: Option<Tagged<String>>,
 }
 
 #[async_trait]
@@ -22,27 +25,57 @@ impl WholeStreamCommand for Merge {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("merge").required(
-            "block",
-            SyntaxShape::Block,
-            "the block to run and merge into the table",
-        )
+        Signature::build("merge")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run and merge into the table",
+            )
+            .switch(
+                "deep",
+                "merge the nested rows too, instead of replacing them",
+                Some('d'),
+            )
+            .named(
+                "lists",
+                SyntaxShape::String,
+                "how --deep merges lists: replace (the default) or append",
+                Some('l'),
+            )
     }
 
     fn usage(&self) -> &str {
         "Merge a table."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Each row of the input is merged with the row at the same place in the output of the block,
+whose columns replace those of the input row. With --deep, the columns that hold rows on both
+sides are merged in the same way, all the way down, which layers one configuration over another."#
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         merge(args).await
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Merge a 1-based index column with some ls output",
-            example: "ls | select name | keep 3 | merge { echo [1 2 3] | wrap index }",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Merge a 1-based index column with some ls output",
+                example: "ls | select name | keep 3 | merge { echo [1 2 3] | wrap index }",
+                result: None,
+            },
+            Example {
+                description: "Layer the local settings over the defaults",
+                example: "open defaults.toml | merge --deep { open local.toml }",
+                result: None,
+            },
+            Example {
+                description: "Keep the plugins of both, rather than only the local ones",
+                example: "open defaults.toml | merge --deep --lists append { open local.toml }",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -52,6 +85,29 @@ async fn merge(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     let (merge_args, input): (MergeArgs, _) = raw_args.process().await?;
     let block = merge_args.block;
 
+    let lists = match &merge_args.lists {
+        None => ListMerge::Replace,
+        Some(lists) if !merge_args.deep => {
+            return Err(ShellError::labeled_error(
+                "Lists are only merged with --deep",
+                "add --deep to merge the lists",
+                &lists.tag,
+            ))
+        }
+        Some(lists) => match lists.item.as_str() {
+            "replace" => ListMerge::Replace,
+            "append" => ListMerge::Append,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown way to merge lists",
+                    "expected replace or append",
+                    &lists.tag,
+                ))
+            }
+        },
+    };
+    let deep = merge_args.deep;
+
     context.scope.enter_scope();
     context.scope.add_vars(&block.captured.entries);
     let result = run_block(&block.block, &context, InputStream::empty()).await;
@@ -76,18 +132,24 @@ async fn merge(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
         .map(move |(idx, value)| {
             let other = table.get(idx);
 
-            match other {
-                Some(replacement) => match merge_values(&value.value, &replacement.value) {
-                    Ok(merged_value) => ReturnSuccess::value(merged_value.into_value(&value.tag)),
-                    Err(_) => {
-                        let message = format!("The row at {:?} types mismatch", idx);
-                        Err(ShellError::labeled_error(
-                            "Could not merge",
-                            &message,
-                            &value.tag,
-                        ))
-                    }
-                },
+            let merged = other.map(|replacement| {
+                if deep {
+                    merge_values_deep(&value.value, &replacement.value, lists)
+                } else {
+                    merge_values(&value.value, &replacement.value)
+                }
+            });
+
+            match merged {
+                Some(Ok(merged_value)) => ReturnSuccess::value(merged_value.into_value(&value.tag)),
+                Some(Err(_)) => {
+                    let message = format!("The row at {:?} types mismatch", idx);
+                    Err(ShellError::labeled_error(
+                        "Could not merge",
+                        &message,
+                        &value.tag,
+                    ))
+                }
                 None => ReturnSuccess::value(value),
             }
         })
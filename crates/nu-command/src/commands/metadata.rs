@@ -0,0 +1,117 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+
+pub struct Metadata;
+
+#[derive(Deserialize)]
+struct MetadataArgs {
+    value: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Metadata {
+    fn name(&self) -> &str {
+        "metadata"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("metadata").optional(
+            "value",
+            SyntaxShape::Any,
+            "the value to describe (default the input)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Show where a value came from: its span, anchor and content type."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The anchor is the file or URL the value was loaded from, and the content type is the media
+type it was loaded as (eg application/json), which save uses for a file with no extension. The
+values of a stream come from the same place, so the first one is described."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        metadata(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "See which file a table was loaded from",
+                example: "open Cargo.toml | metadata | get anchor",
+                result: None,
+            },
+            Example {
+                description: "See the content type of a web page",
+                example: "fetch https://www.nushell.sh | metadata | get content_type",
+                result: None,
+            },
+            Example {
+                description: "Describe a variable",
+                example: "metadata $config",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn metadata(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (MetadataArgs { value }, mut input) = args.process().await?;
+
+    let value = match value {
+        Some(value) => value,
+        None => match input.next().await {
+            Some(value) => value,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "No value to describe",
+                    "requires a value or input",
+                    &name,
+                ))
+            }
+        },
+    };
+
+    let tag = &value.tag;
+    let mut record = TaggedDictBuilder::new(&name);
+
+    let mut span = TaggedDictBuilder::new(&name);
+    span.insert_untagged("start", UntaggedValue::int(tag.span.start() as i64));
+    span.insert_untagged("end", UntaggedValue::int(tag.span.end() as i64));
+    record.insert_value("span", span.into_value());
+
+    record.insert_untagged(
+        "anchor",
+        match tag.anchor_name() {
+            Some(anchor) => UntaggedValue::string(anchor),
+            None => UntaggedValue::nothing(),
+        },
+    );
+    record.insert_untagged(
+        "content_type",
+        match &tag.content_type {
+            Some(content_type) => UntaggedValue::string(content_type),
+            None => UntaggedValue::nothing(),
+        },
+    );
+
+    Ok(OutputStream::one(record.into_value()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Metadata;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Metadata {})
+    }
+}
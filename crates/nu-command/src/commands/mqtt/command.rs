@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Mqtt;
+
+#[async_trait]
+impl WholeStreamCommand for Mqtt {
+    fn name(&self) -> &str {
+        "mqtt"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("mqtt")
+    }
+
+    fn usage(&self) -> &str {
+        "Publish and subscribe to topics on an MQTT broker."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The broker is given with --broker, or else taken from $nu.env.MQTT_BROKER, then from
+'mqtt_broker' in the config, and finally defaults to mqtt://127.0.0.1:1883. Credentials can be
+part of the url or given with --username and --password. Passing --ca, or using an mqtts:// url
+with --ca, connects over TLS."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(get_full_help(&Mqtt, &args.scope)).into_value(Tag::unknown()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mqtt;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Mqtt {})
+    }
+}
@@ -0,0 +1,197 @@
+mod command;
+mod publish;
+mod subscribe;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+use rumqttc::{MqttOptions, QoS};
+use std::path::{Path, PathBuf};
+use url::Url;
+
+pub use command::Mqtt;
+pub use publish::MqttPublish;
+pub use subscribe::MqttSubscribe;
+
+const DEFAULT_BROKER: &str = "mqtt://127.0.0.1:1883";
+
+/// How to reach the broker, shared by every mqtt command
+struct BrokerArgs {
+    broker: Option<Tagged<String>>,
+    username: Option<Tagged<String>>,
+    password: Option<Tagged<String>>,
+    ca: Option<Tagged<PathBuf>>,
+    cert: Option<Tagged<PathBuf>>,
+    key: Option<Tagged<PathBuf>>,
+}
+
+/// Adds the flags that make up `BrokerArgs` to a signature
+fn broker_flags(signature: Signature) -> Signature {
+    signature
+        .named(
+            "broker",
+            SyntaxShape::String,
+            "the broker to connect to, eg mqtts://broker.local:8883",
+            Some('b'),
+        )
+        .named(
+            "username",
+            SyntaxShape::String,
+            "the user to log in as",
+            Some('u'),
+        )
+        .named(
+            "password",
+            SyntaxShape::String,
+            "the password to log in with",
+            None,
+        )
+        .named(
+            "ca",
+            SyntaxShape::FilePath,
+            "connect with TLS, trusting the certificate authority in this PEM file",
+            None,
+        )
+        .named(
+            "cert",
+            SyntaxShape::FilePath,
+            "a PEM client certificate, for brokers that check them",
+            None,
+        )
+        .named(
+            "key",
+            SyntaxShape::FilePath,
+            "the PEM private key for --cert",
+            None,
+        )
+}
+
+/// The broker to talk to: the `--broker` flag, then `$nu.env.MQTT_BROKER`, then `mqtt_broker`
+/// in the config, then a local broker
+fn broker_url(url: Option<Tagged<String>>, env: &IndexMap<String, String>) -> Tagged<String> {
+    if let Some(url) = url {
+        return url;
+    }
+
+    if let Some(url) = env.get("MQTT_BROKER") {
+        return url.clone().tagged_unknown();
+    }
+
+    if let Ok(config) = config::config(Tag::unknown()) {
+        if let Some(url) = config.get("mqtt_broker") {
+            if let Ok(string) = url.as_string() {
+                return string.tagged(&url.tag);
+            }
+        }
+    }
+
+    DEFAULT_BROKER.to_string().tagged_unknown()
+}
+
+fn mqtt_error(error: impl std::fmt::Display, tag: impl Into<Tag>) -> ShellError {
+    ShellError::labeled_error("MQTT error", error.to_string(), tag)
+}
+
+fn read_file(cwd: &Path, path: &Tagged<PathBuf>) -> Result<Vec<u8>, ShellError> {
+    std::fs::read(cwd.join(&path.item)).map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not read {}", path.item.display()),
+            e.to_string(),
+            &path.tag,
+        )
+    })
+}
+
+fn mqtt_options(
+    args: BrokerArgs,
+    env: &IndexMap<String, String>,
+    cwd: &Path,
+) -> Result<MqttOptions, ShellError> {
+    let broker = broker_url(args.broker, env);
+
+    let url = Url::parse(&broker.item)
+        .map_err(|e| ShellError::labeled_error("Invalid broker url", e.to_string(), &broker.tag))?;
+
+    let tls = match url.scheme() {
+        "mqtt" | "tcp" => false,
+        "mqtts" | "ssl" => true,
+        scheme => {
+            return Err(ShellError::labeled_error(
+                "Invalid broker url",
+                format!("expected a mqtt:// or mqtts:// url, got {}://", scheme),
+                &broker.tag,
+            ))
+        }
+    };
+
+    let host = url.host_str().ok_or_else(|| {
+        ShellError::labeled_error("Invalid broker url", "the url has no host", &broker.tag)
+    })?;
+    let port = url
+        .port()
+        .unwrap_or_else(|| if tls || args.ca.is_some() { 8883 } else { 1883 });
+
+    // Client ids must be unique on the broker, which disconnects the older client otherwise
+    let client_id = format!("nu-{}-{:08x}", std::process::id(), rand::random::<u32>());
+    let mut options = MqttOptions::new(client_id, host, port);
+
+    let username = args
+        .username
+        .map(|username| username.item)
+        .or_else(|| Some(url.username().to_string()).filter(|username| !username.is_empty()));
+    let password = args
+        .password
+        .map(|password| password.item)
+        .or_else(|| url.password().map(String::from));
+
+    if let Some(username) = username {
+        options.set_credentials(username, password.unwrap_or_default());
+    }
+
+    match &args.ca {
+        Some(ca) => {
+            options.set_ca(read_file(cwd, ca)?);
+        }
+        None if tls => {
+            return Err(ShellError::labeled_error(
+                "Missing certificate authority",
+                "connecting with TLS needs a --ca file",
+                &broker.tag,
+            ))
+        }
+        None => {}
+    }
+
+    match (&args.cert, &args.key) {
+        (Some(cert), Some(key)) => {
+            options.set_client_auth(read_file(cwd, cert)?, read_file(cwd, key)?);
+        }
+        (Some(flag), None) | (None, Some(flag)) => {
+            return Err(ShellError::labeled_error(
+                "Incomplete client certificate",
+                "--cert and --key have to be given together",
+                &flag.tag,
+            ))
+        }
+        (None, None) => {}
+    }
+
+    Ok(options)
+}
+
+fn qos(level: Option<Tagged<u32>>) -> Result<QoS, ShellError> {
+    match level {
+        None => Ok(QoS::AtMostOnce),
+        Some(level) => match level.item {
+            0 => Ok(QoS::AtMostOnce),
+            1 => Ok(QoS::AtLeastOnce),
+            2 => Ok(QoS::ExactlyOnce),
+            _ => Err(ShellError::labeled_error(
+                "Invalid quality of service",
+                "expected 0, 1 or 2",
+                &level.tag,
+            )),
+        },
+    }
+}
@@ -0,0 +1,195 @@
+use super::{broker_flags, mqtt_error, mqtt_options, qos, BrokerArgs};
+use crate::prelude::*;
+use crate::utils::json::value_to_json_value;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use rumqttc::{Client, Event, Outgoing, Packet, QoS};
+use std::path::PathBuf;
+
+pub struct MqttPublish;
+
+#[derive(Deserialize)]
+pub struct MqttPublishArgs {
+    topic: Tagged<String>,
+    qos: Option<Tagged<u32>>,
+    retain: bool,
+    broker: Option<Tagged<String>>,
+    username: Option<Tagged<String>>,
+    password: Option<Tagged<String>>,
+    ca: Option<Tagged<PathBuf>>,
+    cert: Option<Tagged<PathBuf>>,
+    key: Option<Tagged<PathBuf>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for MqttPublish {
+    fn name(&self) -> &str {
+        "mqtt pub"
+    }
+
+    fn signature(&self) -> Signature {
+        broker_flags(
+            Signature::build("mqtt pub")
+                .required("topic", SyntaxShape::String, "the topic to publish to")
+                .named(
+                    "qos",
+                    SyntaxShape::Int,
+                    "the quality of service to publish with: 0, 1 or 2 (default 0)",
+                    Some('q'),
+                )
+                .switch(
+                    "retain",
+                    "ask the broker to keep the last message for new subscribers",
+                    Some('r'),
+                ),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Publish each value from the pipeline as an MQTT message."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Strings and binary data are sent as they are, records and tables are sent as JSON and
+anything else is sent as its text. With a quality of service of 1 or 2, the command waits until
+the broker has acknowledged every message."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        mqtt_publish(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Turn on the kitchen light",
+                example: "echo on | mqtt pub home/kitchen/light/set",
+                result: None,
+            },
+            Example {
+                description: "Publish a retained status record as JSON, making sure it arrives",
+                example: "echo [[state battery]; [online 87]] | first | mqtt pub sensors/door/status --qos 1 --retain",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn mqtt_publish(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let env = args.scope.get_env_vars();
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let (
+        MqttPublishArgs {
+            topic,
+            qos: level,
+            retain,
+            broker,
+            username,
+            password,
+            ca,
+            cert,
+            key,
+        },
+        input,
+    ) = args.process().await?;
+
+    let options = mqtt_options(
+        BrokerArgs {
+            broker,
+            username,
+            password,
+            ca,
+            cert,
+            key,
+        },
+        &env,
+        &cwd,
+    )?;
+    let level = qos(level)?;
+
+    let values: Vec<Value> = input.collect().await;
+    let payloads = values.iter().map(to_bytes).collect::<Result<Vec<_>, _>>()?;
+
+    if payloads.is_empty() {
+        return Ok(OutputStream::empty());
+    }
+
+    let (mut client, mut connection) = Client::new(options, 10);
+    let expected = payloads.len();
+
+    // Nothing is sent until the connection is polled, so it's polled on its own thread until
+    // every message is out (or acknowledged, when the quality of service asks for that)
+    let sender = std::thread::spawn(move || {
+        let mut done = 0;
+
+        for notification in connection.iter() {
+            match (notification, level) {
+                (Ok(Event::Outgoing(Outgoing::Publish(_))), QoS::AtMostOnce)
+                | (Ok(Event::Incoming(Packet::PubAck(_))), QoS::AtLeastOnce)
+                | (Ok(Event::Incoming(Packet::PubComp(_))), QoS::ExactlyOnce) => done += 1,
+                (Ok(_), _) => {}
+                (Err(e), _) => return Err(e),
+            }
+
+            if done == expected {
+                break;
+            }
+        }
+
+        Ok(())
+    });
+
+    // Publishing only fails once the connection is gone, in which case the reason is reported
+    // by the polling thread
+    let mut published = Ok(());
+    for payload in payloads {
+        if let Err(e) = client.publish(topic.item.as_str(), level, retain, payload) {
+            published = Err(mqtt_error(e, &topic.tag));
+            break;
+        }
+    }
+
+    let sent = sender.join().map_err(|_| {
+        ShellError::labeled_error(
+            "MQTT error",
+            "the connection to the broker stopped unexpectedly",
+            &name_tag,
+        )
+    })?;
+
+    let _ = client.disconnect();
+    sent.map_err(|e| mqtt_error(e, &name_tag))?;
+    published?;
+
+    Ok(OutputStream::empty())
+}
+
+fn to_bytes(value: &Value) -> Result<Vec<u8>, ShellError> {
+    Ok(match &value.value {
+        UntaggedValue::Primitive(Primitive::Binary(bytes)) => bytes.clone(),
+        UntaggedValue::Primitive(Primitive::String(s)) => s.clone().into_bytes(),
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => {
+            let json = value_to_json_value(value)?;
+            serde_json::to_vec(&json).map_err(|e| {
+                ShellError::labeled_error("Could not convert to JSON", e.to_string(), &value.tag)
+            })?
+        }
+        _ => value.convert_to_string().into_bytes(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MqttPublish;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(MqttPublish {})
+    }
+}
@@ -0,0 +1,208 @@
+use super::{broker_flags, mqtt_error, mqtt_options, qos, BrokerArgs};
+use crate::futures::ThreadedReceiver;
+use crate::prelude::*;
+use chrono::{DateTime, Local};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+use rumqttc::{Client, Event, Packet, Publish, QoS};
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+pub struct MqttSubscribe;
+
+#[derive(Deserialize)]
+pub struct MqttSubscribeArgs {
+    topic: Tagged<String>,
+    qos: Option<Tagged<u32>>,
+    count: Option<Tagged<usize>>,
+    broker: Option<Tagged<String>>,
+    username: Option<Tagged<String>>,
+    password: Option<Tagged<String>>,
+    ca: Option<Tagged<PathBuf>>,
+    cert: Option<Tagged<PathBuf>>,
+    key: Option<Tagged<PathBuf>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for MqttSubscribe {
+    fn name(&self) -> &str {
+        "mqtt sub"
+    }
+
+    fn signature(&self) -> Signature {
+        broker_flags(
+            Signature::build("mqtt sub")
+                .required(
+                    "topic",
+                    SyntaxShape::String,
+                    "the topic filter to subscribe to, wildcards like 'home/+/temp' and 'home/#' are allowed",
+                )
+                .named(
+                    "qos",
+                    SyntaxShape::Int,
+                    "the quality of service to subscribe with: 0, 1 or 2 (default 0)",
+                    Some('q'),
+                )
+                .named(
+                    "count",
+                    SyntaxShape::Int,
+                    "stop after this many messages",
+                    Some('c'),
+                ),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Stream the messages published to an MQTT topic."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each message becomes a row with its topic, payload, quality of service, retain flag and the
+time it was received. The payload is a string when it's valid UTF-8 and binary otherwise, so JSON
+payloads can be decoded with 'from json'. Without --count, messages are streamed until
+interrupted."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        mqtt_subscribe(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Watch everything happening in the house",
+                example: "mqtt sub 'home/#'",
+                result: None,
+            },
+            Example {
+                description: "Read the next five temperature readings",
+                example: "mqtt sub 'home/+/temperature' --count 5 | get payload | into int",
+                result: None,
+            },
+            Example {
+                description: "Subscribe over TLS with a client certificate",
+                example: "mqtt sub 'sensors/#' --broker mqtts://broker.local --ca ca.pem --cert me.pem --key me.key",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn mqtt_subscribe(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let env = args.scope.get_env_vars();
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let (
+        MqttSubscribeArgs {
+            topic,
+            qos: level,
+            count,
+            broker,
+            username,
+            password,
+            ca,
+            cert,
+            key,
+        },
+        _,
+    ) = args.process().await?;
+
+    let options = mqtt_options(
+        BrokerArgs {
+            broker,
+            username,
+            password,
+            ca,
+            cert,
+            key,
+        },
+        &env,
+        &cwd,
+    )?;
+    let level = qos(level)?;
+
+    if let Some(Tagged { item: 0, .. }) = count {
+        return Ok(OutputStream::empty());
+    }
+
+    let (mut client, mut connection) = Client::new(options, 10);
+    client
+        .subscribe(topic.item.as_str(), level)
+        .map_err(|e| mqtt_error(e, &topic.tag))?;
+
+    // Messages only arrive while the connection is being polled, which blocks, so it's polled on
+    // its own thread and the messages are handed over as the pipeline asks for them
+    let (tx, rx) = mpsc::sync_channel(0);
+
+    std::thread::spawn(move || {
+        let mut remaining = count.map(|count| count.item).unwrap_or(usize::MAX);
+
+        for notification in connection.iter() {
+            let message = match notification {
+                Ok(Event::Incoming(Packet::Publish(publish))) => {
+                    remaining -= 1;
+                    Ok(publish_to_value(publish, &name_tag))
+                }
+                Ok(_) => continue,
+                Err(e) => Err(mqtt_error(e, &name_tag)),
+            };
+
+            let failed = message.is_err();
+            if tx.send(message).is_err() || failed || remaining == 0 {
+                // Either nobody is reading the rest of the messages anymore, the connection is
+                // gone, or we have all the messages we were asked for
+                break;
+            }
+        }
+
+        let _ = client.disconnect();
+    });
+
+    Ok(ThreadedReceiver::new(rx)
+        .map(|message: Result<Value, ShellError>| message.map(ReturnSuccess::Value))
+        .to_output_stream())
+}
+
+fn publish_to_value(publish: Publish, tag: &Tag) -> Value {
+    let received: DateTime<Local> = Local::now();
+    let mut dict = TaggedDictBuilder::new(tag);
+
+    dict.insert_untagged("topic", UntaggedValue::string(publish.topic));
+    dict.insert_untagged(
+        "payload",
+        match String::from_utf8(publish.payload.to_vec()) {
+            Ok(string) => UntaggedValue::string(string),
+            Err(e) => UntaggedValue::binary(e.into_bytes()),
+        },
+    );
+    dict.insert_untagged(
+        "qos",
+        UntaggedValue::int(match publish.qos {
+            QoS::AtMostOnce => 0,
+            QoS::AtLeastOnce => 1,
+            QoS::ExactlyOnce => 2,
+        }),
+    );
+    dict.insert_untagged("retain", UntaggedValue::boolean(publish.retain));
+    dict.insert_untagged(
+        "received",
+        UntaggedValue::date(received.with_timezone(received.offset())),
+    );
+
+    dict.into_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MqttSubscribe;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(MqttSubscribe {})
+    }
+}
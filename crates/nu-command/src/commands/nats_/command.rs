@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Nats;
+
+#[async_trait]
+impl WholeStreamCommand for Nats {
+    fn name(&self) -> &str {
+        "nats"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("nats")
+    }
+
+    fn usage(&self) -> &str {
+        "Publish and subscribe to subjects on a NATS server."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The server is given with --url, or else taken from $nu.env.NATS_URL, then from 'nats_url'
+in the config, and finally defaults to nats://127.0.0.1:4222."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(get_full_help(&Nats, &args.scope)).into_value(Tag::unknown()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Nats;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Nats {})
+    }
+}
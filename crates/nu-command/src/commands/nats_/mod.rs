@@ -0,0 +1,43 @@
+mod command;
+mod publish;
+mod subscribe;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_source::Tagged;
+
+pub use command::Nats;
+pub use publish::NatsPublish;
+pub use subscribe::NatsSubscribe;
+
+const DEFAULT_URL: &str = "nats://127.0.0.1:4222";
+
+/// The server to talk to: the `--url` flag, then `$nu.env.NATS_URL`, then `nats_url` in the
+/// config, then a local server
+fn nats_url(url: Option<Tagged<String>>, env: &IndexMap<String, String>) -> Tagged<String> {
+    if let Some(url) = url {
+        return url;
+    }
+
+    if let Some(url) = env.get("NATS_URL") {
+        return url.clone().tagged_unknown();
+    }
+
+    if let Ok(config) = config::config(Tag::unknown()) {
+        if let Some(url) = config.get("nats_url") {
+            if let Ok(string) = url.as_string() {
+                return string.tagged(&url.tag);
+            }
+        }
+    }
+
+    DEFAULT_URL.to_string().tagged_unknown()
+}
+
+fn nats_error(error: std::io::Error, tag: impl Into<Tag>) -> ShellError {
+    ShellError::labeled_error("NATS error", error.to_string(), tag)
+}
+
+fn connect(url: &Tagged<String>) -> Result<nats::Connection, ShellError> {
+    nats::connect(url.item.as_str()).map_err(|e| nats_error(e, &url.tag))
+}
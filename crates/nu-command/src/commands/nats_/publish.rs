@@ -0,0 +1,127 @@
+use super::{connect, nats_error, nats_url};
+use crate::prelude::*;
+use crate::utils::json::value_to_json_value;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct NatsPublish;
+
+#[derive(Deserialize)]
+pub struct NatsPublishArgs {
+    subject: Tagged<String>,
+    reply: Option<Tagged<String>>,
+    url: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for NatsPublish {
+    fn name(&self) -> &str {
+        "nats publish"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("nats publish")
+            .required("subject", SyntaxShape::String, "the subject to publish to")
+            .named(
+                "reply",
+                SyntaxShape::String,
+                "the subject responders should reply to",
+                Some('r'),
+            )
+            .named(
+                "url",
+                SyntaxShape::String,
+                "the server to connect to, eg nats://127.0.0.1:4222",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Publish each value from the pipeline as a NATS message."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Strings and binary data are sent as they are, records and tables are sent as JSON and
+anything else is sent as its text."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        nats_publish(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Publish a message",
+                example: "echo 'deploy finished' | nats publish events.deploy",
+                result: None,
+            },
+            Example {
+                description: "Publish every row of a table as its own JSON message",
+                example: "open orders.csv | nats publish orders.created",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn nats_publish(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let env = args.scope.get_env_vars();
+    let (
+        NatsPublishArgs {
+            subject,
+            reply,
+            url,
+        },
+        mut input,
+    ) = args.process().await?;
+
+    let url = nats_url(url, &env);
+    let connection = connect(&url)?;
+
+    while let Some(value) = input.next().await {
+        let data = to_bytes(&value)?;
+
+        match &reply {
+            Some(reply) => connection.publish_request(&subject.item, &reply.item, data),
+            None => connection.publish(&subject.item, data),
+        }
+        .map_err(|e| nats_error(e, &value.tag))?;
+    }
+
+    // Publishing is buffered, so make sure everything reached the server before returning
+    connection
+        .flush()
+        .map_err(|e| nats_error(e, &subject.tag))?;
+
+    Ok(OutputStream::empty())
+}
+
+fn to_bytes(value: &Value) -> Result<Vec<u8>, ShellError> {
+    Ok(match &value.value {
+        UntaggedValue::Primitive(Primitive::Binary(bytes)) => bytes.clone(),
+        UntaggedValue::Primitive(Primitive::String(s)) => s.clone().into_bytes(),
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => {
+            let json = value_to_json_value(value)?;
+            serde_json::to_vec(&json).map_err(|e| {
+                ShellError::labeled_error("Could not convert to JSON", e.to_string(), &value.tag)
+            })?
+        }
+        _ => value.convert_to_string().into_bytes(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NatsPublish;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(NatsPublish {})
+    }
+}
@@ -0,0 +1,192 @@
+use super::{connect, nats_error, nats_url};
+use crate::futures::ThreadedReceiver;
+use crate::prelude::*;
+use chrono::{DateTime, Local};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+use std::sync::mpsc;
+
+pub struct NatsSubscribe;
+
+#[derive(Deserialize)]
+pub struct NatsSubscribeArgs {
+    subject: Tagged<String>,
+    queue: Option<Tagged<String>>,
+    count: Option<Tagged<usize>>,
+    url: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for NatsSubscribe {
+    fn name(&self) -> &str {
+        "nats subscribe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("nats subscribe")
+            .required(
+                "subject",
+                SyntaxShape::String,
+                "the subject to listen on, wildcards like 'orders.*' and 'orders.>' are allowed",
+            )
+            .named(
+                "queue",
+                SyntaxShape::String,
+                "join a queue group, so each message goes to only one of its members",
+                Some('q'),
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "stop after this many messages",
+                Some('c'),
+            )
+            .named(
+                "url",
+                SyntaxShape::String,
+                "the server to connect to, eg nats://127.0.0.1:4222",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Stream the messages published to a NATS subject."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each message becomes a row with its subject, reply subject, headers, data and the time it
+was received. The data is a string when it's valid UTF-8 and binary otherwise, so JSON payloads
+can be decoded with 'from json'. Without --count, messages are streamed until interrupted."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        nats_subscribe(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Watch every order event as it happens",
+                example: "nats subscribe 'orders.>'",
+                result: None,
+            },
+            Example {
+                description: "Take the next ten JSON messages and decode them",
+                example: "nats subscribe orders.created --count 10 | get data | from json",
+                result: None,
+            },
+            Example {
+                description: "Share the work with other members of a queue group",
+                example: "nats subscribe jobs --queue workers",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn nats_subscribe(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let env = args.scope.get_env_vars();
+    let (
+        NatsSubscribeArgs {
+            subject,
+            queue,
+            count,
+            url,
+        },
+        _,
+    ) = args.process().await?;
+
+    let url = nats_url(url, &env);
+    let connection = connect(&url)?;
+
+    let subscription = match &queue {
+        Some(queue) => connection.queue_subscribe(&subject.item, &queue.item),
+        None => connection.subscribe(&subject.item),
+    }
+    .map_err(|e| nats_error(e, &subject.tag))?;
+
+    // Waiting for the next message blocks, so messages are read on their own thread and handed
+    // over as the pipeline asks for them
+    let (tx, rx) = mpsc::sync_channel(0);
+
+    std::thread::spawn(move || {
+        // The subscription only lives as long as its connection
+        let _connection = connection;
+        let limit = count.map(|count| count.item).unwrap_or(usize::MAX);
+
+        for message in subscription.messages().take(limit) {
+            if tx.send(Ok(message_to_value(message, &name_tag))).is_err() {
+                // Nobody is reading the rest of the messages anymore
+                break;
+            }
+        }
+
+        let _ = subscription.unsubscribe();
+    });
+
+    Ok(ThreadedReceiver::new(rx)
+        .map(|message: Result<Value, ShellError>| message.map(ReturnSuccess::Value))
+        .to_output_stream())
+}
+
+fn message_to_value(message: nats::Message, tag: &Tag) -> Value {
+    let received: DateTime<Local> = Local::now();
+    let mut dict = TaggedDictBuilder::new(tag);
+
+    dict.insert_untagged("subject", UntaggedValue::string(&message.subject));
+    dict.insert_untagged(
+        "reply",
+        match &message.reply {
+            Some(reply) => UntaggedValue::string(reply),
+            None => UntaggedValue::nothing(),
+        },
+    );
+
+    let mut headers = TaggedDictBuilder::new(tag);
+    if let Some(message_headers) = &message.headers {
+        for (name, values) in message_headers.iter() {
+            // Most headers have a single value, so only repeated ones become lists
+            let mut values: Vec<Value> = values
+                .iter()
+                .map(|value| UntaggedValue::string(value).into_value(tag))
+                .collect();
+
+            if values.len() == 1 {
+                headers.insert_value(name, values.remove(0));
+            } else {
+                headers.insert_untagged(name, UntaggedValue::Table(values));
+            }
+        }
+    }
+    dict.insert_value("headers", headers.into_value());
+
+    dict.insert_untagged(
+        "data",
+        match String::from_utf8(message.data) {
+            Ok(string) => UntaggedValue::string(string),
+            Err(e) => UntaggedValue::binary(e.into_bytes()),
+        },
+    );
+    dict.insert_untagged(
+        "received",
+        UntaggedValue::date(received.with_timezone(received.offset())),
+    );
+
+    dict.into_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NatsSubscribe;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(NatsSubscribe {})
+    }
+}
@@ -5,6 +5,7 @@ use nu_engine::filesystem::path::canonicalize;
 use nu_engine::WholeStreamCommand;
 
 use nu_errors::ShellError;
+use nu_plugin::PluginAddress;
 use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
 use nu_source::Tagged;
 
@@ -17,6 +18,7 @@ pub struct SubCommand;
 pub struct Arguments {
     #[serde(rename = "load")]
     pub load_path: Option<Tagged<PathBuf>>,
+    pub remote: Option<Tagged<String>>,
 }
 
 #[async_trait]
@@ -26,12 +28,19 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("nu plugin").named(
-            "load",
-            SyntaxShape::FilePath,
-            "a path to load the plugins from",
-            Some('l'),
-        )
+        Signature::build("nu plugin")
+            .named(
+                "load",
+                SyntaxShape::FilePath,
+                "a path to load the plugins from",
+                Some('l'),
+            )
+            .named(
+                "remote",
+                SyntaxShape::String,
+                "the address of a plugin to load, eg tcp://localhost:4000",
+                Some('r'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -39,17 +48,39 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Load all plugins in the current directory",
-            example: "nu plugin --load .",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Load all plugins in the current directory",
+                example: "nu plugin --load .",
+                result: None,
+            },
+            Example {
+                description: "Load the plugin served at a socket address",
+                example: "nu plugin --remote tcp://localhost:4000",
+                result: None,
+            },
+        ]
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         let scope = args.scope.clone();
         let shell_manager = args.shell_manager.clone();
-        let (Arguments { load_path }, _) = args.process().await?;
+        let (Arguments { load_path, remote }, _) = args.process().await?;
+
+        if let Some(Tagged { item: address, tag }) = remote {
+            let address = PluginAddress::parse(&address).map_err(|_| {
+                ShellError::labeled_error(
+                    "Cannot load the remote plugin",
+                    "expected an address starting with tcp:// or unix://",
+                    &tag,
+                )
+            })?;
+
+            return Ok(vec![ReturnSuccess::action(CommandAction::AddPlugins(
+                address.to_string(),
+            ))]
+            .into());
+        }
 
         if let Some(Tagged {
             item: load_path,
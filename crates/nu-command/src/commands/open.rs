@@ -3,10 +3,13 @@ use crate::prelude::*;
 use encoding_rs::{Encoding, UTF_8};
 use futures_util::StreamExt;
 use log::debug;
+use nu_data::content_type;
 use nu_engine::StringOrBinary;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    CommandAction, NuGlob, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
 use nu_source::{AnchorLocation, Span, Tagged};
 use std::path::{Path, PathBuf};
 
@@ -116,13 +119,14 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
         _,
     ) = args.process().await?;
 
-    if path.is_dir() {
+    if shell_manager.is_dir(&path.item) {
         let args = nu_engine::shell::LsArgs {
-            path: Some(path),
+            path: Some(NuGlob::Literal(path.to_string_lossy().to_string()).tagged(&path.tag)),
             all: false,
             long: false,
             short_names: false,
             du: false,
+            max_depth: None,
         };
         return shell_manager.ls(args, name, ctrl_c);
     }
@@ -143,19 +147,24 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
     if let Some(ext) = ext {
         // Check if we have a conversion command
         if let Some(_command) = scope.get_command(&format!("from {}", ext)) {
-            let (_, tagged_contents) = crate::commands::open::fetch(
-                &cwd,
-                &PathBuf::from(&path.item),
-                path.tag.span,
-                encoding,
-            )
-            .await?;
+            let tagged_contents = if shell_manager.is_filesystem() {
+                crate::commands::open::fetch(
+                    &cwd,
+                    &PathBuf::from(&path.item),
+                    path.tag.span,
+                    encoding,
+                )
+                .await?
+                .1
+            } else {
+                fetch_from_shell(&shell_manager, &path, encoding).await?
+            };
             return Ok(OutputStream::one(ReturnSuccess::action(
                 CommandAction::AutoConvert(tagged_contents, ext),
             )));
         }
         // Check if bat does syntax highlighting
-        if BAT_LANGUAGES.contains(&ext.as_ref()) {
+        if BAT_LANGUAGES.contains(&ext.as_ref()) && shell_manager.is_filesystem() {
             let (_, tagged_contents) = crate::commands::open::fetch(
                 &cwd,
                 &PathBuf::from(&path.item),
@@ -176,11 +185,17 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     let sob_stream = shell_manager.open(&path.item, path.tag.span, with_encoding)?;
 
+    let content_type = path
+        .extension()
+        .and_then(|ext| content_type::for_extension(&ext.to_string_lossy()))
+        .map(String::from);
+
     let final_stream = sob_stream.map(move |x| {
         // The tag that will used when returning a Value
         let file_tag = Tag {
             span: path.tag.span,
             anchor: Some(AnchorLocation::File(path.to_string_lossy().to_string())),
+            content_type: content_type.clone(),
         };
 
         match x {
@@ -197,6 +212,48 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
     Ok(OutputStream::new(final_stream))
 }
 
+/// Reads a whole file through the current shell, for files that aren't on the filesystem, like
+/// those inside an archive
+async fn fetch_from_shell(
+    shell_manager: &ShellManager,
+    location: &Tagged<PathBuf>,
+    encoding_choice: Option<Tagged<String>>,
+) -> Result<Value, ShellError> {
+    let with_encoding = if encoding_choice.is_none() {
+        None
+    } else {
+        Some(get_encoding(encoding_choice)?)
+    };
+
+    let file_tag = Tag {
+        span: location.tag.span,
+        anchor: Some(AnchorLocation::File(location.to_string_lossy().to_string())),
+        content_type: location
+            .extension()
+            .and_then(|ext| content_type::for_extension(&ext.to_string_lossy()))
+            .map(String::from),
+    };
+
+    let mut text = String::new();
+    let mut binary = vec![];
+    let chunks: Vec<_> = shell_manager
+        .open(&location.item, location.tag.span, with_encoding)?
+        .collect()
+        .await;
+    for chunk in chunks {
+        match chunk? {
+            StringOrBinary::String(s) => text.push_str(&s),
+            StringOrBinary::Binary(b) => binary.extend(b),
+        }
+    }
+
+    if binary.is_empty() {
+        Ok(UntaggedValue::string(text).into_value(file_tag))
+    } else {
+        Ok(UntaggedValue::binary(binary).into_value(file_tag))
+    }
+}
+
 // Note that we do not output a Stream in "fetch" since it is only used by "enter" command
 // Which we expect to use a concrete Value a not a Stream
 pub async fn fetch(
@@ -235,6 +292,10 @@ pub async fn fetch(
         anchor: Some(AnchorLocation::File(
             nice_location.to_string_lossy().to_string(),
         )),
+        content_type: ext
+            .as_deref()
+            .and_then(content_type::for_extension)
+            .map(String::from),
     };
 
     let res = std::fs::read(location)
@@ -0,0 +1,178 @@
+use crate::commands::each::{make_indexed_item, process_row};
+use crate::futures::ThreadedReceiver;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+
+use futures::executor::{block_on, block_on_stream};
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, ReturnValue, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use rayon::iter::{ParallelBridge, ParallelIterator};
+use std::collections::BTreeMap;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+
+pub struct ParEach;
+
+#[derive(Deserialize)]
+pub struct ParEachArgs {
+    block: CapturedBlock,
+    threads: Option<Tagged<usize>>,
+    unordered: Tagged<bool>,
+    numbered: Tagged<bool>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for ParEach {
+    fn name(&self) -> &str {
+        "par-each"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("par-each")
+            .required("block", SyntaxShape::Block, "the block to run on each row")
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "the number of threads to run the block on (defaults to one per CPU)",
+                Some('t'),
+            )
+            .switch(
+                "unordered",
+                "return the results as they finish instead of in the order of the rows",
+                Some('u'),
+            )
+            .switch(
+                "numbered",
+                "returned a numbered item ($it.index and $it.item)",
+                Some('n'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block on each row of the table, on several threads at once."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each row gets a scope of its own, so variables set in the block don't carry over to the other rows. The results come out in the order of the rows unless --unordered is given."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        par_each(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Double each number, two at a time",
+                example: "echo [1 2 3] | par-each --threads 2 { echo $(= $it * 2) }",
+                result: Some(vec![
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(4).into(),
+                    UntaggedValue::int(6).into(),
+                ]),
+            },
+            Example {
+                description: "Hash the files in the directory, in the order they finish",
+                example: "ls | par-each --unordered { open $it.name | hash md5 }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn par_each(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = Arc::new(EvaluationContext::from_args(&raw_args));
+
+    let (args, input): (ParEachArgs, _) = raw_args.process().await?;
+    let block = Arc::new(Box::new(args.block));
+    let numbered = args.numbered.item;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.threads.map(|threads| threads.item).unwrap_or(0))
+        .build()
+        .map_err(|e| ShellError::untagged_runtime_error(e.to_string()))?;
+
+    // The rows are run on the pool from a thread of its own, which hands each row's results over
+    // with its index as they finish
+    let (tx, rx) = mpsc::sync_channel::<(usize, Vec<ReturnValue>)>(pool.current_num_threads());
+
+    std::thread::spawn(move || {
+        pool.install(|| {
+            let _ = block_on_stream(input)
+                .enumerate()
+                .par_bridge()
+                .try_for_each_init(
+                    // Every thread runs its rows in a scope of its own
+                    || EvaluationContext {
+                        scope: context.scope.snapshot(),
+                        ..(*context).clone()
+                    },
+                    |thread_context, (index, row)| {
+                        if context.ctrl_c.load(Ordering::SeqCst) {
+                            return Err(());
+                        }
+
+                        let row = if numbered {
+                            make_indexed_item(index, row)
+                        } else {
+                            row
+                        };
+
+                        let results = block_on(async {
+                            let context = Arc::new(thread_context.clone());
+                            match process_row(block.clone(), context, row).await {
+                                Ok(stream) => stream.collect::<Vec<_>>().await,
+                                Err(e) => vec![Err(e)],
+                            }
+                        });
+
+                        // Nobody is reading the rest of the results anymore
+                        tx.send((index, results)).map_err(|_| ())
+                    },
+                );
+        });
+    });
+
+    let results = ThreadedReceiver::new(rx);
+
+    if args.unordered.item {
+        Ok(results
+            .map(|(_, results)| futures::stream::iter(results))
+            .flatten()
+            .to_output_stream())
+    } else {
+        Ok(results
+            .scan(
+                (0, BTreeMap::new()),
+                |(next, pending): &mut (usize, BTreeMap<usize, Vec<ReturnValue>>),
+                 (index, results)| {
+                    // Results that finish early wait for the rows before them
+                    pending.insert(index, results);
+
+                    let mut ready = vec![];
+                    while let Some(results) = pending.remove(&*next) {
+                        ready.extend(results);
+                        *next += 1;
+                    }
+
+                    futures::future::ready(Some(futures::stream::iter(ready)))
+                },
+            )
+            .flatten()
+            .to_output_stream())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParEach;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(ParEach {})
+    }
+}
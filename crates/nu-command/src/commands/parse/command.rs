@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tagged;
 
 use regex::Regex;
@@ -9,6 +9,7 @@ use regex::Regex;
 #[derive(Deserialize)]
 struct Arguments {
     pattern: Tagged<String>,
+    rest: Vec<Tagged<String>>,
     regex: Tagged<bool>,
 }
 
@@ -27,6 +28,10 @@ impl WholeStreamCommand for Command {
                 SyntaxShape::String,
                 "the pattern to match. Eg) \"{foo}: {bar}\"",
             )
+            .rest(
+                SyntaxShape::String,
+                "more patterns, tried in order on the strings the ones before don't match",
+            )
             .switch("regex", "use full regex syntax for patterns", Some('r'))
     }
 
@@ -34,6 +39,15 @@ impl WholeStreamCommand for Command {
         "Parse columns from string data using a simple pattern."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"With --regex, the named capture groups of the pattern become the columns, and the unnamed ones
+are called Capture1, Capture2 and so on. Every match in a string gives a row, and the strings are
+parsed as they come in.
+
+More patterns can be given, which are tried in order on each string until one matches. The rows
+then have a pattern column with the position of the pattern that matched, starting at 0."#
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         operate(args).await
     }
@@ -42,61 +56,141 @@ impl WholeStreamCommand for Command {
         let mut row = IndexMap::new();
         row.insert("foo".to_string(), Value::from("hi"));
         row.insert("bar".to_string(), Value::from("there"));
-        vec![Example {
-            description: "Parse a string into two named columns",
-            example: "echo \"hi there\" | parse \"{foo} {bar}\"",
-            result: Some(vec![UntaggedValue::row(row).into()]),
-        }]
+        vec![
+            Example {
+                description: "Parse a string into two named columns",
+                example: "echo \"hi there\" | parse \"{foo} {bar}\"",
+                result: Some(vec![UntaggedValue::row(row.clone()).into()]),
+            },
+            Example {
+                description: "Parse a string using the named capture groups of a regex",
+                example: r#"echo "hi there" | parse --regex '(?P<foo>\w+) (?P<bar>\w+)'"#,
+                result: Some(vec![UntaggedValue::row(row).into()]),
+            },
+            Example {
+                description: "Get a row for every match in the string",
+                example: r#"echo "a=1 b=2" | parse --regex '(?P<key>\w+)=(?P<value>\w+)'"#,
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "key".to_string() => Value::from("a"),
+                        "value".to_string() => Value::from("1"),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "key".to_string() => Value::from("b"),
+                        "value".to_string() => Value::from("2"),
+                    })
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Try another pattern on the strings the first doesn't match",
+                example: r#"echo ["GET /index" "404 /missing"] | parse "GET {path}" "{status} {path}""#,
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "pattern".to_string() => UntaggedValue::int(0).into(),
+                        "path".to_string() => Value::from("/index"),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "pattern".to_string() => UntaggedValue::int(1).into(),
+                        "status".to_string() => Value::from("404"),
+                        "path".to_string() => Value::from("/missing"),
+                    })
+                    .into(),
+                ]),
+            },
+        ]
     }
 }
 
 pub async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_tag = args.call_info.name_tag.clone();
-    let (Arguments { regex, pattern }, mut input) = args.process().await?;
+    let (
+        Arguments {
+            regex,
+            pattern,
+            rest,
+        },
+        input,
+    ) = args.process().await?;
 
-    let regex_pattern = if let Tagged { item: true, tag } = regex {
-        Regex::new(&pattern.item)
-            .map_err(|_| ShellError::labeled_error("Invalid regex", "invalid regex", tag.span))?
-    } else {
-        let parse_regex = build_regex(&pattern.item, name_tag.clone())?;
+    // With more than one pattern, the rows say which of them matched
+    let with_pattern = !rest.is_empty();
 
-        Regex::new(&parse_regex).map_err(|_| {
-            ShellError::labeled_error("Invalid pattern", "invalid pattern", name_tag.span)
-        })?
-    };
+    let patterns = std::iter::once(pattern)
+        .chain(rest)
+        .map(|pattern| {
+            let regex_pattern = if let Tagged { item: true, tag } = &regex {
+                Regex::new(&pattern.item).map_err(|_| {
+                    ShellError::labeled_error("Invalid regex", "invalid regex", tag.span)
+                })?
+            } else {
+                let parse_regex = build_regex(&pattern.item, name_tag.clone())?;
+
+                Regex::new(&parse_regex).map_err(|_| {
+                    ShellError::labeled_error("Invalid pattern", "invalid pattern", name_tag.span)
+                })?
+            };
 
-    let columns = column_names(&regex_pattern);
-    let mut parsed: VecDeque<Value> = VecDeque::new();
+            let columns = column_names(&regex_pattern);
+            Ok((regex_pattern, columns))
+        })
+        .collect::<Result<Vec<_>, ShellError>>()?;
 
-    while let Some(v) = input.next().await {
-        match v.as_string() {
-            Ok(s) => {
-                let results = regex_pattern.captures_iter(&s);
+    // Each string is parsed as it comes, so long inputs like logs don't have to be read in at once
+    Ok(input
+        .map(move |v| match v.as_string() {
+            Ok(s) => parse_string(&s, &v.tag, &patterns, with_pattern)
+                .into_iter()
+                .map(ReturnSuccess::value)
+                .collect::<Vec<_>>(),
+            Err(_) => vec![Err(ShellError::labeled_error_with_secondary(
+                "Expected string input",
+                "expected string input",
+                &name_tag,
+                "value originated here",
+                v.tag,
+            ))],
+        })
+        .map(futures::stream::iter)
+        .flatten()
+        .to_output_stream())
+}
 
-                for c in results {
-                    let mut dict = TaggedDictBuilder::new(&v.tag);
+/// A row for every match of the first pattern that matches the string
+fn parse_string(
+    s: &str,
+    tag: &Tag,
+    patterns: &[(Regex, Vec<String>)],
+    with_pattern: bool,
+) -> Vec<Value> {
+    let (index, (regex_pattern, columns)) = match patterns
+        .iter()
+        .enumerate()
+        .find(|(_, (regex_pattern, _))| regex_pattern.is_match(s))
+    {
+        Some(found) => found,
+        None => return vec![],
+    };
 
-                    for (column_name, cap) in columns.iter().zip(c.iter().skip(1)) {
-                        let cap_string = cap.map(|v| v.as_str()).unwrap_or("").to_string();
-                        dict.insert_untagged(column_name, UntaggedValue::string(cap_string));
-                    }
+    regex_pattern
+        .captures_iter(s)
+        .map(|c| {
+            let mut dict = TaggedDictBuilder::new(tag);
 
-                    parsed.push_back(dict.into_value());
-                }
+            if with_pattern {
+                dict.insert_untagged("pattern", UntaggedValue::int(index));
             }
-            Err(_) => {
-                return Err(ShellError::labeled_error_with_secondary(
-                    "Expected string input",
-                    "expected string input",
-                    &name_tag,
-                    "value originated here",
-                    v.tag,
-                ))
+
+            for (column_name, cap) in columns.iter().zip(c.iter().skip(1)) {
+                let cap_string = cap.map(|v| v.as_str()).unwrap_or("").to_string();
+                dict.insert_untagged(column_name, UntaggedValue::string(cap_string));
             }
-        }
-    }
 
-    Ok(futures::stream::iter(parsed).to_output_stream())
+            dict.into_value()
+        })
+        .collect()
 }
 
 fn build_regex(input: &str, tag: Tag) -> Result<String, ShellError> {
@@ -0,0 +1,97 @@
+use nu_errors::ShellError;
+use nu_source::{Tag, Tagged};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Where the plugins cargo installs end up, which is on the plugin search path
+pub fn plugins_bin_path() -> Result<PathBuf, ShellError> {
+    Ok(nu_data::config::managed_plugins_path()?.join("bin"))
+}
+
+/// Nu only loads plugins whose binaries are named `nu_plugin_*`, which their crates are too
+pub fn check_plugin_name(name: &Tagged<String>) -> Result<(), ShellError> {
+    if name.item.starts_with("nu_plugin_") {
+        Ok(())
+    } else {
+        Err(ShellError::labeled_error(
+            "Not the name of a plugin",
+            format!(
+                "plugin crates are named nu_plugin_*, eg nu_plugin_{}",
+                name.item
+            ),
+            &name.tag,
+        ))
+    }
+}
+
+/// The cargo arguments for the registry to install from. The flag takes precedence over the
+/// plugin_registry of the config, which can be a registry name or the URL of an index.
+pub fn registry_args(registry: Option<String>) -> Vec<String> {
+    let registry = registry.or_else(|| {
+        nu_data::config::config(Tag::unknown())
+            .ok()?
+            .get("plugin_registry")?
+            .as_string()
+            .ok()
+    });
+
+    match registry {
+        Some(registry) if registry.contains("://") => vec!["--index".to_string(), registry],
+        Some(registry) => vec!["--registry".to_string(), registry],
+        None => vec![],
+    }
+}
+
+/// The arguments that make cargo work on the managed plugins directory
+pub fn root_args() -> Result<Vec<String>, ShellError> {
+    let root = nu_data::config::managed_plugins_path()?;
+
+    Ok(vec![
+        "--root".to_string(),
+        root.to_string_lossy().to_string(),
+    ])
+}
+
+/// Runs cargo with its output going straight to the terminal, to show its progress
+pub fn run(args: &[String], tag: &Tag) -> Result<(), ShellError> {
+    let status = Command::new("cargo").args(args).status().map_err(|e| {
+        ShellError::labeled_error(
+            "Could not run cargo",
+            format!("cargo is needed to manage plugins ({})", e),
+            tag,
+        )
+    })?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(ShellError::labeled_error(
+            "Cargo failed",
+            "cargo failed for this plugin",
+            tag,
+        ))
+    }
+}
+
+/// The crates installed in the managed plugins directory
+pub fn installed_plugins(tag: &Tag) -> Result<Vec<String>, ShellError> {
+    let mut args = vec!["install".to_string(), "--list".to_string()];
+    args.extend(root_args()?);
+
+    let output = Command::new("cargo").args(&args).output().map_err(|e| {
+        ShellError::labeled_error(
+            "Could not run cargo",
+            format!("cargo is needed to manage plugins ({})", e),
+            tag,
+        )
+    })?;
+
+    // Each crate is listed as `name version:`, followed by its binaries indented below it
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.starts_with(char::is_whitespace))
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|name| name.starts_with("nu_plugin_"))
+        .map(String::from)
+        .collect())
+}
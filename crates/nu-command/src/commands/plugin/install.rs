@@ -0,0 +1,106 @@
+use super::cargo;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    name: Tagged<String>,
+    version: Option<Tagged<String>>,
+    registry: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "plugin install"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("plugin install")
+            .required("name", SyntaxShape::String, "the crate of the plugin")
+            .named(
+                "version",
+                SyntaxShape::String,
+                "the version to install (default the latest)",
+                Some('v'),
+            )
+            .named(
+                "registry",
+                SyntaxShape::String,
+                "the registry name or index URL to install from (default plugin_registry of the config, then crates.io)",
+                Some('r'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Install a plugin with cargo and load it."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The plugin is built and put in a directory Nu manages, which is searched for plugins at startup, and its commands can be used straight away."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        install(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Install the plugin for the `s3` command",
+                example: "plugin install nu_plugin_s3",
+                result: None,
+            },
+            Example {
+                description: "Install a version of a plugin from a company registry",
+                example: "plugin install nu_plugin_deploy --version 1.2.0 --registry internal",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn install(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (
+        Arguments {
+            name,
+            version,
+            registry,
+        },
+        _,
+    ) = args.process().await?;
+
+    cargo::check_plugin_name(&name)?;
+
+    let mut cargo_args = vec!["install".to_string(), name.item.clone()];
+    if let Some(version) = version {
+        cargo_args.push("--version".to_string());
+        cargo_args.push(version.item);
+    }
+    cargo_args.extend(cargo::registry_args(registry.map(|r| r.item)));
+    cargo_args.extend(cargo::root_args()?);
+
+    cargo::run(&cargo_args, &name.tag)?;
+
+    Ok(OutputStream::one(ReturnSuccess::action(
+        CommandAction::AddPlugins(cargo::plugins_bin_path()?.to_string_lossy().to_string()),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,92 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "plugin list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("plugin list")
+    }
+
+    fn usage(&self) -> &str {
+        "List the commands that come from plugins, with the version and path of each plugin."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Plugins are looked for in the plugin_dirs of the config, in the order they're listed, then in the directory `plugin install` puts them in, and then in the directory nu is in. When more than one plugin provides the same command, the first one found is used and a warning is shown at startup. Built-in commands always take precedence over plugins."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        list(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the loaded plugins",
+            example: "plugin list",
+            result: None,
+        }]
+    }
+}
+
+async fn list(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let plugins = plugin_rows(&args.scope, &tag)
+        .into_iter()
+        .map(ReturnSuccess::value)
+        .collect::<Vec<_>>();
+
+    Ok(futures::stream::iter(plugins).to_output_stream())
+}
+
+/// A row for each command that comes from a plugin
+pub(crate) fn plugin_rows(scope: &Scope, tag: &Tag) -> Vec<Value> {
+    scope
+        .get_command_names()
+        .into_iter()
+        .filter_map(|name| scope.get_command(&name))
+        .filter_map(|command| {
+            let path = command.plugin_path()?.to_string();
+            let signature = command.signature();
+            let kind = if signature.is_filter {
+                "filter"
+            } else {
+                "sink"
+            };
+
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("name", UntaggedValue::string(command.name()));
+            row.insert_untagged(
+                "version",
+                match signature.version {
+                    Some(version) => UntaggedValue::string(version),
+                    None => UntaggedValue::nothing(),
+                },
+            );
+            row.insert_untagged("kind", UntaggedValue::string(kind));
+            row.insert_untagged("path", UntaggedValue::string(path));
+
+            Some(row.into_value())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,13 @@
+pub mod command;
+pub mod install;
+pub mod list;
+pub mod uninstall;
+pub mod update;
+
+mod cargo;
+
+pub use command::Command as Plugin;
+pub use install::SubCommand as PluginInstall;
+pub use list::SubCommand as PluginList;
+pub use uninstall::SubCommand as PluginUninstall;
+pub use update::SubCommand as PluginUpdate;
@@ -0,0 +1,89 @@
+use super::cargo;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::Path;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    name: Tagged<String>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "plugin uninstall"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("plugin uninstall").required(
+            "name",
+            SyntaxShape::String,
+            "the crate of the plugin",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Uninstall a plugin installed with `plugin install` and unload its commands."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        uninstall(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Uninstall a plugin",
+            example: "plugin uninstall nu_plugin_s3",
+            result: None,
+        }]
+    }
+}
+
+async fn uninstall(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let scope = args.scope.clone();
+    let (Arguments { name }, _) = args.process().await?;
+
+    cargo::check_plugin_name(&name)?;
+
+    let mut cargo_args = vec!["uninstall".to_string(), name.item.clone()];
+    cargo_args.extend(cargo::root_args()?);
+
+    cargo::run(&cargo_args, &name.tag)?;
+
+    // The commands of the plugins that were removed can't run anymore. Plugins are loaded with
+    // their canonical paths, so the directory they're in is compared the same way.
+    let bin_path = cargo::plugins_bin_path()?;
+    let bin_path = dunce::canonicalize(&bin_path).unwrap_or(bin_path);
+    for command_name in scope.get_command_names() {
+        let removed = scope.get_command(&command_name).map_or(false, |command| {
+            command.plugin_path().map_or(false, |path| {
+                let path = Path::new(path);
+                path.starts_with(&bin_path) && !path.exists()
+            })
+        });
+
+        if removed {
+            scope.remove_command(&command_name);
+        }
+    }
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,102 @@
+use super::cargo;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    name: Option<Tagged<String>>,
+    registry: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "plugin update"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("plugin update")
+            .optional(
+                "name",
+                SyntaxShape::String,
+                "the crate of the plugin (default every plugin installed with `plugin install`)",
+            )
+            .named(
+                "registry",
+                SyntaxShape::String,
+                "the registry name or index URL to update from (default plugin_registry of the config, then crates.io)",
+                Some('r'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Update plugins installed with `plugin install` to their latest version."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        update(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Update a plugin",
+                example: "plugin update nu_plugin_s3",
+                result: None,
+            },
+            Example {
+                description: "Update every installed plugin",
+                example: "plugin update",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn update(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let (Arguments { name, registry }, _) = args.process().await?;
+
+    let plugins = match name {
+        Some(name) => {
+            cargo::check_plugin_name(&name)?;
+            vec![name]
+        }
+        None => cargo::installed_plugins(&tag)?
+            .into_iter()
+            .map(|name| name.tagged(&tag))
+            .collect(),
+    };
+
+    let registry_args = cargo::registry_args(registry.map(|r| r.item));
+    for plugin in plugins {
+        let mut cargo_args = vec!["install".to_string(), "--force".to_string(), plugin.item];
+        cargo_args.extend(registry_args.iter().cloned());
+        cargo_args.extend(cargo::root_args()?);
+
+        cargo::run(&cargo_args, &plugin.tag)?;
+    }
+
+    // The plugins are loaded again from the same paths, which picks up any changes to them
+    Ok(OutputStream::one(ReturnSuccess::action(
+        CommandAction::AddPlugins(cargo::plugins_bin_path()?.to_string_lossy().to_string()),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+use nu_engine::shell::CdArgs;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::Signature;
+use std::path::PathBuf;
+
+pub struct Popd;
+
+#[async_trait]
+impl WholeStreamCommand for Popd {
+    fn name(&self) -> &str {
+        "popd"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("popd")
+    }
+
+    fn usage(&self) -> &str {
+        "Change to the path most recently saved with pushd and take it off the directory stack."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let name = args.call_info.name_tag.clone();
+        let shell_manager = args.shell_manager.clone();
+
+        let target = shell_manager.dirs().get(1).cloned().ok_or_else(|| {
+            ShellError::labeled_error(
+                "The directory stack is empty",
+                "no saved directory to change to",
+                &name,
+            )
+        })?;
+
+        let stream = shell_manager.cd(
+            CdArgs {
+                path: Some(PathBuf::from(target).tagged(&name)),
+            },
+            name,
+        )?;
+        shell_manager.pop_dir();
+
+        Ok(stream)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Go back to where pushd was run",
+            example: "pushd dirname; popd",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Popd;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Popd {})
+    }
+}
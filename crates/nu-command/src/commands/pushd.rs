@@ -0,0 +1,95 @@
+use crate::prelude::*;
+use nu_engine::shell::CdArgs;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use std::path::PathBuf;
+
+pub struct Pushd;
+
+#[async_trait]
+impl WholeStreamCommand for Pushd {
+    fn name(&self) -> &str {
+        "pushd"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pushd").optional(
+            "directory",
+            SyntaxShape::FilePath,
+            "the directory to change to",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Save the current path on the directory stack and change to a new path."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Without a directory, the current path and the most recently saved one swap places."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        pushd(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Change to a directory, saving the current path to come back to",
+                example: "pushd dirname",
+                result: None,
+            },
+            Example {
+                description: "Swap the current path with the most recently saved one",
+                example: "pushd",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn pushd(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let shell_manager = args.shell_manager.clone();
+    let (args, _): (CdArgs, _) = args.process().await?;
+
+    if args.path.is_none() {
+        let target = shell_manager.dirs().get(1).cloned().ok_or_else(|| {
+            ShellError::labeled_error(
+                "The directory stack is empty",
+                "no saved directory to swap with",
+                &name,
+            )
+        })?;
+
+        let stream = shell_manager.cd(
+            CdArgs {
+                path: Some(PathBuf::from(target).tagged(&name)),
+            },
+            name,
+        )?;
+        shell_manager.pivot_dirs(1);
+
+        return Ok(stream);
+    }
+
+    // The directory is checked before anything is saved
+    let stream = shell_manager.cd(args, name)?;
+    shell_manager.push_dir();
+
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pushd;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Pushd {})
+    }
+}
@@ -0,0 +1,164 @@
+use crate::commands::into_sqlite::{
+    create_table, quote_identifier, sqlite_value, table_columns, DEFAULT_TABLE_NAME,
+};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+use rusqlite::{types::ValueRef, Connection, NO_PARAMS};
+
+pub struct QuerySql;
+
+#[derive(Deserialize)]
+pub struct QuerySqlArgs {
+    query: Tagged<String>,
+    #[serde(rename = "table-name")]
+    table_name: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for QuerySql {
+    fn name(&self) -> &str {
+        "query sql"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("query sql")
+            .required("query", SyntaxShape::String, "the SQL to run")
+            .named(
+                "table-name",
+                SyntaxShape::String,
+                "the name the input table has in the query (defaults to 'data')",
+                Some('t'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run an SQL query over the table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The table is loaded into an in-memory SQLite database, the same way into sqlite writes it, so
+booleans and durations come back as integers, dates as text, and nested tables as JSON text.
+The rows the query returns are given as a table with the query's column names."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        query_sql(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Keep the names of the well-starred projects",
+                example: r#"echo [[name stars]; [nu 5] [jq 3]] | query sql "select name from data where stars > 4""#,
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "name".to_string() => UntaggedValue::string("nu").into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Total the size of the files of each type",
+                example: r#"ls | query sql "select type, sum(size) as total from data group by type""#,
+                result: None,
+            },
+            Example {
+                description: "Name the table, to join it with itself",
+                example: r#"open people.csv | query sql -t people "select a.name, b.name as manager from people a join people b on a.manager_id = b.id""#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn nu_value(value: ValueRef, tag: &Tag) -> Value {
+    match value {
+        ValueRef::Null => UntaggedValue::nothing().into_value(tag),
+        ValueRef::Integer(i) => UntaggedValue::int(i).into_value(tag),
+        ValueRef::Real(f) => UntaggedValue::decimal_from_float(f, tag.span).into_value(tag),
+        ValueRef::Text(s) => {
+            UntaggedValue::string(String::from_utf8_lossy(s).to_string()).into_value(tag)
+        }
+        ValueRef::Blob(bytes) => UntaggedValue::binary(bytes.to_owned()).into_value(tag),
+    }
+}
+
+async fn query_sql(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (QuerySqlArgs { query, table_name }, input) = args.process().await?;
+    let rows: Vec<Value> = input.collect().await;
+
+    let table_name = table_name
+        .map(|name| name.item)
+        .unwrap_or_else(|| DEFAULT_TABLE_NAME.to_string());
+
+    let sqlite_error = |e: rusqlite::Error| {
+        ShellError::labeled_error("Could not run the query", e.to_string(), &query.tag)
+    };
+
+    let conn = Connection::open_in_memory().map_err(sqlite_error)?;
+
+    // Without input there's no table, but queries that don't need one still run
+    let columns = table_columns(&rows, &name_tag)?;
+    if !columns.is_empty() {
+        conn.execute_batch(&create_table(&table_name, &columns))
+            .map_err(sqlite_error)?;
+
+        let column_names: Vec<String> = columns.keys().map(|name| quote_identifier(name)).collect();
+        let mut insert = conn
+            .prepare(&format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                quote_identifier(&table_name),
+                column_names.join(", "),
+                vec!["?"; columns.len()].join(", ")
+            ))
+            .map_err(sqlite_error)?;
+
+        for row in rows.iter() {
+            if let UntaggedValue::Row(dict) = &row.value {
+                let values = columns
+                    .keys()
+                    .map(|name| sqlite_value(dict.entries.get(name)))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                insert.execute(&values).map_err(sqlite_error)?;
+            }
+        }
+    }
+
+    let mut statement = conn.prepare(&query.item).map_err(sqlite_error)?;
+    let names: Vec<String> = statement
+        .column_names()
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let mut output = vec![];
+    let mut results = statement.query(NO_PARAMS).map_err(sqlite_error)?;
+    while let Some(result) = results.next().map_err(sqlite_error)? {
+        let mut row = TaggedDictBuilder::new(&name_tag);
+
+        for (index, name) in names.iter().enumerate() {
+            let value = result.get_raw_checked(index).map_err(sqlite_error)?;
+            row.insert_value(name, nu_value(value, &name_tag));
+        }
+
+        output.push(row.into_value());
+    }
+
+    Ok(futures::stream::iter(output.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuerySql;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(QuerySql {})
+    }
+}
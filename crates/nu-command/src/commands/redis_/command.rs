@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Redis;
+
+#[async_trait]
+impl WholeStreamCommand for Redis {
+    fn name(&self) -> &str {
+        "redis"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("redis")
+    }
+
+    fn usage(&self) -> &str {
+        "Read and write keys on a Redis server."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The server is given with --url, or else taken from $nu.env.REDIS_URL, then from 'redis_url'
+in the config, and finally defaults to redis://127.0.0.1/."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(get_full_help(&Redis, &args.scope)).into_value(Tag::unknown()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Redis;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Redis {})
+    }
+}
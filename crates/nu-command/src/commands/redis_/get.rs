@@ -0,0 +1,168 @@
+use super::{bytes_to_value, connect, pairs_to_record, redis_error, redis_url};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct RedisGet;
+
+#[derive(Deserialize)]
+pub struct RedisGetArgs {
+    key: Tagged<String>,
+    url: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for RedisGet {
+    fn name(&self) -> &str {
+        "redis get"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("redis get")
+            .required("key", SyntaxShape::String, "the key to read")
+            .named(
+                "url",
+                SyntaxShape::String,
+                "the server to connect to, eg redis://127.0.0.1:6379/0",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Read a key from Redis, whatever type it holds."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Strings are returned as they are, hashes as records, lists and sets as lists, and sorted
+sets as a table of members and scores. A missing key gives nothing."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        redis_get(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Read a cached session",
+                example: "redis get session:42",
+                result: None,
+            },
+            Example {
+                description: "Read a hash from another server and pick a field",
+                example: "redis get user:7 --url redis://cache.internal:6379/1 | get email",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn redis_get(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let env = args.scope.get_env_vars();
+    let (RedisGetArgs { key, url }, _) = args.process().await?;
+
+    let url = redis_url(url, &env);
+    let mut connection = connect(&url)?;
+    let error = |e| redis_error(e, &name_tag);
+
+    let kind: String = redis::cmd("TYPE")
+        .arg(&key.item)
+        .query(&mut connection)
+        .map_err(error)?;
+
+    let value = match kind.as_str() {
+        "none" => UntaggedValue::nothing().into_value(&name_tag),
+        "string" => {
+            let bytes: Vec<u8> = redis::cmd("GET")
+                .arg(&key.item)
+                .query(&mut connection)
+                .map_err(error)?;
+
+            bytes_to_value(bytes).into_value(&name_tag)
+        }
+        "hash" => {
+            let pairs: Vec<Vec<u8>> = redis::cmd("HGETALL")
+                .arg(&key.item)
+                .query(&mut connection)
+                .map_err(error)?;
+
+            pairs_to_record(pairs, &name_tag)
+        }
+        "list" => members_to_table(
+            redis::cmd("LRANGE")
+                .arg(&key.item)
+                .arg(0)
+                .arg(-1)
+                .query(&mut connection)
+                .map_err(error)?,
+            &name_tag,
+        ),
+        "set" => members_to_table(
+            redis::cmd("SMEMBERS")
+                .arg(&key.item)
+                .query(&mut connection)
+                .map_err(error)?,
+            &name_tag,
+        ),
+        "zset" => {
+            let members: Vec<(Vec<u8>, f64)> = redis::cmd("ZRANGE")
+                .arg(&key.item)
+                .arg(0)
+                .arg(-1)
+                .arg("WITHSCORES")
+                .query(&mut connection)
+                .map_err(error)?;
+
+            UntaggedValue::Table(
+                members
+                    .into_iter()
+                    .map(|(member, score)| {
+                        let mut dict = TaggedDictBuilder::new(&name_tag);
+                        dict.insert_untagged("member", bytes_to_value(member));
+                        dict.insert_untagged(
+                            "score",
+                            UntaggedValue::decimal_from_float(score, name_tag.span),
+                        );
+                        dict.into_value()
+                    })
+                    .collect(),
+            )
+            .into_value(&name_tag)
+        }
+        other => {
+            return Err(ShellError::labeled_error(
+                "Unsupported Redis type",
+                format!("'{}' holds a {}, which can't be read yet", key.item, other),
+                &key.tag,
+            ))
+        }
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(value)))
+}
+
+fn members_to_table(members: Vec<Vec<u8>>, tag: &Tag) -> Value {
+    UntaggedValue::Table(
+        members
+            .into_iter()
+            .map(|member| bytes_to_value(member).into_value(tag))
+            .collect(),
+    )
+    .into_value(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedisGet;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(RedisGet {})
+    }
+}
@@ -0,0 +1,71 @@
+mod command;
+mod get;
+mod scan;
+mod set;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub use command::Redis;
+pub use get::RedisGet;
+pub use scan::RedisScan;
+pub use set::RedisSet;
+
+const DEFAULT_URL: &str = "redis://127.0.0.1/";
+
+/// The server to talk to: the `--url` flag, then `$nu.env.REDIS_URL`, then `redis_url` in the
+/// config, then a local server
+fn redis_url(url: Option<Tagged<String>>, env: &IndexMap<String, String>) -> Tagged<String> {
+    if let Some(url) = url {
+        return url;
+    }
+
+    if let Some(url) = env.get("REDIS_URL") {
+        return url.clone().tagged_unknown();
+    }
+
+    if let Ok(config) = config::config(Tag::unknown()) {
+        if let Some(url) = config.get("redis_url") {
+            if let Ok(string) = url.as_string() {
+                return string.tagged(&url.tag);
+            }
+        }
+    }
+
+    DEFAULT_URL.to_string().tagged_unknown()
+}
+
+fn redis_error(error: redis::RedisError, tag: impl Into<Tag>) -> ShellError {
+    ShellError::labeled_error("Redis error", error.to_string(), tag)
+}
+
+fn connect(url: &Tagged<String>) -> Result<redis::Connection, ShellError> {
+    redis::Client::open(url.item.as_str())
+        .and_then(|client| client.get_connection())
+        .map_err(|e| redis_error(e, &url.tag))
+}
+
+/// Replies are binary safe; anything that isn't valid UTF-8 is kept as binary
+fn bytes_to_value(bytes: Vec<u8>) -> UntaggedValue {
+    match String::from_utf8(bytes) {
+        Ok(string) => UntaggedValue::string(string),
+        Err(e) => UntaggedValue::binary(e.into_bytes()),
+    }
+}
+
+/// Turns a flat `[field, value, field, value..]` reply (HGETALL) into a record
+fn pairs_to_record(reply: Vec<Vec<u8>>, tag: &Tag) -> Value {
+    let mut dict = TaggedDictBuilder::new(tag);
+
+    let mut items = reply.into_iter();
+    while let (Some(field), Some(value)) = (items.next(), items.next()) {
+        dict.insert_untagged(
+            String::from_utf8_lossy(&field).to_string(),
+            bytes_to_value(value),
+        );
+    }
+
+    dict.into_value()
+}
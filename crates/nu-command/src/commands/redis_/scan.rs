@@ -0,0 +1,140 @@
+use super::{bytes_to_value, connect, redis_error, redis_url};
+use crate::futures::ThreadedReceiver;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::sync::mpsc;
+
+pub struct RedisScan;
+
+#[derive(Deserialize)]
+pub struct RedisScanArgs {
+    pattern: Option<Tagged<String>>,
+    count: Option<Tagged<u64>>,
+    url: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for RedisScan {
+    fn name(&self) -> &str {
+        "redis scan"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("redis scan")
+            .optional(
+                "pattern",
+                SyntaxShape::String,
+                "only list keys matching this glob, eg 'session:*'",
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "how many keys to ask the server for at a time",
+                Some('c'),
+            )
+            .named(
+                "url",
+                SyntaxShape::String,
+                "the server to connect to, eg redis://127.0.0.1:6379/0",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "List the keys on a Redis server."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Keys are listed with SCAN rather than KEYS, so the server isn't blocked, and they are
+streamed as they arrive. Like SCAN itself, a key may be listed more than once."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        redis_scan(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List every key",
+                example: "redis scan",
+                result: None,
+            },
+            Example {
+                description: "Count the sessions, asking for a thousand keys at a time",
+                example: "redis scan 'session:*' --count 1000 | count",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn redis_scan(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let env = args.scope.get_env_vars();
+    let (
+        RedisScanArgs {
+            pattern,
+            count,
+            url,
+        },
+        _,
+    ) = args.process().await?;
+
+    let url = redis_url(url, &env);
+    let mut connection = connect(&url)?;
+
+    // SCAN blocks while it waits on the server, so the keys are read on their own thread and
+    // handed over as the pipeline asks for them
+    let (tx, rx) = mpsc::sync_channel(0);
+
+    std::thread::spawn(move || {
+        let mut command = redis::cmd("SCAN");
+        command.cursor_arg(0);
+
+        if let Some(pattern) = &pattern {
+            command.arg("MATCH").arg(&pattern.item);
+        }
+        if let Some(count) = &count {
+            command.arg("COUNT").arg(count.item);
+        }
+
+        let keys = match command.iter::<Vec<u8>>(&mut connection) {
+            Ok(keys) => keys,
+            Err(e) => {
+                let _ = tx.send(Err(redis_error(e, &name_tag)));
+                return;
+            }
+        };
+
+        for key in keys {
+            if tx
+                .send(Ok(bytes_to_value(key).into_value(&name_tag)))
+                .is_err()
+            {
+                // Nobody is reading the rest of the keys anymore
+                break;
+            }
+        }
+    });
+
+    Ok(ThreadedReceiver::new(rx)
+        .map(|key: Result<Value, ShellError>| key.map(ReturnSuccess::Value))
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedisScan;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(RedisScan {})
+    }
+}
@@ -0,0 +1,183 @@
+use super::{connect, redis_error, redis_url};
+use crate::prelude::*;
+use crate::utils::json::value_to_json_value;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct RedisSet;
+
+#[derive(Deserialize)]
+pub struct RedisSetArgs {
+    key: Tagged<String>,
+    value: Option<Value>,
+    ttl: Option<Tagged<u64>>,
+    url: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for RedisSet {
+    fn name(&self) -> &str {
+        "redis set"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("redis set")
+            .required("key", SyntaxShape::String, "the key to write")
+            .optional(
+                "value",
+                SyntaxShape::Any,
+                "the value to store (defaults to the pipeline input)",
+            )
+            .named(
+                "ttl",
+                SyntaxShape::Unit,
+                "expire the key after this long",
+                None,
+            )
+            .named(
+                "url",
+                SyntaxShape::String,
+                "the server to connect to, eg redis://127.0.0.1:6379/0",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Write a key to Redis."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Records are stored as hashes and lists as lists, replacing whatever the key held before.
+Anything else is stored as a string. Nested values are written as JSON."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        redis_set(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Store a string that expires after an hour",
+                example: "redis set maintenance on --ttl 1hr",
+                result: None,
+            },
+            Example {
+                description: "Store a record as a hash",
+                example:
+                    "echo [[name email]; [andres andres@example.com]] | first | redis set user:7",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn redis_set(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let env = args.scope.get_env_vars();
+    let (
+        RedisSetArgs {
+            key,
+            value,
+            ttl,
+            url,
+        },
+        input,
+    ) = args.process().await?;
+
+    let value = match value {
+        Some(value) => value,
+        None => {
+            let mut values: Vec<Value> = input.collect().await;
+            match values.len() {
+                0 => {
+                    return Err(ShellError::labeled_error(
+                        "No value to store",
+                        "give a value or pipe one in",
+                        &name_tag,
+                    ))
+                }
+                1 => values.remove(0),
+                _ => UntaggedValue::Table(values).into_value(&name_tag),
+            }
+        }
+    };
+
+    let mut pipeline = redis::pipe();
+    pipeline.atomic();
+
+    match &value.value {
+        UntaggedValue::Row(dict) => {
+            pipeline.cmd("DEL").arg(&key.item).ignore();
+
+            if !dict.entries.is_empty() {
+                let command = pipeline.cmd("HSET").arg(&key.item);
+                for (field, value) in dict.entries.iter() {
+                    command.arg(field).arg(to_bytes(value)?);
+                }
+                command.ignore();
+            }
+        }
+        UntaggedValue::Table(items) => {
+            pipeline.cmd("DEL").arg(&key.item).ignore();
+
+            if !items.is_empty() {
+                let command = pipeline.cmd("RPUSH").arg(&key.item);
+                for item in items {
+                    command.arg(to_bytes(item)?);
+                }
+                command.ignore();
+            }
+        }
+        _ => {
+            pipeline
+                .cmd("SET")
+                .arg(&key.item)
+                .arg(to_bytes(&value)?)
+                .ignore();
+        }
+    }
+
+    if let Some(ttl) = ttl {
+        let millis = ttl.item / 1_000_000;
+        pipeline.cmd("PEXPIRE").arg(&key.item).arg(millis).ignore();
+    }
+
+    let url = redis_url(url, &env);
+    let mut connection = connect(&url)?;
+
+    pipeline
+        .query::<()>(&mut connection)
+        .map_err(|e| redis_error(e, &name_tag))?;
+
+    Ok(OutputStream::empty())
+}
+
+fn to_bytes(value: &Value) -> Result<Vec<u8>, ShellError> {
+    Ok(match &value.value {
+        UntaggedValue::Primitive(Primitive::Binary(bytes)) => bytes.clone(),
+        UntaggedValue::Primitive(Primitive::String(s)) => s.clone().into_bytes(),
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => {
+            let json = value_to_json_value(value)?;
+            serde_json::to_vec(&json).map_err(|e| {
+                ShellError::labeled_error("Could not convert to JSON", e.to_string(), &value.tag)
+            })?
+        }
+        _ => value.convert_to_string().into_bytes(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedisSet;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(RedisSet {})
+    }
+}
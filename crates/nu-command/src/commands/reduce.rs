@@ -60,6 +60,11 @@ impl WholeStreamCommand for Reduce {
                 example: "echo 1 2 3 4 | reduce { = $acc + $it }",
                 result: Some(vec![UntaggedValue::int(10).into()]),
             },
+            Example {
+                description: "Summation using named block parameters",
+                example: "echo 1 2 3 4 | reduce {|num, sum| = $sum + $num }",
+                result: Some(vec![UntaggedValue::int(10).into()]),
+            },
             Example {
                 description: "Summation from starting value using fold",
                 example: "echo 1 2 3 4 | reduce -f $(= -1) { = $acc + $it }",
@@ -83,13 +88,25 @@ async fn process_row(
     block: Arc<CapturedBlock>,
     context: &EvaluationContext,
     row: Value,
+    acc: Value,
 ) -> Result<InputStream, ShellError> {
     let row_clone = row.clone();
     let input_stream = once(async { Ok(row_clone) }).to_input_stream();
 
     context.scope.enter_scope();
     context.scope.add_vars(&block.captured.entries);
-    context.scope.add_var("$it", row);
+
+    context.scope.add_var("$it", row.clone());
+    context.scope.add_var("$acc", acc.clone());
+
+    // Explicit block parameters, eg `{|item, sum| ... }`, take the item and the accumulator
+    let mut params = block.block.params.positional.iter();
+    if let Some((param, _)) = params.next() {
+        context.scope.add_var(param.name(), row);
+    }
+    if let Some((param, _)) = params.next() {
+        context.scope.add_var(param.name(), acc);
+    }
     let result = run_block(&block.block, context, input_stream).await;
     context.scope.exit_scope();
 
@@ -146,12 +163,7 @@ async fn reduce(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
                         UntaggedValue::table(&values).into_untagged_value()
                     };
 
-                    context.scope.enter_scope();
-                    context.scope.add_var("$acc", f);
-                    let result = process_row(block, &*context, row).await;
-                    context.scope.exit_scope();
-
-                    result
+                    process_row(block, &*context, row, f).await
                 }
             })
             .await?
@@ -177,11 +189,7 @@ async fn reduce(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
                         UntaggedValue::table(&values).into_untagged_value()
                     };
 
-                    context.scope.enter_scope();
-                    context.scope.add_var("$acc", f);
-                    let result = process_row(block, &*context, row).await;
-                    context.scope.exit_scope();
-                    result
+                    process_row(block, &*context, row, f).await
                 }
             })
             .await?
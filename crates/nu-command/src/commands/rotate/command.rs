@@ -8,11 +8,14 @@ use nu_protocol::{
 use nu_source::{SpannedItem, Tagged};
 use nu_value_ext::ValueExt;
 
+use super::counter_clockwise::counter_clockwise;
+
 pub struct Command;
 
 #[derive(Deserialize)]
 pub struct Arguments {
     rest: Vec<Tagged<String>>,
+    ccw: bool,
 }
 
 #[async_trait]
@@ -22,10 +25,16 @@ impl WholeStreamCommand for Command {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("rotate").rest(
-            SyntaxShape::String,
-            "the names to give columns once rotated",
-        )
+        Signature::build("rotate")
+            .switch(
+                "ccw",
+                "rotate counter clockwise, like rotate counter-clockwise",
+                None,
+            )
+            .rest(
+                SyntaxShape::String,
+                "the names to give columns once rotated",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -35,11 +44,30 @@ impl WholeStreamCommand for Command {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         rotate(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the wide row of the host information as a column",
+                example: "sys | get host | rotate",
+                result: None,
+            },
+            Example {
+                description: "Rotate the other way, naming the columns",
+                example: "sys | get host | rotate --ccw field value",
+                result: None,
+            },
+        ]
+    }
 }
 
 pub async fn rotate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
-    let (Arguments { rest }, input) = args.process().await?;
+    let (Arguments { rest, ccw }, input) = args.process().await?;
+
+    if ccw {
+        return counter_clockwise(rest, input, name).await;
+    }
 
     let input = input.into_vec().await;
     let total_rows = input.len();
@@ -119,3 +147,16 @@ pub async fn rotate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     )
     .to_output_stream())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Command {})
+    }
+}
@@ -35,12 +35,28 @@ impl WholeStreamCommand for SubCommand {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         rotate(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Rotate a table so its columns read from the top",
+            example: "ls | rotate counter-clockwise",
+            result: None,
+        }]
+    }
 }
 
 pub async fn rotate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
     let (Arguments { rest }, input) = args.process().await?;
 
+    counter_clockwise(rest, input, name).await
+}
+
+pub(crate) async fn counter_clockwise(
+    rest: Vec<Tagged<String>>,
+    input: InputStream,
+    name: Tag,
+) -> Result<OutputStream, ShellError> {
     let input = input.into_vec().await;
     let descs = merge_descriptors(&input);
     let total_rows = input.len();
@@ -115,3 +131,16 @@ pub async fn rotate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     )
     .to_output_stream())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
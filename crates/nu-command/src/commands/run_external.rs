@@ -85,6 +85,7 @@ impl WholeStreamCommand for RunExternalCommand {
                 host: args.host.clone(),
                 user_recently_used_autoenv_untrust: Arc::new(AtomicBool::new(false)),
                 shell_manager: args.shell_manager.clone(),
+                jobs: args.jobs.clone(),
                 ctrl_c: args.ctrl_c.clone(),
                 current_errors: Arc::new(Mutex::new(vec![])),
                 windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
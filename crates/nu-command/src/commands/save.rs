@@ -6,20 +6,20 @@ use nu_protocol::{
     Value,
 };
 use nu_source::Tagged;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 
 pub struct Save;
 
 macro_rules! process_unknown {
     ($scope:tt, $input:ident, $name_tag:ident) => {{
-        if $input.len() > 0 {
-            match $input[0] {
-                Value {
-                    value: UntaggedValue::Primitive(Primitive::Binary(_)),
-                    ..
-                } => process_binary!($scope, $input, $name_tag),
-                _ => process_string!($scope, $input, $name_tag),
-            }
+        // The output of an external can start out as text before it turns out to be binary
+        if $input
+            .iter()
+            .any(|value| matches!(value.value, UntaggedValue::Primitive(Primitive::Binary(_))))
+        {
+            process_binary!($scope, $input, $name_tag)
         } else {
             process_string!($scope, $input, $name_tag)
         }
@@ -63,6 +63,12 @@ macro_rules! process_binary {
                         result_binary.push(u);
                     }
                 }
+                Value {
+                    value: UntaggedValue::Primitive(Primitive::String(s)),
+                    ..
+                } => {
+                    result_binary.extend(s.into_bytes());
+                }
                 _ => {
                     break $scope Err(ShellError::labeled_error(
                         "Save could not successfully save",
@@ -169,6 +175,7 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     let host = raw_args.host.clone();
     let ctrl_c = raw_args.ctrl_c.clone();
     let current_errors = raw_args.current_errors.clone();
+    let jobs = raw_args.jobs.clone();
     let shell_manager = raw_args.shell_manager.clone();
 
     let head = raw_args.call_info.args.head.clone();
@@ -177,15 +184,15 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
             path,
             raw: save_raw,
         },
-        input,
+        mut input,
     ) = raw_args.process().await?;
-    let input: Vec<Value> = input.collect().await;
+    let first = input.next().await;
     if path.is_none() {
         let mut should_return_file_path_error = true;
 
         // If there is no filename, check the metadata for the anchor filename
-        if !input.is_empty() {
-            let anchor = input[0].tag.anchor();
+        if let Some(first) = &first {
+            let anchor = first.tag.anchor();
 
             if let Some(AnchorLocation::File(file)) = anchor {
                 should_return_file_path_error = false;
@@ -204,19 +211,43 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
         full_path.push(file.item());
     }
 
+    let converter = full_path
+        .extension()
+        .map(|extension| format!("to {}", extension.to_string_lossy()))
+        .filter(|command_name| scope.has_command(command_name));
+
+    // Text and binary data with nothing to convert it is written to the file as it comes, so
+    // saving the output of an external doesn't hold all of it in memory
+    let streamable = matches!(
+        first.as_ref().map(|first| &first.value),
+        Some(UntaggedValue::Primitive(Primitive::String(_)))
+            | Some(UntaggedValue::Primitive(Primitive::Binary(_)))
+    );
+    if !save_raw && converter.is_none() && streamable && shell_manager.is_filesystem() {
+        let input = futures::stream::iter(first).chain(input);
+        return write_stream(&full_path, input, &name_tag).await;
+    }
+
+    let input: Vec<Value> = futures::stream::iter(first).chain(input).collect().await;
+
     // TODO use label_break_value once it is stable:
     // https://github.com/rust-lang/rust/issues/48594
     #[allow(clippy::never_loop)]
     let content: Result<Vec<u8>, ShellError> = 'scope: loop {
         break if !save_raw {
-            if let Some(extension) = full_path.extension() {
-                let command_name = format!("to {}", extension.to_string_lossy());
+            // A file with no converter for its extension is saved in the format the input was
+            // loaded from, eg JSON for data fetched from an API
+            let command_name =
+                converter.or_else(|| content_format(&input).map(|format| format!("to {}", format)));
+
+            if let Some(command_name) = command_name {
                 if let Some(converter) = scope.get_command(&command_name) {
                     let new_args = RawCommandArgs {
                         host,
                         ctrl_c,
                         current_errors,
                         shell_manager: shell_manager.clone(),
+                        jobs,
                         call_info: UnevaluatedCallInfo {
                             args: nu_protocol::hir::Call {
                                 head,
@@ -251,6 +282,58 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     shell_manager.save(&full_path, &content?, name.span)
 }
 
+/// Writes the text and binary data to the file as it arrives
+async fn write_stream(
+    full_path: &Path,
+    mut input: impl Stream<Item = Value> + Unpin,
+    name_tag: &Tag,
+) -> Result<OutputStream, ShellError> {
+    let io_error = |e: std::io::Error| {
+        ShellError::labeled_error(e.to_string(), "IO error while saving", name_tag)
+    };
+
+    let mut file = BufWriter::new(File::create(full_path).map_err(io_error)?);
+
+    while let Some(value) = input.next().await {
+        match value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => {
+                file.write_all(s.as_bytes()).map_err(io_error)?
+            }
+            UntaggedValue::Primitive(Primitive::Binary(b)) => {
+                file.write_all(&b).map_err(io_error)?
+            }
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Save could not successfully save",
+                    "unexpected data during binary save",
+                    name_tag,
+                ))
+            }
+        }
+    }
+
+    file.flush().map_err(io_error)?;
+
+    Ok(OutputStream::empty())
+}
+
+/// The format of the content type the input was loaded with. Text and binary data is left out,
+/// since it's saved as it is.
+fn content_format(input: &[Value]) -> Option<String> {
+    let first = input.first()?;
+
+    if matches!(
+        first.value,
+        UntaggedValue::Primitive(Primitive::String(_))
+            | UntaggedValue::Primitive(Primitive::Binary(_))
+    ) {
+        return None;
+    }
+
+    let content_type = first.tag.content_type.as_deref()?;
+    nu_data::content_type::format_for(content_type).map(String::from)
+}
+
 fn string_from(input: &[Value]) -> String {
     let mut save_data = String::new();
 
@@ -5,6 +5,7 @@ use nu_protocol::value::StrExt;
 use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 use std::cmp;
+use std::sync::atomic::Ordering;
 
 pub struct Seq;
 
@@ -91,6 +92,7 @@ impl WholeStreamCommand for Seq {
 
 async fn seq(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
+    let ctrl_c = args.ctrl_c.clone();
 
     let (
         SeqArgs {
@@ -158,7 +160,7 @@ async fn seq(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     let rest_nums: Vec<String> = rest_nums.iter().map(|n| n.item.to_string()).collect();
 
-    run_seq(sep, Some(term), widths.item, rest_nums)
+    run_seq(sep, Some(term), widths.item, rest_nums, ctrl_c)
 }
 
 #[cfg(test)]
@@ -196,6 +198,7 @@ pub fn run_seq(
     termy: Option<String>,
     widths: bool,
     free: Vec<String>,
+    ctrl_c: Arc<AtomicBool>,
 ) -> Result<OutputStream, ShellError> {
     let mut largest_dec = 0;
     let mut padding = 0;
@@ -268,6 +271,7 @@ pub fn run_seq(
         terminator,
         widths,
         padding,
+        ctrl_c,
     ))
 }
 
@@ -289,11 +293,17 @@ fn print_seq(
     terminator: String,
     pad: bool,
     padding: usize,
+    ctrl_c: Arc<AtomicBool>,
 ) -> OutputStream {
     let mut i = 0isize;
     let mut value = first + i as f64 * step;
     let mut ret_str = "".to_owned();
     while !done_printing(value, step, last) {
+        // The whole sequence is made before it's output, which can take a while
+        if ctrl_c.load(Ordering::SeqCst) {
+            return OutputStream::empty();
+        }
+
         let istr = format!("{:.*}", largest_dec, value);
         let ilen = istr.len();
         let before_dec = istr.find('.').unwrap_or(ilen);
@@ -0,0 +1,174 @@
+use crate::commands::get::get_column_path;
+use crate::prelude::*;
+use indexmap::{IndexMap, IndexSet};
+use nu_engine::run_block;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Signature, SyntaxShape, UntaggedValue, Value};
+
+#[derive(Deserialize)]
+pub struct SetOperationArgs {
+    other: Value,
+    rest: Vec<ColumnPath>,
+    all: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SetOperation {
+    Intersect,
+    Union,
+    Difference,
+}
+
+/// The arguments shared by `intersect`, `union` and `difference`
+pub fn set_operation_signature(name: &str) -> Signature {
+    Signature::build(name)
+        .required(
+            "other",
+            SyntaxShape::Any,
+            "the list or table to compare with, or a block whose output is used",
+        )
+        .rest(
+            SyntaxShape::ColumnPath,
+            "the columns that identify a row (defaults to the whole row)",
+        )
+        .switch(
+            "all",
+            "keep duplicates, counting each occurrence separately",
+            Some('a'),
+        )
+}
+
+pub async fn set_operation(
+    args: CommandArgs,
+    operation: SetOperation,
+) -> Result<OutputStream, ShellError> {
+    let context = EvaluationContext::from_args(&args);
+    let (
+        SetOperationArgs {
+            other,
+            rest: columns,
+            all,
+        },
+        input,
+    ) = args.process().await?;
+
+    let other = match other.value {
+        UntaggedValue::Block(block) => {
+            context.scope.enter_scope();
+            context.scope.add_vars(&block.captured.entries);
+            let result = run_block(&block.block, &context, InputStream::empty()).await;
+            context.scope.exit_scope();
+
+            result?.drain_vec().await
+        }
+        UntaggedValue::Table(values) => values,
+        _ => vec![other],
+    };
+    let input: Vec<Value> = input.collect().await;
+
+    let output = if all {
+        multiset_operation(operation, input, other, &columns)?
+    } else {
+        distinct_operation(operation, input, other, &columns)?
+    };
+
+    Ok(futures::stream::iter(output).to_output_stream())
+}
+
+/// Rows are compared by the values in the given columns, or by the whole row when no columns are
+/// given. Tags are ignored, so equal values from different places still match.
+fn key(value: &Value, columns: &[ColumnPath]) -> Result<UntaggedValue, ShellError> {
+    if columns.is_empty() {
+        return Ok(value.value.clone());
+    }
+
+    Ok(UntaggedValue::Table(
+        columns
+            .iter()
+            .map(|path| get_column_path(path, value))
+            .collect::<Result<Vec<_>, _>>()?,
+    ))
+}
+
+fn keyed(
+    values: Vec<Value>,
+    columns: &[ColumnPath],
+) -> Result<Vec<(UntaggedValue, Value)>, ShellError> {
+    values
+        .into_iter()
+        .map(|value| Ok((key(&value, columns)?, value)))
+        .collect()
+}
+
+/// Set semantics: every key shows up at most once in the output
+fn distinct_operation(
+    operation: SetOperation,
+    input: Vec<Value>,
+    other: Vec<Value>,
+    columns: &[ColumnPath],
+) -> Result<Vec<Value>, ShellError> {
+    let input = keyed(input, columns)?;
+    let other = keyed(other, columns)?;
+
+    let mut seen = IndexSet::new();
+    let mut output = vec![];
+
+    match operation {
+        SetOperation::Intersect | SetOperation::Difference => {
+            let other: IndexSet<UntaggedValue> = other.into_iter().map(|(key, _)| key).collect();
+            let keep_shared = operation == SetOperation::Intersect;
+
+            for (key, value) in input {
+                if other.contains(&key) == keep_shared && seen.insert(key) {
+                    output.push(value);
+                }
+            }
+        }
+        SetOperation::Union => {
+            for (key, value) in input.into_iter().chain(other) {
+                if seen.insert(key) {
+                    output.push(value);
+                }
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Multiset semantics, like SQL's `INTERSECT ALL`, `UNION ALL` and `EXCEPT ALL`: a key that shows
+/// up twice on one side can match twice on the other
+fn multiset_operation(
+    operation: SetOperation,
+    input: Vec<Value>,
+    other: Vec<Value>,
+    columns: &[ColumnPath],
+) -> Result<Vec<Value>, ShellError> {
+    if operation == SetOperation::Union {
+        return Ok(input.into_iter().chain(other).collect());
+    }
+
+    let mut counts = IndexMap::<UntaggedValue, usize>::new();
+    for (key, _) in keyed(other, columns)? {
+        *counts.entry(key).or_insert(0) += 1;
+    }
+
+    let mut output = vec![];
+
+    for (key, value) in keyed(input, columns)? {
+        // Each occurrence on the other side is used up by the first row it matches
+        let matched = match counts.get_mut(&key) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        };
+
+        if matched == (operation == SetOperation::Intersect) {
+            output.push(value);
+        }
+    }
+
+    Ok(output)
+}
@@ -1,10 +1,18 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::{bytes_of, collect_memory_limit};
 use nu_data::base::coerce_compare;
-use nu_engine::WholeStreamCommand;
+use nu_engine::{estimated_size, WholeStreamCommand};
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 use nu_value_ext::ValueExt;
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Seek, SeekFrom, Write};
+
+/// How much of the input is sorted in memory before it's written to a file, unless the
+/// memory budget is given or set in the config
+const DEFAULT_MEMORY_BUDGET: usize = 256 * 1024 * 1024;
 
 pub struct SortBy;
 
@@ -13,6 +21,8 @@ pub struct SortByArgs {
     rest: Vec<Tagged<String>>,
     insensitive: bool,
     reverse: bool,
+    #[serde(rename = "memory-budget")]
+    memory_budget: Option<Value>,
 }
 
 #[async_trait]
@@ -29,6 +39,12 @@ impl WholeStreamCommand for SortBy {
                 Some('i'),
             )
             .switch("reverse", "Sort in reverse order", Some('r'))
+            .named(
+                "memory-budget",
+                SyntaxShape::Unit,
+                "how much of the input to sort in memory before using temporary files (default: sort_memory_budget of the config, or 256mb)",
+                Some('m'),
+            )
             .rest(SyntaxShape::String, "the column(s) to sort by")
     }
 
@@ -36,6 +52,12 @@ impl WholeStreamCommand for SortBy {
         "Sort by the given columns, in increasing order."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Input larger than the memory budget is sorted in parts, which are written to temporary
+files and merged as the output is read. The budget is compared with an estimate of the memory the
+values take up."#
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         sort_by(args).await
     }
@@ -72,6 +94,11 @@ impl WholeStreamCommand for SortBy {
                 example: "ls | sort-by type size",
                 result: None,
             },
+            Example {
+                description: "Sort a large file using at most about 64mb of memory",
+                example: "open --raw huge.csv | from csv | sort-by name --memory-budget 64mb",
+                result: None,
+            },
             Example {
                 description: "Sort strings (case-sensitive)",
                 example: "echo [airplane Truck Car] | sort-by",
@@ -114,24 +141,276 @@ impl WholeStreamCommand for SortBy {
 
 async fn sort_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let tag = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
 
     let (
         SortByArgs {
             rest,
             insensitive,
             reverse,
+            memory_budget,
         },
         mut input,
     ) = args.process().await?;
-    let mut vec = input.drain_vec().await;
+    let memory_budget = memory_budget_of(memory_budget, &scope)?;
+
+    // The input is sorted in chunks that fit the budget, and each chunk but the last is written
+    // to a file, as a run to merge
+    let mut runs = vec![];
+    let mut chunk = vec![];
+    let mut chunk_size = 0;
+    while let Some(value) = input.next().await {
+        chunk_size += estimated_size(&value);
+        chunk.push(value);
+
+        if chunk_size > memory_budget {
+            sort(&mut chunk, &rest, &tag, insensitive)?;
+            if reverse {
+                chunk.reverse();
+            }
+            runs.push(Run::spill(&chunk, &tag)?);
+
+            chunk.clear();
+            chunk_size = 0;
+        }
+    }
+
+    if runs.is_empty() {
+        sort(&mut chunk, &rest, &tag, insensitive)?;
+
+        if reverse {
+            chunk.reverse()
+        }
+
+        return Ok(futures::stream::iter(chunk.into_iter()).to_output_stream());
+    }
+
+    if !chunk.is_empty() {
+        sort(&mut chunk, &rest, &tag, insensitive)?;
+        if reverse {
+            chunk.reverse();
+        }
+        runs.push(Run::Memory(chunk.into_iter()));
+    }
+
+    let merged = Merge::new(runs, rest, insensitive, reverse, tag)?
+        .map(|value| value.and_then(ReturnSuccess::value));
+
+    Ok(futures::stream::iter(merged).to_output_stream())
+}
+
+fn memory_budget_of(memory_budget: Option<Value>, scope: &Scope) -> Result<usize, ShellError> {
+    let memory_budget = match memory_budget {
+        Some(memory_budget) => memory_budget,
+        None => {
+            return Ok(scope
+                .get_config_var("sort_memory_budget")
+                .and_then(|budget| bytes_of(&budget))
+                // Spilling to disk keeps sort-by within the collect_memory_limit too
+                .or_else(|| {
+                    collect_memory_limit(scope).map(|limit| limit.min(DEFAULT_MEMORY_BUDGET))
+                })
+                .unwrap_or(DEFAULT_MEMORY_BUDGET));
+        }
+    };
+
+    match bytes_of(&memory_budget) {
+        Some(budget) if budget > 0 => Ok(budget),
+        _ => Err(ShellError::labeled_error(
+            "Invalid memory budget",
+            "expected a file size, eg 100mb",
+            &memory_budget.tag,
+        )),
+    }
+}
+
+/// A sorted part of the input, kept in memory or in a temporary file with a value on each line
+enum Run {
+    Memory(std::vec::IntoIter<Value>),
+    Spilled(std::io::Lines<BufReader<File>>),
+}
+
+impl Run {
+    fn spill(values: &[Value], tag: &Tag) -> Result<Run, ShellError> {
+        let spill_error = |err: std::io::Error| {
+            ShellError::labeled_error(
+                "Could not write the sorted values to a temporary file",
+                err.to_string(),
+                tag,
+            )
+        };
+
+        let mut file = tempfile::tempfile().map_err(spill_error)?;
+        {
+            let mut writer = BufWriter::new(&file);
+            for value in values {
+                serde_json::to_writer(&mut writer, value).map_err(|err| spill_error(err.into()))?;
+                writer.write_all(b"\n").map_err(spill_error)?;
+            }
+            writer.flush().map_err(spill_error)?;
+        }
+        file.seek(SeekFrom::Start(0)).map_err(spill_error)?;
+
+        Ok(Run::Spilled(BufReader::new(file).lines()))
+    }
+
+    fn next(&mut self) -> Option<Result<Value, ShellError>> {
+        match self {
+            Run::Memory(values) => values.next().map(Ok),
+            Run::Spilled(lines) => lines.next().map(|line| {
+                line.map_err(|err| err.to_string())
+                    .and_then(|line| serde_json::from_str(&line).map_err(|err| err.to_string()))
+                    .map_err(|err| {
+                        ShellError::untagged_runtime_error(format!(
+                            "Could not read the sorted values back from a temporary file: {}",
+                            err
+                        ))
+                    })
+            }),
+        }
+    }
+}
+
+/// Merges the runs, by taking the smallest (or for a reverse sort, the largest) of the first
+/// values of each run
+struct Merge {
+    runs: Vec<Run>,
+    heads: Vec<Option<Value>>,
+    keys: Vec<Tagged<String>>,
+    insensitive: bool,
+    reverse: bool,
+}
 
-    sort(&mut vec, &rest, &tag, insensitive)?;
+impl Merge {
+    fn new(
+        mut runs: Vec<Run>,
+        keys: Vec<Tagged<String>>,
+        insensitive: bool,
+        reverse: bool,
+        tag: Tag,
+    ) -> Result<Merge, ShellError> {
+        let heads = runs
+            .iter_mut()
+            .map(|run| run.next().transpose())
+            .collect::<Result<Vec<_>, _>>()?;
 
-    if reverse {
-        vec.reverse()
+        // Each run was checked when it was sorted, so the values of all of them can be compared
+        // when the first values of the runs can
+        let firsts: Vec<&Value> = heads.iter().flatten().collect();
+        if firsts.iter().all(|value| value.is_primitive()) {
+            if let Some(Err((type_1, type_2))) = firsts
+                .windows(2)
+                .map(|pair| coerce_compare(pair[0], pair[1]))
+                .find(|compared| compared.is_err())
+            {
+                return Err(ShellError::labeled_error(
+                    "Not all values can be compared",
+                    format!(
+                        "Unable to sort values, as \"{}\" cannot compare against \"{}\"",
+                        type_1, type_2
+                    ),
+                    tag,
+                ));
+            }
+        }
+
+        Ok(Merge {
+            runs,
+            heads,
+            keys,
+            insensitive,
+            reverse,
+        })
     }
+}
+
+impl Iterator for Merge {
+    type Item = Result<Value, ShellError>;
 
-    Ok(futures::stream::iter(vec.into_iter()).to_output_stream())
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut next: Option<usize> = None;
+        for (index, head) in self.heads.iter().enumerate() {
+            if let Some(value) = head {
+                let better = match next.and_then(|next| self.heads[next].as_ref()) {
+                    None => true,
+                    Some(best) => {
+                        let ordering = compare(value, best, &self.keys, self.insensitive);
+                        // A reverse sort gives equal values in the opposite of the input order,
+                        // so ties go to the run made from the later part of the input
+                        if self.reverse {
+                            ordering != Ordering::Less
+                        } else {
+                            ordering == Ordering::Less
+                        }
+                    }
+                };
+
+                if better {
+                    next = Some(index);
+                }
+            }
+        }
+
+        let next = next?;
+        let value = match self.runs[next].next().transpose() {
+            Ok(head) => std::mem::replace(&mut self.heads[next], head),
+            Err(err) => {
+                self.heads[next] = None;
+                return Some(Err(err));
+            }
+        };
+
+        value.map(Ok)
+    }
+}
+
+/// The order of two values, the same as the one they're sorted in
+fn compare(a: &Value, b: &Value, keys: &[Tagged<String>], insensitive: bool) -> Ordering {
+    match (a, b) {
+        (
+            Value {
+                value: UntaggedValue::Primitive(_),
+                ..
+            },
+            Value {
+                value: UntaggedValue::Primitive(_),
+                ..
+            },
+        ) => {
+            if insensitive && a.is_string() && b.is_string() {
+                a.expect_string()
+                    .to_ascii_lowercase()
+                    .cmp(&b.expect_string().to_ascii_lowercase())
+            } else {
+                coerce_compare(a, b)
+                    .map(|compared| compared.compare())
+                    .unwrap_or(Ordering::Equal)
+            }
+        }
+        _ => sort_key(a, keys, insensitive).cmp(&sort_key(b, keys, insensitive)),
+    }
+}
+
+/// What a row is sorted by: the values of the columns to sort by
+fn sort_key(item: &Value, keys: &[Tagged<String>], insensitive: bool) -> Vec<Option<Value>> {
+    keys.iter()
+        .map(|f| {
+            let mut value_option = item.get_data_by_key(f.borrow_spanned());
+
+            if insensitive {
+                if let Some(value) = &value_option {
+                    if let Ok(string_value) = value.as_string() {
+                        value_option = Some(
+                            UntaggedValue::string(string_value.to_ascii_lowercase())
+                                .into_value(value.tag.clone()),
+                        )
+                    }
+                }
+            }
+
+            value_option
+        })
+        .collect()
 }
 
 pub fn sort(
@@ -198,27 +477,7 @@ pub fn sort(
             });
         }
         _ => {
-            let calc_key = |item: &Value| {
-                keys.iter()
-                    .map(|f| {
-                        let mut value_option = item.get_data_by_key(f.borrow_spanned());
-
-                        if insensitive {
-                            if let Some(value) = &value_option {
-                                if let Ok(string_value) = value.as_string() {
-                                    value_option = Some(
-                                        UntaggedValue::string(string_value.to_ascii_lowercase())
-                                            .into_value(value.tag.clone()),
-                                    )
-                                }
-                            }
-                        }
-
-                        value_option
-                    })
-                    .collect::<Vec<Option<Value>>>()
-            };
-            vec.sort_by_cached_key(calc_key);
+            vec.sort_by_cached_key(|item| sort_key(item, keys, insensitive));
         }
     };
 
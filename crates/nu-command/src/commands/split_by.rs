@@ -31,9 +31,30 @@ impl WholeStreamCommand for SplitBy {
         "Creates a new table with the data from the inner tables split by the column given."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"It takes the groups from group-by and splits each of them by the column, giving a record with
+a column for each value of it, holding the groups that have rows with that value. Groups grouped
+by more than one column are split all the way down."#
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         split_by(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split the releases of each year by the language they are written in",
+                example: "echo [[name lang year]; [nu rust 2019] [jq c 2012] [ripgrep rust 2016]] | group-by year | split-by lang",
+                result: None,
+            },
+            Example {
+                description: "Count the employees of each title in each department, by office",
+                example: "open employees.csv | group-by department title | split-by office",
+                result: None,
+            },
+        ]
+    }
 }
 
 pub async fn split_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
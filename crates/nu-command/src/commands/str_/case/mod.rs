@@ -3,6 +3,7 @@ pub mod kebab_case;
 pub mod pascal_case;
 pub mod screaming_snake_case;
 pub mod snake_case;
+pub mod title_case;
 
 use crate::prelude::*;
 use nu_errors::ShellError;
@@ -15,6 +16,7 @@ pub use camel_case::SubCommand as CamelCase;
 pub use pascal_case::SubCommand as PascalCase;
 pub use screaming_snake_case::SubCommand as ScreamingSnakeCase;
 pub use snake_case::SubCommand as SnakeCase;
+pub use title_case::SubCommand as TitleCase;
 
 #[derive(Deserialize)]
 struct Arguments {
@@ -0,0 +1,153 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    levenshtein_distance, ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    compare_string: Tagged<String>,
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str distance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str distance")
+            .required(
+                "compare_string",
+                SyntaxShape::String,
+                "the string to compare with",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally give the edit distance to the string by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "gives the edit distance between two strings"
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The distance is the Levenshtein distance: how many characters have to be inserted, deleted or changed to turn one string into the other."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        operate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the edit distance between two strings",
+                example: "echo 'nushell' | str distance 'nutshell'",
+                result: Some(vec![UntaggedValue::int(1).into_untagged_value()]),
+            },
+            Example {
+                description: "Get the edit distance of the names to a string",
+                example: "echo [[name]; [ls] [lsof]] | str distance ps name",
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "name".to_string() => UntaggedValue::int(1).into(),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "name".to_string() => UntaggedValue::int(3).into(),
+                    })
+                    .into(),
+                ]),
+            },
+        ]
+    }
+}
+
+async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (
+        Arguments {
+            compare_string,
+            rest,
+        },
+        input,
+    ) = args.process().await?;
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, &compare_string, v.tag())?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let compare_string = compare_string.clone();
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &compare_string, old.tag())),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, compare_string: &str, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => {
+            Ok(UntaggedValue::int(levenshtein_distance(s, compare_string)).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{action, SubCommand};
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn gives_the_edit_distance() {
+        let word = string("kitten");
+        let expected = UntaggedValue::int(3).into_untagged_value();
+
+        let actual = action(&word, "sitting", Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn errors_on_values_that_are_not_strings() {
+        let number = int(5);
+
+        assert!(action(&number, "5", Tag::unknown()).is_err());
+    }
+}
@@ -3,6 +3,7 @@ mod case;
 mod collect;
 mod command;
 mod contains;
+mod distance;
 mod downcase;
 mod ends_with;
 mod find_replace;
@@ -26,9 +27,11 @@ pub use case::kebab_case::SubCommand as StrKebabCase;
 pub use case::pascal_case::SubCommand as StrPascalCase;
 pub use case::screaming_snake_case::SubCommand as StrScreamingSnakeCase;
 pub use case::snake_case::SubCommand as StrSnakeCase;
+pub use case::title_case::SubCommand as StrTitleCase;
 pub use collect::SubCommand as StrCollect;
 pub use command::Command as Str;
 pub use contains::SubCommand as StrContains;
+pub use distance::SubCommand as StrDistance;
 pub use downcase::SubCommand as StrDowncase;
 pub use ends_with::SubCommand as StrEndsWith;
 pub use find_replace::SubCommand as StrFindReplace;
@@ -0,0 +1,208 @@
+use crate::prelude::*;
+use nu_engine::run_block_to_end;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::hir::Block;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
+use nu_source::{Tagged, Text};
+use std::path::PathBuf;
+use std::time::Instant;
+
+pub struct TestRun;
+
+#[derive(Deserialize)]
+struct TestRunArgs {
+    file: Tagged<PathBuf>,
+    filter: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for TestRun {
+    fn name(&self) -> &str {
+        "test run"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("test run")
+            .required("file", SyntaxShape::FilePath, "the file with the tests")
+            .named(
+                "filter",
+                SyntaxShape::String,
+                "only run the tests with this in their name",
+                Some('f'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs the commands of a file whose names start with test_, and reports which of them failed."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The file is run first, so the commands it defines and the variables it sets are there for the
+tests. Each test then runs on its own: the variables, environment and directory it changes are
+put back before the next one. A test fails if it ends with an error, eg from 'assert'.
+
+Each test gets a row with:
+    test: the name of the test
+    result: passed or failed
+    duration: how long it took
+    error: why it failed, with the line of the file the error points to"#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        test_run(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Run the tests of a file",
+                example: "test run tests.nu",
+                result: None,
+            },
+            Example {
+                description: "Run the tests about parsing, and show the ones that failed",
+                example: "test run tests.nu --filter parse | where result == failed",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn test_run(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = raw_args.call_info.name_tag.clone();
+    let context = EvaluationContext::from_args(&raw_args);
+    let (TestRunArgs { file, filter }, _) = raw_args.process().await?;
+
+    let path = PathBuf::from(context.shell_manager.path()).join(&file.item);
+    let source = std::fs::read_to_string(&path).map_err(|e| {
+        ShellError::labeled_error(
+            format!("Cannot read {}: {}", file.item.display(), e),
+            "cannot read the tests",
+            &file.tag,
+        )
+    })?;
+
+    // As with `debug run`, the errors of the file point into it, so they're shown with its source
+    let (block, err) = nu_parser::parse(&source, 0, &context.scope);
+    if let Some(err) = err {
+        context.with_host(|host| host.print_err(err.into(), &Text::from(source.clone())));
+        return Err(ShellError::labeled_error(
+            "Could not parse the tests",
+            "could not parse",
+            &file.tag,
+        ));
+    }
+
+    let tests = block
+        .definitions
+        .iter()
+        .filter(|(name, _)| name.starts_with("test_"))
+        .filter(|(name, _)| match &filter {
+            Some(filter) => name.contains(&filter.item),
+            None => true,
+        })
+        .map(|(name, test)| (name.clone(), test.clone()))
+        .collect::<Vec<_>>();
+
+    // The file runs in a scope of its own, which the tests run in a scope each inside of
+    let directory = context.shell_manager.path();
+    context.scope.enter_scope();
+    let setup = run_to_end(&block, &context).await;
+    let rows = match setup {
+        Ok(()) => {
+            let mut rows = vec![];
+            for (name, test) in tests {
+                context.scope.enter_scope();
+                let started = Instant::now();
+                let result = run_to_end(&test, &context).await;
+                let duration = started.elapsed();
+                context.scope.exit_scope();
+                context.shell_manager.set_path(directory.clone());
+
+                let mut row = TaggedDictBuilder::new(&tag);
+                row.insert_untagged("test", UntaggedValue::string(name));
+                row.insert_untagged(
+                    "result",
+                    UntaggedValue::string(if result.is_ok() { "passed" } else { "failed" }),
+                );
+                row.insert_untagged("duration", UntaggedValue::duration(duration.as_nanos()));
+                row.insert_untagged(
+                    "error",
+                    UntaggedValue::string(match result {
+                        Ok(()) => String::new(),
+                        Err(err) => describe_error(err, &source),
+                    }),
+                );
+
+                rows.push(ReturnSuccess::value(row.into_value()));
+            }
+            Ok(rows)
+        }
+        Err(err) => Err(err),
+    };
+    context.scope.exit_scope();
+    context.shell_manager.set_path(directory);
+
+    match rows {
+        Ok(rows) => Ok(futures::stream::iter(rows).to_output_stream()),
+        Err(err) => {
+            context.with_host(|host| host.print_err(err, &Text::from(source)));
+            Err(ShellError::labeled_error(
+                "The tests could not be set up",
+                "running the file failed",
+                &file.tag,
+            ))
+        }
+    }
+}
+
+/// Runs the block and reads all of its output, failing with the first error it ends with
+async fn run_to_end(block: &Block, context: &EvaluationContext) -> Result<(), ShellError> {
+    let output = run_block_to_end(block, context, InputStream::empty()).await?;
+
+    let error = output.into_iter().find_map(|value| match value.value {
+        UntaggedValue::Error(err) => Some(err),
+        _ => None,
+    });
+
+    match error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// The message of the error on one line, with the line of the file it points to if it does
+fn describe_error(err: ShellError, source: &str) -> String {
+    let diagnostic = match err.into_diagnostic() {
+        Some(diagnostic) => diagnostic,
+        None => return "the test failed".to_string(),
+    };
+
+    let label = diagnostic
+        .labels
+        .iter()
+        .find(|label| !label.message.is_empty());
+
+    match label {
+        Some(label) if label.range.end <= source.len() => {
+            let line = source[..label.range.start].matches('\n').count() + 1;
+            format!("{} (line {}: {})", diagnostic.message, line, label.message)
+        }
+        Some(label) => format!("{} ({})", diagnostic.message, label.message),
+        None => diagnostic.message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::TestRun;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(TestRun {})
+    }
+}
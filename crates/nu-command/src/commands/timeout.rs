@@ -0,0 +1,167 @@
+use crate::prelude::*;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{format_duration, hir::CapturedBlock, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+pub struct Timeout;
+
+#[derive(Deserialize, Debug)]
+struct TimeoutArgs {
+    duration: Tagged<u64>,
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Timeout {
+    fn name(&self) -> &str {
+        "timeout"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("timeout")
+            .required(
+                "duration",
+                SyntaxShape::Unit,
+                "how long the block can run for",
+            )
+            .required("block", SyntaxShape::Block, "the block to run")
+    }
+
+    fn usage(&self) -> &str {
+        "Runs a block, failing if it doesn't finish within the given time."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "When the time is up the block is stopped and the externals it started are killed. The output of the block is given once it finishes, and the error is a timeout error rather than the errors of the killed externals."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        timeout(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Give an external 5 seconds to finish",
+                example: "timeout 5sec { ^cargo test }",
+                result: None,
+            },
+            Example {
+                description: "Run a block that finishes in time",
+                example: "timeout 1min { echo hello }",
+                result: Some(vec![Value::from("hello")]),
+            },
+        ]
+    }
+}
+
+async fn timeout(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let external_redirection = raw_args.call_info.args.external_redirection;
+    let ctrl_c = raw_args.ctrl_c().clone();
+
+    let mut context = EvaluationContext::from_args(&raw_args);
+    let (
+        TimeoutArgs {
+            duration,
+            mut block,
+        },
+        input,
+    ) = raw_args.process().await?;
+
+    // `stop` is what the block sees as ctrl-c, so its commands stop when it's set. `cancel` kills
+    // its externals too, so it's only set when the time is up, leaving ctrl-c to the externals.
+    let stop = Arc::new(AtomicBool::new(false));
+    let cancel = Arc::new(AtomicBool::new(false));
+    let timed_out = Arc::new(AtomicBool::new(false));
+    let finished = Arc::new(AtomicBool::new(false));
+
+    // A timeout in a block that is itself cancelled goes with it
+    let outer_cancel = context.scope.get_cancellation();
+
+    {
+        let stop = stop.clone();
+        let cancel = cancel.clone();
+        let timed_out = timed_out.clone();
+        let finished = finished.clone();
+        let deadline = Instant::now() + Duration::from_nanos(duration.item);
+
+        thread::spawn(move || {
+            while !finished.load(Ordering::SeqCst) {
+                if ctrl_c.load(Ordering::SeqCst) {
+                    stop.store(true, Ordering::SeqCst);
+                }
+
+                let outer_cancelled =
+                    matches!(&outer_cancel, Some(outer) if outer.load(Ordering::SeqCst));
+                let now = Instant::now();
+                if now >= deadline || outer_cancelled {
+                    timed_out.store(!outer_cancelled, Ordering::SeqCst);
+                    stop.store(true, Ordering::SeqCst);
+                    cancel.store(true, Ordering::SeqCst);
+                    return;
+                }
+
+                thread::sleep(CHECK_INTERVAL.min(deadline - now));
+            }
+        });
+    }
+
+    context.ctrl_c = stop.clone();
+    block.block.set_redirect(external_redirection);
+
+    context.scope.enter_scope();
+    context.scope.set_cancellation(cancel);
+    let result = run_block(&block.block, &context, input).await;
+
+    // The block keeps running as its output is read, so it only finishes once that's done
+    let mut output = vec![];
+    let result = match result {
+        Ok(mut stream) => {
+            while let Some(value) = stream.next().await {
+                if stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                output.push(value);
+            }
+            Ok(())
+        }
+        Err(err) => Err(err),
+    };
+
+    context.scope.exit_scope();
+    finished.store(true, Ordering::SeqCst);
+
+    if timed_out.load(Ordering::SeqCst) {
+        // The errors left behind are those of the externals that were killed
+        context.clear_errors();
+
+        return Err(ShellError::timed_out(
+            format_duration(&BigInt::from(duration.item)),
+            duration.tag.span,
+        ));
+    }
+
+    result?;
+
+    Ok(futures::stream::iter(output).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::Timeout;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Timeout {})
+    }
+}
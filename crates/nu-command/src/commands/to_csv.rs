@@ -44,6 +44,7 @@ impl WholeStreamCommand for ToCsv {
 
 async fn to_csv(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
     let (
         ToCsvArgs {
             separator,
@@ -74,7 +75,7 @@ async fn to_csv(args: CommandArgs) -> Result<OutputStream, ShellError> {
         _ => ',',
     };
 
-    to_delimited_data(noheaders, sep, "CSV", input, name).await
+    to_delimited_data(noheaders, sep, "CSV", input, name, scope).await
 }
 
 #[cfg(test)]
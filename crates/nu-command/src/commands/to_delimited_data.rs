@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
 use csv::WriterBuilder;
 use indexmap::{indexset, IndexSet};
 use nu_errors::ShellError;
@@ -170,11 +171,13 @@ pub async fn to_delimited_data(
     format_name: &'static str,
     input: InputStream,
     name: Tag,
+    scope: Scope,
 ) -> Result<OutputStream, ShellError> {
     let name_tag = name;
     let name_span = name_tag.span;
 
-    let input: Vec<Value> = input.collect().await;
+    let command = format!("to {}", format_name.to_lowercase());
+    let input: Vec<Value> = collect_within_limit(input, &command, &scope, &name_tag).await?;
 
     let to_process_input = match input.len() {
         x if x > 1 => {
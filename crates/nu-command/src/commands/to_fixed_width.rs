@@ -0,0 +1,346 @@
+use crate::prelude::*;
+use nu_data::value::format_leaf;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct ToFixedWidth;
+
+#[derive(Deserialize)]
+pub struct ToFixedWidthArgs {
+    widths: Option<Value>,
+    align: Option<Value>,
+    separator: Option<Tagged<String>>,
+    #[serde(rename = "no-headers")]
+    no_headers: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Alignment {
+    Left,
+    Right,
+    Center,
+}
+
+#[async_trait]
+impl WholeStreamCommand for ToFixedWidth {
+    fn name(&self) -> &str {
+        "to fixed-width"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to fixed-width")
+            .named(
+                "widths",
+                SyntaxShape::Any,
+                "the width of each column, as a list in column order or a row keyed by column name",
+                Some('w'),
+            )
+            .named(
+                "align",
+                SyntaxShape::Any,
+                "left, right or center for each column, as a list in column order or a row keyed by column name",
+                Some('a'),
+            )
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the text between columns (a space, or nothing when --widths is given)",
+                Some('s'),
+            )
+            .switch("no-headers", "leave out the header line", Some('n'))
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a table into text with its columns lined up."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Without --widths, each column is as wide as its widest cell and columns are separated by a
+space, which makes plain text reports. With --widths, every cell is padded or cut to exactly that
+width, which is what fixed-width file formats expect. Numbers are right-aligned and everything else
+is left-aligned unless --align says otherwise."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        to_fixed_width(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Line up the columns of a table",
+                example: "echo [[name size]; [a.txt 120] [notes.md 8]] | to fixed-width",
+                result: Some(vec![Value::from(
+                    "name     size\na.txt     120\nnotes.md    8",
+                )]),
+            },
+            Example {
+                description: "Write exact column widths for a legacy tool",
+                example: "echo [[id name]; [7 ann] [42 bob]] | to fixed-width --widths [4 6] --align [right left] --no-headers",
+                result: Some(vec![Value::from("   7ann   \n  42bob   ")]),
+            },
+            Example {
+                description: "Center one column by name",
+                example: "ls | to fixed-width --align $(echo [[type]; [center]] | first)",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn to_fixed_width(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (
+        ToFixedWidthArgs {
+            widths,
+            align,
+            separator,
+            no_headers,
+        },
+        input,
+    ) = args.process().await?;
+
+    let input: Vec<Value> = input.collect().await;
+    let headers = nu_protocol::merge_descriptors(&input);
+
+    let rows: Vec<Vec<(String, bool)>> = input
+        .iter()
+        .map(|row| match &row.value {
+            UntaggedValue::Row(_) => headers
+                .iter()
+                .map(|header| cell(row.get_data(header).borrow()))
+                .collect(),
+            _ => vec![cell(row)],
+        })
+        .collect();
+
+    // A list of plain values makes a single, unnamed column
+    let headers = if headers.is_empty() && !rows.is_empty() {
+        vec![String::new()]
+    } else {
+        headers
+    };
+
+    let fixed = widths.is_some();
+    let widths = per_column(widths, &headers, "widths", |value| {
+        value.as_u64().map(|width| width as usize).map_err(|_| {
+            ShellError::labeled_error("Expected a width", "expected a whole number", &value.tag)
+        })
+    })?;
+    let align = per_column(align, &headers, "align", |value| {
+        match value.as_string()?.to_ascii_lowercase().as_str() {
+            "l" | "left" => Ok(Alignment::Left),
+            "r" | "right" => Ok(Alignment::Right),
+            "c" | "center" | "centre" => Ok(Alignment::Center),
+            _ => Err(ShellError::labeled_error(
+                "Expected an alignment",
+                "expected left, right or center",
+                &value.tag,
+            )),
+        }
+    })?;
+
+    let separator = separator
+        .map(|separator| separator.item)
+        .unwrap_or_else(|| if fixed { "" } else { " " }.to_string());
+    let show_headers = !no_headers && headers.iter().any(|header| !header.is_empty());
+
+    let columns: Vec<(usize, Alignment)> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, header)| {
+            let width = widths[i].unwrap_or_else(|| {
+                rows.iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|(text, _)| text_width(text))
+                    .chain(std::iter::once(if show_headers {
+                        text_width(header)
+                    } else {
+                        0
+                    }))
+                    .max()
+                    .unwrap_or(0)
+            });
+
+            let numeric = !rows.is_empty()
+                && rows
+                    .iter()
+                    .all(|row| row.get(i).map(|(_, numeric)| *numeric).unwrap_or(true));
+            let alignment = align[i].unwrap_or(if numeric {
+                Alignment::Right
+            } else {
+                Alignment::Left
+            });
+
+            (width, alignment)
+        })
+        .collect();
+
+    let mut lines = vec![];
+
+    if show_headers {
+        lines.push(line(
+            headers.iter().map(String::as_str),
+            &columns,
+            &separator,
+            fixed,
+        ));
+    }
+    for row in &rows {
+        lines.push(line(
+            row.iter().map(|(text, _)| text.as_str()),
+            &columns,
+            &separator,
+            fixed,
+        ));
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(lines.join("\n")).into_value(if input.is_empty() {
+            name_tag
+        } else {
+            input[0].tag()
+        }),
+    )))
+}
+
+/// The text of a cell, and whether it holds a number
+fn cell(value: &Value) -> (String, bool) {
+    let numeric = matches!(
+        value.value,
+        UntaggedValue::Primitive(Primitive::Int(_))
+            | UntaggedValue::Primitive(Primitive::Decimal(_))
+            | UntaggedValue::Primitive(Primitive::Filesize(_))
+    );
+
+    (format_leaf(&value.value).plain_string(100_000), numeric)
+}
+
+/// Reads a per-column setting given either as a list in column order or a row keyed by column
+/// name. Columns without a setting get `None`.
+fn per_column<T>(
+    spec: Option<Value>,
+    headers: &[String],
+    flag: &str,
+    parse: impl Fn(&Value) -> Result<T, ShellError>,
+) -> Result<Vec<Option<T>>, ShellError> {
+    let mut settings: Vec<Option<T>> = headers.iter().map(|_| None).collect();
+
+    match spec {
+        None => {}
+        Some(Value {
+            value: UntaggedValue::Table(values),
+            tag,
+        }) => {
+            if values.len() > headers.len() {
+                return Err(ShellError::labeled_error(
+                    format!("Too many {}", flag),
+                    format!(
+                        "{} given for a table with {} columns",
+                        values.len(),
+                        headers.len()
+                    ),
+                    tag,
+                ));
+            }
+
+            for (setting, value) in settings.iter_mut().zip(values.iter()) {
+                *setting = Some(parse(value)?);
+            }
+        }
+        Some(Value {
+            value: UntaggedValue::Row(dict),
+            ..
+        }) => {
+            for (column, value) in dict.entries.iter() {
+                let index = headers.iter().position(|header| header == column);
+
+                match index {
+                    Some(index) => settings[index] = Some(parse(value)?),
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "Unknown column",
+                            format!("the table has no column named '{}'", column),
+                            &value.tag,
+                        ))
+                    }
+                }
+            }
+        }
+        // A single value applies to every column
+        Some(value) => {
+            for setting in settings.iter_mut() {
+                *setting = Some(parse(&value)?);
+            }
+        }
+    }
+
+    Ok(settings)
+}
+
+fn text_width(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+fn line<'a>(
+    cells: impl Iterator<Item = &'a str>,
+    columns: &[(usize, Alignment)],
+    separator: &str,
+    fixed: bool,
+) -> String {
+    let line = cells
+        .zip(columns.iter())
+        .map(|(text, (width, alignment))| pad(text, *width, *alignment))
+        .collect::<Vec<_>>()
+        .join(separator);
+
+    // Fixed-width records keep their exact length, reports don't need trailing spaces
+    if fixed {
+        line
+    } else {
+        line.trim_end().to_string()
+    }
+}
+
+fn pad(text: &str, width: usize, alignment: Alignment) -> String {
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+
+    if graphemes.len() >= width {
+        return graphemes[..width].concat();
+    }
+
+    let padding = width - graphemes.len();
+    let (before, after) = match alignment {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+
+    format!("{}{}{}", " ".repeat(before), text, " ".repeat(after))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::ToFixedWidth;
+    use super::{pad, Alignment};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(ToFixedWidth {})
+    }
+
+    #[test]
+    fn pads_and_cuts_to_width() {
+        assert_eq!(pad("ab", 5, Alignment::Left), "ab   ");
+        assert_eq!(pad("ab", 5, Alignment::Right), "   ab");
+        assert_eq!(pad("ab", 5, Alignment::Center), " ab  ");
+        assert_eq!(pad("abcdef", 3, Alignment::Right), "abc");
+        assert_eq!(pad("añb", 2, Alignment::Left), "añ");
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
 use futures::StreamExt;
 use nu_data::value::format_leaf;
 use nu_engine::WholeStreamCommand;
@@ -270,6 +271,7 @@ fn get_list_of_theme_names() -> Vec<String> {
 
 async fn to_html(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_tag = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
     let (
         ToHtmlArgs {
             html_color,
@@ -281,7 +283,7 @@ async fn to_html(args: CommandArgs) -> Result<OutputStream, ShellError> {
         },
         input,
     ) = args.process().await?;
-    let input: Vec<Value> = input.collect().await;
+    let input: Vec<Value> = collect_within_limit(input, "to html", &scope, &name_tag).await?;
     let headers = nu_protocol::merge_descriptors(&input);
     let headers = Some(headers)
         .filter(|headers| !headers.is_empty() && (headers.len() > 1 || !headers[0].is_empty()));
@@ -1,9 +1,9 @@
 use crate::prelude::*;
+use crate::utils::json::value_to_json_value;
+use crate::utils::memory_limit::collect_within_limit;
 use nu_engine::WholeStreamCommand;
-use nu_errors::{CoerceInto, ShellError};
-use nu_protocol::{
-    Primitive, ReturnSuccess, Signature, SyntaxShape, UnspannedPathMember, UntaggedValue, Value,
-};
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use serde::Serialize;
 use serde_json::json;
 
@@ -55,114 +55,12 @@ impl WholeStreamCommand for ToJson {
     }
 }
 
-pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
-    Ok(match &v.value {
-        UntaggedValue::Primitive(Primitive::Boolean(b)) => serde_json::Value::Bool(*b),
-        UntaggedValue::Primitive(Primitive::Filesize(b)) => serde_json::Value::Number(
-            serde_json::Number::from(b.to_u64().expect("What about really big numbers")),
-        ),
-        UntaggedValue::Primitive(Primitive::Duration(i)) => {
-            serde_json::Value::String(i.to_string())
-        }
-        UntaggedValue::Primitive(Primitive::Date(d)) => serde_json::Value::String(d.to_string()),
-        UntaggedValue::Primitive(Primitive::EndOfStream) => serde_json::Value::Null,
-        UntaggedValue::Primitive(Primitive::BeginningOfStream) => serde_json::Value::Null,
-        UntaggedValue::Primitive(Primitive::Decimal(f)) => {
-            if let Some(f) = f.to_f64() {
-                if let Some(num) = serde_json::Number::from_f64(
-                    f.to_f64().expect("TODO: What about really big decimals?"),
-                ) {
-                    serde_json::Value::Number(num)
-                } else {
-                    return Err(ShellError::labeled_error(
-                        "Could not convert value to decimal number",
-                        "could not convert to decimal",
-                        &v.tag,
-                    ));
-                }
-            } else {
-                return Err(ShellError::labeled_error(
-                    "Could not convert value to decimal number",
-                    "could not convert to decimal",
-                    &v.tag,
-                ));
-            }
-        }
-
-        UntaggedValue::Primitive(Primitive::Int(i)) => {
-            serde_json::Value::Number(serde_json::Number::from(CoerceInto::<i64>::coerce_into(
-                i.tagged(&v.tag),
-                "converting to JSON number",
-            )?))
-        }
-        UntaggedValue::Primitive(Primitive::Nothing) => serde_json::Value::Null,
-        UntaggedValue::Primitive(Primitive::GlobPattern(s)) => serde_json::Value::String(s.clone()),
-        UntaggedValue::Primitive(Primitive::String(s)) => serde_json::Value::String(s.clone()),
-        UntaggedValue::Primitive(Primitive::ColumnPath(path)) => serde_json::Value::Array(
-            path.iter()
-                .map(|x| match &x.unspanned {
-                    UnspannedPathMember::String(string) => {
-                        Ok(serde_json::Value::String(string.clone()))
-                    }
-                    UnspannedPathMember::Int(int) => Ok(serde_json::Value::Number(
-                        serde_json::Number::from(CoerceInto::<i64>::coerce_into(
-                            int.tagged(&v.tag),
-                            "converting to JSON number",
-                        )?),
-                    )),
-                })
-                .collect::<Result<Vec<serde_json::Value>, ShellError>>()?,
-        ),
-        UntaggedValue::Primitive(Primitive::FilePath(s)) => {
-            serde_json::Value::String(s.display().to_string())
-        }
-
-        UntaggedValue::Table(l) => serde_json::Value::Array(json_list(l)?),
-        UntaggedValue::Error(e) => return Err(e.clone()),
-        UntaggedValue::Block(_) | UntaggedValue::Primitive(Primitive::Range(_)) => {
-            serde_json::Value::Null
-        }
-        UntaggedValue::Primitive(Primitive::Binary(b)) => serde_json::Value::Array(
-            b.iter()
-                .map(|x| {
-                    serde_json::Number::from_f64(*x as f64).ok_or_else(|| {
-                        ShellError::labeled_error(
-                            "Can not convert number from floating point",
-                            "can not convert to number",
-                            &v.tag,
-                        )
-                    })
-                })
-                .collect::<Result<Vec<serde_json::Number>, ShellError>>()?
-                .into_iter()
-                .map(serde_json::Value::Number)
-                .collect(),
-        ),
-        UntaggedValue::Row(o) => {
-            let mut m = serde_json::Map::new();
-            for (k, v) in o.entries.iter() {
-                m.insert(k.clone(), value_to_json_value(v)?);
-            }
-            serde_json::Value::Object(m)
-        }
-    })
-}
-
-fn json_list(input: &[Value]) -> Result<Vec<serde_json::Value>, ShellError> {
-    let mut out = vec![];
-
-    for value in input {
-        out.push(value_to_json_value(value)?);
-    }
-
-    Ok(out)
-}
-
 async fn to_json(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_tag = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
     let (ToJsonArgs { pretty }, input) = args.process().await?;
     let name_span = name_tag.span;
-    let input: Vec<Value> = input.collect().await;
+    let input: Vec<Value> = collect_within_limit(input, "to json", &scope, &name_tag).await?;
 
     let to_process_input = match input.len() {
         x if x > 1 => {
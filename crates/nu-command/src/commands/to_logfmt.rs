@@ -0,0 +1,137 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+
+pub struct ToLogfmt;
+
+#[async_trait]
+impl WholeStreamCommand for ToLogfmt {
+    fn name(&self) -> &str {
+        "to logfmt"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to logfmt")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into logfmt text, one line per row."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        to_logfmt(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Outputs a row as logfmt",
+                example: r#"echo [[level msg]; [info "server started"]] | to logfmt"#,
+                result: Some(vec![Value::from(r#"level=info msg="server started""#)]),
+            },
+            Example {
+                description: "Lists are written as repeated keys",
+                example: "echo [[tag]; [[a b]]] | to logfmt",
+                result: Some(vec![Value::from("tag=a tag=b")]),
+            },
+        ]
+    }
+}
+
+/// Quotes the value if it would otherwise be read back differently
+fn logfmt_value(value: &str) -> String {
+    if !value.is_empty()
+        && !value
+            .chars()
+            .any(|c| c.is_whitespace() || c == '"' || c == '=' || c == '\\')
+    {
+        return value.to_string();
+    }
+
+    let mut output = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\t' => output.push_str("\\t"),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+fn logfmt_pair(key: &str, value: &Value, pairs: &mut Vec<String>) -> Result<(), ShellError> {
+    match &value.value {
+        UntaggedValue::Table(values) => {
+            for value in values {
+                logfmt_pair(key, value, pairs)?;
+            }
+        }
+        UntaggedValue::Row(_) | UntaggedValue::Block(_) | UntaggedValue::Error(_) => {
+            return Err(ShellError::labeled_error(
+                "Expected a flat table",
+                format!("'{}' can't be written as logfmt", key),
+                &value.tag,
+            ));
+        }
+        UntaggedValue::Primitive(Primitive::Nothing) => pairs.push(format!("{}=", key)),
+        UntaggedValue::Primitive(Primitive::Date(date)) => {
+            pairs.push(format!("{}={}", key, date.to_rfc3339()))
+        }
+        _ => pairs.push(format!(
+            "{}={}",
+            key,
+            logfmt_value(&value.convert_to_string())
+        )),
+    }
+
+    Ok(())
+}
+
+pub fn value_to_logfmt_line(value: &Value) -> Result<String, ShellError> {
+    match &value.value {
+        UntaggedValue::Row(row) => {
+            let mut pairs = vec![];
+            for (key, value) in row.entries.iter() {
+                logfmt_pair(key, value, &mut pairs)?;
+            }
+            Ok(pairs.join(" "))
+        }
+        _ => Err(ShellError::labeled_error(
+            "Expected a table with rows",
+            "requires rows to convert",
+            &value.tag,
+        )),
+    }
+}
+
+async fn to_logfmt(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once().await?;
+    let tag = args.name_tag();
+    let input: Vec<Value> = args.input.collect().await;
+
+    let mut lines = vec![];
+    for value in input.iter() {
+        lines.push(value_to_logfmt_line(value)?);
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(lines.join("\n")).into_value(&tag),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::ToLogfmt;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(ToLogfmt {})
+    }
+}
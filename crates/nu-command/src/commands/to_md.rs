@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
 use futures::StreamExt;
 use nu_data::value::format_leaf;
 use nu_engine::WholeStreamCommand;
@@ -85,9 +86,10 @@ impl WholeStreamCommand for Command {
 
 async fn to_md(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_tag = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
     let (arguments, input) = args.process().await?;
 
-    let input: Vec<Value> = input.collect().await;
+    let input: Vec<Value> = collect_within_limit(input, "to md", &scope, &name_tag).await?;
 
     Ok(OutputStream::one(ReturnSuccess::value(
         UntaggedValue::string(process(&input, arguments)).into_value(if input.is_empty() {
@@ -0,0 +1,161 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_value_ext::ValueExt;
+
+pub struct ToPrometheus;
+
+#[async_trait]
+impl WholeStreamCommand for ToPrometheus {
+    fn name(&self) -> &str {
+        "to prometheus"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to prometheus")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into the Prometheus exposition format."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Rows need a metric and a value column, and may have labels (a record), timestamp and type
+columns, as created by `from prometheus`."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        to_prometheus(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Outputs a gauge",
+            example: "echo [[metric value type]; [queue_length 42 gauge]] | to prometheus",
+            result: Some(vec![Value::from(
+                "# TYPE queue_length gauge\nqueue_length 42\n",
+            )]),
+        }]
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn sample_line(row: &Value, tag: &Tag) -> Result<(String, Option<String>, String), ShellError> {
+    let column = |name: &str| row.get_data_by_key(name.spanned(tag.span));
+
+    let metric = column("metric")
+        .ok_or_else(|| {
+            ShellError::labeled_error(
+                "Expected a metric column",
+                "requires a metric column",
+                &row.tag,
+            )
+        })?
+        .as_string()?;
+
+    let value = column("value").ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected a value column",
+            "requires a value column",
+            &row.tag,
+        )
+    })?;
+    let value = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Decimal(_))
+        | UntaggedValue::Primitive(Primitive::String(_)) => value.convert_to_string(),
+        other => {
+            return Err(ShellError::labeled_error(
+                "Expected a number",
+                format!("{} can't be used as a sample value", other.type_name()),
+                &value.tag,
+            ))
+        }
+    };
+
+    let mut line = metric.clone();
+
+    if let Some(labels) = column("labels") {
+        if let UntaggedValue::Row(labels) = &labels.value {
+            let labels = labels
+                .entries
+                .iter()
+                .map(|(name, value)| {
+                    format!(
+                        "{}=\"{}\"",
+                        name,
+                        escape_label_value(&value.convert_to_string())
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            if !labels.is_empty() {
+                line.push_str(&format!("{{{}}}", labels.join(",")));
+            }
+        }
+    }
+
+    line.push(' ');
+    line.push_str(&value);
+
+    match column("timestamp").map(|timestamp| timestamp.value) {
+        Some(UntaggedValue::Primitive(Primitive::Date(date))) => {
+            line.push_str(&format!(" {}", date.timestamp_millis()))
+        }
+        Some(UntaggedValue::Primitive(Primitive::Int(millis))) => {
+            line.push_str(&format!(" {}", millis))
+        }
+        _ => {}
+    }
+
+    let kind = column("type").and_then(|kind| kind.as_string().ok());
+
+    Ok((metric, kind, line))
+}
+
+async fn to_prometheus(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once().await?;
+    let tag = args.name_tag();
+    let input: Vec<Value> = args.input.collect().await;
+
+    let mut output = String::new();
+    let mut typed_metrics = vec![];
+
+    for row in input.iter() {
+        let (metric, kind, line) = sample_line(row, &tag)?;
+
+        if let Some(kind) = kind {
+            if !typed_metrics.contains(&metric) {
+                output.push_str(&format!("# TYPE {} {}\n", metric, kind));
+                typed_metrics.push(metric);
+            }
+        }
+
+        output.push_str(&line);
+        output.push('\n');
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(output).into_value(&tag),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::ToPrometheus;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(ToPrometheus {})
+    }
+}
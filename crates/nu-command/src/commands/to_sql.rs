@@ -0,0 +1,418 @@
+use crate::prelude::*;
+use crate::utils::json::value_to_json_value;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use num_traits::ToPrimitive;
+
+const DEFAULT_TABLE_NAME: &str = "data";
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+pub struct ToSql;
+
+#[derive(Deserialize)]
+pub struct ToSqlArgs {
+    #[serde(rename = "table-name")]
+    table_name: Option<Tagged<String>>,
+    dialect: Option<Tagged<String>>,
+    #[serde(rename = "batch-size")]
+    batch_size: Option<Tagged<usize>>,
+    #[serde(rename = "insert-only")]
+    insert_only: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Dialect {
+    Sqlite,
+    Postgres,
+    Mysql,
+}
+
+/// What a column holds, worked out from all of its values
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ColumnKind {
+    Integer,
+    Real,
+    Boolean,
+    Text,
+    Timestamp,
+    Blob,
+    Json,
+}
+
+#[async_trait]
+impl WholeStreamCommand for ToSql {
+    fn name(&self) -> &str {
+        "to sql"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to sql")
+            .named(
+                "table-name",
+                SyntaxShape::String,
+                "the table to create and insert into (defaults to 'data')",
+                Some('t'),
+            )
+            .named(
+                "dialect",
+                SyntaxShape::String,
+                "sqlite, postgres or mysql (defaults to sqlite)",
+                Some('d'),
+            )
+            .named(
+                "batch-size",
+                SyntaxShape::Int,
+                "the number of rows in each INSERT statement (defaults to 500)",
+                Some('b'),
+            )
+            .switch(
+                "insert-only",
+                "leave out the CREATE TABLE statement",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a table into SQL statements that create and fill a database table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Column types are worked out from all the values of a column: whole numbers, decimals,
+booleans, dates, binary data and nested data (stored as JSON) each get the matching type of the
+dialect, and columns mixing different kinds of values are stored as text. Identifiers and strings
+are quoted the way the dialect expects."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        to_sql(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create a table of files",
+                example: "echo [[name size]; [a.txt 120] [notes.md 8]] | to sql --table-name files",
+                result: Some(vec![Value::from(
+                    r#"CREATE TABLE "files" (
+  "name" TEXT,
+  "size" INTEGER
+);
+INSERT INTO "files" ("name", "size") VALUES
+  ('a.txt', 120),
+  ('notes.md', 8);"#,
+                )]),
+            },
+            Example {
+                description: "Only insert the rows, for a MySQL table that already exists",
+                example:
+                    "echo [[name done]; [\"it's\" $true]] | to sql -t todo -d mysql --insert-only",
+                result: Some(vec![Value::from(
+                    "INSERT INTO `todo` (`name`, `done`) VALUES\n  ('it''s', TRUE);",
+                )]),
+            },
+            Example {
+                description: "Load the process list into Postgres",
+                example: "ps | to sql -t processes -d postgres | save processes.sql",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn to_sql(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (
+        ToSqlArgs {
+            table_name,
+            dialect,
+            batch_size,
+            insert_only,
+        },
+        input,
+    ) = args.process().await?;
+
+    let dialect = match dialect {
+        None => Dialect::Sqlite,
+        Some(dialect) => match dialect.item.to_ascii_lowercase().as_str() {
+            "sqlite" => Dialect::Sqlite,
+            "postgres" | "postgresql" => Dialect::Postgres,
+            "mysql" | "mariadb" => Dialect::Mysql,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown SQL dialect",
+                    "expected sqlite, postgres or mysql",
+                    &dialect.tag,
+                ))
+            }
+        },
+    };
+    let batch_size = match batch_size {
+        Some(size) if size.item == 0 => {
+            return Err(ShellError::labeled_error(
+                "Batch size must be at least 1",
+                "batch size of zero",
+                &size.tag,
+            ))
+        }
+        Some(size) => size.item,
+        None => DEFAULT_BATCH_SIZE,
+    };
+    let table_name = table_name
+        .map(|name| name.item)
+        .unwrap_or_else(|| DEFAULT_TABLE_NAME.to_string());
+
+    let input: Vec<Value> = input.collect().await;
+
+    if let Some(value) = input.iter().find(|value| !value.value.is_row()) {
+        return Err(ShellError::labeled_error_with_secondary(
+            "Expected a table",
+            "requires a table of rows",
+            &name_tag,
+            format!("{} is not a row", value.type_name()),
+            &value.tag,
+        ));
+    }
+
+    let columns = nu_protocol::merge_descriptors(&input);
+    let kinds: Vec<Option<ColumnKind>> = columns
+        .iter()
+        .map(|column| {
+            input
+                .iter()
+                .filter_map(|row| column_kind(row.get_data(column).borrow()))
+                .fold(None, |kind, next| Some(merge_kinds(kind, next)))
+        })
+        .collect();
+
+    if columns.is_empty() {
+        return Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string("").into_value(name_tag),
+        )));
+    }
+
+    let mut statements = vec![];
+
+    if !insert_only {
+        let definitions: Vec<String> = columns
+            .iter()
+            .zip(kinds.iter())
+            .map(|(column, kind)| {
+                format!(
+                    "  {} {}",
+                    quote_identifier(column, dialect),
+                    sql_type(kind.unwrap_or(ColumnKind::Text), dialect)
+                )
+            })
+            .collect();
+
+        statements.push(format!(
+            "CREATE TABLE {} (\n{}\n);",
+            quote_identifier(&table_name, dialect),
+            definitions.join(",\n")
+        ));
+    }
+
+    let insert = format!(
+        "INSERT INTO {} ({}) VALUES",
+        quote_identifier(&table_name, dialect),
+        columns
+            .iter()
+            .map(|column| quote_identifier(column, dialect))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    for batch in input.chunks(batch_size) {
+        let rows = batch
+            .iter()
+            .map(|row| {
+                let values = columns
+                    .iter()
+                    .zip(kinds.iter())
+                    .map(|(column, kind)| {
+                        literal(
+                            row.get_data(column).borrow(),
+                            kind.unwrap_or(ColumnKind::Text),
+                            dialect,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(format!("  ({})", values.join(", ")))
+            })
+            .collect::<Result<Vec<String>, ShellError>>()?;
+
+        statements.push(format!("{}\n{};", insert, rows.join(",\n")));
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(statements.join("\n")).into_value(name_tag),
+    )))
+}
+
+fn column_kind(value: &Value) -> Option<ColumnKind> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => None,
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Filesize(_))
+        | UntaggedValue::Primitive(Primitive::Duration(_)) => Some(ColumnKind::Integer),
+        UntaggedValue::Primitive(Primitive::Decimal(_)) => Some(ColumnKind::Real),
+        UntaggedValue::Primitive(Primitive::Boolean(_)) => Some(ColumnKind::Boolean),
+        UntaggedValue::Primitive(Primitive::Date(_)) => Some(ColumnKind::Timestamp),
+        UntaggedValue::Primitive(Primitive::Binary(_)) => Some(ColumnKind::Blob),
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => Some(ColumnKind::Json),
+        _ => Some(ColumnKind::Text),
+    }
+}
+
+/// Whole numbers widen to decimals, any other mix of kinds falls back to text
+fn merge_kinds(kind: Option<ColumnKind>, next: ColumnKind) -> ColumnKind {
+    match (kind, next) {
+        (None, next) => next,
+        (Some(kind), next) if kind == next => kind,
+        (Some(ColumnKind::Integer), ColumnKind::Real)
+        | (Some(ColumnKind::Real), ColumnKind::Integer) => ColumnKind::Real,
+        _ => ColumnKind::Text,
+    }
+}
+
+fn sql_type(kind: ColumnKind, dialect: Dialect) -> &'static str {
+    match (kind, dialect) {
+        (ColumnKind::Integer, Dialect::Sqlite) => "INTEGER",
+        (ColumnKind::Integer, _) => "BIGINT",
+        (ColumnKind::Real, Dialect::Sqlite) => "REAL",
+        (ColumnKind::Real, Dialect::Postgres) => "DOUBLE PRECISION",
+        (ColumnKind::Real, Dialect::Mysql) => "DOUBLE",
+        (ColumnKind::Boolean, Dialect::Sqlite) => "INTEGER",
+        (ColumnKind::Boolean, _) => "BOOLEAN",
+        (ColumnKind::Text, _) => "TEXT",
+        (ColumnKind::Timestamp, Dialect::Sqlite) => "TEXT",
+        (ColumnKind::Timestamp, Dialect::Postgres) => "TIMESTAMP WITH TIME ZONE",
+        (ColumnKind::Timestamp, Dialect::Mysql) => "DATETIME(6)",
+        (ColumnKind::Blob, Dialect::Postgres) => "BYTEA",
+        (ColumnKind::Blob, _) => "BLOB",
+        (ColumnKind::Json, Dialect::Sqlite) => "TEXT",
+        (ColumnKind::Json, Dialect::Postgres) => "JSONB",
+        (ColumnKind::Json, Dialect::Mysql) => "JSON",
+    }
+}
+
+fn quote_identifier(name: &str, dialect: Dialect) -> String {
+    match dialect {
+        Dialect::Mysql => format!("`{}`", name.replace('`', "``")),
+        Dialect::Sqlite | Dialect::Postgres => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
+fn quote_string(text: &str, dialect: Dialect) -> String {
+    let text = match dialect {
+        // MySQL treats backslashes in strings as escapes by default
+        Dialect::Mysql => text.replace('\\', "\\\\"),
+        Dialect::Sqlite | Dialect::Postgres => text.to_string(),
+    };
+
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// Renders a value for a column of the given kind. Values in text columns are always quoted, so a
+/// column mixing numbers and strings stays valid in every dialect.
+fn literal(value: &Value, kind: ColumnKind, dialect: Dialect) -> Result<String, ShellError> {
+    if value.value.is_none() {
+        return Ok("NULL".to_string());
+    }
+
+    Ok(match (&value.value, kind) {
+        (UntaggedValue::Primitive(Primitive::Int(i)), ColumnKind::Integer)
+        | (UntaggedValue::Primitive(Primitive::Filesize(i)), ColumnKind::Integer)
+        | (UntaggedValue::Primitive(Primitive::Duration(i)), ColumnKind::Integer)
+        | (UntaggedValue::Primitive(Primitive::Int(i)), ColumnKind::Real) => {
+            if i.to_i64().is_none() {
+                return Err(ShellError::labeled_error(
+                    "Value too large for SQL",
+                    "doesn't fit in a 64-bit integer",
+                    &value.tag,
+                ));
+            }
+            i.to_string()
+        }
+        (UntaggedValue::Primitive(Primitive::Decimal(d)), ColumnKind::Real) => d.to_string(),
+        (UntaggedValue::Primitive(Primitive::Boolean(b)), ColumnKind::Boolean) => {
+            match (dialect, b) {
+                (Dialect::Sqlite, true) => "1",
+                (Dialect::Sqlite, false) => "0",
+                (_, true) => "TRUE",
+                (_, false) => "FALSE",
+            }
+            .to_string()
+        }
+        (UntaggedValue::Primitive(Primitive::Date(date)), ColumnKind::Timestamp) => {
+            match dialect {
+                // DATETIME has no time zone, so MySQL gets the time in UTC
+                Dialect::Mysql => quote_string(
+                    &date.naive_utc().format("%Y-%m-%d %H:%M:%S%.6f").to_string(),
+                    dialect,
+                ),
+                Dialect::Sqlite | Dialect::Postgres => quote_string(&date.to_rfc3339(), dialect),
+            }
+        }
+        (UntaggedValue::Primitive(Primitive::Binary(bytes)), ColumnKind::Blob) => {
+            let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+            match dialect {
+                Dialect::Postgres => format!("'\\x{}'", hex),
+                Dialect::Sqlite | Dialect::Mysql => format!("X'{}'", hex),
+            }
+        }
+        (UntaggedValue::Row(_), _) | (UntaggedValue::Table(_), _) => {
+            let json = value_to_json_value(value)?;
+            let json = serde_json::to_string(&json).map_err(|e| {
+                ShellError::labeled_error("Could not convert to JSON", e.to_string(), &value.tag)
+            })?;
+            quote_string(&json, dialect)
+        }
+        (UntaggedValue::Primitive(Primitive::Date(date)), _) => {
+            quote_string(&date.to_rfc3339(), dialect)
+        }
+        _ => quote_string(&value.convert_to_string(), dialect),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::ToSql;
+    use super::{merge_kinds, quote_identifier, quote_string, ColumnKind, Dialect};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(ToSql {})
+    }
+
+    #[test]
+    fn quotes_for_each_dialect() {
+        assert_eq!(quote_identifier("a\"b", Dialect::Sqlite), "\"a\"\"b\"");
+        assert_eq!(quote_identifier("a`b", Dialect::Mysql), "`a``b`");
+        assert_eq!(quote_string("it's", Dialect::Postgres), "'it''s'");
+        assert_eq!(quote_string("a\\b", Dialect::Postgres), "'a\\b'");
+        assert_eq!(quote_string("a\\b", Dialect::Mysql), "'a\\\\b'");
+    }
+
+    #[test]
+    fn mixed_columns_widen() {
+        assert_eq!(
+            merge_kinds(Some(ColumnKind::Integer), ColumnKind::Real),
+            ColumnKind::Real
+        );
+        assert_eq!(
+            merge_kinds(Some(ColumnKind::Integer), ColumnKind::Text),
+            ColumnKind::Text
+        );
+        assert_eq!(merge_kinds(None, ColumnKind::Blob), ColumnKind::Blob);
+    }
+}
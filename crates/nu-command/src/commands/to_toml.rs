@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
 use nu_engine::WholeStreamCommand;
 use nu_errors::{CoerceInto, ShellError};
 use nu_protocol::{Primitive, ReturnSuccess, Signature, UnspannedPathMember, UntaggedValue, Value};
@@ -87,6 +88,8 @@ fn helper(v: &Value) -> Result<toml::Value, ShellError> {
         UntaggedValue::Table(l) => toml::Value::Array(collect_values(l)?),
         UntaggedValue::Error(e) => return Err(e.clone()),
         UntaggedValue::Block(_) => toml::Value::String("<Block>".to_string()),
+        #[cfg(feature = "dataframe")]
+        UntaggedValue::DataFrame(df) => toml::Value::Array(collect_values(&df.to_rows(&v.tag)?)?),
         UntaggedValue::Primitive(Primitive::Range(_)) => toml::Value::String("<Range>".to_string()),
         UntaggedValue::Primitive(Primitive::Binary(b)) => {
             toml::Value::Array(b.iter().map(|x| toml::Value::Integer(*x as i64)).collect())
@@ -144,7 +147,8 @@ async fn to_toml(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once().await?;
     let name_tag = args.name_tag();
     let name_span = name_tag.span;
-    let input: Vec<Value> = args.input.collect().await;
+    let scope = args.scope.clone();
+    let input: Vec<Value> = collect_within_limit(args.input, "to toml", &scope, &name_tag).await?;
 
     let to_process_input = match input.len() {
         x if x > 1 => {
@@ -36,9 +36,10 @@ impl WholeStreamCommand for ToTsv {
 
 async fn to_tsv(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
     let (ToTsvArgs { noheaders }, input) = args.process().await?;
 
-    to_delimited_data(noheaders, '\t', "TSV", input, name).await
+    to_delimited_data(noheaders, '\t', "TSV", input, name, scope).await
 }
 
 #[cfg(test)]
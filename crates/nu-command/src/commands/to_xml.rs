@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
 use indexmap::IndexMap;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
@@ -135,8 +136,9 @@ pub fn write_xml_events<W: Write>(
 async fn to_xml(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_tag = args.call_info.name_tag.clone();
     let name_span = name_tag.span;
+    let scope = args.scope.clone();
     let (ToXmlArgs { pretty }, input) = args.process().await?;
-    let input: Vec<Value> = input.collect().await;
+    let input: Vec<Value> = collect_within_limit(input, "to xml", &scope, &name_tag).await?;
 
     let to_process_input = match input.len() {
         x if x > 1 => {
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
 use nu_engine::WholeStreamCommand;
 use nu_errors::{CoerceInto, ShellError};
 use nu_protocol::{Primitive, ReturnSuccess, Signature, UnspannedPathMember, UntaggedValue, Value};
@@ -96,6 +97,16 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
         UntaggedValue::Block(_) | UntaggedValue::Primitive(Primitive::Range(_)) => {
             serde_yaml::Value::Null
         }
+        #[cfg(feature = "dataframe")]
+        UntaggedValue::DataFrame(df) => {
+            let mut out = vec![];
+
+            for value in df.to_rows(&v.tag)? {
+                out.push(value_to_yaml_value(&value)?);
+            }
+
+            serde_yaml::Value::Sequence(out)
+        }
         UntaggedValue::Primitive(Primitive::Binary(b)) => serde_yaml::Value::Sequence(
             b.iter()
                 .map(|x| serde_yaml::Value::Number(serde_yaml::Number::from(*x)))
@@ -118,8 +129,9 @@ async fn to_yaml(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once().await?;
     let name_tag = args.name_tag();
     let name_span = name_tag.span;
+    let scope = args.scope.clone();
 
-    let input: Vec<Value> = args.input.collect().await;
+    let input: Vec<Value> = collect_within_limit(args.input, "to yaml", &scope, &name_tag).await?;
 
     let to_process_input = match input.len() {
         x if x > 1 => {
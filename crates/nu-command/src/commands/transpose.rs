@@ -0,0 +1,235 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    merge_descriptors, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+};
+use nu_source::{SpannedItem, Tagged};
+use nu_value_ext::ValueExt;
+
+pub struct Transpose;
+
+#[derive(Deserialize)]
+pub struct TransposeArgs {
+    rest: Vec<Tagged<String>>,
+    #[serde(rename(deserialize = "header-row"))]
+    header_row: bool,
+    #[serde(rename(deserialize = "ignore-titles"))]
+    ignore_titles: bool,
+    #[serde(rename(deserialize = "key-column"))]
+    key_column: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "skip-incomplete"))]
+    skip_incomplete: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Transpose {
+    fn name(&self) -> &str {
+        "transpose"
+    }
+
+    fn signature(&self) -> Signature {
+        signature("transpose")
+    }
+
+    fn usage(&self) -> &str {
+        "Transposes the table contents so rows become columns and columns become rows."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        transpose(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Turn a record into a table of its keys and values",
+                example: "echo [[name stars]; [nu 5]] | transpose --key-column field",
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "field".to_string() => UntaggedValue::string("name").into(),
+                        "Column1".to_string() => UntaggedValue::string("nu").into(),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "field".to_string() => UntaggedValue::string("stars").into(),
+                        "Column1".to_string() => UntaggedValue::int(5).into(),
+                    })
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Use the values of the first column as the new column names",
+                example: "echo [[key value]; [name nu] [stars 5]] | transpose --header-row",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "name".to_string() => UntaggedValue::string("nu").into(),
+                    "stars".to_string() => UntaggedValue::int(5).into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Leave out the columns that only some of the rows have",
+                example: "ls | transpose --skip-incomplete",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub(crate) fn signature(name: &str) -> Signature {
+    Signature::build(name)
+        .switch(
+            "header-row",
+            "treat the first row as column names",
+            Some('r'),
+        )
+        .switch(
+            "ignore-titles",
+            "don't transpose the column names into values",
+            Some('i'),
+        )
+        .named(
+            "key-column",
+            SyntaxShape::String,
+            "the name of the column the column names are transposed into",
+            Some('k'),
+        )
+        .switch(
+            "skip-incomplete",
+            "leave out the columns that not every row has, instead of filling them with nothing",
+            Some('s'),
+        )
+        .rest(
+            SyntaxShape::String,
+            "the names to give columns once transposed",
+        )
+}
+
+pub async fn transpose(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (args, input): (TransposeArgs, _) = args.process().await?;
+    let input = input.into_vec().await;
+
+    let descs = merge_descriptors(&input);
+
+    if !args.rest.is_empty() && args.header_row {
+        return Err(ShellError::labeled_error(
+            "Can not provide header names and use header row",
+            "using header row",
+            name,
+        ));
+    }
+
+    // The key column holds the column names, so it's there unless they're ignored or used as headers
+    let with_key = !args.ignore_titles && (!args.header_row || args.key_column.is_some());
+
+    let mut headers: Vec<String> = vec![];
+    let mut rest = args.rest.iter().map(|name| name.to_string());
+
+    if with_key {
+        headers.push(match &args.key_column {
+            Some(key_column) => key_column.to_string(),
+            None => rest.next().unwrap_or_else(|| "Column0".to_string()),
+        });
+    }
+
+    if args.header_row {
+        for i in input.clone() {
+            if let Some(desc) = descs.get(0) {
+                match &i.get_data_by_key(desc[..].spanned_unknown()) {
+                    Some(x) => {
+                        if let Ok(s) = x.as_string() {
+                            headers.push(s.to_string());
+                        } else {
+                            return Err(ShellError::labeled_error(
+                                "Header row needs string headers",
+                                "used non-string headers",
+                                name,
+                            ));
+                        }
+                    }
+                    _ => {
+                        return Err(ShellError::labeled_error(
+                            "Header row is incomplete and can't be used",
+                            "using incomplete header row",
+                            name,
+                        ));
+                    }
+                }
+            } else {
+                return Err(ShellError::labeled_error(
+                    "Header row is incomplete and can't be used",
+                    "using incomplete header row",
+                    name,
+                ));
+            }
+        }
+    } else {
+        for _ in 0..input.len() {
+            let header = rest
+                .next()
+                .unwrap_or_else(|| format!("Column{}", headers.len()));
+            headers.push(header);
+        }
+    }
+
+    let descs: Vec<_> = if args.header_row {
+        descs.into_iter().skip(1).collect()
+    } else {
+        descs
+    };
+
+    let descs: Vec<_> = if args.skip_incomplete {
+        descs
+            .into_iter()
+            .filter(|desc| {
+                input
+                    .iter()
+                    .all(|i| i.get_data_by_key(desc[..].spanned_unknown()).is_some())
+            })
+            .collect()
+    } else {
+        descs
+    };
+
+    Ok(futures::stream::iter(descs.into_iter().map(move |desc| {
+        let mut column_num: usize = 0;
+        let mut dict = TaggedDictBuilder::new(&name);
+
+        if with_key {
+            dict.insert_untagged(
+                headers[column_num].clone(),
+                UntaggedValue::string(desc.clone()),
+            );
+            column_num += 1
+        }
+
+        for i in input.clone() {
+            match &i.get_data_by_key(desc[..].spanned_unknown()) {
+                Some(x) => {
+                    dict.insert_value(headers[column_num].clone(), x.clone());
+                }
+                _ => {
+                    dict.insert_untagged(headers[column_num].clone(), UntaggedValue::nothing());
+                }
+            }
+            column_num += 1;
+        }
+
+        ReturnSuccess::value(dict.into_value())
+    }))
+    .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::Transpose;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Transpose {})
+    }
+}
@@ -0,0 +1,70 @@
+use crate::commands::set_operations::{set_operation, set_operation_signature, SetOperation};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, UntaggedValue};
+
+pub struct Union;
+
+#[async_trait]
+impl WholeStreamCommand for Union {
+    fn name(&self) -> &str {
+        "union"
+    }
+
+    fn signature(&self) -> Signature {
+        set_operation_signature("union")
+    }
+
+    fn usage(&self) -> &str {
+        "Combine the rows of the input with another list or table, dropping duplicates."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Rows are matched by the given columns, or by the whole row when no columns are given. The
+input's rows come first, followed by the rows of the other side that didn't match any of them.
+With --all nothing is dropped and the two sides are simply appended."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        set_operation(args, SetOperation::Union).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Combine two lists",
+                example: "echo [1 2 3] | union [3 4]",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+            Example {
+                description: "Append two lists, keeping duplicates",
+                example: "echo [1 2] | union [2 3] --all",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::Union;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Union {})
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Signature, UntaggedValue};
@@ -57,10 +58,12 @@ impl WholeStreamCommand for Uniq {
 async fn uniq(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once().await?;
     let should_show_count = args.has("count");
-    let input = args.input;
+    let name = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
+    let input = collect_within_limit(args.input, "uniq", &scope, &name).await?;
     let uniq_values = {
         let mut counter = IndexMap::<nu_protocol::Value, usize>::new();
-        for line in input.into_vec().await {
+        for line in input {
             *counter.entry(line).or_insert(0) += 1;
         }
         counter
@@ -106,6 +109,14 @@ async fn uniq(args: CommandArgs) -> Result<OutputStream, ShellError> {
                         ))
                     }
                     UntaggedValue::Error(_) | UntaggedValue::Block(_) => item.0,
+                    #[cfg(feature = "dataframe")]
+                    UntaggedValue::DataFrame(_) => {
+                        return Err(ShellError::labeled_error(
+                            "uniq -c cannot operate on dataframes.",
+                            "source",
+                            item.0.tag.span,
+                        ))
+                    }
                 }
             };
             values_vec_deque.push_back(value);
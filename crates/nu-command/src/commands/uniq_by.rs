@@ -0,0 +1,259 @@
+use crate::prelude::*;
+use crate::utils::memory_limit::collect_within_limit;
+use crate::utils::suggestions::suggestions;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_value_ext::as_string;
+use parking_lot::Mutex;
+use std::collections::{HashMap, HashSet};
+
+pub struct UniqBy;
+
+#[derive(Deserialize)]
+pub struct UniqByArgs {
+    key: Value,
+    rest: Vec<Value>,
+    last: bool,
+    count: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for UniqBy {
+    fn name(&self) -> &str {
+        "uniq-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("uniq-by")
+            .required(
+                "key",
+                SyntaxShape::Any,
+                "the column, or the block that gives the value, the rows have to differ in",
+            )
+            .rest(SyntaxShape::Any, "more columns or blocks")
+            .switch(
+                "last",
+                "keep the last row of each key instead of the first",
+                Some('l'),
+            )
+            .switch(
+                "count",
+                "add a count column with how many rows had the key",
+                Some('c'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Return the rows with unique values in the given columns."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Rows with the same values in all of the columns, or from all of the blocks, as a row before them
+are left out. Without --last or --count the rows stream through, keeping only the keys seen."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        uniq_by(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Keep the first row of each language",
+                example: "echo [[name lang]; [nu rust] [jq c] [ripgrep rust]] | uniq-by lang",
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "name".to_string() => UntaggedValue::string("nu").into(),
+                        "lang".to_string() => UntaggedValue::string("rust").into(),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "name".to_string() => UntaggedValue::string("jq").into(),
+                        "lang".to_string() => UntaggedValue::string("c").into(),
+                    })
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Keep the last row of each language, with how many there were",
+                example: "echo [[name lang]; [nu rust] [jq c] [ripgrep rust]] | uniq-by lang --last --count",
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "name".to_string() => UntaggedValue::string("jq").into(),
+                        "lang".to_string() => UntaggedValue::string("c").into(),
+                        "count".to_string() => UntaggedValue::int(1).into(),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "name".to_string() => UntaggedValue::string("ripgrep").into(),
+                        "lang".to_string() => UntaggedValue::string("rust").into(),
+                        "count".to_string() => UntaggedValue::int(2).into(),
+                    })
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Keep one file of each extension",
+                example: "ls | uniq-by { get name | path extension }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn uniq_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let context = Arc::new(EvaluationContext::from_args(&args));
+    let (
+        UniqByArgs {
+            key,
+            rest,
+            last,
+            count,
+        },
+        input,
+    ) = args.process().await?;
+
+    let keys = Arc::new(std::iter::once(key).chain(rest).collect::<Vec<_>>());
+
+    if !last && !count {
+        let seen = Arc::new(Mutex::new(HashSet::new()));
+
+        return Ok(input
+            .then(move |value| {
+                let keys = keys.clone();
+                let context = context.clone();
+                let seen = seen.clone();
+
+                async move {
+                    match key_of(&value, &keys, &context).await {
+                        Ok(key) => {
+                            if seen.lock().insert(key) {
+                                vec![ReturnSuccess::value(value)]
+                            } else {
+                                vec![]
+                            }
+                        }
+                        Err(err) => vec![Err(err)],
+                    }
+                }
+            })
+            .map(futures::stream::iter)
+            .flatten()
+            .to_output_stream());
+    }
+
+    let values = collect_within_limit(input, "uniq-by", &context.scope, &name).await?;
+
+    let mut row_keys = vec![];
+    for value in &values {
+        row_keys.push(key_of(value, &keys, &context).await?);
+    }
+
+    // The row kept for each key, and how many rows had it
+    let mut kept: HashMap<&Vec<UntaggedValue>, (usize, usize)> = HashMap::new();
+    for (index, key) in row_keys.iter().enumerate() {
+        let entry = kept.entry(key).or_insert((index, 0));
+        if last {
+            entry.0 = index;
+        }
+        entry.1 += 1;
+    }
+
+    let mut kept = kept.values().cloned().collect::<Vec<_>>();
+    kept.sort_unstable();
+
+    let values = kept
+        .into_iter()
+        .map(|(index, total)| {
+            let value = values[index].clone();
+
+            if count {
+                with_count(value, total)
+            } else {
+                Ok(value)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(futures::stream::iter(values.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+/// The values of the columns, or from the blocks, the row is told apart by
+async fn key_of(
+    value: &Value,
+    keys: &[Value],
+    context: &Arc<EvaluationContext>,
+) -> Result<Vec<UntaggedValue>, ShellError> {
+    let mut key = vec![];
+
+    for grouper in keys {
+        match &grouper.value {
+            UntaggedValue::Block(block) => {
+                let block = Arc::new(block.clone());
+                let mut stream =
+                    crate::commands::each::process_row(block, context.clone(), value.clone())
+                        .await?;
+
+                let mut output = vec![];
+                for result in stream.drain_vec().await {
+                    if let Some(value) = result?.raw_value() {
+                        output.push(value.value);
+                    }
+                }
+
+                key.push(if output.len() == 1 {
+                    output.remove(0)
+                } else {
+                    UntaggedValue::Table(output.into_iter().map(Value::from).collect())
+                });
+            }
+            _ => {
+                let column = as_string(grouper)?.tagged(&grouper.tag);
+
+                match value.get_data_by_key(column.borrow_spanned()) {
+                    Some(found) => key.push(found.value),
+                    None => return Err(suggestions(column.borrow_tagged(), value)),
+                }
+            }
+        }
+    }
+
+    Ok(key)
+}
+
+/// The row with a count column, or a row of the value and its count for values that aren't rows
+fn with_count(value: Value, total: usize) -> Result<Value, ShellError> {
+    let count = UntaggedValue::int(total).into_untagged_value();
+
+    match value.value {
+        UntaggedValue::Row(mut row) => {
+            row.entries.insert("count".to_string(), count);
+            Ok(UntaggedValue::Row(row).into_value(value.tag))
+        }
+        UntaggedValue::Table(_) => Err(ShellError::labeled_error(
+            "uniq-by --count cannot operate on tables.",
+            "source",
+            value.tag.span,
+        )),
+        other => Ok(UntaggedValue::row(indexmap! {
+            "value".to_string() => other.into_untagged_value(),
+            "count".to_string() => count,
+        })
+        .into_value(value.tag)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::UniqBy;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(UniqBy {})
+    }
+}
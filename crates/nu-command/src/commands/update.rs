@@ -88,6 +88,9 @@ async fn process_row(
             context.scope.enter_scope();
             context.scope.add_var("$it", input.clone());
             context.scope.add_vars(&captured_block.captured.entries);
+            if let Some((param, _)) = captured_block.block.params.positional.first() {
+                context.scope.add_var(param.name(), input.clone());
+            }
 
             let result = run_block(&captured_block.block, &*context, input_stream).await;
 
@@ -0,0 +1,143 @@
+use crate::prelude::*;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_value_ext::ValueExt;
+
+use futures::stream::once;
+
+pub struct Command;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    field: ColumnPath,
+    value: Value,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "upsert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("upsert")
+            .required(
+                "field",
+                SyntaxShape::ColumnPath,
+                "the name of the column to update or insert",
+            )
+            .required("value", SyntaxShape::Any, "the value to give the cell(s)")
+    }
+
+    fn usage(&self) -> &str {
+        "Update a column to have a new value, inserting the column if it doesn't exist."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        upsert(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Update a column value",
+            example: "echo [[name, stars]; ['nu', 5]] | upsert name 'Nushell'",
+            result: Some(vec![UntaggedValue::row(indexmap! {
+                    "name".to_string() => Value::from("Nushell"),
+                    "stars".to_string() => UntaggedValue::int(5).into(),
+            })
+            .into()]),
+        }, Example {
+            description: "Insert a column that isn't there",
+            example: "echo [[name, stars]; ['nu', 5]] | upsert language 'Rust'",
+            result: Some(vec![UntaggedValue::row(indexmap! {
+                    "name".to_string() => Value::from("nu"),
+                    "stars".to_string() => UntaggedValue::int(5).into(),
+                    "language".to_string() => Value::from("Rust"),
+            })
+            .into()]),
+        }, Example {
+            description: "Use in block form to compute the value from the row",
+            example: "echo [[author, lucky_number]; ['Yehuda', 4]] | upsert lucky_number { = $it.lucky_number * 10 }",
+            result: Some(vec![UntaggedValue::row(indexmap! {
+                    "author".to_string() => Value::from("Yehuda"),
+                    "lucky_number".to_string() => UntaggedValue::int(40).into(),
+            })
+            .into()]),
+        }]
+    }
+}
+
+async fn process_row(
+    context: Arc<EvaluationContext>,
+    input: Value,
+    value: Arc<Value>,
+    field: Arc<ColumnPath>,
+) -> Result<OutputStream, ShellError> {
+    let value = match &*value {
+        Value {
+            value: UntaggedValue::Block(captured_block),
+            ..
+        } => {
+            let for_block = input.clone();
+            let input_stream = once(async { Ok(for_block) }).to_input_stream();
+
+            context.scope.enter_scope();
+            context.scope.add_vars(&captured_block.captured.entries);
+            context.scope.add_var("$it", input.clone());
+            if let Some((param, _)) = captured_block.block.params.positional.first() {
+                context.scope.add_var(param.name(), input.clone());
+            }
+
+            let result = run_block(&captured_block.block, &*context, input_stream).await;
+
+            context.scope.exit_scope();
+
+            let values = result?.drain_vec().await;
+
+            let errors = context.get_errors();
+            if let Some(error) = errors.first() {
+                return Err(error.clone());
+            }
+
+            match values.len() {
+                0 => UntaggedValue::nothing().into_value(&input.tag),
+                1 => values[0].value.clone().into_value(&input.tag),
+                _ => UntaggedValue::table(&values).into_value(&input.tag),
+            }
+        }
+        value => value.clone(),
+    };
+
+    // Updating in place keeps the column where it is, and what a list index points at
+    let upserted = match input.replace_data_at_column_path(&field, value.clone()) {
+        Some(upserted) => upserted,
+        None => input.forgiving_insert_data_at_column_path(&field, value)?,
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(upserted)))
+}
+
+async fn upsert(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = Arc::new(EvaluationContext::from_args(&raw_args));
+    let (Arguments { field, value }, input) = raw_args.process().await?;
+    let value = Arc::new(value);
+    let field = Arc::new(field);
+
+    Ok(input
+        .then(move |input| {
+            let context = context.clone();
+            let value = value.clone();
+            let field = field.clone();
+
+            async {
+                match process_row(context, input, value, field).await {
+                    Ok(s) => s,
+                    Err(e) => OutputStream::one(Err(e)),
+                }
+            }
+        })
+        .flatten()
+        .to_output_stream())
+}
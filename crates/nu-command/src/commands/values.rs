@@ -0,0 +1,114 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    merge_descriptors, ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value,
+};
+
+pub struct Values;
+
+#[async_trait]
+impl WholeStreamCommand for Values {
+    fn name(&self) -> &str {
+        "values"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("values")
+    }
+
+    fn usage(&self) -> &str {
+        "Show the values in the columns of a record or table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "For a record, each of its values is given in the order of its columns. For a table, each column gives a list of its values, with nothing in the place of rows that don't have it."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        values(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the values of a record",
+                example: "echo [[name size]; [nu 5]] | values",
+                result: Some(vec![
+                    UntaggedValue::string("nu").into(),
+                    UntaggedValue::int(5).into(),
+                ]),
+            },
+            Example {
+                description: "Get the values of each column of a table",
+                example: "echo [[name size]; [nu 5] [rust 3]] | values",
+                result: Some(vec![
+                    UntaggedValue::table(&[
+                        UntaggedValue::string("nu").into(),
+                        UntaggedValue::string("rust").into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(5).into(),
+                        UntaggedValue::int(3).into(),
+                    ])
+                    .into(),
+                ]),
+            },
+        ]
+    }
+}
+
+async fn values(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let args = args.evaluate_once().await?;
+    let (input, _args) = args.parts();
+
+    let rows: Vec<Value> = input.collect().await;
+
+    let mut records = vec![];
+    for row in &rows {
+        match &row.value {
+            UntaggedValue::Row(record) => records.push(record),
+            _ => {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "Expected a record or table",
+                    "requires a record or table input",
+                    &tag,
+                    format!("this is {}", row.type_name()),
+                    &row.tag,
+                ))
+            }
+        }
+    }
+
+    let values: Vec<Value> = match records[..] {
+        [record] => record.entries.values().cloned().collect(),
+        _ => merge_descriptors(&rows)
+            .iter()
+            .map(|column| {
+                let column_values: Vec<Value> = records
+                    .iter()
+                    .map(|record| record.get_data(column).borrow().clone())
+                    .collect();
+
+                UntaggedValue::table(&column_values).into_value(&tag)
+            })
+            .collect(),
+    };
+
+    Ok(futures::stream::iter(values.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::Values;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Values {})
+    }
+}
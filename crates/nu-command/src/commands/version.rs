@@ -1,8 +1,9 @@
+use crate::commands::plugin::list::plugin_rows;
 use crate::prelude::*;
 use indexmap::IndexMap;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{value::StrExt, value::StringExt, Dictionary, Signature, UntaggedValue};
+use nu_protocol::{value::StrExt, Dictionary, Signature, TaggedDictBuilder, UntaggedValue, Value};
 
 pub mod shadow {
     include!(concat!(env!("OUT_DIR"), "/shadow.rs"));
@@ -24,16 +25,33 @@ impl WholeStreamCommand for Version {
         "Display Nu version."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Along with the version, the record has the commit and time Nu was built from, the Rust
+toolchain that built it, the features it was built with, and the plugins it has loaded."#
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         version(args)
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Display Nu version",
-            example: "version",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Display Nu version",
+                example: "version",
+                result: None,
+            },
+            Example {
+                description: "Check that Nu was built with the commands for files",
+                example: "version | get features | where $it == filesystem | length",
+                result: None,
+            },
+            Example {
+                description: "List the versions of the plugins",
+                example: "version | get plugins | select name version",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -46,6 +64,7 @@ pub fn version(args: CommandArgs) -> Result<OutputStream, ShellError> {
         "version".to_string(),
         UntaggedValue::string(clap::crate_version!()).into_value(&tag),
     );
+    indexmap.insert("semver".to_string(), semver(&tag));
 
     let branch: Option<&str> = Some(shadow::BRANCH).filter(|x| !x.is_empty());
     if let Some(branch) = branch {
@@ -138,15 +157,83 @@ pub fn version(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     indexmap.insert(
         "features".to_string(),
-        features_enabled().join(", ").to_string_value_create_tag(),
+        UntaggedValue::Table(
+            features_enabled()
+                .into_iter()
+                .map(|feature| UntaggedValue::string(feature).into_value(&tag))
+                .collect(),
+        )
+        .into_value(&tag),
+    );
+
+    indexmap.insert(
+        "plugins".to_string(),
+        UntaggedValue::Table(plugin_rows(&args.scope, &args.call_info.name_tag)).into_value(&tag),
     );
 
     let value = UntaggedValue::Row(Dictionary::from(indexmap)).into_value(&tag);
     Ok(OutputStream::one(value))
 }
 
+/// The parts of the version, to compare them in scripts
+fn semver(tag: impl Into<Tag>) -> Value {
+    let number = |part: &str| UntaggedValue::int(part.parse::<u64>().unwrap_or_default());
+
+    let mut row = TaggedDictBuilder::new(tag);
+    row.insert_untagged("major", number(env!("CARGO_PKG_VERSION_MAJOR")));
+    row.insert_untagged("minor", number(env!("CARGO_PKG_VERSION_MINOR")));
+    row.insert_untagged("patch", number(env!("CARGO_PKG_VERSION_PATCH")));
+    row.insert_untagged(
+        "pre",
+        match env!("CARGO_PKG_VERSION_PRE") {
+            "" => UntaggedValue::nothing(),
+            pre => UntaggedValue::string(pre),
+        },
+    );
+
+    row.into_value()
+}
+
 fn features_enabled() -> Vec<String> {
-    let mut names = vec!["default".to_string()];
+    let mut names = vec![];
+
+    // Builds with all the groups of commands have the default set, slim builds only some of them
+    #[cfg(all(
+        feature = "core",
+        feature = "filesystem",
+        feature = "network",
+        feature = "formats",
+        feature = "extra"
+    ))]
+    {
+        names.push("default".to_string());
+    }
+
+    // The groups of commands built in
+    #[cfg(feature = "core")]
+    {
+        names.push("core".to_string());
+    }
+
+    #[cfg(feature = "filesystem")]
+    {
+        names.push("filesystem".to_string());
+    }
+
+    #[cfg(feature = "network")]
+    {
+        names.push("network".to_string());
+    }
+
+    #[cfg(feature = "formats")]
+    {
+        names.push("formats".to_string());
+    }
+
+    #[cfg(feature = "extra")]
+    {
+        names.push("extra".to_string());
+    }
 
     #[cfg(feature = "ctrlc")]
     {
@@ -213,6 +300,36 @@ fn features_enabled() -> Vec<String> {
         names.push("trash".to_string());
     }
 
+    #[cfg(feature = "extra-formats")]
+    {
+        names.push("extra-formats".to_string());
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        names.push("sqlite".to_string());
+    }
+
+    #[cfg(feature = "database")]
+    {
+        names.push("database".to_string());
+    }
+
+    #[cfg(feature = "mqtt")]
+    {
+        names.push("mqtt".to_string());
+    }
+
+    #[cfg(feature = "nats")]
+    {
+        names.push("nats".to_string());
+    }
+
+    #[cfg(feature = "redis")]
+    {
+        names.push("redis".to_string());
+    }
+
     // #[cfg(feature = "binaryview")]
     // {
     //     names.push("binaryview".to_string());
@@ -0,0 +1,308 @@
+use crate::futures::ThreadedReceiver;
+use crate::prelude::*;
+use chrono::{DateTime, Local};
+use futures::stream::once;
+use notify::{DebouncedEvent, RecursiveMode, Watcher};
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::CapturedBlock, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::time::Duration;
+
+const DEFAULT_DEBOUNCE_MS: u64 = 100;
+
+pub struct Watch;
+
+#[derive(Deserialize)]
+pub struct WatchArgs {
+    path: Tagged<PathBuf>,
+    block: Option<CapturedBlock>,
+    glob: Option<Tagged<String>>,
+    #[serde(rename = "debounce-ms")]
+    debounce_ms: Option<Tagged<u64>>,
+    count: Option<Tagged<usize>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Watch {
+    fn name(&self) -> &str {
+        "watch"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("watch")
+            .required(
+                "path",
+                SyntaxShape::FilePath,
+                "the file, or the directory and everything below it, to watch",
+            )
+            .optional(
+                "block",
+                SyntaxShape::Block,
+                "the block to run on each change, eg {|op, path| ... }",
+            )
+            .named(
+                "glob",
+                SyntaxShape::String,
+                "only the changes to the paths matching this pattern, eg '*.rs'",
+                Some('g'),
+            )
+            .named(
+                "debounce-ms",
+                SyntaxShape::Int,
+                "how long to wait for a path to settle before reporting its change (default 100)",
+                Some('d'),
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "stop after this many changes",
+                Some('c'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Stream the changes to the files in a directory."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each change becomes a row with its op (create, write, remove, rename or chmod), the path
+changed and the time it was seen. Changes made in quick succession to the same path are reported
+once. With a block, the block is run on each change instead, with the op and path as its
+parameters or the row as $it. Without --count, changes are streamed until interrupted."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        watch(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the changes to the files in the current directory",
+                example: "watch .",
+                result: None,
+            },
+            Example {
+                description: "Run the tests whenever a rust file changes",
+                example: "watch ./src --glob '*.rs' { cargo test }",
+                result: None,
+            },
+            Example {
+                description: "Describe each change with the block parameters",
+                example: "watch . {|op, path| echo `{{$op}} {{$path}}` }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn watch(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = Arc::new(EvaluationContext::from_args(&raw_args));
+    let ctrl_c = raw_args.ctrl_c.clone();
+    let name_tag = raw_args.call_info.name_tag.clone();
+    let (
+        WatchArgs {
+            path,
+            block,
+            glob,
+            debounce_ms,
+            count,
+        },
+        _,
+    ) = raw_args.process().await?;
+
+    if let Some(Tagged { item: 0, .. }) = count {
+        return Ok(OutputStream::empty());
+    }
+
+    let root = path.item.canonicalize().map_err(|e| {
+        ShellError::labeled_error(
+            format!("Cannot watch {}: {}", path.item.display(), e),
+            "cannot watch this path",
+            &path.tag,
+        )
+    })?;
+
+    let pattern = glob
+        .map(|glob| {
+            glob::Pattern::new(&glob.item)
+                .map_err(|e| ShellError::labeled_error(e.to_string(), "invalid pattern", &glob.tag))
+        })
+        .transpose()?;
+
+    let delay = Duration::from_millis(
+        debounce_ms
+            .map(|debounce| debounce.item)
+            .unwrap_or(DEFAULT_DEBOUNCE_MS),
+    );
+
+    let (events_tx, events_rx) = mpsc::channel();
+    let mut watcher = notify::watcher(events_tx, delay).map_err(|e| watch_error(e, &name_tag))?;
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .map_err(|e| watch_error(e, &path.tag))?;
+
+    // The watcher hands over the changes on a channel, which is read on its own thread so it can
+    // stop on ctrl-c while no changes are coming in
+    let (tx, rx) = mpsc::sync_channel(0);
+    let thread_tag = name_tag.clone();
+
+    std::thread::spawn(move || {
+        // The watcher stops when it's dropped, so it lives as long as the thread
+        let _watcher = watcher;
+        let mut remaining = count.map(|count| count.item).unwrap_or(usize::MAX);
+
+        while !ctrl_c.load(Ordering::SeqCst) {
+            let event = match events_rx.recv_timeout(Duration::from_millis(100)) {
+                Ok(event) => event,
+                Err(mpsc::RecvTimeoutError::Timeout) => continue,
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            };
+
+            let change = match event {
+                DebouncedEvent::Create(path) => ("create", path),
+                DebouncedEvent::Write(path) => ("write", path),
+                DebouncedEvent::Remove(path) => ("remove", path),
+                DebouncedEvent::Rename(_, path) => ("rename", path),
+                DebouncedEvent::Chmod(path) => ("chmod", path),
+                DebouncedEvent::Error(e, _) => {
+                    if tx.send(Err(watch_error(e, &thread_tag))).is_err() {
+                        break;
+                    }
+                    continue;
+                }
+                // The changes before a path settles are reported once it has
+                _ => continue,
+            };
+
+            if !is_watched(&pattern, &root, &change.1) {
+                continue;
+            }
+
+            remaining -= 1;
+            if tx.send(Ok(change_to_value(change, &thread_tag))).is_err() || remaining == 0 {
+                // Either nobody is reading the rest of the changes anymore, or we have all the
+                // changes we were asked for
+                break;
+            }
+        }
+    });
+
+    let changes = ThreadedReceiver::new(rx);
+
+    let block = match block {
+        Some(block) => Arc::new(block),
+        None => {
+            return Ok(changes
+                .map(|change: Result<Value, ShellError>| change.map(ReturnSuccess::Value))
+                .to_output_stream())
+        }
+    };
+
+    Ok(changes
+        .then(move |change| {
+            let block = block.clone();
+            let context = context.clone();
+
+            async {
+                match change {
+                    Ok(change) => match process_change(block, context, change).await {
+                        Ok(s) => s,
+                        Err(e) => OutputStream::one(Err(e)),
+                    },
+                    Err(e) => OutputStream::one(Err(e)),
+                }
+            }
+        })
+        .flatten()
+        .to_output_stream())
+}
+
+/// Whether the path, relative to the watched directory, matches the pattern given with --glob
+fn is_watched(pattern: &Option<glob::Pattern>, root: &Path, path: &Path) -> bool {
+    match pattern {
+        Some(pattern) => {
+            let relative = path.strip_prefix(root).unwrap_or(path);
+
+            pattern.matches_path(relative)
+                || relative
+                    .file_name()
+                    .map(|name| pattern.matches(&name.to_string_lossy()))
+                    .unwrap_or(false)
+        }
+        None => true,
+    }
+}
+
+fn change_to_value((op, path): (&str, PathBuf), tag: &Tag) -> Value {
+    let seen: DateTime<Local> = Local::now();
+    let mut dict = TaggedDictBuilder::new(tag);
+
+    dict.insert_untagged("op", UntaggedValue::string(op));
+    dict.insert_untagged("path", UntaggedValue::filepath(path));
+    dict.insert_untagged(
+        "time",
+        UntaggedValue::date(seen.with_timezone(seen.offset())),
+    );
+
+    dict.into_value()
+}
+
+async fn process_change(
+    block: Arc<CapturedBlock>,
+    context: Arc<EvaluationContext>,
+    change: Value,
+) -> Result<OutputStream, ShellError> {
+    let input_stream = if block.block.params.positional.is_empty() {
+        let change = change.clone();
+        once(async { Ok(change) }).to_input_stream()
+    } else {
+        InputStream::empty()
+    };
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+
+    let params = block.block.params.positional.iter();
+    for ((param, _), column) in params.zip(&["op", "path"]) {
+        if let Some(value) = change.get_data_by_key(column.spanned_unknown()) {
+            context.scope.add_var(param.name(), value);
+        }
+    }
+    context.scope.add_var("$it", change);
+
+    let result = run_block(&block.block, &*context, input_stream).await;
+    context.scope.exit_scope();
+
+    Ok(result?.to_output_stream())
+}
+
+fn watch_error(error: notify::Error, tag: &Tag) -> ShellError {
+    ShellError::labeled_error(
+        format!("Could not watch for changes: {}", error),
+        "could not watch for changes",
+        tag,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::Watch;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Watch {})
+    }
+}
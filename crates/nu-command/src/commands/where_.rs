@@ -31,6 +31,10 @@ impl WholeStreamCommand for Where {
         "Filter table to match the condition."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Bare words on the left of a comparison are columns of the current row, and conditions can be combined with && and ||, eg where size > 10kb && type == Dir. A block like { $it.size > 10kb } works too."
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         where_command(args).await
     }
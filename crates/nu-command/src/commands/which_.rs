@@ -5,6 +5,7 @@ use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
+use std::path::PathBuf;
 
 pub struct Which;
 
@@ -18,46 +19,88 @@ impl WholeStreamCommand for Which {
         Signature::build("which")
             .required("application", SyntaxShape::String, "application")
             .rest(SyntaxShape::String, "additional applications")
-            .switch("all", "list all executables", Some('a'))
+            .switch(
+                "all",
+                "list everything the name could run, not just what it runs",
+                Some('a'),
+            )
     }
 
     fn usage(&self) -> &str {
         "Finds a program file, alias or custom command."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Each row has the kind of what was found (alias, custom, builtin, plugin or external), its
+path and whether it shadows anything else with the same name. Aliases are tried first, then
+custom commands, built-in and plugin commands, and finally the executables on the PATH. Prefix the
+name with ^ to only look on the PATH."#
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         which(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find out what ls runs",
+                example: "which ls",
+                result: None,
+            },
+            Example {
+                description: "List everything ls could run, with what shadows what",
+                example: "which -a ls",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// One of the things a name can run, in the order they're tried: aliases, custom commands,
+/// built-in and plugin commands, then the executables on the PATH
+struct Resolution {
+    kind: &'static str,
+    path: UntaggedValue,
+}
+
+impl Resolution {
+    fn new(kind: &'static str, path: impl Into<String>) -> Resolution {
+        Resolution {
+            kind,
+            path: UntaggedValue::string(path),
+        }
+    }
+
+    fn file(kind: &'static str, path: impl Into<PathBuf>) -> Resolution {
+        Resolution {
+            kind,
+            path: UntaggedValue::filepath(path),
+        }
+    }
 }
 
 /// Shortcuts for creating an entry to the output table
-fn entry(arg: impl Into<String>, path: Value, builtin: bool, tag: Tag) -> Value {
+fn entry(arg: impl Into<String>, resolution: Resolution, shadows: bool, tag: Tag) -> Value {
     let mut map = IndexMap::new();
     map.insert(
         "arg".to_string(),
         UntaggedValue::Primitive(Primitive::String(arg.into())).into_value(tag.clone()),
     );
-    map.insert("path".to_string(), path);
     map.insert(
-        "builtin".to_string(),
-        UntaggedValue::boolean(builtin).into_value(tag.clone()),
+        "kind".to_string(),
+        UntaggedValue::string(resolution.kind).into_value(tag.clone()),
+    );
+    map.insert("path".to_string(), resolution.path.into_value(tag.clone()));
+    map.insert(
+        "shadows".to_string(),
+        UntaggedValue::boolean(shadows).into_value(tag.clone()),
     );
 
     UntaggedValue::row(map).into_value(tag)
 }
 
-macro_rules! create_entry {
-    ($arg:expr, $path:expr, $tag:expr, $is_builtin:expr) => {
-        entry(
-            $arg.clone(),
-            UntaggedValue::Primitive(Primitive::String($path.to_string())).into_value($tag.clone()),
-            $is_builtin,
-            $tag,
-        )
-    };
-}
-
-fn get_entries_in_aliases(scope: &Scope, name: &str, tag: Tag) -> Vec<Value> {
+fn get_entries_in_aliases(scope: &Scope, name: &str) -> Vec<Resolution> {
     let aliases = scope
         .get_aliases_with_name(name)
         .unwrap_or_default()
@@ -69,98 +112,56 @@ fn get_entries_in_aliases(scope: &Scope, name: &str, tag: Tag) -> Vec<Value> {
                 .collect::<Vec<String>>()
                 .join(" ")
         })
-        .map(|alias| {
-            create_entry!(
-                name,
-                format!("Nushell alias: {}", alias),
-                tag.clone(),
-                false
-            )
-        })
+        .map(|alias| Resolution::new("alias", format!("Nushell alias: {}", alias)))
         .collect::<Vec<_>>();
     trace!("Found {} aliases", aliases.len());
     aliases
 }
 
-fn get_entries_in_custom_command(scope: &Scope, name: &str, tag: Tag) -> Vec<Value> {
+fn get_entries_in_custom_command(scope: &Scope, name: &str) -> Vec<Resolution> {
     scope
         .get_custom_commands_with_name(name)
         .unwrap_or_default()
         .into_iter()
-        .map(|_| create_entry!(name, "Nushell custom command", tag.clone(), false))
+        .map(|_| Resolution::new("custom", "Nushell custom command"))
         .collect()
 }
 
-fn get_entry_in_commands(scope: &Scope, name: &str, tag: Tag) -> Option<Value> {
-    if scope.has_command(name) {
-        Some(create_entry!(name, "Nushell built-in command", tag, true))
-    } else {
-        None
-    }
+fn get_entries_in_commands(scope: &Scope, name: &str) -> Vec<Resolution> {
+    scope
+        .get_commands_with_name(name)
+        .into_iter()
+        // Custom commands are found with their blocks
+        .filter(|command| !command.is_custom())
+        .map(|command| match command.plugin_path() {
+            Some(path) => Resolution::file("plugin", path),
+            None => Resolution::new("builtin", "Nushell built-in command"),
+        })
+        .collect()
 }
 
-fn get_entries_in_nu(
-    scope: &Scope,
-    name: &str,
-    tag: Tag,
-    skip_after_first_found: bool,
-) -> Vec<Value> {
+fn get_entries_in_nu(scope: &Scope, name: &str) -> Vec<Resolution> {
     let mut all_entries = vec![];
 
-    all_entries.extend(get_entries_in_aliases(scope, name, tag.clone()));
-    if !all_entries.is_empty() && skip_after_first_found {
-        return all_entries;
-    }
-
-    all_entries.extend(get_entries_in_custom_command(scope, name, tag.clone()));
-    if !all_entries.is_empty() && skip_after_first_found {
-        return all_entries;
-    }
-
-    if let Some(entry) = get_entry_in_commands(scope, name, tag) {
-        all_entries.push(entry);
-    }
+    all_entries.extend(get_entries_in_aliases(scope, name));
+    all_entries.extend(get_entries_in_custom_command(scope, name));
+    all_entries.extend(get_entries_in_commands(scope, name));
 
     all_entries
 }
 
-#[allow(unused)]
-macro_rules! entry_path {
-    ($arg:expr, $path:expr, $tag:expr) => {
-        entry(
-            $arg.clone(),
-            UntaggedValue::Primitive(Primitive::FilePath($path)).into_value($tag.clone()),
-            false,
-            $tag,
-        )
-    };
-}
-
 #[cfg(feature = "ichwh")]
-async fn get_first_entry_in_path(item: &str, tag: Tag) -> Option<Value> {
-    ichwh::which(item)
-        .await
-        .unwrap_or(None)
-        .map(|path| entry_path!(item, path.into(), tag))
-}
-
-#[cfg(not(feature = "ichwh"))]
-async fn get_first_entry_in_path(_: &str, _: Tag) -> Option<Value> {
-    None
-}
-
-#[cfg(feature = "ichwh")]
-async fn get_all_entries_in_path(item: &str, tag: Tag) -> Vec<Value> {
+async fn get_all_entries_in_path(item: &str) -> Vec<Resolution> {
     ichwh::which_all(&item)
         .await
         .unwrap_or_default()
         .into_iter()
-        .map(|path| entry_path!(item, path.into(), tag.clone()))
+        .map(|path| Resolution::file("external", path))
         .collect()
 }
 
 #[cfg(not(feature = "ichwh"))]
-async fn get_all_entries_in_path(_: &str, _: Tag) -> Vec<Value> {
+async fn get_all_entries_in_path(_: &str) -> Vec<Resolution> {
     vec![]
 }
 
@@ -179,43 +180,30 @@ async fn which_single(application: Tagged<String>, all: bool, scope: &Scope) ->
     };
 
     //If prog_name is an external command, don't search for nu-specific programs
-    //If all is false, we can save some time by only searching for the first matching
-    //program
-    //This match handles all different cases
-    match (all, external) {
-        (true, true) => {
-            return get_all_entries_in_path(&prog_name, application.tag.clone()).await;
-        }
-        (true, false) => {
-            let mut output: Vec<Value> = vec![];
-            output.extend(get_entries_in_nu(
-                scope,
+    let mut resolutions = vec![];
+    if !external {
+        resolutions.extend(get_entries_in_nu(scope, &prog_name));
+    }
+    resolutions.extend(get_all_entries_in_path(&prog_name).await);
+
+    // Every resolution hides the ones after it, so without --all only the one that runs is shown,
+    // along with whether it hides any others
+    let count = resolutions.len();
+    let shown = if all { count } else { count.min(1) };
+
+    resolutions
+        .into_iter()
+        .take(shown)
+        .enumerate()
+        .map(|(index, resolution)| {
+            entry(
                 &prog_name,
+                resolution,
+                index + 1 < count,
                 application.tag.clone(),
-                false,
-            ));
-            output.extend(get_all_entries_in_path(&prog_name, application.tag.clone()).await);
-            return output;
-        }
-        (false, true) => {
-            if let Some(entry) = get_first_entry_in_path(&prog_name, application.tag.clone()).await
-            {
-                return vec![entry];
-            }
-            return vec![];
-        }
-        (false, false) => {
-            let nu_entries = get_entries_in_nu(scope, &prog_name, application.tag.clone(), true);
-            if !nu_entries.is_empty() {
-                return vec![nu_entries[0].clone()];
-            } else if let Some(entry) =
-                get_first_entry_in_path(&prog_name, application.tag.clone()).await
-            {
-                return vec![entry];
-            }
-            return vec![];
-        }
-    }
+            )
+        })
+        .collect()
 }
 
 async fn which(args: CommandArgs) -> Result<OutputStream, ShellError> {
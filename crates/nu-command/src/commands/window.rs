@@ -0,0 +1,154 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use std::collections::VecDeque;
+
+pub struct Window;
+
+#[derive(Deserialize)]
+pub struct WindowArgs {
+    size: Tagged<u64>,
+    stride: Option<Tagged<u64>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Window {
+    fn name(&self) -> &str {
+        "window"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("window")
+            .required("size", SyntaxShape::Int, "how many rows each window has")
+            .named(
+                "stride",
+                SyntaxShape::Int,
+                "how many rows each window starts after the one before (default: 1)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Slides a window over the rows, giving a table of the rows in it at each step."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Only full windows are given, so there are none if there are fewer rows than the size. With a
+stride bigger than the size, the rows between the windows are skipped.
+
+Only the rows of the current window are kept, so the input doesn't have to be read in at once."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        window(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Slide a window of two rows over a list",
+                example: "echo [1 2 3 4] | window 2",
+                result: Some(vec![
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(1).into(),
+                        UntaggedValue::int(2).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(2).into(),
+                        UntaggedValue::int(3).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(3).into(),
+                        UntaggedValue::int(4).into(),
+                    ])
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Move the window two rows at a time",
+                example: "echo [1 2 3 4 5] | window 3 --stride 2",
+                result: Some(vec![
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(1).into(),
+                        UntaggedValue::int(2).into(),
+                        UntaggedValue::int(3).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(3).into(),
+                        UntaggedValue::int(4).into(),
+                        UntaggedValue::int(5).into(),
+                    ])
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "The moving average of the closing prices over five days",
+                example: "open prices.csv | get close | window 5 | each { echo $it | math avg }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn window(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (WindowArgs { size, stride }, input) = args.process().await?;
+
+    for arg in std::iter::once(&size).chain(stride.as_ref()) {
+        if arg.item == 0 {
+            return Err(ShellError::labeled_error(
+                "Expected a number bigger than 0",
+                "cannot be 0",
+                &arg.tag,
+            ));
+        }
+    }
+
+    let size = size.item as usize;
+    let stride = stride.map(|stride| stride.item as usize).unwrap_or(1);
+
+    Ok(input
+        .scan(
+            (VecDeque::with_capacity(size), 0),
+            move |(rows, skip): &mut (VecDeque<Value>, usize), value| {
+                // Rows between windows farther apart than their size are never in one
+                if *skip > 0 {
+                    *skip -= 1;
+                    return futures::future::ready(Some(None));
+                }
+
+                rows.push_back(value);
+                if rows.len() < size {
+                    return futures::future::ready(Some(None));
+                }
+
+                let window = rows.iter().cloned().collect::<Vec<_>>();
+                rows.drain(..stride.min(size));
+                *skip = stride.saturating_sub(size);
+
+                futures::future::ready(Some(Some(ReturnSuccess::value(
+                    UntaggedValue::table(&window).into_value(&name),
+                ))))
+            },
+        )
+        .filter_map(futures::future::ready)
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::Window;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Window {})
+    }
+}
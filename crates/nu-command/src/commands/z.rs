@@ -0,0 +1,175 @@
+use crate::prelude::*;
+use chrono::{Local, TimeZone};
+use nu_data::config::{path::history as history_path, NuConfig};
+use nu_data::frecency::{self, Frecency};
+use nu_engine::shell::CdArgs;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct Z;
+
+#[derive(Deserialize)]
+pub struct ZArgs {
+    rest: Vec<Tagged<String>>,
+    list: bool,
+    store: Option<Tagged<PathBuf>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Z {
+    fn name(&self) -> &str {
+        "z"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("z")
+            .rest(
+                SyntaxShape::String,
+                "parts of the path of the directory to change to, in order",
+            )
+            .switch(
+                "list",
+                "List the visited directories that match instead, the best first",
+                Some('l'),
+            )
+            .named(
+                "store",
+                SyntaxShape::FilePath,
+                "Read the visited directories from this file instead of the one next to the history file",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Change to the visited directory that best matches the given parts of its path."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Every directory changed to in the REPL is remembered, in dirs.txt next to the history file.
+The best match is the one visited most often, with recent visits counting for more, whose path
+has the parts in order, ignoring case, and whose name has the last part."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        z(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Change to the best match for 'nu'",
+                example: "z nu",
+                result: None,
+            },
+            Example {
+                description: "Change to the best match for 'src' under a directory matching 'proj'",
+                example: "z proj src",
+                result: None,
+            },
+            Example {
+                description: "List the visited directories, the best first",
+                example: "z --list",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn z(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let shell_manager = args.shell_manager.clone();
+    let (ZArgs { rest, list, store }, _) = args.process().await?;
+
+    let (path, path_tag) = match store {
+        Some(store) => (store.item, store.tag),
+        None => (
+            frecency::store_path(&history_path(&NuConfig::new())),
+            name.clone(),
+        ),
+    };
+
+    let visited = Frecency::load(&path).map_err(|e| {
+        ShellError::labeled_error(
+            "Could not read the visited directories",
+            format!("{}: {}", path.display(), e),
+            &path_tag,
+        )
+    })?;
+
+    let fragments = rest
+        .iter()
+        .map(|fragment| &fragment.item)
+        .collect::<Vec<_>>();
+    let now = frecency::now();
+    // Directories that were removed since are left out
+    let ranked = visited
+        .ranked(&fragments, now)
+        .into_iter()
+        .filter(|dir| dir.path.is_dir());
+
+    if list {
+        return Ok(ranked
+            .map(|dir| {
+                let mut dict = TaggedDictBuilder::new(&name);
+                dict.insert_untagged("path", UntaggedValue::filepath(&dir.path));
+                dict.insert_untagged(
+                    "score",
+                    UntaggedValue::decimal_from_float(dir.score(now), name.span),
+                );
+                dict.insert_untagged(
+                    "rank",
+                    UntaggedValue::decimal_from_float(dir.rank, name.span),
+                );
+                dict.insert_untagged(
+                    "last_visit",
+                    UntaggedValue::date(Local.timestamp(dir.last_visit as i64, 0)),
+                );
+
+                ReturnSuccess::value(dict.into_value())
+            })
+            .collect::<VecDeque<_>>()
+            .into());
+    }
+
+    if !shell_manager.is_filesystem() {
+        return Err(ShellError::labeled_error(
+            "z only changes between directories on the filesystem",
+            "not in a filesystem shell",
+            &name,
+        ));
+    }
+
+    let best = ranked.map(|dir| dir.path.clone()).next().ok_or_else(|| {
+        ShellError::labeled_error(
+            "No visited directory matches",
+            "no visited directory matches",
+            match (rest.first(), rest.last()) {
+                (Some(first), Some(last)) => first.tag.until(&last.tag),
+                _ => name.clone(),
+            },
+        )
+    })?;
+
+    shell_manager.cd(
+        CdArgs {
+            path: Some(best.tagged(&name)),
+        },
+        name,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::Z;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Z {})
+    }
+}
@@ -0,0 +1,244 @@
+use crate::prelude::*;
+use futures::stream::once;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::CapturedBlock, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+
+pub struct ZipWith;
+
+#[derive(Deserialize)]
+pub struct ZipWithArgs {
+    other: Value,
+    block: CapturedBlock,
+    pad: Option<Value>,
+    strict: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for ZipWith {
+    fn name(&self) -> &str {
+        "zip-with"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("zip-with")
+            .required(
+                "other",
+                SyntaxShape::Any,
+                "the list to pair the input with, or a block whose output is used",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run on each pair, eg {|before, after| ... }",
+            )
+            .named(
+                "pad",
+                SyntaxShape::Any,
+                "when one side runs out, pair what's left of the other with this value",
+                Some('p'),
+            )
+            .switch(
+                "strict",
+                "fail if the two sides don't have the same length",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block on the input paired up element by element with another list."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The block's first two parameters get the values from the input and from the other list.
+Without parameters, $it is a row with a 'left' and a 'right' column. Both sides are read lazily,
+one pair at a time, and by default pairing stops as soon as either side runs out."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        zip_with(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Add two lists together",
+                example: "echo [1 2 3] | zip-with [10 20 30] {|a, b| = $a + $b }",
+                result: Some(vec![
+                    UntaggedValue::int(11).into(),
+                    UntaggedValue::int(22).into(),
+                    UntaggedValue::int(33).into(),
+                ]),
+            },
+            Example {
+                description: "Pad the shorter side",
+                example: "echo [1 2 3] | zip-with [10] --pad 0 {|a, b| = $a + $b }",
+                result: Some(vec![
+                    UntaggedValue::int(11).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Compare file sizes against a snapshot taken earlier",
+                example:
+                    "ls | zip-with { open snapshot.json } { = $it.left.size - $it.right.size }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn zip_with(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = Arc::new(EvaluationContext::from_args(&raw_args));
+    let name_tag = raw_args.call_info.name_tag.clone();
+    let (
+        ZipWithArgs {
+            other,
+            block,
+            pad,
+            strict,
+        },
+        input,
+    ) = raw_args.process().await?;
+
+    if strict && pad.is_some() {
+        return Err(ShellError::labeled_error(
+            "Conflicting flags",
+            "--strict and --pad can't be used together",
+            &name_tag,
+        ));
+    }
+
+    let other = match other.value {
+        UntaggedValue::Block(other_block) => {
+            context.scope.enter_scope();
+            context.scope.add_vars(&other_block.captured.entries);
+            let result = run_block(&other_block.block, &*context, InputStream::empty()).await;
+            context.scope.exit_scope();
+
+            result?
+        }
+        UntaggedValue::Table(values) => InputStream::from(values),
+        _ => InputStream::one(other),
+    };
+
+    let block = Arc::new(block);
+    let state = Some((input, other));
+
+    Ok(futures::stream::unfold(state, move |state| {
+        let pad = pad.clone();
+        let name_tag = name_tag.clone();
+
+        async move {
+            let (mut left, mut right) = state?;
+
+            let pair = match (left.next().await, right.next().await) {
+                (Some(left_value), Some(right_value)) => Ok((left_value, right_value)),
+                (None, None) => return None,
+                (left_value, right_value) => match pad {
+                    Some(pad) => Ok((
+                        left_value.unwrap_or_else(|| pad.clone()),
+                        right_value.unwrap_or(pad),
+                    )),
+                    None if strict => Err(ShellError::labeled_error(
+                        "Lengths don't match",
+                        format!(
+                            "the {} side ran out first",
+                            if left_value.is_none() {
+                                "input"
+                            } else {
+                                "other"
+                            }
+                        ),
+                        &name_tag,
+                    )),
+                    None => return None,
+                },
+            };
+
+            // After an error there's nothing left to pair
+            let next = if pair.is_ok() {
+                Some((left, right))
+            } else {
+                None
+            };
+
+            Some((pair, next))
+        }
+    })
+    .then(move |pair| {
+        let block = block.clone();
+        let context = context.clone();
+
+        async {
+            match pair {
+                Ok((left, right)) => match process_pair(block, context, left, right).await {
+                    Ok(s) => s,
+                    Err(e) => OutputStream::one(Err(e)),
+                },
+                Err(e) => OutputStream::one(Err(e)),
+            }
+        }
+    })
+    .flatten()
+    .to_output_stream())
+}
+
+async fn process_pair(
+    block: Arc<CapturedBlock>,
+    context: Arc<EvaluationContext>,
+    left: Value,
+    right: Value,
+) -> Result<OutputStream, ShellError> {
+    let mut dict = TaggedDictBuilder::new(left.tag.clone());
+    dict.insert_value("left", left.clone());
+    dict.insert_value("right", right.clone());
+    let pair = dict.into_value();
+
+    let input_stream = if block.block.params.positional.is_empty() {
+        let pair = pair.clone();
+        once(async { Ok(pair) }).to_input_stream()
+    } else {
+        InputStream::empty()
+    };
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+
+    // A block using $it gets an implied `$it` param, which is the pair rather than the left side
+    let mut params = block
+        .block
+        .params
+        .positional
+        .iter()
+        .filter(|(param, _)| param.name() != "$it");
+    if let Some((param, _)) = params.next() {
+        context.scope.add_var(param.name(), left);
+    }
+    if let Some((param, _)) = params.next() {
+        context.scope.add_var(param.name(), right);
+    }
+    context.scope.add_var("$it", pair);
+
+    let result = run_block(&block.block, &*context, input_stream).await;
+    context.scope.exit_scope();
+
+    Ok(result?.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::ZipWith;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(ZipWith {})
+    }
+}
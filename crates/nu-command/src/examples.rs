@@ -13,6 +13,7 @@ use nu_protocol::{ShellTypeName, Value};
 use nu_source::AnchorLocation;
 use stub_generate::{mock_path, Command as StubOpen};
 
+use crate::commands::default_context::create_default_context;
 use crate::commands::{
     Append, BuildString, Each, Echo, First, Get, Keep, Last, Let, Nth, Select, StrCollect, Wrap,
 };
@@ -43,7 +44,7 @@ pub fn test_examples(cmd: Command) -> Result<(), ShellError> {
         whole_stream_command(Select),
         whole_stream_command(StrCollect),
         whole_stream_command(Wrap),
-        cmd,
+        cmd.clone(),
     ]);
 
     for sample_pipeline in examples {
@@ -88,6 +89,7 @@ pub fn test_examples(cmd: Command) -> Result<(), ShellError> {
 }
 
 pub fn test(cmd: impl WholeStreamCommand + 'static) -> Result<(), ShellError> {
+    let cmd = whole_stream_command(cmd);
     let examples = cmd.examples();
 
     let base_context = EvaluationContext::basic()?;
@@ -99,7 +101,7 @@ pub fn test(cmd: impl WholeStreamCommand + 'static) -> Result<(), ShellError> {
         whole_stream_command(Keep {}),
         whole_stream_command(Each {}),
         whole_stream_command(Let {}),
-        whole_stream_command(cmd),
+        cmd.clone(),
         whole_stream_command(Select),
         whole_stream_command(StrCollect),
         whole_stream_command(Wrap),
@@ -166,7 +168,7 @@ pub fn test_anchors(cmd: Command) -> Result<(), ShellError> {
         whole_stream_command(Select),
         whole_stream_command(StrCollect),
         whole_stream_command(Wrap),
-        cmd,
+        cmd.clone(),
     ]);
 
     for sample_pipeline in examples {
@@ -200,6 +202,50 @@ pub fn test_anchors(cmd: Command) -> Result<(), ShellError> {
     Ok(())
 }
 
+/// Runs the examples declared in the comments above the custom commands `source` defines,
+/// comparing what each returns with what its `=>` expression evaluates to
+pub fn test_custom_examples(source: &str) -> Result<(), ShellError> {
+    let mut ctx = create_default_context(false)
+        .map_err(|e| ShellError::untagged_runtime_error(e.to_string()))?;
+
+    let definitions = parse_line(source, &ctx)?;
+    if let Some(err) = definitions.failed {
+        return Err(err.into());
+    }
+
+    for definition in definitions.block.definitions.values() {
+        for sample_pipeline in &definition.params.examples {
+            let block = parse_line(&sample_pipeline.example, &ctx)?;
+            let result = block_on(evaluate_block(block, &mut ctx))?;
+
+            ctx.with_errors(|reasons| reasons.iter().cloned().take(1).next())
+                .map_or(Ok(()), Err)?;
+
+            if let Some(expected) = &sample_pipeline.result {
+                let block = parse_line(expected, &ctx)?;
+                let expected = block_on(evaluate_block(block, &mut ctx))?;
+
+                if expected.len() != result.len()
+                    || !expected
+                        .iter()
+                        .zip(result.iter())
+                        .all(|(e, a)| values_equal(e, a))
+                {
+                    let row_errored = format!("expected: {:#?}\nactual: {:#?}", expected, result);
+                    let failed_call = format!("command: {}\n", sample_pipeline.example);
+
+                    panic!(
+                        "example command produced unexpected result.\n {} {}",
+                        failed_call, row_errored
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Parse and run a nushell pipeline
 fn parse_line(line: &str, ctx: &EvaluationContext) -> Result<ClassifiedBlock, ShellError> {
     //FIXME: do we still need this?
@@ -41,6 +41,7 @@ impl WholeStreamCommand for Command {
                 tag: Tag {
                     anchor: Some(mock_path()),
                     span: name_tag.span,
+                    content_type: None,
                 },
             }))))
         } else {
@@ -10,7 +10,7 @@ pub mod commands;
 mod futures;
 pub mod utils;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "core"))]
 mod examples;
 
 pub use nu_data::config;
@@ -1,3 +1,5 @@
 pub mod arguments;
+pub mod json;
+pub mod memory_limit;
 pub mod suggestions;
 pub mod test_bins;
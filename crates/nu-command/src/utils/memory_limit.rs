@@ -0,0 +1,68 @@
+use futures::StreamExt;
+use nu_engine::{estimated_size, Scope};
+use nu_errors::ShellError;
+use nu_protocol::Value;
+use nu_source::Tag;
+use nu_stream::InputStream;
+use num_traits::ToPrimitive;
+
+/// The number of bytes a setting stands for, given as a file size or a plain number
+pub fn bytes_of(value: &Value) -> Option<usize> {
+    match value.as_filesize() {
+        Ok(size) => size.to_usize(),
+        Err(_) => value.as_u64().ok()?.to_usize(),
+    }
+}
+
+/// The most memory a command may collect its input into, from the `collect_memory_limit` of the
+/// config. There's no limit unless one is set.
+pub fn collect_memory_limit(scope: &Scope) -> Option<usize> {
+    bytes_of(&scope.get_config_var("collect_memory_limit")?)
+}
+
+/// How to get the output of the command without going over the limit
+fn hint(command: &str) -> String {
+    if command.starts_with("to ") {
+        format!(
+            "convert fewer rows at a time, eg with `each {{ {} }}`, or raise the collect_memory_limit of the config",
+            command
+        )
+    } else {
+        "narrow the input first, eg with `where` or `first`, or raise the collect_memory_limit of the config".to_string()
+    }
+}
+
+/// Collects the whole input of a command that needs all of it at once, failing when the input
+/// takes up more memory than the `collect_memory_limit` of the config allows
+pub async fn collect_within_limit(
+    mut input: InputStream,
+    command: &str,
+    scope: &Scope,
+    tag: &Tag,
+) -> Result<Vec<Value>, ShellError> {
+    let limit = match collect_memory_limit(scope) {
+        Some(limit) => limit,
+        None => return Ok(input.collect().await),
+    };
+
+    let mut values = vec![];
+    let mut size = 0;
+    while let Some(value) = input.next().await {
+        size += estimated_size(&value);
+        if size > limit {
+            return Err(ShellError::labeled_error(
+                format!(
+                    "Input too large for {}, over the collect_memory_limit of {}",
+                    command,
+                    byte_unit::Byte::from_bytes(limit as u128).get_appropriate_unit(false)
+                ),
+                hint(command),
+                tag,
+            ));
+        }
+
+        values.push(value);
+    }
+
+    Ok(values)
+}
@@ -42,7 +42,34 @@ pub fn iecho() {
         .try_for_each(|v| writeln!(stdout, "{}", v));
 }
 
+pub fn bytes() {
+    let mut stdout = io::stdout();
+    let count: usize = args()
+        .get(1)
+        .map(|count| count.parse().expect("can't convert count to number"))
+        .unwrap_or(1);
+
+    // Every byte there is, starting with ones that are never valid text
+    let all_bytes: Vec<u8> = (0..=255).rev().collect();
+    for _ in 0..count {
+        if stdout.write_all(&all_bytes).is_err() {
+            break;
+        }
+    }
+    let _ = stdout.flush();
+}
+
+pub fn relay() {
+    let _ = io::copy(&mut io::stdin(), &mut io::stdout());
+}
+
 pub fn fail() {
+    // Any arguments are written to stderr, to say why
+    let args = args();
+    if args.len() > 1 {
+        eprintln!("{}", &args[1..].join(" "));
+    }
+
     std::process::exit(1);
 }
 
@@ -0,0 +1,103 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn passes_when_the_condition_holds() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        let x = 10; assert $x > 5; echo done
+        "#
+    ));
+
+    assert_eq!(actual.out, "done");
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn shows_both_sides_of_a_failed_comparison() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        let x = 3; assert $x > 5
+        "#
+    ));
+
+    assert!(actual.err.contains("Assertion failed"));
+    assert!(actual.err.contains("this is 3"));
+    assert!(actual.err.contains("this is 5"));
+}
+
+#[test]
+fn fails_with_the_message_given() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        assert $false "the flag is set"
+        "#
+    ));
+
+    assert!(actual.err.contains("Assertion failed: the flag is set"));
+}
+
+#[test]
+fn equal_compares_tables() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        assert equal $(echo [3 1 2] | sort-by) [1 2 3]; echo done
+        "#
+    ));
+
+    assert_eq!(actual.out, "done");
+}
+
+#[test]
+fn equal_shows_both_values() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        assert equal $(echo [1 2 3] | math sum) 7
+        "#
+    ));
+
+    assert!(actual.err.contains("the values are not equal"));
+    assert!(actual.err.contains("this is 6"));
+    assert!(actual.err.contains("but this is 7"));
+}
+
+#[test]
+fn not_equal_fails_on_equal_values() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        assert not equal $(echo hello | str upcase) HELLO
+        "#
+    ));
+
+    assert!(actual.err.contains("the values are equal"));
+}
+
+#[test]
+fn error_passes_when_the_block_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        assert error { open does-not-exist.txt }; echo done
+        "#
+    ));
+
+    assert_eq!(actual.out, "done");
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn error_fails_when_the_block_succeeds() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        assert error { echo fine }
+        "#
+    ));
+
+    assert!(actual.err.contains("the block ran without an error"));
+}
@@ -0,0 +1,49 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn summarizes_the_runs() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        benchmark { echo 1 } --runs 3 | columns | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "min,mean,max");
+}
+
+#[test]
+fn lists_each_run() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        benchmark { echo 1 } --runs 3 --each | get run | str from | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "1,2,3");
+}
+
+#[test]
+fn runs_the_block_on_the_same_input_each_time() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3] | benchmark { math sum } --runs 2 --passthrough {}
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn fails_without_runs() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        benchmark { echo 1 } --runs 0
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected at least one run"));
+}
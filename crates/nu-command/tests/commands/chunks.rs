@@ -0,0 +1,41 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn splits_the_rows_into_chunks() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5] | chunks 2 | each { echo $it | math sum } | str from | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "3,7,5");
+}
+
+#[test]
+fn keeps_the_rows_of_a_table_together() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name age]; [Andrés 30] [Jonathan 40] [Jason 50]]
+        | chunks 2
+        | first
+        | get name
+        | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "Andrés,Jonathan");
+}
+
+#[test]
+fn fails_with_a_size_of_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3] | chunks 0
+        "#
+    ));
+
+    assert!(actual.err.contains("Chunks can't be empty"));
+}
@@ -0,0 +1,28 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn lists_the_columns_of_all_the_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu]]
+            | append $(echo [[name size]; [rust 5]])
+            | columns
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "name,size");
+}
+
+#[test]
+fn errors_on_values_without_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3] | columns
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a record or table"));
+}
@@ -0,0 +1,56 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn captures_the_stdout_of_an_external() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            complete { nu --testbin cococo andres }
+            | get stdout
+            | str trim
+        "#
+    ));
+
+    assert_eq!(actual.out, "andres");
+}
+
+#[test]
+fn gives_zero_exit_code_when_the_external_succeeds() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            complete { nu --testbin cococo andres }
+            | get exit_code
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn captures_the_stderr_of_a_failed_external() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            complete { nu --testbin fail jonathan }
+            | get stderr
+            | str trim
+        "#
+    ));
+
+    assert_eq!(actual.out, "jonathan");
+    assert_eq!(actual.err, "");
+}
+
+#[test]
+fn gives_the_exit_code_of_a_failed_external() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            complete { nu --testbin fail }
+            | get exit_code
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
@@ -0,0 +1,166 @@
+#[cfg(feature = "dataframe")]
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+#[cfg(feature = "dataframe")]
+use nu_test_support::playground::Playground;
+#[cfg(feature = "dataframe")]
+use nu_test_support::{nu, pipeline};
+
+#[cfg(feature = "dataframe")]
+fn with_sales(name: &str, test: impl FnOnce(&std::path::Path)) {
+    Playground::setup(name, |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContentToBeTrimmed(
+                "sales.csv",
+                r#"
+                    region,product_id,amount
+                    EMEA,1,100
+                    APAC,2,250
+                    EMEA,2,50
+                    APAC,1,300
+                "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "products.csv",
+                r#"
+                    product_id,product
+                    1,shoes
+                    2,socks
+                "#,
+            ),
+        ]);
+
+        test(dirs.test())
+    })
+}
+
+#[cfg(feature = "dataframe")]
+#[test]
+fn opens_a_csv_file_as_a_dataframe() {
+    with_sales("dfr_open_test_1", |cwd| {
+        let actual = nu!(
+            cwd: cwd, pipeline(
+            r#"
+                dfr open sales.csv
+                | dfr collect
+                | get amount
+                | math sum
+            "#
+        ));
+
+        assert_eq!(actual.out, "700");
+    })
+}
+
+#[cfg(feature = "dataframe")]
+#[test]
+fn opens_a_file_without_a_header() {
+    with_sales("dfr_open_test_2", |cwd| {
+        let actual = nu!(
+            cwd: cwd, pipeline(
+            r#"
+                dfr open products.csv --no-header
+                | dfr collect
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
+
+#[cfg(feature = "dataframe")]
+#[test]
+fn filters_the_rows_that_compare_true() {
+    with_sales("dfr_filter_test_1", |cwd| {
+        let actual = nu!(
+            cwd: cwd, pipeline(
+            r#"
+                dfr open sales.csv
+                | dfr filter amount '>' 100
+                | dfr collect
+                | get amount
+                | str from
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "250,300");
+    })
+}
+
+#[cfg(feature = "dataframe")]
+#[test]
+fn filters_by_a_string() {
+    with_sales("dfr_filter_test_2", |cwd| {
+        let actual = nu!(
+            cwd: cwd, pipeline(
+            r#"
+                dfr open sales.csv
+                | dfr filter region == EMEA
+                | dfr collect
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[cfg(feature = "dataframe")]
+#[test]
+fn groups_and_aggregates_the_other_columns() {
+    with_sales("dfr_group_by_test_1", |cwd| {
+        let actual = nu!(
+            cwd: cwd, pipeline(
+            r#"
+                dfr open sales.csv
+                | dfr group-by region --aggregate sum
+                | dfr collect
+                | where region == APAC
+                | get amount_sum
+            "#
+        ));
+
+        assert_eq!(actual.out, "550");
+    })
+}
+
+#[cfg(feature = "dataframe")]
+#[test]
+fn joins_the_rows_with_the_same_key() {
+    with_sales("dfr_join_test_1", |cwd| {
+        let actual = nu!(
+            cwd: cwd, pipeline(
+            r#"
+                let products = (dfr open products.csv);
+                dfr open sales.csv
+                | dfr join $products product_id
+                | dfr collect
+                | where product == socks
+                | get amount
+                | math sum
+            "#
+        ));
+
+        assert_eq!(actual.out, "300");
+    })
+}
+
+#[cfg(feature = "dataframe")]
+#[test]
+fn dataframes_are_written_as_their_rows() {
+    with_sales("dfr_to_json_test_1", |cwd| {
+        let actual = nu!(
+            cwd: cwd, pipeline(
+            r#"
+                dfr open products.csv
+                | to json
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            r#"[{"product_id":1,"product":"shoes"},{"product_id":2,"product":"socks"}]"#
+        );
+    })
+}
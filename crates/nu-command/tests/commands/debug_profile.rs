@@ -0,0 +1,29 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn has_a_row_for_each_command() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        debug profile { echo [1 2 3] | each { = $it * 2 } }
+        | get command
+        | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "echo,each,expression");
+}
+
+#[test]
+fn counts_the_runs_and_rows_of_each_command() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        debug profile { echo [1 2 3] | each { = $it * 2 } }
+        | each { echo `{{$it.command}}:{{$it.depth}}:{{$it.calls}}:{{$it.rows}}` }
+        | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, "echo:0:1:3 each:0:1:3 expression:1:3:3");
+}
@@ -0,0 +1,88 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn runs_the_script_when_no_breakpoint_is_hit() {
+    Playground::setup("debug_run_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "script.nu",
+            r#"
+            let x = 3
+            = $x + 1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+            debug run script.nu 99
+            "#
+        ));
+
+        assert_eq!(actual.out, "4");
+    })
+}
+
+#[test]
+fn shows_the_variables_where_it_stops() {
+    Playground::setup("debug_run_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "script.nu",
+            "let greeting = hello\necho $greeting\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+            debug run script.nu 2
+            vars
+            continue
+            "#
+        );
+
+        assert!(actual
+            .out
+            .contains("Breakpoint at line 2: script.nu:2: echo $greeting"));
+        assert!(actual.out.contains("greeting = hello"));
+    })
+}
+
+#[test]
+fn stops_before_a_command() {
+    Playground::setup("debug_run_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "script.nu",
+            "echo one\necho two | str upcase\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+            debug run script.nu "str upcase"
+            continue
+            "#
+        );
+
+        assert!(actual.out.contains("Breakpoint at command str upcase"));
+        assert!(actual.out.contains("TWO"));
+    })
+}
+
+#[test]
+fn quitting_stops_the_script() {
+    Playground::setup("debug_run_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("script.nu", "echo one\necho two\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+            debug run script.nu
+            quit
+            "#
+        );
+
+        assert!(actual.err.contains("Stopped debugging"));
+        assert!(!actual.out.contains("two"));
+    })
+}
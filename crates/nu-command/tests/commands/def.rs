@@ -17,3 +17,31 @@ def e [arg] {echo $arg}
         assert!(actual.out.contains("My echo\\n\\n"));
     });
 }
+
+#[test]
+fn def_with_extra_usage_and_examples() {
+    Playground::setup("def_with_extra_usage_and_examples", |dirs, _| {
+        let data = r#"
+# Doubles a number
+#
+# Works on decimals too.
+#
+# Example: Double two
+# > double 2
+# => 4
+def double [n] { = $n * 2 }
+            "#;
+        fs::write(dirs.root().join("def_test"), data).expect("Unable to write file");
+        let actual = nu!(
+            cwd: dirs.root(),
+            "source def_test; help double | to json"
+        );
+
+        assert!(actual
+            .out
+            .contains("Doubles a number\\n\\nWorks on decimals too."));
+        assert!(actual.out.contains("Double two"));
+        assert!(actual.out.contains("double 2"));
+        assert!(!actual.out.contains("Example:"));
+    });
+}
@@ -0,0 +1,30 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn difference_of_a_block_output() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [a b c d]
+        | difference { echo [b d] }
+        | str collect
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "ac");
+}
+
+#[test]
+fn missing_key_column_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name]; [nu]]
+        | difference $(echo [[name]; [git]]) version
+        "#
+        )
+    );
+
+    assert!(actual.err.contains("version"));
+}
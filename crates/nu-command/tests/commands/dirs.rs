@@ -0,0 +1,100 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+use std::path::PathBuf;
+
+#[test]
+fn popd_returns_to_where_pushd_was_run() {
+    Playground::setup("dirs_test_1", |dirs, sandbox| {
+        sandbox.mkdir("odin");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                pushd odin
+                popd
+                echo $(pwd)
+            "#
+        );
+
+        assert_eq!(PathBuf::from(actual.out), *dirs.test());
+    })
+}
+
+#[test]
+fn lists_the_current_path_then_the_pushed_ones() {
+    Playground::setup("dirs_test_2", |dirs, sandbox| {
+        sandbox.mkdir("odin").mkdir("thor");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                pushd odin
+                pushd ../thor
+                echo $(dirs | get path | nth 1)
+            "#
+        );
+
+        assert_eq!(PathBuf::from(actual.out), dirs.test().join("odin"));
+    })
+}
+
+#[test]
+fn changes_to_an_entry_of_the_stack() {
+    Playground::setup("dirs_test_3", |dirs, sandbox| {
+        sandbox.mkdir("odin").mkdir("thor");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                pushd odin
+                pushd ../thor
+                dirs 2
+                echo $(dirs | get path | str collect ",")
+            "#
+        );
+
+        let expected = vec![
+            dirs.test().clone(),
+            dirs.test().join("thor"),
+            dirs.test().join("odin"),
+        ]
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+        assert_eq!(actual.out, expected);
+    })
+}
+
+#[test]
+fn popd_fails_when_nothing_was_pushed() {
+    Playground::setup("dirs_test_4", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "popd"
+        );
+
+        assert!(actual.err.contains("directory stack is empty"));
+    })
+}
+
+#[test]
+fn cd_dash_returns_to_the_previous_directory_after_switching_shells() {
+    Playground::setup("dirs_test_5", |dirs, sandbox| {
+        sandbox.mkdir("odin");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                cd odin
+                enter ..
+                n
+                cd -
+                echo $(pwd)
+            "#
+        );
+
+        assert_eq!(PathBuf::from(actual.out), *dirs.test());
+    })
+}
@@ -0,0 +1,32 @@
+use nu_test_support::nu;
+
+#[test]
+fn ignore_errors_drops_the_stderr_of_a_failed_external() {
+    let actual = nu!(cwd: ".", "do -i { nu --testbin fail jonathan }");
+
+    assert_eq!(actual.out, "");
+    assert_eq!(actual.err, "");
+}
+
+#[test]
+fn capture_errors_fails_with_the_stderr_of_a_failed_external() {
+    let actual = nu!(cwd: ".", "do -c { nu --testbin fail jonathan }");
+
+    assert!(actual.err.contains("External command failed"));
+    assert!(actual.err.contains("jonathan"));
+}
+
+#[test]
+fn capture_errors_keeps_the_output_of_an_external_that_succeeds() {
+    let actual = nu!(cwd: ".", "do -c { nu --testbin cococo andres }");
+
+    assert_eq!(actual.out, "andres");
+    assert_eq!(actual.err, "");
+}
+
+#[test]
+fn errors_cannot_be_both_ignored_and_captured() {
+    let actual = nu!(cwd: ".", "do -i -c { echo andres }");
+
+    assert!(actual.err.contains("can't be both ignored and captured"));
+}
@@ -71,3 +71,27 @@ fn each_implicit_it_in_block() {
 
     assert_eq!(actual.out, "ace");
 }
+
+#[test]
+fn each_with_block_parameter() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[foo bar]; [a b] [c d]] | each {|row| echo $row.bar } | str collect
+        "#
+    ));
+
+    assert_eq!(actual.out, "bd");
+}
+
+#[test]
+fn nested_each_can_refer_to_outer_block_parameter() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 1 2 | each {|outer| echo 10 20 | each {|inner| = $outer + $inner } } | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "66");
+}
@@ -83,3 +83,107 @@ fn errors_if_file_not_found() {
         assert!(actual.err.contains("Cannot find file"));
     })
 }
+
+#[test]
+fn lists_the_entries_of_an_archive() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"
+            enter sample_archive.zip
+            ls | get name | str collect ','
+        "#
+    );
+
+    assert_eq!(actual.out, "data,notes.txt");
+}
+
+#[test]
+fn opens_files_inside_an_archive() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"
+            enter sample_archive.tar.gz
+            cd data
+            open cities.json | get name | str collect ','
+        "#
+    );
+
+    assert_eq!(actual.out, "Lisbon,Porto");
+}
+
+#[test]
+fn copies_files_out_of_an_archive() {
+    Playground::setup("enter_test_3", |dirs, _| {
+        nu!(
+            cwd: dirs.formats(),
+            r#"
+                enter sample_archive.zip
+                cp data {}/extracted --recursive
+                cp notes.txt {}
+            "#,
+            dirs.test(),
+            dirs.test()
+        );
+
+        assert!(files_exist_at(
+            vec![Path::new("extracted/cities.json"), Path::new("notes.txt")],
+            dirs.test()
+        ));
+    })
+}
+
+#[test]
+fn archives_are_read_only() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"
+            enter sample_archive.zip
+            rm notes.txt
+        "#
+    );
+
+    assert!(actual.err.contains("archives are read-only"));
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn lists_the_tables_of_a_database() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"
+            enter sample.db
+            ls | get name | str collect ','
+        "#
+    );
+
+    assert_eq!(actual.out, "strings,ints,floats");
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn lists_the_rows_of_a_table_in_a_database() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"
+            enter sample.db
+            cd ints
+            ls | first 4 | get z | math sum
+        "#
+    );
+
+    assert_eq!(actual.out, "4721");
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn opens_tables_of_a_database() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"
+            enter sample.db
+            open strings | where x == world | length
+        "#
+    );
+
+    assert_eq!(actual.out, "2");
+}
@@ -0,0 +1,31 @@
+use nu_test_support::nu;
+
+#[test]
+fn reads_variables_from_env() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let-env NU_TEST_GREETING = hello; echo $env.NU_TEST_GREETING"#
+    );
+
+    assert_eq!(actual.out, "hello");
+}
+
+#[test]
+fn path_is_a_list_of_directories() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let-env PATH = $(echo $env.PATH | prepend /nu/bin); echo $env.PATH | first"#
+    );
+
+    assert_eq!(actual.out, "/nu/bin");
+}
+
+#[test]
+fn lists_variables_set_in_the_session() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let-env NU_TEST_PROXY = localhost; env | where name =~ NU_TEST_PROXY | get origin"#
+    );
+
+    assert_eq!(actual.out, "session");
+}
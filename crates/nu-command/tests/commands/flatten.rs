@@ -182,3 +182,109 @@ fn flatten_more_than_one_column_that_are_subtables_not_supported() {
         assert!(actual.err.contains("but is flattened already"));
     })
 }
+
+#[test]
+fn flatten_all_levels() {
+    Playground::setup("flatten_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.json",
+            r#"
+                {
+                    "name": "nushell",
+                    "owner": {
+                        "login": "nushell",
+                        "plan": {
+                            "seats": 4
+                        }
+                    },
+                    "topics": ["shell", "rust"]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open katz.json
+                | flatten --all
+                | format "{login} {seats} {topics}"
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "nushell 4 shell,nushell 4 rust");
+    })
+}
+
+#[test]
+fn flatten_to_a_depth() {
+    Playground::setup("flatten_test_6", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.json",
+            r#"
+                {
+                    "owner": {
+                        "login": "nushell",
+                        "plan": {
+                            "billing": {
+                                "seats": 4
+                            }
+                        }
+                    }
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open katz.json
+                | flatten owner --depth 2
+                | get billing.seats
+            "#
+        ));
+
+        assert_eq!(actual.out, "4");
+    })
+}
+
+#[test]
+fn flatten_names_the_columns_that_are_taken_apart() {
+    Playground::setup("flatten_test_7", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.json",
+            r#"
+                {
+                    "name": "Andres",
+                    "people": {
+                        "name": "Katz"
+                    },
+                    "people_name": "Yehuda"
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open katz.json
+                | flatten
+                | format "{name} {people_name} {people_name_2}"
+            "#
+        ));
+
+        assert_eq!(actual.out, "Andres Katz Yehuda");
+    })
+}
+
+#[test]
+fn flatten_zero_levels_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[a]; [1]] | flatten --depth 0
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a depth of at least 1"));
+}
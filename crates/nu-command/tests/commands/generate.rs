@@ -0,0 +1,56 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn generates_only_as_many_values_as_are_taken() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        generate 1 { echo [[out next]; [$it $(= $it * 2)]] }
+        | first 10
+        | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "1023");
+}
+
+#[test]
+fn stops_when_there_is_no_next_state() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        generate 3 {|n| if $n > 1 { echo [[out next]; [$n $(= $n - 1)]] } { echo [[out]; [$n]] } }
+        | str from
+        | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "3,2,1");
+}
+
+#[test]
+fn skips_steps_without_output() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        generate 1 {|n| if $n == 2 { echo [[next]; [3]] } { echo [[out next]; [$n $(= $n + 1)]] } }
+        | first 3
+        | str from
+        | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "1,3,4");
+}
+
+#[test]
+fn errors_if_the_block_does_not_return_a_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        generate 1 { = $it + 1 } | first 3
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a row"));
+}
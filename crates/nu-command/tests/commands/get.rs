@@ -220,3 +220,60 @@ fn quoted_column_access() {
 
     assert_eq!(actual.out, "4");
 }
+
+#[test]
+fn quoted_column_names_can_contain_dots() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '{"weird.dotted.name": 1, "weird": {"dotted": {"name": 2}}}' | from json | get "weird.dotted.name" "#
+    );
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn quoted_column_names_in_variable_paths() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"column with spaces": 1, "a..b": 2, "say \"hi\"": 3}]'
+            | from json
+            | each { = $it."column with spaces" + $it."a..b" + $it.'say "hi"' }
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn fetches_by_negative_index() {
+    Playground::setup("get_test_9", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [spanish_lesson]
+                sentence_words = ["Yo", "quiero", "taconushell"]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get spanish_lesson.sentence_words.-1
+            "#
+        ));
+
+        assert_eq!(actual.out, "taconushell");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get spanish_lesson.sentence_words.-4
+            "#
+        ));
+
+        assert!(actual.err.contains("Row not found"));
+    })
+}
@@ -0,0 +1,97 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+fn setup(sandbox: &mut Playground) {
+    sandbox.mkdir("src/deep").mkdir(".hidden").with_files(vec![
+        EmptyFile("a.rs"),
+        EmptyFile("src/b.rs"),
+        EmptyFile("src/deep/c.rs"),
+        EmptyFile(".hidden/d.rs"),
+    ]);
+}
+
+#[test]
+fn matches_files_in_every_directory_below() {
+    Playground::setup("glob_test_1", |dirs, sandbox| {
+        setup(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob **/*.rs
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
+
+#[test]
+fn matches_no_deeper_than_the_max_depth() {
+    Playground::setup("glob_test_2", |dirs, sandbox| {
+        setup(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob **/*.rs --max-depth 2
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn matches_hidden_files_with_all() {
+    Playground::setup("glob_test_3", |dirs, sandbox| {
+        setup(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob **/*.rs --all
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "4");
+    })
+}
+
+#[test]
+fn leaves_out_files() {
+    Playground::setup("glob_test_4", |dirs, sandbox| {
+        setup(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob **/* --no-file
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn leaves_out_directories() {
+    Playground::setup("glob_test_5", |dirs, sandbox| {
+        setup(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob **/* --no-dir
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
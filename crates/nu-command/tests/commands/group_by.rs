@@ -95,3 +95,56 @@ fn errors_if_block_given_evaluates_more_than_one_row() {
         assert!(actual.err.contains("Unknown column"));
     })
 }
+
+#[test]
+fn groups_by_more_than_one_column() {
+    Playground::setup("group_by_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+                Yehuda,Katz,10/11/2013,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by type rusty_at
+                | get A."10/11/2013".first_name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "Andrés,Yehuda");
+    })
+}
+
+#[test]
+fn groups_by_a_block_and_a_column() {
+    Playground::setup("group_by_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+                Yehuda,Katz,10/11/2013,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by { get last_name | str length } type
+                | get "6".B.first_name
+            "#
+        ));
+
+        assert_eq!(actual.out, "Jonathan");
+    })
+}
@@ -29,3 +29,71 @@ fn help_generate_docs_length() {
     let is_positive = output_int.is_positive();
     assert!(is_positive);
 }
+
+#[test]
+fn help_shows_flags_and_input_output() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        help str trim
+        "#
+    ));
+
+    assert!(actual.out.contains("Flags:"));
+    assert!(actual.out.contains("-c, --char <string>"));
+    assert!(actual.out.contains("Input/output:"));
+}
+
+#[test]
+fn help_shows_example_results() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        help str upcase
+        "#
+    ));
+
+    // The expected output is indented under the example
+    assert!(actual.out.contains("Upcase contents"));
+    assert!(actual.out.contains("    NU"));
+}
+
+#[test]
+fn help_as_json() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        help str trim --json | from json | get flags.long | str collect ','
+        "#
+    ));
+
+    assert!(actual.out.contains("char"));
+    assert!(actual.out.contains("help"));
+}
+
+#[test]
+fn help_as_json_includes_examples() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        help str upcase --json | from json | get examples | first | get result
+        "#
+    ));
+
+    assert_eq!(actual.out, "NU");
+}
+
+#[test]
+fn help_commands_as_json_includes_subcommands() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        help commands --json
+        | from json
+        | where name == "str trim"
+        | get parameters.name
+        "#
+    ));
+
+    assert_eq!(actual.out, "...args");
+}
@@ -98,7 +98,7 @@ fn count() {
             echo [[bit];  [1] [0] [0] [0] [0] [0] [0] [1]]
             | histogram bit
             | sort-by count
-            | reject frequency
+            | reject frequency quantile
             | to json
         "#
     ));
@@ -107,3 +107,64 @@ fn count() {
 
     assert_eq!(actual.out, bit_json);
 }
+
+#[test]
+fn quantile_is_the_share_up_to_the_group() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[bit]; [1] [0] [0] [0]]
+            | histogram bit
+            | where bit == "0"
+            | get quantile
+        "#
+    ));
+
+    assert_eq!(actual.out, "0.75");
+}
+
+#[test]
+fn bins_numbers_into_ranges() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7 8 9 10]
+            | histogram --bins 3
+            | each { build-string $it.value ":" $it.count }
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "1 to 4:3,4 to 7:3,7 to 10:4");
+}
+
+#[test]
+fn bins_no_value_falls_in_are_counted_as_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 10]
+            | histogram --bins 3
+            | get count
+            | str from
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "1,0,1");
+}
+
+#[test]
+fn draws_bars_with_the_character_and_width_given() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a a b]
+            | histogram --char '#' --width 10
+            | where value == b
+            | get frequency
+        "#
+    ));
+
+    assert_eq!(actual.out, "#####");
+}
@@ -0,0 +1,73 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+const LOG: &str = r#"{"command":"ls | length","start":"2021-03-01T09:30:00+00:00","duration_ms":10,"success":true,"cwd":"/"}
+{"command":"ls | where size > 10","start":"2021-03-01T09:31:00+00:00","duration_ms":30,"success":true,"cwd":"/"}
+{"command":"str trim foo","start":"2021-03-01T22:00:00+00:00","duration_ms":5,"success":false,"cwd":"/"}
+{"command":"ls"#;
+
+#[test]
+fn counts_commands_across_lines() {
+    Playground::setup("history_stats_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("history.jsonl", LOG)]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                history stats --log history.jsonl
+                | first
+                | get command
+            "#
+        ));
+
+        assert_eq!(actual.out, "ls");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                history stats --log history.jsonl
+                | where command == ls
+                | get count
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn reports_errors_of_subcommands() {
+    Playground::setup("history_stats_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("history.jsonl", LOG)]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                history stats --log history.jsonl
+                | where errors > 0
+                | get command
+            "#
+        ));
+
+        assert_eq!(actual.out, "str trim");
+    })
+}
+
+#[test]
+fn counts_lines_by_hour() {
+    Playground::setup("history_stats_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("history.jsonl", LOG)]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                history stats --log history.jsonl --by-hour
+                | get count
+                | math sum
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
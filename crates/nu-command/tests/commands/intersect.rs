@@ -0,0 +1,16 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn intersects_tables_by_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name version]; [nu 0.28] [git 2.30] [jq 1.6]]
+        | intersect $(echo [[name version]; [git 2.30] [jq 1.5]]) name version
+        | get name
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "git");
+}
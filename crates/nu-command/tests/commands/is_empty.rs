@@ -0,0 +1,39 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn reports_empty_containers_of_every_type() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [
+                $(echo '' | is-empty)
+                $(echo [] | is-empty)
+                $(echo [[name]; [nu]] | reject name | is-empty)
+                $(echo $nothing | is-empty)
+            ]
+            | where $it
+            | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn reports_containers_with_something_in_them() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [
+                $(echo 'nu' | is-empty)
+                $(echo [1 2] | is-empty)
+                $(echo [[name]; [nu]] | is-empty)
+                $(open sample.db --raw | is-empty)
+            ]
+            | where $it
+            | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
@@ -0,0 +1,56 @@
+use nu_test_support::{nu, pipeline};
+
+#[cfg(unix)]
+#[test]
+fn spawn_returns_the_job() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        job spawn 'sleep 1'
+        | each { echo `{{$it.id}}:{{$it.command}}:{{$it.status}}` }
+        "#
+    ));
+
+    assert_eq!(actual.out, "1:sleep 1:running");
+}
+
+#[cfg(unix)]
+#[test]
+fn lists_the_jobs_spawned_before() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        let first = $(job spawn 'sleep 1');
+        let second = $(job spawn 'sleep 1');
+        job list | get id | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "1,2");
+}
+
+#[cfg(unix)]
+#[test]
+fn disown_takes_the_job_out_of_the_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        let first = $(job spawn 'sleep 1');
+        let second = $(job spawn 'sleep 1');
+        let disowned = $(job disown 1);
+        job list | get id | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn disown_fails_for_an_unknown_job() {
+    let actual = nu!(
+        cwd: ".",
+        "job disown 7"
+    );
+
+    assert!(actual.err.contains("No such job"));
+}
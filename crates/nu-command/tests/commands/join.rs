@@ -0,0 +1,140 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+fn with_tables(sandbox: &mut Playground) {
+    sandbox.with_files(vec![
+        FileWithContentToBeTrimmed(
+            "caballeros.csv",
+            r#"
+                id,name
+                1,Andrés
+                2,Jonathan
+                3,Jason
+            "#,
+        ),
+        FileWithContentToBeTrimmed(
+            "rustaceans.csv",
+            r#"
+                id,name,crate
+                1,Andrés,nu-cli
+                1,Andrés,nu-data
+                3,Jason,nu-table
+                4,Yehuda,nu-protocol
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn keeps_only_the_rows_with_a_match() {
+    Playground::setup("join_test_1", |dirs, sandbox| {
+        with_tables(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join $(open rustaceans.csv) id
+                | get crate
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "nu-cli,nu-data,nu-table");
+    })
+}
+
+#[test]
+fn suffixes_the_columns_both_tables_have() {
+    Playground::setup("join_test_2", |dirs, sandbox| {
+        with_tables(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join $(open rustaceans.csv) id
+                | first
+                | get name_right
+            "#
+        ));
+
+        assert_eq!(actual.out, "Andrés");
+    })
+}
+
+#[test]
+fn left_keeps_the_rows_of_the_input_without_a_match() {
+    Playground::setup("join_test_3", |dirs, sandbox| {
+        with_tables(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join $(open rustaceans.csv) id --left
+                | get name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "Andrés,Andrés,Jonathan,Jason");
+    })
+}
+
+#[test]
+fn outer_keeps_the_rows_of_both_without_a_match() {
+    Playground::setup("join_test_4", |dirs, sandbox| {
+        with_tables(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join $(open rustaceans.csv) id --outer
+                | get id
+                | str from
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "1,1,2,3,4");
+    })
+}
+
+#[test]
+fn joins_on_more_than_one_column() {
+    Playground::setup("join_test_5", |dirs, sandbox| {
+        with_tables(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join $(open rustaceans.csv) id name --right
+                | get name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "Andrés,Andrés,Jason,Yehuda");
+    })
+}
+
+#[test]
+fn errors_on_an_unknown_key() {
+    Playground::setup("join_test_6", |dirs, sandbox| {
+        with_tables(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join $(open rustaceans.csv) crate
+            "#
+        ));
+
+        assert!(actual.err.contains("Unknown column"));
+    })
+}
@@ -23,3 +23,15 @@ fn length_columns_no_rows() {
 
     assert_eq!(actual.out, "0");
 }
+
+#[test]
+fn length_counts_streamed_binary_data_as_one_item() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        nu --testbin bytes 64 | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
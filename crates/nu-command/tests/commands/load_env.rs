@@ -0,0 +1,60 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn sets_the_variables_of_a_record_from_the_input() {
+    Playground::setup("load_env_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "env.json",
+            r#"{"NAME": "nu", "PORT": 8080}"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open env.json | load-env;
+                echo $nu.env | select NAME PORT | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"NAME":"nu","PORT":"8080"}"#);
+    })
+}
+
+#[test]
+fn sets_the_variables_of_a_record_given_as_an_argument() {
+    let actual = nu!(
+        cwd: ".",
+        r#"load-env $(echo [[NAME]; [nu]]); echo $nu.env.NAME"#
+    );
+
+    assert_eq!(actual.out, "nu");
+}
+
+#[test]
+fn is_scoped_to_the_block() {
+    let actual = nu!(
+        cwd: ".",
+        r#"do { load-env $(echo [[NAME]; [nu]]) }; echo $nu.env | get NAME"#
+    );
+
+    assert!(!actual.out.contains("nu"));
+}
+
+#[test]
+fn passes_the_variables_to_externals() {
+    let actual = nu!(
+        cwd: ".",
+        r#"load-env $(echo [[NAME]; [nu]]); nu -c "echo $nu.env.NAME""#
+    );
+
+    assert_eq!(actual.out, "nu");
+}
+
+#[test]
+fn requires_a_record() {
+    let actual = nu!(cwd: ".", "echo 5 | load-env");
+
+    assert!(actual.err.contains("Expected a record"));
+}
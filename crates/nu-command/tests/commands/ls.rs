@@ -323,3 +323,95 @@ fn list_all_columns() {
         );
     });
 }
+
+#[test]
+fn quoted_paths_are_not_expanded_as_globs() {
+    Playground::setup("ls_test_glob_1", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("yehuda.txt"),
+            EmptyFile("jonathan.txt"),
+            EmptyFile("[abc].txt"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls "[abc].txt"
+                | get name
+            "#
+        ));
+
+        assert_eq!(actual.out, "[abc].txt");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"ls "*.txt""#
+        );
+
+        assert!(!actual.err.is_empty());
+    })
+}
+
+#[test]
+fn strings_can_be_turned_into_globs() {
+    Playground::setup("ls_test_glob_2", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("yehuda.txt"),
+            EmptyFile("jonathan.txt"),
+            EmptyFile("andres.md"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls $(echo "*.txt" | into glob)
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn lists_files_in_every_directory_below_with_double_asterisk() {
+    Playground::setup("ls_test_recursive_1", |dirs, sandbox| {
+        sandbox.mkdir("src/deep").with_files(vec![
+            EmptyFile("main.rs"),
+            EmptyFile("src/lib.rs"),
+            EmptyFile("src/deep/mod.rs"),
+            EmptyFile("README.md"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls **/*.rs
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
+
+#[test]
+fn lists_files_no_deeper_than_the_max_depth() {
+    Playground::setup("ls_test_recursive_2", |dirs, sandbox| {
+        sandbox.mkdir("src/deep").with_files(vec![
+            EmptyFile("main.rs"),
+            EmptyFile("src/lib.rs"),
+            EmptyFile("src/deep/mod.rs"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls **/*.rs --max-depth 2
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
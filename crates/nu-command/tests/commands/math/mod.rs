@@ -1,6 +1,7 @@
 mod avg;
 mod eval;
 mod median;
+mod percentile;
 mod sum;
 
 use nu_test_support::{nu, pipeline};
@@ -173,6 +174,43 @@ fn modulo() {
     assert_eq!(actual.out, "1");
 }
 
+#[test]
+fn bitwise_operators() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [$(= 12 bit-and 10) $(= 12 bit-or 10) $(= 12 bit-xor 10) $(= 1 bit-shl 4) $(= 256 bit-shr 2)]
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "8,14,6,16,64");
+}
+
+#[test]
+fn bitwise_operators_bind_tighter_than_comparisons() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 493 bit-and 64 == 64
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn negative_shift_is_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1 bit-shl -1
+        "#
+    ));
+
+    assert!(actual.err.contains("shift amount out of range"));
+}
+
 #[test]
 fn duration_math() {
     let actual = nu!(
@@ -256,3 +294,16 @@ fn compound_where_paren() {
 
     assert_eq!(actual.out, r#"[{"a":2,"b":1},{"a":2,"b":2}]"#);
 }
+
+#[test]
+fn membership_operators() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [$(= 1 in [1.0 2]) $(= "ell" in "hello") $(= "x" not-in $(echo [[x]; [1]] | first))]
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[true,true,false]");
+}
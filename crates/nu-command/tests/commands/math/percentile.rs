@@ -0,0 +1,54 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn percentile_between_numbers_is_interpolated() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+             echo [1 2 3 4]
+             | math percentile 50
+         "#
+    ));
+
+    assert_eq!(actual.out, "2.5")
+}
+
+#[test]
+fn percentile_at_a_number_is_the_number() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+             echo [40 10 30 20 50]
+             | math percentile 25
+         "#
+    ));
+
+    assert_eq!(actual.out, "20")
+}
+
+#[test]
+fn percentile_of_each_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+             echo [[a b]; [1 10] [2 20] [3 30]]
+             | math percentile 100
+             | get b
+         "#
+    ));
+
+    assert_eq!(actual.out, "30")
+}
+
+#[test]
+fn percentile_out_of_range_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+             echo [1 2 3]
+             | math percentile 101
+         "#
+    ));
+
+    assert!(actual.err.contains("from 0 to 100"));
+}
@@ -40,3 +40,85 @@ fn row() {
         assert_eq!(actual.out, "2");
     })
 }
+
+#[test]
+fn deep_merges_nested_rows() {
+    Playground::setup("merge_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContentToBeTrimmed(
+                "defaults.toml",
+                r#"
+                plugins = ["git"]
+
+                [server]
+                host = "localhost"
+                port = 8080
+            "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "local.toml",
+                r#"
+                plugins = ["docker"]
+
+                [server]
+                port = 9090
+            "#,
+            ),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open defaults.toml
+                | merge --deep { open local.toml }
+                | format "{server.host}:{server.port}"
+                "#
+        ));
+
+        assert_eq!(actual.out, "localhost:9090");
+    })
+}
+
+#[test]
+fn deep_merge_appends_lists() {
+    Playground::setup("merge_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContentToBeTrimmed(
+                "defaults.toml",
+                r#"
+                plugins = ["git"]
+            "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "local.toml",
+                r#"
+                plugins = ["docker"]
+            "#,
+            ),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open defaults.toml
+                | merge --deep --lists append { open local.toml }
+                | get plugins
+                | str collect ","
+                "#
+        ));
+
+        assert_eq!(actual.out, "git,docker");
+    })
+}
+
+#[test]
+fn lists_need_a_deep_merge() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[a]; [1]] | merge --lists append { echo [[b]; [2]] }
+            "#
+    ));
+
+    assert!(actual.err.contains("Lists are only merged with --deep"));
+}
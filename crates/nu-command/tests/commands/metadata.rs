@@ -0,0 +1,67 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gives_the_content_type_of_an_opened_file() {
+    Playground::setup("metadata_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "los_tres_caballeros.json",
+            r#"{ "name": "Andrés", "rusty_luck": 1 }"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.json
+                | metadata
+                | get content_type
+            "#
+        ));
+
+        assert_eq!(actual.out, "application/json");
+    })
+}
+
+#[test]
+fn gives_the_anchor_of_an_opened_file() {
+    Playground::setup("metadata_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("notes.txt", "hello")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open notes.txt
+                | metadata
+                | get anchor
+                | path basename
+            "#
+        ));
+
+        assert_eq!(actual.out, "notes.txt");
+    })
+}
+
+#[test]
+fn saves_in_the_format_the_data_was_loaded_from() {
+    Playground::setup("metadata_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "los_tres_caballeros.json",
+            r#"[{ "name": "Andrés" }, { "name": "Jonathan" }]"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.json
+                | save caballeros;
+                open caballeros
+                | from json
+                | get name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "Andrés,Jonathan");
+    })
+}
@@ -1,39 +1,62 @@
 mod append;
+mod assert;
+mod benchmark;
 mod cal;
 mod cd;
+mod chunks;
+mod columns;
 mod compact;
+mod complete;
 mod cp;
+mod dataframe;
+mod debug_profile;
+mod debug_run;
 mod def;
 mod default;
+mod difference;
+mod dirs;
+mod do_;
 mod drop;
 mod each;
 mod echo;
 mod empty;
 mod enter;
+mod env;
 mod every;
 mod first;
 mod flatten;
 mod format;
+mod generate;
 mod get;
+mod glob;
 mod group_by;
 mod hash_;
 mod headers;
 mod help;
 mod histogram;
+mod history_stats;
 mod insert;
+mod intersect;
 mod into_int;
+mod is_empty;
+mod job;
+mod join;
 mod keep;
 mod last;
 mod length;
 mod lines;
+mod load_env;
 mod ls;
 mod math;
 mod merge;
+mod metadata;
 mod mkdir;
 mod move_;
 mod open;
+mod par_each;
 mod parse;
 mod path;
+mod plugin;
 mod prepend;
 mod random;
 mod range;
@@ -52,10 +75,22 @@ mod split_by;
 mod split_column;
 mod split_row;
 mod str_;
+mod test_run;
+mod timeout;
 mod touch;
+mod transpose;
+mod union;
 mod uniq;
+mod uniq_by;
 mod update;
+mod upsert;
+mod values;
+mod version;
+mod watch;
 mod where_;
 mod which;
+mod window;
 mod with_env;
 mod wrap;
+mod z;
+mod zip_with;
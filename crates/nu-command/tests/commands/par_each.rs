@@ -0,0 +1,49 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn par_each_keeps_the_order_of_the_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5 6] | par-each --threads 3 { echo $it 10 | math sum } | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[11,12,13,14,15,16]");
+}
+
+#[test]
+fn par_each_unordered_returns_every_result() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4] | par-each --unordered { echo $(= $it * 2) } | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "20");
+}
+
+#[test]
+fn par_each_numbers_the_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [a b] | par-each --numbered { echo $it.index } | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[0,1]");
+}
+
+#[test]
+fn par_each_runs_each_row_in_a_scope_of_its_own() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5 6 7 8] | par-each { let x = $it; echo $x } | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "36");
+}
@@ -81,6 +81,41 @@ mod simple {
         })
     }
 
+    #[test]
+    fn tries_the_patterns_in_order() {
+        Playground::setup("parse_test_6", |dirs, _sandbox| {
+            let actual = nu!(
+                cwd: dirs.test(), pipeline(
+                r#"
+                    echo ["ERROR disk full" "took 12ms" "WARN low memory"]
+                    | parse "ERROR {message}" "WARN {message}" "{message}"
+                    | get pattern
+                    | str from
+                    | str collect ","
+                "#
+            ));
+
+            assert_eq!(actual.out, "0,2,1");
+        })
+    }
+
+    #[test]
+    fn leaves_out_strings_no_pattern_matches() {
+        Playground::setup("parse_test_7", |dirs, _sandbox| {
+            let actual = nu!(
+                cwd: dirs.test(), pipeline(
+                r#"
+                    echo ["a=1" "nothing here" "b:2"]
+                    | parse "{key}={value}" "{key}:{value}"
+                    | get key
+                    | str collect ","
+                "#
+            ));
+
+            assert_eq!(actual.out, "a,b");
+        })
+    }
+
     #[test]
     fn errors_when_missing_closing_brace() {
         Playground::setup("parse_test_regex_5", |dirs, _sandbox| {
@@ -168,6 +203,40 @@ mod regex {
         })
     }
 
+    #[test]
+    fn extracts_a_row_for_every_match_in_a_line() {
+        Playground::setup("parse_test_regex_4", |dirs, _sandbox| {
+            let actual = nu!(
+                cwd: dirs.test(), pipeline(
+                r#"
+                    echo "level=INFO user=nu took=12ms"
+                    | parse --regex '(?P<key>\w+)=(?P<value>\w+)'
+                    | get key
+                    | str collect ","
+                "#
+            ));
+
+            assert_eq!(actual.out, "level,user,took");
+        })
+    }
+
+    #[test]
+    fn keeps_parsing_the_lines_after_one_without_matches() {
+        Playground::setup("parse_test_regex_5", |dirs, _sandbox| {
+            let actual = nu!(
+                cwd: dirs.test(), pipeline(
+                r#"
+                    echo ["ae87582c Fix missing invocation errors (#1846)" "not a commit" "b89976da let format access variables also (#1842)"]
+                    | parse --regex '(?P<Hash>\w{8}) .+ \(#(?P<PR>\d+)\)'
+                    | get PR
+                    | str collect ","
+                "#
+            ));
+
+            assert_eq!(actual.out, "1846,1842");
+        })
+    }
+
     #[test]
     fn errors_with_invalid_regex() {
         Playground::setup("parse_test_regex_1", |dirs, sandbox| {
@@ -0,0 +1,15 @@
+use nu_test_support::nu;
+
+#[test]
+fn install_rejects_crates_that_are_not_plugins() {
+    let actual = nu!(cwd: ".", "plugin install serde");
+
+    assert!(actual.err.contains("Not the name of a plugin"));
+}
+
+#[test]
+fn uninstall_rejects_crates_that_are_not_plugins() {
+    let actual = nu!(cwd: ".", "plugin uninstall serde");
+
+    assert!(actual.err.contains("Not the name of a plugin"));
+}
@@ -105,3 +105,16 @@ fn error_reduce_empty() {
 
     assert!(actual.err.contains("needs input"));
 }
+
+#[test]
+fn reduce_with_block_parameters() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 1 2 3 4 | reduce -f 10 {|num, total| = $total + $num }
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "20");
+}
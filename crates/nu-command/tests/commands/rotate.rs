@@ -81,3 +81,33 @@ fn clockwise() {
 
     assert_eq!(actual.out, expected.out);
 }
+
+#[test]
+fn ccw_rotates_like_counter_clockwise() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name stars]; [nu 5]]
+            | rotate --ccw
+            | get Column0
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "stars,name");
+}
+
+#[test]
+fn names_the_rotated_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name stars]; [nu 5]]
+            | rotate value field
+            | where field == name
+            | get value
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu");
+}
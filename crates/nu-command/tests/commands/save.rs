@@ -47,3 +47,31 @@ fn writes_out_csv() {
         assert!(actual.contains("nu,0.14,A new type of shell,MIT,2018"));
     })
 }
+
+#[test]
+fn writes_out_the_binary_output_of_an_external_as_it_comes() {
+    Playground::setup("save_test_3", |dirs, _| {
+        nu!(
+            cwd: dirs.test(),
+            "nu --testbin bytes 4096 | save out.gz"
+        );
+
+        let expected: Vec<u8> = (0..4096).flat_map(|_| (0..=255).rev()).collect();
+        let actual = std::fs::read(dirs.test().join("out.gz")).expect("saved file");
+
+        assert_eq!(actual, expected);
+    })
+}
+
+#[test]
+fn writes_out_the_text_of_an_external() {
+    Playground::setup("save_test_4", |dirs, _| {
+        nu!(
+            cwd: dirs.test(),
+            "nu --testbin cococo andres | save out.txt"
+        );
+
+        let actual = file_contents(dirs.test().join("out.txt"));
+        assert_eq!(actual.trim_end(), "andres");
+    })
+}
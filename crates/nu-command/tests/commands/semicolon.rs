@@ -27,3 +27,53 @@ fn semicolon_lhs_error_stops_processing() {
 
     assert!(!actual.out.contains("done"));
 }
+
+#[test]
+fn semicolon_returns_only_the_last_value() {
+    let actual = nu!(
+        cwd: ".",
+        "echo 1 2 3; echo 4"
+    );
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn semicolon_runs_every_statement_in_order() {
+    Playground::setup("semicolon_test_3", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mkdir foo; cd foo; touch bar.txt; ls | get name | str collect"
+        );
+
+        assert!(dirs.test().join("foo").join("bar.txt").exists());
+        assert_eq!(actual.out, "bar.txt");
+    })
+}
+
+#[test]
+fn semicolon_lhs_runs_to_the_end() {
+    Playground::setup("semicolon_test_4", |dirs, _sandbox| {
+        nu!(
+            cwd: dirs.test(),
+            "echo a b c | each { mkdir $it }; echo done"
+        );
+
+        assert!(dirs.test().join("a").exists());
+        assert!(dirs.test().join("c").exists());
+    })
+}
+
+#[test]
+fn semicolon_error_in_the_middle_stops_the_rest() {
+    Playground::setup("semicolon_test_5", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "touch first.txt; open missing.txt; touch last.txt"
+        );
+
+        assert!(dirs.test().join("first.txt").exists());
+        assert!(!dirs.test().join("last.txt").exists());
+        assert!(!actual.err.is_empty());
+    })
+}
@@ -144,3 +144,58 @@ fn ls_sort_by_type_name_insensitive() {
 
     assert_eq!(actual.out, json_output);
 }
+
+#[test]
+fn sorts_through_temporary_files_when_over_the_memory_budget() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [e c i a g b h]
+            | sort-by --memory-budget 1b
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "a,b,c,e,g,h,i");
+}
+
+#[test]
+fn sorts_rows_in_reverse_through_temporary_files() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample-ls-output.json
+            | sort-by -i -r name --memory-budget 1b
+            | get name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "C,B.txt,a.txt");
+}
+
+#[test]
+fn sorts_ties_in_reverse_the_same_through_temporary_files() {
+    let in_memory = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name id]; [b 1] [a 2] [b 3] [a 4] [b 5]]
+            | sort-by -r name
+            | get id
+            | str collect ","
+        "#
+    ));
+
+    let spilled = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name id]; [b 1] [a 2] [b 3] [a 4] [b 5]]
+            | sort-by -r name --memory-budget 1b
+            | get id
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(in_memory.out, "5,3,1,4,2");
+    assert_eq!(spilled.out, in_memory.out);
+}
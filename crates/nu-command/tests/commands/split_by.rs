@@ -52,3 +52,60 @@ fn errors_if_no_table_given_as_input() {
         assert!(actual.err.contains("Expected table from pipeline"));
     })
 }
+
+#[test]
+fn splits_groups_of_groups() {
+    Playground::setup("split_by_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type,lang
+                Andrés,Robalino,10/11/2013,A,rust
+                Jonathan,Turner,10/12/2013,B,rust
+                Yehuda,Katz,10/11/2013,A,ruby
+                Jason,Gedge,10/11/2013,A,rust
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by rusty_at type
+                | split-by lang
+                | get rust."10/11/2013".A.first_name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "Andrés,Jason");
+    })
+}
+
+#[test]
+fn leaves_out_groups_without_the_value() {
+    Playground::setup("split_by_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+                Yehuda,Katz,10/11/2013,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by rusty_at
+                | split-by type
+                | get B
+                | get "10/11/2013"
+            "#
+        ));
+
+        assert!(actual.err.contains("Unknown column"));
+    })
+}
@@ -354,3 +354,43 @@ fn str_reverse() {
 
     assert!(actual.out.contains("llehsun"));
 }
+
+#[test]
+fn title_cases() {
+    Playground::setup("str_test_14", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                     [package]
+                     name = "nu-arepas"
+                 "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                 open sample.toml
+                 | str title-case package.name
+                 | get package.name
+             "#
+        ));
+
+        assert_eq!(actual.out, "Nu Arepas");
+    })
+}
+
+#[test]
+fn distance_to_the_string_given() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name]; [nushell] [nutshell] [bash]]
+        | str distance nushell name
+        | get name
+        | str from
+        | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "0,1,5");
+}
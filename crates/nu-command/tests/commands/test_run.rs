@@ -0,0 +1,97 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn reports_the_tests_that_pass_and_fail() {
+    Playground::setup("test_run_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "tests.nu",
+            r#"
+            def double [x] { = $x * 2 }
+            def test_doubles [] { assert equal $(double 2) 4 }
+            def test_doubles_wrong [] { assert equal $(double 2) 5 }
+            def helper [] { assert $false }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+            test run tests.nu
+            | each { echo $it.test $it.result | str collect " " }
+            | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "test_doubles passed,test_doubles_wrong failed");
+    })
+}
+
+#[test]
+fn reports_the_line_a_test_failed_on() {
+    Playground::setup("test_run_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "tests.nu",
+            "def test_fails [] {\n  let x = 3\n  assert $x > 5\n}\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+            test run tests.nu | get error
+            "#
+        ));
+
+        assert!(actual.out.contains("Assertion failed"));
+        assert!(actual.out.contains("line 3"));
+    })
+}
+
+#[test]
+fn runs_each_test_on_its_own() {
+    Playground::setup("test_run_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "tests.nu",
+            r#"
+            let-env GREETING = hello
+            def test_changes_the_environment [] { let-env GREETING = bye; cd .. }
+            def test_sees_the_environment_of_the_file [] {
+                assert equal $nu.env.GREETING hello
+                assert equal $(pwd | path basename) test_run_test_3
+            }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+            test run tests.nu | where result == passed | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn runs_only_the_tests_matching_the_filter() {
+    Playground::setup("test_run_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "tests.nu",
+            r#"
+            def test_parse_numbers [] { assert equal $(echo "12" | str to-int) 12 }
+            def test_format_numbers [] { assert $false }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+            test run tests.nu --filter parse | get test
+            "#
+        ));
+
+        assert_eq!(actual.out, "test_parse_numbers");
+    })
+}
@@ -0,0 +1,51 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gives_the_output_of_a_block_that_finishes_in_time() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            timeout 10sec { echo [1 2 3] | math sum }
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn stops_a_block_that_takes_too_long() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            timeout 100ms { sleep 10sec; echo done }
+        "#
+    ));
+
+    assert_eq!(actual.out, "");
+    assert!(actual.err.contains("Timed out"));
+}
+
+#[test]
+fn kills_an_external_that_takes_too_long() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            timeout 500ms { nu --testbin iecho y }
+        "#
+    ));
+
+    assert!(actual.err.contains("Timed out"));
+}
+
+#[test]
+fn errors_in_time_are_not_timeouts() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            timeout 10sec { echo [1 2] | get nonexistent }
+        "#
+    ));
+
+    assert!(!actual.err.contains("Timed out"));
+    assert!(actual.err.contains("Unknown column"));
+}
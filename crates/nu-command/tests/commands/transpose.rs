@@ -0,0 +1,75 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn turns_columns_into_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name stars]; [nu 5] [jq 2]]
+            | transpose
+            | where Column0 == name
+            | format "{Column1},{Column2}"
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu,jq");
+}
+
+#[test]
+fn names_the_key_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name stars]; [nu 5]]
+            | transpose --key-column field value
+            | get field
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "name,stars");
+}
+
+#[test]
+fn uses_the_first_column_as_headers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[key value]; [name nu] [stars 5]]
+            | transpose --header-row
+            | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu");
+}
+
+#[test]
+fn skips_the_columns_not_every_row_has() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name stars]; [nu 5]] [[name]; [jq]]
+            | transpose --skip-incomplete
+            | get Column0
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "name");
+}
+
+#[test]
+fn fills_the_columns_not_every_row_has() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name stars]; [nu 5]] [[name]; [jq]]
+            | transpose
+            | get Column0
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "name,stars");
+}
@@ -0,0 +1,17 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn union_keeps_the_first_row_for_each_key() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name version]; [nu '0.28'] [git '2.30']]
+        | union $(echo [[name version]; [git '2.31'] [jq '1.6']]) name
+        | get version
+        | str collect ","
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "0.28,2.30,1.6");
+}
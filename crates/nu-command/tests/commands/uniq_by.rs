@@ -0,0 +1,88 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn keeps_the_first_row_of_each_key() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name lang]; [nu rust] [jq c] [ripgrep rust] [curl c]]
+            | uniq-by lang
+            | get name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu,jq");
+}
+
+#[test]
+fn keeps_the_last_row_of_each_key() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name lang]; [nu rust] [jq c] [ripgrep rust] [curl c]]
+            | uniq-by lang --last
+            | get name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "ripgrep,curl");
+}
+
+#[test]
+fn tells_rows_apart_by_more_than_one_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name lang year]; [nu rust 2019] [jq c 2012] [ripgrep rust 2016] [sled rust 2016]]
+            | uniq-by lang year
+            | get name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu,jq,ripgrep");
+}
+
+#[test]
+fn tells_rows_apart_by_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nushell] [nuclear] [ripgrep]]
+            | uniq-by { echo $it.name | str substring 0,2 }
+            | get name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "nushell,ripgrep");
+}
+
+#[test]
+fn counts_the_rows_of_each_key() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name lang]; [nu rust] [jq c] [ripgrep rust]]
+            | uniq-by lang --count
+            | format "{lang}:{count}"
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "rust:2,c:1");
+}
+
+#[test]
+fn errors_on_an_unknown_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name lang]; [nu rust]] | uniq-by language
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown column"));
+}
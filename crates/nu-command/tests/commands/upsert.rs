@@ -0,0 +1,59 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn updates_a_column_that_exists() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | upsert dev-dependencies.pretty_assertions "0.7.0"
+            | get dev-dependencies.pretty_assertions
+        "#
+    ));
+
+    assert_eq!(actual.out, "0.7.0");
+}
+
+#[test]
+fn inserts_a_column_that_does_not_exist() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | upsert dev-dependencies.assert_cmd "1.0.0"
+            | get dev-dependencies.assert_cmd
+        "#
+    ));
+
+    assert_eq!(actual.out, "1.0.0");
+}
+
+#[test]
+fn inserts_the_missing_rows_of_a_nested_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | upsert metadata.docs.rs "all-features"
+            | get metadata.docs.rs
+        "#
+    ));
+
+    assert_eq!(actual.out, "all-features");
+}
+
+#[test]
+fn sets_the_column_of_each_row_from_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name stars]; [nu 5] [jq 2]]
+            | upsert stars { = $it.stars * 2 }
+            | upsert initial { echo $it.name | str substring 0,1 }
+            | format "{initial}{stars}"
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "n10,j4");
+}
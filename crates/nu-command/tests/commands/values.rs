@@ -0,0 +1,32 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gives_the_values_of_a_record() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name lang]; [nu rust]]
+            | values
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu,rust");
+}
+
+#[test]
+fn gives_nothing_for_rows_missing_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu]]
+            | append $(echo [[name size]; [rust 5]])
+            | values
+            | nth 1
+            | compact
+            | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
@@ -0,0 +1,29 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn has_the_parts_of_the_version() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        version
+        | each { echo `{{$it.semver.major}}.{{$it.semver.minor}}.{{$it.semver.patch}}` }
+        "#
+    ));
+
+    assert_eq!(actual.out, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn lists_the_features_it_was_built_with() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        version
+        | get features
+        | where $it == core
+        | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
@@ -0,0 +1,37 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+use std::time::Duration;
+
+#[test]
+fn reports_a_created_file() {
+    Playground::setup("watch_test_1", |dirs, sandbox| {
+        sandbox.mkdir("src");
+
+        // The file is made once the watcher has had time to start
+        let file = dirs.test().join("src").join("main.rs");
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_secs(2));
+            std::fs::write(file, "fn main() {}").expect("can not create file");
+        });
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "watch src --count 1 | get op"
+        );
+
+        writer.join().expect("writer panicked");
+        assert_eq!(actual.out, "create");
+    })
+}
+
+#[test]
+fn fails_on_a_path_that_does_not_exist() {
+    Playground::setup("watch_test_2", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "watch missing"
+        );
+
+        assert!(actual.err.contains("Cannot watch"));
+    })
+}
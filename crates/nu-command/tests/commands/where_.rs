@@ -1,7 +1,4 @@
-use nu_test_support::nu;
-
-#[cfg(feature = "sqlite")]
-use nu_test_support::pipeline;
+use nu_test_support::{nu, pipeline};
 
 #[test]
 fn filters_by_unit_size_comparison() {
@@ -43,6 +40,77 @@ fn where_not_in_table() {
     assert_eq!(actual.out, "4");
 }
 
+#[test]
+fn where_column_in_record() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[key]; [a] [b] [c]]
+        | where key in $(echo [[a c]; [1 2]] | first)
+        | get key
+        | str collect
+        "#
+    ));
+
+    assert_eq!(actual.out, "ac");
+}
+
+#[test]
+fn where_substring_in_string() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name]; [cat] [dog] [bird]]
+        | where name not-in "catdog"
+        | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "bird");
+}
+
+#[test]
+fn where_combines_bare_conditions() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name size type]; [a 5 File] [b 20 Dir] [c 30 File] [d 40 Dir]]
+        | where size > 10 && type == Dir
+        | get name
+        | str collect
+        "#
+    ));
+
+    assert_eq!(actual.out, "bd");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name size type]; [a 5 File] [b 20 Dir] [c 30 File] [d 40 Dir]]
+        | where size < 10 || type == Dir && size > 30
+        | get name
+        | str collect
+        "#
+    ));
+
+    assert_eq!(actual.out, "ad");
+}
+
+#[test]
+fn where_bare_boolean_column_after_and() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo '[{"name": "a", "size": 5, "active": true}, {"name": "b", "size": 20, "active": false}, {"name": "c", "size": 30, "active": true}]'
+        | from json
+        | where size > 10 && active
+        | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "c");
+}
+
 #[cfg(feature = "sqlite")]
 #[test]
 fn explicit_block_condition() {
@@ -50,8 +118,7 @@ fn explicit_block_condition() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where {= $it.z > 4200}
             | get z
@@ -68,8 +135,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where z > 4200
             | get z
@@ -82,8 +148,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where z >= 4253
             | get z
@@ -96,8 +161,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where z < 10
             | get z
@@ -110,8 +174,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where z <= 1
             | get z
@@ -124,8 +187,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | where z != 1
             | first 1
             | get z
@@ -142,8 +204,7 @@ fn contains_operator() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == strings
-            | get table_values
+            | get strings
             | where x =~ ell
             | length
         "#
@@ -155,8 +216,7 @@ fn contains_operator() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == strings
-            | get table_values
+            | get strings
             | where x !~ ell
             | length
         "#
@@ -80,17 +80,53 @@ fn multiple_reports_of_multiple_defs() {
     assert_eq!(length, 2);
 }
 
-//Fails due to ParserScope::add_definition
-// frame.custom_commands.insert(name.clone(), block.clone());
-// frame.commands.insert(name, whole_stream_command(block));
-#[ignore]
 #[test]
 fn def_only_seen_once() {
     let actual = nu!(
         cwd: ".",
         "def xaz [] {echo def1}; which -a xaz | length"
     );
-    //length is 2. One custom_command (def) one built in ("wrongly" added)
+
     let length: i32 = actual.out.parse().unwrap();
     assert_eq!(length, 1);
 }
+
+#[test]
+fn reports_the_kind_of_command() {
+    let actual = nu!(
+        cwd: ".",
+        "which ls | get kind"
+    );
+
+    assert_eq!(actual.out, "builtin");
+}
+
+#[test]
+fn alias_shadows_the_builtin() {
+    let actual = nu!(
+        cwd: ".",
+        "alias ls = ls -a; which ls | get shadows"
+    );
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn lists_every_kind_in_order() {
+    let actual = nu!(
+        cwd: ".",
+        "alias ls = ls -a; which -a ls | where kind != external | get kind | str collect ','"
+    );
+
+    assert_eq!(actual.out, "alias,builtin");
+}
+
+#[test]
+fn last_resolution_shadows_nothing() {
+    let actual = nu!(
+        cwd: ".",
+        "which -a which | last | get shadows"
+    );
+
+    assert_eq!(actual.out, "false");
+}
@@ -0,0 +1,49 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn slides_one_row_at_a_time() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5] | window 3 | each { echo $it | math sum } | str from | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "6,9,12");
+}
+
+#[test]
+fn skips_the_rows_between_windows_farther_apart_than_their_size() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5 6 7] | window 2 --stride 3 | each { echo $it | math sum } | str from | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "3,9");
+}
+
+#[test]
+fn gives_nothing_for_fewer_rows_than_the_size() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2] | window 3 | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn fails_with_a_stride_of_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3] | window 2 --stride 0
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a number bigger than 0"));
+}
@@ -64,3 +64,43 @@ fn with_env_shorthand_nested_quotes() {
 
     assert_eq!(actual.out, "-arg \"hello world\"");
 }
+
+#[test]
+fn with_env_shorthand_can_set_multiple_variables() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "FOO=BARRRR BAZ=QUUUX echo $nu.env | select FOO BAZ | to json"
+    );
+
+    assert_eq!(actual.out, r#"{"FOO":"BARRRR","BAZ":"QUUUX"}"#);
+}
+
+#[test]
+fn with_env_shorthand_keeps_equals_in_value() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "FOO=a=b echo $nu.env | get FOO"
+    );
+
+    assert_eq!(actual.out, "a=b");
+}
+
+#[test]
+fn with_env_shorthand_is_scoped_to_the_command() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "FOO=BARRRR echo done; echo $nu.env | get FOO"
+    );
+
+    assert!(!actual.out.contains("BARRRR"));
+}
+
+#[test]
+fn with_env_shorthand_requires_a_command() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "FOO=BARRRR BAZ=QUUUX"
+    );
+
+    assert!(actual.err.contains("a command following variable"));
+}
@@ -0,0 +1,67 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+use std::path::PathBuf;
+
+fn store(dirs: &[(PathBuf, u64)]) -> String {
+    dirs.iter()
+        .map(|(path, rank)| format!("{}|{}|0\n", path.display(), rank))
+        .collect()
+}
+
+#[test]
+fn changes_to_the_most_visited_match() {
+    Playground::setup("z_test_1", |dirs, sandbox| {
+        sandbox.mkdir("projects/odin").mkdir("archive/odin");
+        let contents = store(&[
+            (dirs.test().join("archive/odin"), 2),
+            (dirs.test().join("projects/odin"), 5),
+        ]);
+        sandbox.with_files(vec![FileWithContent("dirs.txt", &contents)]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                z odin --store dirs.txt
+                echo $(pwd)
+            "#
+        );
+
+        assert_eq!(PathBuf::from(actual.out), dirs.test().join("projects/odin"));
+    })
+}
+
+#[test]
+fn lists_the_matches_that_still_exist() {
+    Playground::setup("z_test_2", |dirs, sandbox| {
+        sandbox.mkdir("projects/odin");
+        let contents = store(&[
+            (dirs.test().join("projects/odin"), 1),
+            (dirs.test().join("removed/odin"), 3),
+        ]);
+        sandbox.with_files(vec![FileWithContent("dirs.txt", &contents)]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "z odin --list --store dirs.txt | length"
+        );
+
+        assert_eq!(actual.out, "1");
+    })
+}
+
+#[test]
+fn fails_when_nothing_matches() {
+    Playground::setup("z_test_3", |dirs, sandbox| {
+        sandbox.mkdir("projects/odin");
+        let contents = store(&[(dirs.test().join("projects/odin"), 1)]);
+        sandbox.with_files(vec![FileWithContent("dirs.txt", &contents)]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "z thor --store dirs.txt"
+        );
+
+        assert!(actual.err.contains("No visited directory matches"));
+    })
+}
@@ -0,0 +1,45 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn pairs_up_the_input_with_a_block_output() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [a b c]
+        | zip-with { echo [1 2 3] } { echo `{{$it.left}}{{$it.right}}` }
+        | str collect
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "a1b2c3");
+}
+
+#[test]
+fn stops_at_the_shorter_side() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4]
+        | zip-with [10 20] {|a, b| = $a + $b }
+        | math sum
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "33");
+}
+
+#[test]
+fn strict_fails_on_length_mismatch() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3]
+        | zip-with [10 20] --strict {|a, b| = $a + $b }
+        "#
+        )
+    );
+
+    assert!(actual.err.contains("Lengths don't match"));
+}
@@ -0,0 +1,190 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[cfg(feature = "extra-formats")]
+#[test]
+fn from_srt_text_to_table() {
+    Playground::setup("filter_from_srt_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "movie.srt",
+            r#"
+                1
+                00:00:01,000 --> 00:00:04,000
+                Hello there.
+
+                2
+                00:00:05,500 --> 00:00:07,250
+                General
+                Kenobi!
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open movie.srt
+                | where start > 5sec
+                | get text
+                | str find-replace "\n" " "
+            "#
+        ));
+
+        assert_eq!(actual.out, "General Kenobi!");
+    })
+}
+
+#[cfg(feature = "extra-formats")]
+#[test]
+fn from_vtt_skips_notes_and_reads_settings() {
+    Playground::setup("filter_from_vtt_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "captions.vtt",
+            r#"
+                WEBVTT
+
+                NOTE written by hand
+
+                intro
+                00:01.000 --> 00:04.000 align:start
+                Never drink liquid nitrogen.
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open captions.vtt
+                | get settings
+            "#
+        ));
+
+        assert_eq!(actual.out, "align:start");
+    })
+}
+
+#[cfg(feature = "extra-formats")]
+#[test]
+fn from_access_log_text_to_table() {
+    Playground::setup("filter_from_access_log_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "access.log",
+            r#"
+                127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326
+                10.0.0.7 - - [10/Oct/2000:13:56:01 -0700] "POST /login HTTP/1.1" 302 - "https://example.com/" "curl/7.68.0"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open access.log
+                | from access-log
+                | where status == 302
+                | get agent
+            "#
+        ));
+
+        assert_eq!(actual.out, "curl/7.68.0");
+    })
+}
+
+#[cfg(feature = "extra-formats")]
+#[test]
+fn from_journald_text_to_table() {
+    Playground::setup("filter_from_journald_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "journal.export",
+            r#"
+                __REALTIME_TIMESTAMP=1342540861416409
+                _SYSTEMD_UNIT=sshd.service
+                MESSAGE=Accepted publickey
+
+                __REALTIME_TIMESTAMP=1342540861421465
+                _SYSTEMD_UNIT=cron.service
+                MESSAGE=Job started
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open journal.export
+                | from journald
+                | where _SYSTEMD_UNIT == cron.service
+                | get MESSAGE
+            "#
+        ));
+
+        assert_eq!(actual.out, "Job started");
+    })
+}
+
+#[test]
+fn from_logs_detects_each_line_format() {
+    Playground::setup("filter_from_logs_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "app.log",
+            r#"
+                {"time": "2021-03-01T10:00:00Z", "level": "INFO", "msg": "started", "port": 8080}
+                <11>Mar  1 10:00:01 web01 nginx[1234]: upstream timed out
+                level=warning msg="disk almost full" mount=/var
+                127.0.0.1 - - [01/Mar/2021:10:00:02 +0000] "GET /missing HTTP/1.1" 404 0
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open app.log
+                | from logs
+                | get level
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["info","error","warn","warn"]"#);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open app.log
+                | from logs
+                | where format == syslog
+                | get source
+            "#
+        ));
+
+        assert_eq!(actual.out, "nginx");
+    })
+}
+
+#[test]
+fn from_logs_joins_lines_split_across_the_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo ["level=in" "fo msg=a\nlevel=err" "or msg=b"]
+            | from logs
+            | get level
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["info","error"]"#);
+}
+
+#[test]
+fn from_logs_gives_the_rows_as_the_lines_arrive() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            nu --testbin iecho "level=error msg=down"
+            | from logs
+            | first 1
+            | get message
+        "#
+    ));
+
+    assert_eq!(actual.out, "down");
+}
@@ -0,0 +1,130 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn to_fixed_width_aligns_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name size]; [a.txt 120] [notes.md 8]]
+        | to fixed-width
+        | lines
+        | nth 2
+        "#
+    ));
+
+    assert_eq!(actual.out, "notes.md    8");
+}
+
+#[test]
+fn to_fixed_width_cuts_cells_to_width() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[code label]; [ABC123 something-long]]
+        | to fixed-width --widths $(echo [[code label]; [3 4]] | first) --no-headers
+        "#
+    ));
+
+    assert_eq!(actual.out, "ABCsome");
+}
+
+#[test]
+fn to_fixed_width_rejects_unknown_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[code]; [A]]
+        | to fixed-width --align $(echo [[name]; [left]] | first)
+        "#
+    ));
+
+    assert!(actual.err.contains("no column named 'name'"));
+}
+
+#[test]
+fn from_fixed_width_with_column_spec() {
+    Playground::setup("from_fixed_width_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "accounts.txt",
+            r#"
+                0042Ann Smith    +0012.50Y
+                0007Bob Jones    -0003.00N
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open accounts.txt
+                | from fixed-width --columns [[name start length type]; [id 0 4 int] [owner 4 13] [balance 17 8 decimal] [active 25 1 bool]]
+                | where active == $true
+                | get id
+            "#
+        ));
+
+        assert_eq!(actual.out, "42");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open accounts.txt
+                | from fixed-width --columns [[name start length type]; [id 0 4 int] [owner 4 13] [balance 17 8 decimal] [active 25 1 bool]]
+                | where balance < 0
+                | get owner
+            "#
+        ));
+
+        assert_eq!(actual.out, "Bob Jones");
+    })
+}
+
+#[test]
+fn from_fixed_width_infers_columns() {
+    Playground::setup("from_fixed_width_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "report.txt",
+            r#"
+                name      size
+                a.txt      120
+                notes.md     8
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open report.txt
+                | from fixed-width
+                | get size
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "120,8");
+    })
+}
+
+#[test]
+fn from_fixed_width_reports_bad_values() {
+    Playground::setup("from_fixed_width_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "ids.txt",
+            r#"
+                0001
+                00x2
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open ids.txt
+                | from fixed-width --columns [[name start type]; [id 0 int]]
+            "#
+        ));
+
+        assert!(actual.err.contains("line 2"));
+    })
+}
@@ -0,0 +1,58 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_logfmt_text_to_table() {
+    Playground::setup("filter_from_logfmt_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "app.txt",
+            r#"
+                level=info msg="server started" port=8080
+                level=error msg="connection \"db\" lost" retry
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open app.txt
+                | from logfmt
+                | where level == error
+                | get msg
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"connection "db" lost"#);
+    })
+}
+
+#[test]
+fn from_logfmt_collects_duplicate_keys() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "tag=a tag=b tag=c"
+            | from logfmt
+            | get tag
+            | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn logfmt_roundtrip() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[level msg]; [warn 'disk is "full"']]
+            | to logfmt
+            | from logfmt
+            | get msg
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"disk is "full""#);
+}
@@ -1,11 +1,16 @@
 mod bson;
 mod csv;
 mod eml;
+mod extra_formats;
+mod fixed_width;
 mod html;
 mod ics;
 mod json;
+mod logfmt;
 mod markdown;
 mod ods;
+mod prometheus;
+mod sql;
 mod sqlite;
 mod ssv;
 mod toml;
@@ -0,0 +1,61 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_prometheus_text_to_table() {
+    Playground::setup("filter_from_prometheus_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "metrics.txt",
+            r#"
+                # HELP http_requests_total The total number of HTTP requests.
+                # TYPE http_requests_total counter
+                http_requests_total{method="post",code="200"} 1027 1395066363000
+                http_requests_total{method="post",code="400"} 3 1395066363000
+                # TYPE temperature gauge
+                temperature 21.5
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open metrics.txt
+                | from prometheus
+                | where metric == http_requests_total
+                | where labels.code == "400"
+                | get value
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open metrics.txt
+                | from prometheus
+                | where metric == temperature
+                | get type
+            "#
+        ));
+
+        assert_eq!(actual.out, "gauge");
+    })
+}
+
+#[test]
+fn prometheus_roundtrip() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'jobs_total{queue="mail"} 7'
+            | from prometheus
+            | to prometheus
+            | from prometheus
+            | get labels.queue
+        "#
+    ));
+
+    assert_eq!(actual.out, "mail");
+}
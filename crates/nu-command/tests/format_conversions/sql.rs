@@ -0,0 +1,55 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn to_sql_batches_inserts() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[n]; [1] [2] [3]]
+        | to sql --batch-size 2 --insert-only
+        | lines
+        | where $it =~ INSERT
+        | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn to_sql_maps_types_per_dialect() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[a b c]; [1 1.5 $true] [2 3 $false]]
+        | to sql -d postgres
+        | lines
+        | skip 1
+        | first 3
+        | str trim
+        | str collect " "
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#""a" BIGINT, "b" DOUBLE PRECISION, "c" BOOLEAN"#
+    );
+}
+
+#[test]
+fn to_sql_stores_mixed_columns_as_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[v]; [1] [two]]
+        | to sql
+        | lines
+        | last 2
+        | str trim
+        | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, "('1'), ('two');");
+}
@@ -1,4 +1,6 @@
 #[cfg(feature = "sqlite")]
+use nu_test_support::playground::Playground;
+#[cfg(feature = "sqlite")]
 use nu_test_support::{nu, pipeline};
 
 #[cfg(feature = "sqlite")]
@@ -10,7 +12,7 @@ fn table_to_sqlite_and_back_into_table() {
             open sample.db
             | to sqlite
             | from sqlite
-            | get table_values
+            | get strings
             | nth 2
             | get x
         "#
@@ -18,3 +20,37 @@ fn table_to_sqlite_and_back_into_table() {
 
     assert_eq!(actual.out, "hello");
 }
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn into_sqlite_creates_and_upserts_rows() {
+    Playground::setup("into_sqlite_test_1", |dirs, _| {
+        nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo [[name version]; [nu 27] [ripgrep 12]]
+                | into sqlite packages.db -t packages --upsert name
+            "#
+        ));
+
+        nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo [[name version]; [nu 28]]
+                | into sqlite packages.db -t packages --upsert name
+            "#
+        ));
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open packages.db
+                | get packages
+                | where name == nu
+                | get version
+            "#
+        ));
+
+        assert_eq!(actual.out, "28");
+    })
+}
@@ -40,6 +40,9 @@ pub enum InlineShape {
 
     // TODO: Block arguments
     Block,
+    // How many rows and columns the dataframe has
+    #[cfg(feature = "dataframe")]
+    DataFrame(usize, usize),
     // TODO: Error type
     Error,
 
@@ -123,6 +126,11 @@ impl InlineShape {
             UntaggedValue::Table(table) => InlineShape::from_table(table.iter()),
             UntaggedValue::Error(_) => InlineShape::Error,
             UntaggedValue::Block(_) => InlineShape::Block,
+            #[cfg(feature = "dataframe")]
+            UntaggedValue::DataFrame(dataframe) => {
+                let (rows, columns) = dataframe.shape();
+                InlineShape::DataFrame(rows, columns)
+            }
         }
     }
 
@@ -311,6 +319,21 @@ impl PrettyDebug for FormatInlineShape {
             )
             .group(),
             InlineShape::Block => DbgDocBldr::opaque("block"),
+            #[cfg(feature = "dataframe")]
+            InlineShape::DataFrame(rows, columns) => DbgDocBldr::delimit(
+                "[",
+                DbgDocBldr::kind("dataframe")
+                    + DbgDocBldr::space()
+                    + DbgDocBldr::primitive(rows)
+                    + DbgDocBldr::space()
+                    + DbgDocBldr::description("rows")
+                    + DbgDocBldr::space()
+                    + DbgDocBldr::primitive(columns)
+                    + DbgDocBldr::space()
+                    + DbgDocBldr::description("columns"),
+                "]",
+            )
+            .group(),
             InlineShape::Error => DbgDocBldr::error("error"),
             InlineShape::BeginningOfStream => DbgDocBldr::blank(),
             InlineShape::EndOfStream => DbgDocBldr::blank(),
@@ -113,6 +113,8 @@ fn helper(v: &Value) -> Result<toml::Value, ShellError> {
         UntaggedValue::Table(l) => toml::Value::Array(collect_values(l)?),
         UntaggedValue::Error(e) => return Err(e.clone()),
         UntaggedValue::Block(_) => toml::Value::String("<Block>".to_string()),
+        #[cfg(feature = "dataframe")]
+        UntaggedValue::DataFrame(_) => toml::Value::String("<DataFrame>".to_string()),
         UntaggedValue::Primitive(Primitive::Range(_)) => toml::Value::String("<Range>".to_string()),
         UntaggedValue::Primitive(Primitive::Binary(b)) => {
             toml::Value::Array(b.iter().map(|x| toml::Value::Integer(*x as i64)).collect())
@@ -216,6 +218,15 @@ pub fn user_data() -> Result<PathBuf, ShellError> {
     Ok(std::path::PathBuf::from("/"))
 }
 
+/// The directory `plugin install` manages. Cargo installs the plugins into the `bin` directory
+/// inside it, which is searched for plugins at startup.
+pub fn managed_plugins_path() -> Result<PathBuf, ShellError> {
+    let mut path = user_data()?;
+    path.push("plugins");
+
+    Ok(path)
+}
+
 #[derive(Debug, Clone)]
 pub enum Status {
     LastModified(std::time::SystemTime),
@@ -263,7 +274,7 @@ pub fn read(
     trace!("config file = {}", filename.display());
 
     let tag = tag.into();
-    let contents = fs::read_to_string(filename)
+    let contents = fs::read_to_string(&filename)
         .map(|v| v.tagged(&tag))
         .map_err(|err| {
             ShellError::labeled_error(
@@ -275,7 +286,7 @@ pub fn read(
 
     let parsed: toml::Value = toml::from_str(&contents).map_err(|err| {
         ShellError::labeled_error(
-            &format!("Couldn't parse config file:\n{}", err),
+            parse_error_message(&filename, &contents, &err),
             "file name",
             &tag,
         )
@@ -292,8 +303,118 @@ pub fn read(
     }
 }
 
+/// Reads the config file like `read`, then applies the `[[when]]` sections whose `os` and `host`
+/// match this machine over the rest, so one file can serve several machines. Rows are merged key
+/// by key, anything else is replaced.
+pub fn load(
+    tag: impl Into<Tag>,
+    at: &Option<PathBuf>,
+) -> Result<IndexMap<String, Value>, ShellError> {
+    let mut config = read(tag, at)?;
+
+    if let Some(sections) = config.shift_remove("when") {
+        let sections = match sections.value {
+            UntaggedValue::Table(sections) => sections,
+            _ => vec![sections],
+        };
+
+        for section in sections {
+            if let UntaggedValue::Row(Dictionary { entries }) = section.value {
+                if condition_holds(entries.get("os"), || {
+                    vec![
+                        std::env::consts::OS.to_string(),
+                        std::env::consts::FAMILY.to_string(),
+                    ]
+                }) && condition_holds(entries.get("host"), || host_name().into_iter().collect())
+                {
+                    let overrides = entries
+                        .into_iter()
+                        .filter(|(key, _)| key != "os" && key != "host")
+                        .collect();
+                    merge_into(&mut config, overrides);
+                }
+            }
+        }
+    }
+
+    Ok(config)
+}
+
+/// A missing condition always holds; otherwise one of the names it lists has to be current
+fn condition_holds(wanted: Option<&Value>, current: impl FnOnce() -> Vec<String>) -> bool {
+    let wanted = match wanted {
+        Some(wanted) => wanted,
+        None => return true,
+    };
+
+    let current = current();
+    let wanted = match &wanted.value {
+        UntaggedValue::Table(names) => names.clone(),
+        _ => vec![wanted.clone()],
+    };
+
+    wanted
+        .iter()
+        .filter_map(|name| name.as_string().ok())
+        .any(|name| {
+            current
+                .iter()
+                .any(|current| current.eq_ignore_ascii_case(&name))
+        })
+}
+
+fn merge_into(config: &mut IndexMap<String, Value>, overrides: IndexMap<String, Value>) {
+    for (key, value) in overrides {
+        let merged = match (config.get_mut(&key), &value.value) {
+            (
+                Some(Value {
+                    value: UntaggedValue::Row(existing),
+                    ..
+                }),
+                UntaggedValue::Row(overrides),
+            ) => {
+                merge_into(&mut existing.entries, overrides.entries.clone());
+                true
+            }
+            _ => false,
+        };
+
+        if !merged {
+            config.insert(key, value);
+        }
+    }
+}
+
+fn host_name() -> Option<String> {
+    std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| {
+            let output = std::process::Command::new("hostname").output().ok()?;
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+        .filter(|name| !name.is_empty())
+}
+
+/// Describes a config file that couldn't be parsed, quoting the line it failed on
+fn parse_error_message(filename: &Path, contents: &str, err: &toml::de::Error) -> String {
+    let mut message = format!(
+        "Couldn't parse config file {}:\n{}",
+        filename.display(),
+        err
+    );
+
+    if let Some((line, _)) = err.line_col() {
+        if let Some(text) = contents.lines().nth(line) {
+            message.push_str(&format!("\n\n{} | {}", line + 1, text));
+        }
+    }
+
+    message
+}
+
 pub fn config(tag: impl Into<Tag>) -> Result<IndexMap<String, Value>, ShellError> {
-    read(tag, &None)
+    load(tag, &None)
 }
 
 pub fn write(config: &IndexMap<String, Value>, at: &Option<PathBuf>) -> Result<(), ShellError> {
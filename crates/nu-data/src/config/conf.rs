@@ -1,3 +1,4 @@
+use nu_errors::ShellError;
 use nu_protocol::Value;
 use std::any::Any;
 use std::fmt::Debug;
@@ -8,6 +9,7 @@ pub trait Conf: Debug + Send {
     fn var(&self, key: &str) -> Option<Value>;
     fn env(&self) -> Option<Value>;
     fn path(&self) -> Option<Value>;
+    fn error(&self) -> Option<ShellError>;
     fn reload(&mut self);
     fn clone_box(&self) -> Box<dyn Conf>;
 }
@@ -33,6 +35,10 @@ impl Conf for Box<dyn Conf> {
         (**self).path()
     }
 
+    fn error(&self) -> Option<ShellError> {
+        (**self).error()
+    }
+
     fn reload(&mut self) {
         (**self).reload();
     }
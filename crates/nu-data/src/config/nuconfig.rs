@@ -1,5 +1,6 @@
-use crate::config::{last_modified, read, Conf, Status};
+use crate::config::{last_modified, load, Conf, Status};
 use indexmap::IndexMap;
+use nu_errors::ShellError;
 use nu_protocol::Value;
 use nu_source::Tag;
 use std::any::Any;
@@ -11,6 +12,9 @@ pub struct NuConfig {
     pub source_file: Option<std::path::PathBuf>,
     pub vars: IndexMap<String, Value>,
     pub modified_at: Status,
+    /// Why the config file couldn't be read, if it couldn't. The defaults are used until it's
+    /// fixed.
+    pub error: Option<ShellError>,
 }
 
 impl Conf for NuConfig {
@@ -34,17 +38,25 @@ impl Conf for NuConfig {
         self.path()
     }
 
+    fn error(&self) -> Option<ShellError> {
+        self.error.clone()
+    }
+
     fn reload(&mut self) {
         let vars = &mut self.vars;
 
-        if let Ok(variables) = read(Tag::unknown(), &self.source_file) {
-            vars.extend(variables);
+        match load(Tag::unknown(), &self.source_file) {
+            Ok(variables) => {
+                vars.extend(variables);
+                self.error = None;
 
-            self.modified_at = if let Ok(status) = last_modified(&None) {
-                status
-            } else {
-                Status::Unavailable
-            };
+                self.modified_at = if let Ok(status) = last_modified(&None) {
+                    status
+                } else {
+                    Status::Unavailable
+                };
+            }
+            Err(err) => self.error = Some(err),
         }
     }
 
@@ -60,32 +72,32 @@ impl NuConfig {
             Some(_) => {
                 let source_file = config_file.map(std::path::PathBuf::from);
 
-                let vars = if let Ok(variables) = read(Tag::unknown(), &source_file) {
-                    variables
-                } else {
-                    IndexMap::default()
+                let (vars, error) = match load(Tag::unknown(), &source_file) {
+                    Ok(variables) => (variables, None),
+                    Err(err) => (IndexMap::default(), Some(err)),
                 };
 
                 NuConfig {
                     source_file: source_file.clone(),
                     vars,
                     modified_at: NuConfig::get_last_modified(&source_file),
+                    error,
                 }
             }
         }
     }
 
     pub fn new() -> NuConfig {
-        let vars = if let Ok(variables) = read(Tag::unknown(), &None) {
-            variables
-        } else {
-            IndexMap::default()
+        let (vars, error) = match load(Tag::unknown(), &None) {
+            Ok(variables) => (variables, None),
+            Err(err) => (IndexMap::default(), Some(err)),
         };
 
         NuConfig {
             source_file: None,
             vars,
             modified_at: NuConfig::get_last_modified(&None),
+            error,
         }
     }
 
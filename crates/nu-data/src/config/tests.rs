@@ -1,4 +1,5 @@
 use crate::config::{Conf, NuConfig, Status};
+use nu_errors::ShellError;
 use nu_protocol::Value;
 use std::any::Any;
 use std::path::{Path, PathBuf};
@@ -30,6 +31,10 @@ impl Conf for FakeConfig {
         self.config.path()
     }
 
+    fn error(&self) -> Option<ShellError> {
+        self.config.error.clone()
+    }
+
     fn reload(&mut self) {
         self.reload()
     }
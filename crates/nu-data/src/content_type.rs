@@ -0,0 +1,96 @@
+/// The media types of the formats Nu reads and writes, by the extension its `from` and `to`
+/// commands are named after. The first type of a format is the one files of it are given.
+const FORMATS: &[(&str, &[&str])] = &[
+    ("csv", &["text/csv"]),
+    ("eml", &["message/rfc822"]),
+    ("html", &["text/html"]),
+    ("ics", &["text/calendar"]),
+    ("ini", &["text/x-ini"]),
+    (
+        "json",
+        &["application/json", "text/json", "application/x-json"],
+    ),
+    ("md", &["text/markdown"]),
+    ("toml", &["application/toml", "text/x-toml"]),
+    ("tsv", &["text/tab-separated-values"]),
+    ("txt", &["text/plain"]),
+    ("url", &["application/x-www-form-urlencoded"]),
+    ("vcf", &["text/vcard"]),
+    (
+        "xlsx",
+        &["application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"],
+    ),
+    ("xml", &["application/xml", "text/xml"]),
+    (
+        "yaml",
+        &[
+            "application/yaml",
+            "application/x-yaml",
+            "text/yaml",
+            "text/x-yaml",
+        ],
+    ),
+];
+
+/// The media type of a file with the given extension
+pub fn for_extension(extension: &str) -> Option<&'static str> {
+    let extension = extension.to_ascii_lowercase();
+    let extension = match extension.as_str() {
+        "yml" => "yaml",
+        "htm" => "html",
+        "markdown" => "md",
+        other => other,
+    };
+
+    FORMATS
+        .iter()
+        .find(|(format, _)| *format == extension)
+        .map(|(_, types)| types[0])
+}
+
+/// The format of the given media type, as the extension for it, eg `json` for `application/json`.
+/// Parameters such as a charset are left out, and a type with a `+json` or `+xml` suffix is taken
+/// as JSON or XML.
+pub fn format_for(content_type: &str) -> Option<&'static str> {
+    let essence = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    if let Some((format, _)) = FORMATS
+        .iter()
+        .find(|(_, types)| types.contains(&essence.as_str()))
+    {
+        return Some(format);
+    }
+
+    if essence.ends_with("+json") {
+        Some("json")
+    } else if essence.ends_with("+xml") {
+        Some("xml")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{for_extension, format_for};
+
+    #[test]
+    fn finds_the_type_of_an_extension() {
+        assert_eq!(for_extension("json"), Some("application/json"));
+        assert_eq!(for_extension("YML"), Some("application/yaml"));
+        assert_eq!(for_extension("exe"), None);
+    }
+
+    #[test]
+    fn finds_the_format_of_a_type() {
+        assert_eq!(format_for("application/json; charset=utf-8"), Some("json"));
+        assert_eq!(format_for("application/vnd.github+json"), Some("json"));
+        assert_eq!(format_for("text/x-toml"), Some("toml"));
+        assert_eq!(format_for("image/png"), None);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::config::user_data;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The environment variables at some point, and optionally the directory, kept by `env save` so
+/// `env restore` can bring them back later.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvSnapshot {
+    pub cwd: Option<PathBuf>,
+    pub env: IndexMap<String, String>,
+}
+
+/// The directory the snapshots are kept in, one TOML file for each
+pub fn snapshots_path() -> Result<PathBuf, ShellError> {
+    let mut path = user_data()?;
+    path.push("env-snapshots");
+
+    Ok(path)
+}
+
+/// Whether the name can be given to a snapshot. It becomes a file name, so it can't have path
+/// separators in it.
+pub fn is_valid_name(name: &str) -> bool {
+    !(name.is_empty() || name.contains(|c| c == '/' || c == '\\') || name == "." || name == "..")
+}
+
+/// Where the snapshot with the given name is kept
+pub fn snapshot_path(name: &str) -> Result<PathBuf, ShellError> {
+    if !is_valid_name(name) {
+        return Err(ShellError::untagged_runtime_error(format!(
+            "'{}' can't be the name of a snapshot",
+            name
+        )));
+    }
+
+    Ok(snapshots_path()?.join(format!("{}.toml", name)))
+}
+
+/// Keeps the snapshot under the given name, replacing the one that had it
+pub fn save(name: &str, snapshot: &EnvSnapshot) -> Result<(), ShellError> {
+    let path = snapshot_path(name)?;
+    std::fs::create_dir_all(snapshots_path()?)?;
+
+    write(&path, snapshot)
+}
+
+pub fn load(name: &str) -> Result<EnvSnapshot, ShellError> {
+    let path = snapshot_path(name)?;
+
+    if !path.exists() {
+        return Err(ShellError::untagged_runtime_error(format!(
+            "There is no snapshot named '{}'",
+            name
+        )));
+    }
+
+    read(&path)
+}
+
+/// The names of the snapshots kept, in alphabetical order
+pub fn names() -> Result<Vec<String>, ShellError> {
+    let path = snapshots_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut names: Vec<String> = std::fs::read_dir(path)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "toml"))
+        .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+        .collect();
+    names.sort();
+
+    Ok(names)
+}
+
+fn write(path: &Path, snapshot: &EnvSnapshot) -> Result<(), ShellError> {
+    let contents = toml::to_string(snapshot).map_err(|err| {
+        ShellError::untagged_runtime_error(format!("Couldn't save the snapshot: {}", err))
+    })?;
+
+    Ok(std::fs::write(path, contents)?)
+}
+
+fn read(path: &Path) -> Result<EnvSnapshot, ShellError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    toml::from_str(&contents).map_err(|err| {
+        ShellError::untagged_runtime_error(format!(
+            "Couldn't read the snapshot {}: {}",
+            path.display(),
+            err
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid_name, read, write, EnvSnapshot};
+    use indexmap::indexmap;
+    use nu_test_support::playground::Playground;
+    use std::path::PathBuf;
+
+    #[test]
+    fn reads_back_a_saved_snapshot() {
+        let snapshot = EnvSnapshot {
+            cwd: Some(PathBuf::from("/home/nu/project")),
+            env: indexmap! {
+                "PATH".to_string() => "/usr/bin:/bin".to_string(),
+                "RUST_LOG".to_string() => "debug".to_string(),
+            },
+        };
+
+        Playground::setup("env_snapshot_test_1", |dirs, _| {
+            let path = dirs.test().join("work.toml");
+
+            write(&path, &snapshot).expect("the snapshot is written");
+            assert_eq!(read(&path).expect("the snapshot is read"), snapshot);
+        })
+    }
+
+    #[test]
+    fn names_cannot_be_paths() {
+        assert!(is_valid_name("work"));
+        assert!(!is_valid_name("../config"));
+        assert!(!is_valid_name(""));
+    }
+}
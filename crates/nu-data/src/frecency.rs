@@ -0,0 +1,237 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+
+/// Once the ranks add up to more than this, every rank is scaled down and the directories that
+/// are left with a rank below one are forgotten
+const MAX_TOTAL_RANK: f64 = 9000.0;
+
+/// A directory that was changed to, with how often and how recently
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisitedDir {
+    pub path: PathBuf,
+    /// One for every visit, until the ranks are scaled down
+    pub rank: f64,
+    /// Seconds since the Unix epoch
+    pub last_visit: u64,
+}
+
+impl VisitedDir {
+    /// The rank weighted by how long ago the last visit was
+    pub fn score(&self, now: u64) -> f64 {
+        let age = now.saturating_sub(self.last_visit);
+
+        let weight = if age < HOUR {
+            4.0
+        } else if age < DAY {
+            2.0
+        } else if age < WEEK {
+            0.5
+        } else {
+            0.25
+        };
+
+        self.rank * weight
+    }
+
+    /// Whether the fragments are found in the path in order, ignoring case, with the last one in
+    /// the name of the directory itself
+    pub fn matches<S: AsRef<str>>(&self, fragments: &[S]) -> bool {
+        let path = self.path.to_string_lossy().to_lowercase();
+
+        let mut rest = path.as_str();
+        for fragment in fragments {
+            let fragment = fragment.as_ref().to_lowercase();
+            match rest.find(&fragment) {
+                Some(index) => rest = &rest[index + fragment.len()..],
+                None => return false,
+            }
+        }
+
+        match (fragments.last(), self.path.file_name()) {
+            (Some(last), Some(name)) => name
+                .to_string_lossy()
+                .to_lowercase()
+                .contains(&last.as_ref().to_lowercase()),
+            (Some(_), None) => false,
+            (None, _) => true,
+        }
+    }
+}
+
+/// The directories that were changed to, for jumping back to them by a part of their path
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Frecency {
+    pub dirs: Vec<VisitedDir>,
+}
+
+impl Frecency {
+    /// Reads the directories from lines of `path|rank|last visit`, the same format `z` keeps,
+    /// skipping the lines that can't be read
+    pub fn parse(contents: &str) -> Frecency {
+        let dirs = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.rsplitn(3, '|');
+                let last_visit = fields.next()?.parse().ok()?;
+                let rank = fields.next()?.parse().ok()?;
+                let path = PathBuf::from(fields.next()?);
+
+                Some(VisitedDir {
+                    path,
+                    rank,
+                    last_visit,
+                })
+            })
+            .collect();
+
+        Frecency { dirs }
+    }
+
+    pub fn to_lines(&self) -> String {
+        self.dirs
+            .iter()
+            .map(|dir| {
+                format!(
+                    "{}|{}|{}\n",
+                    dir.path.to_string_lossy(),
+                    dir.rank,
+                    dir.last_visit
+                )
+            })
+            .collect()
+    }
+
+    /// Reads the directories from the file at `path`, where a missing file means none were visited
+    pub fn load(path: &Path) -> io::Result<Frecency> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Ok(Frecency::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Frecency::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_lines())
+    }
+
+    pub fn visit(&mut self, path: &Path, now: u64) {
+        match self.dirs.iter_mut().find(|dir| dir.path == path) {
+            Some(dir) => {
+                dir.rank += 1.0;
+                dir.last_visit = now;
+            }
+            None => self.dirs.push(VisitedDir {
+                path: path.to_path_buf(),
+                rank: 1.0,
+                last_visit: now,
+            }),
+        }
+
+        if self.dirs.iter().map(|dir| dir.rank).sum::<f64>() > MAX_TOTAL_RANK {
+            for dir in &mut self.dirs {
+                dir.rank *= 0.99;
+            }
+            self.dirs.retain(|dir| dir.rank >= 1.0);
+        }
+    }
+
+    /// The directories matching the fragments, the highest score first
+    pub fn ranked<S: AsRef<str>>(&self, fragments: &[S], now: u64) -> Vec<&VisitedDir> {
+        let mut dirs = self
+            .dirs
+            .iter()
+            .filter(|dir| dir.matches(fragments))
+            .collect::<Vec<_>>();
+
+        dirs.sort_by(|a, b| {
+            b.score(now)
+                .partial_cmp(&a.score(now))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        dirs
+    }
+}
+
+/// The directories are kept next to the history file, eg `dirs.txt` next to `history.txt`
+pub fn store_path(history: &Path) -> PathBuf {
+    history.with_file_name("dirs.txt")
+}
+
+/// The current time as the seconds since the Unix epoch, which is how visits are timed
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or(0)
+}
+
+/// Counts a visit to the directory in the store next to the history file
+pub fn record_visit(history: &Path, dir: &Path) -> io::Result<()> {
+    let store = store_path(history);
+
+    let mut frecency = Frecency::load(&store)?;
+    frecency.visit(dir, now());
+    frecency.save(&store)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frecency, VisitedDir, DAY, HOUR};
+    use std::path::{Path, PathBuf};
+
+    fn dir(path: &str, rank: f64, last_visit: u64) -> VisitedDir {
+        VisitedDir {
+            path: PathBuf::from(path),
+            rank,
+            last_visit,
+        }
+    }
+
+    #[test]
+    fn reads_back_what_it_writes_and_skips_broken_lines() {
+        let frecency = Frecency {
+            dirs: vec![dir("/home/nu/a|b", 2.5, 100), dir("/tmp", 1.0, 200)],
+        };
+        let contents = format!("{}/broken|line\n", frecency.to_lines());
+
+        assert_eq!(Frecency::parse(&contents), frecency);
+    }
+
+    #[test]
+    fn recent_visits_count_for_more() {
+        let now = 10 * DAY;
+        let frecency = Frecency {
+            dirs: vec![
+                dir("/home/nu/old-project", 10.0, now - 2 * DAY),
+                dir("/home/nu/new-project", 3.0, now - HOUR / 2),
+            ],
+        };
+
+        let ranked = frecency.ranked(&["proj"], now);
+        assert_eq!(ranked[0].path, Path::new("/home/nu/new-project"));
+    }
+
+    #[test]
+    fn fragments_match_in_order_ending_in_the_directory_name() {
+        let visited = dir("/home/nu/Projects/nushell", 1.0, 0);
+
+        assert!(visited.matches(&["proj", "nu"]));
+        assert!(!visited.matches(&["nu", "proj"]));
+        assert!(!visited.matches(&["home"]));
+    }
+
+    #[test]
+    fn visiting_again_raises_the_rank() {
+        let mut frecency = Frecency::default();
+        frecency.visit(Path::new("/tmp"), 1);
+        frecency.visit(Path::new("/tmp"), 2);
+
+        assert_eq!(frecency.dirs, vec![dir("/tmp", 2.0, 2)]);
+    }
+}
@@ -1,7 +1,10 @@
 pub mod base;
 pub mod command;
 pub mod config;
+pub mod content_type;
 pub mod dict;
+pub mod env_snapshot;
+pub mod frecency;
 pub mod keybinding;
 pub mod primitive;
 pub mod types;
@@ -105,6 +105,8 @@ pub fn string_to_lookup_value(str_prim: &str) -> String {
         "header_style" => "header_style".to_string(),
         "index_color" => "index_color".to_string(),
         "leading_trailing_space_bg" => "leading_trailing_space_bg".to_string(),
+        "prompt_indicator_color" => "prompt_indicator_color".to_string(),
+        "error_indicator_color" => "error_indicator_color".to_string(),
         _ => "Primitive::Nothing".to_string(),
     }
 }
@@ -148,6 +150,8 @@ pub fn get_color_config() -> HashMap<String, Style> {
         "leading_trailing_space_bg".to_string(),
         Style::default().on(Color::Rgb(128, 128, 128)),
     );
+    hm.insert("prompt_indicator_color".to_string(), Style::default());
+    hm.insert("error_indicator_color".to_string(), Color::Red.bold());
 
     // populate hashmap from config values
     if let Ok(config) = crate::config::config(Tag::unknown()) {
@@ -214,6 +218,12 @@ pub fn get_color_config() -> HashMap<String, Style> {
                     "leading_trailing_space_bg" => {
                         update_hashmap(&key, &value, &mut hm);
                     }
+                    "prompt_indicator_color" => {
+                        update_hashmap(&key, &value, &mut hm);
+                    }
+                    "error_indicator_color" => {
+                        update_hashmap(&key, &value, &mut hm);
+                    }
                     _ => (),
                 }
             }
@@ -21,6 +21,20 @@ pub fn split(
     }
 
     for (column, value) in value.row_entries() {
+        // Groups of groups are split all the way down, keeping the groups they are in
+        if value.is_row() {
+            let nested = split(&value, splitter, &tag)?;
+
+            for (split_label, subset) in nested.row_entries() {
+                splits
+                    .entry(split_label.clone())
+                    .or_insert(indexmap::IndexMap::new())
+                    .insert(column.clone(), subset.clone());
+            }
+
+            continue;
+        }
+
         if !&value.is_table() {
             return Err(ShellError::type_error(
                 "a table value",
@@ -63,6 +63,55 @@ pub fn merge_values(
     }
 }
 
+/// How `merge_values_deep` combines two lists found at the same place
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListMerge {
+    /// The list on the right replaces the one on the left
+    Replace,
+    /// The items of the list on the right come after those of the one on the left
+    Append,
+}
+
+/// Merges the rows like `merge_values`, except that the columns both have rows in are merged too,
+/// all the way down. Anything else on the right replaces what's on the left.
+pub fn merge_values_deep(
+    left: &UntaggedValue,
+    right: &UntaggedValue,
+    lists: ListMerge,
+) -> Result<UntaggedValue, (&'static str, &'static str)> {
+    match (left, right) {
+        (UntaggedValue::Row(_), UntaggedValue::Row(_)) => Ok(merge_deep(left, right, lists)),
+        (left, right) => Err((left.type_name(), right.type_name())),
+    }
+}
+
+fn merge_deep(left: &UntaggedValue, right: &UntaggedValue, lists: ListMerge) -> UntaggedValue {
+    match (left, right) {
+        (UntaggedValue::Row(columns), UntaggedValue::Row(columns_b)) => {
+            let mut merged = columns.clone();
+
+            for (key, value_b) in columns_b.entries.iter() {
+                let value = match merged.entries.get(key) {
+                    Some(value) => {
+                        merge_deep(&value.value, &value_b.value, lists).into_value(&value_b.tag)
+                    }
+                    None => value_b.clone(),
+                };
+
+                merged.entries.insert(key.clone(), value);
+            }
+
+            UntaggedValue::Row(merged)
+        }
+        (UntaggedValue::Table(items), UntaggedValue::Table(items_b))
+            if lists == ListMerge::Append =>
+        {
+            UntaggedValue::Table(items.iter().chain(items_b.iter()).cloned().collect())
+        }
+        (_, right) => right.clone(),
+    }
+}
+
 fn zero_division_error() -> UntaggedValue {
     UntaggedValue::Error(ShellError::untagged_runtime_error("division by zero"))
 }
@@ -95,6 +144,9 @@ pub fn unsafe_compute_values(
     }
 }
 
+/// The largest number of bits `bit-shl` and `bit-shr` will shift by
+const MAX_SHIFT: usize = 1 << 20;
+
 pub fn compute_values(
     operator: Operator,
     left: &UntaggedValue,
@@ -149,6 +201,20 @@ pub fn compute_values(
                         _ => Err((left.type_name(), right.type_name())),
                     }
                 }
+                Operator::BitAnd => Ok(UntaggedValue::Primitive(Primitive::Int(x & y))),
+                Operator::BitOr => Ok(UntaggedValue::Primitive(Primitive::Int(x | y))),
+                Operator::BitXor => Ok(UntaggedValue::Primitive(Primitive::Int(x ^ y))),
+                Operator::ShiftLeft | Operator::ShiftRight => {
+                    // Negative shifts make no sense, and huge ones would try to allocate
+                    // unbounded memory for a left shift
+                    match ToPrimitive::to_usize(y).filter(|bits| *bits <= MAX_SHIFT) {
+                        Some(bits) if operator == Operator::ShiftLeft => {
+                            Ok(UntaggedValue::Primitive(Primitive::Int(x << bits)))
+                        }
+                        Some(bits) => Ok(UntaggedValue::Primitive(Primitive::Int(x >> bits))),
+                        None => Err((left.type_name(), "shift amount out of range")),
+                    }
+                }
                 _ => Err((left.type_name(), right.type_name())),
             },
             (Primitive::Decimal(x), Primitive::Int(y)) => {
@@ -363,9 +429,9 @@ pub fn format_for_column<'a>(
 
 #[cfg(test)]
 mod tests {
-    use super::merge_values;
     use super::Date as d;
     use super::UntaggedValue as v;
+    use super::{merge_values, merge_values_deep, ListMerge};
     use nu_source::TaggedItem;
 
     use indexmap::indexmap;
@@ -394,4 +460,44 @@ mod tests {
             merge_values(&table_author_row, &other_table_author_row).unwrap()
         );
     }
+
+    #[test]
+    fn merges_nested_rows() {
+        let base = v::row(indexmap! {
+            "server".into() => v::row(indexmap! {
+                "host".into() => v::string("localhost").into_untagged_value(),
+                "port".into() => v::int(8080).into_untagged_value(),
+            }).into_untagged_value(),
+            "plugins".into() => v::table(&[v::string("git").into_untagged_value()]).into_untagged_value(),
+        });
+
+        let local = v::row(indexmap! {
+            "server".into() => v::row(indexmap! {
+                "port".into() => v::int(9090).into_untagged_value(),
+            }).into_untagged_value(),
+            "plugins".into() => v::table(&[v::string("docker").into_untagged_value()]).into_untagged_value(),
+        });
+
+        assert_eq!(
+            v::row(indexmap! {
+                "server".into() => v::row(indexmap! {
+                    "host".into() => v::string("localhost").into_untagged_value(),
+                    "port".into() => v::int(9090).into_untagged_value(),
+                }).into_untagged_value(),
+                "plugins".into() => v::table(&[v::string("docker").into_untagged_value()]).into_untagged_value(),
+            }),
+            merge_values_deep(&base, &local, ListMerge::Replace).unwrap()
+        );
+
+        assert_eq!(
+            v::table(&[
+                v::string("git").into_untagged_value(),
+                v::string("docker").into_untagged_value()
+            ]),
+            match merge_values_deep(&base, &local, ListMerge::Append).unwrap() {
+                v::Row(merged) => merged.entries["plugins"].value.clone(),
+                other => panic!("expected a row, got {:?}", other),
+            }
+        );
+    }
 }
@@ -3,6 +3,7 @@ use crate::deserializer::ConfigDeserializer;
 use crate::env::host::Host;
 use crate::evaluate::scope::Scope;
 use crate::evaluation_context::EvaluationContext;
+use crate::jobs::Jobs;
 use crate::shell::shell_manager::ShellManager;
 use derive_new::new;
 use getset::Getters;
@@ -24,6 +25,7 @@ pub struct CommandArgs {
     pub ctrl_c: Arc<AtomicBool>,
     pub current_errors: Arc<Mutex<Vec<ShellError>>>,
     pub shell_manager: ShellManager,
+    pub jobs: Jobs,
     pub call_info: UnevaluatedCallInfo,
     pub scope: Scope,
     pub input: InputStream,
@@ -36,6 +38,7 @@ pub struct RawCommandArgs {
     pub ctrl_c: Arc<AtomicBool>,
     pub current_errors: Arc<Mutex<Vec<ShellError>>>,
     pub shell_manager: ShellManager,
+    pub jobs: Jobs,
     pub scope: Scope,
     pub call_info: UnevaluatedCallInfo,
 }
@@ -47,6 +50,7 @@ impl RawCommandArgs {
             ctrl_c: self.ctrl_c,
             current_errors: self.current_errors,
             shell_manager: self.shell_manager,
+            jobs: self.jobs,
             call_info: self.call_info,
             scope: self.scope,
             input: input.into(),
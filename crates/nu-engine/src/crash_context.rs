@@ -0,0 +1,99 @@
+use nu_source::Span;
+use parking_lot::{const_mutex, Mutex};
+
+/// What the shell was doing, kept up to date as it runs so a crash report can tell where it
+/// crashed. It's only read when the shell panics.
+#[derive(Debug, Clone)]
+pub struct CrashContext {
+    /// The text of the last script or line that was run, and the offset its spans start at
+    pub source: Option<(String, usize)>,
+    /// The span of the last command that was started
+    pub span: Option<Span>,
+    /// The name and path of each plugin registered
+    pub plugins: Vec<(String, String)>,
+}
+
+static CONTEXT: Mutex<CrashContext> = const_mutex(CrashContext {
+    source: None,
+    span: None,
+    plugins: Vec::new(),
+});
+
+pub fn set_source(source: &str, span_offset: usize) {
+    let mut context = CONTEXT.lock();
+    context.source = Some((source.to_string(), span_offset));
+    context.span = None;
+}
+
+pub(crate) fn set_span(span: Span) {
+    CONTEXT.lock().span = Some(span);
+}
+
+pub fn add_plugin(name: impl Into<String>, path: impl Into<String>) {
+    CONTEXT.lock().plugins.push((name.into(), path.into()));
+}
+
+/// What was kept, unless the panic happened while it was being updated
+pub fn current() -> Option<CrashContext> {
+    CONTEXT.try_lock().map(|context| context.clone())
+}
+
+impl CrashContext {
+    /// The line of the source the last command was on, with a line of carets under the command
+    pub fn excerpt(&self) -> Option<String> {
+        let (source, offset) = self.source.as_ref()?;
+        let span = self.span?;
+        let start = span.start().checked_sub(*offset)?;
+        let end = span.end().checked_sub(*offset)?.min(source.len());
+        if start > end || !source.is_char_boundary(start) || !source.is_char_boundary(end) {
+            return None;
+        }
+
+        let line_start = source[..start].rfind('\n').map_or(0, |at| at + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |at| start + at);
+        let line = &source[line_start..line_end];
+
+        let indent = source[line_start..start].chars().count();
+        let width = source[start..end.min(line_end)].chars().count().max(1);
+
+        Some(format!(
+            "{}\n{}{}",
+            line,
+            " ".repeat(indent),
+            "^".repeat(width)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CrashContext;
+    use nu_source::Span;
+
+    #[test]
+    fn points_at_the_last_command() {
+        let context = CrashContext {
+            source: Some(("ls\nopen foo.json | get bar".to_string(), 10)),
+            span: Some(Span::new(29, 32)),
+            plugins: vec![],
+        };
+
+        assert_eq!(
+            context.excerpt(),
+            Some("open foo.json | get bar\n                ^^^".to_string())
+        );
+    }
+
+    #[test]
+    fn leaves_out_spans_from_other_sources() {
+        let context = CrashContext {
+            source: Some(("ls".to_string(), 10)),
+            span: Some(Span::new(0, 2)),
+            plugins: vec![],
+        };
+
+        assert_eq!(context.excerpt(), None);
+    }
+}
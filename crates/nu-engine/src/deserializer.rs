@@ -1,7 +1,7 @@
 use log::trace;
 use nu_errors::{CoerceInto, ShellError};
 use nu_protocol::{
-    hir::CapturedBlock, CallInfo, ColumnPath, Primitive, RangeInclusion, ShellTypeName,
+    hir::CapturedBlock, CallInfo, ColumnPath, NuGlob, Primitive, RangeInclusion, ShellTypeName,
     UntaggedValue, Value,
 };
 use nu_source::Span;
@@ -380,6 +380,27 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut ConfigDeserializer<'de> {
             return visit::<Value, _>(value.val, name, fields, visitor);
         }
 
+        if type_name == std::any::type_name::<Tagged<NuGlob>>() {
+            // Only glob values are expanded, any other path is taken literally
+            let tag = value.val.tag();
+            let glob = match value.val.value {
+                UntaggedValue::Primitive(Primitive::GlobPattern(pattern)) => {
+                    NuGlob::Expand(pattern)
+                }
+                UntaggedValue::Primitive(Primitive::String(path)) => NuGlob::Literal(path),
+                UntaggedValue::Primitive(Primitive::FilePath(path)) => {
+                    NuGlob::Literal(path.to_string_lossy().to_string())
+                }
+                other => {
+                    return Err(ShellError::type_error(
+                        "glob",
+                        other.type_name().spanned(tag.span),
+                    ))
+                }
+            };
+            return visit::<Tagged<NuGlob>, _>(glob.tagged(tag), name, fields, visitor);
+        }
+
         if name == "CapturedBlock" {
             let block = match value.val {
                 Value {
@@ -465,6 +486,10 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut ConfigDeserializer<'de> {
                 value: UntaggedValue::Primitive(Primitive::String(string)),
                 ..
             } => visit::<Tagged<String>, _>(string.tagged(tag), name, fields, visitor),
+            Value {
+                value: UntaggedValue::Primitive(Primitive::GlobPattern(pattern)),
+                ..
+            } => visit::<Tagged<String>, _>(pattern.tagged(tag), name, fields, visitor),
             Value {
                 value: UntaggedValue::Primitive(Primitive::Range(range)),
                 ..
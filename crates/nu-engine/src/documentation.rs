@@ -2,8 +2,11 @@ use crate::evaluate::scope::Scope;
 use crate::whole_stream_command::WholeStreamCommand;
 use indexmap::IndexMap;
 use itertools::Itertools;
-use nu_protocol::{NamedType, PositionalType, Signature, UntaggedValue, Value};
-use nu_source::PrettyDebug;
+use nu_protocol::{
+    NamedType, PositionalType, Primitive, Signature, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::{PrettyDebug, Tag};
+use nu_value_ext::ValueExt;
 use std::collections::HashMap;
 
 const COMMANDS_DOCS_DIR: &str = "docs/commands";
@@ -122,7 +125,195 @@ fn retrieve_doc_link(name: &str) -> Option<String> {
     })
 }
 
-#[allow(clippy::cognitive_complexity)]
+/// A positional parameter as the help shows it
+struct ParameterDoc {
+    name: String,
+    shape: String,
+    required: bool,
+    description: String,
+}
+
+impl ParameterDoc {
+    fn label(&self) -> String {
+        if self.name == "...args" {
+            self.name.clone()
+        } else if self.required {
+            format!("<{}>", self.name)
+        } else {
+            format!("({})", self.name)
+        }
+    }
+}
+
+/// A flag as the help shows it. Switches have no shape.
+struct FlagDoc {
+    long: String,
+    short: Option<char>,
+    shape: Option<String>,
+    required: bool,
+    description: String,
+}
+
+impl FlagDoc {
+    fn label(&self) -> String {
+        let mut label = match self.short {
+            Some(c) => format!("-{}, --{}", c, self.long),
+            None => format!("--{}", self.long),
+        };
+        if let Some(shape) = &self.shape {
+            label.push_str(&format!(" <{}>", shape));
+        }
+        label
+    }
+}
+
+fn parameter_docs(signature: &Signature) -> Vec<ParameterDoc> {
+    let mut parameters: Vec<ParameterDoc> = signature
+        .positional
+        .iter()
+        .map(|(positional, description)| ParameterDoc {
+            name: positional.name().to_string(),
+            shape: positional.syntax_type().display(),
+            required: matches!(positional, PositionalType::Mandatory(..)),
+            description: description.clone(),
+        })
+        .collect();
+
+    if let Some((shape, description)) = &signature.rest_positional {
+        parameters.push(ParameterDoc {
+            name: "...args".to_string(),
+            shape: shape.display(),
+            required: false,
+            description: description.clone(),
+        });
+    }
+
+    parameters
+}
+
+fn flag_docs(signature: &Signature) -> Vec<FlagDoc> {
+    signature
+        .named
+        .iter()
+        .map(|(long, (named, description))| {
+            let (short, shape, required) = match named {
+                NamedType::Switch(s) => (*s, None, false),
+                NamedType::Mandatory(s, m) => (*s, Some(m.display()), true),
+                NamedType::Optional(s, o) => (*s, Some(o.display()), false),
+            };
+
+            FlagDoc {
+                long: long.clone(),
+                short,
+                shape,
+                required,
+                description: description.clone(),
+            }
+        })
+        .collect()
+}
+
+/// The input and output types from the signature. Commands that don't declare them take and give
+/// anything.
+fn input_output(signature: &Signature) -> (String, String) {
+    let describe = |ty: &Option<nu_protocol::Type>| {
+        ty.as_ref()
+            .map(|ty| ty.display())
+            .unwrap_or_else(|| "any".to_string())
+    };
+
+    (describe(&signature.input), describe(&signature.yields))
+}
+
+fn subcommand_names(cmd_name: &str, scope: &Scope) -> Vec<String> {
+    let mut names: Vec<String> = scope
+        .get_command_names()
+        .into_iter()
+        .filter(|name| name.starts_with(&format!("{} ", cmd_name)))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Lines up rows of (label, description) in two columns
+fn aligned(rows: &[(String, String)]) -> String {
+    let width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    rows.iter()
+        .map(|(label, description)| {
+            if description.is_empty() {
+                format!("  {}\n", label)
+            } else {
+                let padding = width - label.chars().count();
+                format!("  {}{}  {}\n", label, " ".repeat(padding), description)
+            }
+        })
+        .collect()
+}
+
+/// Writes the expected output of an example the way it could be typed back in
+fn format_example_result(values: &[Value]) -> String {
+    match values {
+        [value] => format_result_value(value, true),
+        values => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(|value| format_result_value(value, false))
+                .join(" ")
+        ),
+    }
+}
+
+fn format_result_value(value: &Value, top_level: bool) -> String {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s)) if !top_level => {
+            if s.is_empty() || s.contains(|c: char| c.is_whitespace() || "[]{}\"'".contains(c)) {
+                format!("{:?}", s)
+            } else {
+                s.clone()
+            }
+        }
+        UntaggedValue::Row(dict) => format!(
+            "[[{}]; [{}]]",
+            dict.keys().join(" "),
+            dict.values()
+                .map(|value| format_result_value(value, false))
+                .join(" ")
+        ),
+        UntaggedValue::Table(rows)
+            if !rows.is_empty() && rows.iter().all(|row| row.value.is_row()) =>
+        {
+            let headers = nu_protocol::merge_descriptors(rows);
+            let rows = rows
+                .iter()
+                .map(|row| {
+                    format!(
+                        "[{}]",
+                        headers
+                            .iter()
+                            .map(|header| format_result_value(row.get_data(header).borrow(), false))
+                            .join(" ")
+                    )
+                })
+                .join(" ");
+            format!("[[{}]; {}]", headers.join(" "), rows)
+        }
+        UntaggedValue::Table(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(|value| format_result_value(value, false))
+                .join(" ")
+        ),
+        other => nu_data::value::format_leaf(other).plain_string(100_000),
+    }
+}
+
 pub fn get_documentation(
     cmd: &dyn WholeStreamCommand,
     scope: &Scope,
@@ -144,74 +335,80 @@ pub fn get_documentation(
         long_desc.push_str("\n\n");
     }
 
-    let mut subcommands = vec![];
-    if !config.no_subcommands {
-        for name in scope.get_command_names() {
-            if name.starts_with(&format!("{} ", cmd_name)) {
-                let subcommand = scope.get_command(&name).expect("This shouldn't happen");
-
-                subcommands.push(format!("  {} - {}", name, subcommand.usage()));
-            }
-        }
-    }
-
-    let mut one_liner = String::new();
-    one_liner.push_str(&signature.name);
-    one_liner.push(' ');
-
-    for positional in &signature.positional {
-        match &positional.0 {
-            PositionalType::Mandatory(name, _m) => {
-                one_liner.push_str(&format!("<{}> ", name));
-            }
-            PositionalType::Optional(name, _o) => {
-                one_liner.push_str(&format!("({}) ", name));
-            }
-        }
-    }
-
-    if signature.rest_positional.is_some() {
-        one_liner.push_str("...args ");
-    }
+    let subcommands = if config.no_subcommands {
+        vec![]
+    } else {
+        subcommand_names(cmd_name, scope)
+    };
+    let parameters = parameter_docs(&signature);
+    let flags = flag_docs(&signature);
 
+    let mut one_liner = vec![signature.name.clone()];
+    one_liner.extend(parameters.iter().map(ParameterDoc::label));
     if !subcommands.is_empty() {
-        one_liner.push_str("<subcommand> ");
+        one_liner.push("<subcommand>".to_string());
     }
-
-    if !signature.named.is_empty() {
-        one_liner.push_str("{flags} ");
+    if !flags.is_empty() {
+        one_liner.push("{flags}".to_string());
     }
 
-    long_desc.push_str(&format!("Usage:\n  > {}\n", one_liner));
+    long_desc.push_str(&format!("Usage:\n  > {}\n", one_liner.join(" ")));
 
     if !subcommands.is_empty() {
         long_desc.push_str("\nSubcommands:\n");
-        subcommands.sort();
-        long_desc.push_str(&subcommands.join("\n"));
-        long_desc.push('\n');
+        long_desc.push_str(&aligned(
+            &subcommands
+                .iter()
+                .map(|name| {
+                    let usage = scope
+                        .get_command(name)
+                        .map(|command| command.usage().to_string())
+                        .unwrap_or_default();
+                    (name.clone(), usage)
+                })
+                .collect::<Vec<_>>(),
+        ));
     }
 
-    if !signature.positional.is_empty() || signature.rest_positional.is_some() {
+    if !parameters.is_empty() {
         long_desc.push_str("\nParameters:\n");
-        for positional in &signature.positional {
-            match &positional.0 {
-                PositionalType::Mandatory(name, _m) => {
-                    long_desc.push_str(&format!("  <{}> {}\n", name, positional.1));
-                }
-                PositionalType::Optional(name, _o) => {
-                    long_desc.push_str(&format!("  ({}) {}\n", name, positional.1));
-                }
-            }
-        }
-
-        if let Some(rest_positional) = &signature.rest_positional {
-            long_desc.push_str(&format!("  ...args: {}\n", rest_positional.1));
-        }
+        long_desc.push_str(&aligned(
+            &parameters
+                .iter()
+                .map(|parameter| {
+                    (
+                        format!("{} <{}>", parameter.label(), parameter.shape),
+                        parameter.description.clone(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        ));
     }
-    if !signature.named.is_empty() {
-        long_desc.push_str(&get_flags_section(&signature))
+
+    if !flags.is_empty() {
+        long_desc.push_str("\nFlags:\n");
+        long_desc.push_str(&aligned(
+            &flags
+                .iter()
+                .map(|flag| {
+                    let description = if flag.required {
+                        format!("(required) {}", flag.description)
+                    } else {
+                        flag.description.clone()
+                    };
+                    (flag.label(), description)
+                })
+                .collect::<Vec<_>>(),
+        ));
     }
 
+    let (input, output) = input_output(&signature);
+    long_desc.push_str("\nInput/output:\n");
+    long_desc.push_str(&aligned(&[
+        ("input".to_string(), input),
+        ("output".to_string(), output),
+    ]));
+
     let palette = crate::shell::palette::DefaultPalette {};
     let examples = cmd.examples();
     if !examples.is_empty() {
@@ -229,6 +426,12 @@ pub fn get_documentation(
                 crate::shell::painter::Painter::paint_string(example.example, scope, &palette);
             long_desc.push_str(&format!("\n  > {}\n", colored_example));
         }
+
+        if let Some(result) = &example.result {
+            for line in format_example_result(result).lines() {
+                long_desc.push_str(&format!("    {}\n", line));
+            }
+        }
     }
 
     long_desc.push('\n');
@@ -236,73 +439,84 @@ pub fn get_documentation(
     long_desc
 }
 
-fn get_flags_section(signature: &Signature) -> String {
-    let mut long_desc = String::new();
-    long_desc.push_str("\nFlags:\n");
-    for (flag, ty) in &signature.named {
-        let msg = match ty.0 {
-            NamedType::Switch(s) => {
-                if let Some(c) = s {
-                    format!(
-                        "  -{}, --{}{} {}\n",
-                        c,
-                        flag,
-                        if !ty.1.is_empty() { ":" } else { "" },
-                        ty.1
-                    )
-                } else {
-                    format!(
-                        "  --{}{} {}\n",
-                        flag,
-                        if !ty.1.is_empty() { ":" } else { "" },
-                        ty.1
-                    )
-                }
-            }
-            NamedType::Mandatory(s, m) => {
-                if let Some(c) = s {
-                    format!(
-                        "  -{}, --{} <{}> (required parameter){} {}\n",
-                        c,
-                        flag,
-                        m.display(),
-                        if !ty.1.is_empty() { ":" } else { "" },
-                        ty.1
-                    )
-                } else {
-                    format!(
-                        "  --{} <{}> (required parameter){} {}\n",
-                        flag,
-                        m.display(),
-                        if !ty.1.is_empty() { ":" } else { "" },
-                        ty.1
-                    )
-                }
-            }
-            NamedType::Optional(s, o) => {
-                if let Some(c) = s {
-                    format!(
-                        "  -{}, --{} <{}>{} {}\n",
-                        c,
-                        flag,
-                        o.display(),
-                        if !ty.1.is_empty() { ":" } else { "" },
-                        ty.1
-                    )
-                } else {
-                    format!(
-                        "  --{} <{}>{} {}\n",
-                        flag,
-                        o.display(),
-                        if !ty.1.is_empty() { ":" } else { "" },
-                        ty.1
-                    )
-                }
-            }
-        };
-        long_desc.push_str(&msg);
-    }
-    long_desc
+/// The same documentation as `get_documentation`, as a record for `help <command> --json`
+pub fn get_documentation_value(cmd: &dyn WholeStreamCommand, scope: &Scope, tag: Tag) -> Value {
+    let signature = cmd.signature();
+    let string = |s: &str| UntaggedValue::string(s).into_value(&tag);
+
+    let mut row = TaggedDictBuilder::new(&tag);
+    row.insert_value("name", string(cmd.name()));
+    row.insert_value("usage", string(cmd.usage()));
+    row.insert_value("extra_usage", string(cmd.extra_usage()));
+
+    let parameters = parameter_docs(&signature)
+        .into_iter()
+        .map(|parameter| {
+            let mut dict = TaggedDictBuilder::new(&tag);
+            dict.insert_value("name", string(&parameter.name));
+            dict.insert_value("shape", string(&parameter.shape));
+            dict.insert_untagged("required", UntaggedValue::boolean(parameter.required));
+            dict.insert_value("description", string(&parameter.description));
+            dict.into_value()
+        })
+        .collect::<Vec<_>>();
+    row.insert_untagged("parameters", UntaggedValue::table(&parameters));
+
+    let flags = flag_docs(&signature)
+        .into_iter()
+        .map(|flag| {
+            let mut dict = TaggedDictBuilder::new(&tag);
+            dict.insert_value("long", string(&flag.long));
+            dict.insert_value(
+                "short",
+                flag.short
+                    .map(|c| string(&c.to_string()))
+                    .unwrap_or_else(|| UntaggedValue::nothing().into_value(&tag)),
+            );
+            dict.insert_value(
+                "shape",
+                flag.shape
+                    .as_deref()
+                    .map(string)
+                    .unwrap_or_else(|| UntaggedValue::nothing().into_value(&tag)),
+            );
+            dict.insert_untagged("required", UntaggedValue::boolean(flag.required));
+            dict.insert_value("description", string(&flag.description));
+            dict.into_value()
+        })
+        .collect::<Vec<_>>();
+    row.insert_untagged("flags", UntaggedValue::table(&flags));
+
+    let (input, output) = input_output(&signature);
+    row.insert_value("input", string(&input));
+    row.insert_value("output", string(&output));
+
+    let subcommands = subcommand_names(cmd.name(), scope)
+        .iter()
+        .map(|name| string(name))
+        .collect::<Vec<_>>();
+    row.insert_untagged("subcommands", UntaggedValue::table(&subcommands));
+
+    let examples = cmd
+        .examples()
+        .into_iter()
+        .map(|example| {
+            let mut dict = TaggedDictBuilder::new(&tag);
+            dict.insert_value("description", string(example.description));
+            dict.insert_value("example", string(example.example));
+            dict.insert_value(
+                "result",
+                match &example.result {
+                    Some(result) => string(&format_example_result(result)),
+                    None => UntaggedValue::nothing().into_value(&tag),
+                },
+            );
+            dict.into_value()
+        })
+        .collect::<Vec<_>>();
+    row.insert_untagged("examples", UntaggedValue::table(&examples));
+
+    row.into_value()
 }
 
 pub fn get_brief_help(cmd: &dyn WholeStreamCommand, scope: &Scope) -> String {
@@ -320,3 +534,47 @@ pub fn get_brief_help(cmd: &dyn WholeStreamCommand, scope: &Scope) -> String {
 pub fn get_full_help(cmd: &dyn WholeStreamCommand, scope: &Scope) -> String {
     get_documentation(cmd, scope, &DocumentationConfig::default())
 }
+
+/// Shows help that is taller than the terminal through a pager: the `pager` config setting, then
+/// `$PAGER`, then `less`. An empty `pager` setting turns paging off. Returns false when nothing
+/// was paged, so the caller can output the help as usual.
+pub fn page_documentation(text: &str) -> bool {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let height = match term_size::dimensions_stdout() {
+        Some((_, height)) => height,
+        None => return false,
+    };
+    if text.lines().count() < height {
+        return false;
+    }
+
+    let pager = nu_data::config::config(Tag::unknown())
+        .ok()
+        .and_then(|config| config.get("pager").and_then(|pager| pager.as_string().ok()))
+        .or_else(|| std::env::var("PAGER").ok())
+        .unwrap_or_else(|| "less -FRX".to_string());
+
+    let mut parts = pager.split_whitespace();
+    let program = match parts.next() {
+        Some(program) => program,
+        None => return false,
+    };
+
+    let mut child = match Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => return false,
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager may quit before reading everything, which is fine
+        let _ = stdin.write_all(text.as_bytes());
+    }
+
+    child.wait().is_ok()
+}
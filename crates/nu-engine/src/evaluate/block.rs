@@ -13,6 +13,7 @@ use nu_source::{Span, Tag};
 use nu_stream::InputStream;
 use nu_stream::ToOutputStream;
 use std::sync::atomic::Ordering;
+use std::time::Instant;
 
 #[async_recursion]
 pub async fn run_block(
@@ -89,44 +90,27 @@ pub async fn run_block(
             match output {
                 Ok(inp) if inp.is_empty() => {}
                 Ok(inp) => {
-                    let mut output_stream = inp.to_output_stream();
+                    // Only the last statement's output goes on, but the earlier ones still run
+                    // to the end, eg the `mkdir foo` in `mkdir foo; cd foo`
+                    drain_statement(inp, ctx).await?;
 
-                    match output_stream.try_next().await {
-                        Ok(Some(ReturnSuccess::Value(Value {
-                            value: UntaggedValue::Error(e),
-                            ..
-                        }))) => {
-                            return Err(e);
-                        }
-                        Ok(Some(_item)) => {
-                            if let Some(err) = ctx.get_errors().get(0) {
-                                ctx.clear_errors();
-                                return Err(err.clone());
-                            }
-                            if ctx.ctrl_c.load(Ordering::SeqCst) {
-                                // This early return doesn't return the result
-                                // we have so far, but breaking out of this loop
-                                // causes lifetime issues. A future contribution
-                                // could attempt to return the current output.
-                                // https://github.com/nushell/nushell/pull/2830#discussion_r550319687
-                                return Ok(InputStream::empty());
-                            }
-                        }
-                        Ok(None) => {
-                            if let Some(err) = ctx.get_errors().get(0) {
-                                ctx.clear_errors();
-                                return Err(err.clone());
-                            }
-                        }
-                        Err(e) => {
-                            return Err(e);
-                        }
+                    if ctx.ctrl_c.load(Ordering::SeqCst) {
+                        // This early return doesn't return the result
+                        // we have so far, but breaking out of this loop
+                        // causes lifetime issues. A future contribution
+                        // could attempt to return the current output.
+                        // https://github.com/nushell/nushell/pull/2830#discussion_r550319687
+                        return Ok(InputStream::empty());
                     }
                 }
                 Err(e) => {
                     return Err(e);
                 }
             }
+            if let Some((debugger, depth)) = ctx.scope.get_debugger() {
+                debugger.before_pipeline(pipeline, depth, ctx).await?;
+            }
+
             output = run_pipeline(pipeline, ctx, input).await;
 
             input = InputStream::empty();
@@ -136,13 +120,82 @@ pub async fn run_block(
     output
 }
 
+/// Runs the block and reads all of its output. The errors of an external that isn't the last in
+/// its pipeline end up in the context rather than in the output, so they're taken out of the
+/// context and come after the output, as error values.
+pub async fn run_block_to_end(
+    block: &Block,
+    ctx: &EvaluationContext,
+    input: InputStream,
+) -> Result<Vec<Value>, ShellError> {
+    let mut output = run_block(block, ctx, input).await?.into_vec().await;
+
+    let tag = Tag::unknown_anchor(block.span);
+    output.extend(
+        ctx.get_errors()
+            .into_iter()
+            .map(|err| UntaggedValue::Error(err).into_value(&tag)),
+    );
+    ctx.clear_errors();
+
+    Ok(output)
+}
+
+/// Runs a statement whose output isn't used to the end, stopping at the first error so the
+/// statements after it don't run
+async fn drain_statement(input: InputStream, ctx: &EvaluationContext) -> Result<(), ShellError> {
+    let mut output_stream = input.to_output_stream();
+
+    loop {
+        match output_stream.try_next().await {
+            Ok(Some(ReturnSuccess::Value(Value {
+                value: UntaggedValue::Error(e),
+                ..
+            }))) => {
+                return Err(e);
+            }
+            Ok(Some(_item)) => {
+                if let Some(err) = ctx.get_errors().get(0) {
+                    ctx.clear_errors();
+                    return Err(err.clone());
+                }
+                if ctx.ctrl_c.load(Ordering::SeqCst) {
+                    return Ok(());
+                }
+            }
+            Ok(None) => {
+                if let Some(err) = ctx.get_errors().get(0) {
+                    ctx.clear_errors();
+                    return Err(err.clone());
+                }
+                return Ok(());
+            }
+            Err(e) => {
+                return Err(e);
+            }
+        }
+    }
+}
+
 #[async_recursion]
 async fn run_pipeline(
     commands: &Pipeline,
     ctx: &EvaluationContext,
     mut input: InputStream,
 ) -> Result<InputStream, ShellError> {
+    let profiler = ctx.scope.get_profiler();
+    let mut upstream = None;
+
     for item in commands.list.clone() {
+        // A dynamic command hands the rest of the pipeline over to its block, whose commands are
+        // profiled themselves
+        let profiled = match &profiler {
+            Some((profiler, depth)) if !matches!(item, ClassifiedCommand::Dynamic(_)) => {
+                Some((profiler.start(&item, *depth, upstream), Instant::now()))
+            }
+            _ => None,
+        };
+
         input = match item {
             ClassifiedCommand::Dynamic(call) => {
                 let mut args = vec![];
@@ -210,6 +263,12 @@ async fn run_pipeline(
 
             ClassifiedCommand::Internal(left) => run_internal_command(left, ctx, input).await?,
         };
+
+        if let (Some((profiler, _)), Some((index, start))) = (&profiler, profiled) {
+            profiler.add_time(index, start.elapsed());
+            input = profiler.profile_output(index, input);
+            upstream = Some(index);
+        }
     }
 
     Ok(input)
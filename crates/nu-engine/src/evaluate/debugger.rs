@@ -0,0 +1,487 @@
+use crate::evaluate::block::run_block;
+use crate::evaluate::profiler::command_name;
+use crate::evaluation_context::EvaluationContext;
+use futures::stream::TryStreamExt;
+use nu_errors::ShellError;
+use nu_protocol::hir::{Call, Expression, Pipeline, SpannedExpression, Synthetic};
+use nu_protocol::{ReturnSuccess, UntaggedValue, Value};
+use nu_source::{Span, Tag, Text};
+use nu_stream::InputStream;
+use parking_lot::Mutex;
+use std::io::{BufRead, Write};
+use std::sync::Arc;
+
+const HELP: &str = r#"Debugger commands:
+  c, continue          run until the next breakpoint
+  s, step              run the next pipeline, stopping inside the blocks it runs
+  n, next              run the next pipeline, stepping over the blocks it runs
+  b, break [line|cmd]  stop at a line or before a command, or list the breakpoints
+  d, delete <line|cmd> remove a breakpoint
+  l, list              show the lines around the paused pipeline
+  w, where             show where the script is paused
+  v, vars              show the variables in the paused scope
+  e, env               show the environment of the paused scope
+  p, print <pipeline>  run a pipeline in the paused scope and show its output
+  q, quit              stop the script
+  h, help              show this help"#;
+
+/// Where the debugger stops, a line of the script or the commands with a name
+#[derive(Debug, Clone, PartialEq)]
+pub enum Breakpoint {
+    Line(usize),
+    Command(String),
+}
+
+impl Breakpoint {
+    /// A number is a line, anything else the name of a command
+    pub fn parse(text: &str) -> Breakpoint {
+        match text.trim().parse() {
+            Ok(line) => Breakpoint::Line(line),
+            Err(_) => Breakpoint::Command(text.trim().to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Breakpoint::Line(line) => write!(f, "line {}", line),
+            Breakpoint::Command(name) => write!(f, "command {}", name),
+        }
+    }
+}
+
+/// Where the commands typed at the debugger prompt come from
+pub trait DebuggerInput: Send {
+    /// The next line typed, or None once there are no more
+    fn read_line(&mut self, prompt: &str) -> Option<String>;
+}
+
+/// Reads the debugger commands from stdin, a line at a time
+#[derive(Debug, Default)]
+pub struct StdinInput;
+
+impl DebuggerInput for StdinInput {
+    fn read_line(&mut self, prompt: &str) -> Option<String> {
+        print!("{}", prompt);
+        let _ = std::io::stdout().flush();
+
+        let mut line = String::new();
+        match std::io::stdin().lock().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Stepping {
+    /// Only stop at the breakpoints
+    Run,
+    /// Stop before the next pipeline, however deep
+    Step,
+    /// Stop before the next pipeline at most as deep as this
+    Next(usize),
+}
+
+struct DebuggerState {
+    name: String,
+    source: String,
+    breakpoints: Vec<Breakpoint>,
+    stepping: Stepping,
+    input: Box<dyn DebuggerInput>,
+    /// Set while the pipelines typed at the prompt run, which aren't stopped at
+    evaluating: bool,
+}
+
+/// Pauses a script before its pipelines to look around in the scope they run in, while it's set
+/// on the scope, see `Scope::set_debugger`
+#[derive(Clone)]
+pub struct Debugger {
+    state: Arc<Mutex<DebuggerState>>,
+}
+
+impl std::fmt::Debug for Debugger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock();
+
+        f.debug_struct("Debugger")
+            .field("name", &state.name)
+            .field("breakpoints", &state.breakpoints)
+            .field("stepping", &state.stepping)
+            .finish()
+    }
+}
+
+impl Debugger {
+    /// A debugger for the script with the given name and source, which the spans of its
+    /// pipelines point into. Without breakpoints it stops before the first pipeline.
+    pub fn new(
+        name: impl Into<String>,
+        source: impl Into<String>,
+        breakpoints: Vec<Breakpoint>,
+        input: Box<dyn DebuggerInput>,
+    ) -> Debugger {
+        let stepping = if breakpoints.is_empty() {
+            Stepping::Step
+        } else {
+            Stepping::Run
+        };
+
+        Debugger {
+            state: Arc::new(Mutex::new(DebuggerState {
+                name: name.into(),
+                source: source.into(),
+                breakpoints,
+                stepping,
+                input,
+                evaluating: false,
+            })),
+        }
+    }
+
+    /// Called before each pipeline runs, to stop there if stepping or at a breakpoint and take
+    /// commands until told to go on
+    pub(crate) async fn before_pipeline(
+        &self,
+        pipeline: &Pipeline,
+        depth: usize,
+        ctx: &EvaluationContext,
+    ) -> Result<(), ShellError> {
+        let commands = pipeline
+            .list
+            .iter()
+            .map(|command| command_name(command).0)
+            .collect::<Vec<_>>();
+        let line = self.line_of(pipeline.span);
+
+        let reason = match self.stop_reason(line, &commands, depth) {
+            Some(reason) => reason,
+            None => return Ok(()),
+        };
+
+        self.print(ctx, &format!("{}{}", reason, self.location(pipeline, line)));
+
+        loop {
+            let input = {
+                let mut state = self.state.lock();
+                state.input.read_line("debug> ")
+            };
+
+            let input = match input {
+                Some(input) => input,
+                None => {
+                    // Nothing more is coming from the prompt, so the rest of the script just runs
+                    let mut state = self.state.lock();
+                    state.stepping = Stepping::Run;
+                    state.breakpoints.clear();
+                    return Ok(());
+                }
+            };
+
+            let input = input.trim();
+            let (command, rest) = match input.find(char::is_whitespace) {
+                Some(index) => (&input[..index], input[index..].trim()),
+                None => (input, ""),
+            };
+
+            match command {
+                "" => {}
+                "c" | "continue" => {
+                    self.resume(Stepping::Run);
+                    return Ok(());
+                }
+                "s" | "step" => {
+                    self.resume(Stepping::Step);
+                    return Ok(());
+                }
+                "n" | "next" => {
+                    self.resume(Stepping::Next(depth));
+                    return Ok(());
+                }
+                "b" | "break" if rest.is_empty() => {
+                    let breakpoints = self.state.lock().breakpoints.clone();
+                    if breakpoints.is_empty() {
+                        self.print(ctx, "No breakpoints");
+                    }
+                    for breakpoint in breakpoints {
+                        self.print(ctx, &format!("Breakpoint at {}", breakpoint));
+                    }
+                }
+                "b" | "break" => {
+                    let breakpoint = Breakpoint::parse(rest);
+                    self.print(ctx, &format!("Breakpoint at {}", breakpoint));
+                    self.state.lock().breakpoints.push(breakpoint);
+                }
+                "d" | "delete" => {
+                    let breakpoint = Breakpoint::parse(rest);
+                    let mut state = self.state.lock();
+                    let before = state.breakpoints.len();
+                    state.breakpoints.retain(|existing| *existing != breakpoint);
+                    let removed = state.breakpoints.len() < before;
+                    drop(state);
+
+                    if removed {
+                        self.print(ctx, &format!("Removed the breakpoint at {}", breakpoint));
+                    } else {
+                        self.print(ctx, &format!("No breakpoint at {}", breakpoint));
+                    }
+                }
+                "l" | "list" => self.print(ctx, &self.listing(line)),
+                "w" | "where" => self.print(ctx, &self.location(pipeline, line)),
+                "v" | "vars" => {
+                    for (name, value) in ctx.scope.get_vars() {
+                        self.print(ctx, &format!("{} = {}", name, describe(&value)));
+                    }
+                }
+                "e" | "env" => {
+                    let mut env = ctx.scope.get_env_vars().into_iter().collect::<Vec<_>>();
+                    env.sort();
+                    for (name, value) in env {
+                        self.print(ctx, &format!("{} = {}", name, value));
+                    }
+                }
+                "p" | "print" => {
+                    if let Err(err) = self.evaluate(rest, ctx).await {
+                        ctx.with_host(|host| host.print_err(err, &Text::from(rest)));
+                    }
+                }
+                "q" | "quit" => {
+                    return Err(ShellError::untagged_runtime_error("Stopped debugging"))
+                }
+                "h" | "help" => self.print(ctx, HELP),
+                _ => self.print(
+                    ctx,
+                    &format!(
+                        "Unknown debugger command '{}', type help for the commands",
+                        command
+                    ),
+                ),
+            }
+        }
+    }
+
+    fn stop_reason(
+        &self,
+        line: Option<usize>,
+        commands: &[String],
+        depth: usize,
+    ) -> Option<String> {
+        let state = self.state.lock();
+        if state.evaluating {
+            return None;
+        }
+
+        let breakpoint = state
+            .breakpoints
+            .iter()
+            .find(|breakpoint| match breakpoint {
+                Breakpoint::Line(at) => Some(*at) == line,
+                Breakpoint::Command(name) => commands.iter().any(|command| command == name),
+            });
+
+        match (breakpoint, state.stepping) {
+            (Some(breakpoint), _) => Some(format!("Breakpoint at {}: ", breakpoint)),
+            (None, Stepping::Step) => Some(String::new()),
+            (None, Stepping::Next(at_most)) if depth <= at_most => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    fn resume(&self, stepping: Stepping) {
+        self.state.lock().stepping = stepping;
+    }
+
+    /// The line of the script the span starts on, if it's in the script
+    fn line_of(&self, span: Span) -> Option<usize> {
+        let state = self.state.lock();
+        if span.is_unknown() || span.end() > state.source.len() {
+            return None;
+        }
+
+        state
+            .source
+            .get(..span.start())
+            .map(|before| before.matches('\n').count() + 1)
+    }
+
+    fn location(&self, pipeline: &Pipeline, line: Option<usize>) -> String {
+        let state = self.state.lock();
+        let text = state
+            .source
+            .get(pipeline.span.start()..pipeline.span.end())
+            .unwrap_or_default()
+            .trim();
+
+        match line {
+            Some(line) => format!("{}:{}: {}", state.name, line, text),
+            None => format!("{}: {}", state.name, text),
+        }
+    }
+
+    /// The lines around the one paused at, which is marked with an arrow and the lines with
+    /// breakpoints with a star
+    fn listing(&self, line: Option<usize>) -> String {
+        let state = self.state.lock();
+        let current = line.unwrap_or(1);
+        let first = current.saturating_sub(3).max(1);
+
+        state
+            .source
+            .lines()
+            .enumerate()
+            .map(|(index, text)| (index + 1, text))
+            .skip(first - 1)
+            .take(7)
+            .map(|(number, text)| {
+                let marker = if Some(number) == line { "->" } else { "  " };
+                let breakpoint = if state.breakpoints.contains(&Breakpoint::Line(number)) {
+                    "*"
+                } else {
+                    " "
+                };
+
+                format!("{}{}{:>4} {}", marker, breakpoint, number, text)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn print(&self, ctx: &EvaluationContext, text: &str) {
+        ctx.with_host(|host| host.stdout(text));
+    }
+
+    /// Runs the pipeline typed at the prompt in the paused scope and views its output
+    async fn evaluate(&self, source: &str, ctx: &EvaluationContext) -> Result<(), ShellError> {
+        let (block, err) = nu_parser::parse(source, 0, &ctx.scope);
+        if let Some(err) = err {
+            return Err(err.into());
+        }
+
+        self.state.lock().evaluating = true;
+        let result = view(run_block(&block, ctx, InputStream::empty()).await, ctx).await;
+        self.state.lock().evaluating = false;
+
+        result
+    }
+}
+
+async fn view(
+    output: Result<InputStream, ShellError>,
+    ctx: &EvaluationContext,
+) -> Result<(), ShellError> {
+    let autoview = match ctx.get_command("autoview") {
+        Some(autoview) => autoview,
+        None => return Ok(()),
+    };
+
+    let mut output_stream = ctx
+        .run_command(
+            autoview,
+            Tag::unknown(),
+            Call::new(
+                Box::new(SpannedExpression::new(
+                    Expression::Synthetic(Synthetic::String("autoview".into())),
+                    Span::unknown(),
+                )),
+                Span::unknown(),
+            ),
+            output?,
+        )
+        .await?;
+
+    while let Some(item) = output_stream.try_next().await? {
+        if let ReturnSuccess::Value(Value {
+            value: UntaggedValue::Error(e),
+            ..
+        }) = item
+        {
+            return Err(e);
+        }
+    }
+
+    match ctx.get_errors().get(0) {
+        Some(err) => {
+            ctx.clear_errors();
+            Err(err.clone())
+        }
+        None => Ok(()),
+    }
+}
+
+/// A variable's value on one line, tables and rows shortened to what they hold
+fn describe(value: &Value) -> String {
+    match &value.value {
+        UntaggedValue::Table(rows) => format!("[table {} rows]", rows.len()),
+        UntaggedValue::Row(row) => format!(
+            "[row {}]",
+            row.keys().cloned().collect::<Vec<_>>().join(" ")
+        ),
+        UntaggedValue::Block(_) => "<block>".to_string(),
+        _ => value.convert_to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Breakpoint, Debugger, DebuggerInput};
+
+    struct NoInput;
+
+    impl DebuggerInput for NoInput {
+        fn read_line(&mut self, _: &str) -> Option<String> {
+            None
+        }
+    }
+
+    fn debugger(breakpoints: Vec<Breakpoint>) -> Debugger {
+        Debugger::new(
+            "script.nu",
+            "echo one\nls | where size > 10kb\necho three\n",
+            breakpoints,
+            Box::new(NoInput),
+        )
+    }
+
+    #[test]
+    fn breakpoints_are_lines_or_commands() {
+        assert_eq!(Breakpoint::parse("12"), Breakpoint::Line(12));
+        assert_eq!(
+            Breakpoint::parse(" where "),
+            Breakpoint::Command("where".to_string())
+        );
+    }
+
+    #[test]
+    fn stops_at_the_first_pipeline_without_breakpoints() {
+        let debugger = debugger(vec![]);
+
+        assert!(debugger
+            .stop_reason(Some(1), &["echo".to_string()], 0)
+            .is_some());
+    }
+
+    #[test]
+    fn stops_at_the_breakpoints() {
+        let debugger = debugger(vec![Breakpoint::Line(3), Breakpoint::parse("where")]);
+
+        assert!(debugger
+            .stop_reason(Some(1), &["echo".to_string()], 0)
+            .is_none());
+        assert!(debugger
+            .stop_reason(Some(2), &["ls".to_string(), "where".to_string()], 0)
+            .is_some());
+        assert!(debugger
+            .stop_reason(Some(3), &["echo".to_string()], 0)
+            .is_some());
+    }
+
+    #[test]
+    fn lines_are_counted_from_one() {
+        let debugger = debugger(vec![]);
+
+        assert_eq!(debugger.line_of(nu_source::Span::new(0, 8)), Some(1));
+        assert_eq!(debugger.line_of(nu_source::Span::new(9, 11)), Some(2));
+        assert_eq!(debugger.line_of(nu_source::Span::new(100, 110)), None);
+    }
+}
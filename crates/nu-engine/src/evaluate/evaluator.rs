@@ -25,6 +25,7 @@ pub async fn evaluate_baseline_expr(
     let tag = Tag {
         span: expr.span,
         anchor: None,
+        content_type: None,
     };
     let span = expr.span;
     match &expr.expr {
@@ -42,6 +43,12 @@ pub async fn evaluate_baseline_expr(
         Expression::Invocation(block) => evaluate_invocation(block, ctx).await,
         Expression::ExternalCommand(_) => unimplemented!(),
         Expression::Binary(binary) => {
+            if let Expression::Literal(hir::Literal::Operator(hir::Operator::Coalesce)) =
+                binary.op.expr
+            {
+                return evaluate_coalesce(&binary.left, &binary.right, ctx).await;
+            }
+
             // TODO: If we want to add short-circuiting, we'll need to move these down
             let left = evaluate_baseline_expr(&binary.left, ctx).await?;
             let right = evaluate_baseline_expr(&binary.right, ctx).await?;
@@ -167,9 +174,15 @@ pub async fn evaluate_baseline_expr(
         Expression::Path(path) => {
             let value = evaluate_baseline_expr(&path.head, ctx).await?;
             let mut item = value;
+            let is_env = matches!(&path.head.expr, Expression::Variable(name, _) if name == "$env");
 
-            for member in &path.tail {
-                let next = item.get_data_by_member(member);
+            for (idx, member) in path.tail.iter().enumerate() {
+                let member = if is_env && idx == 0 {
+                    crate::evaluate::variables::env_member(&item, member)
+                } else {
+                    member.clone()
+                };
+                let next = item.get_data_by_member(&member);
 
                 match next {
                     Err(err) => match &member.unspanned {
@@ -234,6 +247,8 @@ fn evaluate_reference(name: &str, ctx: &EvaluationContext, tag: Tag) -> Result<V
     match name {
         "$nu" => crate::evaluate::variables::nu(&ctx.scope, tag),
 
+        "$env" => crate::evaluate::variables::env(&ctx.scope, tag),
+
         "$true" => Ok(Value {
             value: UntaggedValue::boolean(true),
             tag,
@@ -269,6 +284,72 @@ fn evaluate_reference(name: &str, ctx: &EvaluationContext, tag: Tag) -> Result<V
     }
 }
 
+/// Evaluates `left ?? right`. The right-hand side is only evaluated when the left-hand side is
+/// nothing, or is a variable or cell path that doesn't exist
+async fn evaluate_coalesce(
+    left: &SpannedExpression,
+    right: &SpannedExpression,
+    ctx: &EvaluationContext,
+) -> Result<Value, ShellError> {
+    match evaluate_if_present(left, ctx).await? {
+        Some(value) if value.is_some() => Ok(value),
+        _ => evaluate_baseline_expr(right, ctx).await,
+    }
+}
+
+/// Like `evaluate_baseline_expr`, but gives `None` when the expression is a variable that isn't
+/// in scope or a cell path whose column or row is missing. Any other error is passed through.
+#[async_recursion]
+async fn evaluate_if_present(
+    expr: &SpannedExpression,
+    ctx: &EvaluationContext,
+) -> Result<Option<Value>, ShellError> {
+    let tag = Tag {
+        span: expr.span,
+        anchor: None,
+        content_type: None,
+    };
+
+    match &expr.expr {
+        Expression::Variable(name, _) if !is_builtin_variable(name) => Ok(ctx.scope.get_var(name)),
+        Expression::Path(path) => {
+            let mut item = match evaluate_if_present(&path.head, ctx).await? {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+            let is_env = matches!(&path.head.expr, Expression::Variable(name, _) if name == "$env");
+
+            for (idx, member) in path.tail.iter().enumerate() {
+                let member = if is_env && idx == 0 {
+                    crate::evaluate::variables::env_member(&item, member)
+                } else {
+                    member.clone()
+                };
+
+                match item.get_data_by_member(&member) {
+                    Ok(next) => item = next.value.into_value(&tag),
+                    Err(err) => {
+                        let missing = matches!(
+                            (&item.value, &member.unspanned),
+                            (UntaggedValue::Row(_), UnspannedPathMember::String(_))
+                                | (UntaggedValue::Table(_), _)
+                        );
+
+                        return if missing { Ok(None) } else { Err(err) };
+                    }
+                }
+            }
+
+            Ok(Some(item.value.into_value(tag)))
+        }
+        _ => evaluate_baseline_expr(expr, ctx).await.map(Some),
+    }
+}
+
+fn is_builtin_variable(name: &str) -> bool {
+    matches!(name, "$nu" | "$env" | "$true" | "$false" | "$nothing")
+}
+
 async fn evaluate_invocation(
     block: &hir::Block,
     ctx: &EvaluationContext,
@@ -7,10 +7,11 @@ use crate::shell::value_shell::ValueShell;
 use futures::StreamExt;
 use log::{log_enabled, trace};
 use nu_errors::ShellError;
+use nu_plugin::PluginAddress;
 use nu_protocol::hir::{ExternalRedirection, InternalCommand};
 use nu_protocol::{CommandAction, Primitive, ReturnSuccess, UntaggedValue, Value};
 use nu_source::{PrettyDebug, Span, Tag};
-use nu_stream::{trace_stream, InputStream, ToInputStream};
+use nu_stream::{trace_stream, InputStream, Interruptible, ToInputStream};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
@@ -26,7 +27,12 @@ pub(crate) async fn run_internal_command(
 
     let objects: InputStream = trace_stream!(target: "nu::trace_stream::internal", "input" = input);
 
+    // Both ends of a command stop at ctrl-c, so a command that reads all of its input (eg where,
+    // when nothing matches) or keeps writing output (eg each) doesn't run until the stream ends
+    let objects = InputStream::from_stream(objects.interruptible(context.ctrl_c.clone()));
+
     let internal_command = context.scope.expect_command(&command.name);
+    crate::crash_context::set_span(command.name_span);
 
     if command.name == "autoenv untrust" {
         context
@@ -51,6 +57,7 @@ pub(crate) async fn run_internal_command(
 
     Ok(InputStream::from_stream(
         result
+            .interruptible(context.ctrl_c.clone())
             .then(move |item| {
                 let head = head.clone();
                 let command = command.clone();
@@ -62,21 +69,36 @@ pub(crate) async fn run_internal_command(
                                 context.shell_manager.set_path(path);
                                 InputStream::empty()
                             }
-                            CommandAction::Exit(code) => std::process::exit(code), // TODO: save history.txt
+                            CommandAction::Exit(code) => context.exit(code),
                             CommandAction::Error(err) => {
                                 context.error(err);
                                 InputStream::empty()
                             }
                             CommandAction::AutoConvert(tagged_contents, extension) => {
                                 let contents_tag = tagged_contents.tag.clone();
-                                let command_name = format!("from {}", extension);
                                 let command = command.clone();
-                                if let Some(converter) = context.scope.get_command(&command_name) {
+
+                                // Without a converter for the extension, the content type (eg
+                                // from a web server) may still say what the contents are
+                                let converter = context
+                                    .scope
+                                    .get_command(&format!("from {}", extension))
+                                    .or_else(|| {
+                                        let format = tagged_contents
+                                            .tag
+                                            .content_type
+                                            .as_deref()
+                                            .and_then(nu_data::content_type::format_for)?;
+                                        context.scope.get_command(&format!("from {}", format))
+                                    });
+
+                                if let Some(converter) = converter {
                                     let new_args = RawCommandArgs {
                                         host: context.host.clone(),
                                         ctrl_c: context.ctrl_c.clone(),
                                         current_errors: context.current_errors.clone(),
                                         shell_manager: context.shell_manager.clone(),
+                                        jobs: context.jobs.clone(),
                                         call_info: UnevaluatedCallInfo {
                                             args: nu_protocol::hir::Call {
                                                 head: (&*head).clone(),
@@ -105,7 +127,14 @@ pub(crate) async fn run_internal_command(
                                                         value: UntaggedValue::Table(list),
                                                         ..
                                                     })) => {
-                                                        for l in list {
+                                                        for mut l in list {
+                                                            // The rows keep saying what they
+                                                            // were loaded from, eg for save
+                                                            if l.tag.content_type.is_none() {
+                                                                l.tag.content_type = contents_tag
+                                                                    .content_type
+                                                                    .clone();
+                                                            }
                                                             output.push(Ok(l));
                                                         }
                                                     }
@@ -171,6 +200,66 @@ pub(crate) async fn run_internal_command(
                                     .insert_at_current(Box::new(ValueShell::new(value)));
                                 InputStream::from_stream(futures::stream::iter(vec![]))
                             }
+                            #[cfg(feature = "archive")]
+                            CommandAction::EnterArchiveShell(location) => {
+                                context.shell_manager.insert_at_current(Box::new(
+                                    match crate::shell::archive_shell::ArchiveShell::new(location) {
+                                        Ok(v) => v,
+                                        Err(err) => {
+                                            context.error(err);
+                                            return InputStream::empty();
+                                        }
+                                    },
+                                ));
+                                InputStream::from_stream(futures::stream::iter(vec![]))
+                            }
+                            #[cfg(not(feature = "archive"))]
+                            CommandAction::EnterArchiveShell(_) => {
+                                context.error(ShellError::untagged_runtime_error(
+                                    "Nu was built without archive support",
+                                ));
+                                InputStream::empty()
+                            }
+                            #[cfg(feature = "sqlite")]
+                            CommandAction::EnterSqliteShell(location) => {
+                                context.shell_manager.insert_at_current(Box::new(
+                                    match crate::shell::sqlite_shell::SqliteShell::new(location) {
+                                        Ok(v) => v,
+                                        Err(err) => {
+                                            context.error(err);
+                                            return InputStream::empty();
+                                        }
+                                    },
+                                ));
+                                InputStream::from_stream(futures::stream::iter(vec![]))
+                            }
+                            #[cfg(not(feature = "sqlite"))]
+                            CommandAction::EnterSqliteShell(_) => {
+                                context.error(ShellError::untagged_runtime_error(
+                                    "Nu was built without SQLite support",
+                                ));
+                                InputStream::empty()
+                            }
+                            #[cfg(feature = "s3")]
+                            CommandAction::EnterS3Shell(url) => {
+                                context.shell_manager.insert_at_current(Box::new(
+                                    match crate::shell::s3_shell::S3Shell::new(&url) {
+                                        Ok(v) => v,
+                                        Err(err) => {
+                                            context.error(err);
+                                            return InputStream::empty();
+                                        }
+                                    },
+                                ));
+                                InputStream::from_stream(futures::stream::iter(vec![]))
+                            }
+                            #[cfg(not(feature = "s3"))]
+                            CommandAction::EnterS3Shell(_) => {
+                                context.error(ShellError::untagged_runtime_error(
+                                    "Nu was built without S3 support",
+                                ));
+                                InputStream::empty()
+                            }
                             CommandAction::EnterShell(location) => {
                                 context.shell_manager.insert_at_current(Box::new(
                                     match FilesystemShell::with_location(location) {
@@ -184,15 +273,36 @@ pub(crate) async fn run_internal_command(
                                 InputStream::from_stream(futures::stream::iter(vec![]))
                             }
                             CommandAction::AddPlugins(path) => {
-                                match crate::plugin::build_plugin::scan(vec![
-                                    std::path::PathBuf::from(path),
-                                ]) {
-                                    Ok(plugins) => {
+                                let scanned = if PluginAddress::is_remote(&path) {
+                                    crate::plugin::build_plugin::scan_with_remotes(&[path], vec![])
+                                } else {
+                                    crate::plugin::build_plugin::scan(vec![
+                                        std::path::PathBuf::from(path),
+                                    ])
+                                };
+
+                                match scanned {
+                                    Ok(mut plugins) => {
+                                        if let Some(err) = plugins.errors.pop() {
+                                            context.error(err);
+                                            return InputStream::empty();
+                                        }
+
                                         context.add_commands(
                                             plugins
+                                                .commands
                                                 .into_iter()
                                                 .filter(|p| {
-                                                    !context.is_command_registered(p.name())
+                                                    // A plugin loaded again from the same path
+                                                    // is refreshed, eg after it's updated
+                                                    match context.get_command(p.name()) {
+                                                        Some(command) => {
+                                                            command.plugin_path().is_some()
+                                                                && command.plugin_path()
+                                                                    == p.plugin_path()
+                                                        }
+                                                        None => true,
+                                                    }
                                                 })
                                                 .collect(),
                                         );
@@ -216,7 +326,7 @@ pub(crate) async fn run_internal_command(
                             CommandAction::LeaveShell(code) => {
                                 context.shell_manager.remove_at_current();
                                 if context.shell_manager.is_empty() {
-                                    std::process::exit(code); // TODO: save history.txt
+                                    context.exit(code);
                                 }
                                 InputStream::empty()
                             }
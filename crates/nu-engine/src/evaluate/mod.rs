@@ -1,8 +1,10 @@
 pub(crate) mod block;
+pub(crate) mod debugger;
 pub(crate) mod evaluate_args;
 pub(crate) mod evaluator;
 pub(crate) mod expr;
 pub(crate) mod internal;
 pub(crate) mod operator;
+pub(crate) mod profiler;
 pub(crate) mod scope;
 pub(crate) mod variables;
@@ -26,6 +26,11 @@ pub fn apply_operator(
         Operator::Minus => value::compute_values(op, left, right),
         Operator::Multiply => value::compute_values(op, left, right),
         Operator::Pow => value::compute_values(op, left, right),
+        Operator::BitAnd
+        | Operator::BitOr
+        | Operator::BitXor
+        | Operator::ShiftLeft
+        | Operator::ShiftRight => value::compute_values(op, left, right),
         Operator::Divide => value::compute_values(op, left, right).map(|res| match res {
             UntaggedValue::Error(_) => UntaggedValue::Error(ShellError::labeled_error(
                 "Evaluation error",
@@ -52,6 +57,13 @@ pub fn apply_operator(
             (Ok(left), Ok(right)) => Ok(UntaggedValue::boolean(left || right)),
             _ => Err((left.type_name(), right.type_name())),
         },
+        Operator::Coalesce => {
+            if left.is_none() {
+                Ok(right.value.clone())
+            } else {
+                Ok(left.value.clone())
+            }
+        }
     }
 }
 
@@ -76,19 +88,28 @@ fn string_contains(
     }
 }
 
+/// Membership for `in` and `not-in`: an item of a list, a column of a record, or a substring of a
+/// string
 fn table_contains(
     left: &UntaggedValue,
     right: &UntaggedValue,
 ) -> Result<bool, (&'static str, &'static str)> {
     match right {
-        UntaggedValue::Table(values) => {
-            Ok(values
-                .iter()
-                .any(|x| match compare_values(Operator::Equal, &left, &x.value) {
+        UntaggedValue::Table(values) => Ok(values.iter().any(|x| {
+            // Exact matches are cheap to check and the common case, coercing (eg 1 in [1.0]) is
+            // only needed when they differ
+            left == &x.value
+                || match compare_values(Operator::Equal, &left, &x.value) {
                     Ok(coerced) => coerced,
                     _ => false,
-                }))
-        }
+                }
+        })),
+        UntaggedValue::Row(dict) => match left {
+            UntaggedValue::Primitive(Primitive::String(key)) => Ok(dict.entries.contains_key(key)),
+            _ => Err((left.type_name(), right.type_name())),
+        },
+        UntaggedValue::Primitive(Primitive::String(_))
+        | UntaggedValue::Primitive(Primitive::FilePath(_)) => string_contains(right, left),
         _ => Err((left.type_name(), right.type_name())),
     }
 }
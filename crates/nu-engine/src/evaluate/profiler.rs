@@ -0,0 +1,184 @@
+use futures::task::{Context, Poll};
+use futures::Stream;
+use nu_protocol::hir::{ClassifiedCommand, Expression, Literal};
+use nu_protocol::{Primitive, UntaggedValue, Value};
+use nu_source::Span;
+use nu_stream::InputStream;
+use parking_lot::Mutex;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// What was measured of one command of a profiled block. A command inside a block that runs many
+/// times, like the block of `each`, adds up all of its runs.
+#[derive(Debug, Clone)]
+pub struct ProfileEntry {
+    pub command: String,
+    pub span: Span,
+    /// How deeply the command is nested in blocks, 0 for the commands of the profiled block
+    pub depth: usize,
+    pub calls: usize,
+    /// The time spent running the command and reading its output, which includes the time it
+    /// spent reading its input
+    pub elapsed: Duration,
+    /// The entry of the command before this one in the pipeline
+    pub upstream: Option<usize>,
+    pub rows: usize,
+    /// A rough estimate of the memory the values the command output took up
+    pub bytes: usize,
+}
+
+impl ProfileEntry {
+    /// The time spent in the command itself, without the time of the commands before it
+    pub fn self_time(&self, entries: &[ProfileEntry]) -> Duration {
+        match self.upstream.and_then(|upstream| entries.get(upstream)) {
+            Some(upstream) => self.elapsed.saturating_sub(upstream.elapsed),
+            None => self.elapsed,
+        }
+    }
+}
+
+/// Collects the measurements of the commands run while it's set on the scope, see
+/// `Scope::set_profiler`
+#[derive(Debug, Clone, Default)]
+pub struct Profiler {
+    entries: Arc<Mutex<Vec<ProfileEntry>>>,
+}
+
+impl Profiler {
+    pub fn new() -> Profiler {
+        Profiler::default()
+    }
+
+    pub fn entries(&self) -> Vec<ProfileEntry> {
+        self.entries.lock().clone()
+    }
+
+    /// The entry of the given command, which is started if it's the first run of it
+    pub(crate) fn start(
+        &self,
+        command: &ClassifiedCommand,
+        depth: usize,
+        upstream: Option<usize>,
+    ) -> usize {
+        let (name, span) = command_name(command);
+        let mut entries = self.entries.lock();
+
+        let index = match entries
+            .iter()
+            .position(|entry| entry.span == span && entry.command == name)
+        {
+            Some(index) => index,
+            None => {
+                entries.push(ProfileEntry {
+                    command: name,
+                    span,
+                    depth,
+                    calls: 0,
+                    elapsed: Duration::default(),
+                    upstream,
+                    rows: 0,
+                    bytes: 0,
+                });
+                entries.len() - 1
+            }
+        };
+        entries[index].calls += 1;
+
+        index
+    }
+
+    pub(crate) fn add_time(&self, index: usize, elapsed: Duration) {
+        if let Some(entry) = self.entries.lock().get_mut(index) {
+            entry.elapsed += elapsed;
+        }
+    }
+
+    fn add_row(&self, index: usize, elapsed: Duration, row: Option<&Value>) {
+        if let Some(entry) = self.entries.lock().get_mut(index) {
+            entry.elapsed += elapsed;
+            if let Some(row) = row {
+                entry.rows += 1;
+                entry.bytes += estimated_size(row);
+            }
+        }
+    }
+
+    /// Wraps the output of the command with the given entry, to time the reading of it
+    pub(crate) fn profile_output(&self, index: usize, output: InputStream) -> InputStream {
+        InputStream::from_stream(ProfiledStream {
+            inner: output,
+            profiler: self.clone(),
+            index,
+        })
+    }
+}
+
+struct ProfiledStream {
+    inner: InputStream,
+    profiler: Profiler,
+    index: usize,
+}
+
+impl Stream for ProfiledStream {
+    type Item = Value;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let start = Instant::now();
+        let result = Stream::poll_next(Pin::new(&mut self.inner), cx);
+
+        match &result {
+            Poll::Ready(row) => self
+                .profiler
+                .add_row(self.index, start.elapsed(), row.as_ref()),
+            Poll::Pending => self.profiler.add_time(self.index, start.elapsed()),
+        }
+
+        result
+    }
+}
+
+pub(crate) fn command_name(command: &ClassifiedCommand) -> (String, Span) {
+    match command {
+        ClassifiedCommand::Internal(internal) if internal.name == "run_external" => {
+            let name = internal
+                .args
+                .positional
+                .as_ref()
+                .and_then(|positional| positional.first())
+                .and_then(|name| match &name.expr {
+                    Expression::Literal(Literal::String(name)) => Some(format!("^{}", name)),
+                    _ => None,
+                })
+                .unwrap_or_else(|| internal.name.clone());
+
+            (name, internal.name_span)
+        }
+        ClassifiedCommand::Internal(internal) => (internal.name.clone(), internal.name_span),
+        ClassifiedCommand::Expr(expr) => ("expression".to_string(), expr.span),
+        ClassifiedCommand::Dynamic(call) => ("block".to_string(), call.span),
+        ClassifiedCommand::Error(_) => ("error".to_string(), Span::unknown()),
+    }
+}
+
+/// A rough estimate of the memory a value takes up, counting the value itself and what it owns
+pub fn estimated_size(value: &Value) -> usize {
+    let owned = match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::GlobPattern(s)) => s.capacity(),
+        UntaggedValue::Primitive(Primitive::FilePath(path)) => path.as_os_str().len(),
+        UntaggedValue::Primitive(Primitive::Binary(bytes)) => bytes.capacity(),
+        UntaggedValue::Primitive(Primitive::Int(int))
+        | UntaggedValue::Primitive(Primitive::Filesize(int))
+        | UntaggedValue::Primitive(Primitive::Duration(int)) => (int.bits() as usize + 7) / 8,
+        UntaggedValue::Row(row) => row
+            .entries
+            .iter()
+            .map(|(key, value)| key.capacity() + estimated_size(value))
+            .sum(),
+        UntaggedValue::Table(rows) => rows.iter().map(estimated_size).sum(),
+        _ => 0,
+    };
+
+    std::mem::size_of::<Value>() + owned
+}
@@ -1,9 +1,13 @@
+use crate::evaluate::debugger::Debugger;
+use crate::evaluate::profiler::Profiler;
 use crate::whole_stream_command::{whole_stream_command, Command};
 use indexmap::IndexMap;
+use nu_data::config::Conf;
 use nu_errors::ShellError;
 use nu_parser::ParserScope;
 use nu_protocol::{hir::Block, Value};
 use nu_source::Spanned;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -33,6 +37,16 @@ impl Scope {
         None
     }
 
+    /// Every command with the name, the one in the innermost scope first
+    pub fn get_commands_with_name(&self, name: &str) -> Vec<Command> {
+        self.frames
+            .lock()
+            .iter()
+            .rev()
+            .filter_map(|frame| frame.get_command(name))
+            .collect()
+    }
+
     pub fn get_aliases_with_name(&self, name: &str) -> Option<Vec<Vec<Spanned<String>>>> {
         let aliases: Vec<_> = self
             .frames
@@ -71,6 +85,99 @@ impl Scope {
         }
     }
 
+    /// Leaves the current scope, handing the environment variables set in it on to the scope
+    /// it was entered from
+    pub fn exit_scope_with_env(&self) {
+        let mut frames = self.frames.lock();
+        if let Some(frame) = frames.pop() {
+            if let Some(caller) = frames.last_mut() {
+                caller.env.extend(frame.env);
+            }
+        }
+    }
+
+    /// Gives the current scope a flag that, once set, kills the externals started in it
+    pub fn set_cancellation(&self, cancel: Arc<AtomicBool>) {
+        if let Some(frame) = self.frames.lock().last_mut() {
+            frame.cancellation = Some(cancel);
+        }
+    }
+
+    /// The flag of the innermost scope that has one, see `set_cancellation`
+    pub fn get_cancellation(&self) -> Option<Arc<AtomicBool>> {
+        self.frames
+            .lock()
+            .iter()
+            .rev()
+            .find_map(|frame| frame.cancellation.clone())
+    }
+
+    /// Gives the current scope a profiler that measures the commands run in it and in the scopes
+    /// entered from it
+    pub fn set_profiler(&self, profiler: Profiler) {
+        if let Some(frame) = self.frames.lock().last_mut() {
+            frame.profiler = Some(profiler);
+        }
+    }
+
+    /// The profiler of the innermost scope that has one, with how many scopes deeper than that one
+    /// the current scope is
+    pub fn get_profiler(&self) -> Option<(Profiler, usize)> {
+        self.frames
+            .lock()
+            .iter()
+            .rev()
+            .enumerate()
+            .find_map(|(depth, frame)| Some((frame.profiler.clone()?, depth)))
+    }
+
+    /// Gives the current scope a debugger that can pause before the pipelines run in it and in
+    /// the scopes entered from it
+    pub fn set_debugger(&self, debugger: Debugger) {
+        if let Some(frame) = self.frames.lock().last_mut() {
+            frame.debugger = Some(debugger);
+        }
+    }
+
+    /// The debugger of the innermost scope that has one, with how many scopes deeper than that one
+    /// the current scope is
+    pub fn get_debugger(&self) -> Option<(Debugger, usize)> {
+        self.frames
+            .lock()
+            .iter()
+            .rev()
+            .enumerate()
+            .find_map(|(depth, frame)| Some((frame.debugger.clone()?, depth)))
+    }
+
+    /// Gives the current scope the config the commands run in it, and in the scopes entered from it,
+    /// read their settings from
+    pub fn set_config(&self, config: &dyn Conf) {
+        if let Some(frame) = self.frames.lock().last_mut() {
+            frame.config = Some(Arc::new(parking_lot::Mutex::new(config.clone_box())));
+        }
+    }
+
+    /// The setting from the config of the innermost scope that has one, see `set_config`. Without
+    /// a config, as when running outside the REPL, nothing is set.
+    pub fn get_config_var(&self, name: &str) -> Option<Value> {
+        let config = self
+            .frames
+            .lock()
+            .iter()
+            .rev()
+            .find_map(|frame| frame.config.clone())?;
+
+        let config = config.lock();
+        config.var(name)
+    }
+
+    pub fn remove_command(&self, name: &str) {
+        for frame in self.frames.lock().iter_mut() {
+            frame.remove_command(name);
+        }
+    }
+
     pub fn get_command_names(&self) -> Vec<String> {
         let mut names = vec![];
 
@@ -85,6 +192,19 @@ impl Scope {
         names
     }
 
+    pub fn get_alias_names(&self) -> Vec<String> {
+        let mut names = vec![];
+
+        for frame in self.frames.lock().iter() {
+            names.extend(frame.aliases.keys().cloned());
+        }
+
+        names.sort();
+        names.dedup();
+
+        names
+    }
+
     pub fn len(&self) -> usize {
         self.frames.lock().len()
     }
@@ -180,6 +300,13 @@ impl Scope {
         }
     }
 
+    /// Unsets the variable, in the scopes this one was entered from too
+    pub fn remove_env_var(&self, name: &str) {
+        for frame in self.frames.lock().iter_mut() {
+            frame.env.shift_remove(name);
+        }
+    }
+
     pub fn add_env(&self, env_vars: IndexMap<String, String>) {
         if let Some(frame) = self.frames.lock().last_mut() {
             frame.env.extend(env_vars)
@@ -197,6 +324,14 @@ impl Scope {
             frame.env.insert(name.into(), value);
         }
     }
+
+    /// A copy of the scope with frames of its own, so blocks can run alongside each other (eg on
+    /// several threads) without entering and leaving scopes under each other's feet
+    pub fn snapshot(&self) -> Scope {
+        Scope {
+            frames: Arc::new(parking_lot::Mutex::new(self.frames.lock().clone())),
+        }
+    }
 }
 
 impl ParserScope for Scope {
@@ -259,6 +394,10 @@ pub struct ScopeFrame {
     pub commands: IndexMap<String, Command>,
     pub custom_commands: IndexMap<String, Block>,
     pub aliases: IndexMap<String, Vec<Spanned<String>>>,
+    pub cancellation: Option<Arc<AtomicBool>>,
+    pub profiler: Option<Profiler>,
+    pub debugger: Option<Debugger>,
+    pub config: Option<Arc<parking_lot::Mutex<Box<dyn Conf>>>>,
 }
 
 impl ScopeFrame {
@@ -282,6 +421,10 @@ impl ScopeFrame {
         self.commands.insert(name, command);
     }
 
+    pub fn remove_command(&mut self, name: &str) {
+        self.commands.shift_remove(name);
+    }
+
     pub fn get_command(&self, name: &str) -> Option<Command> {
         self.commands.get(name).cloned()
     }
@@ -293,6 +436,10 @@ impl ScopeFrame {
             commands: IndexMap::new(),
             custom_commands: IndexMap::new(),
             aliases: IndexMap::new(),
+            cancellation: None,
+            profiler: None,
+            debugger: None,
+            config: None,
         }
     }
 }
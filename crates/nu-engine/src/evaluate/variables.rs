@@ -1,7 +1,9 @@
 use crate::evaluate::scope::Scope;
 use nu_data::config::NuConfig;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, TaggedDictBuilder, UntaggedValue, Value};
+use nu_protocol::{
+    PathMember, Primitive, TaggedDictBuilder, UnspannedPathMember, UntaggedValue, Value,
+};
 use nu_source::Tag;
 
 pub fn nu(scope: &Scope, tag: impl Into<Tag>) -> Result<Value, ShellError> {
@@ -34,6 +36,15 @@ pub fn nu(scope: &Scope, tag: impl Into<Tag>) -> Result<Value, ShellError> {
         UntaggedValue::row(config.vars.clone()).into_value(&tag),
     );
 
+    // Lets the prompt show when the config file is broken and the defaults are in use
+    let config_error = config
+        .error
+        .clone()
+        .and_then(|err| err.into_diagnostic())
+        .map(|diagnostic| UntaggedValue::string(diagnostic.message))
+        .unwrap_or_else(UntaggedValue::nothing);
+    nu_dict.insert_value("config-error", config_error.into_value(&tag));
+
     let mut table = vec![];
     for v in env.iter() {
         if v.0 == "PATH" || v.0 == "Path" {
@@ -75,3 +86,45 @@ pub fn nu(scope: &Scope, tag: impl Into<Tag>) -> Result<Value, ShellError> {
 
     Ok(nu_dict.into_value())
 }
+
+/// The environment as a record, for `$env`. PATH is a list of directories and everything else is
+/// a string.
+pub fn env(scope: &Scope, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    let mut dict = TaggedDictBuilder::new(&tag);
+    for (name, value) in scope.get_env_vars() {
+        if is_path_var(&name) {
+            let paths: Vec<Value> = std::env::split_paths(&value)
+                .map(|path| UntaggedValue::filepath(path).into_value(&tag))
+                .collect();
+            dict.insert_value(name, UntaggedValue::table(&paths).into_value(&tag));
+        } else {
+            dict.insert_untagged(name, UntaggedValue::string(value));
+        }
+    }
+
+    Ok(dict.into_value())
+}
+
+/// Whether the variable holds the search path, which is PATH on most systems and usually Path on
+/// Windows
+pub fn is_path_var(name: &str) -> bool {
+    name.eq_ignore_ascii_case("path")
+}
+
+/// Environment variable names aren't case sensitive on Windows, so there `$env.path` finds the
+/// `Path` member of `$env`
+pub fn env_member(env: &Value, member: &PathMember) -> PathMember {
+    if cfg!(windows) {
+        if let (UntaggedValue::Row(dict), UnspannedPathMember::String(name)) =
+            (&env.value, &member.unspanned)
+        {
+            if let Some(key) = dict.keys().find(|key| key.eq_ignore_ascii_case(name)) {
+                return UnspannedPathMember::String(key.clone()).into_path_member(member.span);
+            }
+        }
+    }
+
+    member.clone()
+}
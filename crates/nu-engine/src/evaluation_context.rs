@@ -2,6 +2,7 @@ use crate::call_info::UnevaluatedCallInfo;
 use crate::command_args::CommandArgs;
 use crate::env::{basic_host::BasicHost, host::Host};
 use crate::evaluate::scope::Scope;
+use crate::jobs::Jobs;
 use crate::shell::shell_manager::ShellManager;
 use crate::whole_stream_command::Command;
 use indexmap::IndexMap;
@@ -21,6 +22,7 @@ pub struct EvaluationContext {
     pub ctrl_c: Arc<AtomicBool>,
     pub user_recently_used_autoenv_untrust: Arc<AtomicBool>,
     pub shell_manager: ShellManager,
+    pub jobs: Jobs,
 
     /// Windows-specific: keep track of previous cwd on each drive
     pub windows_drives_previous_cwd: Arc<Mutex<std::collections::HashMap<String, String>>>,
@@ -35,6 +37,7 @@ impl EvaluationContext {
             ctrl_c: Arc::new(AtomicBool::new(false)),
             user_recently_used_autoenv_untrust: Arc::new(AtomicBool::new(false)),
             shell_manager: ShellManager::basic()?,
+            jobs: Jobs::default(),
             windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
         })
     }
@@ -46,11 +49,19 @@ impl EvaluationContext {
             current_errors: args.current_errors.clone(),
             ctrl_c: args.ctrl_c.clone(),
             shell_manager: args.shell_manager.clone(),
+            jobs: args.jobs.clone(),
             user_recently_used_autoenv_untrust: Arc::new(AtomicBool::new(false)),
             windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
         }
     }
 
+    /// Ends the shell, stopping the background jobs and running the exit hooks first
+    pub fn exit(&self, code: i32) -> ! {
+        self.jobs.stop_all();
+        crate::exit_hooks::run();
+        std::process::exit(code)
+    }
+
     pub fn error(&self, error: ShellError) {
         self.with_errors(|errors| errors.push(error))
     }
@@ -89,7 +100,6 @@ impl EvaluationContext {
         }
     }
 
-    #[allow(unused)]
     pub(crate) fn get_command(&self, name: &str) -> Option<Command> {
         self.scope.get_command(name)
     }
@@ -119,6 +129,7 @@ impl EvaluationContext {
             ctrl_c: self.ctrl_c.clone(),
             current_errors: self.current_errors.clone(),
             shell_manager: self.shell_manager.clone(),
+            jobs: self.jobs.clone(),
             call_info: self.call_info(args, name_tag),
             scope: self.scope.clone(),
             input,
@@ -1,7 +1,7 @@
 use nu_protocol::Value;
 
-pub struct Example {
-    pub example: &'static str,
-    pub description: &'static str,
+pub struct Example<'a> {
+    pub example: &'a str,
+    pub description: &'a str,
     pub result: Option<Vec<Value>>,
 }
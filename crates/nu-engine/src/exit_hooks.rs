@@ -0,0 +1,39 @@
+use parking_lot::{const_mutex, Mutex};
+
+/// What has to be done before the shell goes when a command like `exit` ends it. Those commands
+/// end the process right away, so the shell never gets back to its own cleanup.
+static HOOKS: Mutex<Vec<Box<dyn FnOnce() + Send>>> = const_mutex(Vec::new());
+
+pub fn on_exit(hook: impl FnOnce() + Send + 'static) {
+    HOOKS.lock().push(Box::new(hook));
+}
+
+/// Runs the hooks added so far, each only once
+pub fn run() {
+    let hooks = std::mem::take(&mut *HOOKS.lock());
+
+    for hook in hooks {
+        hook();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{on_exit, run};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn hooks_run_once() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        on_exit(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        run();
+        run();
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::command_args::EvaluatedWholeStreamCommandArgs;
 use crate::filesystem::dir_info::{DirBuilder, DirInfo};
+use crate::filesystem::glob::{glob_paths, GlobOptions};
 use crate::filesystem::path::canonicalize;
 use crate::filesystem::utils::FileStructure;
 use crate::maybe_text_codec::{MaybeTextCodec, StringOrBinary};
@@ -10,6 +11,7 @@ use futures::stream::BoxStream;
 use futures::StreamExt;
 use futures_codec::FramedRead;
 use futures_util::TryStreamExt;
+use nu_protocol::value::glob::escape as glob_escape;
 use nu_protocol::{TaggedDictBuilder, Value};
 use nu_source::{Span, Tag};
 use nu_stream::{Interruptible, OutputStream, ToOutputStream};
@@ -86,6 +88,14 @@ impl Shell for FilesystemShell {
         "filesystem".to_string()
     }
 
+    fn is_filesystem(&self) -> bool {
+        true
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
     fn homedir(&self) -> Option<PathBuf> {
         homedir_if_possible()
     }
@@ -98,35 +108,49 @@ impl Shell for FilesystemShell {
             long,
             short_names,
             du,
+            max_depth,
         }: LsArgs,
         name_tag: Tag,
         ctrl_c: Arc<AtomicBool>,
     ) -> Result<OutputStream, ShellError> {
         let ctrl_c_copy = ctrl_c.clone();
-        let (path, p_tag) = match path {
+        let (path, pattern, p_tag) = match path {
             Some(p) => {
                 let p_tag = p.tag;
-                let mut p = p.item;
-                if p.is_dir() {
-                    if is_empty_dir(&p) {
+                let mut path = PathBuf::from(p.item.as_str());
+                if path.is_dir() {
+                    if is_empty_dir(&path) {
                         return Ok(OutputStream::empty());
                     }
-                    p.push("*");
+                    // A directory is listed as it's named, even if that looks like a glob
+                    let pattern = PathBuf::from(glob_escape(&path.to_string_lossy()))
+                        .join("*")
+                        .to_string_lossy()
+                        .to_string();
+                    path.push("*");
+                    (path, pattern, p_tag)
+                } else {
+                    let pattern = p.item.pattern_in(Path::new(""));
+                    (path, pattern, p_tag)
                 }
-                (p, p_tag)
             }
             None => {
                 if is_empty_dir(&self.path()) {
                     return Ok(OutputStream::empty());
                 } else {
-                    (PathBuf::from("./*"), name_tag.clone())
+                    (PathBuf::from("./*"), "./*".to_string(), name_tag.clone())
                 }
             }
         };
 
         let hidden_dir_specified = is_hidden_dir(&path);
 
-        let mut paths = glob::glob(&path.to_string_lossy())
+        let options = GlobOptions {
+            max_depth: max_depth.map(|depth| depth.item as usize),
+            hidden: all || hidden_dir_specified,
+        };
+
+        let mut paths = glob_paths(&pattern, options)
             .map_err(|e| ShellError::labeled_error(e.to_string(), "invalid pattern", &p_tag))?
             .peekable();
 
@@ -271,10 +295,9 @@ impl Shell for FilesystemShell {
         let name_tag = name;
 
         let path = Path::new(path);
-        let source = path.join(&src.item);
         let destination = path.join(&dst.item);
 
-        let sources: Vec<_> = match glob::glob(&source.to_string_lossy()) {
+        let sources: Vec<_> = match glob::glob(&src.item.pattern_in(path)) {
             Ok(files) => files.collect(),
             Err(e) => {
                 return Err(ShellError::labeled_error(
@@ -442,11 +465,10 @@ impl Shell for FilesystemShell {
         path: &str,
     ) -> Result<OutputStream, ShellError> {
         let path = Path::new(path);
-        let source = path.join(&src.item);
         let destination = path.join(&dst.item);
 
         let mut sources =
-            glob::glob(&source.to_string_lossy()).map_or_else(|_| Vec::new(), Iterator::collect);
+            glob::glob(&src.item.pattern_in(path)).map_or_else(|_| Vec::new(), Iterator::collect);
 
         if sources.is_empty() {
             return Err(ShellError::labeled_error(
@@ -536,10 +558,7 @@ impl Shell for FilesystemShell {
         let path = Path::new(path);
         let mut all_targets: HashMap<PathBuf, Tag> = HashMap::new();
         for target in targets {
-            let all_dots = target
-                .item
-                .to_str()
-                .map_or(false, |v| v.chars().all(|c| c == '.'));
+            let all_dots = target.item.as_str().chars().all(|c| c == '.');
 
             if all_dots {
                 return Err(ShellError::labeled_error(
@@ -549,8 +568,9 @@ impl Shell for FilesystemShell {
                 ));
             }
 
-            let path = path.join(&target.item);
-            match glob::glob(&path.to_string_lossy()) {
+            let pattern = target.item.pattern_in(path);
+            let path = path.join(target.item.as_str());
+            match glob::glob(&pattern) {
                 Ok(files) => {
                     for file in files {
                         match file {
@@ -1048,6 +1068,7 @@ pub(crate) fn dir_entry_dict(
                     Tag {
                         anchor: None,
                         span: Span::new(0, 2),
+                        content_type: None,
                     },
                     None,
                     false,
@@ -0,0 +1,72 @@
+use std::path::{Component, Path, PathBuf};
+
+/// How far a glob pattern is expanded
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlobOptions {
+    /// How many directories below where the pattern starts a match may be, with 1 for only the
+    /// entries of that directory
+    pub max_depth: Option<usize>,
+    /// Whether hidden files, and the files inside hidden directories, are matched
+    pub hidden: bool,
+}
+
+/// The part of the pattern before its first wildcard, which is where the matching starts
+fn literal_prefix(pattern: &str) -> PathBuf {
+    Path::new(pattern)
+        .components()
+        .take_while(|component| {
+            !component
+                .as_os_str()
+                .to_string_lossy()
+                .contains(|c| c == '*' || c == '?' || c == '[')
+        })
+        .collect()
+}
+
+fn is_hidden(component: &Component) -> bool {
+    match component {
+        Component::Normal(name) => name.to_string_lossy().starts_with('.'),
+        _ => false,
+    }
+}
+
+/// Expands a glob pattern, where `**` matches any number of directories. `ls` and `glob` both
+/// expand their patterns here, so they match the same paths.
+pub fn glob_paths(
+    pattern: &str,
+    options: GlobOptions,
+) -> Result<impl Iterator<Item = Result<PathBuf, glob::GlobError>>, glob::PatternError> {
+    let prefix = literal_prefix(pattern);
+    let paths = glob::glob(pattern)?;
+
+    Ok(paths.filter(move |path| {
+        let path = match path {
+            Ok(path) => path,
+            Err(_) => return true,
+        };
+        let below = path.strip_prefix(&prefix).unwrap_or(path);
+
+        let within_depth = match options.max_depth {
+            Some(max_depth) => below.components().count() <= max_depth,
+            None => true,
+        };
+
+        within_depth && (options.hidden || !below.components().any(|c| is_hidden(&c)))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::literal_prefix;
+    use std::path::PathBuf;
+
+    #[test]
+    fn matching_starts_before_the_first_wildcard() {
+        assert_eq!(literal_prefix("src/**/*.rs"), PathBuf::from("src"));
+        assert_eq!(literal_prefix("**/*.rs"), PathBuf::new());
+        assert_eq!(
+            literal_prefix("docs/commands/ls.md"),
+            PathBuf::from("docs/commands/ls.md")
+        );
+    }
+}
@@ -1,4 +1,6 @@
 pub(crate) mod dir_info;
+pub mod executables;
 pub mod filesystem_shell;
+pub mod glob;
 pub mod path;
 pub(crate) mod utils;
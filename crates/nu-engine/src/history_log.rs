@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// One line entered in the REPL, with how it went. The log is kept next to the history file as
+/// JSON lines and never leaves the machine.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryLogEntry {
+    pub command: String,
+    /// When the line started running, as an RFC 3339 date with the local offset
+    pub start: String,
+    pub duration_ms: u64,
+    pub success: bool,
+    pub cwd: String,
+}
+
+/// The log sits next to the history file, eg `history.jsonl` for `history.txt`
+pub fn log_path(history: &Path) -> PathBuf {
+    history.with_extension("jsonl")
+}
+
+pub fn append(path: &Path, entry: &HistoryLogEntry) -> io::Result<()> {
+    let line = serde_json::to_string(entry)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    writeln!(file, "{}", line)
+}
+
+/// Reads the log, skipping lines that can't be read (eg a line cut short by a crash)
+pub fn read(path: &Path) -> io::Result<Vec<HistoryLogEntry>> {
+    let reader = BufReader::new(File::open(path)?);
+
+    Ok(reader
+        .lines()
+        .filter_map(|line| line.ok())
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{append, log_path, read, HistoryLogEntry};
+    use std::path::Path;
+
+    #[test]
+    fn log_sits_next_to_history() {
+        assert_eq!(
+            log_path(Path::new("/home/nu/history.txt")),
+            Path::new("/home/nu/history.jsonl")
+        );
+    }
+
+    #[test]
+    fn reads_back_entries_and_skips_broken_lines() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("history.jsonl");
+        let entry = HistoryLogEntry {
+            command: "ls | length".to_string(),
+            start: "2021-03-01T09:30:00+01:00".to_string(),
+            duration_ms: 12,
+            success: true,
+            cwd: "/home/nu".to_string(),
+        };
+
+        append(&path, &entry)?;
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, b"{\"command\": \"ls"))?;
+
+        assert_eq!(read(&path)?, vec![entry]);
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use parking_lot::Mutex;
+use std::process::Child;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Running,
+    /// The exit code, which is missing when the job was ended by a signal
+    Exited(Option<i32>),
+}
+
+#[derive(Debug, Clone)]
+pub struct JobInfo {
+    pub id: usize,
+    pub pid: u32,
+    pub command: String,
+    pub status: JobStatus,
+}
+
+struct Job {
+    info: JobInfo,
+    child: Child,
+}
+
+impl Job {
+    fn refresh(&mut self) {
+        if self.info.status == JobStatus::Running {
+            if let Ok(Some(status)) = self.child.try_wait() {
+                self.info.status = JobStatus::Exited(status.code());
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct JobTable {
+    next_id: usize,
+    jobs: Vec<Job>,
+}
+
+/// The external commands running in the background. The table is kept on the context, so it
+/// lasts from one line of the shell to the next.
+#[derive(Clone, Default)]
+pub struct Jobs {
+    table: Arc<Mutex<JobTable>>,
+}
+
+impl Jobs {
+    pub fn add(&self, command: impl Into<String>, child: Child) -> JobInfo {
+        let mut table = self.table.lock();
+        table.next_id += 1;
+
+        let info = JobInfo {
+            id: table.next_id,
+            pid: child.id(),
+            command: command.into(),
+            status: JobStatus::Running,
+        };
+        table.jobs.push(Job {
+            info: info.clone(),
+            child,
+        });
+
+        info
+    }
+
+    pub fn list(&self) -> Vec<JobInfo> {
+        let mut table = self.table.lock();
+        table
+            .jobs
+            .iter_mut()
+            .map(|job| {
+                job.refresh();
+                job.info.clone()
+            })
+            .collect()
+    }
+
+    /// Takes a job out of the table without stopping it, so it keeps running after the shell
+    /// exits
+    pub fn disown(&self, id: usize) -> Option<JobInfo> {
+        let mut table = self.table.lock();
+        let index = table.jobs.iter().position(|job| job.info.id == id)?;
+        let mut job = table.jobs.remove(index);
+        job.refresh();
+
+        Some(job.info)
+    }
+
+    /// The jobs that finished since they were last looked at, which are taken out of the table
+    pub fn take_finished(&self) -> Vec<JobInfo> {
+        let mut table = self.table.lock();
+        for job in table.jobs.iter_mut() {
+            job.refresh();
+        }
+
+        let (running, finished): (Vec<_>, Vec<_>) = table
+            .jobs
+            .drain(..)
+            .partition(|job| job.info.status == JobStatus::Running);
+        table.jobs = running;
+
+        finished.into_iter().map(|job| job.info).collect()
+    }
+
+    /// Stops the jobs that are still running, for when the shell exits
+    pub fn stop_all(&self) {
+        let mut table = self.table.lock();
+        for job in table.jobs.iter_mut() {
+            job.refresh();
+            if job.info.status == JobStatus::Running {
+                let _ = job.child.kill();
+                let _ = job.child.wait();
+            }
+        }
+        table.jobs.clear();
+    }
+}
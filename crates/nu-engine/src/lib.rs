@@ -1,12 +1,16 @@
 mod call_info;
 mod command_args;
+pub mod crash_context;
 pub mod deserializer;
 pub mod documentation;
 mod env;
 mod evaluate;
 pub mod evaluation_context;
 mod example;
+pub mod exit_hooks;
 pub mod filesystem;
+pub mod history_log;
+pub mod jobs;
 mod maybe_text_codec;
 pub mod plugin;
 mod runnable_context;
@@ -18,23 +22,40 @@ pub use crate::call_info::UnevaluatedCallInfo;
 pub use crate::command_args::{
     CommandArgs, EvaluatedCommandArgs, EvaluatedWholeStreamCommandArgs, RawCommandArgs,
 };
-pub use crate::documentation::{generate_docs, get_brief_help, get_documentation, get_full_help};
+pub use crate::documentation::{
+    generate_docs, get_brief_help, get_documentation, get_documentation_value, get_full_help,
+    page_documentation,
+};
 pub use crate::env::environment::Env;
 pub use crate::env::host::FakeHost;
 pub use crate::env::host::Host;
-pub use crate::evaluate::block::run_block;
+pub use crate::evaluate::block::{run_block, run_block_to_end};
+pub use crate::evaluate::debugger::{Breakpoint, Debugger, DebuggerInput, StdinInput};
 pub use crate::evaluate::evaluator::evaluate_baseline_expr;
+pub use crate::evaluate::profiler::{estimated_size, ProfileEntry, Profiler};
 pub use crate::evaluate::scope::Scope;
+pub use crate::evaluate::variables::env as env_variable;
+pub use crate::evaluate::variables::is_path_var;
+pub use crate::evaluate::variables::nu as nu_variable;
 pub use crate::evaluation_context::EvaluationContext;
 pub use crate::example::Example;
 pub use crate::filesystem::dir_info::{DirBuilder, DirInfo, FileInfo};
+pub use crate::filesystem::executables::{is_executable, path_executables};
 pub use crate::filesystem::filesystem_shell::FilesystemShell;
+pub use crate::filesystem::glob::{glob_paths, GlobOptions};
 pub use crate::filesystem::path;
+pub use crate::jobs::{JobInfo, JobStatus, Jobs};
 pub use crate::maybe_text_codec::{MaybeTextCodec, StringOrBinary};
 pub use crate::runnable_context::RunnableContext;
+#[cfg(feature = "archive")]
+pub use crate::shell::archive_shell::ArchiveShell;
 pub use crate::shell::help_shell::{command_dict, HelpShell};
 pub use crate::shell::painter::Painter;
 pub use crate::shell::palette::{DefaultPalette, Palette};
+#[cfg(feature = "s3")]
+pub use crate::shell::s3_shell::S3Shell;
 pub use crate::shell::shell_manager::ShellManager;
+#[cfg(feature = "sqlite")]
+pub use crate::shell::sqlite_shell::SqliteShell;
 pub use crate::shell::value_shell::ValueShell;
 pub use crate::whole_stream_command::{whole_stream_command, Command, WholeStreamCommand};
@@ -17,6 +17,8 @@ pub enum StringOrBinary {
 
 pub struct MaybeTextCodec {
     decoder: Decoder,
+    // Once the data turns out not to be text, the rest of it is passed on as binary too
+    binary: bool,
 }
 
 impl MaybeTextCodec {
@@ -26,7 +28,10 @@ impl MaybeTextCodec {
             Some(e) => e.new_decoder_with_bom_removal(),
             None => UTF_8.new_decoder(),
         };
-        MaybeTextCodec { decoder }
+        MaybeTextCodec {
+            decoder,
+            binary: false,
+        }
     }
 }
 
@@ -34,6 +39,7 @@ impl Default for MaybeTextCodec {
     fn default() -> Self {
         MaybeTextCodec {
             decoder: UTF_8.new_decoder(),
+            binary: false,
         }
     }
 }
@@ -67,12 +73,20 @@ impl futures_codec::Decoder for MaybeTextCodec {
             return Ok(None);
         }
 
+        if self.binary {
+            let result = StringOrBinary::Binary(src.to_vec());
+            src.clear();
+
+            return Ok(Some(result));
+        }
+
         let mut s = String::with_capacity(OUTPUT_BUFFER_SIZE);
 
         let (res, _read, replacements) = self.decoder.decode_to_string(src, &mut s, false);
 
         let result = if replacements {
             // If we had to make replacements when converting to utf8, fall back to binary
+            self.binary = true;
             StringOrBinary::Binary(src.to_vec())
         } else {
             // If original buffer size is too small, we continue to allocate new Strings and append
@@ -123,4 +137,22 @@ mod tests {
         );
         assert!(bytes.is_empty());
     }
+
+    #[test]
+    fn stays_binary_once_the_data_is_not_text() {
+        let mut maybe_text = MaybeTextCodec::new(None);
+
+        let mut bytes = BytesMut::from(&[0xff, 0xfe, 0x00][..]);
+        assert_eq!(
+            maybe_text.decode(&mut bytes),
+            Ok(Some(StringOrBinary::Binary(vec![0xff, 0xfe, 0x00])))
+        );
+
+        let mut bytes = BytesMut::from("text");
+        assert_eq!(
+            maybe_text.decode(&mut bytes),
+            Ok(Some(StringOrBinary::Binary(b"text".to_vec())))
+        );
+        assert!(bytes.is_empty());
+    }
 }
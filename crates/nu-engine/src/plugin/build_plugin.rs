@@ -1,59 +1,39 @@
 use crate::plugin::run_plugin::PluginCommandBuilder;
+use crate::plugin::session::PluginSession;
 use log::trace;
 use nu_errors::ShellError;
 use nu_plugin::jsonrpc::JsonRpc;
+use nu_plugin::PluginAddress;
 use nu_protocol::{Signature, Value};
-use std::io::{BufRead, BufReader, Write};
-use std::process::{Child, Command, Stdio};
 
 use rayon::prelude::*;
 
 pub fn build_plugin_command(
     path: &std::path::Path,
 ) -> Result<Option<PluginCommandBuilder>, ShellError> {
-    let ext = path.extension();
-    let ps1_file = match ext {
-        Some(ext) => ext == "ps1",
-        None => false,
-    };
+    let path = dunce::canonicalize(path)?;
 
-    let mut child: Child = if ps1_file {
-        Command::new("pwsh")
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .args(&[
-                "-NoLogo",
-                "-NoProfile",
-                "-ExecutionPolicy",
-                "Bypass",
-                "-File",
-                &path.to_string_lossy(),
-            ])
-            .spawn()
-            .expect("Failed to spawn PowerShell process")
-    } else {
-        Command::new(path)
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .spawn()
-            .expect("Failed to spawn child process")
-    };
+    plugin_config(&path.to_string_lossy())
+}
 
-    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-    let stdout = child.stdout.as_mut().expect("Failed to open stdout");
+/// Asks the plugin served at the given address for its signature, like `build_plugin_command`
+/// does with a plugin binary
+pub fn build_remote_plugin_command(
+    address: &str,
+) -> Result<Option<PluginCommandBuilder>, ShellError> {
+    plugin_config(&PluginAddress::parse(address)?.to_string())
+}
 
-    let mut reader = BufReader::new(stdout);
+fn plugin_config(registration: &str) -> Result<Option<PluginCommandBuilder>, ShellError> {
+    let mut session = PluginSession::start(registration, true)?;
 
-    let request = JsonRpc::new("config", Vec::<Value>::new());
-    let request_raw = serde_json::to_string(&request)?;
-    trace!(target: "nu::load", "plugin infrastructure config -> path {:#?}, request {:?}", &path, &request_raw);
-    stdin.write_all(format!("{}\n", request_raw).as_bytes())?;
-    let path = dunce::canonicalize(path)?;
+    trace!(target: "nu::load", "plugin infrastructure config -> {:#?}", registration);
+    session.send("config", Vec::<Value>::new())?;
 
     let mut input = String::new();
-    let result = match reader.read_line(&mut input) {
+    let result = match session.read_line(&mut input) {
         Ok(count) => {
-            trace!(target: "nu::load", "plugin infrastructure -> config response for {:#?}", &path);
+            trace!(target: "nu::load", "plugin infrastructure -> config response for {:#?}", registration);
             trace!(target: "nu::load", "plugin infrastructure -> processing response ({} bytes)", count);
             trace!(target: "nu::load", "plugin infrastructure -> response: {}", input);
 
@@ -61,15 +41,11 @@ pub fn build_plugin_command(
             match response {
                 Ok(jrpc) => match jrpc.params {
                     Ok(params) => {
-                        let fname = path.to_string_lossy();
-
                         trace!(target: "nu::load", "plugin infrastructure -> processing {:?}", params);
 
                         let name = params.name.clone();
 
-                        let fname = fname.to_string();
-
-                        Ok(Some(PluginCommandBuilder::new(&name, &fname, params)))
+                        Ok(Some(PluginCommandBuilder::new(&name, registration, params)))
                     }
                     Err(e) => Err(e),
                 },
@@ -88,15 +64,60 @@ pub fn build_plugin_command(
         ))),
     };
 
-    let _ = child.wait();
+    session.finish();
 
     result
 }
 
-pub fn scan(
+/// A plugin left out because a plugin that takes precedence over it provides a command of the
+/// same name
+#[derive(Debug, Clone)]
+pub struct ShadowedPlugin {
+    pub name: String,
+    pub version: Option<String>,
+    pub path: String,
+    /// What provides the command instead
+    pub used: String,
+}
+
+pub struct PluginScan {
+    /// The commands to register, one for each name
+    pub commands: Vec<crate::whole_stream_command::Command>,
+    pub shadowed: Vec<ShadowedPlugin>,
+    /// Why the remote plugins that couldn't be reached weren't loaded
+    pub errors: Vec<ShellError>,
+}
+
+/// Finds the plugins in the given directories. When several of them provide the same command,
+/// the one in the earliest directory is used, and within a directory the first by file name.
+pub fn scan(paths: Vec<std::path::PathBuf>) -> Result<PluginScan, ShellError> {
+    scan_with_remotes(&[], paths)
+}
+
+/// Like `scan`, with the plugins served at the given addresses (eg `tcp://localhost:4000`) taking
+/// precedence over the ones in the directories
+pub fn scan_with_remotes(
+    addresses: &[String],
     paths: Vec<std::path::PathBuf>,
-) -> Result<Vec<crate::whole_stream_command::Command>, ShellError> {
-    let mut plugins = vec![];
+) -> Result<PluginScan, ShellError> {
+    let mut plugins: Vec<PluginCommandBuilder> = vec![];
+    let mut shadowed = vec![];
+    let mut errors = vec![];
+
+    let remotes: Vec<_> = addresses
+        .par_iter()
+        .map(|address| build_remote_plugin_command(address))
+        .collect();
+
+    let mut found = vec![];
+    for remote in remotes {
+        match remote {
+            Ok(Some(plugin)) => found.push(plugin),
+            Ok(None) => {}
+            Err(err) => errors.push(err),
+        }
+    }
+    add_found(&mut plugins, &mut shadowed, found);
 
     let opts = glob::MatchOptions {
         case_sensitive: false,
@@ -162,10 +183,103 @@ pub fn scan(
                 } else {
                     None
                 }
-            }).map(|p| p.build())
-            .collect::<Vec<crate::whole_stream_command::Command>>();
-        plugins.extend(plugs);
+            })
+            .collect::<Vec<PluginCommandBuilder>>();
+
+        add_found(&mut plugins, &mut shadowed, plugs);
     }
 
-    Ok(plugins)
+    Ok(PluginScan {
+        commands: plugins.iter().map(|plugin| plugin.build()).collect(),
+        shadowed,
+        errors,
+    })
+}
+
+/// Adds the plugins found in one place to those to use, leaving out the ones for commands that
+/// are already provided
+fn add_found(
+    plugins: &mut Vec<PluginCommandBuilder>,
+    shadowed: &mut Vec<ShadowedPlugin>,
+    found: Vec<PluginCommandBuilder>,
+) {
+    for plugin in found {
+        match plugins.iter().find(|used| used.name() == plugin.name()) {
+            // The same directory can be on the search path more than once
+            Some(used) if used.path() == plugin.path() => {}
+            Some(used) => shadowed.push(ShadowedPlugin {
+                name: plugin.name().to_string(),
+                version: plugin.version().map(String::from),
+                path: plugin.path().to_string(),
+                used: used.path().to_string(),
+            }),
+            None => plugins.push(plugin),
+        }
+    }
+}
+
+/// A table of the plugins that were left out, to warn about at startup
+pub fn shadowed_plugins_report(shadowed: &[ShadowedPlugin]) -> String {
+    let mut rows = vec![[
+        "command".to_string(),
+        "version".to_string(),
+        "ignored".to_string(),
+        "used instead".to_string(),
+    ]];
+    rows.extend(shadowed.iter().map(|plugin| {
+        [
+            plugin.name.clone(),
+            plugin.version.clone().unwrap_or_else(|| "-".to_string()),
+            plugin.path.clone(),
+            plugin.used.clone(),
+        ]
+    }));
+
+    let widths: Vec<usize> = (0..4)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut report =
+        "warning: more than one plugin provides these commands, so only one of each is used\n"
+            .to_string();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        report.push_str("  ");
+        report.push_str(line.trim_end());
+        report.push('\n');
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shadowed_plugins_report, ShadowedPlugin};
+
+    #[test]
+    fn reports_the_shadowed_plugins_as_a_table() {
+        let report = shadowed_plugins_report(&[ShadowedPlugin {
+            name: "inc".to_string(),
+            version: Some("0.27.0".to_string()),
+            path: "/usr/bin/nu_plugin_inc".to_string(),
+            used: "/home/nu/plugins/nu_plugin_inc".to_string(),
+        }]);
+
+        assert_eq!(
+            report,
+            "warning: more than one plugin provides these commands, so only one of each is used\n\
+             \x20 command  version  ignored                 used instead\n\
+             \x20 inc      0.27.0   /usr/bin/nu_plugin_inc  /home/nu/plugins/nu_plugin_inc\n"
+        );
+    }
 }
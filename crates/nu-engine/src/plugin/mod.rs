@@ -1,2 +1,3 @@
 pub mod build_plugin;
 pub(crate) mod run_plugin;
+pub(crate) mod session;
@@ -1,20 +1,21 @@
 use crate::command_args::CommandArgs;
+use crate::plugin::session::{plugin_command, PluginSession};
 use crate::whole_stream_command::{whole_stream_command, WholeStreamCommand};
 use async_trait::async_trait;
 use derive_new::new;
-use futures::StreamExt;
+use futures::channel::{mpsc, oneshot};
+use futures::executor::block_on_stream;
+use futures::{SinkExt, StreamExt};
 use log::trace;
 use nu_errors::ShellError;
 use nu_plugin::jsonrpc::JsonRpc;
-use nu_protocol::{Primitive, ReturnValue, Signature, UntaggedValue, Value};
+use nu_plugin::PluginAddress;
+use nu_protocol::{CallInfo, Primitive, ReturnValue, Signature, UntaggedValue, Value};
 use nu_stream::{OutputStream, ToOutputStream};
 use serde::{self, Deserialize, Serialize};
 use std::collections::VecDeque;
-use std::io::prelude::*;
-use std::io::BufReader;
 use std::io::Write;
-use std::path::Path;
-use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "method")]
@@ -71,6 +72,18 @@ impl PluginCommandBuilder {
         }
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.config.version.as_deref()
+    }
+
     pub fn build(&self) -> crate::whole_stream_command::Command {
         let mode = &self.mode;
 
@@ -111,6 +124,10 @@ impl WholeStreamCommand for PluginFilter {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         run_filter(self.path.clone(), args).await
     }
+
+    fn plugin_path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
 }
 
 async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -125,34 +142,7 @@ async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, She
 
     let args = args.evaluate_once().await?;
 
-    let real_path = Path::new(&path);
-    let ext = real_path.extension();
-    let ps1_file = match ext {
-        Some(ext) => ext == "ps1",
-        None => false,
-    };
-
-    let mut child: Child = if ps1_file {
-        Command::new("pwsh")
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .args(&[
-                "-NoLogo",
-                "-NoProfile",
-                "-ExecutionPolicy",
-                "Bypass",
-                "-File",
-                &real_path.to_string_lossy(),
-            ])
-            .spawn()
-            .expect("Failed to spawn PowerShell process")
-    } else {
-        Command::new(path)
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .spawn()
-            .expect("Failed to spawn child process")
-    };
+    let mut session = PluginSession::start(&path, true)?;
 
     let call_info = args.call_info.clone();
 
@@ -168,37 +158,13 @@ async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, She
                     ..
                 } => {
                     // Beginning of the stream
-                    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-                    let stdout = child.stdout.as_mut().expect("Failed to open stdout");
-
-                    let mut reader = BufReader::new(stdout);
-
-                    let request = JsonRpc::new("begin_filter", call_info.clone());
-                    let request_raw = serde_json::to_string(&request);
-                    trace!("begin_filter:request {:?}", &request_raw);
-
-                    match request_raw {
-                        Err(_) => {
-                            return OutputStream::one(Err(ShellError::labeled_error(
-                                "Could not load json from plugin",
-                                "could not load json from plugin",
-                                &call_info.name_tag,
-                            )));
-                        }
-                        Ok(request_raw) => {
-                            match stdin.write(format!("{}\n", request_raw).as_bytes()) {
-                                Ok(_) => {}
-                                Err(err) => {
-                                    return OutputStream::one(Err(ShellError::unexpected(
-                                        format!("{}", err),
-                                    )));
-                                }
-                            }
-                        }
+                    trace!("begin_filter:request {:?}", &call_info);
+                    if let Err(err) = session.send("begin_filter", call_info.clone()) {
+                        return OutputStream::one(Err(err));
                     }
 
                     let mut input = String::new();
-                    match reader.read_line(&mut input) {
+                    match session.read_line(&mut input) {
                         Ok(_) => {
                             let response = serde_json::from_str::<NuResult>(&input);
                             trace!("begin_filter:response {:?}", &response);
@@ -228,37 +194,13 @@ async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, She
                     ..
                 } => {
                     // post stream contents
-                    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-                    let stdout = child.stdout.as_mut().expect("Failed to open stdout");
-
-                    let mut reader = BufReader::new(stdout);
-
-                    let request: JsonRpc<std::vec::Vec<Value>> = JsonRpc::new("end_filter", vec![]);
-                    let request_raw = serde_json::to_string(&request);
-                    trace!("end_filter:request {:?}", &request_raw);
-
-                    match request_raw {
-                        Err(_) => {
-                            return OutputStream::one(Err(ShellError::labeled_error(
-                                "Could not load json from plugin",
-                                "could not load json from plugin",
-                                &call_info.name_tag,
-                            )));
-                        }
-                        Ok(request_raw) => {
-                            match stdin.write(format!("{}\n", request_raw).as_bytes()) {
-                                Ok(_) => {}
-                                Err(err) => {
-                                    return OutputStream::one(Err(ShellError::unexpected(
-                                        format!("{}", err),
-                                    )));
-                                }
-                            }
-                        }
+                    trace!("end_filter:request");
+                    if let Err(err) = session.send("end_filter", Vec::<Value>::new()) {
+                        return OutputStream::one(Err(err));
                     }
 
                     let mut input = String::new();
-                    let stream = match reader.read_line(&mut input) {
+                    let stream = match session.read_line(&mut input) {
                         Ok(_) => {
                             let response = serde_json::from_str::<NuResult>(&input);
                             trace!("end_filter:response {:?}", &response);
@@ -286,53 +228,23 @@ async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, She
                         }
                     };
 
-                    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-
-                    let request: JsonRpc<std::vec::Vec<Value>> = JsonRpc::new("quit", vec![]);
-                    let request_raw = serde_json::to_string(&request);
-                    trace!("quit:request {:?}", &request_raw);
-
-                    match request_raw {
-                        Ok(request_raw) => {
-                            let _ = stdin.write(format!("{}\n", request_raw).as_bytes());
-                            // TODO: Handle error
-                        }
-                        Err(e) => {
-                            return OutputStream::one(Err(ShellError::untagged_runtime_error(
-                                format!("Error while processing quit response: {:?}", e),
-                            )));
-                        }
-                    }
-                    let _ = child.wait();
+                    trace!("quit:request");
+                    // The plugin may have already stopped after end_filter
+                    let _ = session.send("quit", Vec::<Value>::new());
+                    session.finish();
 
                     stream
                 }
 
                 v => {
                     // Stream contents
-                    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-                    let stdout = child.stdout.as_mut().expect("Failed to open stdout");
-
-                    let mut reader = BufReader::new(stdout);
-
-                    let request = JsonRpc::new("filter", v);
-                    let request_raw = serde_json::to_string(&request);
-                    trace!("filter:request {:?}", &request_raw);
-
-                    match request_raw {
-                        Ok(request_raw) => {
-                            let _ = stdin.write(format!("{}\n", request_raw).as_bytes());
-                            // TODO: Handle error
-                        }
-                        Err(e) => {
-                            return OutputStream::one(Err(ShellError::untagged_runtime_error(
-                                format!("Error while processing filter response: {:?}", e),
-                            )));
-                        }
+                    trace!("filter:request {:?}", &v);
+                    if let Err(err) = session.send("filter", v) {
+                        return OutputStream::one(Err(err));
                     }
 
                     let mut input = String::new();
-                    match reader.read_line(&mut input) {
+                    match session.read_line(&mut input) {
                         Ok(_) => {
                             let response = serde_json::from_str::<NuResult>(&input);
                             trace!("filter:response {:?}", &response);
@@ -384,7 +296,15 @@ impl WholeStreamCommand for PluginSink {
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        run_sink(self.path.clone(), args).await
+        if self.config.is_streaming_sink {
+            run_streaming_sink(self.path.clone(), args).await
+        } else {
+            run_sink(self.path.clone(), args).await
+        }
+    }
+
+    fn plugin_path(&self) -> Option<&str> {
+        Some(&self.path)
     }
 }
 
@@ -394,6 +314,15 @@ async fn run_sink(path: String, args: CommandArgs) -> Result<OutputStream, Shell
 
     let input: Vec<Value> = args.input.collect().await;
 
+    // A remote plugin gets the request over its connection rather than in a file
+    if PluginAddress::is_remote(&path) {
+        let mut session = PluginSession::start(&path, false)?;
+        session.send("sink", (call_info, input))?;
+        session.finish();
+
+        return Ok(OutputStream::empty());
+    }
+
     let request = JsonRpc::new("sink", (call_info.clone(), input));
     let request_raw = serde_json::to_string(&request);
     if let Ok(request_raw) = request_raw {
@@ -401,33 +330,9 @@ async fn run_sink(path: String, args: CommandArgs) -> Result<OutputStream, Shell
             let _ = writeln!(tmpfile, "{}", request_raw);
             let _ = tmpfile.flush();
 
-            let real_path = Path::new(&path);
-            let ext = real_path.extension();
-            let ps1_file = match ext {
-                Some(ext) => ext == "ps1",
-                None => false,
-            };
-
             // TODO: This sink may not work in powershell, trying to find
             // an example of what CallInfo would look like in this temp file
-            let child = if ps1_file {
-                Command::new("pwsh")
-                    .args(&[
-                        "-NoLogo",
-                        "-NoProfile",
-                        "-ExecutionPolicy",
-                        "Bypass",
-                        "-File",
-                        &real_path.to_string_lossy(),
-                        &tmpfile
-                            .path()
-                            .to_str()
-                            .expect("Failed getting tmpfile path"),
-                    ])
-                    .spawn()
-            } else {
-                Command::new(path).arg(&tmpfile.path()).spawn()
-            };
+            let child = plugin_command(&path).arg(&tmpfile.path()).spawn();
 
             if let Ok(mut child) = child {
                 let _ = child.wait();
@@ -449,3 +354,68 @@ async fn run_sink(path: String, args: CommandArgs) -> Result<OutputStream, Shell
         ))
     }
 }
+
+/// How many values can wait for the plugin to take them before the pipeline waits on it
+const SINK_BUFFER: usize = 32;
+
+/// Runs a sink that takes its input a value at a time over its stdin, so the values are passed on
+/// as they arrive instead of being collected first. The plugin is talked to from a thread of its
+/// own, as writing to it and waiting for it to exit block.
+async fn run_streaming_sink(path: String, args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let ctrl_c = args.ctrl_c.clone();
+    let args = args.evaluate_once().await?;
+    let call_info = args.call_info.clone();
+    let mut input = args.input;
+
+    let (mut values, receiver) = mpsc::channel(SINK_BUFFER);
+    let (done, finished) = oneshot::channel();
+    let interrupted = ctrl_c.clone();
+    std::thread::spawn(move || {
+        let _ = done.send(feed_sink(&path, call_info, receiver, &interrupted));
+    });
+
+    // A plugin that stops early stops taking values, and the rest of the input isn't read
+    while let Some(value) = input.next().await {
+        if ctrl_c.load(Ordering::SeqCst) || values.send(value).await.is_err() {
+            break;
+        }
+    }
+    drop(values);
+
+    match finished.await {
+        Ok(result) => result.map(|_| OutputStream::empty()),
+        Err(_) => Err(ShellError::unexpected("the sink stopped without finishing")),
+    }
+}
+
+fn feed_sink(
+    path: &str,
+    call_info: CallInfo,
+    values: mpsc::Receiver<Value>,
+    ctrl_c: &AtomicBool,
+) -> Result<(), ShellError> {
+    let mut session = PluginSession::start(path, false).map_err(|_| {
+        ShellError::labeled_error(
+            "Could not create process for sink command",
+            "could not start the plugin",
+            &call_info.name_tag,
+        )
+    })?;
+
+    if session.send("begin_sink", call_info).is_ok() {
+        for value in block_on_stream(values) {
+            if session.send("sink_value", value).is_err() {
+                break;
+            }
+        }
+
+        // An interrupted sink isn't told the input is done, it only sees its stdin close
+        if !ctrl_c.load(Ordering::SeqCst) {
+            let _ = session.send("end_sink", Vec::<Value>::new());
+        }
+    }
+
+    session.finish();
+
+    Ok(())
+}
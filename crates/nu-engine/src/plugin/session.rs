@@ -0,0 +1,116 @@
+use nu_errors::ShellError;
+use nu_plugin::jsonrpc::JsonRpc;
+use nu_plugin::PluginAddress;
+use serde::Serialize;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+
+/// One run of a plugin, which is either a process started for it or a connection to the service
+/// the plugin is registered at
+pub(crate) struct PluginSession {
+    reader: BufReader<Box<dyn Read + Send>>,
+    writer: Option<Box<dyn Write + Send>>,
+    child: Option<Child>,
+}
+
+impl PluginSession {
+    /// Starts a run of the plugin registered with the given path or address. A process only gets
+    /// its stdout piped when `read_responses` is set, so a sink can write to the terminal.
+    pub fn start(registration: &str, read_responses: bool) -> Result<PluginSession, ShellError> {
+        if PluginAddress::is_remote(registration) {
+            let (reader, writer) =
+                PluginAddress::parse(registration)?
+                    .connect()
+                    .map_err(|err| {
+                        ShellError::untagged_runtime_error(format!(
+                            "Could not connect to the plugin at {}: {}",
+                            registration, err
+                        ))
+                    })?;
+
+            return Ok(PluginSession {
+                reader: BufReader::new(reader),
+                writer: Some(writer),
+                child: None,
+            });
+        }
+
+        let mut command = plugin_command(registration);
+        command.stdin(Stdio::piped());
+        if read_responses {
+            command.stdout(Stdio::piped());
+        }
+
+        let mut child = command.spawn().map_err(|err| {
+            ShellError::untagged_runtime_error(format!(
+                "Could not start the plugin {}: {}",
+                registration, err
+            ))
+        })?;
+
+        let writer: Box<dyn Write + Send> = Box::new(child.stdin.take().expect("stdin is piped"));
+        let reader: Box<dyn Read + Send> = match child.stdout.take() {
+            Some(stdout) => Box::new(stdout),
+            None => Box::new(io::empty()),
+        };
+
+        Ok(PluginSession {
+            reader: BufReader::new(reader),
+            writer: Some(writer),
+            child: Some(child),
+        })
+    }
+
+    /// Sends a request to the plugin, which fails once the plugin has stopped taking them
+    pub fn send<T: Serialize>(&mut self, method: &str, params: T) -> Result<(), ShellError> {
+        let request = serde_json::to_string(&JsonRpc::new(method, params))?;
+
+        let writer = match &mut self.writer {
+            Some(writer) => writer,
+            None => return Err(ShellError::unexpected("the plugin session has finished")),
+        };
+
+        writeln!(writer, "{}", request)
+            .and_then(|_| writer.flush())
+            .map_err(|err| ShellError::unexpected(format!("{}", err)))
+    }
+
+    pub fn read_line(&mut self, line: &mut String) -> io::Result<usize> {
+        self.reader.read_line(line)
+    }
+
+    /// Stops sending requests and waits for a process to exit
+    pub fn finish(&mut self) {
+        self.writer = None;
+
+        if let Some(mut child) = self.child.take() {
+            let _ = child.wait();
+        }
+    }
+}
+
+/// The command that runs a plugin binary, through PowerShell for a script of it
+pub(crate) fn plugin_command(path: &str) -> Command {
+    let real_path = Path::new(path);
+    let ps1_file = match real_path.extension() {
+        Some(ext) => ext == "ps1",
+        None => false,
+    };
+
+    if ps1_file {
+        let mut command = Command::new("pwsh");
+        command
+            .args(&[
+                "-NoLogo",
+                "-NoProfile",
+                "-ExecutionPolicy",
+                "Bypass",
+                "-File",
+            ])
+            .arg(real_path);
+        command
+    } else {
+        Command::new(path)
+    }
+}
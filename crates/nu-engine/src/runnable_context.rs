@@ -1,4 +1,4 @@
-use crate::{Command, Host, Scope, ShellManager};
+use crate::{Command, Host, Jobs, Scope, ShellManager};
 use nu_errors::ShellError;
 use nu_source::Tag;
 use nu_stream::InputStream;
@@ -11,6 +11,7 @@ pub struct RunnableContext {
     pub host: Arc<Mutex<Box<dyn Host>>>,
     pub ctrl_c: Arc<AtomicBool>,
     pub current_errors: Arc<Mutex<Vec<ShellError>>>,
+    pub jobs: Jobs,
     pub scope: Scope,
     pub name: Tag,
 }
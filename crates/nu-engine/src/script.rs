@@ -1,6 +1,9 @@
+use crate::documentation::get_full_help;
 use crate::path::canonicalize;
 use crate::run_block;
+use crate::{whole_stream_command, CommandArgs, WholeStreamCommand};
 use crate::{MaybeTextCodec, StringOrBinary};
+use async_trait::async_trait;
 use futures::StreamExt;
 use futures_codec::FramedRead;
 use nu_errors::ShellError;
@@ -8,8 +11,8 @@ use nu_protocol::hir::{
     Call, ClassifiedCommand, Expression, InternalCommand, Literal, NamedArguments,
     SpannedExpression,
 };
-use nu_protocol::{Primitive, ReturnSuccess, UntaggedValue, Value};
-use nu_stream::{InputStream, ToInputStream};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_stream::{InputStream, OutputStream, ToInputStream};
 
 use crate::{evaluation_context, EvaluationContext};
 use log::{debug, trace};
@@ -50,6 +53,7 @@ pub async fn process_script(
         let line = chomp_newline(script_text);
 
         let (block, err) = nu_parser::parse(&line, span_offset, &ctx.scope);
+        crate::crash_context::set_source(line, span_offset);
 
         debug!("{:#?}", block);
         //println!("{:#?}", pipeline);
@@ -263,3 +267,121 @@ pub async fn run_script_standalone(
     }
     Ok(())
 }
+
+/// Whether the script defines `main`, or subcommands of it like `main build`
+pub fn defines_main(context: &EvaluationContext) -> bool {
+    context
+        .scope
+        .get_command_names()
+        .iter()
+        .any(|name| name == "main" || name.starts_with("main "))
+}
+
+/// Runs the `main` command a script defines with the arguments the script was run with, so they
+/// are parsed against its signature like the arguments of any other command, `--help` included.
+/// The first argument can name a subcommand, `def "main build"` for `build`, which is found the
+/// way the subcommands of internal commands are.
+pub async fn run_script_main(
+    args: &[String],
+    context: &EvaluationContext,
+    exit_on_error: bool,
+) -> Result<(), Box<dyn Error>> {
+    // A script with only subcommands lists them when it's run without one, like `str` does
+    if !context.scope.has_command("main") {
+        context
+            .scope
+            .add_command("main".to_string(), whole_stream_command(Subcommands));
+    }
+
+    let line = std::iter::once("main".to_string())
+        .chain(args.iter().map(|arg| quote_argument(arg)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    run_script_standalone(line, false, context, exit_on_error).await
+}
+
+/// Quotes an argument the shell has already split out, if it would otherwise be split again or
+/// read as something other than a word. Words are left as they are, so `3` can still be an int.
+/// The quotes are ones that take the argument as written, so `C:\new` keeps its backslash.
+fn quote_argument(arg: &str) -> String {
+    let is_word = !arg.is_empty()
+        && !arg
+            .chars()
+            .any(|c| c.is_whitespace() || "|;()[]{}$#'\"`".contains(c));
+
+    if is_word {
+        arg.to_string()
+    } else if !arg.contains('\'') {
+        format!("'{}'", arg)
+    } else {
+        // A raw string needs more #s than follow any quote inside it
+        let hashes = (1..)
+            .map(|count| "#".repeat(count))
+            .find(|hashes| !arg.contains(&format!("'{}", hashes)))
+            .unwrap_or_default();
+
+        format!("r{}'{}'{}", hashes, arg, hashes)
+    }
+}
+
+/// The `main` of a script that only defines subcommands of it
+struct Subcommands;
+
+#[async_trait]
+impl WholeStreamCommand for Subcommands {
+    fn name(&self) -> &str {
+        "main"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("main").rest(SyntaxShape::Any, "the subcommand and its arguments")
+    }
+
+    fn usage(&self) -> &str {
+        "Runs one of the subcommands of the script."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let scope = args.scope.clone();
+        let args = args.evaluate_once().await?;
+
+        match args.nth(0) {
+            Some(subcommand) => {
+                let mut subcommands = scope
+                    .get_command_names()
+                    .into_iter()
+                    .filter_map(|name| name.strip_prefix("main ").map(String::from))
+                    .collect::<Vec<_>>();
+                subcommands.sort();
+
+                Err(ShellError::labeled_error(
+                    format!("Unknown subcommand {}", subcommand.convert_to_string()),
+                    format!("expected one of: {}", subcommands.join(", ")),
+                    &subcommand.tag,
+                ))
+            }
+            None => Ok(OutputStream::one(ReturnSuccess::value(
+                UntaggedValue::string(get_full_help(&Subcommands, &scope))
+                    .into_value(Tag::unknown()),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::quote_argument;
+
+    #[test]
+    fn quotes_only_what_needs_it() {
+        assert_eq!(quote_argument("--count"), "--count");
+        assert_eq!(quote_argument("3"), "3");
+        assert_eq!(quote_argument("two words"), "'two words'");
+        assert_eq!(quote_argument("say \"hi\""), "'say \"hi\"'");
+        assert_eq!(quote_argument(r"C:\new dir\temp"), r"'C:\new dir\temp'");
+        assert_eq!(quote_argument("it's"), "r#'it's'#");
+        assert_eq!(quote_argument("it'#s"), "r##'it'#s'##");
+        assert_eq!(quote_argument(""), "''");
+    }
+}
@@ -0,0 +1,565 @@
+use crate::command_args::EvaluatedWholeStreamCommandArgs;
+use crate::maybe_text_codec::{MaybeTextCodec, StringOrBinary};
+use crate::shell::shell_args::{CdArgs, CopyArgs, LsArgs, MkdirArgs, MvArgs, RemoveArgs};
+use crate::shell::{normalize, Shell};
+use encoding_rs::Encoding;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use nu_errors::ShellError;
+use nu_protocol::{NuGlob, ReturnSuccess, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{Span, Tag, Tagged};
+use nu_stream::OutputStream;
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveKind {
+    fn of(path: &Path) -> Option<ArchiveKind> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+
+        if name.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveKind::Tar)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveKind::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ArchiveEntry {
+    /// The name of the entry as it's stored in the archive
+    name: String,
+    is_dir: bool,
+    size: u64,
+    modified: Option<SystemTime>,
+}
+
+/// A read-only shell inside a zip or tar archive. The entries are indexed when the shell is
+/// entered, and files are read out of the archive when they're opened or copied.
+#[derive(Clone)]
+pub struct ArchiveShell {
+    pub(crate) path: String,
+    pub(crate) last_path: String,
+    archive: PathBuf,
+    kind: ArchiveKind,
+    entries: BTreeMap<PathBuf, ArchiveEntry>,
+}
+
+impl std::fmt::Debug for ArchiveShell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ArchiveShell {} @ {}", self.archive.display(), self.path)
+    }
+}
+
+fn read_error(archive: &Path, error: impl std::fmt::Display) -> ShellError {
+    ShellError::untagged_runtime_error(format!(
+        "Could not read the archive {}: {}",
+        archive.display(),
+        error
+    ))
+}
+
+fn zip_modified(modified: zip::DateTime) -> Option<SystemTime> {
+    use chrono::TimeZone;
+
+    let naive = chrono::NaiveDate::from_ymd_opt(
+        modified.year() as i32,
+        modified.month() as u32,
+        modified.day() as u32,
+    )?
+    .and_hms_opt(
+        modified.hour() as u32,
+        modified.minute() as u32,
+        modified.second() as u32,
+    )?;
+
+    // Zip archives keep the local time the file was added at
+    Some(chrono::Local.from_local_datetime(&naive).single()?.into())
+}
+
+impl ArchiveShell {
+    /// Whether the file looks like an archive that can be entered, going by its extension
+    pub fn is_archive(path: &Path) -> bool {
+        ArchiveKind::of(path).is_some()
+    }
+
+    pub fn new(archive: impl Into<PathBuf>) -> Result<ArchiveShell, ShellError> {
+        let archive = archive.into();
+        let kind = ArchiveKind::of(&archive).ok_or_else(|| {
+            ShellError::untagged_runtime_error(format!(
+                "{} is not a zip or tar archive",
+                archive.display()
+            ))
+        })?;
+
+        let mut shell = ArchiveShell {
+            path: "/".to_string(),
+            last_path: "/".to_string(),
+            archive,
+            kind,
+            entries: BTreeMap::new(),
+        };
+        shell.index().map_err(|e| read_error(&shell.archive, e))?;
+
+        Ok(shell)
+    }
+
+    fn index(&mut self) -> std::io::Result<()> {
+        let root = Path::new("/");
+        let mut entries = vec![];
+
+        match self.kind {
+            ArchiveKind::Zip => {
+                let mut archive = zip::ZipArchive::new(File::open(&self.archive)?)
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+                for index in 0..archive.len() {
+                    let file = archive
+                        .by_index(index)
+                        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+                    entries.push((
+                        normalize(root, Path::new(file.name())),
+                        ArchiveEntry {
+                            name: file.name().to_string(),
+                            is_dir: file.is_dir(),
+                            size: file.size(),
+                            modified: zip_modified(file.last_modified()),
+                        },
+                    ));
+                }
+            }
+            ArchiveKind::Tar | ArchiveKind::TarGz => {
+                let mut archive = tar::Archive::new(self.reader()?);
+
+                for entry in archive.entries()? {
+                    let entry = entry?;
+                    let header = entry.header();
+                    let path = entry.path()?;
+
+                    entries.push((
+                        normalize(root, &path),
+                        ArchiveEntry {
+                            name: path.to_string_lossy().to_string(),
+                            is_dir: header.entry_type().is_dir(),
+                            size: header.size()?,
+                            modified: header
+                                .mtime()
+                                .ok()
+                                .map(|mtime| UNIX_EPOCH + Duration::from_secs(mtime)),
+                        },
+                    ));
+                }
+            }
+        }
+
+        for (path, entry) in entries {
+            // Archives don't always have entries for the directories their files are in
+            for parent in path.ancestors().skip(1) {
+                self.entries
+                    .entry(parent.to_path_buf())
+                    .or_insert_with(|| ArchiveEntry {
+                        name: parent.to_string_lossy().to_string(),
+                        is_dir: true,
+                        size: 0,
+                        modified: None,
+                    });
+            }
+
+            self.entries.insert(path, entry);
+        }
+
+        Ok(())
+    }
+
+    fn reader(&self) -> std::io::Result<Box<dyn Read>> {
+        let file = File::open(&self.archive)?;
+
+        Ok(match self.kind {
+            ArchiveKind::TarGz => Box::new(flate2::read::GzDecoder::new(file)),
+            _ => Box::new(file),
+        })
+    }
+
+    /// Reads the files at `paths` out of the archive, handing each to `read` in the order they're
+    /// found in
+    fn extract(
+        &self,
+        paths: &[PathBuf],
+        mut read: impl FnMut(&Path, &mut dyn Read) -> std::io::Result<()>,
+    ) -> std::io::Result<()> {
+        match self.kind {
+            ArchiveKind::Zip => {
+                let mut archive = zip::ZipArchive::new(File::open(&self.archive)?)
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+                for path in paths {
+                    if let Some(entry) = self.entries.get(path) {
+                        let mut file = archive
+                            .by_name(&entry.name)
+                            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+                        read(path, &mut file)?;
+                    }
+                }
+            }
+            ArchiveKind::Tar | ArchiveKind::TarGz => {
+                let mut archive = tar::Archive::new(self.reader()?);
+
+                for entry in archive.entries()? {
+                    let mut entry = entry?;
+                    let path = normalize(Path::new("/"), &entry.path()?);
+
+                    if paths.contains(&path) {
+                        read(&path, &mut entry)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn contains_dir(&self, path: &Path) -> bool {
+        path == Path::new("/") || matches!(self.entries.get(path), Some(entry) if entry.is_dir)
+    }
+
+    fn children(&self, dir: &Path) -> Vec<PathBuf> {
+        self.entries
+            .keys()
+            .filter(|path| path.parent() == Some(dir))
+            .cloned()
+            .collect()
+    }
+
+    /// The entries a path given to a command names, expanding it if it's a glob
+    fn matching(&self, target: &Tagged<NuGlob>) -> Result<Vec<PathBuf>, ShellError> {
+        let path = normalize(Path::new(&self.path), Path::new(target.item.as_str()));
+
+        if self.entries.contains_key(&path) || !target.item.is_expand() {
+            return Ok(vec![path]);
+        }
+
+        let pattern = glob::Pattern::new(&path.to_string_lossy()).map_err(|e| {
+            ShellError::labeled_error(e.to_string(), "invalid pattern", &target.tag)
+        })?;
+        let options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..glob::MatchOptions::new()
+        };
+
+        Ok(self
+            .entries
+            .keys()
+            .filter(|path| pattern.matches_path_with(path, options))
+            .cloned()
+            .collect())
+    }
+
+    fn entry_dict(&self, path: &Path, tag: &Tag) -> Value {
+        let entry = &self.entries[path];
+        let name = path
+            .strip_prefix(&self.path)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+
+        let mut dict = TaggedDictBuilder::new(tag);
+        dict.insert_untagged("name", UntaggedValue::filepath(name));
+        dict.insert_untagged("type", if entry.is_dir { "dir" } else { "file" });
+        dict.insert_untagged("size", UntaggedValue::filesize(entry.size));
+        dict.insert_untagged(
+            "modified",
+            match entry.modified {
+                Some(modified) => UntaggedValue::system_date(modified),
+                None => UntaggedValue::nothing(),
+            },
+        );
+
+        dict.into_value()
+    }
+
+    fn read_only(&self, command: &str, name: Tag) -> ShellError {
+        ShellError::labeled_error(
+            format!("{} is not supported inside archives", command),
+            "archives are read-only",
+            name,
+        )
+    }
+}
+
+impl Shell for ArchiveShell {
+    fn name(&self) -> String {
+        match self.archive.file_name() {
+            Some(name) => format!("[{}]", name.to_string_lossy()),
+            None => "[archive]".to_string(),
+        }
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.contains_dir(&normalize(Path::new(&self.path), path))
+    }
+
+    fn homedir(&self) -> Option<PathBuf> {
+        Some(PathBuf::from("/"))
+    }
+
+    fn ls(
+        &self,
+        LsArgs { path, all, .. }: LsArgs,
+        name_tag: Tag,
+        _ctrl_c: Arc<AtomicBool>,
+    ) -> Result<OutputStream, ShellError> {
+        let paths = match &path {
+            None => self.children(Path::new(&self.path)),
+            Some(target) => {
+                let dir = normalize(Path::new(&self.path), Path::new(target.item.as_str()));
+
+                if self.contains_dir(&dir) {
+                    self.children(&dir)
+                } else {
+                    let paths: Vec<_> = self
+                        .matching(target)?
+                        .into_iter()
+                        .filter(|path| self.entries.contains_key(path))
+                        .collect();
+
+                    if paths.is_empty() {
+                        return Err(ShellError::labeled_error(
+                            "No matches found",
+                            "no matches found",
+                            &target.tag,
+                        ));
+                    }
+
+                    paths
+                }
+            }
+        };
+
+        let output = paths
+            .iter()
+            .filter(|path| {
+                all || !path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().starts_with('.'))
+                    .unwrap_or(false)
+            })
+            .map(|path| ReturnSuccess::value(self.entry_dict(path, &name_tag)))
+            .collect::<VecDeque<_>>();
+
+        Ok(output.into())
+    }
+
+    fn cd(&self, args: CdArgs, name: Tag) -> Result<OutputStream, ShellError> {
+        let path = match &args.path {
+            None => PathBuf::from("/"),
+            Some(target) if target.item == Path::new("-") => PathBuf::from(&self.last_path),
+            Some(target) => normalize(Path::new(&self.path), &target.item),
+        };
+
+        if !self.contains_dir(&path) {
+            return Err(ShellError::labeled_error(
+                "Can not change to path inside",
+                "No such path exists",
+                match &args.path {
+                    Some(target) => target.tag(),
+                    None => name,
+                },
+            ));
+        }
+
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::change_cwd(
+            path.to_string_lossy().to_string(),
+        ));
+        Ok(stream.into())
+    }
+
+    fn cp(
+        &self,
+        CopyArgs {
+            src,
+            dst,
+            recursive,
+        }: CopyArgs,
+        _name: Tag,
+        _path: &str,
+    ) -> Result<OutputStream, ShellError> {
+        // Files are only copied out of the archive, to a path on the filesystem
+        let destination = std::env::current_dir()?.join(&dst.item);
+
+        let sources: Vec<_> = self
+            .matching(&src)?
+            .into_iter()
+            .filter(|path| self.entries.contains_key(path))
+            .collect();
+
+        if sources.is_empty() {
+            return Err(ShellError::labeled_error(
+                "No matches found",
+                "no matches found",
+                src.tag,
+            ));
+        }
+
+        if sources.len() > 1 && !destination.is_dir() {
+            return Err(ShellError::labeled_error(
+                "Destination must be a directory when copying multiple files",
+                "is not a directory",
+                dst.tag,
+            ));
+        }
+
+        if sources.iter().any(|path| self.contains_dir(path)) && !recursive.item {
+            return Err(ShellError::labeled_error(
+                "Directories must be copied using \"--recursive\"",
+                "resolves to a directory (not copied)",
+                src.tag,
+            ));
+        }
+
+        // Where each file of the archive goes, with the directories made first
+        let mut targets = BTreeMap::new();
+        for source in &sources {
+            let target = match source.file_name() {
+                Some(name) if destination.is_dir() => destination.join(name),
+                _ => destination.clone(),
+            };
+
+            for (path, entry) in self.entries.range(source.clone()..) {
+                let relative = match path.strip_prefix(source) {
+                    Ok(relative) => relative,
+                    Err(_) => break,
+                };
+
+                let target = if relative.as_os_str().is_empty() {
+                    target.clone()
+                } else {
+                    target.join(relative)
+                };
+
+                if entry.is_dir {
+                    std::fs::create_dir_all(target)?;
+                } else {
+                    targets.insert(path.clone(), target);
+                }
+            }
+        }
+
+        let paths: Vec<_> = targets.keys().cloned().collect();
+        self.extract(&paths, |path, reader| {
+            let target = &targets[path];
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            std::io::copy(reader, &mut File::create(target)?).map(|_| ())
+        })
+        .map_err(|e| {
+            ShellError::labeled_error(
+                format!("Could not copy out of {}: {}", self.archive.display(), e),
+                "could not copy",
+                src.tag,
+            )
+        })?;
+
+        Ok(OutputStream::empty())
+    }
+
+    fn mkdir(&self, _args: MkdirArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("mkdir", name))
+    }
+
+    fn mv(&self, _args: MvArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("mv", name))
+    }
+
+    fn rm(&self, _args: RemoveArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("rm", name))
+    }
+
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    fn pwd(&self, args: EvaluatedWholeStreamCommandArgs) -> Result<OutputStream, ShellError> {
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::value(
+            UntaggedValue::string(self.path()).into_value(&args.call_info.name_tag),
+        ));
+        Ok(stream.into())
+    }
+
+    fn set_path(&mut self, path: String) {
+        self.last_path = self.path.clone();
+        self.path = path;
+    }
+
+    fn open(
+        &self,
+        path: &Path,
+        name: Span,
+        with_encoding: Option<&'static Encoding>,
+    ) -> Result<BoxStream<'static, Result<StringOrBinary, ShellError>>, ShellError> {
+        use futures_codec::Decoder;
+
+        let path = normalize(Path::new(&self.path), path);
+
+        match self.entries.get(&path) {
+            Some(entry) if !entry.is_dir => {}
+            _ => {
+                return Err(ShellError::labeled_error(
+                    format!("Cannot find file {} in the archive", path.display()),
+                    "cannot find file",
+                    name,
+                ))
+            }
+        }
+
+        let mut buffer = vec![];
+        self.extract(&[path], |_, reader| {
+            reader.read_to_end(&mut buffer).map(|_| ())
+        })
+        .map_err(|e| {
+            ShellError::labeled_error(
+                format!("Error opening file: {:?}", e),
+                "Error opening file",
+                name,
+            )
+        })?;
+
+        let mut bytes_mut = bytes::BytesMut::from(&buffer[..]);
+        let mut codec = MaybeTextCodec::new(with_encoding);
+
+        match codec.decode(&mut bytes_mut).map_err(|_| {
+            ShellError::labeled_error("Error opening file", "error opening file", name)
+        })? {
+            Some(sb) => Ok(futures::stream::iter(vec![Ok(sb)].into_iter()).boxed()),
+            None => Ok(futures::stream::iter(vec![].into_iter()).boxed()),
+        }
+    }
+
+    fn save(
+        &mut self,
+        _path: &Path,
+        _contents: &[u8],
+        name: Span,
+    ) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("save", name.into()))
+    }
+}
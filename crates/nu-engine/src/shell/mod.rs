@@ -7,19 +7,35 @@ use encoding_rs::Encoding;
 use futures::stream::BoxStream;
 use nu_errors::ShellError;
 use nu_source::{Span, Tag};
+#[cfg(any(feature = "archive", feature = "s3", feature = "sqlite"))]
+use std::path::Component;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
+#[cfg(feature = "archive")]
+pub(crate) mod archive_shell;
 pub(crate) mod help_shell;
 pub(crate) mod painter;
 pub(crate) mod palette;
+#[cfg(feature = "s3")]
+pub(crate) mod s3_shell;
 pub(crate) mod shell_args;
 pub(crate) mod shell_manager;
+#[cfg(feature = "sqlite")]
+pub(crate) mod sqlite_shell;
 pub(crate) mod value_shell;
 
 pub trait Shell: std::fmt::Debug {
     fn name(&self) -> String;
+    /// Whether this shell is on the filesystem, rather than inside a value or an archive
+    fn is_filesystem(&self) -> bool {
+        false
+    }
+    /// Whether the path names a directory, or whatever the shell lists the way it does directories
+    fn is_dir(&self, _path: &Path) -> bool {
+        false
+    }
     fn homedir(&self) -> Option<PathBuf>;
 
     fn ls(
@@ -49,3 +65,23 @@ pub trait Shell: std::fmt::Debug {
         name: Span,
     ) -> Result<OutputStream, ShellError>;
 }
+
+/// The path of `target` from the root of a shell that isn't on the filesystem, like the root of an
+/// archive, with any `.` and `..` taken out
+#[cfg(any(feature = "archive", feature = "s3", feature = "sqlite"))]
+pub(crate) fn normalize(base: &Path, target: &Path) -> PathBuf {
+    let mut normalized = base.to_path_buf();
+
+    for component in target.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => normalized = PathBuf::from("/"),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::Normal(name) => normalized.push(name),
+        }
+    }
+
+    normalized
+}
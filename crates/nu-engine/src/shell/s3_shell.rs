@@ -0,0 +1,320 @@
+use crate::command_args::EvaluatedWholeStreamCommandArgs;
+use crate::maybe_text_codec::StringOrBinary;
+use crate::shell::normalize;
+use crate::shell::shell_args::{CdArgs, CopyArgs, LsArgs, MkdirArgs, MvArgs, RemoveArgs};
+use crate::shell::Shell;
+use encoding_rs::Encoding;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{Span, SpannedItem, Tag};
+use nu_stream::OutputStream;
+use nu_value_ext::ValueExt;
+use s3handler::{CredentialConfig, Handler as S3Handler};
+use std::collections::{BTreeMap, VecDeque};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+/// A shell over a bucket of an S3 compatible object store, where the parts of the keys between
+/// slashes are listed like directories
+pub struct S3Shell {
+    pub(crate) path: String,
+    pub(crate) last_path: String,
+    bucket: String,
+    config: CredentialConfig,
+}
+
+impl std::fmt::Debug for S3Shell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "S3Shell s3://{} @ {}", self.bucket, self.path)
+    }
+}
+
+/// The credentials from the `s3` table of the config, falling back to the variables the AWS tools
+/// read
+fn credentials() -> Result<CredentialConfig, ShellError> {
+    let config = nu_data::config::config(Tag::unknown())?;
+    let s3 = config.get("s3");
+    let setting = |key: &str, var: &str| {
+        s3.and_then(|s3| s3.get_data_by_key(key.spanned_unknown()))
+            .and_then(|value| value.as_string().ok())
+            .or_else(|| std::env::var(var).ok())
+    };
+
+    let missing = |key: &str, var: &str| {
+        ShellError::untagged_runtime_error(format!(
+            "No S3 {} given, set s3.{} in the config or ${}",
+            key.replace('_', " "),
+            key,
+            var
+        ))
+    };
+
+    Ok(CredentialConfig {
+        host: setting("endpoint", "S3_ENDPOINT").unwrap_or_else(|| "s3.amazonaws.com".into()),
+        access_key: setting("access_key", "AWS_ACCESS_KEY_ID")
+            .ok_or_else(|| missing("access_key", "AWS_ACCESS_KEY_ID"))?,
+        secret_key: setting("secret_key", "AWS_SECRET_ACCESS_KEY")
+            .ok_or_else(|| missing("secret_key", "AWS_SECRET_ACCESS_KEY"))?,
+        user: None,
+        region: setting("region", "AWS_DEFAULT_REGION"),
+        s3_type: None,
+        secure: None,
+    })
+}
+
+fn s3_error(error: impl std::fmt::Display, tag: impl Into<Tag>) -> ShellError {
+    ShellError::labeled_error(
+        format!("S3 request failed: {}", error),
+        "S3 request failed",
+        tag,
+    )
+}
+
+impl S3Shell {
+    /// A shell in the bucket and at the prefix of an `s3://bucket/prefix` URL
+    pub fn new(url: &str) -> Result<S3Shell, ShellError> {
+        let location = url.trim_start_matches("s3://");
+        let (bucket, prefix) = match location.find('/') {
+            Some(index) => (&location[..index], &location[index..]),
+            None => (location, "/"),
+        };
+
+        if bucket.is_empty() {
+            return Err(ShellError::untagged_runtime_error(format!(
+                "No bucket given in {}",
+                url
+            )));
+        }
+
+        let path = normalize(Path::new("/"), Path::new(prefix))
+            .to_string_lossy()
+            .to_string();
+
+        Ok(S3Shell {
+            last_path: path.clone(),
+            path,
+            bucket: bucket.to_string(),
+            config: credentials()?,
+        })
+    }
+
+    /// The URL of the object at the path, which is the key without its leading slash
+    fn url(&self, path: &Path) -> String {
+        format!(
+            "s3://{}/{}",
+            self.bucket,
+            path.to_string_lossy().trim_start_matches('/')
+        )
+    }
+
+    /// The directories and objects right under the path, with the time each object was modified
+    fn entries_under(
+        &self,
+        path: &Path,
+        tag: &Tag,
+    ) -> Result<BTreeMap<String, Option<String>>, ShellError> {
+        let mut prefix = path.to_string_lossy().trim_start_matches('/').to_string();
+        if !prefix.is_empty() {
+            prefix.push('/');
+        }
+
+        let mut handler = S3Handler::from(&self.config);
+        let objects = handler
+            .ls(Some(&format!("s3://{}/{}", self.bucket, prefix)))
+            .map_err(|e| s3_error(e, tag))?;
+
+        let mut entries = BTreeMap::new();
+        for object in objects {
+            let key = match object.key {
+                Some(key) => key,
+                None => continue,
+            };
+            let rest = match key.trim_start_matches('/').strip_prefix(&prefix) {
+                Some(rest) if !rest.is_empty() => rest.to_string(),
+                _ => continue,
+            };
+
+            match rest.find('/') {
+                // A directory, which has no modified time of its own
+                Some(index) => {
+                    entries.insert(rest[..=index].to_string(), None);
+                }
+                None => {
+                    entries.insert(rest, object.mtime);
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn entry_dict(&self, name: &str, modified: Option<&String>, tag: &Tag) -> Value {
+        let mut dict = TaggedDictBuilder::new(tag);
+        dict.insert_untagged("name", UntaggedValue::filepath(name.trim_end_matches('/')));
+        dict.insert_untagged("type", if name.ends_with('/') { "dir" } else { "file" });
+        dict.insert_untagged(
+            "modified",
+            match modified.and_then(|m| chrono::DateTime::parse_from_rfc3339(m).ok()) {
+                Some(modified) => UntaggedValue::date(modified),
+                None => UntaggedValue::nothing(),
+            },
+        );
+
+        dict.into_value()
+    }
+
+    fn unsupported(&self, command: &str, name: Tag) -> ShellError {
+        ShellError::labeled_error(
+            format!("{} is not supported on object stores", command),
+            "not supported on object stores",
+            name,
+        )
+    }
+}
+
+impl Shell for S3Shell {
+    fn name(&self) -> String {
+        format!("s3://{}", self.bucket)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        let path = normalize(Path::new(&self.path), path);
+
+        path == Path::new("/")
+            || matches!(self.entries_under(&path, &Tag::unknown()), Ok(entries) if !entries.is_empty())
+    }
+
+    fn homedir(&self) -> Option<PathBuf> {
+        Some(PathBuf::from("/"))
+    }
+
+    fn ls(
+        &self,
+        LsArgs { path, .. }: LsArgs,
+        name_tag: Tag,
+        _ctrl_c: Arc<AtomicBool>,
+    ) -> Result<OutputStream, ShellError> {
+        let dir = match &path {
+            Some(target) => normalize(Path::new(&self.path), Path::new(target.item.as_str())),
+            None => PathBuf::from(&self.path),
+        };
+
+        let entries = self.entries_under(&dir, &name_tag)?;
+        if entries.is_empty() && dir != Path::new("/") {
+            return Err(ShellError::labeled_error(
+                "Can not list entries inside",
+                "No such path exists",
+                match &path {
+                    Some(target) => target.tag(),
+                    None => name_tag,
+                },
+            ));
+        }
+
+        Ok(entries
+            .iter()
+            .map(|(name, modified)| {
+                ReturnSuccess::value(self.entry_dict(name, modified.as_ref(), &name_tag))
+            })
+            .collect::<VecDeque<_>>()
+            .into())
+    }
+
+    fn cd(&self, args: CdArgs, name: Tag) -> Result<OutputStream, ShellError> {
+        let path = match &args.path {
+            None => PathBuf::from("/"),
+            Some(target) if target.item == Path::new("-") => PathBuf::from(&self.last_path),
+            Some(target) => normalize(Path::new(&self.path), &target.item),
+        };
+
+        if path != Path::new("/") && self.entries_under(&path, &name)?.is_empty() {
+            return Err(ShellError::labeled_error(
+                "Can not change to path inside",
+                "No such path exists",
+                match &args.path {
+                    Some(target) => target.tag(),
+                    None => name,
+                },
+            ));
+        }
+
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::change_cwd(
+            path.to_string_lossy().to_string(),
+        ));
+        Ok(stream.into())
+    }
+
+    fn cp(&self, _args: CopyArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.unsupported("cp", name))
+    }
+
+    fn mkdir(&self, _args: MkdirArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.unsupported("mkdir", name))
+    }
+
+    fn mv(&self, _args: MvArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.unsupported("mv", name))
+    }
+
+    fn rm(&self, _args: RemoveArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.unsupported("rm", name))
+    }
+
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    fn pwd(&self, args: EvaluatedWholeStreamCommandArgs) -> Result<OutputStream, ShellError> {
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::value(
+            UntaggedValue::string(format!("s3://{}{}", self.bucket, self.path))
+                .into_value(&args.call_info.name_tag),
+        ));
+        Ok(stream.into())
+    }
+
+    fn set_path(&mut self, path: String) {
+        self.last_path = self.path.clone();
+        self.path = path;
+    }
+
+    fn open(
+        &self,
+        path: &Path,
+        name: Span,
+        _with_encoding: Option<&'static Encoding>,
+    ) -> Result<BoxStream<'static, Result<StringOrBinary, ShellError>>, ShellError> {
+        let url = self.url(&normalize(Path::new(&self.path), path));
+
+        let mut handler = S3Handler::from(&self.config);
+        let (contents, _content_type) = handler.cat(&url).map_err(|e| s3_error(e, name))?;
+
+        Ok(futures::stream::iter(vec![Ok(StringOrBinary::String(contents))]).boxed())
+    }
+
+    fn save(
+        &mut self,
+        path: &Path,
+        contents: &[u8],
+        name: Span,
+    ) -> Result<OutputStream, ShellError> {
+        let url = self.url(&normalize(Path::new(&self.path), path));
+
+        // Objects are uploaded from a file
+        let mut file = tempfile::NamedTempFile::new()?;
+        file.write_all(contents)?;
+        file.flush()?;
+
+        let mut handler = S3Handler::from(&self.config);
+        handler
+            .put(&file.path().to_string_lossy(), &url)
+            .map_err(|e| s3_error(e, name))?;
+
+        Ok(OutputStream::empty())
+    }
+}
@@ -1,3 +1,4 @@
+use nu_protocol::NuGlob;
 use nu_source::Tagged;
 use serde::{self, Deserialize};
 use std::path::PathBuf;
@@ -9,25 +10,27 @@ pub struct CdArgs {
 
 #[derive(Deserialize)]
 pub struct CopyArgs {
-    pub src: Tagged<PathBuf>,
+    pub src: Tagged<NuGlob>,
     pub dst: Tagged<PathBuf>,
     pub recursive: Tagged<bool>,
 }
 
 #[derive(Deserialize)]
 pub struct LsArgs {
-    pub path: Option<Tagged<PathBuf>>,
+    pub path: Option<Tagged<NuGlob>>,
     pub all: bool,
     pub long: bool,
     #[serde(rename = "short-names")]
     pub short_names: bool,
     #[serde(rename = "du")]
     pub du: bool,
+    #[serde(rename = "max-depth")]
+    pub max_depth: Option<Tagged<u64>>,
 }
 
 #[derive(Deserialize)]
 pub struct MvArgs {
-    pub src: Tagged<PathBuf>,
+    pub src: Tagged<NuGlob>,
     pub dst: Tagged<PathBuf>,
 }
 
@@ -40,7 +43,7 @@ pub struct MkdirArgs {
 
 #[derive(Deserialize)]
 pub struct RemoveArgs {
-    pub rest: Vec<Tagged<PathBuf>>,
+    pub rest: Vec<Tagged<NuGlob>>,
     pub recursive: Tagged<bool>,
     #[allow(unused)]
     pub trash: Tagged<bool>,
@@ -9,16 +9,26 @@ use crate::shell::Shell;
 
 use encoding_rs::Encoding;
 use nu_errors::ShellError;
-use nu_source::{Span, Tag};
+use nu_source::{Span, Tag, TaggedItem};
 use parking_lot::Mutex;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 
+/// Where a shell has been: the path it was in before the current one, for `cd -`, and the
+/// paths saved by `pushd`, with the most recent last
+#[derive(Clone, Debug, Default)]
+pub struct DirHistory {
+    pub previous: Option<String>,
+    pub stack: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct ShellManager {
     pub current_shell: Arc<AtomicUsize>,
     pub shells: Arc<Mutex<Vec<Box<dyn Shell + Send>>>>,
+    /// The history of each shell, at the same index as the shell
+    pub dir_history: Arc<Mutex<Vec<DirHistory>>>,
 }
 
 impl ShellManager {
@@ -26,10 +36,12 @@ impl ShellManager {
         Ok(ShellManager {
             current_shell: Arc::new(AtomicUsize::new(0)),
             shells: Arc::new(Mutex::new(vec![Box::new(FilesystemShell::basic()?)])),
+            dir_history: Arc::new(Mutex::new(vec![DirHistory::default()])),
         })
     }
 
     pub fn insert_at_current(&self, shell: Box<dyn Shell + Send>) {
+        self.dir_history.lock().push(DirHistory::default());
         self.shells.lock().push(shell);
         self.current_shell
             .store(self.shells.lock().len() - 1, Ordering::SeqCst);
@@ -46,6 +58,7 @@ impl ShellManager {
             if shells.len() > 0 {
                 if self.current_shell() == shells.len() - 1 {
                     shells.pop();
+                    self.dir_history.lock().pop();
                     let new_len = shells.len();
                     if new_len > 0 {
                         self.current_shell.store(new_len - 1, Ordering::SeqCst);
@@ -54,6 +67,7 @@ impl ShellManager {
                     }
                 } else {
                     shells.remove(self.current_shell());
+                    self.dir_history.lock().remove(self.current_shell());
                 }
             }
         }
@@ -75,7 +89,63 @@ impl ShellManager {
     }
 
     pub fn set_path(&self, path: String) {
-        self.shells.lock()[self.current_shell()].set_path(path)
+        let mut shells = self.shells.lock();
+        let shell = &mut shells[self.current_shell()];
+
+        let before = shell.path();
+        shell.set_path(path);
+        // Switching shells sets the path a shell is already in, which isn't a move
+        if shell.path() != before {
+            self.dir_history.lock()[self.current_shell()].previous = Some(before);
+        }
+    }
+
+    /// The paths in the directory stack of the current shell, starting with the path it's in and
+    /// then the most recently pushed
+    pub fn dirs(&self) -> Vec<String> {
+        let path = self.path();
+        let history = self.dir_history.lock();
+
+        std::iter::once(path)
+            .chain(history[self.current_shell()].stack.iter().rev().cloned())
+            .collect()
+    }
+
+    /// Saves the path the current shell is in on its directory stack
+    pub fn push_dir(&self) {
+        let path = self.path();
+        self.dir_history.lock()[self.current_shell()]
+            .stack
+            .push(path);
+    }
+
+    /// Takes the most recently pushed path off the directory stack of the current shell
+    pub fn pop_dir(&self) -> Option<String> {
+        self.dir_history.lock()[self.current_shell()].stack.pop()
+    }
+
+    /// Rotates the directory stack of the current shell so the path at `index` of `dirs` comes
+    /// first, and returns it. The path the shell is in goes onto the stack with the others.
+    pub fn pivot_dirs(&self, index: usize) -> Option<String> {
+        let mut dirs = self.dirs();
+        if index >= dirs.len() {
+            return None;
+        }
+
+        dirs.rotate_left(index);
+        let target = dirs.remove(0);
+        dirs.reverse();
+        self.dir_history.lock()[self.current_shell()].stack = dirs;
+
+        Some(target)
+    }
+
+    pub fn is_filesystem(&self) -> bool {
+        self.shells.lock()[self.current_shell()].is_filesystem()
+    }
+
+    pub fn is_dir(&self, path: &Path) -> bool {
+        self.shells.lock()[self.current_shell()].is_dir(path)
     }
 
     pub fn open(
@@ -140,7 +210,23 @@ impl ShellManager {
         env[self.current_shell()].ls(args, name, ctrl_c)
     }
 
-    pub fn cd(&self, args: CdArgs, name: Tag) -> Result<OutputStream, ShellError> {
+    pub fn cd(&self, mut args: CdArgs, name: Tag) -> Result<OutputStream, ShellError> {
+        if let Some(target) = &args.path {
+            if target.item == Path::new("-") {
+                let previous = self.dir_history.lock()[self.current_shell()]
+                    .previous
+                    .clone()
+                    .ok_or_else(|| {
+                        ShellError::labeled_error(
+                            "No previous directory to change to",
+                            "no previous directory",
+                            &target.tag,
+                        )
+                    })?;
+                args.path = Some(PathBuf::from(previous).tagged(&target.tag));
+            }
+        }
+
         let env = self.shells.lock();
 
         env[self.current_shell()].cd(args, name)
@@ -0,0 +1,296 @@
+use crate::command_args::EvaluatedWholeStreamCommandArgs;
+use crate::maybe_text_codec::StringOrBinary;
+use crate::shell::normalize;
+use crate::shell::shell_args::{CdArgs, CopyArgs, LsArgs, MkdirArgs, MvArgs, RemoveArgs};
+use crate::shell::Shell;
+use encoding_rs::Encoding;
+use futures::stream::BoxStream;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{Span, Tag};
+use nu_stream::OutputStream;
+use rusqlite::{types::ValueRef, Connection, OpenFlags, Row, NO_PARAMS};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A read-only shell inside a SQLite database, where the tables are listed like directories and
+/// the rows of a table are listed inside it
+#[derive(Clone)]
+pub struct SqliteShell {
+    pub(crate) path: String,
+    pub(crate) last_path: String,
+    database: PathBuf,
+}
+
+impl std::fmt::Debug for SqliteShell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SqliteShell {} @ {}", self.database.display(), self.path)
+    }
+}
+
+fn sqlite_error(database: &Path, error: rusqlite::Error) -> ShellError {
+    ShellError::untagged_runtime_error(format!(
+        "Could not read the database {}: {}",
+        database.display(),
+        error
+    ))
+}
+
+fn row_to_value(row: &Row, tag: &Tag) -> Value {
+    let mut dict = TaggedDictBuilder::new(tag);
+
+    for (index, column) in row.column_names().iter().enumerate() {
+        let value = match row.get_raw(index) {
+            ValueRef::Null => UntaggedValue::nothing(),
+            ValueRef::Integer(i) => UntaggedValue::int(i),
+            ValueRef::Real(f) => UntaggedValue::decimal_from_float(f, tag.span),
+            ValueRef::Text(s) => UntaggedValue::string(String::from_utf8_lossy(s)),
+            ValueRef::Blob(b) => UntaggedValue::binary(b.to_vec()),
+        };
+        dict.insert_untagged(*column, value);
+    }
+
+    dict.into_value()
+}
+
+impl SqliteShell {
+    pub fn new(database: impl Into<PathBuf>) -> Result<SqliteShell, ShellError> {
+        let shell = SqliteShell {
+            path: "/".to_string(),
+            last_path: "/".to_string(),
+            database: database.into(),
+        };
+        // Reading the tables up front fails early for a file that isn't a database
+        shell.tables()?;
+
+        Ok(shell)
+    }
+
+    fn connect(&self) -> Result<Connection, ShellError> {
+        Connection::open_with_flags(&self.database, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| sqlite_error(&self.database, e))
+    }
+
+    /// The names and types of the tables and views, in the order they were made in
+    fn tables(&self) -> Result<Vec<(String, String)>, ShellError> {
+        let conn = self.connect()?;
+        let error = |e| sqlite_error(&self.database, e);
+
+        let mut statement = conn
+            .prepare(
+                "select name, type from sqlite_master \
+                 where type in ('table', 'view') and name not like 'sqlite_%'",
+            )
+            .map_err(error)?;
+        let tables = statement
+            .query_map(NO_PARAMS, |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(error)?;
+
+        Ok(tables)
+    }
+
+    /// The table a path names, which is the first part of it from the root of the database
+    fn table_of(&self, path: &Path) -> Option<String> {
+        normalize(Path::new(&self.path), path)
+            .components()
+            .nth(1)
+            .map(|table| table.as_os_str().to_string_lossy().to_string())
+    }
+
+    fn has_table(&self, table: &str) -> bool {
+        matches!(self.tables(), Ok(tables) if tables.iter().any(|(name, _)| name == table))
+    }
+
+    fn rows(
+        &self,
+        table: &str,
+        tag: &Tag,
+        ctrl_c: &AtomicBool,
+    ) -> Result<VecDeque<Value>, ShellError> {
+        let conn = self.connect()?;
+        let error = |e| sqlite_error(&self.database, e);
+
+        let mut statement = conn
+            .prepare(&format!("select * from [{}]", table.replace("]", "]]")))
+            .map_err(error)?;
+        let mut rows = statement.query(NO_PARAMS).map_err(error)?;
+
+        let mut values = VecDeque::new();
+        while let Some(row) = rows.next().map_err(error)? {
+            if ctrl_c.load(Ordering::SeqCst) {
+                break;
+            }
+
+            values.push_back(row_to_value(row, tag));
+        }
+
+        Ok(values)
+    }
+
+    fn table_dicts(&self, tag: &Tag) -> Result<VecDeque<Value>, ShellError> {
+        let conn = self.connect()?;
+
+        self.tables()?
+            .into_iter()
+            .map(|(name, kind)| {
+                let count: i64 = conn
+                    .query_row(
+                        &format!("select count(*) from [{}]", name.replace("]", "]]")),
+                        NO_PARAMS,
+                        |row| row.get(0),
+                    )
+                    .map_err(|e| sqlite_error(&self.database, e))?;
+
+                let mut dict = TaggedDictBuilder::new(tag);
+                dict.insert_untagged("name", UntaggedValue::string(name));
+                dict.insert_untagged("type", UntaggedValue::string(kind));
+                dict.insert_untagged("rows", UntaggedValue::int(count));
+
+                Ok(dict.into_value())
+            })
+            .collect()
+    }
+
+    fn read_only(&self, command: &str, name: Tag) -> ShellError {
+        ShellError::labeled_error(
+            format!("{} is not supported inside databases", command),
+            "databases are read-only",
+            name,
+        )
+    }
+}
+
+impl Shell for SqliteShell {
+    fn name(&self) -> String {
+        match self.database.file_name() {
+            Some(name) => format!("[{}]", name.to_string_lossy()),
+            None => "[database]".to_string(),
+        }
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        match self.table_of(path) {
+            Some(table) => self.has_table(&table),
+            None => true,
+        }
+    }
+
+    fn homedir(&self) -> Option<PathBuf> {
+        Some(PathBuf::from("/"))
+    }
+
+    fn ls(
+        &self,
+        LsArgs { path, .. }: LsArgs,
+        name_tag: Tag,
+        ctrl_c: Arc<AtomicBool>,
+    ) -> Result<OutputStream, ShellError> {
+        let target = match &path {
+            Some(path) => PathBuf::from(path.item.as_str()),
+            None => PathBuf::from("."),
+        };
+
+        let values = match self.table_of(&target) {
+            None => self.table_dicts(&name_tag)?,
+            Some(table) if self.has_table(&table) => self.rows(&table, &name_tag, &ctrl_c)?,
+            Some(_) => {
+                return Err(ShellError::labeled_error(
+                    "Can not list entries inside",
+                    "No such table exists",
+                    match &path {
+                        Some(path) => path.tag(),
+                        None => name_tag,
+                    },
+                ))
+            }
+        };
+
+        Ok(values
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect::<VecDeque<_>>()
+            .into())
+    }
+
+    fn cd(&self, args: CdArgs, _name: Tag) -> Result<OutputStream, ShellError> {
+        let path = match &args.path {
+            None => "/".to_string(),
+            Some(target) if target.item == Path::new("-") => self.last_path.clone(),
+            Some(target) => match self.table_of(&target.item) {
+                None => "/".to_string(),
+                Some(table) if self.has_table(&table) => format!("/{}", table),
+                Some(_) => {
+                    return Err(ShellError::labeled_error(
+                        "Can not change to path inside",
+                        "No such table exists",
+                        target.tag(),
+                    ))
+                }
+            },
+        };
+
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::change_cwd(path));
+        Ok(stream.into())
+    }
+
+    fn cp(&self, _args: CopyArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("cp", name))
+    }
+
+    fn mkdir(&self, _args: MkdirArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("mkdir", name))
+    }
+
+    fn mv(&self, _args: MvArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("mv", name))
+    }
+
+    fn rm(&self, _args: RemoveArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("rm", name))
+    }
+
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    fn pwd(&self, args: EvaluatedWholeStreamCommandArgs) -> Result<OutputStream, ShellError> {
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::value(
+            UntaggedValue::string(self.path()).into_value(&args.call_info.name_tag),
+        ));
+        Ok(stream.into())
+    }
+
+    fn set_path(&mut self, path: String) {
+        self.last_path = self.path.clone();
+        self.path = path;
+    }
+
+    fn open(
+        &self,
+        path: &Path,
+        name: Span,
+        _with_encoding: Option<&'static Encoding>,
+    ) -> Result<BoxStream<'static, Result<StringOrBinary, ShellError>>, ShellError> {
+        // Tables are opened by listing their rows, so there's nothing else to open
+        Err(ShellError::labeled_error(
+            format!("Cannot find table {} in the database", path.display()),
+            "cannot find table",
+            name,
+        ))
+    }
+
+    fn save(
+        &mut self,
+        _path: &Path,
+        _contents: &[u8],
+        name: Span,
+    ) -> Result<OutputStream, ShellError> {
+        Err(self.read_only("save", name.into()))
+    }
+}
@@ -104,7 +104,7 @@ impl Shell for ValueShell {
         let mut full_path = PathBuf::from(self.path());
 
         if let Some(value) = &path {
-            full_path.push(value.as_ref());
+            full_path.push(value.item.as_str());
         }
 
         let mut value_system = ValueStructure::new();
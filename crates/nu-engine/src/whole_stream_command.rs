@@ -1,12 +1,12 @@
 use crate::command_args::CommandArgs;
-use crate::documentation::get_full_help;
+use crate::documentation::{get_full_help, page_documentation};
 use crate::evaluate::block::run_block;
 use crate::evaluation_context::EvaluationContext;
 use crate::example::Example;
 use async_trait::async_trait;
 use nu_errors::ShellError;
 use nu_parser::ParserScope;
-use nu_protocol::hir::Block;
+use nu_protocol::hir::{Block, ExternalRedirection};
 use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
 use nu_source::{DbgDocBldr, DebugDocBuilder, PrettyDebugWithSource, Span, Tag};
 use nu_stream::{OutputStream, ToOutputStream};
@@ -40,6 +40,16 @@ pub trait WholeStreamCommand: Send + Sync {
     fn examples(&self) -> Vec<Example> {
         Vec::new()
     }
+
+    // The binary that runs the command, for commands that come from plugins
+    fn plugin_path(&self) -> Option<&str> {
+        None
+    }
+
+    // Commands defined with `def`
+    fn is_custom(&self) -> bool {
+        false
+    }
 }
 
 // Custom commands are blocks, so we can use the information in the block to also
@@ -59,6 +69,10 @@ impl WholeStreamCommand for Block {
         &self.params.usage
     }
 
+    fn extra_usage(&self) -> &str {
+        &self.params.extra_usage
+    }
+
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         let call_info = args.call_info.clone();
 
@@ -154,7 +168,11 @@ impl WholeStreamCommand for Block {
             }
         }
         let result = run_block(&block, &ctx, input).await;
-        ctx.scope.exit_scope();
+        if block.preserves_env {
+            ctx.scope.exit_scope_with_env();
+        } else {
+            ctx.scope.exit_scope();
+        }
         result.map(|x| x.to_output_stream())
     }
 
@@ -166,8 +184,20 @@ impl WholeStreamCommand for Block {
         false
     }
 
+    fn is_custom(&self) -> bool {
+        true
+    }
+
     fn examples(&self) -> Vec<Example> {
-        vec![]
+        self.params
+            .examples
+            .iter()
+            .map(|example| Example {
+                example: &example.example,
+                description: &example.description,
+                result: None,
+            })
+            .collect()
     }
 }
 
@@ -213,8 +243,17 @@ impl Command {
     pub async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         if args.call_info.switch_present("help") {
             let cl = self.0.clone();
+            let help = get_full_help(&*cl, &args.scope);
+
+            // Long help at the end of the pipeline goes straight to a pager
+            if args.call_info.args.external_redirection == ExternalRedirection::None
+                && page_documentation(&help)
+            {
+                return Ok(OutputStream::empty());
+            }
+
             Ok(OutputStream::one(Ok(ReturnSuccess::Value(
-                UntaggedValue::string(get_full_help(&*cl, &args.scope)).into_value(Tag::unknown()),
+                UntaggedValue::string(help).into_value(Tag::unknown()),
             ))))
         } else {
             self.0.run(args).await
@@ -229,6 +268,14 @@ impl Command {
         self.0.is_internal()
     }
 
+    pub fn plugin_path(&self) -> Option<&str> {
+        self.0.plugin_path()
+    }
+
+    pub fn is_custom(&self) -> bool {
+        self.0.is_custom()
+    }
+
     pub fn stream_command(&self) -> &dyn WholeStreamCommand {
         &*self.0
     }
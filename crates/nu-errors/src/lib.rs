@@ -135,8 +135,9 @@ pub enum ArgumentError {
     MissingValueForName(String),
     /// An argument was found, but the command does not recognize it
     UnexpectedArgument(Spanned<String>),
-    /// An flag was found, but the command does not recognize it
-    UnexpectedFlag(Spanned<String>),
+    /// An flag was found, but the command does not recognize it. The second part is the closest
+    /// flag the command does have, if any is close enough to be a likely typo.
+    UnexpectedFlag(Spanned<String>, Option<String>),
     /// A sequence of characters was found that was not syntactically valid (but would have
     /// been valid if the command was an external command)
     InvalidExternalWord,
@@ -155,10 +156,18 @@ impl PrettyDebug for ArgumentError {
                     + DbgDocBldr::description(&name.item)
                     + DbgDocBldr::description("` is not supported")
             }
-            ArgumentError::UnexpectedFlag(name) => {
+            ArgumentError::UnexpectedFlag(name, suggestion) => {
                 DbgDocBldr::description("unexpected `")
                     + DbgDocBldr::description(&name.item)
                     + DbgDocBldr::description("` is not supported")
+                    + match suggestion {
+                        Some(suggestion) => {
+                            DbgDocBldr::description(", did you mean `")
+                                + DbgDocBldr::description(suggestion)
+                                + DbgDocBldr::description("`?")
+                        }
+                        None => DbgDocBldr::blank(),
+                    }
             }
             ArgumentError::MissingMandatoryPositional(pos) => {
                 DbgDocBldr::description("missing `")
@@ -310,9 +319,20 @@ impl PrettyDebug for ShellError {
                 DbgDocBldr::error("Unknown Error")
                     + DbgDocBldr::delimit("(", DbgDocBldr::description(reason), ")")
             }
-            ProximateShellError::ExternalPlaceholderError => {
+            ProximateShellError::ExternalPlaceholderError { .. } => {
                 DbgDocBldr::error("non-zero external exit code")
             }
+            ProximateShellError::TimedOut { duration } => {
+                DbgDocBldr::error("Timed out")
+                    + DbgDocBldr::space()
+                    + DbgDocBldr::delimit(
+                        "(",
+                        DbgDocBldr::description("after:")
+                            + DbgDocBldr::space()
+                            + DbgDocBldr::description(&duration.item),
+                        ")",
+                    )
+            }
         }
     }
 }
@@ -433,8 +453,29 @@ impl ShellError {
         ProximateShellError::Diagnostic(ShellDiagnostic { diagnostic }).start()
     }
 
-    pub fn external_non_zero() -> ShellError {
-        ProximateShellError::ExternalPlaceholderError.start()
+    pub fn external_non_zero(exit_code: Option<i32>) -> ShellError {
+        ProximateShellError::ExternalPlaceholderError { exit_code }.start()
+    }
+
+    /// The error of a block that didn't finish in time, such as one run by `timeout`
+    pub fn timed_out(duration: impl Into<String>, span: impl Into<Span>) -> ShellError {
+        ProximateShellError::TimedOut {
+            duration: duration.into().spanned(span.into()),
+        }
+        .start()
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        matches!(self.error, ProximateShellError::TimedOut { .. })
+    }
+
+    /// The exit code of an external that failed, if this is the error it leaves behind. A
+    /// failed external killed by a signal has no exit code, so it gets `Some(None)`.
+    pub fn external_exit_code(&self) -> Option<Option<i32>> {
+        match self.error {
+            ProximateShellError::ExternalPlaceholderError { exit_code } => Some(exit_code),
+            _ => None,
+        }
     }
 
     pub fn into_diagnostic(self) -> Option<Diagnostic<usize>> {
@@ -465,7 +506,7 @@ impl ShellError {
                     vec![Label::primary(0, argument.span).with_message(
                         format!("unexpected argument (try {} -h)", &command.item))]
                 ),
-                ArgumentError::UnexpectedFlag(flag) => Diagnostic::error().with_message(
+                ArgumentError::UnexpectedFlag(flag, suggestion) => Diagnostic::error().with_message(
                     format!(
                         "{} unexpected {}",
                         Color::Cyan.paint(&command.item),
@@ -473,8 +514,10 @@ impl ShellError {
                     ),
                 )
                 .with_labels(vec![
-                    Label::primary(0, flag.span).with_message(
-                    format!("unexpected flag (try {} -h)", &command.item))
+                    Label::primary(0, flag.span).with_message(match suggestion {
+                        Some(suggestion) => format!("unexpected flag (did you mean {}?)", suggestion),
+                        None => format!("unexpected flag (try {} -h)", &command.item),
+                    })
                     ]),
                 ArgumentError::MissingMandatoryFlag(name) => Diagnostic::error().with_message(                    format!(
                         "{} requires {}{}",
@@ -600,7 +643,9 @@ impl ShellError {
             }
 
             ProximateShellError::UntaggedRuntimeError { reason } => Some(Diagnostic::error().with_message(format!("Error: {}", reason))),
-            ProximateShellError::ExternalPlaceholderError => None,
+            ProximateShellError::ExternalPlaceholderError { .. } => None,
+            ProximateShellError::TimedOut { duration } => Some(Diagnostic::error().with_message("Timed out")
+                .with_labels(vec![Label::primary(0, duration.span).with_message(format!("didn't finish within {}", duration.item))])),
         }
     }
 
@@ -747,7 +792,12 @@ pub enum ProximateShellError {
     UntaggedRuntimeError {
         reason: String,
     },
-    ExternalPlaceholderError,
+    ExternalPlaceholderError {
+        exit_code: Option<i32>,
+    },
+    TimedOut {
+        duration: Spanned<String>,
+    },
 }
 
 impl ProximateShellError {
@@ -779,7 +829,8 @@ impl HasFallibleSpan for ProximateShellError {
             ProximateShellError::Diagnostic(_) => return None,
             ProximateShellError::CoerceError { left, right } => left.span.until(right.span),
             ProximateShellError::UntaggedRuntimeError { .. } => return None,
-            ProximateShellError::ExternalPlaceholderError => return None,
+            ProximateShellError::ExternalPlaceholderError { .. } => return None,
+            ProximateShellError::TimedOut { duration } => duration.span,
         })
     }
 }
@@ -68,7 +68,15 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
     // closing quote.
     let mut quote_start: Option<char> = None;
 
+    // Inside a double-quoted string, a backslash escapes the character after it, so `\"`
+    // doesn't close the string.
+    let mut escaped = false;
+
     let mut in_comment = false;
+    let mut in_block_comment = false;
+
+    // The closing delimiter of a raw string that the input ended inside of
+    let mut unclosed_raw_string: Option<String> = None;
 
     // This Vec tracks paired delimiters
     let mut block_level: Vec<BlockKind> = vec![];
@@ -97,13 +105,30 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
         if quote_start.is_some() {
             // If we encountered the closing quote character for the current
             // string, we're done with the current string.
-            if Some(c) == quote_start {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && quote_start == Some('"') {
+                escaped = true;
+            } else if Some(c) == quote_start {
                 quote_start = None;
             }
+        } else if in_block_comment {
+            // A block comment ends at the first `]#`, whatever it's nested in.
+            if c == '#' && token_contents.ends_with(']') {
+                in_block_comment = false;
+            }
         } else if c == '#' {
             if is_termination(&block_level, c) {
                 break;
             }
+            if starts_block_comment(src) {
+                in_block_comment = true;
+                token_contents.push(c);
+                token_contents.push('[');
+                let _ = src.next();
+                let _ = src.next();
+                continue;
+            }
             in_comment = true;
         } else if c == '\n' {
             in_comment = false;
@@ -114,6 +139,16 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
             if is_termination(&block_level, c) {
                 break;
             }
+        } else if c == 'r' && at_word_start(&token_contents) {
+            if let Some(hashes) = raw_string_hashes(src) {
+                // A raw string like r#'text'# is taken as written, newlines and quotes included,
+                // up to its closing delimiter
+                if !consume_raw_string(src, &mut token_contents, hashes) {
+                    unclosed_raw_string = Some(format!("'{}", "#".repeat(hashes)));
+                    break;
+                }
+                continue;
+            }
         } else if c == '\'' || c == '"' || c == '`' {
             // We encountered the opening quote of a string literal.
             quote_start = Some(c);
@@ -171,6 +206,15 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
         return (token_contents.spanned(span), Some(cause));
     }
 
+    if let Some(delimiter) = unclosed_raw_string {
+        token_contents.push_str(&delimiter);
+
+        return (
+            token_contents.spanned(span),
+            Some(ParseError::unexpected_eof(delimiter, span)),
+        );
+    }
+
     if let Some(delimiter) = quote_start {
         // The non-lite parse trims quotes on both sides, so we add the expected quote so that
         // anyone wanting to consume this partial parse (e.g., completions) will be able to get
@@ -194,6 +238,81 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
     (token_contents.spanned(span), None)
 }
 
+/// Whether a token part starting here begins a new word, so the `r` of `bar#'` isn't taken for the
+/// start of a raw string
+fn at_word_start(token_contents: &str) -> bool {
+    match token_contents.chars().last() {
+        None => true,
+        Some(c) => c.is_whitespace() || matches!(c, '[' | '(' | '{' | '|' | ';' | ','),
+    }
+}
+
+/// Checks whether the `r` about to be consumed starts a raw string (`r#'`, `r##'` and so on),
+/// returning the number of `#`s in its delimiters
+fn raw_string_hashes(input: &Input) -> Option<usize> {
+    let mut ahead = input.clone();
+    let _ = ahead.next();
+
+    let mut hashes = 0;
+    loop {
+        match ahead.next() {
+            Some((_, '#')) => hashes += 1,
+            Some((_, '\'')) if hashes > 0 => return Some(hashes),
+            _ => return None,
+        }
+    }
+}
+
+/// Consumes a raw string into the token, returning false if the input ends before its closing
+/// delimiter
+fn consume_raw_string(input: &mut Input, token_contents: &mut String, hashes: usize) -> bool {
+    let closing = format!("'{}", "#".repeat(hashes));
+
+    // The opening `r`, `#`s and `'`
+    for _ in 0..hashes + 2 {
+        if let Some((_, c)) = input.next() {
+            token_contents.push(c);
+        }
+    }
+    let body_start = token_contents.len();
+
+    for (_, c) in input {
+        token_contents.push(c);
+
+        if token_contents.len() >= body_start + closing.len() && token_contents.ends_with(&closing)
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Checks whether the `#` about to be consumed starts a `#[ ... ]#` block comment.
+fn starts_block_comment(input: &Input) -> bool {
+    let mut ahead = input.clone();
+    let _ = ahead.next();
+
+    matches!(ahead.next(), Some((_, '[')))
+}
+
+/// We encountered the `#[` of a block comment. Keep consuming characters until the closing
+/// `]#`, returning false if the input ends before it.
+fn skip_block_comment(input: &mut Input) -> bool {
+    let _ = input.next();
+    let _ = input.next();
+
+    let mut prev = '[';
+    for (_, c) in input {
+        if c == '#' && prev == ']' {
+            return true;
+        }
+        prev = c;
+    }
+
+    false
+}
+
 /// We encountered a `#` character. Keep consuming characters until we encounter
 /// a newline character (but don't consume it).
 fn parse_comment(input: &mut Input, hash_offset: usize) -> LiteComment {
@@ -247,11 +366,51 @@ struct BlockParser {
     prev_token: Option<Token>,
     prev_comments: CommentsBuilder,
     prev_comment_indent: usize,
+    /// Whether the last real token was a `|` or an operator waiting for its right-hand side
+    continues: bool,
 }
 
 impl BlockParser {
     fn consumed(&mut self, token: Token) {
-        self.prev_token = Some(token);
+        match &token.contents {
+            TokenContents::Eol | TokenContents::Comment(_) => {}
+            TokenContents::Pipe => self.continues = true,
+            TokenContents::Baseline(part) => {
+                self.continues = CONTINUING_OPERATORS.contains(&part.as_str())
+                    && matches!(
+                        self.prev_token,
+                        Some(Token {
+                            contents: TokenContents::Baseline(_),
+                            ..
+                        })
+                    )
+            }
+            TokenContents::Semicolon => self.continues = false,
+        }
+
+        match (&self.prev_token, &token.contents) {
+            // Trailing comments don't change where we are in the pipeline
+            (_, TokenContents::Comment(_)) if !self.starts_line() => {}
+            // A `|` at the end of a line continues the pipeline past any following
+            // newlines, so keep remembering it until the next real token
+            (
+                Some(Token {
+                    contents: TokenContents::Pipe,
+                    ..
+                }),
+                TokenContents::Eol,
+            ) => {}
+            _ => self.prev_token = Some(token),
+        }
+    }
+
+    /// A comment is documentation for the next command when it's on its own line. Comments
+    /// after a command, eg `ls # list files`, are skipped.
+    fn starts_line(&self) -> bool {
+        match &self.prev_token {
+            None => true,
+            Some(token) => matches!(token.contents, TokenContents::Eol),
+        }
     }
 
     fn success(mut self) -> (LiteBlock, Option<ParseError>) {
@@ -281,12 +440,10 @@ impl BlockParser {
     }
 
     fn eol(&mut self) {
-        // If the last token on the current line is a `|`, the group
+        // If the last token on the current line is a `|` or an operator, the group
         // continues on the next line.
-        if let Some(prev) = &self.prev_token {
-            if let TokenContents::Pipe = prev.contents {
-                return;
-            }
+        if self.continues {
+            return;
         }
 
         self.close_group();
@@ -405,7 +562,11 @@ pub fn parse_block(tokens: Vec<Token>) -> (LiteBlock, Option<ParseError>) {
 
                 parser.baseline(part.to_string().spanned(token.span));
             }
-            TokenContents::Comment(comment) => parser.comment(comment),
+            TokenContents::Comment(comment) => {
+                if parser.starts_line() {
+                    parser.comment(comment)
+                }
+            }
         }
 
         parser.consumed(token.clone());
@@ -414,6 +575,35 @@ pub fn parse_block(tokens: Vec<Token>) -> (LiteBlock, Option<ParseError>) {
     parser.success()
 }
 
+/// Operators that leave an expression waiting for its right-hand side. Operators that also work as
+/// the last argument of a command, like the `-` of `cd -` or the `*` of `ls *`, aren't included.
+const CONTINUING_OPERATORS: &[&str] = &[
+    "==", "!=", "<", "<=", ">", ">=", "=~", "!~", "+", "&&", "||", "??",
+];
+
+/// Checks whether the tokens stop in the middle of a pipeline, after a `|` or after an operator
+/// that still needs its right-hand side, so the rest is expected on the next line.
+pub fn line_continues(tokens: &[Token]) -> bool {
+    let mut tokens = tokens.iter().rev().filter(|token| {
+        !matches!(
+            token.contents,
+            TokenContents::Eol | TokenContents::Comment(_)
+        )
+    });
+
+    match tokens.next().map(|token| &token.contents) {
+        Some(TokenContents::Pipe) => true,
+        Some(TokenContents::Baseline(part)) if CONTINUING_OPERATORS.contains(&part.as_str()) => {
+            // An operator needs a left-hand side too, so a lone `>` isn't waiting for anything
+            matches!(
+                tokens.next().map(|token| &token.contents),
+                Some(TokenContents::Baseline(_))
+            )
+        }
+        _ => false,
+    }
+}
+
 /// Breaks the input string into a vector of tokens. This tokenization only tries to classify separators like
 /// semicolons, pipes, etc from external bare values (values that haven't been classified further)
 /// Takes in a string and and offset, which is used to offset the spans created (for when this function is used to parse inner strings)
@@ -483,6 +673,17 @@ pub fn lex(input: &str, span_offset: usize) -> (Vec<Token>, Option<ParseError>)
                 TokenContents::Eol,
                 Span::new(span_offset + idx, span_offset + idx + 1),
             ));
+        } else if *c == '#' && starts_block_comment(&char_indices) {
+            // A `#[` starts a block comment, which continues until the next `]#`
+            // and is skipped entirely.
+            let idx = *idx;
+
+            if !skip_block_comment(&mut char_indices) && error.is_none() {
+                error = Some(ParseError::unexpected_eof(
+                    "]#".to_string(),
+                    Span::new(span_offset + idx, span_offset + input.len()),
+                ));
+            }
         } else if *c == '#' {
             // If the next character is `#`, we're at the beginning of a line
             // comment. The comment continues until the next newline.
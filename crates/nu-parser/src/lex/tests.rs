@@ -111,6 +111,62 @@ mod bare {
         assert_eq!(result[0].span, span(0, 10));
     }
 
+    #[test]
+    fn escaped_quote_in_double_quotes() {
+        let input = r#""foo \" bar" baz"#;
+
+        let (result, err) = lex(input, 0);
+
+        assert!(err.is_none());
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].span, span(0, 12));
+    }
+
+    #[test]
+    fn backslash_in_single_quotes_is_literal() {
+        let input = r#"'foo\' bar"#;
+
+        let (result, err) = lex(input, 0);
+
+        assert!(err.is_none());
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].span, span(0, 6));
+    }
+
+    #[test]
+    fn raw_string_spans_lines_and_keeps_quotes() {
+        let input = "echo r#'\nselect \"a\", 'b' # not a comment\n'# | save q.sql";
+
+        let (result, err) = lex(input, 0);
+
+        assert!(err.is_none());
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[1].span, span(5, 43));
+    }
+
+    #[test]
+    fn raw_string_closes_with_matching_hashes() {
+        let input = "r##'a '# b'## c";
+
+        let (result, err) = lex(input, 0);
+
+        assert!(err.is_none());
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].span, span(0, 13));
+    }
+
+    #[test]
+    fn unterminated_raw_string() {
+        let input = "echo r#'never closed";
+
+        let (_, err) = lex(input, 0);
+
+        assert!(matches!(
+            err.map(|err| err.reason().clone()),
+            Some(nu_errors::ParseErrorReason::Eof { .. })
+        ));
+    }
+
     #[test]
     fn lex_comment() {
         let input = r#"
@@ -131,6 +187,27 @@ def e [] {echo hi}
         );
     }
 
+    #[test]
+    fn lex_block_comment() {
+        let input = "echo #[ a block\ncomment ]# hi";
+
+        let (result, err) = lex(input, 0);
+        assert!(err.is_none());
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].span, span(0, 4));
+        assert_eq!(result[1].span, span(27, 29));
+    }
+
+    #[test]
+    fn unterminated_block_comment() {
+        let input = "echo #[ never closed";
+
+        let (_, err) = lex(input, 0);
+
+        assert!(err.is_some());
+    }
+
     #[test]
     fn def_comment_with_sinqle_quote() {
         let input = r#"def f [] {
@@ -311,6 +388,40 @@ def my_echo [arg] { echo $arg }
             ])
         );
     }
+    #[test]
+    fn trailing_comment_is_not_documentation() {
+        let code = r#"
+ls # list the files
+echo 42
+        "#;
+        let (result, err) = lex(code, 0);
+        assert!(err.is_none());
+        let (result, err) = parse_block(result);
+        assert!(err.is_none());
+
+        assert_eq!(result.block.len(), 2);
+        assert_eq!(result.block[0].pipelines[0].commands[0].parts.len(), 1);
+        assert_eq!(result.block[1].pipelines[0].commands[0].comments, None);
+    }
+
+    #[test]
+    fn comment_after_trailing_pipe() {
+        let code = r#"
+ls | # only the big ones
+# still the same pipeline
+
+where size > 10kb
+        "#;
+        let (result, err) = lex(code, 0);
+        assert!(err.is_none());
+        let (result, err) = parse_block(result);
+        assert!(err.is_none());
+
+        assert_eq!(result.block.len(), 1);
+        assert_eq!(result.block[0].pipelines.len(), 1);
+        assert_eq!(result.block[0].pipelines[0].commands.len(), 2);
+    }
+
     #[test]
     fn discarded_comment() {
         let code = r#"
@@ -399,3 +510,29 @@ echo 42
         ])
     );
 }
+
+#[test]
+fn lines_ending_in_a_pipe_or_operator_continue() {
+    let continues = |code: &str| line_continues(&lex(code, 0).0);
+
+    assert!(continues("ls |"));
+    assert!(continues("ls | # sorted next\n"));
+    assert!(continues("ls | where size >"));
+    assert!(continues("ls | where size > 10kb &&"));
+    assert!(!continues("ls | where size > 10kb"));
+    assert!(!continues("cd -"));
+    assert!(!continues("ls *"));
+    assert!(!continues(">"));
+    assert!(!continues(""));
+}
+
+#[test]
+fn trailing_operator_continues_the_group() {
+    let (result, err) = lex("ls | where size >\n  10kb\necho done", 0);
+    assert!(err.is_none());
+    let (result, err) = parse_block(result);
+    assert!(err.is_none());
+    assert_eq!(result.block.len(), 2);
+    assert_eq!(result.block[0].pipelines[0].commands.len(), 2);
+    assert_eq!(result.block[0].pipelines[0].commands[1].parts.len(), 4);
+}
@@ -11,7 +11,7 @@ mod scope;
 mod shapes;
 mod signature;
 
-pub use lex::lexer::{lex, parse_block};
+pub use lex::lexer::{lex, line_continues, parse_block};
 pub use lex::tokens::{LiteBlock, LiteCommand, LiteGroup, LitePipeline};
 pub use parse::{classify_block, garbage, parse, parse_full_column_path, parse_math_expression};
 pub use path::expand_ndots;
@@ -8,7 +8,9 @@ use nu_protocol::hir::{
     InternalCommand, Member, NamedArguments, Operator, Pipeline, RangeOperator, SpannedExpression,
     Unit,
 };
-use nu_protocol::{NamedType, PositionalType, Signature, SyntaxShape, UnspannedPathMember};
+use nu_protocol::{
+    closest_matches, NamedType, PositionalType, Signature, SyntaxShape, UnspannedPathMember,
+};
 use nu_source::{HasSpan, Span, Spanned, SpannedItem};
 use num_bigint::BigInt;
 
@@ -19,8 +21,12 @@ use crate::scope::ParserScope;
 use bigdecimal::BigDecimal;
 
 use self::{
-    def::{parse_definition, parse_definition_prototype},
+    def::{parse_definition, parse_definition_prototype, parse_signature},
+    util::contains_unquoted,
+    util::raw_string_contents,
     util::trim_quotes,
+    util::unquote_path,
+    util::unquote_string,
     util::verify_and_strip,
 };
 
@@ -35,15 +41,22 @@ pub fn parse_simple_column_path(
 ) -> (SpannedExpression, Option<ParseError>) {
     let mut delimiter = '.';
     let mut inside_delimiter = false;
+    let mut escaped = false;
     let mut output = vec![];
     let mut current_part = String::new();
     let mut start_index = 0;
     let mut last_index = 0;
+    let mut error = None;
 
     for (idx, c) in lite_arg.item.char_indices() {
         last_index = idx;
         if inside_delimiter {
-            if c == delimiter {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && delimiter == '"' {
+                // A backslash escapes the next character of a double-quoted name, eg `"a\"b"`
+                escaped = true;
+            } else if c == delimiter {
                 inside_delimiter = false;
             }
         } else if c == '\'' || c == '"' || c == '`' {
@@ -55,11 +68,14 @@ pub fn parse_simple_column_path(
                 lite_arg.span.start() + idx,
             );
 
-            if let Ok(row_number) = current_part.parse::<u64>() {
+            if let Ok(row_number) = current_part.parse::<i64>() {
                 output.push(Member::Int(BigInt::from(row_number), part_span));
             } else {
-                let trimmed = trim_quotes(&current_part);
-                output.push(Member::Bare(trimmed.clone().spanned(part_span)));
+                let (unquoted, err) = unquote_string(&current_part.clone().spanned(part_span));
+                if error.is_none() {
+                    error = err;
+                }
+                output.push(Member::Bare(unquoted.spanned(part_span)));
             }
             current_part.clear();
             // Note: I believe this is safe because of the delimiter we're using, but if we get fancy with
@@ -75,17 +91,20 @@ pub fn parse_simple_column_path(
             lite_arg.span.start() + start_index,
             lite_arg.span.start() + last_index + 1,
         );
-        if let Ok(row_number) = current_part.parse::<u64>() {
+        if let Ok(row_number) = current_part.parse::<i64>() {
             output.push(Member::Int(BigInt::from(row_number), part_span));
         } else {
-            let current_part = trim_quotes(&current_part);
-            output.push(Member::Bare(current_part.spanned(part_span)));
+            let (unquoted, err) = unquote_string(&current_part.spanned(part_span));
+            if error.is_none() {
+                error = err;
+            }
+            output.push(Member::Bare(unquoted.spanned(part_span)));
         }
     }
 
     (
         SpannedExpression::new(Expression::simple_column_path(output), lite_arg.span),
-        None,
+        error,
     )
 }
 
@@ -96,6 +115,7 @@ pub fn parse_full_column_path(
 ) -> (SpannedExpression, Option<ParseError>) {
     let mut delimiter = '.';
     let mut inside_delimiter = false;
+    let mut escaped = false;
     let mut output = vec![];
     let mut current_part = String::new();
     let mut start_index = 0;
@@ -107,13 +127,18 @@ pub fn parse_full_column_path(
     for (idx, c) in lite_arg.item.char_indices() {
         last_index = idx;
         if inside_delimiter {
-            if c == delimiter {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && delimiter == '"' {
+                // A backslash escapes the next character of a double-quoted name, eg `"a\"b"`
+                escaped = true;
+            } else if c == delimiter {
                 inside_delimiter = false;
             }
         } else if c == '(' {
             inside_delimiter = true;
             delimiter = ')';
-        } else if c == '\'' || c == '"' {
+        } else if c == '\'' || c == '"' || c == '`' {
             inside_delimiter = true;
             delimiter = c;
         } else if c == '.' {
@@ -129,18 +154,30 @@ pub fn parse_full_column_path(
                     error = err;
                 }
                 head = Some(invoc.expr);
+            } else if head.is_none() && current_part.starts_with('(') && current_part.ends_with(')')
+            {
+                let (subexpr, err) = parse_parenthesized_expression(
+                    &current_part.clone().spanned(part_span),
+                    scope,
+                    false,
+                );
+                if error.is_none() {
+                    error = err;
+                }
+                head = Some(subexpr.expr);
             } else if head.is_none() && current_part.starts_with('$') {
                 // We have the variable head
                 head = Some(Expression::variable(current_part.clone(), part_span))
-            } else if let Ok(row_number) = current_part.parse::<u64>() {
+            } else if let Ok(row_number) = current_part.parse::<i64>() {
                 output.push(
                     UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
                 );
             } else {
-                let current_part = trim_quotes(&current_part);
-                output.push(
-                    UnspannedPathMember::String(current_part.clone()).into_path_member(part_span),
-                );
+                let (unquoted, err) = unquote_string(&current_part.clone().spanned(part_span));
+                if error.is_none() {
+                    error = err;
+                }
+                output.push(UnspannedPathMember::String(unquoted).into_path_member(part_span));
             }
             current_part.clear();
             // Note: I believe this is safe because of the delimiter we're using, but if we get fancy with
@@ -164,23 +201,36 @@ pub fn parse_full_column_path(
                     error = err;
                 }
                 head = Some(invoc.expr);
+            } else if current_part.starts_with('(') && current_part.ends_with(')') {
+                let (subexpr, err) =
+                    parse_parenthesized_expression(&current_part.spanned(part_span), scope, false);
+                if error.is_none() {
+                    error = err;
+                }
+                head = Some(subexpr.expr);
             } else if current_part.starts_with('$') {
                 head = Some(Expression::variable(current_part, lite_arg.span));
-            } else if let Ok(row_number) = current_part.parse::<u64>() {
+            } else if let Ok(row_number) = current_part.parse::<i64>() {
                 output.push(
                     UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
                 );
             } else {
-                let current_part = trim_quotes(&current_part);
-                output.push(UnspannedPathMember::String(current_part).into_path_member(part_span));
+                let (unquoted, err) = unquote_string(&current_part.spanned(part_span));
+                if error.is_none() {
+                    error = err;
+                }
+                output.push(UnspannedPathMember::String(unquoted).into_path_member(part_span));
             }
-        } else if let Ok(row_number) = current_part.parse::<u64>() {
+        } else if let Ok(row_number) = current_part.parse::<i64>() {
             output.push(
                 UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
             );
         } else {
-            let current_part = trim_quotes(&current_part);
-            output.push(UnspannedPathMember::String(current_part).into_path_member(part_span));
+            let (unquoted, err) = unquote_string(&current_part.spanned(part_span));
+            if error.is_none() {
+                error = err;
+            }
+            output.push(UnspannedPathMember::String(unquoted).into_path_member(part_span));
         }
     }
 
@@ -309,6 +359,12 @@ fn parse_operator(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<Pars
         "&&" => Operator::And,
         "||" => Operator::Or,
         "**" => Operator::Pow,
+        "??" => Operator::Coalesce,
+        "bit-and" => Operator::BitAnd,
+        "bit-or" => Operator::BitOr,
+        "bit-xor" => Operator::BitXor,
+        "bit-shl" => Operator::ShiftLeft,
+        "bit-shr" => Operator::ShiftRight,
         _ => {
             return (
                 garbage(lite_arg.span),
@@ -450,7 +506,7 @@ fn parse_dollar_expr(
         //Return invocation
         trace!("Parsing invocation expression");
         parse_invocation(lite_arg, scope)
-    } else if lite_arg.item.contains("..") {
+    } else if contains_unquoted(&lite_arg.item, "..") {
         parse_range(lite_arg, scope)
     } else if lite_arg.item.contains('.') {
         trace!("Parsing path expression");
@@ -634,7 +690,19 @@ fn parse_external_arg(
         return parse_dollar_expr(&lite_arg, scope);
     }
 
-    if lite_arg.item.starts_with('`') && lite_arg.item.len() > 1 && lite_arg.item.ends_with('`') {
+    if lite_arg.item.starts_with('(') && lite_arg.item.ends_with(')') {
+        return parse_parenthesized_expression(&lite_arg, scope, false);
+    }
+
+    if let Some(raw) = raw_string_contents(&lite_arg.item) {
+        (
+            SpannedExpression::new(Expression::string(raw), lite_arg.span),
+            None,
+        )
+    } else if lite_arg.item.starts_with('`')
+        && lite_arg.item.len() > 1
+        && lite_arg.item.ends_with('`')
+    {
         // This is an interpolated string
         parse_interpolated_string(&lite_arg, scope)
     } else {
@@ -752,6 +820,15 @@ fn parse_arg(
         return parse_dollar_expr(&lite_arg, scope);
     }
 
+    if lite_arg.item.starts_with('(') {
+        // A subexpression, possibly followed by a cell path, eg `(ls).0.name`
+        return if lite_arg.item.ends_with(')') {
+            parse_parenthesized_expression(&lite_arg, scope, false)
+        } else {
+            parse_full_column_path(&lite_arg, scope)
+        };
+    }
+
     match expected_type {
         SyntaxShape::Number => {
             if let Ok(x) = lite_arg.item.parse::<BigInt>() {
@@ -792,28 +869,36 @@ fn parse_arg(
                 // This is an interpolated string
                 parse_interpolated_string(&lite_arg, scope)
             } else {
-                let trimmed = trim_quotes(&lite_arg.item);
+                let (unquoted, err) = unquote_string(&lite_arg);
                 (
-                    SpannedExpression::new(Expression::string(trimmed), lite_arg.span),
-                    None,
+                    SpannedExpression::new(Expression::string(unquoted), lite_arg.span),
+                    err,
                 )
             }
         }
         SyntaxShape::GlobPattern => {
-            let trimmed = trim_quotes(&lite_arg.item);
-            let expanded = expand_path(&trimmed).to_string();
-            (
-                SpannedExpression::new(Expression::glob_pattern(expanded), lite_arg.span),
-                None,
-            )
+            let unquoted = unquote_path(&lite_arg);
+            let expanded = expand_path(&unquoted).to_string();
+
+            // Quoting a path opts out of glob expansion
+            let quoted = lite_arg
+                .item
+                .starts_with(|c| c == '"' || c == '\'' || c == '`');
+            let expr = if quoted {
+                Expression::string(expanded)
+            } else {
+                Expression::glob_pattern(expanded)
+            };
+
+            (SpannedExpression::new(expr, lite_arg.span), None)
         }
 
         SyntaxShape::Range => parse_range(&lite_arg, scope),
         SyntaxShape::Operator => parse_operator(&lite_arg),
         SyntaxShape::Unit => parse_unit(&lite_arg),
         SyntaxShape::FilePath => {
-            let trimmed = trim_quotes(&lite_arg.item);
-            let expanded = expand_path(&trimmed).to_string();
+            let unquoted = unquote_path(&lite_arg);
+            let expanded = expand_path(&unquoted).to_string();
             let path = Path::new(&expanded);
             (
                 SpannedExpression::new(Expression::FilePath(path.to_path_buf()), lite_arg.span),
@@ -905,8 +990,15 @@ fn parse_arg(
                     // We have a literal block
                     let string: String = chars.collect();
 
+                    let (params, consumed, err) =
+                        parse_block_params(&string, lite_arg.span.start() + 1);
+                    if err.is_some() {
+                        return (garbage(lite_arg.span), err);
+                    }
+
                     // We haven't done much with the inner string, so let's go ahead and work with it
-                    let (tokens, err) = lex(&string, lite_arg.span.start() + 1);
+                    let (tokens, err) =
+                        lex(&string[consumed..], lite_arg.span.start() + 1 + consumed);
                     if err.is_some() {
                         return (garbage(lite_arg.span), err);
                     }
@@ -917,9 +1009,13 @@ fn parse_arg(
                     }
 
                     scope.enter_scope();
-                    let (classified_block, err) = classify_block(&lite_block, scope);
+                    let (mut classified_block, err) = classify_block(&lite_block, scope);
                     scope.exit_scope();
 
+                    if let Some(params) = params {
+                        classified_block.params = params;
+                    }
+
                     (
                         SpannedExpression::new(Expression::Block(classified_block), lite_arg.span),
                         err,
@@ -938,6 +1034,50 @@ fn parse_arg(
     }
 }
 
+/// Parses the explicit parameter list at the start of a block's contents, eg the `|x, y|` in
+/// `{|x, y| = $x + $y }`. Returns the parameters, if any, and how many bytes of the contents
+/// they take up
+fn parse_block_params(
+    contents: &str,
+    offset: usize,
+) -> (Option<Signature>, usize, Option<ParseError>) {
+    let trimmed = contents.trim_start();
+    if !trimmed.starts_with('|') {
+        return (None, 0, None);
+    }
+
+    let start = contents.len() - trimmed.len();
+    let end = match trimmed[1..].find('|') {
+        Some(idx) => start + idx + 2,
+        None => {
+            return (
+                None,
+                contents.len(),
+                Some(ParseError::unexpected_eof(
+                    "closing '|' of the block parameters",
+                    Span::new(offset + start, offset + contents.len()),
+                )),
+            )
+        }
+    };
+
+    // The parameters use the same syntax as a definition's signature, so reuse its parser
+    let params = format!("[{}]", &contents[start + 1..end - 1])
+        .spanned(Span::new(offset + start, offset + end));
+    let (mut signature, err) = parse_signature("<block>", &params);
+
+    // Block parameters are bound as variables, so they are named the way they are used
+    for (param, _) in signature.positional.iter_mut() {
+        match param {
+            PositionalType::Mandatory(name, _) | PositionalType::Optional(name, _) => {
+                name.insert(0, '$');
+            }
+        }
+    }
+
+    (Some(signature), end, err)
+}
+
 /// Match the available flags in a signature with what the user provided. This will check both long-form flags (--long) and shorthand flags (-l)
 /// This also allows users to provide a group of shorthand flags (-la) that correspond to multiple shorthand flags at once.
 fn get_flags_from_flag(
@@ -958,9 +1098,14 @@ fn get_flags_from_flag(
             if let Some((named_type, _)) = signature.named.get(&remainder) {
                 output.push((remainder.clone(), named_type.clone()));
             } else {
+                let suggestion = closest_matches(signature.named.keys().cloned(), &remainder)
+                    .into_iter()
+                    .next()
+                    .map(|name| format!("--{}", name));
+
                 error = Some(ParseError::argument_error(
                     cmd.clone(),
-                    ArgumentError::UnexpectedFlag(arg.clone()),
+                    ArgumentError::UnexpectedFlag(arg.clone(), suggestion),
                 ));
             }
         } else {
@@ -983,6 +1128,7 @@ fn get_flags_from_flag(
                             arg.item
                                 .clone()
                                 .spanned(Span::new(starting_pos, starting_pos + c.len_utf8())),
+                            None,
                         ),
                     ));
                 }
@@ -998,6 +1144,27 @@ fn get_flags_from_flag(
     }
 }
 
+/// Splits a long flag written as `--name=value` into the flag and its value, keeping the spans
+/// of both so errors still point at the right place. Short flags and flags without an `=` are
+/// left alone.
+fn split_flag_value(arg: &Spanned<String>) -> Option<(Spanned<String>, Spanned<String>)> {
+    if !arg.item.starts_with("--") {
+        return None;
+    }
+
+    let eq = arg.item.find('=')?;
+    let start = arg.span.start();
+
+    let flag = arg.item[..eq]
+        .to_string()
+        .spanned(Span::new(start, start + eq));
+    let value = arg.item[eq + 1..]
+        .to_string()
+        .spanned(Span::new(start + eq + 1, arg.span.end()));
+
+    Some((flag, value))
+}
+
 /// This is a bit of a "fix-up" of previously parsed areas. In cases where we're in shorthand mode (eg in the `where` command), we need
 /// to use the original source to parse a column path. Without it, we'll lose a little too much information to parse it correctly. As we'll
 /// only know we were on the left-hand side of an expression after we do the full math parse, we need to do this step after rather than during
@@ -1020,6 +1187,32 @@ fn shorthand_reparse(
     }
 }
 
+/// Both sides of `&&` and `||` are conditions on their own, so in shorthand mode a bare word on the
+/// right-hand side is a column too, eg the `active` in `where size > 10kb && active`
+fn shorthand_reparse_condition(
+    right: SpannedExpression,
+    orig_right: Option<Spanned<String>>,
+    op: &SpannedExpression,
+    scope: &dyn ParserScope,
+    shorthand_mode: bool,
+) -> (SpannedExpression, Option<ParseError>) {
+    let logical = matches!(
+        op.expr,
+        Expression::Literal(hir::Literal::Operator(Operator::And))
+            | Expression::Literal(hir::Literal::Operator(Operator::Or))
+    );
+    let bare_word = matches!(right.expr, Expression::Literal(hir::Literal::String(_)));
+
+    if logical && bare_word {
+        shorthand_reparse(right, orig_right, scope, shorthand_mode)
+    } else {
+        (right, None)
+    }
+}
+
+/// Parses a parenthesized subexpression. A lone math expression, like `(1 + 2)`, is parsed in
+/// place, while anything else (eg `(ls | length)`) becomes an invocation that is run when the
+/// expression is evaluated
 fn parse_parenthesized_expression(
     lite_arg: &Spanned<String>,
     scope: &dyn ParserScope,
@@ -1029,7 +1222,6 @@ fn parse_parenthesized_expression(
 
     match (chars.next(), chars.next_back()) {
         (Some('('), Some(')')) => {
-            // We have a literal row
             let string: String = chars.collect();
 
             // We haven't done much with the inner string, so let's go ahead and work with it
@@ -1043,31 +1235,61 @@ fn parse_parenthesized_expression(
                 return (garbage(lite_arg.span), err);
             }
 
-            if lite_block.block.len() != 1 {
-                return (
-                    garbage(lite_arg.span),
-                    Some(ParseError::mismatch("math expression", lite_arg.clone())),
-                );
-            }
-
-            let mut lite_pipeline = lite_block.block[0].clone();
+            if let Some(parts) = math_expression_parts(&lite_block, scope) {
+                let (_, expr, err) = parse_math_expression(0, parts, scope, shorthand_mode);
+                (expr, err)
+            } else {
+                scope.enter_scope();
+                let (classified_block, err) = classify_block(&lite_block, scope);
+                scope.exit_scope();
 
-            let mut collection = vec![];
-            for lite_pipeline in lite_pipeline.pipelines.iter_mut() {
-                for lite_cmd in lite_pipeline.commands.iter_mut() {
-                    collection.append(&mut lite_cmd.parts);
-                }
+                (
+                    SpannedExpression::new(Expression::Invocation(classified_block), lite_arg.span),
+                    err,
+                )
             }
-            let (_, expr, err) = parse_math_expression(0, &collection[..], scope, shorthand_mode);
-            (expr, err)
         }
         _ => (
             garbage(lite_arg.span),
-            Some(ParseError::mismatch("table", lite_arg.clone())),
+            Some(ParseError::mismatch("subexpression", lite_arg.clone())),
         ),
     }
 }
 
+/// Returns the parts of the block if it is a single math expression rather than a pipeline
+/// that has to be run
+fn math_expression_parts<'a>(
+    lite_block: &'a LiteBlock,
+    scope: &dyn ParserScope,
+) -> Option<&'a [Spanned<String>]> {
+    if lite_block.block.len() != 1 || lite_block.block[0].pipelines.len() != 1 {
+        return None;
+    }
+
+    let pipeline = &lite_block.block[0].pipelines[0];
+    if pipeline.commands.len() != 1 {
+        return None;
+    }
+
+    // `(size > 10)` is math even though `size` is also a command, while `(ls -a)` is a call
+    let parts = &pipeline.commands[0].parts;
+    match parts.get(1) {
+        Some(op) if parse_operator(op).1.is_none() => Some(parts),
+        Some(_) => None,
+        None => {
+            let head = parts.get(0)?;
+            if head.item.starts_with('^')
+                || scope.get_signature(&head.item).is_some()
+                || scope.get_alias(&head.item).is_some()
+            {
+                None
+            } else {
+                Some(parts)
+            }
+        }
+    }
+}
+
 fn parse_possibly_parenthesized(
     lite_arg: &Spanned<String>,
     scope: &dyn ParserScope,
@@ -1076,7 +1298,7 @@ fn parse_possibly_parenthesized(
     (Option<Spanned<String>>, SpannedExpression),
     Option<ParseError>,
 ) {
-    if lite_arg.item.starts_with('(') {
+    if lite_arg.item.starts_with('(') && lite_arg.item.ends_with(')') {
         let (lhs, err) = parse_parenthesized_expression(lite_arg, scope, shorthand_mode);
         ((None, lhs), err)
     } else {
@@ -1170,7 +1392,7 @@ pub fn parse_math_expression(
                 working_exprs,
                 prec
             );
-            let (_, right) = working_exprs.pop().expect("This shouldn't be possible");
+            let (orig_right, right) = working_exprs.pop().expect("This shouldn't be possible");
             let (_, op) = working_exprs.pop().expect("This shouldn't be possible");
             let (orig_left, left) = working_exprs.pop().expect("This shouldn't be possible");
 
@@ -1179,6 +1401,11 @@ pub fn parse_math_expression(
             if error.is_none() {
                 error = err;
             }
+            let (right, err) =
+                shorthand_reparse_condition(right, orig_right, &op, scope, shorthand_mode);
+            if error.is_none() {
+                error = err;
+            }
 
             let span = Span::new(left.span.start(), right.span.end());
             working_exprs.push((
@@ -1199,7 +1426,7 @@ pub fn parse_math_expression(
 
     while working_exprs.len() >= 3 {
         // Pop 3 and create and expression, push and repeat
-        let (_, right) = working_exprs.pop().expect("This shouldn't be possible");
+        let (orig_right, right) = working_exprs.pop().expect("This shouldn't be possible");
         let (_, op) = working_exprs.pop().expect("This shouldn't be possible");
         let (orig_left, left) = working_exprs.pop().expect("This shouldn't be possible");
 
@@ -1207,6 +1434,11 @@ pub fn parse_math_expression(
         if error.is_none() {
             error = err;
         }
+        let (right, err) =
+            shorthand_reparse_condition(right, orig_right, &op, scope, shorthand_mode);
+        if error.is_none() {
+            error = err;
+        }
 
         let span = Span::new(left.span.start(), right.span.end());
         working_exprs.push((
@@ -1368,13 +1600,36 @@ fn parse_internal_command(
 
     while idx < lite_cmd.parts.len() {
         if lite_cmd.parts[idx].item.starts_with('-') && lite_cmd.parts[idx].item.len() > 1 {
-            let (named_types, err) =
-                get_flags_from_flag(&signature, &lite_cmd.parts[0], &lite_cmd.parts[idx]);
+            let (flag, inline_value) = match split_flag_value(&lite_cmd.parts[idx]) {
+                Some((flag, value)) => (flag, Some(value)),
+                None => (lite_cmd.parts[idx].clone(), None),
+            };
+
+            let (named_types, err) = get_flags_from_flag(&signature, &lite_cmd.parts[0], &flag);
 
             if err.is_none() {
                 for (full_name, named_type) in &named_types {
-                    match named_type {
-                        NamedType::Mandatory(_, shape) | NamedType::Optional(_, shape) => {
+                    match (named_type, &inline_value) {
+                        (NamedType::Mandatory(_, shape), Some(value))
+                        | (NamedType::Optional(_, shape), Some(value)) => {
+                            // `--name=value`, so the value is already part of this argument
+                            let (arg, err) = parse_arg(*shape, scope, value);
+                            named.insert_mandatory(full_name.clone(), flag.span, arg);
+
+                            if error.is_none() {
+                                error = err;
+                            }
+                        }
+                        (NamedType::Switch(_), Some(value)) => {
+                            if error.is_none() {
+                                error = Some(ParseError::argument_error(
+                                    lite_cmd.parts[0].clone(),
+                                    ArgumentError::UnexpectedArgument(value.clone()),
+                                ));
+                            }
+                        }
+                        (NamedType::Mandatory(_, shape), None)
+                        | (NamedType::Optional(_, shape), None) => {
                             if idx == lite_cmd.parts.len() {
                                 // Oops, we're missing the argument to our named argument
                                 if error.is_none() {
@@ -1404,7 +1659,7 @@ fn parse_internal_command(
                                 }
                             }
                         }
-                        NamedType::Switch(_) => {
+                        (NamedType::Switch(_), None) => {
                             named.insert_switch(
                                 full_name.clone(),
                                 Some(Flag::new(FlagKind::Longhand, lite_cmd.parts[idx].span)),
@@ -1645,6 +1900,10 @@ fn parse_call(
         );
     } else if lite_cmd.parts[0].item.starts_with('$') || lite_cmd.parts[0].item.starts_with('{') {
         return parse_value_call(lite_cmd, scope);
+    } else if lite_cmd.parts[0].item.starts_with('(') {
+        // A subexpression, possibly used in math, eg `(ls | length) * 2`
+        let (_, expr, err) = parse_math_expression(0, &lite_cmd.parts, scope, false);
+        return (Some(ClassifiedCommand::Expr(Box::new(expr))), err);
     } else if lite_cmd.parts[0].item == "=" {
         let expr = if lite_cmd.parts.len() > 1 {
             let (_, expr, err) = parse_math_expression(0, &lite_cmd.parts[1..], scope, false);
@@ -1766,66 +2025,76 @@ fn parse_pipeline(
         }
     }
 
+    // An external command piping into another one hands its output over as it is, without
+    // turning it into values first
+    let next_is_external: Vec<bool> = commands.list.iter().skip(1).map(is_external_call).collect();
+    for (command, next_is_external) in commands.list.iter_mut().zip(next_is_external) {
+        if let ClassifiedCommand::Internal(internal) = command {
+            if next_is_external && internal.name == "run_external" {
+                internal.args.external_redirection = ExternalRedirection::StdoutBytes;
+            }
+        }
+    }
+
     (commands, error)
 }
 
+fn is_external_call(command: &ClassifiedCommand) -> bool {
+    matches!(command, ClassifiedCommand::Internal(internal) if internal.name == "run_external")
+}
+
 type SpannedKeyValue = (Spanned<String>, Spanned<String>);
 
+/// Parses a `NAME=value` part into the variable name and its unquoted value
+fn parse_env_assignment(part: &Spanned<String>) -> Option<SpannedKeyValue> {
+    let mut assignment = part.item.splitn(2, '=');
+    let name = assignment.next()?;
+    let value = assignment.next()?;
+
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+
+    let name_end = part.span.start() + name.len();
+
+    Some((
+        name.to_string()
+            .spanned(Span::new(part.span.start(), name_end)),
+        trim_quotes(value).spanned(Span::new(name_end + 1, part.span.end())),
+    ))
+}
+
+/// Strips the leading `NAME=value` assignments (eg `FOO=bar BAZ=qux cmd`) from the first
+/// command of the pipeline, returning them so the pipeline can be run with those variables
+/// set for its duration only
 fn expand_shorthand_forms(
     lite_pipeline: &LitePipeline,
-) -> (LitePipeline, Option<SpannedKeyValue>, Option<ParseError>) {
-    if !lite_pipeline.commands.is_empty() {
-        if lite_pipeline.commands[0].parts[0].item == "=" {
-            (lite_pipeline.clone(), None, None)
-        } else if lite_pipeline.commands[0].parts[0].contains('=') {
-            let assignment: Vec<_> = lite_pipeline.commands[0].parts[0].split('=').collect();
-            if assignment.len() != 2 {
-                (
+) -> (LitePipeline, Vec<SpannedKeyValue>, Option<ParseError>) {
+    let mut expanded = lite_pipeline.clone();
+    let mut vars = vec![];
+
+    if let Some(command) = expanded.commands.first_mut() {
+        while let Some(var) = command.parts.first().and_then(parse_env_assignment) {
+            command.parts.remove(0);
+            vars.push(var);
+        }
+
+        if command.parts.is_empty() {
+            if let Some((name, value)) = vars.last() {
+                return (
                     lite_pipeline.clone(),
-                    None,
+                    vec![],
                     Some(ParseError::mismatch(
-                        "environment variable assignment",
-                        lite_pipeline.commands[0].parts[0].clone(),
+                        "a command following variable",
+                        format!("{}={}", name.item, value.item)
+                            .spanned(Span::new(name.span.start(), value.span.end())),
                     )),
-                )
-            } else {
-                let original_span = lite_pipeline.commands[0].parts[0].span;
-                let env_value = trim_quotes(assignment[1]);
-
-                let (variable_name, value) = (assignment[0], env_value);
-                let mut lite_pipeline = lite_pipeline.clone();
-
-                if !lite_pipeline.commands[0].parts.len() > 1 {
-                    let mut new_lite_command_parts = lite_pipeline.commands[0].parts.clone();
-                    new_lite_command_parts.remove(0);
-
-                    lite_pipeline.commands[0].parts = new_lite_command_parts;
-
-                    (
-                        lite_pipeline,
-                        Some((
-                            variable_name.to_string().spanned(original_span),
-                            value.spanned(original_span),
-                        )),
-                        None,
-                    )
-                } else {
-                    (
-                        lite_pipeline.clone(),
-                        None,
-                        Some(ParseError::mismatch(
-                            "a command following variable",
-                            lite_pipeline.commands[0].parts[0].clone(),
-                        )),
-                    )
-                }
+                );
             }
-        } else {
-            (lite_pipeline.clone(), None, None)
         }
-    } else {
-        (lite_pipeline.clone(), None, None)
     }
+
+    (expanded, vars, None)
 }
 
 // pub fn parse_block(lite_block: &LiteBlock, scope: &dyn ParserScope) -> ClassifiedBlock {
@@ -1957,8 +2226,10 @@ pub fn classify_block(
                 error = err;
             }
 
-            let pipeline = if let Some(vars) = vars {
+            let pipeline = if let (Some((first, _)), Some((_, last))) = (vars.first(), vars.last())
+            {
                 let span = pipeline.span();
+                let vars_span = Span::new(first.span.start(), last.span.end());
                 let block = hir::Block::new(
                     Signature::new("<block>"),
                     vec![Group::new(vec![out_pipe.clone()], span)],
@@ -1974,17 +2245,23 @@ pub fn classify_block(
                 );
                 call.positional = Some(vec![
                     SpannedExpression {
-                        expr: Expression::List(vec![
-                            SpannedExpression {
-                                expr: Expression::string(vars.0.item),
-                                span: vars.0.span,
-                            },
-                            SpannedExpression {
-                                expr: Expression::string(vars.1.item),
-                                span: vars.1.span,
-                            },
-                        ]),
-                        span: Span::new(vars.0.span.start(), vars.1.span.end()),
+                        expr: Expression::List(
+                            vars.into_iter()
+                                .flat_map(|(name, value)| {
+                                    vec![
+                                        SpannedExpression {
+                                            expr: Expression::string(name.item),
+                                            span: name.span,
+                                        },
+                                        SpannedExpression {
+                                            expr: Expression::string(value.item),
+                                            span: value.span,
+                                        },
+                                    ]
+                                })
+                                .collect(),
+                        ),
+                        span: vars_span,
                     },
                     SpannedExpression {
                         expr: Expression::Block(block),
@@ -2179,3 +2456,57 @@ fn unit_parse_byte_units() {
         );
     }
 }
+
+#[test]
+fn splits_long_flags_with_values() {
+    let arg = "--length=10".to_string().spanned(Span::new(4, 15));
+    let (flag, value) = split_flag_value(&arg).expect("flag with a value");
+
+    assert_eq!(flag.item, "--length");
+    assert_eq!(flag.span, Span::new(4, 12));
+    assert_eq!(value.item, "10");
+    assert_eq!(value.span, Span::new(13, 15));
+
+    assert!(split_flag_value(&"--length".to_string().spanned(Span::new(0, 8))).is_none());
+    assert!(split_flag_value(&"-l=10".to_string().spanned(Span::new(0, 5))).is_none());
+}
+
+#[test]
+fn quoted_column_path_members() {
+    let arg = r#""weird.dotted.name".x."a\"b".'c d'"#.to_string();
+    let len = arg.len();
+    let (expr, err) = parse_simple_column_path(&arg.spanned(Span::new(0, len)));
+
+    assert_eq!(err, None);
+    match expr.expr {
+        Expression::Literal(hir::Literal::ColumnPath(members)) => {
+            let names: Vec<String> = members
+                .iter()
+                .map(|member| match member {
+                    Member::Bare(name) => name.item.clone(),
+                    other => panic!("unexpected member {:?}", other),
+                })
+                .collect();
+
+            assert_eq!(names, vec!["weird.dotted.name", "x", "a\"b", "c d"]);
+        }
+        other => panic!("expected a column path, got {:?}", other),
+    }
+
+    assert!(contains_unquoted("$it.a..b", ".."));
+    assert!(!contains_unquoted(r#"$it."a..b""#, ".."));
+}
+
+#[test]
+fn paths_are_taken_as_written() {
+    let arg = r#""C:\new\temp""#.to_string();
+    let len = arg.len();
+    let arg = arg.spanned(Span::new(0, len));
+
+    assert_eq!(unquote_path(&arg), r"C:\new\temp");
+    assert_eq!(unquote_string(&arg), ("C:\new\temp".to_string(), None));
+    assert_eq!(
+        unquote_path(&r"r#'C:\new'#".to_string().spanned(Span::new(0, 11))),
+        r"C:\new"
+    );
+}
@@ -5,15 +5,15 @@ use crate::{
 
 use indexmap::IndexMap;
 use nu_errors::ParseError;
-use nu_protocol::hir::Block;
-use nu_source::{HasSpan, SpannedItem};
+use nu_protocol::{hir::Block, SignatureExample};
+use nu_source::{HasSpan, Spanned, SpannedItem};
 
 //use crate::errors::{ParseError, ParseResult};
 use crate::lex::lexer::{lex, parse_block};
 
 use crate::ParserScope;
 
-use self::signature::parse_signature;
+pub(crate) use self::signature::parse_signature;
 
 mod data_structs;
 mod primitives;
@@ -25,26 +25,31 @@ pub(crate) fn parse_definition(call: &LiteCommand, scope: &dyn ParserScope) -> O
     // So our main goal here is to parse the block now that the names and
     // prototypes of adjacent commands are also available
 
-    if call.parts.len() == 4 {
-        if call.parts.len() != 4 {
-            return Some(ParseError::mismatch("definition", call.parts[0].clone()));
+    let (preserves_env, parts) = take_env_flag(&call.parts);
+
+    if parts.len() == 4 {
+        if parts.len() != 4 {
+            return Some(ParseError::mismatch("definition", parts[0].clone()));
         }
 
-        if call.parts[0].item != "def" {
-            return Some(ParseError::mismatch("definition", call.parts[0].clone()));
+        if parts[0].item != "def" {
+            return Some(ParseError::mismatch("definition", parts[0].clone()));
         }
 
-        let name = trim_quotes(&call.parts[1].item);
-        let (mut signature, err) = parse_signature(&name, &call.parts[2]);
+        let name = trim_quotes(&parts[1].item);
+        let (mut signature, err) = parse_signature(&name, &parts[2]);
 
-        //Add commands comments to signature usage
-        signature.usage = call.comments_joined();
+        //Add commands comments to signature usage, extra usage and examples
+        let (usage, extra_usage, examples) = parse_comments(&call.comments_joined());
+        signature.usage = usage;
+        signature.extra_usage = extra_usage;
+        signature.examples = examples;
 
         if err.is_some() {
             return err;
         };
 
-        let mut chars = call.parts[3].chars();
+        let mut chars = parts[3].chars();
         match (chars.next(), chars.next_back()) {
             (Some('{'), Some('}')) => {
                 // We have a literal block
@@ -52,7 +57,7 @@ pub(crate) fn parse_definition(call: &LiteCommand, scope: &dyn ParserScope) -> O
 
                 scope.enter_scope();
 
-                let (tokens, err) = lex(&string, call.parts[3].span.start() + 1);
+                let (tokens, err) = lex(&string, parts[3].span.start() + 1);
                 if err.is_some() {
                     return err;
                 };
@@ -66,12 +71,13 @@ pub(crate) fn parse_definition(call: &LiteCommand, scope: &dyn ParserScope) -> O
 
                 block.params = signature;
                 block.params.name = name;
+                block.preserves_env = preserves_env;
 
                 scope.add_definition(block);
 
                 err
             }
-            _ => Some(ParseError::mismatch("body", call.parts[3].clone())),
+            _ => Some(ParseError::mismatch("body", parts[3].clone())),
         }
     } else {
         Some(ParseError::internal_error(
@@ -80,27 +86,85 @@ pub(crate) fn parse_definition(call: &LiteCommand, scope: &dyn ParserScope) -> O
     }
 }
 
+/// Splits the comments above a definition into its usage, the first paragraph, the extra usage
+/// after it, and its examples. An example is an `Example: <description>` line followed by a
+/// `> <pipeline>` line and, optionally, a `=> <expression>` line with what the pipeline returns.
+fn parse_comments(comments: &str) -> (String, String, Vec<SignatureExample>) {
+    let mut usage = vec![];
+    let mut extra_usage = vec![];
+    let mut examples: Vec<SignatureExample> = vec![];
+    let mut in_usage = true;
+
+    for line in comments.lines() {
+        if let Some(description) = line.strip_prefix("Example:") {
+            in_usage = false;
+            examples.push(SignatureExample {
+                description: description.trim().to_string(),
+                example: String::new(),
+                result: None,
+            });
+        } else if let (Some(example), Some(pipeline)) =
+            (examples.last_mut(), line.strip_prefix('>'))
+        {
+            example.example = pipeline.trim().to_string();
+        } else if let (Some(example), Some(result)) = (examples.last_mut(), line.strip_prefix("=>"))
+        {
+            example.result = Some(result.trim().to_string());
+        } else if in_usage && !line.is_empty() {
+            usage.push(line);
+        } else {
+            in_usage = false;
+            extra_usage.push(line);
+        }
+    }
+
+    examples.retain(|example| !example.example.is_empty());
+
+    (
+        usage.join("\n"),
+        extra_usage.join("\n").trim().to_string(),
+        examples,
+    )
+}
+
 pub(crate) fn parse_definition_prototype(
     call: &LiteCommand,
     scope: &dyn ParserScope,
 ) -> Option<ParseError> {
     let mut err = None;
 
-    if call.parts.len() != 4 {
-        return Some(ParseError::mismatch("definition", call.parts[0].clone()));
+    let (preserves_env, parts) = take_env_flag(&call.parts);
+
+    if parts.len() != 4 {
+        return Some(ParseError::mismatch("definition", parts[0].clone()));
     }
 
-    if call.parts[0].item != "def" {
-        return Some(ParseError::mismatch("definition", call.parts[0].clone()));
+    if parts[0].item != "def" {
+        return Some(ParseError::mismatch("definition", parts[0].clone()));
     }
 
-    let name = trim_quotes(&call.parts[1].item);
-    let (signature, error) = parse_signature(&name, &call.parts[2]);
+    let name = trim_quotes(&parts[1].item);
+    let (signature, error) = parse_signature(&name, &parts[2]);
     if err.is_none() {
         err = error;
     }
 
-    scope.add_definition(Block::new(signature, vec![], IndexMap::new(), call.span()));
+    let mut block = Block::new(signature, vec![], IndexMap::new(), call.span());
+    block.preserves_env = preserves_env;
+    scope.add_definition(block);
 
     err
 }
+
+/// Takes the `--env` flag out of the parts of a definition, saying if it was there
+fn take_env_flag(parts: &[Spanned<String>]) -> (bool, Vec<Spanned<String>>) {
+    let mut parts = parts.to_vec();
+
+    match parts.iter().skip(1).position(|part| part.item == "--env") {
+        Some(index) => {
+            parts.remove(index + 1);
+            (true, parts)
+        }
+        None => (false, parts),
+    }
+}
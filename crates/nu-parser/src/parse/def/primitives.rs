@@ -168,7 +168,7 @@ pub fn parse_type_token(type_: &Token) -> (SyntaxShape, Option<ParseError>) {
             "table" => (SyntaxShape::Table, None),
             "unit" => (SyntaxShape::Unit, None),
             "number" => (SyntaxShape::Number, None),
-            "pattern" => (SyntaxShape::GlobPattern, None),
+            "pattern" | "glob" => (SyntaxShape::GlobPattern, None),
             "range" => (SyntaxShape::Range, None),
             "block" => (SyntaxShape::Block, None),
             "any" => (SyntaxShape::Any, None),
@@ -1,9 +1,9 @@
 #[allow(unused_imports)]
-use super::parse_signature;
+use super::{parse_comments, parse_signature};
 #[allow(unused_imports)]
 use nu_errors::ParseError;
 #[allow(unused_imports)]
-use nu_protocol::{NamedType, PositionalType, Signature, SyntaxShape};
+use nu_protocol::{NamedType, PositionalType, Signature, SignatureExample, SyntaxShape};
 #[allow(unused_imports)]
 use nu_source::{Span, Spanned, SpannedItem};
 #[allow(unused_imports)]
@@ -383,3 +383,36 @@ fn simple_def_with_param_flag_and_rest() {
         Some((SyntaxShape::Table, "Another rest".to_string()))
     );
 }
+
+#[test]
+fn comments_give_usage_extra_usage_and_examples() {
+    let comments = "Doubles a number\n\nWorks on decimals too.\n\nExample: Double two\n> double 2\n=> 4\nExample: Double a decimal\n> double 1.5";
+    let (usage, extra_usage, examples) = parse_comments(comments);
+
+    assert_eq!(usage, "Doubles a number");
+    assert_eq!(extra_usage, "Works on decimals too.");
+    assert_eq!(
+        examples,
+        vec![
+            SignatureExample {
+                description: "Double two".into(),
+                example: "double 2".into(),
+                result: Some("4".into()),
+            },
+            SignatureExample {
+                description: "Double a decimal".into(),
+                example: "double 1.5".into(),
+                result: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn comments_without_a_blank_line_are_all_usage() {
+    let (usage, extra_usage, examples) = parse_comments("My echo\nechoes its argument");
+
+    assert_eq!(usage, "My echo\nechoes its argument");
+    assert_eq!(extra_usage, "");
+    assert!(examples.is_empty());
+}
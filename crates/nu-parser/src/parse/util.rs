@@ -24,6 +24,130 @@ pub(crate) fn trim_quotes(input: &str) -> String {
     }
 }
 
+/// Checks for `needle` outside of any quoted part of `input`, so `$it."a..b"` isn't mistaken for a
+/// range
+pub(crate) fn contains_unquoted(input: &str, needle: &str) -> bool {
+    let mut quote = None;
+    let mut escaped = false;
+
+    for (idx, c) in input.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some('"') if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' || c == '`' => quote = Some(c),
+            None if input[idx..].starts_with(needle) => return true,
+            None => {}
+        }
+    }
+
+    false
+}
+
+/// Strips the quotes around a string literal. Escape sequences in double-quoted strings are
+/// replaced by the characters they stand for, while single-quoted and backtick strings are
+/// taken as written.
+pub(crate) fn unquote_string(input: &Spanned<String>) -> (String, Option<ParseError>) {
+    let item = &input.item;
+
+    if let Some(raw) = raw_string_contents(item) {
+        (raw, None)
+    } else if item.len() > 1 && item.starts_with('"') && item.ends_with('"') {
+        unescape(&item[1..item.len() - 1], input.span.start() + 1)
+    } else {
+        (trim_quotes(item), None)
+    }
+}
+
+/// Strips the quotes around a path or glob pattern, which is taken as written whatever the quotes,
+/// so a Windows path like "C:\new\temp" keeps its backslashes.
+pub(crate) fn unquote_path(input: &Spanned<String>) -> String {
+    raw_string_contents(&input.item).unwrap_or_else(|| trim_quotes(&input.item))
+}
+
+/// The text of a raw string like `r#'text'#`, which is taken as written. A newline right after the
+/// opening delimiter is dropped, so the text can start on a line of its own.
+pub(crate) fn raw_string_contents(input: &str) -> Option<String> {
+    let rest = input.strip_prefix('r')?;
+    let hashes = rest.len() - rest.trim_start_matches('#').len();
+    if hashes == 0 {
+        return None;
+    }
+
+    let closing = format!("'{}", "#".repeat(hashes));
+    let body = rest[hashes..]
+        .strip_prefix('\'')?
+        .strip_suffix(closing.as_str())?;
+    let body = body
+        .strip_prefix("\r\n")
+        .or_else(|| body.strip_prefix('\n'))
+        .unwrap_or(body);
+
+    Some(body.to_string())
+}
+
+/// Replaces `\n`, `\t`, `\r`, `\"`, `\\` and `\u{...}` escapes. Any other backslash is kept
+/// as it is. Paths don't go through here, see `unquote_path`.
+fn unescape(input: &str, offset: usize) -> (String, Option<ParseError>) {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.char_indices();
+
+    while let Some((idx, c)) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some((_, 'n')) => output.push('\n'),
+            Some((_, 't')) => output.push('\t'),
+            Some((_, 'r')) => output.push('\r'),
+            Some((_, '"')) => output.push('"'),
+            Some((_, '\\')) => output.push('\\'),
+            Some((_, 'u')) if input[idx + 2..].starts_with('{') => {
+                let rest = &input[idx + 2..];
+                let code_point = rest.find('}').map(|end| &rest[1..end]);
+
+                match code_point
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(std::char::from_u32)
+                {
+                    Some(unicode) => {
+                        output.push(unicode);
+                        // Skip past the closing `}`
+                        for (_, c) in chars.by_ref() {
+                            if c == '}' {
+                                break;
+                            }
+                        }
+                    }
+                    None => {
+                        let end = code_point.map_or(input.len(), |hex| idx + hex.len() + 4);
+
+                        return (
+                            output,
+                            Some(ParseError::mismatch(
+                                "unicode escape (eg \\u{1F600})",
+                                input[idx..end]
+                                    .to_string()
+                                    .spanned(Span::new(offset + idx, offset + end)),
+                            )),
+                        );
+                    }
+                }
+            }
+            Some((_, other)) => {
+                output.push('\\');
+                output.push(other);
+            }
+            None => output.push('\\'),
+        }
+    }
+
+    (output, None)
+}
+
 pub(crate) fn verify_and_strip(
     contents: &Spanned<String>,
     left: char,
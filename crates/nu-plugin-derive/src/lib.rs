@@ -0,0 +1,295 @@
+//! Derive macros for `nu-plugin`. Use them through the re-exports in `nu_plugin`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, GenericArgument, Lit, Meta,
+    NestedMeta, PathArguments, Type,
+};
+
+/// Derives `nu_plugin::CallArgs` for a struct with named fields.
+///
+/// The struct needs a `#[plugin(name = "...")]` attribute, and its doc comment becomes the usage
+/// of the plugin. Each field is an argument named after it, described by its doc comment:
+///
+/// * `bool` fields are switches
+/// * fields marked `#[arg(named)]` or `#[arg(short = 'x')]` are flags taking a value
+/// * a `Vec<T>` field marked `#[arg(rest)]` takes the rest of the positional arguments
+/// * any other field is a positional argument
+///
+/// Flags and positional arguments are optional when the field is an `Option<T>`.
+#[proc_macro_derive(CallArgs, attributes(plugin, arg))]
+pub fn derive_call_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_call_args(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+enum ArgKind {
+    Switch,
+    Named { optional: bool },
+    Positional { optional: bool },
+    Rest,
+}
+
+struct Arg {
+    field: syn::Ident,
+    name: String,
+    desc: String,
+    short: Option<char>,
+    kind: ArgKind,
+    shape_type: Type,
+}
+
+fn expand_call_args(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "CallArgs can only be derived for a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                ident,
+                "CallArgs can only be derived for a struct",
+            ))
+        }
+    };
+
+    let name = plugin_name(&input.attrs)?.ok_or_else(|| {
+        Error::new_spanned(
+            ident,
+            "CallArgs needs the name of the plugin, as in #[plugin(name = \"...\")]",
+        )
+    })?;
+    let usage = doc_comment(&input.attrs);
+
+    let mut args = vec![];
+    for field in fields {
+        let field_ident = field.ident.clone().expect("fields are named");
+        let name = field_ident.to_string().trim_start_matches("r#").to_string();
+
+        let mut named = false;
+        let mut rest = false;
+        let mut short = None;
+
+        for meta in attr_list(&field.attrs, "arg")? {
+            match meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("named") => named = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("rest") => rest = true,
+                NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("short") => {
+                    match &pair.lit {
+                        Lit::Char(c) => {
+                            named = true;
+                            short = Some(c.value());
+                        }
+                        lit => return Err(Error::new_spanned(lit, "expected a char")),
+                    }
+                }
+                meta => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "expected `named`, `rest` or `short = '...'`",
+                    ))
+                }
+            }
+        }
+
+        let (kind, shape_type) = if rest {
+            match inner_type(&field.ty, "Vec") {
+                Some(inner) => (ArgKind::Rest, inner.clone()),
+                None => {
+                    return Err(Error::new_spanned(
+                        &field.ty,
+                        "the rest of the arguments go in a Vec",
+                    ))
+                }
+            }
+        } else if is_bool(&field.ty) {
+            (ArgKind::Switch, field.ty.clone())
+        } else {
+            let (optional, shape_type) = match inner_type(&field.ty, "Option") {
+                Some(inner) => (true, inner.clone()),
+                None => (false, field.ty.clone()),
+            };
+
+            if named {
+                (ArgKind::Named { optional }, shape_type)
+            } else {
+                (ArgKind::Positional { optional }, shape_type)
+            }
+        };
+
+        args.push(Arg {
+            field: field_ident,
+            name,
+            desc: doc_comment(&field.attrs),
+            short,
+            kind,
+            shape_type,
+        });
+    }
+
+    let mut signature = vec![];
+    let mut constructors = vec![];
+    let mut position = 0usize;
+
+    for arg in &args {
+        let Arg {
+            field,
+            name,
+            desc,
+            shape_type,
+            ..
+        } = arg;
+        let short = match arg.short {
+            Some(c) => quote!(Some(#c)),
+            None => quote!(None),
+        };
+        let shape = quote!(<#shape_type as ::nu_plugin::FromValue>::syntax_shape());
+
+        match arg.kind {
+            ArgKind::Switch => {
+                signature.push(quote!(.switch(#name, #desc, #short)));
+                constructors.push(quote!(#field: call_info.args.has(#name)));
+            }
+            ArgKind::Named { optional: true } => {
+                signature.push(quote!(.named(#name, #shape, #desc, #short)));
+                constructors
+                    .push(quote!(#field: ::nu_plugin::sdk::optional_named(call_info, #name)?));
+            }
+            ArgKind::Named { optional: false } => {
+                signature.push(quote!(.required_named(#name, #shape, #desc, #short)));
+                constructors
+                    .push(quote!(#field: ::nu_plugin::sdk::required_named(call_info, #name)?));
+            }
+            ArgKind::Positional { optional: true } => {
+                signature.push(quote!(.optional(#name, #shape, #desc)));
+                constructors.push(
+                    quote!(#field: ::nu_plugin::sdk::optional_positional(call_info, #position)?),
+                );
+                position += 1;
+            }
+            ArgKind::Positional { optional: false } => {
+                signature.push(quote!(.required(#name, #shape, #desc)));
+                constructors.push(
+                    quote!(#field: ::nu_plugin::sdk::required_positional(call_info, #position, #name)?),
+                );
+                position += 1;
+            }
+            ArgKind::Rest => {
+                signature.push(quote!(.rest(#shape, #desc)));
+                constructors
+                    .push(quote!(#field: ::nu_plugin::sdk::rest_positional(call_info, #position)?));
+            }
+        }
+    }
+
+    Ok(quote! {
+        impl ::nu_plugin::CallArgs for #ident {
+            fn signature() -> ::nu_plugin::sdk::Signature {
+                ::nu_plugin::sdk::Signature::build(#name)
+                    .version(env!("CARGO_PKG_VERSION"))
+                    .desc(#usage)
+                    #(#signature)*
+            }
+
+            fn from_call_info(
+                call_info: &::nu_plugin::sdk::CallInfo,
+            ) -> Result<Self, ::nu_plugin::sdk::ShellError> {
+                Ok(#ident {
+                    #(#constructors,)*
+                })
+            }
+        }
+    })
+}
+
+fn plugin_name(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let mut name = None;
+
+    for meta in attr_list(attrs, "plugin")? {
+        match meta {
+            NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("name") => {
+                match &pair.lit {
+                    Lit::Str(s) => name = Some(s.value()),
+                    lit => return Err(Error::new_spanned(lit, "expected a string")),
+                }
+            }
+            meta => return Err(Error::new_spanned(meta, "expected `name = \"...\"`")),
+        }
+    }
+
+    Ok(name)
+}
+
+/// The items of all the `#[name(...)]` attributes
+fn attr_list(attrs: &[Attribute], name: &str) -> syn::Result<Vec<NestedMeta>> {
+    let mut items = vec![];
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(name)) {
+        match attr.parse_meta()? {
+            Meta::List(list) => items.extend(list.nested),
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    format!("expected #[{}(...)]", name),
+                ))
+            }
+        }
+    }
+
+    Ok(items)
+}
+
+/// The lines of the doc comment joined with spaces, the way they read in `help`
+fn doc_comment(attrs: &[Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(pair)) => match pair.lit {
+                Lit::Str(s) => Some(s.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("bool"))
+}
+
+/// The `T` of a `wrapper<T>` type, such as the `String` of an `Option<String>`
+fn inner_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+
+    if segment.ident != wrapper {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
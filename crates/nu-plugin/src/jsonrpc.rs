@@ -20,19 +20,23 @@ impl<T> JsonRpc<T> {
 }
 
 pub fn send_response<T: Serialize>(result: T) {
+    send_response_to(&mut std::io::stdout(), result);
+}
+
+/// Sends a response over the given connection, such as the socket of a remote plugin
+pub fn send_response_to<T: Serialize>(output: &mut dyn Write, result: T) {
     let response = JsonRpc::new("response", result);
     let response_raw = serde_json::to_string(&response);
 
-    let mut stdout = std::io::stdout();
-
     match response_raw {
         Ok(response) => {
-            let _ = writeln!(stdout, "{}", response);
+            let _ = writeln!(output, "{}", response);
         }
         Err(err) => {
-            let _ = writeln!(stdout, "{}", err);
+            let _ = writeln!(output, "{}", err);
         }
     };
+    let _ = output.flush();
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -44,5 +48,8 @@ pub enum NuCommand {
     filter { params: Value },
     end_filter,
     sink { params: (CallInfo, Vec<Value>) },
+    begin_sink { params: CallInfo },
+    sink_value { params: Value },
+    end_sink,
     quit,
 }
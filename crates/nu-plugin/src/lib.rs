@@ -1,6 +1,17 @@
+// Lets the code generated by the derive macros name this crate from inside it too
+extern crate self as nu_plugin;
+
 pub mod jsonrpc;
 mod plugin;
+mod remote;
+pub mod sdk;
 
 pub mod test_helpers;
 
 pub use crate::plugin::{serve_plugin, Plugin};
+pub use crate::remote::{serve_plugin_remote, PluginAddress};
+pub use crate::sdk::{
+    serve_simple_plugin, serve_simple_plugin_remote, CallArgs, FromValue, LabeledError,
+    SimplePlugin,
+};
+pub use nu_plugin_derive::CallArgs;
@@ -1,7 +1,7 @@
-use crate::jsonrpc::{send_response, NuCommand};
+use crate::jsonrpc::{send_response, send_response_to, NuCommand};
 use nu_errors::ShellError;
 use nu_protocol::{CallInfo, ReturnValue, Signature, Value};
-use std::io;
+use std::io::{self, BufRead, Write};
 
 /// The `Plugin` trait defines the API which plugins may use to "hook" into nushell.
 pub trait Plugin {
@@ -34,6 +34,17 @@ pub trait Plugin {
     /// This method requires the plugin `Signature` to be configured without filtering.
     fn sink(&mut self, _call_info: CallInfo, _input: Vec<Value>) {}
 
+    /// `begin_sink` is the first method to be called if the `Signature` of the plugin is configured as a
+    /// streaming sink, instead of `sink`. The `Value`s are then passed to `sink_value` as they arrive, so
+    /// they don't all have to fit in memory. The plugin's stdin carries them, so it can't read from the terminal.
+    fn begin_sink(&mut self, _call_info: CallInfo) {}
+
+    /// `sink_value` is called for every `Value` that is passed to a streaming sink.
+    fn sink_value(&mut self, _input: Value) {}
+
+    /// `end_sink` is the last method to be called for a streaming sink, after all `Value`s are passed in.
+    fn end_sink(&mut self) {}
+
     fn quit(&mut self) {}
 }
 
@@ -86,51 +97,145 @@ pub fn serve_plugin(plugin: &mut dyn Plugin) {
             }
         }
     } else {
-        loop {
-            let mut input = String::new();
-            match io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let command = serde_json::from_str::<NuCommand>(&input);
-                    match command {
-                        Ok(NuCommand::config) => {
-                            send_response(plugin.config());
-                            break;
-                        }
-                        Ok(NuCommand::begin_filter { params }) => {
-                            send_response(plugin.begin_filter(params));
-                        }
-                        Ok(NuCommand::filter { params }) => {
-                            send_response(plugin.filter(params));
-                        }
-                        Ok(NuCommand::end_filter) => {
-                            send_response(plugin.end_filter());
-                            break;
-                        }
-                        Ok(NuCommand::sink { params }) => {
-                            plugin.sink(params.0, params.1);
-                            break;
-                        }
-                        Ok(NuCommand::quit) => {
-                            plugin.quit();
-                            break;
-                        }
-                        e => {
-                            send_response(ShellError::untagged_runtime_error(format!(
+        let stdin = io::stdin();
+        serve_session(plugin, &mut stdin.lock(), &mut io::stdout());
+    }
+}
+
+/// Answers the requests of one run of the plugin, until the one that ends it
+pub(crate) fn serve_session(
+    plugin: &mut dyn Plugin,
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) {
+    loop {
+        let mut line = String::new();
+        match input.read_line(&mut line) {
+            Ok(_) => {
+                let command = serde_json::from_str::<NuCommand>(&line);
+                match command {
+                    Ok(NuCommand::config) => {
+                        send_response_to(output, plugin.config());
+                        break;
+                    }
+                    Ok(NuCommand::begin_filter { params }) => {
+                        send_response_to(output, plugin.begin_filter(params));
+                    }
+                    Ok(NuCommand::filter { params }) => {
+                        send_response_to(output, plugin.filter(params));
+                    }
+                    Ok(NuCommand::end_filter) => {
+                        send_response_to(output, plugin.end_filter());
+                        break;
+                    }
+                    Ok(NuCommand::sink { params }) => {
+                        plugin.sink(params.0, params.1);
+                        break;
+                    }
+                    Ok(NuCommand::begin_sink { params }) => {
+                        plugin.begin_sink(params);
+                    }
+                    Ok(NuCommand::sink_value { params }) => {
+                        plugin.sink_value(params);
+                    }
+                    Ok(NuCommand::end_sink) => {
+                        plugin.end_sink();
+                        break;
+                    }
+                    Ok(NuCommand::quit) => {
+                        plugin.quit();
+                        break;
+                    }
+                    e => {
+                        send_response_to(
+                            output,
+                            ShellError::untagged_runtime_error(format!(
                                 "Could not handle plugin message: {} {:?}",
-                                input, e
-                            )));
-                            break;
-                        }
+                                line, e
+                            )),
+                        );
+                        break;
                     }
                 }
-                e => {
-                    send_response(ShellError::untagged_runtime_error(format!(
+            }
+            e => {
+                send_response_to(
+                    output,
+                    ShellError::untagged_runtime_error(format!(
                         "Could not handle plugin message: {:?}",
                         e,
-                    )));
-                    break;
-                }
+                    )),
+                );
+                break;
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{serve_session, Plugin};
+    use crate::jsonrpc::JsonRpc;
+    use nu_errors::ShellError;
+    use nu_protocol::{CallInfo, EvaluatedArgs, Signature, UntaggedValue, Value};
+    use nu_source::Tag;
+    use serde::Serialize;
+
+    /// A streaming sink that keeps note of what it's given
+    #[derive(Default)]
+    struct Recorder {
+        calls: Vec<String>,
+    }
+
+    impl Plugin for Recorder {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("recorder"))
+        }
+
+        fn begin_sink(&mut self, call_info: CallInfo) {
+            self.calls
+                .push(format!("begin {:?}", call_info.args.positional));
+        }
+
+        fn sink_value(&mut self, input: Value) {
+            self.calls.push(input.as_string().expect("a string"));
+        }
+
+        fn end_sink(&mut self) {
+            self.calls.push("end".to_string());
+        }
+    }
+
+    fn request<T: Serialize>(method: &str, params: T) -> String {
+        let request = serde_json::to_string(&JsonRpc::new(method, params)).expect("a request");
+        format!("{}\n", request)
+    }
+
+    fn string(s: &str) -> Value {
+        UntaggedValue::string(s).into_value(Tag::unknown())
+    }
+
+    #[test]
+    fn a_streaming_sink_gets_the_values_one_at_a_time() {
+        let call_info = CallInfo {
+            args: EvaluatedArgs::default(),
+            name_tag: Tag::unknown(),
+        };
+        let input = [
+            request("begin_sink", call_info),
+            request("sink_value", string("one")),
+            request("sink_value", string("two")),
+            request("end_sink", Vec::<Value>::new()),
+            // The session is over once the sink has ended
+            request("sink_value", string("three")),
+        ]
+        .concat();
+
+        let mut plugin = Recorder::default();
+        let mut output = vec![];
+        serve_session(&mut plugin, &mut input.as_bytes(), &mut output);
+
+        assert_eq!(plugin.calls, vec!["begin None", "one", "two", "end"]);
+        assert!(output.is_empty());
+    }
+}
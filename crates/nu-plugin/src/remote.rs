@@ -0,0 +1,152 @@
+use crate::plugin::serve_session;
+use crate::Plugin;
+use nu_errors::ShellError;
+use std::io::{self, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Where a plugin served over a connection can be reached, instead of running it as a process.
+///
+/// Written as `tcp://<host>:<port>` or `unix://<path of the socket>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginAddress {
+    Tcp(String),
+    Unix(PathBuf),
+}
+
+impl PluginAddress {
+    /// Whether the plugin registration is an address rather than the path of a plugin binary
+    pub fn is_remote(registration: &str) -> bool {
+        registration.contains("://")
+    }
+
+    pub fn parse(address: &str) -> Result<PluginAddress, ShellError> {
+        if let Some(host) = address.strip_prefix("tcp://") {
+            Ok(PluginAddress::Tcp(host.to_string()))
+        } else if let Some(path) = address.strip_prefix("unix://") {
+            Ok(PluginAddress::Unix(PathBuf::from(path)))
+        } else {
+            Err(ShellError::untagged_runtime_error(format!(
+                "{} is not a plugin address, which starts with tcp:// or unix://",
+                address
+            )))
+        }
+    }
+
+    /// Opens a connection to the plugin, giving back the side to read its responses from and the
+    /// side to send the requests to
+    pub fn connect(&self) -> io::Result<(Box<dyn Read + Send>, Box<dyn Write + Send>)> {
+        match self {
+            PluginAddress::Tcp(host) => {
+                let stream = TcpStream::connect(host)?;
+                Ok((Box::new(stream.try_clone()?), Box::new(stream)))
+            }
+            #[cfg(unix)]
+            PluginAddress::Unix(path) => {
+                let stream = std::os::unix::net::UnixStream::connect(path)?;
+                Ok((Box::new(stream.try_clone()?), Box::new(stream)))
+            }
+            #[cfg(not(unix))]
+            PluginAddress::Unix(_) => Err(unix_sockets_unsupported()),
+        }
+    }
+}
+
+impl std::fmt::Display for PluginAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginAddress::Tcp(host) => write!(f, "tcp://{}", host),
+            PluginAddress::Unix(path) => write!(f, "unix://{}", path.display()),
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn unix_sockets_unsupported() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Other,
+        "unix sockets aren't supported on this platform",
+    )
+}
+
+/// Serves the plugin at the given address, so the shells that have it registered there can all
+/// use it. Each time one of them runs the plugin it gets a connection of its own, with a new
+/// plugin from `new_plugin`, so the runs don't share any state.
+///
+/// This doesn't return unless the address can't be listened on.
+pub fn serve_plugin_remote<P, F>(address: &str, new_plugin: F) -> Result<(), ShellError>
+where
+    P: Plugin,
+    F: Fn() -> P + Send + Sync + 'static,
+{
+    let new_plugin = Arc::new(new_plugin);
+    let cannot_listen = |err: io::Error| {
+        ShellError::untagged_runtime_error(format!("Can't listen on {}: {}", address, err))
+    };
+
+    match PluginAddress::parse(address)? {
+        PluginAddress::Tcp(host) => {
+            let listener = TcpListener::bind(host).map_err(cannot_listen)?;
+            for stream in listener.incoming().flatten() {
+                let reader = stream.try_clone();
+                serve_connection(&new_plugin, reader, stream);
+            }
+        }
+        #[cfg(unix)]
+        PluginAddress::Unix(path) => {
+            let listener = std::os::unix::net::UnixListener::bind(path).map_err(cannot_listen)?;
+            for stream in listener.incoming().flatten() {
+                let reader = stream.try_clone();
+                serve_connection(&new_plugin, reader, stream);
+            }
+        }
+        #[cfg(not(unix))]
+        PluginAddress::Unix(_) => return Err(cannot_listen(unix_sockets_unsupported())),
+    }
+
+    Ok(())
+}
+
+fn serve_connection<P, F, S>(new_plugin: &Arc<F>, reader: io::Result<S>, mut writer: S)
+where
+    P: Plugin,
+    F: Fn() -> P + Send + Sync + 'static,
+    S: Read + Write + Send + 'static,
+{
+    let mut reader = match reader {
+        Ok(reader) => BufReader::new(reader),
+        Err(_) => return,
+    };
+    let new_plugin = new_plugin.clone();
+
+    std::thread::spawn(move || {
+        let mut plugin = new_plugin();
+        serve_session(&mut plugin, &mut reader, &mut writer);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluginAddress;
+    use std::path::PathBuf;
+
+    #[test]
+    fn parses_tcp_and_unix_addresses() {
+        assert_eq!(
+            PluginAddress::parse("tcp://localhost:4000").expect("a tcp address"),
+            PluginAddress::Tcp("localhost:4000".to_string())
+        );
+        assert_eq!(
+            PluginAddress::parse("unix:///run/nu/plugin.sock").expect("a unix address"),
+            PluginAddress::Unix(PathBuf::from("/run/nu/plugin.sock"))
+        );
+        assert!(PluginAddress::parse("http://localhost:4000").is_err());
+    }
+
+    #[test]
+    fn tells_addresses_from_paths() {
+        assert!(PluginAddress::is_remote("tcp://localhost:4000"));
+        assert!(!PluginAddress::is_remote("/usr/bin/nu_plugin_inc"));
+    }
+}
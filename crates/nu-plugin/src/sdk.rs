@@ -0,0 +1,408 @@
+//! A higher level way to write a filter plugin.
+//!
+//! Instead of implementing `Plugin`, describe the arguments with a struct that derives `CallArgs`
+//! and implement `SimplePlugin` to turn each input value into an output value:
+//!
+//! ```ignore
+//! use nu_plugin::{serve_simple_plugin, CallArgs, LabeledError, SimplePlugin};
+//! use nu_protocol::{UntaggedValue, Value};
+//!
+//! /// Repeat strings a number of times.
+//! #[derive(CallArgs)]
+//! #[plugin(name = "repeat")]
+//! struct Args {
+//!     /// how many times to repeat them
+//!     times: u64,
+//!     /// what to put between them
+//!     #[arg(short = 's')]
+//!     separator: Option<String>,
+//! }
+//!
+//! struct Repeat;
+//!
+//! impl SimplePlugin for Repeat {
+//!     type Args = Args;
+//!
+//!     fn run(&mut self, args: &Args, input: Value) -> Result<Value, LabeledError> {
+//!         let text = input
+//!             .as_string()
+//!             .map_err(|_| LabeledError::new("Expected a string", "requires a string", &input.tag))?;
+//!         let parts = vec![text; args.times as usize];
+//!         let separator = args.separator.as_deref().unwrap_or("");
+//!
+//!         Ok(UntaggedValue::string(parts.join(separator)).into_value(input.tag))
+//!     }
+//! }
+//!
+//! fn main() {
+//!     serve_simple_plugin(Repeat);
+//! }
+//! ```
+
+use crate::{serve_plugin, serve_plugin_remote, Plugin};
+use bigdecimal::BigDecimal;
+use nu_protocol::{Primitive, ReturnSuccess, ReturnValue, UntaggedValue};
+use nu_source::{Tag, Tagged, TaggedItem};
+use num_bigint::BigInt;
+use std::path::PathBuf;
+
+pub use nu_errors::ShellError;
+pub use nu_protocol::{CallInfo, Signature, SyntaxShape, Value};
+
+/// The arguments of a plugin, usually derived with `#[derive(CallArgs)]`.
+pub trait CallArgs: Sized {
+    /// The signature of the plugin, describing these arguments
+    fn signature() -> Signature;
+
+    /// Read the arguments the plugin was called with
+    fn from_call_info(call_info: &CallInfo) -> Result<Self, ShellError>;
+}
+
+/// A type that an argument can be read into.
+pub trait FromValue: Sized {
+    /// The shape of the argument in the signature
+    fn syntax_shape() -> SyntaxShape;
+
+    fn from_value(value: &Value) -> Result<Self, ShellError>;
+}
+
+impl FromValue for Value {
+    fn syntax_shape() -> SyntaxShape {
+        SyntaxShape::Any
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        Ok(value.clone())
+    }
+}
+
+impl FromValue for String {
+    fn syntax_shape() -> SyntaxShape {
+        SyntaxShape::String
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        value.as_string()
+    }
+}
+
+impl FromValue for bool {
+    fn syntax_shape() -> SyntaxShape {
+        SyntaxShape::Any
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        value.as_bool()
+    }
+}
+
+impl FromValue for BigInt {
+    fn syntax_shape() -> SyntaxShape {
+        SyntaxShape::Int
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        value.as_int()
+    }
+}
+
+impl FromValue for i64 {
+    fn syntax_shape() -> SyntaxShape {
+        SyntaxShape::Int
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        value.as_i64()
+    }
+}
+
+impl FromValue for u64 {
+    fn syntax_shape() -> SyntaxShape {
+        SyntaxShape::Int
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        value.as_u64()
+    }
+}
+
+impl FromValue for BigDecimal {
+    fn syntax_shape() -> SyntaxShape {
+        SyntaxShape::Number
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        match &value.value {
+            UntaggedValue::Primitive(Primitive::Int(i)) => Ok(BigDecimal::new(i.clone(), 0)),
+            _ => value.as_decimal(),
+        }
+    }
+}
+
+impl FromValue for PathBuf {
+    fn syntax_shape() -> SyntaxShape {
+        SyntaxShape::FilePath
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        value.as_path()
+    }
+}
+
+impl<T: FromValue> FromValue for Tagged<T> {
+    fn syntax_shape() -> SyntaxShape {
+        T::syntax_shape()
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        Ok(T::from_value(value)?.tagged(&value.tag))
+    }
+}
+
+/// Read a positional argument, which the signature makes sure is given
+pub fn required_positional<T: FromValue>(
+    call_info: &CallInfo,
+    position: usize,
+    name: &str,
+) -> Result<T, ShellError> {
+    match call_info.args.nth(position) {
+        Some(value) => T::from_value(value),
+        None => Err(ShellError::labeled_error(
+            format!("Missing argument {}", name),
+            format!("requires the {} argument", name),
+            &call_info.name_tag,
+        )),
+    }
+}
+
+pub fn optional_positional<T: FromValue>(
+    call_info: &CallInfo,
+    position: usize,
+) -> Result<Option<T>, ShellError> {
+    call_info.args.nth(position).map(T::from_value).transpose()
+}
+
+/// Read the positional arguments from `position` on
+pub fn rest_positional<T: FromValue>(
+    call_info: &CallInfo,
+    position: usize,
+) -> Result<Vec<T>, ShellError> {
+    call_info
+        .args
+        .positional_iter()
+        .skip(position)
+        .map(T::from_value)
+        .collect()
+}
+
+/// Read a flag, which the signature makes sure is given
+pub fn required_named<T: FromValue>(call_info: &CallInfo, name: &str) -> Result<T, ShellError> {
+    match call_info.args.get(name) {
+        Some(value) => T::from_value(value),
+        None => Err(ShellError::labeled_error(
+            format!("Missing flag --{}", name),
+            format!("requires the --{} flag", name),
+            &call_info.name_tag,
+        )),
+    }
+}
+
+pub fn optional_named<T: FromValue>(
+    call_info: &CallInfo,
+    name: &str,
+) -> Result<Option<T>, ShellError> {
+    call_info.args.get(name).map(T::from_value).transpose()
+}
+
+/// An error with a message and a label pointing at the value it's about.
+#[derive(Debug, Clone)]
+pub struct LabeledError {
+    pub msg: String,
+    pub label: String,
+    pub tag: Tag,
+}
+
+impl LabeledError {
+    pub fn new(msg: impl Into<String>, label: impl Into<String>, tag: impl Into<Tag>) -> Self {
+        LabeledError {
+            msg: msg.into(),
+            label: label.into(),
+            tag: tag.into(),
+        }
+    }
+}
+
+impl From<LabeledError> for ShellError {
+    fn from(error: LabeledError) -> Self {
+        ShellError::labeled_error(error.msg, error.label, error.tag)
+    }
+}
+
+/// A filter plugin that gives back a value for each value passed to it.
+pub trait SimplePlugin {
+    type Args: CallArgs;
+
+    fn run(&mut self, args: &Self::Args, input: Value) -> Result<Value, LabeledError>;
+}
+
+struct SimplePluginAdapter<P: SimplePlugin> {
+    plugin: P,
+    args: Option<P::Args>,
+}
+
+impl<P: SimplePlugin> Plugin for SimplePluginAdapter<P> {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(P::Args::signature().filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        self.args = Some(P::Args::from_call_info(&call_info)?);
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        let args = match &self.args {
+            Some(args) => args,
+            None => {
+                return Err(ShellError::untagged_runtime_error(
+                    "The plugin was given values before its arguments",
+                ))
+            }
+        };
+
+        let output = self.plugin.run(args, input)?;
+        Ok(vec![ReturnSuccess::value(output)])
+    }
+}
+
+/// Run a `SimplePlugin`, answering the requests nushell makes to it.
+pub fn serve_simple_plugin<P: SimplePlugin>(plugin: P) {
+    serve_plugin(&mut SimplePluginAdapter { plugin, args: None });
+}
+
+/// Serve a `SimplePlugin` at an address, like `serve_plugin_remote` does for a `Plugin`.
+pub fn serve_simple_plugin_remote<P, F>(address: &str, new_plugin: F) -> Result<(), ShellError>
+where
+    P: SimplePlugin,
+    F: Fn() -> P + Send + Sync + 'static,
+{
+    serve_plugin_remote(address, move || SimplePluginAdapter {
+        plugin: new_plugin(),
+        args: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LabeledError, ShellError, SimplePlugin, SimplePluginAdapter};
+    use crate::test_helpers::{expect_return_value_at, PluginTest};
+    use crate::CallArgs;
+    use indexmap::IndexMap;
+    use nu_protocol::{
+        CallInfo, EvaluatedArgs, NamedType, PositionalType, SyntaxShape, UntaggedValue, Value,
+    };
+    use nu_source::{Tag, Tagged};
+
+    /// Repeat strings a number of times.
+    #[derive(CallArgs)]
+    #[plugin(name = "repeat")]
+    struct Args {
+        /// how many times to repeat them
+        times: Tagged<u64>,
+        /// what to put between them
+        #[arg(short = 's')]
+        separator: Option<String>,
+        /// put them on lines of their own
+        lines: bool,
+    }
+
+    struct Repeat;
+
+    impl SimplePlugin for Repeat {
+        type Args = Args;
+
+        fn run(&mut self, args: &Args, input: Value) -> Result<Value, LabeledError> {
+            let text = input.as_string().map_err(|_| {
+                LabeledError::new("Expected a string", "requires a string", &input.tag)
+            })?;
+            let parts = vec![text; args.times.item as usize];
+            let separator = match &args.separator {
+                Some(separator) => separator.as_str(),
+                None if args.lines => "\n",
+                None => "",
+            };
+
+            Ok(UntaggedValue::string(parts.join(separator)).into_value(input.tag))
+        }
+    }
+
+    fn call(positional: Vec<Value>, named: Vec<(&str, Value)>) -> CallInfo {
+        let named: IndexMap<String, Value> = named
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+
+        CallInfo {
+            args: EvaluatedArgs::new(Some(positional), Some(named)),
+            name_tag: Tag::unknown(),
+        }
+    }
+
+    #[test]
+    fn derives_the_signature_from_the_struct() {
+        let signature = Args::signature();
+
+        assert_eq!(signature.name, "repeat");
+        assert_eq!(signature.usage, "Repeat strings a number of times.");
+        assert!(matches!(
+            &signature.positional[0],
+            (PositionalType::Mandatory(name, SyntaxShape::Int), desc)
+                if name == "times" && desc == "how many times to repeat them"
+        ));
+        assert!(matches!(
+            &signature.named["separator"],
+            (NamedType::Optional(Some('s'), SyntaxShape::String), _)
+        ));
+        assert!(matches!(
+            &signature.named["lines"],
+            (NamedType::Switch(None), _)
+        ));
+    }
+
+    #[test]
+    fn reads_the_arguments_from_the_call() -> Result<(), ShellError> {
+        let call_info = call(
+            vec![UntaggedValue::int(3).into()],
+            vec![("lines", UntaggedValue::boolean(true).into())],
+        );
+
+        let args = Args::from_call_info(&call_info)?;
+
+        assert_eq!(args.times.item, 3);
+        assert_eq!(args.separator, None);
+        assert!(args.lines);
+        Ok(())
+    }
+
+    #[test]
+    fn runs_the_plugin_on_each_value() {
+        let mut adapter = SimplePluginAdapter {
+            plugin: Repeat,
+            args: None,
+        };
+
+        let actual = PluginTest::for_plugin(&mut adapter)
+            .args(call(
+                vec![UntaggedValue::int(2).into()],
+                vec![("separator", UntaggedValue::string("-").into())],
+            ))
+            .input(UntaggedValue::string("nu").into_value(Tag::unknown()))
+            .setup(|_, _| {})
+            .test();
+
+        assert_eq!(
+            expect_return_value_at(actual, 0),
+            UntaggedValue::string("nu-nu").into_value(Tag::unknown())
+        );
+    }
+}
@@ -175,6 +175,9 @@ pub struct Block {
     pub block: Vec<Group>,
     pub definitions: IndexMap<String, Block>,
     pub span: Span,
+    /// If the caller keeps the changes to the environment the block makes when it's run as a
+    /// custom command, as for `def --env`
+    pub preserves_env: bool,
 }
 
 impl Block {
@@ -189,6 +192,7 @@ impl Block {
             block,
             definitions,
             span,
+            preserves_env: false,
         }
     }
 
@@ -198,6 +202,7 @@ impl Block {
             block: vec![],
             definitions: IndexMap::new(),
             span: Span::unknown(),
+            preserves_env: false,
         }
     }
 
@@ -236,7 +241,7 @@ impl Block {
             .params
             .positional
             .iter()
-            .map(|(_, name)| name.clone())
+            .map(|(param, _)| param.name().to_string())
             .collect();
         known_variables.extend_from_slice(&positional_params);
 
@@ -667,9 +672,17 @@ impl SpannedExpression {
                 // Higher precedence binds tighter
 
                 match operator {
+                    // Binds tightest so a default can be used directly, eg `$x.count ?? 0 + 1`
+                    Operator::Coalesce => 110,
                     Operator::Pow => 100,
                     Operator::Multiply | Operator::Divide | Operator::Modulo => 95,
                     Operator::Plus | Operator::Minus => 90,
+                    // Like Rust, bitwise operators bind tighter than comparisons, so
+                    // `$mode bit-and 0o111 != 0` does what it looks like
+                    Operator::ShiftLeft | Operator::ShiftRight => 85,
+                    Operator::BitAnd => 84,
+                    Operator::BitXor => 83,
+                    Operator::BitOr => 82,
                     Operator::NotContains
                     | Operator::Contains
                     | Operator::LessThan
@@ -861,6 +874,12 @@ pub enum Operator {
     And,
     Or,
     Pow,
+    Coalesce,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
 }
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Hash, Deserialize, Serialize, new)]
@@ -1288,6 +1307,8 @@ pub enum ExternalRedirection {
     Stdout,
     Stderr,
     StdoutAndStderr,
+    /// Stdout goes straight into another external command, so it's passed along as raw bytes
+    StdoutBytes,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
@@ -20,6 +20,12 @@ pub enum CommandAction {
     EnterValueShell(Value),
     /// Enter the help shell, which allows exploring the help system
     EnterHelpShell(Value),
+    /// Enter an archive shell, one that allows exploring inside of a zip or tar archive
+    EnterArchiveShell(String),
+    /// Enter a SQLite shell, one that allows exploring the tables of a database
+    EnterSqliteShell(String),
+    /// Enter an S3 shell, one that allows exploring a bucket of an object store
+    EnterS3Shell(String),
     /// Add plugins from path given
     AddPlugins(String),
     /// Go to the previous shell in the shell ring buffer
@@ -47,6 +53,15 @@ impl PrettyDebug for CommandAction {
             }
             CommandAction::EnterValueShell(v) => DbgDocBldr::typed("enter value shell", v.pretty()),
             CommandAction::EnterHelpShell(v) => DbgDocBldr::typed("enter help shell", v.pretty()),
+            CommandAction::EnterArchiveShell(s) => {
+                DbgDocBldr::typed("enter archive shell", DbgDocBldr::description(s))
+            }
+            CommandAction::EnterSqliteShell(s) => {
+                DbgDocBldr::typed("enter sqlite shell", DbgDocBldr::description(s))
+            }
+            CommandAction::EnterS3Shell(s) => {
+                DbgDocBldr::typed("enter s3 shell", DbgDocBldr::description(s))
+            }
             CommandAction::AddPlugins(..) => DbgDocBldr::description("add plugins"),
             CommandAction::PreviousShell => DbgDocBldr::description("previous shell"),
             CommandAction::NextShell => DbgDocBldr::description("next shell"),
@@ -100,6 +100,17 @@ impl PositionalType {
 
 type Description = String;
 
+/// An example of calling a command declared in Nu code, written in the comments above its `def`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SignatureExample {
+    /// What the example shows
+    pub description: String,
+    /// The pipeline to run
+    pub example: String,
+    /// An expression for what the pipeline returns, checked when the examples are tested
+    pub result: Option<String>,
+}
+
 /// The full signature of a command. All commands have a signature similar to a function signature.
 /// Commands will use this information to register themselves with Nu's core engine so that the command
 /// can be invoked, help can be displayed, and calls to the command can be error-checked.
@@ -109,6 +120,12 @@ pub struct Signature {
     pub name: String,
     /// Usage instructions about the command
     pub usage: String,
+    /// Longer help shown after the usage, for commands declared in Nu code
+    #[serde(default)]
+    pub extra_usage: String,
+    /// Examples of calling the command, for commands declared in Nu code
+    #[serde(default)]
+    pub examples: Vec<SignatureExample>,
     /// The list of positional arguments, both required and optional, and their corresponding types and help text
     pub positional: Vec<(PositionalType, Description)>,
     /// After the positional arguments, a catch-all for the rest of the arguments that might follow, their type, and help text
@@ -121,6 +138,13 @@ pub struct Signature {
     pub input: Option<Type>,
     /// If the command is expected to filter data, or to consume it (as a sink)
     pub is_filter: bool,
+    /// If a sink takes its input a value at a time as it arrives, rather than all at once when
+    /// the input ends
+    #[serde(default)]
+    pub is_streaming_sink: bool,
+    /// The version of the plugin providing the command, for commands that come from plugins
+    #[serde(default)]
+    pub version: Option<String>,
 }
 
 impl PartialEq for Signature {
@@ -192,12 +216,16 @@ impl Signature {
         Signature {
             name: name.into(),
             usage: String::new(),
+            extra_usage: String::new(),
+            examples: vec![],
             positional: vec![],
             rest_positional: None,
             named: indexmap::indexmap! {"help".into() => (NamedType::Switch(Some('h')), "Display this help message".into())},
             is_filter: false,
+            is_streaming_sink: false,
             yields: None,
             input: None,
+            version: None,
         }
     }
 
@@ -309,6 +337,19 @@ impl Signature {
         self
     }
 
+    /// Set the flag for a sink that takes its input a value at a time
+    pub fn streaming_sink(mut self) -> Signature {
+        self.is_streaming_sink = true;
+        self
+    }
+
+    /// Set the version of the plugin the command comes from, so it can be told apart from other
+    /// copies of the plugin on the search path
+    pub fn version(mut self, version: impl Into<String>) -> Signature {
+        self.version = Some(version.into());
+        self
+    }
+
     /// Set the type for the "rest" of the positional arguments
     /// Note: Not naming the field in your struct holding the rest values "rest", can
     /// cause errors when deserializing
@@ -62,6 +62,9 @@ pub enum Type {
 
     /// A block of script (TODO)
     Block,
+    /// A table kept in columns
+    #[cfg(feature = "dataframe")]
+    DataFrame,
     /// An error value (TODO)
     Error,
 
@@ -183,6 +186,8 @@ impl Type {
             UntaggedValue::Table(table) => Type::from_table(table.iter()),
             UntaggedValue::Error(_) => Type::Error,
             UntaggedValue::Block(_) => Type::Block,
+            #[cfg(feature = "dataframe")]
+            UntaggedValue::DataFrame(_) => Type::DataFrame,
         }
     }
 }
@@ -287,6 +292,8 @@ impl PrettyDebug for Type {
                     })
             }
             Type::Block => ty("block"),
+            #[cfg(feature = "dataframe")]
+            Type::DataFrame => ty("dataframe"),
         }
     }
 }
@@ -1,8 +1,11 @@
 pub mod column_path;
 mod convert;
+#[cfg(feature = "dataframe")]
+pub mod dataframe;
 mod debug;
 pub mod dict;
 pub mod did_you_mean;
+pub mod glob;
 pub mod iter;
 pub mod primitive;
 pub mod range;
@@ -47,6 +50,10 @@ pub enum UntaggedValue {
 
     /// A block of Nu code, eg `{ ls | get name ; echo "done" }` with its captured values
     Block(Box<hir::CapturedBlock>),
+
+    /// A table kept in columns, for working on large amounts of data
+    #[cfg(feature = "dataframe")]
+    DataFrame(dataframe::NuDataFrame),
 }
 
 impl UntaggedValue {
@@ -371,19 +378,7 @@ impl Value {
             UntaggedValue::Primitive(Primitive::Int(x)) => format!("{}", x),
             UntaggedValue::Primitive(Primitive::Filesize(x)) => format!("{}", x),
             UntaggedValue::Primitive(Primitive::FilePath(x)) => format!("{}", x.display()),
-            UntaggedValue::Primitive(Primitive::ColumnPath(path)) => {
-                let joined: String = path
-                    .iter()
-                    .map(|member| member.as_string())
-                    .collect::<Vec<String>>()
-                    .join(".");
-
-                if joined.contains(' ') {
-                    format!("\"{}\"", joined)
-                } else {
-                    joined
-                }
-            }
+            UntaggedValue::Primitive(Primitive::ColumnPath(path)) => path.to_string(),
 
             _ => String::from(""),
         }
@@ -505,6 +500,7 @@ impl From<String> for Value {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -518,6 +514,7 @@ impl From<&str> for Value {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -530,6 +527,7 @@ impl From<bool> for Value {
             tag: Tag {
                 anchor: None,
                 span: Span::unknown(),
+                content_type: None,
             },
         }
     }
@@ -598,6 +596,8 @@ impl ShellTypeName for UntaggedValue {
             UntaggedValue::Table(_) => "table",
             UntaggedValue::Error(_) => "error",
             UntaggedValue::Block(_) => "block",
+            #[cfg(feature = "dataframe")]
+            UntaggedValue::DataFrame(_) => "dataframe",
         }
     }
 }
@@ -650,6 +650,7 @@ impl StringExt for String {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -709,6 +710,7 @@ impl StrExt for &str {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -782,6 +784,7 @@ impl U64Ext for u64 {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -820,6 +823,7 @@ impl I64Ext for i64 {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -855,6 +859,7 @@ impl DecimalExt for f64 {
                 tag: Tag {
                     anchor: None,
                     span: Span::new(0, end),
+                    content_type: None,
                 },
             }
         } else {
@@ -897,6 +902,7 @@ impl PathBufExt for PathBuf {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -928,6 +934,7 @@ impl BooleanExt for bool {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -958,6 +965,7 @@ impl DateTimeExt for DateTime<FixedOffset> {
             tag: Tag {
                 anchor: None,
                 span: Span::new(0, end),
+                content_type: None,
             },
         }
     }
@@ -1066,6 +1074,7 @@ mod tests {
         let the_tag = Tag {
             anchor: None,
             span: Span::new(0, end),
+            content_type: None,
         };
 
         let expected = Value {
@@ -1082,6 +1091,7 @@ mod tests {
         let tag = Tag {
             anchor: None,
             span: Span::new(0, end),
+            content_type: None,
         };
 
         let expected = Value {
@@ -118,6 +118,54 @@ impl HasFallibleSpan for ColumnPath {
     }
 }
 
+impl std::fmt::Display for PathMember {
+    /// Writes the member the way it would be typed, quoting names that couldn't be written bare
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.unspanned {
+            UnspannedPathMember::String(string) => write!(f, "{}", quote_column_name(string)),
+            UnspannedPathMember::Int(int) => write!(f, "{}", int),
+        }
+    }
+}
+
+impl std::fmt::Display for ColumnPath {
+    /// Writes the path the way it would be typed, eg `name."first name".0`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, member) in self.members.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", member)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Quotes a column name if it can't be written bare in a column path: names that are empty, look
+/// like row numbers (negative ones too), or contain whitespace, dots or quotes
+pub fn quote_column_name(name: &str) -> String {
+    let needs_quotes = name.is_empty()
+        || name.parse::<i64>().is_ok()
+        || name
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '.' | '"' | '\'' | '`' | '$' | '|' | ';'));
+
+    if !needs_quotes {
+        return name.to_string();
+    }
+
+    if !name.contains('"') && !name.contains('\\') {
+        format!("\"{}\"", name)
+    } else if !name.contains('\'') {
+        format!("'{}'", name)
+    } else if !name.contains('`') {
+        format!("`{}`", name)
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
 impl PathMember {
     /// Create a string path member
     pub fn string(string: impl Into<String>, span: impl Into<Span>) -> PathMember {
@@ -140,6 +188,7 @@ impl PathMember {
 fn parse(raw_column_path: &Spanned<String>) -> (SpannedExpression, Option<ParseError>) {
     let mut delimiter = '.';
     let mut inside_delimiter = false;
+    let mut escaped = false;
     let mut output = vec![];
     let mut current_part = String::new();
     let mut start_index = 0;
@@ -148,7 +197,11 @@ fn parse(raw_column_path: &Spanned<String>) -> (SpannedExpression, Option<ParseE
     for (idx, c) in raw_column_path.item.char_indices() {
         last_index = idx;
         if inside_delimiter {
-            if c == delimiter {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && delimiter == '"' {
+                escaped = true;
+            } else if c == delimiter {
                 inside_delimiter = false;
             }
         } else if c == '\'' || c == '"' || c == '`' {
@@ -160,7 +213,7 @@ fn parse(raw_column_path: &Spanned<String>) -> (SpannedExpression, Option<ParseE
                 raw_column_path.span.start() + idx,
             );
 
-            if let Ok(row_number) = current_part.parse::<u64>() {
+            if let Ok(row_number) = current_part.parse::<i64>() {
                 output.push(Member::Int(BigInt::from(row_number), part_span));
             } else {
                 let trimmed = trim_quotes(&current_part);
@@ -180,7 +233,7 @@ fn parse(raw_column_path: &Spanned<String>) -> (SpannedExpression, Option<ParseE
             raw_column_path.span.start() + start_index,
             raw_column_path.span.start() + last_index + 1,
         );
-        if let Ok(row_number) = current_part.parse::<u64>() {
+        if let Ok(row_number) = current_part.parse::<i64>() {
             output.push(Member::Int(BigInt::from(row_number), part_span));
         } else {
             let current_part = trim_quotes(&current_part);
@@ -199,8 +252,54 @@ fn trim_quotes(input: &str) -> String {
 
     match (chars.next(), chars.next_back()) {
         (Some('\''), Some('\'')) => chars.collect(),
-        (Some('"'), Some('"')) => chars.collect(),
+        (Some('"'), Some('"')) => unescape(chars.as_str()),
         (Some('`'), Some('`')) => chars.collect(),
         _ => input.to_string(),
     }
 }
+
+/// Undoes the `\"` and `\\` escapes of a double-quoted name
+fn unescape(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some(next)) if next == '"' || next == '\\' => {
+                output.push(next);
+                chars.next();
+            }
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{quote_column_name, ColumnPath};
+    use nu_source::{Span, SpannedItem};
+
+    #[test]
+    fn quotes_names_that_are_not_bare_words() {
+        assert_eq!(quote_column_name("name"), "name");
+        assert_eq!(quote_column_name("first name"), "\"first name\"");
+        assert_eq!(quote_column_name("a.b"), "\"a.b\"");
+        assert_eq!(quote_column_name("1"), "\"1\"");
+        assert_eq!(quote_column_name("say \"hi\""), "'say \"hi\"'");
+    }
+
+    #[test]
+    fn displayed_paths_build_the_same_path() {
+        let text = r#"name."first name".0.'a "b"'"#;
+        let path = ColumnPath::build(&text.to_string().spanned(Span::unknown()));
+
+        assert_eq!(path.members().len(), 4);
+        assert_eq!(path.to_string(), text);
+        assert_eq!(
+            ColumnPath::build(&path.to_string().spanned(Span::unknown())).to_string(),
+            text
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use crate::value::dict::TaggedDictBuilder;
+use crate::value::primitive::Primitive;
+use crate::value::{UntaggedValue, Value};
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_source::{Span, Tag};
+use num_traits::ToPrimitive;
+use polars::prelude::{AnyValue, DataFrame, NamedFrom, Series};
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// A table kept in columns, so large amounts of data can be worked on without a Value per cell
+#[derive(Debug, Clone)]
+pub struct NuDataFrame {
+    pub dataframe: DataFrame,
+}
+
+/// The values a column of a dataframe can be made of. Until a column has a value its type isn't
+/// known, so only how many empty rows it has is kept.
+enum Column {
+    Empty(usize),
+    Int(Vec<Option<i64>>),
+    Decimal(Vec<Option<f64>>),
+    String(Vec<Option<String>>),
+    Boolean(Vec<Option<bool>>),
+}
+
+impl NuDataFrame {
+    pub fn new(dataframe: DataFrame) -> NuDataFrame {
+        NuDataFrame { dataframe }
+    }
+
+    /// How many rows and columns the dataframe has
+    pub fn shape(&self) -> (usize, usize) {
+        self.dataframe.shape()
+    }
+
+    /// Build a dataframe from the rows of a table. Every column has to have values of one type,
+    /// with rows that don't have the column left empty.
+    pub fn try_from_rows(rows: &[Value], tag: &Tag) -> Result<NuDataFrame, ShellError> {
+        let mut columns: IndexMap<String, Column> = IndexMap::new();
+
+        for (index, row) in rows.iter().enumerate() {
+            let entries = match &row.value {
+                UntaggedValue::Row(dictionary) => &dictionary.entries,
+                _ => {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Expected a table of rows",
+                        "dataframes are made from tables",
+                        tag,
+                        "not a row",
+                        &row.tag,
+                    ))
+                }
+            };
+
+            for name in entries.keys() {
+                if !columns.contains_key(name) {
+                    columns.insert(name.clone(), Column::Empty(index));
+                }
+            }
+
+            for (name, column) in columns.iter_mut() {
+                match entries.get(name) {
+                    Some(value) => column.push(value)?,
+                    None => column.push_empty(),
+                }
+            }
+        }
+
+        let series = columns
+            .into_iter()
+            .map(|(name, column)| column.into_series(&name))
+            .collect();
+
+        let dataframe = DataFrame::new(series)
+            .map_err(|err| ShellError::labeled_error("Invalid dataframe", err.to_string(), tag))?;
+
+        Ok(NuDataFrame { dataframe })
+    }
+
+    /// The rows of the dataframe, as a table would have them
+    pub fn to_rows(&self, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+        let columns = self.dataframe.get_columns();
+
+        (0..self.dataframe.height())
+            .map(|index| {
+                let mut row = TaggedDictBuilder::new(tag);
+
+                for series in columns {
+                    row.insert_untagged(series.name(), any_value(series.get(index), tag.span)?);
+                }
+
+                Ok(row.into_value())
+            })
+            .collect()
+    }
+}
+
+impl Column {
+    fn push(&mut self, value: &Value) -> Result<(), ShellError> {
+        if let Column::Empty(rows) = self {
+            let rows = *rows;
+
+            *self = match &value.value {
+                UntaggedValue::Primitive(Primitive::Nothing) => Column::Empty(rows),
+                UntaggedValue::Primitive(Primitive::Int(_))
+                | UntaggedValue::Primitive(Primitive::Filesize(_)) => Column::Int(vec![None; rows]),
+                UntaggedValue::Primitive(Primitive::Decimal(_)) => {
+                    Column::Decimal(vec![None; rows])
+                }
+                UntaggedValue::Primitive(Primitive::String(_)) => Column::String(vec![None; rows]),
+                UntaggedValue::Primitive(Primitive::Boolean(_)) => {
+                    Column::Boolean(vec![None; rows])
+                }
+                _ => return Err(unsupported(value)),
+            };
+        }
+
+        // Integers before the first decimal of a column become decimals too
+        if let (Column::Int(ints), UntaggedValue::Primitive(Primitive::Decimal(_))) =
+            (&*self, &value.value)
+        {
+            *self = Column::Decimal(ints.iter().map(|i| i.map(|i| i as f64)).collect());
+        }
+
+        match (self, &value.value) {
+            (column, UntaggedValue::Primitive(Primitive::Nothing)) => column.push_empty(),
+            (Column::Int(values), UntaggedValue::Primitive(Primitive::Int(i)))
+            | (Column::Int(values), UntaggedValue::Primitive(Primitive::Filesize(i))) => {
+                let i = i.to_i64().ok_or_else(|| {
+                    ShellError::labeled_error(
+                        "Integer too large for a dataframe",
+                        "doesn't fit in 64 bits",
+                        &value.tag,
+                    )
+                })?;
+
+                values.push(Some(i))
+            }
+            (Column::Decimal(values), UntaggedValue::Primitive(Primitive::Decimal(d))) => {
+                values.push(d.to_f64())
+            }
+            (Column::Decimal(values), UntaggedValue::Primitive(Primitive::Int(i))) => {
+                values.push(i.to_f64())
+            }
+            (Column::String(values), UntaggedValue::Primitive(Primitive::String(s))) => {
+                values.push(Some(s.clone()))
+            }
+            (Column::Boolean(values), UntaggedValue::Primitive(Primitive::Boolean(b))) => {
+                values.push(Some(*b))
+            }
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Column has values of different types",
+                    "the values before in this column have another type",
+                    &value.tag,
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_empty(&mut self) {
+        match self {
+            Column::Empty(rows) => *rows += 1,
+            Column::Int(values) => values.push(None),
+            Column::Decimal(values) => values.push(None),
+            Column::String(values) => values.push(None),
+            Column::Boolean(values) => values.push(None),
+        }
+    }
+
+    fn into_series(self, name: &str) -> Series {
+        match self {
+            Column::Empty(rows) => Series::new(name, vec![None::<String>; rows]),
+            Column::Int(values) => Series::new(name, values),
+            Column::Decimal(values) => Series::new(name, values),
+            Column::String(values) => Series::new(name, values),
+            Column::Boolean(values) => Series::new(name, values),
+        }
+    }
+}
+
+fn unsupported(value: &Value) -> ShellError {
+    ShellError::labeled_error(
+        "Value not supported in dataframes",
+        format!(
+            "dataframes hold numbers, strings and booleans, not a {}",
+            crate::type_name::ShellTypeName::type_name(value)
+        ),
+        &value.tag,
+    )
+}
+
+fn any_value(value: AnyValue, span: Span) -> Result<UntaggedValue, ShellError> {
+    Ok(match value {
+        AnyValue::Null => UntaggedValue::nothing(),
+        AnyValue::Boolean(b) => UntaggedValue::boolean(b),
+        AnyValue::Utf8(s) => UntaggedValue::string(s),
+        AnyValue::UInt8(i) => UntaggedValue::int(i),
+        AnyValue::UInt16(i) => UntaggedValue::int(i),
+        AnyValue::UInt32(i) => UntaggedValue::int(i),
+        AnyValue::UInt64(i) => UntaggedValue::int(i),
+        AnyValue::Int8(i) => UntaggedValue::int(i),
+        AnyValue::Int16(i) => UntaggedValue::int(i),
+        AnyValue::Int32(i) => UntaggedValue::int(i),
+        AnyValue::Int64(i) => UntaggedValue::int(i),
+        AnyValue::Float32(f) => UntaggedValue::decimal_from_float(f as f64, span),
+        AnyValue::Float64(f) => UntaggedValue::decimal_from_float(f, span),
+        other => {
+            return Err(ShellError::labeled_error(
+                "Dataframe value not supported",
+                format!("{:?} values can't be turned into Nu values yet", other),
+                span,
+            ))
+        }
+    })
+}
+
+// Dataframes are compared, ordered and (de)serialized by their rows, the same as the table
+// they came from would be. Hashing only looks at the shape and column names, which equal
+// dataframes share, so large dataframes don't have to be turned into rows to be hashed.
+
+impl PartialEq for NuDataFrame {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NuDataFrame {}
+
+impl PartialOrd for NuDataFrame {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NuDataFrame {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let tag = Tag::unknown();
+
+        match (self.to_rows(&tag), other.to_rows(&tag)) {
+            (Ok(rows), Ok(other_rows)) => rows.cmp(&other_rows),
+            _ => self.shape().cmp(&other.shape()),
+        }
+    }
+}
+
+impl Hash for NuDataFrame {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.shape().hash(state);
+        self.dataframe.get_column_names().hash(state);
+    }
+}
+
+impl Serialize for NuDataFrame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_rows(&Tag::unknown())
+            .map_err(|err| S::Error::custom(format!("{:?}", err)))?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NuDataFrame {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Value>::deserialize(deserializer)?;
+
+        NuDataFrame::try_from_rows(&rows, &Tag::unknown())
+            .map_err(|err| D::Error::custom(format!("{:?}", err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NuDataFrame;
+    use crate::UntaggedValue;
+    use indexmap::indexmap;
+    use nu_errors::ShellError;
+    use nu_source::Tag;
+
+    #[test]
+    fn rows_make_the_same_rows_as_a_dataframe() -> Result<(), ShellError> {
+        let rows = vec![
+            UntaggedValue::row(indexmap! {
+                "name".to_string() => UntaggedValue::string("nu").into_untagged_value(),
+                "stars".to_string() => UntaggedValue::int(5).into_untagged_value(),
+            })
+            .into_untagged_value(),
+            UntaggedValue::row(indexmap! {
+                "name".to_string() => UntaggedValue::string("jq").into_untagged_value(),
+                "stars".to_string() => UntaggedValue::nothing().into_untagged_value(),
+            })
+            .into_untagged_value(),
+        ];
+
+        let dataframe = NuDataFrame::try_from_rows(&rows, &Tag::unknown())?;
+
+        assert_eq!(dataframe.shape(), (2, 2));
+        assert_eq!(dataframe.to_rows(&Tag::unknown())?, rows);
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors_on_columns_with_values_of_different_types() {
+        let rows = vec![
+            UntaggedValue::row(indexmap! {
+                "stars".to_string() => UntaggedValue::int(5).into_untagged_value(),
+            })
+            .into_untagged_value(),
+            UntaggedValue::row(indexmap! {
+                "stars".to_string() => UntaggedValue::string("many").into_untagged_value(),
+            })
+            .into_untagged_value(),
+        ];
+
+        assert!(NuDataFrame::try_from_rows(&rows, &Tag::unknown()).is_err());
+    }
+}
@@ -24,6 +24,8 @@ impl PrettyDebug for Value {
             .nest(),
             UntaggedValue::Error(_) => DbgDocBldr::error("error"),
             UntaggedValue::Block(_) => DbgDocBldr::opaque("block"),
+            #[cfg(feature = "dataframe")]
+            UntaggedValue::DataFrame(_) => DbgDocBldr::opaque("dataframe"),
         }
     }
 }
@@ -22,14 +22,35 @@ pub fn did_you_mean(obj_source: &Value, field_tried: String) -> Option<Vec<Strin
     }
 }
 
-/// Borrowed from https://crates.io/crates/natural
-fn levenshtein_distance(str1: &str, str2: &str) -> usize {
-    let n = str1.len();
-    let m = str2.len();
+/// The names closest to the one tried, best first, leaving out those too different to be what
+/// was meant. Used to suggest a flag or command when the one typed doesn't exist.
+pub fn closest_matches<I>(possibilities: I, tried: &str) -> Vec<String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let most = tried.chars().count() / 3 + 1;
 
-    let mut current: Vec<usize> = (0..n + 1).collect();
+    let mut possible_matches: Vec<_> = possibilities
+        .into_iter()
+        .map(|word| (levenshtein_distance(&word, tried), word))
+        .filter(|(edit_distance, _)| *edit_distance <= most)
+        .collect();
+
+    possible_matches.sort();
+    possible_matches.dedup();
+    possible_matches.into_iter().map(|m| m.1).collect()
+}
+
+/// The number of single character insertions, deletions and changes to turn one string into the other
+///
+/// Borrowed from https://crates.io/crates/natural
+pub fn levenshtein_distance(str1: &str, str2: &str) -> usize {
     let a_vec: Vec<char> = str1.chars().collect();
     let b_vec: Vec<char> = str2.chars().collect();
+    let n = a_vec.len();
+    let m = b_vec.len();
+
+    let mut current: Vec<usize> = (0..n + 1).collect();
 
     for i in 1..m + 1 {
         let previous = current;
@@ -91,4 +112,19 @@ mod test {
 
         assert_eq!(None, did_you_mean(&empty_source, "hat".to_string()))
     }
+
+    #[test]
+    fn closest_matches_leaves_out_distant_names() {
+        let flags = vec!["all".to_string(), "long".to_string(), "du".to_string()];
+
+        assert_eq!(
+            closest_matches(flags.clone(), "al"),
+            vec!["all".to_string()]
+        );
+        assert_eq!(closest_matches(flags, "xyz"), Vec::<String>::new());
+        assert_eq!(
+            closest_matches(vec!["mkdir".to_string()], "mdkir"),
+            vec!["mkdir".to_string()]
+        );
+    }
 }
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A path given to a command that understands glob patterns, such as `ls`, `cp` or `rm`.
+///
+/// Bare words like `*.txt` and values made with `into glob` are expanded, while quoted strings
+/// and strings from variables are taken as the literal name of a file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NuGlob {
+    Expand(String),
+    Literal(String),
+}
+
+impl NuGlob {
+    pub fn as_str(&self) -> &str {
+        match self {
+            NuGlob::Expand(s) | NuGlob::Literal(s) => s,
+        }
+    }
+
+    pub fn is_expand(&self) -> bool {
+        matches!(self, NuGlob::Expand(_))
+    }
+
+    /// The pattern to expand for this path, relative to `base`. The special characters of
+    /// literal paths are escaped, so they only ever match the file they name.
+    pub fn pattern_in(&self, base: &Path) -> String {
+        match self {
+            NuGlob::Expand(pattern) => base.join(pattern).to_string_lossy().to_string(),
+            NuGlob::Literal(path) => escape(&base.join(path).to_string_lossy()),
+        }
+    }
+}
+
+/// Escapes the glob metacharacters of `path`, wrapping each in a one-character class. Paths that
+/// have to match only themselves are all escaped with this.
+pub fn escape(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+
+    for c in path.chars() {
+        match c {
+            '?' | '*' | '[' | ']' => {
+                escaped.push('[');
+                escaped.push(c);
+                escaped.push(']');
+            }
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NuGlob;
+    use std::path::Path;
+
+    #[test]
+    fn literal_paths_are_escaped() {
+        let literal = NuGlob::Literal("report[1]*.txt".to_string());
+        let expand = NuGlob::Expand("*.txt".to_string());
+
+        assert_eq!(literal.pattern_in(Path::new("")), "report[[]1[]][*].txt");
+        assert_eq!(expand.pattern_in(Path::new("")), "*.txt");
+    }
+}
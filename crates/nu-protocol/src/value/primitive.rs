@@ -175,6 +175,7 @@ impl Primitive {
         match self {
             Primitive::Nothing => true,
             Primitive::String(s) => s.is_empty(),
+            Primitive::Binary(b) => b.is_empty(),
             _ => false,
         }
     }
@@ -407,127 +408,44 @@ pub fn format_duration(duration: &BigInt) -> String {
     )
 }
 
-#[allow(clippy::cognitive_complexity)]
-/// Format a date value into a humanized string (eg "1 week ago" instead of a formal date string)
-pub fn format_date(d: &DateTime<FixedOffset>) -> String {
+/// How far a date is from now in the largest unit it spans: the amount, negative for dates in
+/// the future, and the unit ("year", "month", "week", "day", "hour", "minute" or "second")
+pub fn date_distance(d: &DateTime<FixedOffset>) -> (i64, &'static str) {
     let utc: DateTime<Utc> = Utc::now();
 
     let duration = utc.signed_duration_since(*d);
 
-    if duration.num_seconds() < 0 {
-        // Our duration is negative, so we need to speak about the future
-        if -duration.num_weeks() >= 52 {
-            let num_years = -duration.num_weeks() / 52;
-
-            format!(
-                "{} year{} from now",
-                num_years,
-                if num_years == 1 { "" } else { "s" }
-            )
-        } else if -duration.num_weeks() >= 4 {
-            let num_months = -duration.num_weeks() / 4;
-
-            format!(
-                "{} month{} from now",
-                num_months,
-                if num_months == 1 { "" } else { "s" }
-            )
-        } else if -duration.num_weeks() >= 1 {
-            let num_weeks = -duration.num_weeks();
-
-            format!(
-                "{} week{} from now",
-                num_weeks,
-                if num_weeks == 1 { "" } else { "s" }
-            )
-        } else if -duration.num_days() >= 1 {
-            let num_days = -duration.num_days();
-
-            format!(
-                "{} day{} from now",
-                num_days,
-                if num_days == 1 { "" } else { "s" }
-            )
-        } else if -duration.num_hours() >= 1 {
-            let num_hours = -duration.num_hours();
-
-            format!(
-                "{} hour{} from now",
-                num_hours,
-                if num_hours == 1 { "" } else { "s" }
-            )
-        } else if -duration.num_minutes() >= 1 {
-            let num_minutes = -duration.num_minutes();
-
-            format!(
-                "{} min{} from now",
-                num_minutes,
-                if num_minutes == 1 { "" } else { "s" }
-            )
-        } else {
-            let num_seconds = -duration.num_seconds();
-
-            format!(
-                "{} sec{} from now",
-                num_seconds,
-                if num_seconds == 1 { "" } else { "s" }
-            )
-        }
-    } else if duration.num_weeks() >= 52 {
-        let num_years = duration.num_weeks() / 52;
-
-        format!(
-            "{} year{} ago",
-            num_years,
-            if num_years == 1 { "" } else { "s" }
-        )
-    } else if duration.num_weeks() >= 4 {
-        let num_months = duration.num_weeks() / 4;
-
-        format!(
-            "{} month{} ago",
-            num_months,
-            if num_months == 1 { "" } else { "s" }
-        )
-    } else if duration.num_weeks() >= 1 {
-        let num_weeks = duration.num_weeks();
-
-        format!(
-            "{} week{} ago",
-            num_weeks,
-            if num_weeks == 1 { "" } else { "s" }
-        )
-    } else if duration.num_days() >= 1 {
-        let num_days = duration.num_days();
-
-        format!(
-            "{} day{} ago",
-            num_days,
-            if num_days == 1 { "" } else { "s" }
-        )
-    } else if duration.num_hours() >= 1 {
-        let num_hours = duration.num_hours();
-
-        format!(
-            "{} hour{} ago",
-            num_hours,
-            if num_hours == 1 { "" } else { "s" }
-        )
-    } else if duration.num_minutes() >= 1 {
-        let num_minutes = duration.num_minutes();
-
-        format!(
-            "{} min{} ago",
-            num_minutes,
-            if num_minutes == 1 { "" } else { "s" }
-        )
+    if duration.num_weeks().abs() >= 52 {
+        (duration.num_weeks() / 52, "year")
+    } else if duration.num_weeks().abs() >= 4 {
+        (duration.num_weeks() / 4, "month")
+    } else if duration.num_weeks().abs() >= 1 {
+        (duration.num_weeks(), "week")
+    } else if duration.num_days().abs() >= 1 {
+        (duration.num_days(), "day")
+    } else if duration.num_hours().abs() >= 1 {
+        (duration.num_hours(), "hour")
+    } else if duration.num_minutes().abs() >= 1 {
+        (duration.num_minutes(), "minute")
     } else {
-        let num_seconds = duration.num_seconds();
+        (duration.num_seconds(), "second")
+    }
+}
 
-        format!(
-            "{} sec{} ago",
-            num_seconds,
-            if num_seconds == 1 { "" } else { "s" }
-        )
+/// Format a date value into a humanized string (eg "1 week ago" instead of a formal date string)
+pub fn format_date(d: &DateTime<FixedOffset>) -> String {
+    let (amount, unit) = date_distance(d);
+
+    let unit = match unit {
+        "minute" => "min",
+        "second" => "sec",
+        unit => unit,
+    };
+    let plural = if amount.abs() == 1 { "" } else { "s" };
+
+    if amount < 0 {
+        format!("{} {}{} from now", -amount, unit, plural)
+    } else {
+        format!("{} {}{} ago", amount, unit, plural)
     }
 }
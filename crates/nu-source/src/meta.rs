@@ -148,6 +148,7 @@ pub trait TaggedItem: Sized {
             tag: Tag {
                 span: Span::unknown(),
                 anchor: None,
+                content_type: None,
             },
         }
     }
@@ -262,11 +263,19 @@ pub struct Tag {
     pub anchor: Option<AnchorLocation>,
     /// The span in the source text for the command that created this value
     pub span: Span,
+    /// The media type of what the value was loaded from, eg `application/json`
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
 }
 
 impl From<Span> for Tag {
     fn from(span: Span) -> Self {
-        Tag { anchor: None, span }
+        Tag {
+            anchor: None,
+            span,
+            content_type: None,
+        }
     }
 }
 
@@ -275,6 +284,7 @@ impl From<&Span> for Tag {
         Tag {
             anchor: None,
             span: *span,
+            content_type: None,
         }
     }
 }
@@ -284,6 +294,7 @@ impl From<(usize, usize, AnchorLocation)> for Tag {
         Tag {
             anchor: Some(anchor),
             span: Span::new(start, end),
+            content_type: None,
         }
     }
 }
@@ -293,6 +304,7 @@ impl From<(usize, usize, Option<AnchorLocation>)> for Tag {
         Tag {
             anchor,
             span: Span::new(start, end),
+            content_type: None,
         }
     }
 }
@@ -315,6 +327,7 @@ impl Tag {
         Tag {
             anchor: None,
             span: Span::unknown(),
+            content_type: None,
         }
     }
 
@@ -322,12 +335,17 @@ impl Tag {
         Tag {
             anchor,
             span: self.span,
+            content_type: None,
         }
     }
 
     /// Creates a `Tag` from the given `Span` with no `AnchorLocation`
     pub fn unknown_anchor(span: Span) -> Tag {
-        Tag { anchor: None, span }
+        Tag {
+            anchor: None,
+            span,
+            content_type: None,
+        }
     }
 
     /// Creates a `Tag` from the given `AnchorLocation` for a span with a length of 1.
@@ -335,6 +353,7 @@ impl Tag {
         Tag {
             anchor: Some(anchor),
             span: Span::new(pos, pos + 1),
+            content_type: None,
         }
     }
 
@@ -343,6 +362,7 @@ impl Tag {
         Tag {
             anchor: Some(anchor),
             span: Span::unknown(),
+            content_type: None,
         }
     }
 
@@ -351,6 +371,7 @@ impl Tag {
         Tag {
             anchor: None,
             span: Span::unknown(),
+            content_type: None,
         }
     }
 
@@ -359,6 +380,14 @@ impl Tag {
         self.anchor.clone()
     }
 
+    /// Gives the tag the media type of what the value was loaded from
+    pub fn with_content_type(self, content_type: impl Into<String>) -> Tag {
+        Tag {
+            content_type: Some(content_type.into()),
+            ..self
+        }
+    }
+
     // Merges the current `Tag` with the given `Tag`.
     ///
     /// Both Tags must share the same `AnchorLocation`.
@@ -373,6 +402,7 @@ impl Tag {
         Tag {
             span: Span::new(self.span.start, other.span.end),
             anchor: self.anchor.clone(),
+            content_type: self.content_type.clone(),
         }
     }
 
@@ -393,6 +423,7 @@ impl Tag {
                 Tag {
                     span: Span::new(self.span.start, other.span.end),
                     anchor: self.anchor.clone(),
+                    content_type: self.content_type.clone(),
                 }
             }
             None => self.clone(),
@@ -72,6 +72,18 @@ impl Director {
         director
     }
 
+    /// Runs the script with the arguments it's given on the command line, rather than a pipeline
+    pub fn script(&self, script: &str, args: &[&str]) -> Self {
+        let mut director = self.pipeline("");
+
+        if let Some(process) = director.executable.as_mut() {
+            process.arg(script);
+            process.args(args);
+        }
+
+        director
+    }
+
     pub fn executable(&self) -> Option<&NuProcess> {
         if let Some(binary) = &self.executable {
             Some(binary)
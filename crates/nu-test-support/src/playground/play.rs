@@ -152,6 +152,10 @@ impl<'a> Playground<'a> {
         self.build().pipeline(commands)
     }
 
+    pub fn script(&mut self, script: &str, args: &[&str]) -> Director {
+        self.build().script(script, args)
+    }
+
     pub fn mkdir(&mut self, directory: &str) -> &mut Self {
         self.cwd.push(directory);
         std::fs::create_dir_all(&self.cwd).expect("can not create directory");
@@ -185,12 +185,8 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
                     }
                 }
                 UnspannedPathMember::Int(int) => {
-                    let index = int.to_usize().ok_or_else(|| {
-                        ShellError::range_error(
-                            ExpectedRange::Usize,
-                            &"massive integer".spanned(name.span),
-                            "indexing",
-                        )
+                    let index = list_index(int, l.len()).ok_or_else(|| {
+                        ShellError::range_error(0..(l.len()), &int.spanned(name.span), "indexing")
                     })?;
 
                     get_data_by_index(value, index.spanned(value.tag.span)).ok_or_else(|| {
@@ -447,7 +443,7 @@ pub fn insert_data_at_member(
                 "string".spanned(member.span),
             )),
             UnspannedPathMember::Int(int) => {
-                let int = int.to_usize().ok_or_else(|| {
+                let int = list_index(int, array.len()).ok_or_else(|| {
                     ShellError::range_error(
                         ExpectedRange::Usize,
                         &"bigger number".spanned(member.span),
@@ -724,6 +720,22 @@ pub fn get_data<'value>(value: &'value Value, desc: &str) -> MaybeOwned<'value,
         UntaggedValue::Block(_) | UntaggedValue::Table(_) | UntaggedValue::Error(_) => {
             MaybeOwned::Owned(UntaggedValue::nothing().into_untagged_value())
         }
+        #[cfg(feature = "dataframe")]
+        UntaggedValue::DataFrame(_) => {
+            MaybeOwned::Owned(UntaggedValue::nothing().into_untagged_value())
+        }
+    }
+}
+
+/// Where an index points in a list of the given length. Negative indexes count back from the
+/// end, so -1 is the last item.
+pub fn list_index(index: &impl ToPrimitive, len: usize) -> Option<usize> {
+    let index = index.to_i64()?;
+
+    if index < 0 {
+        len.checked_sub(index.checked_neg()?.to_usize()?)
+    } else {
+        index.to_usize()
     }
 }
 
@@ -795,7 +807,7 @@ pub(crate) fn get_mut_data_by_member<'value>(
                 None
             }
             UnspannedPathMember::Int(int) => {
-                let index = int.to_usize()?;
+                let index = list_index(int, l.len())?;
                 l.get_mut(index)
             }
         },
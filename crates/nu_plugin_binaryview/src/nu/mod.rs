@@ -8,6 +8,7 @@ use crate::BinaryView;
 impl Plugin for BinaryView {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("binaryview")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Autoview of binary data.")
             .switch("lores", "use low resolution output mode", Some('l')))
     }
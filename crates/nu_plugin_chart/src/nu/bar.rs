@@ -129,6 +129,7 @@ fn display(model: &Model) -> Result<(), Box<dyn Error>> {
 impl Plugin for SubCommand {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("chart bar")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Bar charts")
             .switch("acc", "accumulate values", Some('a'))
             .optional(
@@ -127,6 +127,7 @@ fn display(model: &Model) -> Result<(), Box<dyn Error>> {
 impl Plugin for SubCommand {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("chart line")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Line charts")
             .switch("acc", "accumulate values", Some('a'))
             .optional(
@@ -63,7 +63,7 @@ pub async fn fetch(
     let path_str = path.as_string()?;
     let path_span = path.tag.span;
 
-    let result = helper(&path_str, path_span, has_raw, user, password).await;
+    let result = helper(&path_str, path_span, user, password).await;
 
     if let Err(e) = result {
         return Err(e);
@@ -92,7 +92,6 @@ pub async fn fetch(
 async fn helper(
     location: &str,
     span: Span,
-    has_raw: bool,
     user: Option<String>,
     password: Option<String>,
 ) -> std::result::Result<(Option<String>, Value), ShellError> {
@@ -129,6 +128,7 @@ async fn helper(
     let tag = Tag {
         span,
         anchor: Some(AnchorLocation::Url(location.to_string())),
+        content_type: None,
     };
 
     match response.await {
@@ -153,6 +153,9 @@ async fn helper(
                             span,
                         )
                     })?;
+                // Kept on the value so it can still be converted (eg by save) without a from
+                let tag = tag.with_content_type(content_type.essence_str());
+
                 match (content_type.type_(), content_type.subtype()) {
                     (mime::APPLICATION, mime::XML) => Ok((
                         Some("xml".to_string()),
@@ -244,7 +247,9 @@ async fn helper(
                             .into_value(tag),
                         ))
                     }
-                    (_ty, _sub_ty) if has_raw => {
+                    // Other types are given as they are, and are converted by their content
+                    // type if it's one of a format Nu knows, eg application/vnd.github+json
+                    _ => {
                         let raw_bytes = r.body_bytes().await;
                         let raw_bytes = match raw_bytes {
                             Ok(r) => r,
@@ -266,10 +271,6 @@ async fn helper(
                             Err(_) => Ok((None, UntaggedValue::binary(raw_bytes).into_value(tag))),
                         }
                     }
-                    (ty, sub_ty) => Err(ShellError::unimplemented(format!(
-                        "Not yet supported MIME type: {} {}",
-                        ty, sub_ty
-                    ))),
                 }
             }
             // TODO: Should this return "nothing" or Err?
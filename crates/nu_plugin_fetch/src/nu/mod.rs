@@ -8,7 +8,7 @@ use crate::Fetch;
 
 impl Plugin for Fetch {
     fn config(&mut self) -> Result<Signature, ShellError> {
-        Ok(Signature::build("fetch")
+        Ok(Signature::build("fetch").version(env!("CARGO_PKG_VERSION"))
             .desc("Load from a URL into a cell, convert to table if possible (avoid by appending '--raw').")
             .required(
                 "URL",
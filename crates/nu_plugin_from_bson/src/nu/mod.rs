@@ -10,6 +10,7 @@ use nu_source::Tag;
 impl Plugin for FromBson {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("from bson")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Convert from .bson binary into table")
             .filter())
     }
@@ -21,37 +21,33 @@ impl FromSqlite {
     }
 }
 
+/// Reads the database into a row with a column for each table, so a table can be had with
+/// eg `open app.db | get users`
 pub fn convert_sqlite_file_to_nu_value(
     path: &Path,
     tag: impl Into<Tag> + Clone,
 ) -> Result<Value, rusqlite::Error> {
     let conn = Connection::open(path)?;
 
-    let mut meta_out = Vec::new();
+    let mut tables = TaggedDictBuilder::new(tag.clone());
     let mut meta_stmt = conn.prepare("select name from sqlite_master where type='table'")?;
     let mut meta_rows = meta_stmt.query(NO_PARAMS)?;
 
     while let Some(meta_row) = meta_rows.next()? {
         let table_name: String = meta_row.get(0)?;
-        let mut meta_dict = TaggedDictBuilder::new(tag.clone());
         let mut out = Vec::new();
         let mut table_stmt = conn.prepare(&format!("select * from [{}]", table_name))?;
         let mut table_rows = table_stmt.query(NO_PARAMS)?;
         while let Some(table_row) = table_rows.next()? {
             out.push(convert_sqlite_row_to_nu_value(table_row, tag.clone()))
         }
-        meta_dict.insert_value(
-            "table_name".to_string(),
-            UntaggedValue::Primitive(Primitive::String(table_name)).into_value(tag.clone()),
-        );
-        meta_dict.insert_value(
-            "table_values",
+        tables.insert_value(
+            table_name,
             UntaggedValue::Table(out).into_value(tag.clone()),
         );
-        meta_out.push(meta_dict.into_value());
     }
-    let tag = tag.into();
-    Ok(UntaggedValue::Table(meta_out).into_value(tag))
+
+    Ok(tables.into_value())
 }
 
 fn convert_sqlite_row_to_nu_value(row: &Row, tag: impl Into<Tag> + Clone) -> Value {
@@ -112,13 +108,7 @@ pub fn from_sqlite_bytes_to_value(
 
 pub fn from_sqlite(bytes: Vec<u8>, name_tag: Tag) -> Result<Vec<ReturnValue>, ShellError> {
     match from_sqlite_bytes_to_value(bytes, name_tag.clone()) {
-        Ok(x) => match x {
-            Value {
-                value: UntaggedValue::Table(list),
-                ..
-            } => Ok(list.into_iter().map(ReturnSuccess::value).collect()),
-            _ => Ok(vec![ReturnSuccess::value(x)]),
-        },
+        Ok(x) => Ok(vec![ReturnSuccess::value(x)]),
         Err(_) => Err(ShellError::labeled_error(
             "Could not parse as SQLite",
             "input cannot be parsed as SQLite",
@@ -10,6 +10,7 @@ use nu_source::Tag;
 impl Plugin for FromSqlite {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("from sqlite")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Convert from sqlite binary into table")
             .filter())
     }
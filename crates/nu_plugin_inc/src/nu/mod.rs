@@ -15,6 +15,7 @@ use nu_value_ext::ValueExt;
 impl Plugin for Inc {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("inc")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Increment a value or version. Optionally use the column of a table.")
             .switch(
                 "major",
@@ -9,6 +9,7 @@ use crate::Post;
 impl Plugin for Post {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("post")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Post content to a url and retrieve data as a table if possible.")
             .required("path", SyntaxShape::Any, "the URL to post to")
             .required("body", SyntaxShape::Any, "the contents of the post body")
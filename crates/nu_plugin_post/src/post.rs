@@ -208,6 +208,7 @@ pub async fn post(
                             Tag {
                                 anchor: Some(AnchorLocation::Url(location.to_string())),
                                 span: tag.span,
+                                content_type: None,
                             },
                         )),
                         (mime::APPLICATION, mime::JSON) => Ok((
@@ -222,6 +223,7 @@ pub async fn post(
                             Tag {
                                 anchor: Some(AnchorLocation::Url(location.to_string())),
                                 span: tag.span,
+                                content_type: None,
                             },
                         )),
                         (mime::APPLICATION, mime::OCTET_STREAM) => {
@@ -238,6 +240,7 @@ pub async fn post(
                                 Tag {
                                     anchor: Some(AnchorLocation::Url(location.to_string())),
                                     span: tag.span,
+                                    content_type: None,
                                 },
                             ))
                         }
@@ -255,6 +258,7 @@ pub async fn post(
                                 Tag {
                                     anchor: Some(AnchorLocation::Url(location.to_string())),
                                     span: tag.span,
+                                    content_type: None,
                                 },
                             ))
                         }
@@ -270,6 +274,7 @@ pub async fn post(
                             Tag {
                                 anchor: Some(AnchorLocation::Url(location.to_string())),
                                 span: tag.span,
+                                content_type: None,
                             },
                         )),
                         (mime::TEXT, mime::PLAIN) => {
@@ -302,6 +307,7 @@ pub async fn post(
                                 Tag {
                                     anchor: Some(AnchorLocation::Url(location.to_string())),
                                     span: tag.span,
+                                    content_type: None,
                                 },
                             ))
                         }
@@ -314,6 +320,7 @@ pub async fn post(
                             Tag {
                                 anchor: Some(AnchorLocation::Url(location.to_string())),
                                 span: tag.span,
+                                content_type: None,
                             },
                         )),
                     }
@@ -324,6 +331,7 @@ pub async fn post(
                     Tag {
                         anchor: Some(AnchorLocation::Url(location.to_string())),
                         span: tag.span,
+                        content_type: None,
                     },
                 )),
             },
@@ -401,9 +409,6 @@ pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
 
         UntaggedValue::Table(l) => serde_json::Value::Array(json_list(l)?),
         UntaggedValue::Error(e) => return Err(e.clone()),
-        UntaggedValue::Block(_) | UntaggedValue::Primitive(Primitive::Range(_)) => {
-            serde_json::Value::Null
-        }
         UntaggedValue::Primitive(Primitive::Binary(b)) => {
             let mut output = vec![];
 
@@ -427,6 +432,8 @@ pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
             }
             serde_json::Value::Object(m)
         }
+        // Blocks, ranges, and the values only some builds of Nu have, like dataframes
+        _ => serde_json::Value::Null,
     })
 }
 
@@ -8,6 +8,7 @@ use futures::executor::block_on;
 impl Plugin for Ps {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("ps")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("View information about system processes.")
             .switch(
                 "long",
@@ -125,6 +125,7 @@ pub async fn s3_helper(resource: &Value, has_raw: bool, config: &CredentialConfi
             UntaggedValue::string(output).into_value(Tag {
                 span: resource.tag.span,
                 anchor: Some(AnchorLocation::Url(resource_str)),
+                content_type: None,
             }),
             e,
         )))
@@ -8,7 +8,7 @@ use crate::handler::s3_helper;
 
 impl Plugin for handler::Handler {
     fn config(&mut self) -> Result<Signature, ShellError> {
-        Ok(Signature::build("s3")
+        Ok(Signature::build("s3").version(env!("CARGO_PKG_VERSION"))
             .desc("Load S3 resource into a cell, convert to table if possible (avoid by appending '--raw' or '-R')")
             .required(
                 "RESOURCE",
@@ -10,6 +10,7 @@ use crate::{selector::begin_selector_query, Selector};
 impl Plugin for Selector {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("selector")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("execute selector query on html/web")
             .required("query", SyntaxShape::String, "selector query")
             .switch("as_html", "return the query output as html", Some('a'))
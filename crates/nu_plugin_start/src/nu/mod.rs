@@ -7,6 +7,7 @@ use crate::start::Start;
 impl Plugin for Start {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("start")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Opens each file/directory/URL using the default application")
             .rest(SyntaxShape::String, "files/urls/directories to open")
             .named(
@@ -8,6 +8,7 @@ use futures::executor::block_on;
 impl Plugin for Sys {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("sys")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("View information about the current system.")
             .filter())
     }
@@ -5,7 +5,9 @@ use nu_protocol::{CallInfo, Signature, Value};
 
 impl Plugin for TextView {
     fn config(&mut self) -> Result<Signature, ShellError> {
-        Ok(Signature::build("textview").desc("Autoview of text data."))
+        Ok(Signature::build("textview")
+            .version(env!("CARGO_PKG_VERSION"))
+            .desc("Autoview of text data."))
     }
 
     fn sink(&mut self, _call_info: CallInfo, input: Vec<Value>) {
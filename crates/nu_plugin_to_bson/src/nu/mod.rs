@@ -10,6 +10,7 @@ use nu_source::Tag;
 impl Plugin for ToBson {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("to bson")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Convert table into .bson binary")
             .filter())
     }
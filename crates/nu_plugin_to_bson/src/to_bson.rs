@@ -63,12 +63,13 @@ pub fn value_to_bson_value(v: &Value) -> Result<Bson, ShellError> {
                 .map(|x| value_to_bson_value(x))
                 .collect::<Result<_, _>>()?,
         ),
-        UntaggedValue::Block(_) | UntaggedValue::Primitive(Primitive::Range(_)) => Bson::Null,
         UntaggedValue::Error(e) => return Err(e.clone()),
         UntaggedValue::Primitive(Primitive::Binary(b)) => {
             Bson::Binary(BinarySubtype::Generic, b.clone())
         }
         UntaggedValue::Row(o) => object_value_to_bson(o)?,
+        // Blocks, ranges, and the values only some builds of Nu have, like dataframes
+        _ => Bson::Null,
     })
 }
 
@@ -10,6 +10,7 @@ use nu_source::Tag;
 impl Plugin for ToSqlite {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("to sqlite")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("Convert table into sqlite binary")
             .filter())
     }
@@ -82,36 +82,50 @@ fn get_insert_values(rows: Vec<Value>) -> Result<String, std::io::Error> {
     Ok(values.into_iter().fold("".to_string(), comma_concat))
 }
 
-fn generate_statements(table: Dictionary) -> Result<(String, String), std::io::Error> {
-    let table_name = match table.entries.get("table_name") {
-        Some(Value {
-            value: UntaggedValue::Primitive(Primitive::String(table_name)),
-            ..
-        }) => table_name,
-        _ => {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Could not find table name",
-            ))
-        }
-    };
-    let (columns, insert_values) = match table.entries.get("table_values") {
-        Some(Value {
-            value: UntaggedValue::Table(l),
-            ..
-        }) => (get_columns(l), get_insert_values(l.to_vec())),
-        _ => {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Could not find table values",
-            ))
-        }
-    };
-    let create = format!("create table {}({})", table_name, columns?);
-    let insert = format!("insert into {} values {}", table_name, insert_values?);
+fn generate_statements(
+    table_name: &str,
+    rows: &[Value],
+) -> Result<(String, String), std::io::Error> {
+    let create = format!("create table {}({})", table_name, get_columns(rows)?);
+    let insert = format!(
+        "insert into {} values {}",
+        table_name,
+        get_insert_values(rows.to_vec())?
+    );
     Ok((create, insert))
 }
 
+/// The tables of a row, which is either a table_name and table_values row, or a row with a
+/// column for each table as `open` gives for a database
+fn tables_of(row: &Dictionary) -> Result<Vec<(&str, &[Value])>, std::io::Error> {
+    match (
+        row.entries.get("table_name"),
+        row.entries.get("table_values"),
+    ) {
+        (
+            Some(Value {
+                value: UntaggedValue::Primitive(Primitive::String(table_name)),
+                ..
+            }),
+            Some(Value {
+                value: UntaggedValue::Table(rows),
+                ..
+            }),
+        ) => Ok(vec![(table_name.as_str(), &rows[..])]),
+        _ => row
+            .entries
+            .iter()
+            .map(|(table_name, value)| match &value.value {
+                UntaggedValue::Table(rows) => Ok((table_name.as_str(), &rows[..])),
+                _ => Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("Could not find table values for {}", table_name),
+                )),
+            })
+            .collect(),
+    }
+}
+
 fn sqlite_input_stream_to_bytes(values: Vec<Value>) -> Result<Value, std::io::Error> {
     // FIXME: should probably write a sqlite virtual filesystem
     // that will allow us to use bytes as a file to avoid this
@@ -126,14 +140,16 @@ fn sqlite_input_stream_to_bytes(values: Vec<Value>) -> Result<Value, std::io::Er
     for value in values.into_iter() {
         match &value.value {
             UntaggedValue::Row(d) => {
-                let (create, insert) = generate_statements(d.to_owned())?;
-                match conn
-                    .execute(&create, NO_PARAMS)
-                    .and_then(|_| conn.execute(&insert, NO_PARAMS))
-                {
-                    Ok(_) => (),
-                    Err(e) => {
-                        return Err(std::io::Error::new(std::io::ErrorKind::Other, e));
+                for (table_name, rows) in tables_of(d)? {
+                    let (create, insert) = generate_statements(table_name, rows)?;
+                    match conn
+                        .execute(&create, NO_PARAMS)
+                        .and_then(|_| conn.execute(&insert, NO_PARAMS))
+                    {
+                        Ok(_) => (),
+                        Err(e) => {
+                            return Err(std::io::Error::new(std::io::ErrorKind::Other, e));
+                        }
                     }
                 }
             }
@@ -1,21 +1,20 @@
 use nu_errors::ShellError;
 use nu_plugin::Plugin;
-use nu_protocol::{CallInfo, Signature, Value};
+use nu_protocol::{Signature, Value};
 
 use crate::tree::TreeView;
 use crate::TreeViewer;
 
 impl Plugin for TreeViewer {
     fn config(&mut self) -> Result<Signature, ShellError> {
-        Ok(Signature::build("tree").desc("View the contents of the pipeline as a tree."))
+        Ok(Signature::build("tree")
+            .version(env!("CARGO_PKG_VERSION"))
+            .desc("View the contents of the pipeline as a tree.")
+            .streaming_sink())
     }
 
-    fn sink(&mut self, _call_info: CallInfo, input: Vec<Value>) {
-        if !input.is_empty() {
-            for i in input.iter() {
-                let view = TreeView::from_value(&i);
-                let _ = view.render_view();
-            }
-        }
+    fn sink_value(&mut self, input: Value) {
+        let view = TreeView::from_value(&input);
+        let _ = view.render_view();
     }
 }
@@ -10,6 +10,7 @@ use crate::{xpath::string_to_value, Xpath};
 impl Plugin for Xpath {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("xpath")
+            .version(env!("CARGO_PKG_VERSION"))
             .desc("execute xpath query on xml")
             .required("query", SyntaxShape::String, "xpath query")
             .filter())
@@ -1,14 +1,17 @@
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg};
 use log::LevelFilter;
-use nu_cli::{create_default_context, NuScript, Options};
+use nu_cli::{create_default_context, NuScript, Options, StartupReport};
 use nu_command::utils::test_bins as binaries;
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let mut startup_report = StartupReport::new();
     let mut options = Options::new();
 
     let matches = App::new("nushell")
         .version(clap::crate_version!())
+        // Everything after the script, flags and --help included, is for its main command
+        .setting(AppSettings::TrailingVarArg)
         .arg(
             Arg::with_name("config-file")
                 .long("config-file")
@@ -36,7 +39,9 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .hidden(true)
                 .long("testbin")
                 .value_name("TESTBIN")
-                .possible_values(&["cococo", "iecho", "fail", "nonu", "chop", "repeater"])
+                .possible_values(&[
+                    "cococo", "iecho", "fail", "nonu", "chop", "repeater", "bytes", "relay",
+                ])
                 .takes_value(true),
         )
         .arg(
@@ -64,6 +69,13 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .multiple(false)
                 .takes_value(false),
         )
+        .arg(
+            Arg::with_name("startup-report")
+                .long("startup-report")
+                .help("print how long each phase of starting the shell took")
+                .multiple(false)
+                .takes_value(false),
+        )
         .arg(
             Arg::with_name("no-history")
                 .hidden(true)
@@ -73,13 +85,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         )
         .arg(
             Arg::with_name("script")
-                .help("the nu script to run")
-                .index(1),
-        )
-        .arg(
-            Arg::with_name("args")
-                .help("positional args (used by --testbin)")
-                .index(2)
+                .help("the nu script to run, and the arguments of its main command")
+                .index(1)
                 .multiple(true),
         )
         .get_matches();
@@ -92,12 +99,16 @@ fn main() -> Result<(), Box<dyn Error>> {
             "nonu" => binaries::nonu(),
             "chop" => binaries::chop(),
             "repeater" => binaries::repeater(),
+            "bytes" => binaries::bytes(),
+            "relay" => binaries::relay(),
             _ => unreachable!(),
         }
 
         return Ok(());
     }
 
+    nu_cli::install_crash_reporter();
+
     options.config = matches
         .value_of("config-file")
         .map(std::ffi::OsString::from);
@@ -156,7 +167,13 @@ fn main() -> Result<(), Box<dyn Error>> {
         Some(filepath) => {
             let filepath = std::ffi::OsString::from(filepath);
 
-            options.scripts = vec![NuScript::source_file(filepath.as_os_str())?];
+            let mut script = NuScript::source_file(filepath.as_os_str())?;
+            script.args = matches
+                .values_of("script")
+                .map(|args| args.skip(1).map(String::from).collect())
+                .unwrap_or_default();
+
+            options.scripts = vec![script];
 
             futures::executor::block_on(nu_cli::run_script_file(options))?;
             return Ok(());
@@ -164,10 +181,16 @@ fn main() -> Result<(), Box<dyn Error>> {
 
         None => {
             let mut context = create_default_context(true)?;
+            startup_report.phase("context creation");
 
             if !matches.is_present("skip-plugins") {
                 let _ = nu_cli::register_plugins(&mut context);
             }
+            startup_report.phase("plugin scan");
+
+            if matches.is_present("startup-report") {
+                options.startup_report = Some(startup_report);
+            }
 
             #[cfg(feature = "rustyline-support")]
             {
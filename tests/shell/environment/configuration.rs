@@ -140,3 +140,96 @@ fn removes_config_values() {
         assert!(file_contents(&file).is_empty());
     })
 }
+
+#[test]
+fn reports_a_config_file_that_cannot_be_parsed() {
+    Playground::setup("config_error_test", |dirs, nu| {
+        let file = AbsolutePath::new(dirs.test().join("config.toml"));
+
+        nu.with_config(&file);
+        nu.with_files(vec![FileWithContent(
+            "config.toml",
+            r#"
+            skip_welcome_message = true
+            meal = "taco
+            "#,
+        )]);
+
+        assert_that!(
+            nu.pipeline("echo $nu.config-error | str contains 'line 3'"),
+            says().to_stdout("true")
+        );
+        assert_that!(
+            nu.pipeline("echo $nu.config-error | str contains taco"),
+            says().to_stdout("true")
+        );
+    })
+}
+
+#[test]
+fn applies_the_sections_for_this_machine() {
+    Playground::setup("config_when_test", |dirs, nu| {
+        let file = AbsolutePath::new(dirs.test().join("config.toml"));
+
+        nu.with_config(&file);
+        nu.with_files(vec![FileWithContent(
+            "config.toml",
+            r#"
+            skip_welcome_message = true
+
+            [meal]
+            dish = "taco"
+            drink = "water"
+
+            [[when]]
+            os = ["linux", "macos", "windows", "unix"]
+            [when.meal]
+            dish = "arepa"
+
+            [[when]]
+            host = "not-this-machine"
+            [when.meal]
+            drink = "coffee"
+            "#,
+        )]);
+
+        assert_that!(
+            nu.pipeline("config get meal.dish"),
+            says().to_stdout("arepa")
+        );
+        assert_that!(
+            nu.pipeline("config get meal.drink"),
+            says().to_stdout("water")
+        );
+    })
+}
+
+#[test]
+fn fails_to_convert_more_input_than_the_collect_memory_limit() {
+    Playground::setup("config_collect_memory_limit_test", |dirs, nu| {
+        let file = AbsolutePath::new(dirs.test().join("config.toml"));
+
+        nu.with_config(&file);
+        nu.with_files(vec![FileWithContent(
+            "config.toml",
+            r#"
+            skip_welcome_message = true
+            collect_memory_limit = 1024
+            "#,
+        )]);
+
+        assert_that!(
+            nu.pipeline("echo [1 2 3] | to json"),
+            says().to_stdout("[1,2,3]")
+        );
+
+        let outcome = match nu.pipeline("echo 1..10000 | to json").execute() {
+            Ok(outcome) => outcome,
+            Err(error) => error.output.expect("nu did not run"),
+        };
+
+        assert!(String::from_utf8_lossy(&outcome.out).trim().is_empty());
+        assert!(String::from_utf8_lossy(&outcome.err)
+            .contains("Input too large for to json, over the collect_memory_limit"));
+    })
+}
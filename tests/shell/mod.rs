@@ -5,6 +5,7 @@ use nu_test_support::{nu, pipeline};
 mod environment;
 
 mod pipeline;
+mod script;
 
 #[test]
 fn plugins_are_declared_with_wix() {
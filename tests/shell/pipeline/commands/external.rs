@@ -22,6 +22,59 @@ fn shows_error_for_command_not_found_in_pipeline() {
     assert!(actual.err.contains("Command not found"));
 }
 
+#[cfg(feature = "which")]
+#[test]
+fn suggests_the_closest_command() {
+    let actual = nu!(
+        cwd: ".",
+        "lenght"
+    );
+
+    assert!(actual.err.contains("did you mean length?"));
+}
+
+#[cfg(feature = "which")]
+#[test]
+fn suggests_the_closest_alias() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            alias ferris_says = echo hello
+            ferris_sayz
+        "#
+    );
+
+    assert!(actual.err.contains("did you mean ferris_says?"));
+}
+
+#[cfg(feature = "which")]
+#[test]
+fn runs_the_command_not_found_hook() {
+    use nu_test_support::fs::{AbsolutePath, Stub::FileWithContent};
+    use nu_test_support::playground::{says, Playground};
+
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    Playground::setup("command_not_found_hook_test", |dirs, nu| {
+        let file = AbsolutePath::new(dirs.test().join("config.toml"));
+
+        nu.with_config(&file);
+        nu.with_files(vec![FileWithContent(
+            "config.toml",
+            r#"
+            skip_welcome_message = true
+            command_not_found = "echo $(build-string 'install ' $it)"
+            "#,
+        )]);
+
+        assert_that!(
+            nu.pipeline("ferris_is_not_here"),
+            says().to_stdout("install ferris_is_not_here")
+        );
+    })
+}
+
 #[cfg(feature = "which")]
 #[test]
 fn automatically_change_directory() {
@@ -157,7 +210,7 @@ mod it_evaluation {
 
 mod stdin_evaluation {
     use super::nu;
-    use nu_test_support::pipeline;
+    use nu_test_support::{pipeline, playground::Playground};
 
     #[test]
     fn does_not_panic_with_no_newline_in_stream() {
@@ -188,6 +241,26 @@ mod stdin_evaluation {
 
         assert_eq!(stdout, "y");
     }
+
+    #[test]
+    fn passes_binary_data_between_externals_unchanged() {
+        Playground::setup("external_bytes_test_1", |dirs, _| {
+            nu!(
+                cwd: dirs.test(),
+                pipeline(r#"
+                    nu --testbin bytes 64
+                    | nu --testbin relay
+                    | nu --testbin relay
+                    | save out.bin
+                "#
+            ));
+
+            let expected: Vec<u8> = (0..64).flat_map(|_| (0..=255).rev()).collect();
+            let actual = std::fs::read(dirs.test().join("out.bin")).expect("saved file");
+
+            assert_eq!(actual, expected);
+        })
+    }
 }
 
 mod external_words {
@@ -210,6 +283,15 @@ mod external_words {
 
         assert_eq!(actual.out, "test \"things\"");
     }
+
+    #[test]
+    fn long_flags_with_equals_are_passed_through() {
+        let actual = nu!(cwd: ".", r#"
+        nu --testbin cococo --color=always
+        "#);
+
+        assert_eq!(actual.out, "--color=always");
+    }
 }
 
 mod nu_commands {
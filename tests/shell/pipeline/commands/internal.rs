@@ -1,4 +1,4 @@
-#[cfg(feature = "which")]
+use nu_test_support::fs::file_contents;
 use nu_test_support::fs::Stub::FileWithContent;
 use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
 use nu_test_support::nu;
@@ -58,6 +58,42 @@ fn argument_invocation() {
     assert_eq!(actual.out, "foo");
 }
 
+#[test]
+fn subexpression_as_argument() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo (echo [1 2 3] | length)
+        "#
+    );
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn subexpression_in_math() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            (echo [1 2 3] | length) * 2
+        "#
+    );
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn subexpression_with_cell_path() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo (echo [[name]; [nu] [shell]]).1.name
+        "#
+    );
+
+    assert_eq!(actual.out, "shell");
+}
+
 #[test]
 fn invocation_handles_dot() {
     Playground::setup("invocation_handles_dot", |dirs, sandbox| {
@@ -329,6 +365,54 @@ fn set_env_doesnt_leak() {
     assert!(actual.err.contains("did you mean"));
 }
 
+#[test]
+fn def_env_keeps_the_environment_changes_of_the_command() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        def --env set-xyz [] { let-env xyz = "my message" }; set-xyz; echo $nu.env.xyz
+        "#
+    );
+
+    assert_eq!(actual.out, "my message");
+}
+
+#[test]
+fn def_env_doesnt_keep_the_variables_of_the_command() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        def --env set-xyz [] { let x = 5; let-env xyz = "my message" }; set-xyz; echo $x
+        "#
+    );
+
+    assert!(actual.err.contains("unknown variable"));
+}
+
+#[test]
+fn def_env_doesnt_leak_without_the_flag() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        def set-xyz [] { let-env xyz = "my message" }; set-xyz; echo $nu.env.xyz
+        "#
+    );
+
+    assert!(actual.err.contains("did you mean"));
+}
+
+#[test]
+fn export_env_keeps_the_environment_changes_of_the_block() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        export-env { let message = "my message"; let-env xyz = $message }; echo $nu.env.xyz
+        "#
+    );
+
+    assert_eq!(actual.out, "my message");
+}
+
 #[test]
 fn proper_shadow_set_env_aliases() {
     let actual = nu!(
@@ -384,6 +468,30 @@ fn index_out_of_bounds() {
     assert!(actual.err.contains("unknown row"));
 }
 
+#[test]
+fn index_from_the_end() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        let foo = [[name]; [joe] [bob] [ann]]; echo $foo.-2.name
+        "#
+    );
+
+    assert_eq!(actual.out, "bob");
+}
+
+#[test]
+fn index_from_the_end_out_of_bounds() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let foo = [1, 2, 3]; echo $foo.-4
+        "#
+    );
+
+    assert!(actual.err.contains("unknown row"));
+}
+
 #[test]
 fn index_row() {
     let actual = nu!(
@@ -649,6 +757,148 @@ fn filesize_math7() {
     assert_eq!(actual.out, "1.0 TB");
 }
 
+#[test]
+fn escapes_in_double_quoted_strings() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        echo "one\ttwo\nthree \"four\" \u{1F600}" | lines | nth 1
+        "#
+    );
+
+    assert_eq!(actual.out, "three \"four\" 😀");
+}
+
+#[test]
+fn single_quoted_strings_are_literal() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        echo 'a\nb' | str length
+        "#
+    );
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn raw_strings_are_literal() {
+    let actual = nu!(
+        cwd: ".",
+        r##"
+        echo r#'say "hi" | it's #1\n'#
+        "##
+    );
+
+    assert_eq!(actual.out, r#"say "hi" | it's #1\n"#);
+}
+
+#[test]
+fn raw_strings_span_lines() {
+    Playground::setup("raw_string_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "script.nu",
+            r##"
+            echo r#'
+select "name", 'age'
+from people # not a comment
+'# | save query.sql
+            "##,
+        )]);
+
+        nu!(cwd: dirs.test(), "source script.nu");
+
+        assert_eq!(
+            file_contents(dirs.test().join("query.sql")),
+            "select \"name\", 'age'\nfrom people # not a comment\n"
+        );
+    })
+}
+
+#[test]
+fn long_flag_values_with_equals() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        echo 42 | str lpad --length=5 --character="0"
+        "#
+    );
+
+    assert_eq!(actual.out, "00042");
+}
+
+#[test]
+fn switch_with_equals_is_an_error() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        echo [1 2] | each --numbered=yes { echo $it }
+        "#
+    );
+
+    assert!(actual.err.contains("unexpected"));
+}
+
+#[test]
+fn block_comment_inside_pipeline() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        echo 1 #[ not an argument ]# 2 | math sum # trailing comment
+        "#
+    );
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn coalesce_missing_column() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        echo [[name]; [nu]] | each { = $it.editor ?? "vi" }
+        "#
+    );
+
+    assert_eq!(actual.out, "vi");
+}
+
+#[test]
+fn coalesce_keeps_existing_value() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        echo [[editor]; [emacs]] | each { = $it.editor ?? "vi" }
+        "#
+    );
+
+    assert_eq!(actual.out, "emacs");
+}
+
+#[test]
+fn coalesce_passes_through_other_errors() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        echo [[name]; [nu]] | each { = $it.name.editor ?? "vi" }
+        "#
+    );
+
+    assert!(actual.err.contains("Type Error"));
+}
+
+#[test]
+fn coalesce_nothing() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        = $nothing ?? 10 + 1
+        "#
+    );
+
+    assert_eq!(actual.out, "11");
+}
+
 #[test]
 fn exclusive_range_with_mixed_types() {
     let actual = nu!(
@@ -707,9 +957,9 @@ mod parse {
         Usage:
         > debug {flags}
 
-        flags:
-        -h, --help: Display this help message
-        -r, --raw: Prints the raw value representation.
+        Flags:
+        -h, --help  Display this help message
+        -r, --raw   Prints the raw value representation.
     */
 
     #[test]
@@ -730,6 +980,17 @@ mod parse {
         assert!(actual.err.contains("unexpected flag"),);
     }
 
+    #[test]
+    fn suggests_the_closest_flag() {
+        let actual = nu!(cwd: ".", "ls --al");
+
+        assert!(actual.err.contains("did you mean --all?"));
+
+        let actual = nu!(cwd: ".", "debug --ferris");
+
+        assert!(!actual.err.contains("did you mean"));
+    }
+
     #[test]
     fn errors_if_passed_an_unexpected_argument() {
         let actual = nu!(cwd: ".", "debug ferris");
@@ -0,0 +1,159 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::{says, Executable, Playground};
+
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+
+#[test]
+fn main_gets_the_arguments_of_the_script() {
+    Playground::setup("script_main_test_1", |_, nu| {
+        nu.with_files(vec![FileWithContent(
+            "search.nu",
+            r#"
+            def main [pattern: string, --count: int] {
+                echo $pattern $(= $count * 2) | str collect ","
+            }
+            "#,
+        )]);
+
+        assert_that!(
+            nu.script("search.nu", &["foo", "--count", "3"]),
+            says().to_stdout("foo,6")
+        );
+    })
+}
+
+#[test]
+fn main_quotes_arguments_with_spaces() {
+    Playground::setup("script_main_test_2", |_, nu| {
+        nu.with_files(vec![FileWithContent(
+            "greet.nu",
+            r#"
+            def main [name] { echo $name | str length }
+            "#,
+        )]);
+
+        assert_that!(
+            nu.script("greet.nu", &["Jonathan Turner"]),
+            says().to_stdout("15")
+        );
+    })
+}
+
+#[test]
+fn main_arguments_are_type_checked() {
+    Playground::setup("script_main_test_3", |_, nu| {
+        nu.with_files(vec![FileWithContent(
+            "search.nu",
+            r#"
+            def main [pattern: string, --count: int] { echo $pattern }
+            "#,
+        )]);
+
+        let outcome = nu
+            .script("search.nu", &["foo", "--count", "many"])
+            .execute()
+            .expect_err("the count should not be accepted");
+        let stderr = String::from_utf8_lossy(&outcome.output.expect("no output").err).to_string();
+
+        assert!(stderr.contains("int"));
+    })
+}
+
+#[test]
+fn main_has_help() {
+    Playground::setup("script_main_test_4", |_, nu| {
+        nu.with_files(vec![FileWithContent(
+            "search.nu",
+            r#"
+            # Searches for the pattern
+            def main [pattern: string, --count: int] { echo $pattern }
+            "#,
+        )]);
+
+        let outcome = nu
+            .script("search.nu", &["--help"])
+            .execute()
+            .expect("help should be shown");
+        let stdout = String::from_utf8_lossy(&outcome.out).to_string();
+
+        assert!(stdout.contains("pattern"));
+        assert!(stdout.contains("--count"));
+    })
+}
+
+#[test]
+fn scripts_without_main_just_run() {
+    Playground::setup("script_main_test_5", |_, nu| {
+        nu.with_files(vec![FileWithContent("hello.nu", "echo hello")]);
+
+        assert_that!(nu.script("hello.nu", &[]), says().to_stdout("hello"));
+    })
+}
+
+#[test]
+fn main_subcommands_get_the_arguments_after_their_name() {
+    Playground::setup("script_main_test_6", |_, nu| {
+        nu.with_files(vec![FileWithContent(
+            "tool.nu",
+            r#"
+            def "main build" [--release] { echo $(if $release { echo release } { echo debug }) }
+            def "main greet" [name] { echo $(build-string hello " " $name) }
+            "#,
+        )]);
+
+        assert_that!(
+            nu.script("tool.nu", &["build", "--release"]),
+            says().to_stdout("release")
+        );
+        assert_that!(
+            nu.script("tool.nu", &["greet", "world"]),
+            says().to_stdout("hello world")
+        );
+    })
+}
+
+#[test]
+fn main_lists_the_subcommands_without_one() {
+    Playground::setup("script_main_test_7", |_, nu| {
+        nu.with_files(vec![FileWithContent(
+            "tool.nu",
+            r#"
+            # Builds the project
+            def "main build" [] { echo built }
+            # Tests the project
+            def "main test" [] { echo tested }
+            "#,
+        )]);
+
+        let outcome = nu
+            .script("tool.nu", &[])
+            .execute()
+            .expect("the subcommands should be listed");
+        let stdout = String::from_utf8_lossy(&outcome.out).to_string();
+
+        assert!(stdout.contains("main build"));
+        assert!(stdout.contains("main test"));
+    })
+}
+
+#[test]
+fn main_fails_on_an_unknown_subcommand() {
+    Playground::setup("script_main_test_8", |_, nu| {
+        nu.with_files(vec![FileWithContent(
+            "tool.nu",
+            r#"
+            def "main build" [] { echo built }
+            "#,
+        )]);
+
+        let outcome = nu
+            .script("tool.nu", &["deploy"])
+            .execute()
+            .expect_err("deploy is not a subcommand");
+        let stderr = String::from_utf8_lossy(&outcome.output.expect("no output").err).to_string();
+
+        assert!(stderr.contains("Unknown subcommand deploy"));
+        assert!(stderr.contains("expected one of: build"));
+    })
+}